@@ -0,0 +1,213 @@
+//! Block device I/O scheduler and queue configuration.
+//!
+//! Reads /sys/block/*/queue to show each device's active scheduler,
+//! request queue depth (nr_requests) and read-ahead, and flags combinations
+//! that are known to cost throughput, e.g. BFQ in front of an NVMe drive
+//! whose own queues make per-request scheduling pure overhead.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Queue configuration of one block device
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockQueueConfig {
+    /// Device name (e.g. "nvme0n1", "sda")
+    pub device: String,
+    /// Active scheduler ("none", "mq-deadline", "bfq", "kyber")
+    pub scheduler: String,
+    /// Schedulers the kernel offers for this device
+    pub available: Vec<String>,
+    /// Maximum queued requests
+    pub nr_requests: u32,
+    /// Read-ahead in KiB
+    pub read_ahead_kb: u32,
+    /// Spinning disk
+    pub rotational: bool,
+}
+
+impl BlockQueueConfig {
+    /// NVMe namespaces have deep hardware queues of their own
+    pub fn is_nvme(&self) -> bool {
+        self.device.starts_with("nvme")
+    }
+
+    /// Tuning hints for obviously suboptimal settings
+    pub fn hints(&self) -> Vec<String> {
+        let mut hints = Vec::new();
+        match self.scheduler.as_str() {
+            "bfq" if self.is_nvme() => {
+                hints.push("bfq on NVMe adds per-request overhead; use none".to_string())
+            }
+            "bfq" if !self.rotational => {
+                hints.push("bfq on SSD; mq-deadline or none is cheaper".to_string())
+            }
+            "none" if self.rotational => {
+                hints.push("no scheduler on HDD; mq-deadline or bfq reduce seeks".to_string())
+            }
+            _ => {}
+        }
+        if self.is_nvme() && self.nr_requests > 0 && self.nr_requests < 64 {
+            hints.push(format!("shallow queue (nr_requests {}) for NVMe", self.nr_requests));
+        }
+        if self.rotational && self.read_ahead_kb == 0 {
+            hints.push("read-ahead disabled on HDD hurts sequential reads".to_string());
+        }
+        hints
+    }
+}
+
+/// Parse a scheduler file: "mq-deadline kyber [bfq] none" -> (active, available)
+fn parse_scheduler(content: &str) -> (String, Vec<String>) {
+    let mut active = String::new();
+    let available = content
+        .split_whitespace()
+        .map(|s| match s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            Some(name) => {
+                active = name.to_string();
+                name.to_string()
+            }
+            None => s.to_string(),
+        })
+        .collect();
+    (active, available)
+}
+
+/// I/O scheduler analyzer - reads queue settings of all block devices
+pub struct IoSchedulerAnalyzer {
+    devices: Vec<BlockQueueConfig>,
+    sys_block: PathBuf,
+    last_collect: Instant,
+}
+
+impl IoSchedulerAnalyzer {
+    pub fn new() -> Self {
+        Self::with_path("/sys/block")
+    }
+
+    /// Read devices from an alternate /sys/block (for testing)
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        Self {
+            devices: Vec::new(),
+            sys_block: path.into(),
+            last_collect: Instant::now() - Duration::from_secs(60),
+        }
+    }
+
+    /// Collect queue settings (they rarely change: at most every 10s)
+    pub fn collect(&mut self) {
+        if self.last_collect.elapsed() < Duration::from_secs(10) {
+            return;
+        }
+        self.last_collect = Instant::now();
+
+        let mut devices: Vec<BlockQueueConfig> = fs::read_dir(&self.sys_block)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .filter_map(|e| {
+                        let name = e.file_name().to_string_lossy().to_string();
+                        let virtual_dev = ["loop", "ram", "zram", "dm-"].iter().any(|p| name.starts_with(p));
+                        if virtual_dev {
+                            return None;
+                        }
+                        Self::read_device(&e.path().join("queue"), name)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        devices.sort_by(|a, b| a.device.cmp(&b.device));
+        self.devices = devices;
+    }
+
+    fn read_device(queue: &Path, device: String) -> Option<BlockQueueConfig> {
+        let read = |file: &str| fs::read_to_string(queue.join(file)).ok();
+        let (scheduler, available) = parse_scheduler(&read("scheduler")?);
+        let number = |file: &str| read(file).and_then(|s| s.trim().parse().ok()).unwrap_or(0);
+        Some(BlockQueueConfig {
+            device,
+            scheduler,
+            available,
+            nr_requests: number("nr_requests"),
+            read_ahead_kb: number("read_ahead_kb"),
+            rotational: number("rotational") == 1,
+        })
+    }
+
+    /// All block devices, sorted by name
+    pub fn devices(&self) -> &[BlockQueueConfig] {
+        &self.devices
+    }
+
+    /// Devices with at least one tuning hint
+    pub fn flagged(&self) -> impl Iterator<Item = &BlockQueueConfig> {
+        self.devices.iter().filter(|d| !d.hints().is_empty())
+    }
+}
+
+impl Default for IoSchedulerAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(name: &str, scheduler: &str, rotational: bool) -> BlockQueueConfig {
+        BlockQueueConfig {
+            device: name.to_string(),
+            scheduler: scheduler.to_string(),
+            nr_requests: 256,
+            read_ahead_kb: 128,
+            rotational,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_scheduler() {
+        let (active, available) = parse_scheduler("mq-deadline kyber [bfq] none\n");
+        assert_eq!(active, "bfq");
+        assert_eq!(available, vec!["mq-deadline", "kyber", "bfq", "none"]);
+
+        let (active, _) = parse_scheduler("[none] mq-deadline");
+        assert_eq!(active, "none");
+    }
+
+    #[test]
+    fn test_hints() {
+        assert!(device("nvme0n1", "bfq", false).hints()[0].contains("NVMe"));
+        assert!(device("sda", "bfq", false).hints()[0].contains("SSD"));
+        assert!(device("sdb", "none", true).hints()[0].contains("HDD"));
+        assert!(device("nvme0n1", "none", false).hints().is_empty());
+        assert!(device("sdb", "mq-deadline", true).hints().is_empty());
+
+        let mut shallow = device("nvme1n1", "none", false);
+        shallow.nr_requests = 32;
+        assert_eq!(shallow.hints().len(), 1);
+    }
+
+    #[test]
+    fn test_collect_from_sysfs() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        for (name, sched, rot) in [("nvme0n1", "mq-deadline kyber [bfq] none", "0"), ("sda", "[mq-deadline] none", "1"), ("loop0", "[none]", "0")] {
+            let queue = dir.path().join(name).join("queue");
+            fs::create_dir_all(&queue).expect("queue dir");
+            fs::write(queue.join("scheduler"), sched).expect("scheduler");
+            fs::write(queue.join("nr_requests"), "1023\n").expect("nr_requests");
+            fs::write(queue.join("read_ahead_kb"), "128\n").expect("read_ahead_kb");
+            fs::write(queue.join("rotational"), rot).expect("rotational");
+        }
+
+        let mut analyzer = IoSchedulerAnalyzer::with_path(dir.path());
+        analyzer.collect();
+        let names: Vec<&str> = analyzer.devices().iter().map(|d| d.device.as_str()).collect();
+        assert_eq!(names, vec!["nvme0n1", "sda"]);
+        assert_eq!(analyzer.devices()[0].nr_requests, 1023);
+        assert!(analyzer.devices()[1].rotational);
+        let flagged: Vec<&str> = analyzer.flagged().map(|d| d.device.as_str()).collect();
+        assert_eq!(flagged, vec!["nvme0n1"]);
+    }
+}
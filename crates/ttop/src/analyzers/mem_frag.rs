@@ -0,0 +1,305 @@
+//! Memory fragmentation and hugepage analyzer.
+//!
+//! Parses /proc/buddyinfo (free blocks per allocation order, per zone),
+//! the hugepage counters in /proc/meminfo and the THP/compaction counters
+//! in /proc/vmstat. Large model weights are usually mapped with 2 MiB pages;
+//! when memory is fragmented those allocations fall back to 4 KiB pages or
+//! stall in direct compaction, neither of which shows up as "used" memory.
+//!
+//! The fragmentation index is the unusable free space index (Gorman &
+//! Whitcroft, 2006): the fraction of free memory in blocks too small for an
+//! allocation of the given order. 0 means every free page could serve it,
+//! values near 1 mean the free memory is scattered.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Free block counts of one memory zone
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ZoneFrag {
+    /// NUMA node
+    pub node: u32,
+    /// Zone name (DMA, DMA32, Normal, Movable)
+    pub zone: String,
+    /// Free blocks per order (index = order, block = 2^order pages)
+    pub free_blocks: Vec<u64>,
+}
+
+impl ZoneFrag {
+    /// Total free pages in the zone
+    pub fn free_pages(&self) -> u64 {
+        self.free_blocks.iter().enumerate().map(|(order, &n)| n << order).sum()
+    }
+
+    /// Unusable free space index for an allocation of `order` (0.0 - 1.0)
+    pub fn unusable_index(&self, order: usize) -> f64 {
+        let total = self.free_pages();
+        if total == 0 {
+            return 0.0;
+        }
+        let usable: u64 = self.free_blocks.iter().enumerate().skip(order).map(|(o, &n)| n << o).sum();
+        (total - usable) as f64 / total as f64
+    }
+}
+
+/// Parse /proc/buddyinfo: "Node 0, zone   Normal   1024  512 ..."
+fn parse_buddyinfo(content: &str) -> Vec<ZoneFrag> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            if parts.next()? != "Node" {
+                return None;
+            }
+            let node = parts.next()?.trim_end_matches(',').parse().ok()?;
+            if parts.next()? != "zone" {
+                return None;
+            }
+            let zone = parts.next()?.to_string();
+            let free_blocks = parts.filter_map(|n| n.parse().ok()).collect();
+            Some(ZoneFrag { node, zone, free_blocks })
+        })
+        .collect()
+}
+
+/// Hugepage pool and transparent hugepage usage (from /proc/meminfo)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HugePageStats {
+    /// Preallocated hugepages
+    pub total: u64,
+    /// Free preallocated hugepages
+    pub free: u64,
+    /// Reserved but not yet faulted hugepages
+    pub reserved: u64,
+    /// Hugepage size in KiB
+    pub size_kb: u64,
+    /// Anonymous memory backed by THP (KiB)
+    pub anon_thp_kb: u64,
+    /// Shared memory backed by THP (KiB)
+    pub shmem_thp_kb: u64,
+}
+
+impl HugePageStats {
+    fn from_meminfo(content: &str) -> Self {
+        let fields: HashMap<&str, u64> = content
+            .lines()
+            .filter_map(|line| {
+                let (key, rest) = line.split_once(':')?;
+                Some((key, rest.split_whitespace().next()?.parse().ok()?))
+            })
+            .collect();
+        let get = |key: &str| fields.get(key).copied().unwrap_or(0);
+        Self {
+            total: get("HugePages_Total"),
+            free: get("HugePages_Free"),
+            reserved: get("HugePages_Rsvd"),
+            size_kb: get("Hugepagesize"),
+            anon_thp_kb: get("AnonHugePages"),
+            shmem_thp_kb: get("ShmemHugePages"),
+        }
+    }
+
+    /// Hugepages in use (including reserved ones)
+    pub fn used(&self) -> u64 {
+        (self.total - self.free.min(self.total)) + self.reserved
+    }
+
+    /// Buddy allocator order of one hugepage (9 for 2 MiB on 4 KiB pages)
+    pub fn order(&self) -> usize {
+        if self.size_kb >= 8 { (self.size_kb / 4).ilog2() as usize } else { 9 }
+    }
+}
+
+/// Cumulative THP and compaction counters (from /proc/vmstat)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThpCounters {
+    /// THP page faults served with a hugepage
+    pub fault_alloc: u64,
+    /// THP page faults that fell back to small pages
+    pub fault_fallback: u64,
+    /// Allocations that stalled in direct compaction
+    pub compact_stall: u64,
+}
+
+impl ThpCounters {
+    fn from_vmstat(content: &str) -> Self {
+        let mut counters = Self::default();
+        for line in content.lines() {
+            let mut parts = line.split_whitespace();
+            let (Some(key), Some(value)) = (parts.next(), parts.next().and_then(|v| v.parse().ok())) else {
+                continue;
+            };
+            match key {
+                "thp_fault_alloc" => counters.fault_alloc = value,
+                "thp_fault_fallback" => counters.fault_fallback = value,
+                "compact_stall" => counters.compact_stall = value,
+                _ => {}
+            }
+        }
+        counters
+    }
+}
+
+/// Memory fragmentation analyzer
+pub struct MemFragAnalyzer {
+    proc_path: PathBuf,
+    zones: Vec<ZoneFrag>,
+    hugepages: HugePageStats,
+    thp: ThpCounters,
+    prev: Option<(ThpCounters, Instant)>,
+    /// Direct compaction stalls per second
+    pub compact_stall_rate: f64,
+    /// THP faults falling back to small pages per second
+    pub thp_fallback_rate: f64,
+    last_collect: Instant,
+}
+
+impl MemFragAnalyzer {
+    pub fn new() -> Self {
+        Self::with_path("/proc")
+    }
+
+    /// Read from an alternate /proc (for testing)
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        Self {
+            proc_path: path.into(),
+            zones: Vec::new(),
+            hugepages: HugePageStats::default(),
+            thp: ThpCounters::default(),
+            prev: None,
+            compact_stall_rate: 0.0,
+            thp_fallback_rate: 0.0,
+            last_collect: Instant::now() - Duration::from_secs(10),
+        }
+    }
+
+    /// Collect buddyinfo, hugepage and THP counters (at most once per second)
+    pub fn collect(&mut self) {
+        if self.last_collect.elapsed() < Duration::from_secs(1) {
+            return;
+        }
+        self.last_collect = Instant::now();
+
+        let read = |file: &str| fs::read_to_string(self.proc_path.join(file)).unwrap_or_default();
+        self.zones = parse_buddyinfo(&read("buddyinfo"));
+        self.hugepages = HugePageStats::from_meminfo(&read("meminfo"));
+        let counters = ThpCounters::from_vmstat(&read("vmstat"));
+        let now = Instant::now();
+        if let Some((_, prev_time)) = self.prev {
+            self.update_rates(counters, now.duration_since(prev_time).as_secs_f64());
+        }
+        self.thp = counters;
+        self.prev = Some((counters, now));
+    }
+
+    fn update_rates(&mut self, counters: ThpCounters, elapsed_secs: f64) {
+        if elapsed_secs <= 0.0 {
+            return;
+        }
+        let prev = self.prev.map(|(c, _)| c).unwrap_or(counters);
+        self.compact_stall_rate = counters.compact_stall.saturating_sub(prev.compact_stall) as f64 / elapsed_secs;
+        self.thp_fallback_rate = counters.fault_fallback.saturating_sub(prev.fault_fallback) as f64 / elapsed_secs;
+    }
+
+    /// Check if /proc/buddyinfo was readable
+    pub fn is_available(&self) -> bool {
+        !self.zones.is_empty()
+    }
+
+    /// Zones with their free block counts
+    pub fn zones(&self) -> &[ZoneFrag] {
+        &self.zones
+    }
+
+    /// Hugepage pool and THP usage
+    pub fn hugepages(&self) -> HugePageStats {
+        self.hugepages
+    }
+
+    /// Cumulative THP counters
+    pub fn thp(&self) -> ThpCounters {
+        self.thp
+    }
+
+    /// Most fragmented zone (with free memory) for hugepage-sized allocations
+    pub fn worst_zone(&self) -> Option<(&ZoneFrag, f64)> {
+        let order = self.hugepages.order();
+        self.zones
+            .iter()
+            .filter(|z| z.free_pages() > 0 && z.zone != "DMA")
+            .map(|z| (z, z.unusable_index(order)))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
+
+impl Default for MemFragAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUDDYINFO: &str = "\
+Node 0, zone      DMA      0      0      0      0      0      0      0      0      1      1      2
+Node 0, zone    DMA32      4      3      2      1      0      0      0      0      0      0      0
+Node 0, zone   Normal    100     50      0      0      0      0      0      0      0      1      0
+";
+
+    #[test]
+    fn test_parse_buddyinfo() {
+        let zones = parse_buddyinfo(BUDDYINFO);
+        assert_eq!(zones.len(), 3);
+        assert_eq!(zones[2].zone, "Normal");
+        assert_eq!(zones[2].free_blocks.len(), 11);
+        // 100*1 + 50*2 + 1*512
+        assert_eq!(zones[2].free_pages(), 712);
+    }
+
+    #[test]
+    fn test_unusable_index() {
+        let zones = parse_buddyinfo(BUDDYINFO);
+        // DMA32 has nothing at order >= 4: fully fragmented for hugepages
+        assert!((zones[1].unusable_index(9) - 1.0).abs() < 1e-9);
+        assert!(zones[1].unusable_index(0).abs() < 1e-9);
+        // Normal: 200 of 712 free pages are in blocks below order 9
+        assert!((zones[2].unusable_index(9) - 200.0 / 712.0).abs() < 1e-9);
+        assert_eq!(ZoneFrag::default().unusable_index(9), 0.0);
+    }
+
+    #[test]
+    fn test_hugepages_from_meminfo() {
+        let meminfo = "MemTotal:       65536000 kB\nAnonHugePages:    2048000 kB\nShmemHugePages:        0 kB\n\
+                       HugePages_Total:      16\nHugePages_Free:        4\nHugePages_Rsvd:        2\nHugepagesize:       2048 kB\n";
+        let huge = HugePageStats::from_meminfo(meminfo);
+        assert_eq!((huge.total, huge.free, huge.used()), (16, 4, 14));
+        assert_eq!(huge.anon_thp_kb, 2_048_000);
+        assert_eq!(huge.order(), 9);
+        assert_eq!(HugePageStats { size_kb: 1_048_576, ..Default::default() }.order(), 18);
+    }
+
+    #[test]
+    fn test_thp_rates_and_worst_zone() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        fs::write(dir.path().join("buddyinfo"), BUDDYINFO).expect("buddyinfo");
+        fs::write(dir.path().join("meminfo"), "Hugepagesize:       2048 kB\n").expect("meminfo");
+        fs::write(dir.path().join("vmstat"), "thp_fault_alloc 10\nthp_fault_fallback 4\ncompact_stall 2\n").expect("vmstat");
+
+        let mut analyzer = MemFragAnalyzer::with_path(dir.path());
+        analyzer.collect();
+        assert!(analyzer.is_available());
+        assert_eq!(analyzer.thp().fault_fallback, 4);
+
+        let (zone, index) = analyzer.worst_zone().expect("worst zone");
+        assert_eq!(zone.zone, "DMA32");
+        assert!((index - 1.0).abs() < 1e-9);
+
+        analyzer.update_rates(ThpCounters { fault_alloc: 10, fault_fallback: 8, compact_stall: 12 }, 2.0);
+        assert!((analyzer.compact_stall_rate - 5.0).abs() < 1e-9);
+        assert!((analyzer.thp_fallback_rate - 2.0).abs() < 1e-9);
+    }
+}
@@ -9,6 +9,9 @@
 //! - PSI pressure stall monitoring (Linux 4.20+)
 //! - Container/Docker monitoring
 //! - Network protocol/error/latency analysis
+//! - Block I/O scheduler and queue tuning hints
+//! - Memory fragmentation (unusable free space index) and hugepage usage
+//! - Scheduler run queue wait per CPU (schedstat)
 
 pub mod connections;
 pub mod containers;
@@ -17,9 +20,12 @@ pub mod disk_io;
 pub mod file_analyzer;
 pub mod geoip;
 pub mod gpu_procs;
+pub mod io_sched;
+pub mod mem_frag;
 pub mod network_stats;
 pub mod process_extra;
 pub mod psi;
+pub mod sched_latency;
 pub mod sensor_health;
 pub mod storage;
 pub mod swap;
@@ -31,9 +37,12 @@ pub use disk_entropy::{DiskEntropyAnalyzer, MountEntropy};
 pub use disk_io::{DiskIoAnalyzer, IoWorkloadType};
 pub use file_analyzer::{FileAnalyzer, FileEntry, FileType, DuplicateGroup, WatchedFile, IoActivity, EntropyLevel, FileActivityMetrics};
 pub use gpu_procs::{GpuProcess, GpuProcessAnalyzer, GpuProcType};
+pub use io_sched::{BlockQueueConfig, IoSchedulerAnalyzer};
+pub use mem_frag::{HugePageStats, MemFragAnalyzer, ThpCounters, ZoneFrag};
 pub use network_stats::{NetworkStatsAnalyzer, ProtocolStats, TcpPerformance, QueueStats};
 pub use process_extra::{ProcessExtra, ProcessExtraAnalyzer};
 pub use psi::{PressureLevel, PsiAnalyzer, PsiMetrics};
+pub use sched_latency::{RunQueueWait, SchedLatencyAnalyzer};
 pub use storage::{Anomaly, LargeFileDetector, StorageAnalyzer};
 pub use sensor_health::{SensorHealth, SensorHealthAnalyzer, SensorReading, SensorType};
 pub use swap::{SwapAnalyzer, ThrashingSeverity, ZramStats};
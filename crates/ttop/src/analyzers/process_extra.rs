@@ -16,6 +16,8 @@ pub struct ProcessExtra {
     pub fd_count: u32,
     /// File descriptor limit (ulimit)
     pub fd_limit: u32,
+    /// Task limit of the owning user (ulimit -u), 0 if unlimited
+    pub thread_limit: u32,
     /// CPU usage history (last N samples)
     pub cpu_history: Vec<f64>,
     /// Parent process chain (for ancestry)
@@ -47,6 +49,11 @@ impl ProcessExtra {
             0.0
         }
     }
+
+    /// Check if a thread count is near the task limit (>= 80%)
+    pub fn thread_warning(&self, threads: u32) -> bool {
+        self.thread_limit > 0 && threads as f64 / self.thread_limit as f64 >= 0.8
+    }
 }
 
 /// Analyzer for extended process information
@@ -77,10 +84,11 @@ impl ProcessExtraAnalyzer {
             // Update container info
             extra.container = Self::get_container_name(pid);
 
-            // Update FD count
-            let (fd_count, fd_limit) = Self::get_fd_info(pid);
+            // Update FD count and limits
+            let (fd_count, fd_limit, thread_limit) = Self::get_fd_info(pid);
             extra.fd_count = fd_count;
             extra.fd_limit = fd_limit;
+            extra.thread_limit = thread_limit;
 
             // Update CPU history
             if let Some(&cpu) = cpu_percents.get(&pid) {
@@ -181,32 +189,32 @@ impl ProcessExtraAnalyzer {
         None
     }
 
-    /// Get FD count and limit from /proc/PID/fd and /proc/PID/limits
+    /// Get FD count, FD limit and task limit from /proc/PID/fd and /proc/PID/limits
     #[cfg(target_os = "linux")]
-    fn get_fd_info(pid: u32) -> (u32, u32) {
+    fn get_fd_info(pid: u32) -> (u32, u32, u32) {
         let fd_path = format!("/proc/{}/fd", pid);
         let fd_count = fs::read_dir(&fd_path)
             .map(|d| d.count() as u32)
             .unwrap_or(0);
 
         let limits_path = format!("/proc/{}/limits", pid);
-        let fd_limit = fs::read_to_string(&limits_path)
-            .ok()
-            .and_then(|content| {
-                for line in content.lines() {
-                    if line.starts_with("Max open files") {
-                        let parts: Vec<&str> = line.split_whitespace().collect();
-                        // Format: "Max open files            1024                 1048576              files"
-                        if parts.len() >= 5 {
-                            return parts[3].parse().ok();
-                        }
-                    }
-                }
-                None
-            })
-            .unwrap_or(0);
+        let limits = fs::read_to_string(&limits_path).unwrap_or_default();
+        let fd_limit = Self::soft_limit(&limits, "Max open files");
+        let thread_limit = Self::soft_limit(&limits, "Max processes");
+
+        (fd_count, fd_limit, thread_limit)
+    }
 
-        (fd_count, fd_limit)
+    /// Parse a soft limit from /proc/PID/limits (0 if unlimited or missing)
+    #[cfg(any(target_os = "linux", test))]
+    fn soft_limit(limits: &str, name: &str) -> u32 {
+        // Format: "Max open files            1024                 1048576              files"
+        limits
+            .lines()
+            .find_map(|line| line.strip_prefix(name))
+            .and_then(|rest| rest.split_whitespace().next())
+            .and_then(|soft| soft.parse().ok())
+            .unwrap_or(0)
     }
 
     /// Build process ancestry chain
@@ -287,6 +295,25 @@ mod tests {
         assert!((extra.fd_percent() - 25.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_thread_warning() {
+        let mut extra = ProcessExtra::default();
+        assert!(!extra.thread_warning(10_000)); // unlimited
+
+        extra.thread_limit = 1000;
+        assert!(extra.thread_warning(900));
+        assert!(!extra.thread_warning(100));
+    }
+
+    #[test]
+    fn test_soft_limit() {
+        let limits = "Max processes             63412                63412                processes\n\
+                      Max open files            1024                 1048576              files\n";
+        assert_eq!(ProcessExtraAnalyzer::soft_limit(limits, "Max open files"), 1024);
+        assert_eq!(ProcessExtraAnalyzer::soft_limit(limits, "Max processes"), 63412);
+        assert_eq!(ProcessExtraAnalyzer::soft_limit("Max processes unlimited", "Max processes"), 0);
+    }
+
     #[test]
     fn test_analyzer_creation() {
         let analyzer = ProcessExtraAnalyzer::new();
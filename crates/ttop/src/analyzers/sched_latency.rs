@@ -0,0 +1,209 @@
+//! Scheduler latency (run queue wait) analyzer.
+//!
+//! Reads per-CPU counters from /proc/schedstat. `run_delay` is the total
+//! time tasks spent runnable but waiting for that CPU; its rate shows
+//! contention that utilization hides: a CPU at 100% with nothing queued is
+//! fine, one at 60% whose tasks keep waiting is not.
+//!
+//! Line format (schedstat version 15+):
+//! `cpuN yld_count 0 sched_count sched_goidle ttwu_count ttwu_local rq_cpu_time run_delay pcount`
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Cumulative schedstat counters of one CPU
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct SchedCounters {
+    /// Time tasks waited on the run queue (ns)
+    run_delay_ns: u64,
+    /// Timeslices run
+    timeslices: u64,
+}
+
+/// Run queue wait of one CPU over the last interval
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RunQueueWait {
+    /// Milliseconds of waiting per second (summed over waiting tasks)
+    pub wait_ms_per_sec: f64,
+    /// Average wait per timeslice (microseconds)
+    pub avg_wait_us: f64,
+}
+
+impl RunQueueWait {
+    /// Waiting time as a percentage of wall time (can exceed 100 when
+    /// several tasks wait at once)
+    pub fn wait_percent(&self) -> f64 {
+        self.wait_ms_per_sec / 10.0
+    }
+}
+
+/// Parse /proc/schedstat into per-CPU counters, indexed by CPU number
+fn parse_schedstat(content: &str) -> Vec<(usize, SchedCounters)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            let cpu = parts.first()?.strip_prefix("cpu")?.parse().ok()?;
+            Some((
+                cpu,
+                SchedCounters {
+                    run_delay_ns: parts.get(8)?.parse().ok()?,
+                    timeslices: parts.get(9)?.parse().ok()?,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Scheduler latency analyzer
+pub struct SchedLatencyAnalyzer {
+    path: PathBuf,
+    prev: Vec<SchedCounters>,
+    prev_time: Option<Instant>,
+    waits: Vec<RunQueueWait>,
+    last_collect: Instant,
+}
+
+impl SchedLatencyAnalyzer {
+    pub fn new() -> Self {
+        Self::with_path("/proc/schedstat")
+    }
+
+    /// Read from an alternate schedstat file (for testing)
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            prev: Vec::new(),
+            prev_time: None,
+            waits: Vec::new(),
+            last_collect: Instant::now() - Duration::from_secs(10),
+        }
+    }
+
+    /// Collect schedstat counters (at most once per second)
+    pub fn collect(&mut self) {
+        if self.last_collect.elapsed() < Duration::from_secs(1) {
+            return;
+        }
+        self.last_collect = Instant::now();
+
+        let Ok(content) = fs::read_to_string(&self.path) else {
+            return;
+        };
+        let now = Instant::now();
+        let elapsed = self.prev_time.map(|t| now.duration_since(t).as_secs_f64());
+        self.update(&content, elapsed);
+        self.prev_time = Some(now);
+    }
+
+    fn update(&mut self, content: &str, elapsed_secs: Option<f64>) {
+        let parsed = parse_schedstat(content);
+        let cpus = parsed.iter().map(|(cpu, _)| cpu + 1).max().unwrap_or(0);
+        let mut current = vec![SchedCounters::default(); cpus];
+        for (cpu, counters) in parsed {
+            current[cpu] = counters;
+        }
+
+        if let Some(secs) = elapsed_secs.filter(|&s| s > 0.0) {
+            self.waits = current
+                .iter()
+                .enumerate()
+                .map(|(i, curr)| {
+                    let prev = self.prev.get(i).copied().unwrap_or(*curr);
+                    let delay_ns = curr.run_delay_ns.saturating_sub(prev.run_delay_ns) as f64;
+                    let slices = curr.timeslices.saturating_sub(prev.timeslices);
+                    RunQueueWait {
+                        wait_ms_per_sec: delay_ns / 1e6 / secs,
+                        avg_wait_us: if slices > 0 { delay_ns / 1e3 / slices as f64 } else { 0.0 },
+                    }
+                })
+                .collect();
+        }
+        self.prev = current;
+    }
+
+    /// Check if schedstat has been read
+    pub fn is_available(&self) -> bool {
+        !self.prev.is_empty()
+    }
+
+    /// Run queue wait of a CPU over the last interval
+    pub fn cpu(&self, cpu: usize) -> Option<&RunQueueWait> {
+        self.waits.get(cpu)
+    }
+
+    /// Run queue wait of all CPUs
+    pub fn waits(&self) -> &[RunQueueWait] {
+        &self.waits
+    }
+
+    /// Most contended CPU (index, wait)
+    pub fn worst(&self) -> Option<(usize, &RunQueueWait)> {
+        self.waits
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.wait_ms_per_sec.total_cmp(&b.1.wait_ms_per_sec))
+    }
+}
+
+impl Default for SchedLatencyAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEDSTAT_1: &str = "\
+version 15
+timestamp 4295000000
+cpu0 0 0 100 50 40 20 9000000000 1000000000 500
+domain0 00000003 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0
+cpu1 0 0 100 50 40 20 9000000000 2000000000 1000
+";
+
+    const SCHEDSTAT_2: &str = "\
+version 15
+timestamp 4295000250
+cpu0 0 0 100 50 40 20 9000000000 1000000000 600
+cpu1 0 0 100 50 40 20 9000000000 2500000000 1500
+";
+
+    #[test]
+    fn test_parse_schedstat() {
+        let parsed = parse_schedstat(SCHEDSTAT_1);
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[1].0, 1);
+        assert_eq!(parsed[1].1.run_delay_ns, 2_000_000_000);
+        assert_eq!(parsed[1].1.timeslices, 1000);
+    }
+
+    #[test]
+    fn test_run_queue_wait_rates() {
+        let mut analyzer = SchedLatencyAnalyzer::with_path("/nonexistent");
+        analyzer.update(SCHEDSTAT_1, None);
+        assert!(analyzer.is_available());
+        assert!(analyzer.cpu(0).is_none());
+
+        analyzer.update(SCHEDSTAT_2, Some(2.0));
+        let idle = analyzer.cpu(0).expect("cpu0");
+        assert_eq!(idle.wait_ms_per_sec, 0.0);
+        // 500 ms of waiting over 2 s, across 500 timeslices
+        let busy = analyzer.cpu(1).expect("cpu1");
+        assert!((busy.wait_ms_per_sec - 250.0).abs() < 1e-9);
+        assert!((busy.wait_percent() - 25.0).abs() < 1e-9);
+        assert!((busy.avg_wait_us - 1000.0).abs() < 1e-9);
+        assert_eq!(analyzer.worst().map(|(cpu, _)| cpu), Some(1));
+    }
+
+    #[test]
+    fn test_missing_schedstat() {
+        let mut analyzer = SchedLatencyAnalyzer::with_path("/nonexistent/schedstat");
+        analyzer.collect();
+        assert!(!analyzer.is_available());
+        assert!(analyzer.worst().is_none());
+    }
+}
@@ -5,7 +5,7 @@ use std::time::{Duration, Instant};
 
 use trueno_viz::monitor::collectors::{
     BatteryCollector, CpuCollector, DiskCollector, MemoryCollector, NetworkCollector,
-    ProcessCollector, SensorCollector,
+    PcieCollector, ProcessCollector, SensorCollector,
 };
 use trueno_viz::monitor::types::Collector;
 
@@ -18,8 +18,8 @@ use trueno_viz::monitor::collectors::AmdGpuCollector;
 #[cfg(target_os = "macos")]
 use trueno_viz::monitor::collectors::AppleGpuCollector;
 
-use crate::analyzers::{ContainerAnalyzer, DiskEntropyAnalyzer, DiskIoAnalyzer, GpuProcessAnalyzer, NetworkStatsAnalyzer, PsiAnalyzer, SensorHealthAnalyzer, StorageAnalyzer, SwapAnalyzer, ThrashingSeverity};
-use crate::state::{PanelType, ProcessSortColumn, SignalType};
+use crate::analyzers::{ContainerAnalyzer, DiskEntropyAnalyzer, DiskIoAnalyzer, GpuProcessAnalyzer, IoSchedulerAnalyzer, MemFragAnalyzer, NetworkStatsAnalyzer, PsiAnalyzer, SchedLatencyAnalyzer, SensorHealthAnalyzer, StorageAnalyzer, SwapAnalyzer, ThrashingSeverity};
+use crate::state::{PanelType, ProcessAction, ProcessSortColumn, SignalType};
 
 /// Allocation-free case-insensitive substring search.
 ///
@@ -246,17 +246,22 @@ pub struct App {
     #[cfg(target_os = "linux")]
     pub amd_gpu: AmdGpuCollector,
 
+    pub pcie: PcieCollector,
+
     #[cfg(target_os = "macos")]
     pub apple_gpu: AppleGpuCollector,
 
     // Advanced analyzers (ttop-improve.md spec)
     pub swap_analyzer: SwapAnalyzer,
+    pub mem_frag: MemFragAnalyzer,
     pub disk_io_analyzer: DiskIoAnalyzer,
+    pub io_scheduler: IoSchedulerAnalyzer,
     pub storage_analyzer: StorageAnalyzer,
     pub connection_analyzer: crate::analyzers::ConnectionAnalyzer,
     pub treemap_analyzer: crate::analyzers::TreemapAnalyzer,
     pub gpu_process_analyzer: GpuProcessAnalyzer,
     pub psi_analyzer: PsiAnalyzer,
+    pub sched_latency: SchedLatencyAnalyzer,
     pub container_analyzer: ContainerAnalyzer,
     pub network_stats: NetworkStatsAnalyzer,
     pub disk_entropy: DiskEntropyAnalyzer,
@@ -355,6 +360,17 @@ pub struct App {
     pub pending_signal: Option<(u32, String, SignalType)>, // (pid, name, signal)
     pub signal_result: Option<(bool, String, Instant)>,     // (success, message, timestamp)
 
+    // Process context menu
+    pub show_context_menu: bool,
+    pub files_focus: Option<std::path::PathBuf>, // Files panel limited to this directory
+
+    // Unified search (processes, connections, files)
+    pub show_search: bool,
+    pub search_query: String,
+    pub search_hits: Vec<crate::search::SearchHit>,
+    pub search_selected: usize,
+    pub search_highlight: Option<String>, // lowercase query highlighted after a jump
+
     // Panel focus/explode state
     pub focused_panel: Option<PanelType>,
     pub exploded_panel: Option<PanelType>,
@@ -464,17 +480,22 @@ impl App {
             #[cfg(target_os = "linux")]
             amd_gpu,
 
+            pcie: PcieCollector::new(),
+
             #[cfg(target_os = "macos")]
             apple_gpu,
 
             // Initialize advanced analyzers
             swap_analyzer: SwapAnalyzer::default(),
+            mem_frag: MemFragAnalyzer::default(),
             disk_io_analyzer: DiskIoAnalyzer::default(),
+            io_scheduler: IoSchedulerAnalyzer::default(),
             storage_analyzer: StorageAnalyzer::default(),
             connection_analyzer: crate::analyzers::ConnectionAnalyzer::default(),
             treemap_analyzer: crate::analyzers::TreemapAnalyzer::new("/"),
             gpu_process_analyzer: GpuProcessAnalyzer::default(),
             psi_analyzer: PsiAnalyzer::default(),
+            sched_latency: SchedLatencyAnalyzer::default(),
             container_analyzer: ContainerAnalyzer::default(),
             network_stats: NetworkStatsAnalyzer::default(),
             disk_entropy: DiskEntropyAnalyzer::new(),
@@ -548,6 +569,13 @@ impl App {
             show_signal_menu: false,
             pending_signal: None,
             signal_result: None,
+            show_context_menu: false,
+            files_focus: None,
+            show_search: false,
+            search_query: String::new(),
+            search_hits: Vec::new(),
+            search_selected: 0,
+            search_highlight: None,
 
             focused_panel: None,
             exploded_panel: None,
@@ -599,16 +627,21 @@ impl App {
             #[cfg(target_os = "linux")]
             amd_gpu: AmdGpuCollector::default(),
 
+            pcie: PcieCollector::default(),
+
             #[cfg(target_os = "macos")]
             apple_gpu: AppleGpuCollector::default(),
 
             swap_analyzer: SwapAnalyzer::new(),
+            mem_frag: MemFragAnalyzer::new(),
             disk_io_analyzer: DiskIoAnalyzer::new(),
+            io_scheduler: IoSchedulerAnalyzer::new(),
             storage_analyzer: StorageAnalyzer::new(),
             connection_analyzer: crate::analyzers::ConnectionAnalyzer::new(),
             treemap_analyzer: crate::analyzers::TreemapAnalyzer::new("/tmp"),
             gpu_process_analyzer: GpuProcessAnalyzer::new(),
             psi_analyzer: PsiAnalyzer::new(),
+            sched_latency: SchedLatencyAnalyzer::new(),
             container_analyzer: ContainerAnalyzer::new(),
             network_stats: NetworkStatsAnalyzer::new(),
             disk_entropy: DiskEntropyAnalyzer::new(),
@@ -736,6 +769,13 @@ impl App {
             show_signal_menu: false,
             pending_signal: None,
             signal_result: None,
+            show_context_menu: false,
+            files_focus: None,
+            show_search: false,
+            search_query: String::new(),
+            search_hits: Vec::new(),
+            search_selected: 0,
+            search_highlight: None,
 
             focused_panel: None,
             exploded_panel: None,
@@ -1007,6 +1047,10 @@ impl App {
             let _ = self.amd_gpu.collect();
         }
 
+        if self.pcie.is_available() {
+            let _ = self.pcie.collect();
+        }
+
         #[cfg(target_os = "macos")]
         if self.apple_gpu.is_available() {
             let _ = self.apple_gpu.collect();
@@ -1020,9 +1064,15 @@ impl App {
         if is_first { debug::log(Level::Trace, "collect", "swap_analyzer..."); }
         self.swap_analyzer.collect();
 
+        if is_first { debug::log(Level::Trace, "collect", "mem_frag..."); }
+        self.mem_frag.collect();
+
         if is_first { debug::log(Level::Trace, "collect", "disk_io_analyzer..."); }
         self.disk_io_analyzer.collect();
 
+        if is_first { debug::log(Level::Trace, "collect", "io_scheduler..."); }
+        self.io_scheduler.collect();
+
         if is_first { debug::log(Level::Trace, "collect", "disk_entropy..."); }
         let mount_paths: Vec<String> = self.disk.mounts().iter().map(|m| m.mount_point.clone()).collect();
         self.disk_entropy.collect(&mount_paths);
@@ -1042,6 +1092,9 @@ impl App {
         if is_first { debug::log(Level::Trace, "collect", "psi_analyzer..."); }
         self.psi_analyzer.collect();
 
+        if is_first { debug::log(Level::Trace, "collect", "sched_latency..."); }
+        self.sched_latency.collect();
+
         if is_first { debug::log(Level::Trace, "collect", "container_analyzer..."); }
         self.container_analyzer.collect();
 
@@ -1090,7 +1143,7 @@ impl App {
 
     /// Handle keyboard input. Returns true if app should quit.
     pub fn handle_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
-        // Modal interceptors (signal confirm, signal menu, filter input)
+        // Modal interceptors (signal confirm, signal menu, context menu, search, filter input)
         if let Some(handled) = self.handle_modal_key(code) {
             return handled;
         }
@@ -1166,6 +1219,53 @@ impl App {
             return Some(false);
         }
 
+        // Process context menu (pick action)
+        if self.show_context_menu {
+            match code {
+                KeyCode::Esc | KeyCode::Char('m') => {
+                    self.show_context_menu = false;
+                }
+                KeyCode::Char(c) => {
+                    if let Some(action) = ProcessAction::from_key(c) {
+                        self.show_context_menu = false;
+                        self.run_process_action(action);
+                    }
+                }
+                _ => {}
+            }
+            return Some(false);
+        }
+
+        // Unified search mode
+        if self.show_search {
+            match code {
+                KeyCode::Esc => {
+                    self.show_search = false;
+                    self.search_highlight = None;
+                }
+                KeyCode::Enter => {
+                    self.show_search = false;
+                    self.jump_to_search_hit();
+                }
+                KeyCode::Down => {
+                    if self.search_selected + 1 < self.search_hits.len() {
+                        self.search_selected += 1;
+                    }
+                }
+                KeyCode::Up => self.search_selected = self.search_selected.saturating_sub(1),
+                KeyCode::Backspace => {
+                    self.search_query.pop();
+                    self.update_search();
+                }
+                KeyCode::Char(c) => {
+                    self.search_query.push(c);
+                    self.update_search();
+                }
+                _ => {}
+            }
+            return Some(false);
+        }
+
         // Filter input mode
         if self.show_filter_input {
             match code {
@@ -1321,6 +1421,11 @@ impl App {
                 self.request_signal(SignalType::Term);
             }
 
+            // Process context menu
+            KeyCode::Char('m') if self.focused_panel.is_none() || self.exploded_panel == Some(PanelType::Process) => {
+                self.show_context_menu = true;
+            }
+
             // z key starts focus when nothing is focused/exploded
             KeyCode::Char('z') if self.focused_panel.is_none() && self.exploded_panel.is_none() => {
                 self.focused_panel = Some(self.first_visible_panel());
@@ -1330,7 +1435,19 @@ impl App {
             KeyCode::Char('f') | KeyCode::Char('/') => {
                 self.show_filter_input = true;
             }
-            KeyCode::Delete => self.filter.clear(),
+            KeyCode::Delete => {
+                self.filter.clear();
+                self.files_focus = None;
+                self.search_highlight = None;
+            }
+
+            // Unified search
+            KeyCode::Char('F') => {
+                self.show_search = true;
+                self.search_query.clear();
+                self.search_hits.clear();
+                self.search_selected = 0;
+            }
 
             // Reset view
             KeyCode::Char('0') => {
@@ -1592,6 +1709,185 @@ impl App {
         self.pending_signal = None;
     }
 
+    /// Run a context menu action on the selected process
+    pub fn run_process_action(&mut self, action: ProcessAction) {
+        let Some((pid, name, cmdline)) = self
+            .sorted_processes()
+            .get(self.process_selected)
+            .map(|p| (p.pid, p.name.clone(), p.cmdline.clone()))
+        else {
+            return;
+        };
+
+        let result = match action {
+            ProcessAction::CopyPid => Self::copy_to_clipboard(&pid.to_string(), &format!("PID {}", pid)),
+            ProcessAction::CopyCmdline => {
+                let text = if cmdline.is_empty() { name.clone() } else { cmdline };
+                Self::copy_to_clipboard(&text, &format!("command line of {}", name))
+            }
+            ProcessAction::OpenCwd => Self::process_cwd(pid)
+                .ok_or_else(|| format!("Cannot read working directory of PID {}", pid))
+                .and_then(|cwd| Self::open_path(&cwd)),
+            ProcessAction::ShowInFiles => match Self::process_cwd(pid) {
+                Some(cwd) => {
+                    let msg = format!("Files panel: {}", cwd.display());
+                    self.files_focus = Some(cwd);
+                    self.panels.files = true;
+                    if self.exploded_panel.is_none() {
+                        self.focused_panel = Some(PanelType::Files);
+                    }
+                    Ok(msg)
+                }
+                None => Err(format!("Cannot read working directory of PID {}", pid)),
+            },
+            ProcessAction::Terminate => {
+                self.request_signal(SignalType::Term);
+                return;
+            }
+            ProcessAction::Kill => {
+                self.request_signal(SignalType::Kill);
+                return;
+            }
+            ProcessAction::NiceUp => Self::renice(pid, 1),
+            ProcessAction::NiceDown => Self::renice(pid, -1),
+        };
+
+        self.signal_result = Some(match result {
+            Ok(msg) => (true, msg, Instant::now()),
+            Err(msg) => (false, msg, Instant::now()),
+        });
+    }
+
+    fn copy_to_clipboard(text: &str, what: &str) -> Result<String, String> {
+        crate::clipboard::copy(text).map(|method| format!("Copied {} ({})", what, method))
+    }
+
+    /// Working directory of a process
+    #[cfg(target_os = "linux")]
+    pub fn process_cwd(pid: u32) -> Option<std::path::PathBuf> {
+        std::fs::read_link(format!("/proc/{}/cwd", pid)).ok()
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn process_cwd(_pid: u32) -> Option<std::path::PathBuf> {
+        None
+    }
+
+    /// Open a directory with the desktop file manager
+    fn open_path(path: &std::path::Path) -> Result<String, String> {
+        use std::process::{Command, Stdio};
+
+        let opener = if cfg!(target_os = "macos") { "open" } else { "xdg-open" };
+        Command::new(opener)
+            .arg(path)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map(|_| format!("Opened {}", path.display()))
+            .map_err(|e| format!("Failed to run {} for {}: {}", opener, path.display(), e))
+    }
+
+    /// Change a process's nice value by `delta` (raising priority needs privileges)
+    #[cfg(unix)]
+    fn renice(pid: u32, delta: i32) -> Result<String, String> {
+        use std::process::Command;
+
+        let output = Command::new("ps")
+            .args(["-o", "nice=", "-p", &pid.to_string()])
+            .output()
+            .map_err(|e| format!("Failed to execute ps: {}", e))?;
+        let current: i32 = String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse()
+            .map_err(|_| format!("Cannot read nice value of PID {}", pid))?;
+        let nice = (current + delta).clamp(-20, 19);
+
+        let output = Command::new("renice")
+            .args([&nice.to_string(), "-p", &pid.to_string()])
+            .output()
+            .map_err(|e| format!("Failed to execute renice: {}", e))?;
+        if output.status.success() {
+            Ok(format!("PID {} nice {} -> {}", pid, current, nice))
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(format!("Failed to renice {}: {}", pid, stderr.trim()))
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn renice(_pid: u32, _delta: i32) -> Result<String, String> {
+        Err("Renice not supported on this platform".to_string())
+    }
+
+    /// Re-run the unified search for the current query
+    pub fn update_search(&mut self) {
+        use crate::search::{SearchResults, SearchTarget};
+
+        let mut results = SearchResults::new(&self.search_query);
+        for p in self.process.processes().values() {
+            let label = format!("{} ({})", p.name, p.pid);
+            results.offer(SearchTarget::Process(p.pid), label, &[&p.name, &p.cmdline]);
+        }
+        for conn in self.connection_analyzer.connections() {
+            if conn.remote_ip.is_unspecified() {
+                continue;
+            }
+            let remote = conn.remote_addr();
+            let host = self.connection_analyzer.get_hostname(conn.remote_ip).unwrap_or("");
+            let service = self.connection_analyzer.service_name(conn).unwrap_or("");
+            let label = if host.is_empty() { remote.clone() } else { format!("{} ({})", remote, host) };
+            results.offer(SearchTarget::Connection(remote.clone()), label, &[&remote, host, service]);
+        }
+        for (_, _, _, _, path) in self.treemap_analyzer.top_files_filtered(usize::MAX) {
+            results.offer(SearchTarget::File(path.clone()), path.clone(), &[&path]);
+        }
+
+        self.search_hits = results.into_hits();
+        self.search_selected = 0;
+    }
+
+    /// Jump to the selected search hit and highlight matches in its panel
+    pub fn jump_to_search_hit(&mut self) {
+        use crate::search::SearchTarget;
+
+        let Some(hit) = self.search_hits.get(self.search_selected).cloned() else {
+            return;
+        };
+        self.search_highlight = Some(self.search_query.to_lowercase());
+
+        let panel = match hit.target {
+            SearchTarget::Process(pid) => {
+                // The process filter could hide the hit
+                self.filter.clear();
+                if let Some(idx) = self.sorted_processes().iter().position(|p| p.pid == pid) {
+                    self.process_selected = idx;
+                }
+                self.panels.process = true;
+                Some(PanelType::Process)
+            }
+            // Connections share the bottom row with the process table
+            SearchTarget::Connection(_) => {
+                self.panels.process = true;
+                None
+            }
+            SearchTarget::File(_) => {
+                self.files_focus = None;
+                self.panels.files = true;
+                Some(PanelType::Files)
+            }
+        };
+        if self.exploded_panel.is_some() {
+            self.exploded_panel = panel;
+        } else if panel.is_some() {
+            self.focused_panel = panel;
+        }
+    }
+
+    /// Check if a row matches the highlighted search
+    pub fn is_search_match(&self, fields: &[&str]) -> bool {
+        self.search_highlight.as_ref().is_some_and(|q| crate::search::matches(q, fields))
+    }
+
     /// Clear old signal results (after 3 seconds)
     pub fn clear_old_signal_result(&mut self) {
         if let Some((_, _, timestamp)) = &self.signal_result {
@@ -543,6 +543,87 @@ mod tests {
         assert!(app.pending_signal.is_none());
     }
 
+    #[test]
+    fn test_context_menu_open_and_close() {
+        let mut app = App::new_mock();
+        app.handle_key(KeyCode::Char('m'), KeyModifiers::NONE);
+        assert!(app.show_context_menu);
+
+        // Unbound keys keep the menu open
+        app.handle_key(KeyCode::Char('z'), KeyModifiers::NONE);
+        assert!(app.show_context_menu);
+
+        let quit = app.handle_key(KeyCode::Esc, KeyModifiers::NONE);
+        assert!(!quit);
+        assert!(!app.show_context_menu);
+    }
+
+    #[test]
+    fn test_context_menu_action_closes_menu() {
+        let mut app = App::new_mock();
+        app.show_context_menu = true;
+        app.handle_key(KeyCode::Char('x'), KeyModifiers::NONE);
+        assert!(!app.show_context_menu);
+        // No process selected: nothing to act on
+        assert!(app.pending_signal.is_none());
+        assert!(app.signal_result.is_none());
+    }
+
+    #[test]
+    fn test_delete_clears_files_focus() {
+        let mut app = App::new_mock();
+        app.files_focus = Some(std::path::PathBuf::from("/srv/models"));
+        app.handle_key(KeyCode::Delete, KeyModifiers::NONE);
+        assert!(app.files_focus.is_none());
+    }
+
+    #[test]
+    fn test_search_mode_typing() {
+        let mut app = App::new_mock();
+        app.handle_key(KeyCode::Char('F'), KeyModifiers::NONE);
+        assert!(app.show_search);
+
+        app.handle_key(KeyCode::Char('a'), KeyModifiers::NONE);
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::NONE);
+        app.handle_key(KeyCode::Backspace, KeyModifiers::NONE);
+        assert_eq!(app.search_query, "a");
+
+        // Search keys don't reach global bindings
+        app.handle_key(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert_eq!(app.search_query, "aq");
+
+        app.search_highlight = Some("aq".to_string());
+        let quit = app.handle_key(KeyCode::Esc, KeyModifiers::NONE);
+        assert!(!quit);
+        assert!(!app.show_search);
+        assert!(app.search_highlight.is_none());
+    }
+
+    #[test]
+    fn test_search_jump_to_file_hit() {
+        use crate::search::{SearchHit, SearchTarget};
+
+        let mut app = App::new_mock();
+        app.panels.files = false;
+        app.show_search = true;
+        app.search_query = "Weights".to_string();
+        app.search_hits = vec![
+            SearchHit { target: SearchTarget::Connection("10.0.0.1:443".to_string()), label: "10.0.0.1:443".to_string() },
+            SearchHit { target: SearchTarget::File("/models/weights.bin".to_string()), label: "/models/weights.bin".to_string() },
+        ];
+
+        app.handle_key(KeyCode::Down, KeyModifiers::NONE);
+        app.handle_key(KeyCode::Down, KeyModifiers::NONE); // clamped at last hit
+        assert_eq!(app.search_selected, 1);
+
+        app.handle_key(KeyCode::Enter, KeyModifiers::NONE);
+        assert!(!app.show_search);
+        assert!(app.panels.files);
+        assert_eq!(app.focused_panel, Some(PanelType::Files));
+        assert!(app.is_search_match(&["/models/weights.bin"]));
+        assert!(!app.is_search_match(&["/models/tokenizer.json"]));
+    }
+
     #[test]
     fn test_process_navigation_keys() {
         let mut app = App::new_mock();
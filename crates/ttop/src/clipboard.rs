@@ -0,0 +1,99 @@
+//! Clipboard access for copy actions.
+//!
+//! Uses the platform clipboard tool when one is reachable (pbcopy on macOS,
+//! wl-copy/xclip/xsel under a graphical session). Otherwise the text is sent
+//! to the terminal as an OSC 52 escape, which most modern terminals (and
+//! tmux with `set-clipboard on`) forward to the local clipboard - this is
+//! what makes copying work over SSH.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Standard base64 with padding (OSC 52 payload encoding)
+fn base64(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], chunk.get(1).copied().unwrap_or(0), chunk.get(2).copied().unwrap_or(0)];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// OSC 52 "set clipboard" escape sequence for `text`
+pub fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+/// Clipboard tools to try, in order, for the current session
+fn tools() -> Vec<(&'static str, &'static [&'static str])> {
+    let mut tools: Vec<(&'static str, &'static [&'static str])> = Vec::new();
+    if cfg!(target_os = "macos") {
+        tools.push(("pbcopy", &[]));
+    }
+    if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+        tools.push(("wl-copy", &[]));
+    }
+    if std::env::var_os("DISPLAY").is_some() {
+        tools.push(("xclip", &["-selection", "clipboard"]));
+        tools.push(("xsel", &["--clipboard", "--input"]));
+    }
+    tools
+}
+
+fn copy_with(program: &str, args: &[&str], text: &str) -> bool {
+    let Ok(mut child) = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+    else {
+        return false;
+    };
+    let written =
+        child.stdin.take().is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+    child.wait().is_ok_and(|status| status.success()) && written
+}
+
+/// Copy text to the clipboard, returning the method used
+pub fn copy(text: &str) -> Result<&'static str, String> {
+    for (program, args) in tools() {
+        if copy_with(program, args, text) {
+            return Ok(program);
+        }
+    }
+    let mut stdout = std::io::stdout();
+    stdout
+        .write_all(osc52(text).as_bytes())
+        .and_then(|()| stdout.flush())
+        .map(|()| "OSC 52")
+        .map_err(|e| format!("Clipboard unavailable: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_padding() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_osc52_sequence() {
+        assert_eq!(osc52("1234"), "\x1b]52;c;MTIzNA==\x07");
+    }
+}
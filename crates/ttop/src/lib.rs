@@ -10,8 +10,10 @@
 //!
 //! - **app**: Main application state and logic
 //! - **analyzers**: Advanced analysis algorithms (swap thrashing, disk I/O latency, anomaly detection)
+//! - **clipboard**: Clipboard copy (platform tools or OSC 52)
 //! - **panels**: TUI panel rendering
 //! - **ring_buffer**: SIMD-optimized time-series data structure
+//! - **search**: Unified search across processes, connections and files
 //! - **state**: UI state management
 //! - **theme**: Color schemes and styling
 //! - **ui**: Main rendering logic
@@ -25,9 +27,11 @@
 
 pub mod analyzers;
 pub mod app;
+pub mod clipboard;
 pub mod display_rules;
 pub mod panels;
 pub mod ring_buffer;
+pub mod search;
 pub mod state;
 pub mod theme;
 pub mod ui;
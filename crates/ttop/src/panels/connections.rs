@@ -15,7 +15,17 @@ pub fn draw_connections(f: &mut Frame, app: &App, area: Rect) {
         return;
     }
 
-    // Sort: established first, then by remote port
+    // Unified search hits: remote address, hostname or service
+    let is_match = |conn: &crate::analyzers::Connection| {
+        !conn.remote_ip.is_unspecified()
+            && app.is_search_match(&[
+                &conn.remote_addr(),
+                app.connection_analyzer.get_hostname(conn.remote_ip).unwrap_or(""),
+                app.connection_analyzer.service_name(conn).unwrap_or(""),
+            ])
+    };
+
+    // Sort: search hits first, then established, then by remote port
     let mut sorted_conns: Vec<_> = conns.iter().collect();
     sorted_conns.sort_by(|a, b| {
         match (a.state == ConnState::Established, b.state == ConnState::Established) {
@@ -24,6 +34,7 @@ pub fn draw_connections(f: &mut Frame, app: &App, area: Rect) {
             _ => a.remote_port.cmp(&b.remote_port),
         }
     });
+    sorted_conns.sort_by_key(|c| !is_match(c));
 
     // Header - enhanced with SERVICE, AGE, and GEO columns
     let header = Row::new(vec![
@@ -90,8 +101,10 @@ pub fn draw_connections(f: &mut Frame, app: &App, area: Rect) {
                 format!("{}:{}", conn.remote_ip, conn.remote_port)
             };
 
-            // Color remote based on bandwidth
-            let remote_color = if is_hot {
+            // Color remote based on search hit, then bandwidth
+            let remote_color = if is_match(conn) {
+                trueno_viz::monitor::ratatui::style::Color::Yellow
+            } else if is_hot {
                 trueno_viz::monitor::ratatui::style::Color::LightRed
             } else {
                 trueno_viz::monitor::ratatui::style::Color::White
@@ -134,7 +147,9 @@ pub fn draw_treemap(f: &mut Frame, app: &App, area: Rect) {
     let scanning = app.treemap_analyzer.is_scanning();
 
     // Build title with mount legend
-    let title = if scanning {
+    let title = if let Some(dir) = &app.files_focus {
+        format!(" Files │ in {} │ Del:clear ", dir.display())
+    } else if scanning {
         " Files │ scanning... ".to_string()
     } else {
         " Files │ N:nvme D:hdd h:home ".to_string()
@@ -287,10 +302,30 @@ fn draw_files_unified(f: &mut Frame, app: &App, area: Rect) {
         return;
     }
 
-    let files = app.treemap_analyzer.top_files_filtered(area.height as usize);
+    // Limited to one directory (process context menu "show in Files")
+    let files = match &app.files_focus {
+        Some(dir) => app
+            .treemap_analyzer
+            .top_files_filtered(usize::MAX)
+            .into_iter()
+            .filter(|(_, _, _, _, path)| std::path::Path::new(path).starts_with(dir))
+            .take(area.height as usize)
+            .collect(),
+        None => {
+            let mut files = app.treemap_analyzer.top_files_filtered(usize::MAX);
+            // Unified search hits first, so a jump always shows them
+            files.sort_by_key(|(_, _, _, _, path)| !app.is_search_match(&[path]));
+            files.truncate(area.height as usize);
+            files
+        }
+    };
     if files.is_empty() {
+        let text = match &app.files_focus {
+            Some(dir) => format!("no large files under {}", dir.display()),
+            None => "...".to_string(),
+        };
         f.render_widget(
-            Paragraph::new("...").style(Style::default().fg(Color::Rgb(80, 80, 80))),
+            Paragraph::new(text).style(Style::default().fg(Color::Rgb(80, 80, 80))),
             area,
         );
         return;
@@ -302,7 +337,7 @@ fn draw_files_unified(f: &mut Frame, app: &App, area: Rect) {
         .map(|fe| (fe.path.to_string_lossy().to_string(), fe.entropy))
         .collect();
 
-    let max_size = files.first().map(|(_, s, _, _, _)| *s).unwrap_or(1);
+    let max_size = files.iter().map(|(_, s, _, _, _)| *s).max().unwrap_or(1);
 
     // Layout: [mount 1ch] [bar 5ch] [size 4ch] [space+filename - rest]
     let bar_width = 5usize;
@@ -368,6 +403,8 @@ fn draw_files_unified(f: &mut Frame, app: &App, area: Rect) {
             filename[..name_width.min(filename.len())].to_string()
         };
 
+        let name_color = if app.is_search_match(&[path]) { Color::Yellow } else { Color::Rgb(175, 180, 190) };
+
         // Layout: mount marker, split bar, size, filename
         let spans = vec![
             Span::styled(mount_char.to_string(), Style::default().fg(Color::Rgb(mr, mg, mb))),
@@ -376,7 +413,7 @@ fn draw_files_unified(f: &mut Frame, app: &App, area: Rect) {
                 .bg(Color::Rgb(r, g, b))),       // Top: category color
             Span::styled(&empty, Style::default().fg(Color::Rgb(30, 30, 35))),
             Span::styled(format!("{:>4}", size_str), Style::default().fg(Color::Rgb(150, 150, 120))),
-            Span::styled(format!(" {}", display_name), Style::default().fg(Color::Rgb(175, 180, 190))),
+            Span::styled(format!(" {}", display_name), Style::default().fg(name_color)),
         ];
 
         f.render_widget(Paragraph::new(Line::from(spans)), Rect { x: area.x, y, width: area.width, height: 1 });
@@ -105,6 +105,14 @@ fn cpu_draw_per_core_bars(
                 spans.push(Span::styled(format!(" {}", temp_str), Style::default().fg(Color::Cyan)));
             }
 
+            // Run queue wait: contention that utilization alone hides
+            if let Some(rq) = app.sched_latency.cpu(i) {
+                spans.push(Span::styled(
+                    format!(" w{:>3.0}ms", rq.wait_ms_per_sec),
+                    Style::default().fg(percent_color(rq.wait_percent())),
+                ));
+            }
+
             if let Some(s) = state {
                 spans.push(Span::styled(" │", Style::default().fg(Color::DarkGray)));
                 if s.user > 0.5 {
@@ -693,6 +701,64 @@ fn mem_draw_swap_section(f: &mut Frame, app: &App, inner: Rect, y: u16, is_explo
     }
 }
 
+/// Draw the memory detail rows (exploded mode): fragmentation of the worst zone
+/// for hugepage-sized allocations, the free-block distribution by order, hugepage
+/// pool and THP usage, and THP fallback / compaction stall rates.
+/// Returns the next y position after rendering.
+fn mem_draw_fragmentation(f: &mut Frame, app: &App, inner: Rect, y: u16) -> u16 {
+    use trueno_viz::monitor::ratatui::style::Color;
+    use trueno_viz::monitor::ratatui::text::{Line, Span};
+
+    let frag = &app.mem_frag;
+    // Leave room for PSI and a few top consumers
+    if !frag.is_available() || y + 6 > inner.y + inner.height {
+        return y;
+    }
+    let mut cur_y = y;
+    let huge = frag.hugepages();
+
+    if let Some((zone, index)) = frag.worst_zone() {
+        let color = if index < 0.3 { Color::Green } else if index < 0.7 { Color::Yellow } else { Color::Red };
+        // Free pages held at each order, as a mini histogram
+        let pages: Vec<u64> = zone.free_blocks.iter().enumerate().map(|(o, &n)| n << o).collect();
+        let peak = pages.iter().copied().max().unwrap_or(0).max(1);
+        let bars: String = pages.iter().map(|&p| {
+            const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+            if p == 0 { '·' } else { LEVELS[(p * 7 / peak) as usize] }
+        }).collect();
+
+        let line = Line::from(vec![
+            Span::styled("Frag ", Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("{:.2}", index), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+            Span::styled(format!(" order{} N{} {:<7}", huge.order(), zone.node, zone.zone), Style::default().fg(Color::DarkGray)),
+            Span::styled(bars, Style::default().fg(Color::Cyan)),
+            Span::styled(" 4K→4M", Style::default().fg(Color::DarkGray)),
+        ]);
+        f.render_widget(Paragraph::new(line), Rect { x: inner.x, y: cur_y, width: inner.width, height: 1 });
+        cur_y += 1;
+    }
+
+    let thp_gb = (huge.anon_thp_kb + huge.shmem_thp_kb) as f64 / (1024.0 * 1024.0);
+    let stall_color = if frag.compact_stall_rate > 10.0 { Color::Red } else if frag.compact_stall_rate > 0.0 { Color::Yellow } else { Color::DarkGray };
+    let fallback_color = if frag.thp_fallback_rate > 0.0 { Color::Yellow } else { Color::DarkGray };
+    let mut spans = vec![Span::styled("Huge ", Style::default().fg(Color::DarkGray))];
+    if huge.total > 0 {
+        spans.push(Span::styled(
+            format!("{}/{} × {}M ", huge.used(), huge.total, huge.size_kb / 1024),
+            Style::default().fg(percent_color(huge.used() as f64 / huge.total as f64 * 100.0)),
+        ));
+    }
+    spans.extend([
+        Span::styled(format!("THP {:.1}G ", thp_gb), Style::default().fg(Color::Magenta)),
+        Span::styled("│ stall ", Style::default().fg(Color::DarkGray)),
+        Span::styled(format!("{:.1}/s", frag.compact_stall_rate), Style::default().fg(stall_color)),
+        Span::styled(" fallback ", Style::default().fg(Color::DarkGray)),
+        Span::styled(format!("{:.1}/s", frag.thp_fallback_rate), Style::default().fg(fallback_color)),
+    ]);
+    f.render_widget(Paragraph::new(Line::from(spans)), Rect { x: inner.x, y: cur_y, width: inner.width, height: 1 });
+    cur_y + 1
+}
+
 /// Draw Memory panel - btop style, adaptive to available space
 /// Enhanced with swap thrashing detection (Denning 1968), ZRAM monitoring, and PSI
 pub fn draw_memory(f: &mut Frame, app: &App, area: Rect) {
@@ -754,6 +820,9 @@ pub fn draw_memory(f: &mut Frame, app: &App, area: Rect) {
     // Memory rows with sparklines
     let y = mem_draw_sparklines(f, inner, y, &rows, 2);
 
+    // Fragmentation and hugepages (exploded detail view)
+    let y = if is_exploded { mem_draw_fragmentation(f, app, inner, y) } else { y };
+
     // Swap/ZRAM, PSI, and top consumers
     mem_draw_swap_section(f, app, inner, y, is_exploded);
 }
@@ -10,7 +10,7 @@ use crate::theme::{self, borders, graph, percent_color};
 
 use super::cpu_memory::{btop_block, clamp_rect, truncate_str};
 
-/// Draw the latency gauge bar at the top of the disk panel.
+/// Draw the latency gauge bar, with sampled p50/p95/p99 and distribution, at the top of the disk panel.
 /// Returns the next y position after rendering.
 fn disk_draw_usage_bars(f: &mut Frame, app: &App, inner: Rect, y: u16) -> u16 {
     if let Some(device) = app.disk_io_analyzer.primary_device() {
@@ -24,17 +24,34 @@ fn disk_draw_usage_bars(f: &mut Frame, app: &App, inner: Rect, y: u16) -> u16 {
             Color::Red
         };
 
+        // Sampled percentiles and distribution, when the window has I/O
+        let percentiles = app.disk.latency(&device).and_then(|l| Some((l.percentiles()?, l.distribution())));
+        let tail = percentiles.map(|(p, dist)| {
+            let peak = dist.iter().copied().fold(0.0, f64::max);
+            let bars: String = dist.iter().map(|&f| {
+                const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+                if f <= 0.0 { ' ' } else { LEVELS[((f / peak) * 7.0).round() as usize] }
+            }).collect();
+            (format!("p50 {:.1} p95 {:.1} p99 {:.1} ", p.p50, p.p95, p.p99), bars)
+        });
+        let tail_width = tail.as_ref().map_or(0, |(text, bars)| text.chars().count() + bars.chars().count() + 1);
+
         let latency_pct = (latency / 100.0).min(1.0);
-        let bar_width = inner.width.saturating_sub(20) as usize;
+        let bar_width = (inner.width.saturating_sub(20) as usize).saturating_sub(tail_width);
         let filled = (latency_pct * bar_width as f64) as usize;
         let empty = bar_width.saturating_sub(filled);
 
-        let latency_line = Line::from(vec![
+        let mut spans = vec![
             Span::styled("Latency ", Style::default().fg(Color::DarkGray)),
             Span::styled(format!("{:>5.1}ms ", latency), Style::default().fg(latency_color)),
-            Span::styled("█".repeat(filled), Style::default().fg(latency_color)),
-            Span::styled("░".repeat(empty), Style::default().fg(Color::DarkGray)),
-        ]);
+        ];
+        if let Some((text, bars)) = tail {
+            spans.push(Span::styled(text, Style::default().fg(Color::DarkGray)));
+            spans.push(Span::styled(format!("{bars} "), Style::default().fg(Color::Cyan)));
+        }
+        spans.push(Span::styled("█".repeat(filled), Style::default().fg(latency_color)));
+        spans.push(Span::styled("░".repeat(empty), Style::default().fg(Color::DarkGray)));
+        let latency_line = Line::from(spans);
 
         f.render_widget(
             Paragraph::new(latency_line),
@@ -182,6 +199,39 @@ fn disk_draw_mount_table(
     cur_y
 }
 
+/// Draw each block device's scheduler, queue depth and read-ahead (exploded mode),
+/// with a tuning hint for obviously suboptimal settings.
+/// Returns the next y position after rendering.
+fn disk_draw_io_schedulers(f: &mut Frame, app: &App, inner: Rect, y: u16) -> u16 {
+    let devices = app.io_scheduler.devices();
+    // Keep the PSI row and a few process rows visible below
+    let available = (inner.y + inner.height).saturating_sub(y + 4) as usize;
+    if devices.is_empty() || available < 2 {
+        return y;
+    }
+
+    let mut cur_y = y;
+    for dev in devices.iter().take(available) {
+        let hint = dev.hints().into_iter().next();
+        let kind = if dev.is_nvme() { "nvme" } else if dev.rotational { "hdd" } else { "ssd" };
+        let mut spans = vec![
+            Span::styled(format!("{:<10}", truncate_str(&dev.device, 10)), Style::default().fg(Color::White)),
+            Span::styled(format!("{:<5}", kind), Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("{:<12}", dev.scheduler),
+                Style::default().fg(if hint.is_some() { Color::Yellow } else { Color::Cyan }),
+            ),
+            Span::styled(format!("nr {:>5}  ra {:>5}K", dev.nr_requests, dev.read_ahead_kb), Style::default().fg(Color::DarkGray)),
+        ];
+        if let Some(hint) = hint {
+            spans.push(Span::styled(format!("  ⚠ {}", hint), Style::default().fg(Color::Yellow)));
+        }
+        f.render_widget(Paragraph::new(Line::from(spans)), Rect { x: inner.x, y: cur_y, width: inner.width, height: 1 });
+        cur_y += 1;
+    }
+    cur_y
+}
+
 /// Draw the I/O PSI row and top active processes at the bottom of the disk panel.
 fn disk_draw_io_sparklines(f: &mut Frame, app: &App, inner: Rect, y: u16) {
     use crate::analyzers::PressureLevel;
@@ -312,6 +362,7 @@ pub fn draw_disk(f: &mut Frame, app: &App, area: Rect) {
 
     let y = disk_draw_usage_bars(f, app, inner, inner.y);
     let y = disk_draw_mount_table(f, app, inner, y, is_exploded, 1);
+    let y = if is_exploded { disk_draw_io_schedulers(f, app, inner, y) } else { y };
     disk_draw_io_sparklines(f, app, inner, y);
 }
 
@@ -3,10 +3,11 @@ use trueno_viz::monitor::ratatui::style::{Color, Style};
 use trueno_viz::monitor::ratatui::text::{Line, Span};
 use trueno_viz::monitor::ratatui::widgets::Paragraph;
 use trueno_viz::monitor::ratatui::Frame;
+use trueno_viz::monitor::collectors::PcieLink;
 use trueno_viz::monitor::widgets::MonitorSparkline;
 
 use crate::app::App;
-use crate::theme::{borders, percent_color, temp_color};
+use crate::theme::{borders, format_bytes_rate, percent_color, temp_color};
 
 use super::cpu_memory::{btop_block, truncate_str};
 
@@ -22,6 +23,7 @@ struct GpuDisplay {
     power_limit: u32,
     clock_mhz: u32,
     history: Option<Vec<f64>>,
+    pcie: Option<PcieLink>,
 }
 
 /// Draw a single GPU utilization row with bar and sparkline.
@@ -225,6 +227,37 @@ fn gpu_draw_temperature(
     y + 1
 }
 
+/// Draw the PCIe link row for a single GPU (exploded view).
+/// A link trained below its maximum width is shown as a warning.
+/// Returns the next y position.
+fn gpu_draw_pcie(f: &mut Frame, gpu: &GpuDisplay, inner: Rect, y: u16, label_col: u16) -> u16 {
+    let Some(link) = &gpu.pcie else {
+        return y;
+    };
+
+    let mut spans = vec![Span::styled(
+        format!("{:<width$}", "PCIe", width = label_col as usize),
+        Style::default().fg(Color::DarkGray),
+    )];
+    if link.is_degraded() {
+        spans.push(Span::styled(format!("\u{26A0} {}", link.summary()), Style::default().fg(Color::Red)));
+    } else {
+        spans.push(Span::styled(link.summary(), Style::default().fg(Color::White)));
+    }
+    if let (Some(tx), Some(rx)) = (link.tx_bytes_per_sec, link.rx_bytes_per_sec) {
+        spans.push(Span::styled(
+            format!("  \u{2191}{} \u{2193}{}", format_bytes_rate(tx), format_bytes_rate(rx)),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+
+    f.render_widget(
+        Paragraph::new(Line::from(spans)),
+        Rect { x: inner.x, y, width: inner.width, height: 1 },
+    );
+    y + 1
+}
+
 /// Draw the GPU processes section at the bottom of the GPU panel.
 fn gpu_draw_processes(f: &mut Frame, app: &App, inner: Rect, y: u16, gpu_count: usize) {
     if y >= inner.y + inner.height || !app.gpu_process_analyzer.is_available() {
@@ -327,6 +360,7 @@ fn collect_gpu_data(app: &App) -> Vec<GpuDisplay> {
                 temp: mock_gpu.temperature, power: mock_gpu.power_watts,
                 power_limit: mock_gpu.power_limit_watts, clock_mhz: mock_gpu.clock_mhz,
                 history: Some(mock_gpu.history.clone()),
+                pcie: None,
             });
         }
     }
@@ -346,7 +380,7 @@ fn collect_gpu_data(app: &App) -> Vec<GpuDisplay> {
                 vram_used: gpu.mem_used, vram_total: gpu.mem_total, vram_pct,
                 temp: gpu.temperature, power: gpu.power_mw / 1000,
                 power_limit: gpu.power_limit_mw / 1000, clock_mhz: gpu.gpu_clock_mhz,
-                history,
+                history, pcie: gpu.pcie_link.clone(),
             });
         }
     }
@@ -361,13 +395,18 @@ fn collect_gpu_data(app: &App) -> Vec<GpuDisplay> {
                 v.extend_from_slice(b);
                 v
             });
+            let pcie = app.pcie.link(&gpu.pci_address).map(|l| PcieLink {
+                tx_bytes_per_sec: Some(gpu.pcie_tx_kbps as f64 * 1024.0),
+                rx_bytes_per_sec: Some(gpu.pcie_rx_kbps as f64 * 1024.0),
+                ..l.clone()
+            });
             gpus.push(GpuDisplay {
                 name: gpu.name.clone(), gpu_util: gpu.gpu_util,
                 vram_used: gpu.vram_used, vram_total: gpu.vram_total, vram_pct,
                 temp: gpu.temperature, power: gpu.power_watts as u32,
                 power_limit: if gpu.power_cap_watts > 0.0 { gpu.power_cap_watts as u32 } else { 300 },
                 clock_mhz: gpu.gpu_clock_mhz as u32,
-                history,
+                history, pcie,
             });
         }
     }
@@ -385,7 +424,7 @@ fn collect_gpu_data(app: &App) -> Vec<GpuDisplay> {
                 name: gpu.name.clone(), gpu_util: gpu.gpu_util,
                 vram_used: 0, vram_total: 0, vram_pct: 0.0,
                 temp: 0.0, power: 0, power_limit: 0, clock_mhz: 0,
-                history,
+                history, pcie: None,
             });
         }
     }
@@ -407,7 +446,7 @@ fn collect_gpu_data(app: &App) -> Vec<GpuDisplay> {
                                     name: name.to_string(), gpu_util: 0.0,
                                     vram_used: 0, vram_total: 0, vram_pct: 0.0,
                                     temp: 0.0, power: 0, power_limit: 0, clock_mhz: 0,
-                                    history: None,
+                                    history: None, pcie: None,
                                 });
                             }
                         }
@@ -422,6 +461,7 @@ fn collect_gpu_data(app: &App) -> Vec<GpuDisplay> {
 
 pub fn draw_gpu(f: &mut Frame, app: &App, area: Rect) {
     let gpus = collect_gpu_data(app);
+    let is_exploded = area.width > 82 || area.height > 22;
 
     let title = if gpus.len() > 1 {
         format!(" GPU ({} devices) ", gpus.len())
@@ -470,6 +510,10 @@ pub fn draw_gpu(f: &mut Frame, app: &App, area: Rect) {
             y = gpu_draw_temperature(f, gpu, inner, y, label_col, bar_width, value_col, sparkline_col);
         }
 
+        if is_exploded && y < inner.y + gpu_area_height {
+            y = gpu_draw_pcie(f, gpu, inner, y, label_col);
+        }
+
         if gpus.len() > 1 && i < gpus.len() - 1 && y < inner.y + gpu_area_height {
             y += 1;
         }
@@ -31,7 +31,7 @@ pub fn draw_process(f: &mut Frame, app: &mut App, area: Rect) {
 
     // Header - compact or exploded based on available space
     let header_cells: Vec<&str> = if is_exploded {
-        vec!["PID", "USER", "S", "THR", "CPU%", "MEM%", "MEM", "COMMAND"]
+        vec!["PID", "USER", "S", "THR", "FD%", "CPU%", "MEM%", "MEM", "COMMAND"]
     } else {
         vec!["PID", "S", "C%", "M%", "COMMAND"]
     };
@@ -110,6 +110,13 @@ pub fn draw_process(f: &mut Frame, app: &mut App, area: Rect) {
             let cpu_color = percent_color(p.cpu_percent);
             let mem_color = percent_color(p.mem_percent);
 
+            // Unified search hits stand out from the rest of the table
+            let command_color = if app.is_search_match(&[&p.name, &p.cmdline]) {
+                trueno_viz::monitor::ratatui::style::Color::Yellow
+            } else {
+                trueno_viz::monitor::ratatui::style::Color::White
+            };
+
             // Tree prefix for name if tree view enabled
             let tree_prefix = tree_prefixes.get(&p.pid).cloned().unwrap_or_default();
 
@@ -131,12 +138,25 @@ pub fn draw_process(f: &mut Frame, app: &mut App, area: Rect) {
             };
 
             if is_exploded {
-                // Exploded mode: PID USER S THR CPU% MEM% MEM COMMAND
+                // Exploded mode: PID USER S THR FD% CPU% MEM% MEM COMMAND
                 let user = if p.user.is_empty() { "-" } else { &p.user };
                 let user_display: String = user.chars().take(8).collect();
                 let threads = p.threads;
                 let mem_str = theme::format_bytes(p.mem_bytes);
 
+                // FD and thread usage against ulimits: red when near saturation
+                let extra = app.process_extra.get(p.pid);
+                let thr_color = if extra.is_some_and(|e| e.thread_warning(threads)) {
+                    trueno_viz::monitor::ratatui::style::Color::Red
+                } else {
+                    trueno_viz::monitor::ratatui::style::Color::DarkGray
+                };
+                let (fd_str, fd_color) = match extra.filter(|e| e.fd_limit > 0) {
+                    Some(e) if e.fd_warning() => (format!("{:>4.0}⚠", e.fd_percent()), trueno_viz::monitor::ratatui::style::Color::Red),
+                    Some(e) => (format!("{:>4.0}", e.fd_percent()), percent_color(e.fd_percent())),
+                    None => (format!("{:>4}", "-"), trueno_viz::monitor::ratatui::style::Color::DarkGray),
+                };
+
                 Row::new(vec![
                     Span::styled(format!("{:>7}", p.pid), Style::default().fg(trueno_viz::monitor::ratatui::style::Color::DarkGray)),
                     Span::styled(format!("{:<8}", user_display), Style::default().fg(trueno_viz::monitor::ratatui::style::Color::Cyan)),
@@ -144,7 +164,8 @@ pub fn draw_process(f: &mut Frame, app: &mut App, area: Rect) {
                         p.state.as_char().to_string(),
                         Style::default().fg(state_color),
                     ),
-                    Span::styled(format!("{:>4}", threads), Style::default().fg(trueno_viz::monitor::ratatui::style::Color::DarkGray)),
+                    Span::styled(format!("{:>4}", threads), Style::default().fg(thr_color)),
+                    Span::styled(fd_str, Style::default().fg(fd_color)),
                     Span::styled(
                         format!("{:>6.1}", p.cpu_percent),
                         Style::default().fg(cpu_color),
@@ -156,7 +177,7 @@ pub fn draw_process(f: &mut Frame, app: &mut App, area: Rect) {
                     Span::styled(format!("{:>8}", mem_str), Style::default().fg(trueno_viz::monitor::ratatui::style::Color::DarkGray)),
                     Span::styled(
                         command,
-                        Style::default().fg(trueno_viz::monitor::ratatui::style::Color::White),
+                        Style::default().fg(command_color),
                     ),
                 ])
             } else {
@@ -177,7 +198,7 @@ pub fn draw_process(f: &mut Frame, app: &mut App, area: Rect) {
                     ),
                     Span::styled(
                         command,
-                        Style::default().fg(trueno_viz::monitor::ratatui::style::Color::White),
+                        Style::default().fg(command_color),
                     ),
                 ])
             }
@@ -194,6 +215,7 @@ pub fn draw_process(f: &mut Frame, app: &mut App, area: Rect) {
             Constraint::Length(12),  // USER (wider)
             Constraint::Length(3),   // S
             Constraint::Length(6),   // THR
+            Constraint::Length(6),   // FD%
             Constraint::Length(8),   // CPU%
             Constraint::Length(8),   // MEM%
             Constraint::Length(10),  // MEM
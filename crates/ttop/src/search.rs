@@ -0,0 +1,95 @@
+//! Unified search across processes, connections and files.
+//!
+//! One query is matched (case-insensitive substring) against process names
+//! and command lines, connection remote hosts/ports/services, and the paths
+//! found by the file scanner. Each hit knows where it lives so the UI can
+//! jump to it and highlight it in its panel.
+
+/// Maximum hits kept per search (the result list is a popup, not a pager)
+pub const MAX_HITS: usize = 100;
+
+/// Where a search hit lives
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchTarget {
+    /// Process by PID
+    Process(u32),
+    /// Connection by remote address ("ip:port")
+    Connection(String),
+    /// Scanned file by full path
+    File(String),
+}
+
+impl SearchTarget {
+    /// Short kind label for the result list
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SearchTarget::Process(_) => "PROC",
+            SearchTarget::Connection(_) => "CONN",
+            SearchTarget::File(_) => "FILE",
+        }
+    }
+}
+
+/// One search result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchHit {
+    pub target: SearchTarget,
+    /// Text shown in the result list
+    pub label: String,
+}
+
+/// Case-insensitive substring match of a lowercase query against any field
+pub fn matches(query_lower: &str, fields: &[&str]) -> bool {
+    !query_lower.is_empty() && fields.iter().any(|f| f.to_lowercase().contains(query_lower))
+}
+
+/// Collects hits in kind order (processes, connections, files), up to
+/// [`MAX_HITS`]
+#[derive(Debug, Default)]
+pub struct SearchResults {
+    query_lower: String,
+    hits: Vec<SearchHit>,
+}
+
+impl SearchResults {
+    pub fn new(query: &str) -> Self {
+        Self { query_lower: query.to_lowercase(), hits: Vec::new() }
+    }
+
+    /// Add a hit if any of `fields` matches the query
+    pub fn offer(&mut self, target: SearchTarget, label: String, fields: &[&str]) {
+        if self.hits.len() < MAX_HITS && matches(&self.query_lower, fields) {
+            self.hits.push(SearchHit { target, label });
+        }
+    }
+
+    pub fn into_hits(self) -> Vec<SearchHit> {
+        self.hits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_case_insensitive() {
+        assert!(matches("nginx", &["NGINX: worker", "-"]));
+        assert!(matches("443", &["foo", "10.0.0.1:443"]));
+        assert!(!matches("redis", &["postgres"]));
+        assert!(!matches("", &["anything"]));
+    }
+
+    #[test]
+    fn test_results_are_capped_and_typed() {
+        let mut results = SearchResults::new("Py");
+        for pid in 0..(MAX_HITS as u32 + 10) {
+            results.offer(SearchTarget::Process(pid), format!("python {}", pid), &["python3"]);
+        }
+        results.offer(SearchTarget::File("/x.py".into()), "/x.py".into(), &["/x.py"]);
+        let hits = results.into_hits();
+        assert_eq!(hits.len(), MAX_HITS);
+        assert_eq!(hits[0].target.kind(), "PROC");
+        assert_eq!(SearchTarget::Connection("1.2.3.4:22".into()).kind(), "CONN");
+    }
+}
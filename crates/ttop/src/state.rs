@@ -236,6 +236,76 @@ impl SignalType {
     }
 }
 
+/// Action from the process context menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessAction {
+    /// Copy the PID to the clipboard
+    CopyPid,
+    /// Copy the full command line to the clipboard
+    CopyCmdline,
+    /// Open the working directory in the desktop file manager
+    OpenCwd,
+    /// Show the working directory's files in the Files panel
+    ShowInFiles,
+    /// Send SIGTERM (asks for confirmation)
+    Terminate,
+    /// Send SIGKILL (asks for confirmation)
+    Kill,
+    /// Lower priority (nice +1)
+    NiceUp,
+    /// Raise priority (nice -1, usually needs root)
+    NiceDown,
+}
+
+impl ProcessAction {
+    /// Get key binding for this action
+    pub fn key(&self) -> char {
+        match self {
+            ProcessAction::CopyPid => 'y',
+            ProcessAction::CopyCmdline => 'Y',
+            ProcessAction::OpenCwd => 'o',
+            ProcessAction::ShowInFiles => 'f',
+            ProcessAction::Terminate => 'x',
+            ProcessAction::Kill => 'K',
+            ProcessAction::NiceUp => '+',
+            ProcessAction::NiceDown => '-',
+        }
+    }
+
+    /// Get description
+    pub fn description(&self) -> &'static str {
+        match self {
+            ProcessAction::CopyPid => "Copy PID",
+            ProcessAction::CopyCmdline => "Copy command line",
+            ProcessAction::OpenCwd => "Open working directory",
+            ProcessAction::ShowInFiles => "Show cwd in Files panel",
+            ProcessAction::Terminate => "Terminate (SIGTERM)",
+            ProcessAction::Kill => "Kill (SIGKILL)",
+            ProcessAction::NiceUp => "Renice +1 (lower priority)",
+            ProcessAction::NiceDown => "Renice -1 (higher priority)",
+        }
+    }
+
+    /// Look up an action by its key
+    pub fn from_key(key: char) -> Option<Self> {
+        Self::all().iter().copied().find(|a| a.key() == key)
+    }
+
+    /// All actions, in menu order
+    pub fn all() -> &'static [ProcessAction] {
+        &[
+            ProcessAction::CopyPid,
+            ProcessAction::CopyCmdline,
+            ProcessAction::OpenCwd,
+            ProcessAction::ShowInFiles,
+            ProcessAction::Terminate,
+            ProcessAction::Kill,
+            ProcessAction::NiceUp,
+            ProcessAction::NiceDown,
+        ]
+    }
+}
+
 /// Process sort column
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ProcessSortColumn {
@@ -485,6 +555,19 @@ mod tests {
         assert_eq!(all[7], SignalType::Cont);
     }
 
+    #[test]
+    fn test_process_action_keys_unique() {
+        let all = ProcessAction::all();
+        for action in all {
+            assert_eq!(ProcessAction::from_key(action.key()), Some(*action));
+        }
+        let mut keys: Vec<char> = all.iter().map(|a| a.key()).collect();
+        keys.sort_unstable();
+        keys.dedup();
+        assert_eq!(keys.len(), all.len());
+        assert_eq!(ProcessAction::from_key('z'), None);
+    }
+
     #[test]
     fn test_process_sort_column_default() {
         assert_eq!(ProcessSortColumn::default(), ProcessSortColumn::Cpu);
@@ -126,6 +126,11 @@ pub fn draw(f: &mut Frame, app: &mut App) {
         draw_filter_input(f, app, area);
     }
 
+    // Unified search overlay
+    if app.show_search {
+        draw_search(f, app, area);
+    }
+
     // Signal confirmation overlay
     if app.pending_signal.is_some() {
         draw_signal_confirm(f, app, area);
@@ -136,6 +141,11 @@ pub fn draw(f: &mut Frame, app: &mut App) {
         draw_signal_menu(f, app, area);
     }
 
+    // Process context menu overlay
+    if app.show_context_menu {
+        draw_context_menu(f, app, area);
+    }
+
     // Signal result notification
     if app.signal_result.is_some() {
         draw_signal_result(f, app, area);
@@ -424,7 +434,7 @@ fn draw_fps_overlay(f: &mut Frame, app: &App, area: Rect) {
 
 fn draw_help_overlay(f: &mut Frame, area: Rect) {
     let popup_width = 65;
-    let popup_height = 36;
+    let popup_height = 38;
 
     let popup_area = Rect {
         x: (area.width.saturating_sub(popup_width)) / 2,
@@ -473,6 +483,7 @@ fn draw_help_overlay(f: &mut Frame, area: Rect) {
         Line::from("    r                 Reverse sort order"),
         Line::from("    f, /              Filter processes"),
         Line::from("    Del               Clear filter"),
+        Line::from("    F                 Search processes, connections, files"),
         Line::from("    t                 Toggle tree view"),
         Line::from(""),
         Line::from(Span::styled(
@@ -481,6 +492,7 @@ fn draw_help_overlay(f: &mut Frame, area: Rect) {
         )),
         Line::from("    x                 Send SIGTERM (graceful)"),
         Line::from("    X                 Send SIGKILL (force)"),
+        Line::from("    m                 Process actions (copy, cwd, renice)"),
         Line::from(""),
         Line::from(Span::styled(
             "  Panels:",
@@ -541,6 +553,63 @@ fn draw_filter_input(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(input, popup_area);
 }
 
+/// Draw unified search input and result list
+fn draw_search(f: &mut Frame, app: &App, area: Rect) {
+    let popup_width: u16 = 70.min(area.width.saturating_sub(4));
+    let popup_height: u16 = 16.min(area.height.saturating_sub(2));
+
+    if popup_width < 20 || popup_height < 4 {
+        return; // Too small to render
+    }
+
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    f.render_widget(Clear, popup_area);
+
+    let mut content = vec![Line::from(vec![
+        Span::styled(" > ", Style::default().fg(Color::Cyan)),
+        Span::styled(format!("{}█", app.search_query), Style::default().fg(Color::White)),
+    ])];
+
+    // Keep the selected hit in view
+    let visible = popup_height.saturating_sub(3) as usize;
+    let skip = app.search_selected.saturating_sub(visible.saturating_sub(1));
+    for (i, hit) in app.search_hits.iter().enumerate().skip(skip).take(visible) {
+        let kind_color = match hit.target {
+            crate::search::SearchTarget::Process(_) => Color::Green,
+            crate::search::SearchTarget::Connection(_) => Color::Cyan,
+            crate::search::SearchTarget::File(_) => Color::Magenta,
+        };
+        let mut style = Style::default().fg(Color::White);
+        if i == app.search_selected {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+        content.push(Line::from(vec![
+            Span::styled(format!(" {} ", hit.target.kind()), Style::default().fg(kind_color)),
+            Span::styled(hit.label.clone(), style),
+        ]));
+    }
+    if app.search_hits.is_empty() && !app.search_query.is_empty() {
+        content.push(Line::from(Span::styled("  no matches", Style::default().fg(Color::DarkGray))));
+    }
+
+    let title = format!(" Search ({}) [↑↓ Enter=Jump Esc=Cancel] ", app.search_hits.len());
+    let search = Paragraph::new(content).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    f.render_widget(search, popup_area);
+}
+
 /// Draw signal confirmation dialog
 fn draw_signal_confirm(f: &mut Frame, app: &App, area: Rect) {
     use crate::state::SignalType;
@@ -665,6 +734,61 @@ fn draw_signal_menu(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(menu, popup_area);
 }
 
+/// Draw process context menu overlay
+fn draw_context_menu(f: &mut Frame, app: &App, area: Rect) {
+    use crate::state::ProcessAction;
+
+    let actions = ProcessAction::all();
+    let popup_width = 45;
+    let popup_height = actions.len() as u16 + 7;
+
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width.min(area.width),
+        height: popup_height.min(area.height),
+    };
+
+    f.render_widget(Clear, popup_area);
+
+    let selected = app.selected_process();
+    let proc_info = selected
+        .map(|(pid, name)| format!("{} ({})", name, pid))
+        .unwrap_or_else(|| "No process selected".to_string());
+
+    let mut content = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  Target: {}", proc_info),
+            Style::default().fg(Color::Cyan),
+        )),
+        Line::from(""),
+    ];
+    content.extend(actions.iter().map(|action| {
+        let color = match action {
+            ProcessAction::Kill => Color::Red,
+            ProcessAction::Terminate => Color::Yellow,
+            ProcessAction::NiceUp | ProcessAction::NiceDown => Color::Magenta,
+            _ => Color::Cyan,
+        };
+        Line::from(vec![
+            Span::styled(format!("  {}", action.key()), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+            Span::raw(format!("  {}", action.description())),
+        ])
+    }));
+    content.push(Line::from(""));
+    content.push(Line::from(Span::styled("  Esc to cancel", Style::default().fg(Color::DarkGray))));
+
+    let menu = Paragraph::new(content).block(
+        Block::default()
+            .title(" Process Actions ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    f.render_widget(menu, popup_area);
+}
+
 /// Draw signal result notification (bottom of screen)
 fn draw_signal_result(f: &mut Frame, app: &App, area: Rect) {
     if let Some((success, message, _timestamp)) = &app.signal_result {
@@ -1027,6 +1151,53 @@ mod ui_integration_tests {
         assert!(frame.contains("Signal") || frame.contains("TERM") || frame.contains("KILL") || frame.height() > 0);
     }
 
+    /// Test UI with process context menu
+    #[test]
+    fn test_ui_with_context_menu() {
+        let mut app = App::new_mock();
+        app.show_context_menu = true;
+        let backend = TestBackend::new(160, 50);
+        let mut terminal = Terminal::new(backend).expect("terminal");
+
+        terminal.draw(|f| {
+            draw(f, &mut app);
+        }).expect("draw with context menu");
+
+        let buffer = terminal.backend().buffer().clone();
+        let frame = buffer_to_frame(&buffer, 0);
+
+        assert!(frame.contains("Process Actions"));
+        assert!(frame.contains("Copy command line"));
+        assert!(frame.contains("Renice +1"));
+    }
+
+    /// Test UI with unified search results
+    #[test]
+    fn test_ui_with_search() {
+        use crate::search::{SearchHit, SearchTarget};
+
+        let mut app = App::new_mock();
+        app.show_search = true;
+        app.search_query = "ngi".to_string();
+        app.search_hits = vec![
+            SearchHit { target: SearchTarget::Process(42), label: "nginx (42)".to_string() },
+            SearchHit { target: SearchTarget::File("/srv/nginx.log".to_string()), label: "/srv/nginx.log".to_string() },
+        ];
+        let backend = TestBackend::new(160, 50);
+        let mut terminal = Terminal::new(backend).expect("terminal");
+
+        terminal.draw(|f| {
+            draw(f, &mut app);
+        }).expect("draw with search");
+
+        let buffer = terminal.backend().buffer().clone();
+        let frame = buffer_to_frame(&buffer, 0);
+
+        assert!(frame.contains("Search (2)"));
+        assert!(frame.contains("PROC nginx (42)"));
+        assert!(frame.contains("FILE /srv/nginx.log"));
+    }
+
     /// Test UI with filter input
     #[test]
     fn test_ui_with_filter_input() {
@@ -1326,6 +1497,11 @@ mod ui_integration_tests {
         assert!(header_exploded.contains("THR"),
             "Exploded mode should have THR column.\nExploded header: {}",
             header_exploded.trim());
+
+        // And FD% (descriptor usage against ulimit)
+        assert!(header_exploded.contains("FD%"),
+            "Exploded mode should have FD% column.\nExploded header: {}",
+            header_exploded.trim());
     }
 }
 
@@ -0,0 +1,52 @@
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+//! Batch Rendering Example
+//!
+//! Renders one loss curve per learning rate of a hyperparameter sweep with
+//! `render_batch`, saving each figure as PNG plus a CSV sidecar holding the
+//! plotted data, and reports progress as figures finish.
+//!
+//! Run with: `cargo run --example batch_render --features sidecar`
+//! (add `--features sidecar,parallel` to render on all cores)
+
+use trueno_viz::prompt::{parse_prompt, render_batch, BatchOptions, SidecarFormat};
+
+fn main() {
+    println!("Batch Rendering Example");
+    println!("=======================\n");
+
+    // One spec per learning rate: loss decays faster for larger rates
+    let rates = [0.001_f32, 0.003, 0.01, 0.03, 0.1];
+    let mut specs: Vec<_> = rates
+        .iter()
+        .map(|rate| {
+            let epochs: Vec<String> = (0..20).map(|e| e.to_string()).collect();
+            let losses: Vec<String> = (0..20)
+                .map(|e| format!("{:.4}", 2.0 * (-rate * 50.0 * e as f32).exp() + 0.1))
+                .collect();
+            let prompt = format!(
+                "line x=[{}] y=[{}] width=400 height=300 title=\"loss lr {rate}\"",
+                epochs.join(","),
+                losses.join(",")
+            );
+            parse_prompt(&prompt).expect("Failed to parse")
+        })
+        .collect();
+
+    // A failing spec is reported without stopping the sweep
+    specs.push(parse_prompt("heatmap matrix=[[1,2],[3,4]] width=0 height=300").expect("parse"));
+
+    let options = BatchOptions::new()
+        .output_dir("batch_output")
+        .sidecar(SidecarFormat::Csv)
+        .on_progress(|p| {
+            let status = if p.ok { "ok" } else { "failed" };
+            println!("  [{}/{}] spec #{} {status}", p.completed, p.total, p.index);
+        });
+
+    let report = render_batch(&specs, &options);
+
+    println!("\n{report}");
+    for path in report.paths.iter().flatten() {
+        println!("  Saved: {}", path.display());
+    }
+}
@@ -1,15 +1,35 @@
 //! trueno-monitor - TUI system and ML workload monitor.
 //!
 //! A btop-like terminal monitor with Sovereign AI Stack integration.
+//!
+//! Usage: `trueno-monitor [--profile <name>] [--list-profiles]`
 
+use trueno_viz::monitor::presets::all_profiles;
 use trueno_viz::monitor::{App, Config};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Load configuration
-    let config = Config::load_or_default(
+    let mut config = Config::load_or_default(
         dirs::config_dir().map(|p| p.join("trueno-monitor/config.yaml")).unwrap_or_default(),
     );
 
+    // Command line overrides the config file
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--list-profiles" {
+            for profile in all_profiles() {
+                println!("{:<10} {}", profile.name, profile.description);
+            }
+            return Ok(());
+        }
+        let name = match arg.strip_prefix("--profile=") {
+            Some(name) => name.to_string(),
+            None if arg == "--profile" => args.next().ok_or("--profile requires a name")?,
+            None => return Err(format!("unknown argument '{arg}'").into()),
+        };
+        config.apply_profile(&name)?;
+    }
+
     // Run the application
     let mut app = App::new(config);
     app.run()?;
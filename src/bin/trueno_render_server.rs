@@ -0,0 +1,56 @@
+//! trueno-render-server - HTTP render service for notebooks and web apps.
+//!
+//! Accepts plot-spec JSON on `POST /render` and returns PNG, SVG or HTML,
+//! caching rendered figures. See `trueno_viz::server` for the routes.
+//!
+//! Usage: `trueno-render-server [--addr <host:port>] [--cache <figures>]
+//! [--max-pixels <pixels>] [--max-connections <count>]
+//! [--max-datasets <count>] [--max-dataset-bytes <bytes>]`
+
+use trueno_viz::server::{
+    RenderServer, DEFAULT_CACHE_CAPACITY, DEFAULT_MAX_CONNECTIONS, DEFAULT_MAX_DATASETS,
+    DEFAULT_MAX_DATASET_BYTES, DEFAULT_MAX_PIXELS,
+};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut addr = "127.0.0.1:8787".to_string();
+    let mut cache = DEFAULT_CACHE_CAPACITY;
+    let mut max_pixels = DEFAULT_MAX_PIXELS;
+    let mut max_connections = DEFAULT_MAX_CONNECTIONS;
+    let mut max_datasets = DEFAULT_MAX_DATASETS;
+    let mut max_dataset_bytes = DEFAULT_MAX_DATASET_BYTES;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--addr" => addr = args.next().ok_or("--addr requires host:port")?,
+            "--cache" => cache = args.next().ok_or("--cache requires a count")?.parse()?,
+            "--max-pixels" => {
+                max_pixels = args.next().ok_or("--max-pixels requires a count")?.parse()?;
+            }
+            "--max-connections" => {
+                max_connections =
+                    args.next().ok_or("--max-connections requires a count")?.parse()?;
+            }
+            "--max-datasets" => {
+                max_datasets = args.next().ok_or("--max-datasets requires a count")?.parse()?;
+            }
+            "--max-dataset-bytes" => {
+                max_dataset_bytes =
+                    args.next().ok_or("--max-dataset-bytes requires a size")?.parse()?;
+            }
+            _ => return Err(format!("unknown argument '{arg}'").into()),
+        }
+    }
+
+    eprintln!("trueno-render-server listening on http://{addr} (cache {cache} figures)");
+    RenderServer::new()
+        .cache_capacity(cache)
+        .max_pixels(max_pixels)
+        .max_connections(max_connections)
+        .max_datasets(max_datasets)
+        .max_dataset_bytes(max_dataset_bytes)
+        .serve(&addr)?;
+
+    Ok(())
+}
@@ -7,9 +7,14 @@
 //!
 //! - Sharma, G., Wu, W., & Dalal, E. N. (2005). "The CIEDE2000 Color-Difference Formula."
 //!   *Color Research & Application*, 30(1), 21-30.
+//! - Ottosson, B. (2020). "A perceptual color space for image processing."
+//!   <https://bottosson.github.io/posts/oklab/>
+//! - Machado, G. M., Oliveira, M. M., & Fernandes, L. A. F. (2009). "A Physiologically-based
+//!   Model for Simulation of Color Vision Deficiency." *IEEE TVCG*, 15(6), 1291-1298.
 
 /// RGBA color with 8-bit components.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Rgba {
     /// Red component (0-255).
@@ -81,6 +86,26 @@ impl Rgba {
     }
 }
 
+/// Categorical palette (Tableau 10) for distinguishing discrete series.
+pub const CATEGORY10: [Rgba; 10] = [
+    Rgba::rgb(31, 119, 180),
+    Rgba::rgb(255, 127, 14),
+    Rgba::rgb(44, 160, 44),
+    Rgba::rgb(214, 39, 40),
+    Rgba::rgb(148, 103, 189),
+    Rgba::rgb(140, 86, 75),
+    Rgba::rgb(227, 119, 194),
+    Rgba::rgb(127, 127, 127),
+    Rgba::rgb(188, 189, 34),
+    Rgba::rgb(23, 190, 207),
+];
+
+/// Pick a categorical color by index, cycling through [`CATEGORY10`].
+#[must_use]
+pub const fn categorical(index: usize) -> Rgba {
+    CATEGORY10[index % CATEGORY10.len()]
+}
+
 /// HSLA color with floating-point components.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct Hsla {
@@ -152,6 +177,171 @@ impl From<Hsla> for Rgba {
     }
 }
 
+/// Color in the OKLab perceptual color space.
+///
+/// Euclidean distances in OKLab approximate perceived color differences, and
+/// straight-line interpolation keeps hue and lightness changes even, which
+/// makes it the space of choice for building colormaps.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Oklab {
+    /// Perceived lightness (0.0 black - 1.0 white).
+    pub l: f32,
+    /// Green (negative) to red (positive) axis.
+    pub a: f32,
+    /// Blue (negative) to yellow (positive) axis.
+    pub b: f32,
+}
+
+impl Oklab {
+    /// Create a new OKLab color.
+    #[must_use]
+    pub const fn new(l: f32, a: f32, b: f32) -> Self {
+        Self { l, a, b }
+    }
+
+    /// Convert from sRGB (alpha is ignored).
+    #[must_use]
+    pub fn from_rgba(color: Rgba) -> Self {
+        let [r, g, b] = [color.r, color.g, color.b].map(|c| srgb_to_linear(f32::from(c) / 255.0));
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        Self::new(
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        )
+    }
+
+    /// Linear sRGB components, possibly outside 0.0-1.0 for out-of-gamut colors.
+    fn to_linear_rgb(self) -> [f32; 3] {
+        let l = (self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b).powi(3);
+        let m = (self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b).powi(3);
+        let s = (self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b).powi(3);
+        [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ]
+    }
+
+    /// Convert to opaque sRGB, clipping out-of-gamut components.
+    #[must_use]
+    pub fn to_rgba(self) -> Rgba {
+        let [r, g, b] = self.to_linear_rgb().map(linear_to_srgb_u8);
+        Rgba::rgb(r, g, b)
+    }
+
+    /// Whether the color is representable in sRGB without clipping.
+    #[must_use]
+    pub fn in_gamut(self) -> bool {
+        self.to_linear_rgb().iter().all(|c| (-1e-4..=1.0 + 1e-4).contains(c))
+    }
+
+    /// Perceptual difference (Euclidean distance, "ΔE OK").
+    #[must_use]
+    pub fn distance(self, other: Self) -> f32 {
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+            .sqrt()
+    }
+
+    /// Linear interpolation between two colors.
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        Self::new(
+            self.l + (other.l - self.l) * t,
+            self.a + (other.a - self.a) * t,
+            self.b + (other.b - self.b) * t,
+        )
+    }
+}
+
+impl From<Rgba> for Oklab {
+    fn from(color: Rgba) -> Self {
+        Self::from_rgba(color)
+    }
+}
+
+impl From<Oklab> for Rgba {
+    fn from(color: Oklab) -> Self {
+        color.to_rgba()
+    }
+}
+
+/// Dichromatic color vision deficiencies for simulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorVisionDeficiency {
+    /// Missing long-wavelength (red) cones.
+    Protanopia,
+    /// Missing medium-wavelength (green) cones; the most common form.
+    Deuteranopia,
+    /// Missing short-wavelength (blue) cones.
+    Tritanopia,
+}
+
+impl ColorVisionDeficiency {
+    /// All simulated deficiencies.
+    pub const ALL: [Self; 3] = [Self::Protanopia, Self::Deuteranopia, Self::Tritanopia];
+
+    /// Lowercase name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Protanopia => "protanopia",
+            Self::Deuteranopia => "deuteranopia",
+            Self::Tritanopia => "tritanopia",
+        }
+    }
+
+    /// Machado et al. (2009) simulation matrix at full severity (linear RGB).
+    const fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            Self::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            Self::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            Self::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        }
+    }
+
+    /// How `color` appears to someone with this deficiency (alpha preserved).
+    #[must_use]
+    pub fn simulate(self, color: Rgba) -> Rgba {
+        let rgb = [color.r, color.g, color.b].map(|c| srgb_to_linear(f32::from(c) / 255.0));
+        let [r, g, b] = self
+            .matrix()
+            .map(|row| linear_to_srgb_u8(row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]));
+        Rgba::new(r, g, b, color.a)
+    }
+}
+
+/// sRGB transfer function inverse (0.0-1.0 in, linear out).
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Linear component to an 8-bit sRGB channel, clipping to the gamut.
+fn linear_to_srgb_u8(c: f32) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let v = if c <= 0.003_130_8 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 };
+    (v * 255.0).round() as u8
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -298,4 +488,57 @@ mod tests {
         let above = black.lerp(white, 1.5);
         assert_eq!(above, white);
     }
+
+    #[test]
+    fn test_categorical_cycles() {
+        assert_eq!(categorical(0), CATEGORY10[0]);
+        assert_eq!(categorical(10), CATEGORY10[0]);
+        assert_eq!(categorical(13), CATEGORY10[3]);
+    }
+
+    #[test]
+    fn test_oklab_reference_values() {
+        let white = Oklab::from_rgba(Rgba::WHITE);
+        assert!((white.l - 1.0).abs() < 1e-3 && white.a.abs() < 1e-3 && white.b.abs() < 1e-3);
+        // Reference: sRGB red = OKLab (0.627955, 0.224863, 0.125846)
+        let red = Oklab::from(Rgba::RED);
+        assert!((red.l - 0.627_955).abs() < 1e-3);
+        assert!((red.a - 0.224_863).abs() < 1e-3);
+        assert!((red.b - 0.125_846).abs() < 1e-3);
+        assert!(red.in_gamut());
+        assert!(!Oklab::new(0.9, 0.3, 0.0).in_gamut());
+    }
+
+    #[test]
+    fn test_oklab_round_trip_and_distance() {
+        for color in CATEGORY10 {
+            assert_eq!(Rgba::from(Oklab::from(color)), color);
+        }
+        let black = Oklab::from(Rgba::BLACK);
+        let white = Oklab::from(Rgba::WHITE);
+        assert!((black.distance(white) - 1.0).abs() < 1e-3);
+        let mid = black.lerp(white, 0.5);
+        assert!((mid.l - 0.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_cvd_simulation() {
+        // The red-green axis collapses for red-green deficiencies only
+        let (red, green) = (Rgba::rgb(200, 60, 50), Rgba::rgb(80, 160, 60));
+        let red_green = |cvd: Option<ColorVisionDeficiency>| {
+            let seen = |c| Oklab::from(cvd.map_or(c, |cvd| cvd.simulate(c)));
+            (seen(red).a - seen(green).a).abs()
+        };
+        let normal = red_green(None);
+        assert!(red_green(Some(ColorVisionDeficiency::Deuteranopia)) < normal * 0.25);
+        assert!(red_green(Some(ColorVisionDeficiency::Protanopia)) < normal * 0.25);
+        assert!(red_green(Some(ColorVisionDeficiency::Tritanopia)) > normal * 0.5);
+
+        // Greys are unaffected and alpha is preserved
+        for cvd in ColorVisionDeficiency::ALL {
+            let grey = Rgba::new(128, 128, 128, 90);
+            let seen = cvd.simulate(grey);
+            assert!(seen.r.abs_diff(128) <= 1 && seen.g.abs_diff(128) <= 1 && seen.a == 90);
+        }
+    }
 }
@@ -50,6 +50,10 @@ pub enum Error {
     /// Rendering error.
     #[error("Rendering error: {0}")]
     Rendering(String),
+
+    /// Invalid style file or environment override.
+    #[error("Style error: {0}")]
+    Style(String),
 }
 
 #[cfg(test)]
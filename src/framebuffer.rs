@@ -33,6 +33,39 @@ pub struct Framebuffer {
     stride: usize,
 }
 
+/// How a layer's colors combine with what is already drawn beneath it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    /// Source over destination.
+    #[default]
+    Normal,
+    /// Product of the colors; darkens, white is neutral.
+    Multiply,
+    /// Inverse product of the inverses; lightens, black is neutral.
+    Screen,
+    /// Sum of the colors, clamped (additive light).
+    Add,
+    /// Per-channel minimum.
+    Darken,
+    /// Per-channel maximum.
+    Lighten,
+}
+
+impl BlendMode {
+    /// Combine one channel of `src` over `dst` (both 0-1).
+    #[must_use]
+    pub fn mix(self, src: f32, dst: f32) -> f32 {
+        match self {
+            Self::Normal => src,
+            Self::Multiply => src * dst,
+            Self::Screen => 1.0 - (1.0 - src) * (1.0 - dst),
+            Self::Add => (src + dst).min(1.0),
+            Self::Darken => src.min(dst),
+            Self::Lighten => src.max(dst),
+        }
+    }
+}
+
 impl Framebuffer {
     /// Create a new framebuffer with the given dimensions.
     ///
@@ -309,6 +342,68 @@ impl Framebuffer {
         Ok(())
     }
 
+    /// Composite a same-sized layer over this framebuffer.
+    ///
+    /// Each layer pixel's own alpha is multiplied by `opacity`, and its color
+    /// is combined with the pixel beneath using `mode` before the usual
+    /// "over" operation. Rendering a group of marks into a transparent layer
+    /// and compositing it once gives group opacity: overlapping marks in the
+    /// layer do not darken each other.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the framebuffers have different dimensions.
+    pub fn composite(&mut self, layer: &Framebuffer, opacity: f32, mode: BlendMode) -> Result<()> {
+        if self.width != layer.width || self.height != layer.height {
+            return Err(Error::InvalidDimensions { width: layer.width, height: layer.height });
+        }
+        let opacity = opacity.clamp(0.0, 1.0);
+        let row_pixels = (self.width as usize) * 4;
+
+        for y in 0..self.height as usize {
+            let start = y * self.stride;
+            let src_row = &layer.pixels[start..start + row_pixels];
+            let dst_row = &mut self.pixels[start..start + row_pixels];
+            for (src, dst) in src_row.chunks_exact(4).zip(dst_row.chunks_exact_mut(4)) {
+                let src_a = f32::from(src[3]) / 255.0 * opacity;
+                if src_a <= 0.0 {
+                    continue;
+                }
+                let dst_a = f32::from(dst[3]) / 255.0;
+                let out_a = src_a + dst_a * (1.0 - src_a);
+                for c in 0..3 {
+                    let s = f32::from(src[c]) / 255.0;
+                    let d = f32::from(dst[c]) / 255.0;
+                    // Blend modes only apply where there is something beneath
+                    let mixed = mode.mix(s, d) * dst_a + s * (1.0 - dst_a);
+                    let out = (mixed * src_a + d * dst_a * (1.0 - src_a)) / out_a;
+                    dst[c] = (out * 255.0).round().clamp(0.0, 255.0) as u8;
+                }
+                dst[3] = (out_a * 255.0).round() as u8;
+            }
+        }
+        Ok(())
+    }
+
+    /// Copy another framebuffer into this one with its top-left corner at `(x, y)`.
+    ///
+    /// Pixels falling outside this framebuffer are clipped.
+    pub fn blit(&mut self, src: &Framebuffer, x: u32, y: u32) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let copy_w = src.width.min(self.width - x) as usize * 4;
+        let copy_h = src.height.min(self.height - y);
+        let dst_x = x as usize * 4;
+
+        for row in 0..copy_h {
+            let src_start = (row as usize) * src.stride;
+            let dst_start = ((y + row) as usize) * self.stride + dst_x;
+            self.pixels[dst_start..dst_start + copy_w]
+                .copy_from_slice(&src.pixels[src_start..src_start + copy_w]);
+        }
+    }
+
     /// Apply a brightness adjustment using SIMD-accelerated operations.
     ///
     /// `factor` of 1.0 is no change, < 1.0 darkens, > 1.0 brightens.
@@ -622,6 +717,22 @@ mod tests {
         assert_eq!(fb.get_pixel(5, 5), Some(Rgba::WHITE));
     }
 
+    #[test]
+    fn test_blit_clips() {
+        let mut dst = Framebuffer::new(10, 10).expect("framebuffer creation should succeed");
+        let mut src = Framebuffer::new(4, 4).expect("framebuffer creation should succeed");
+        dst.clear(Rgba::WHITE);
+        src.clear(Rgba::RED);
+
+        dst.blit(&src, 8, 8);
+        assert_eq!(dst.get_pixel(7, 7), Some(Rgba::WHITE));
+        assert_eq!(dst.get_pixel(8, 8), Some(Rgba::RED));
+        assert_eq!(dst.get_pixel(9, 9), Some(Rgba::RED));
+
+        // Entirely out of bounds is a no-op.
+        dst.blit(&src, 20, 0);
+    }
+
     #[test]
     fn test_blend_over_dimension_mismatch() {
         let mut fb1 = Framebuffer::new(100, 100).expect("framebuffer creation should succeed");
@@ -672,4 +783,38 @@ mod tests {
         fb.set_pixel(10, 5, Rgba::RED);
         fb.set_pixel(5, 10, Rgba::RED);
     }
+
+    #[test]
+    fn test_composite_group_opacity() {
+        let mut base = Framebuffer::new(4, 1).expect("framebuffer creation should succeed");
+        base.clear(Rgba::WHITE);
+        let mut layer = Framebuffer::new(4, 1).expect("framebuffer creation should succeed");
+        layer.set_pixel(0, 0, Rgba::BLACK);
+
+        base.composite(&layer, 0.5, BlendMode::Normal).expect("same size");
+        assert_eq!(base.get_pixel(0, 0), Some(Rgba::new(128, 128, 128, 255)));
+        // Transparent layer pixels leave the base untouched
+        assert_eq!(base.get_pixel(1, 0), Some(Rgba::WHITE));
+
+        let small = Framebuffer::new(2, 1).expect("framebuffer creation should succeed");
+        assert!(base.composite(&small, 1.0, BlendMode::Normal).is_err());
+    }
+
+    #[test]
+    fn test_composite_blend_modes() {
+        let over = |mode: BlendMode| {
+            let mut base = Framebuffer::new(1, 1).expect("framebuffer creation should succeed");
+            base.clear(Rgba::rgb(200, 100, 0));
+            let mut layer = Framebuffer::new(1, 1).expect("framebuffer creation should succeed");
+            layer.clear(Rgba::rgb(100, 200, 255));
+            base.composite(&layer, 1.0, mode).expect("same size");
+            base.get_pixel(0, 0).expect("in bounds")
+        };
+        assert_eq!(over(BlendMode::Normal), Rgba::rgb(100, 200, 255));
+        assert_eq!(over(BlendMode::Darken), Rgba::rgb(100, 100, 0));
+        assert_eq!(over(BlendMode::Lighten), Rgba::rgb(200, 200, 255));
+        assert_eq!(over(BlendMode::Add), Rgba::rgb(255, 255, 255));
+        assert_eq!(over(BlendMode::Multiply), Rgba::rgb(78, 78, 0));
+        assert_eq!(over(BlendMode::Screen), Rgba::rgb(222, 222, 255));
+    }
 }
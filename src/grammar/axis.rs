@@ -0,0 +1,177 @@
+//! Secondary axes that mirror a primary axis through a unit transformation.
+//!
+//! A secondary axis does not have its own data range: it relabels the
+//! primary axis in another unit (°C → °F, bytes → bits, seconds → minutes),
+//! like `sec_axis()` in ggplot2. Ticks are chosen at round values in the
+//! transformed unit and placed at the primary value that maps to them.
+
+use crate::scale::{format_tick, nice_ticks};
+
+/// A monotonic mapping from primary to secondary units.
+#[derive(Debug, Clone, Copy)]
+pub enum AxisTransform {
+    /// `secondary = primary * scale + offset`.
+    Linear {
+        /// Multiplier.
+        scale: f32,
+        /// Additive offset.
+        offset: f32,
+    },
+    /// Any monotonic function; inverted numerically for tick placement.
+    Custom(fn(f32) -> f32),
+}
+
+impl AxisTransform {
+    /// Map a primary value to secondary units.
+    #[must_use]
+    pub fn apply(&self, value: f32) -> f32 {
+        match *self {
+            Self::Linear { scale, offset } => value * scale + offset,
+            Self::Custom(f) => f(value),
+        }
+    }
+
+    /// Find the primary value in `domain` that maps to `target`.
+    ///
+    /// Returns `None` when `target` lies outside the transformed domain.
+    #[must_use]
+    pub fn invert(&self, target: f32, domain: (f32, f32)) -> Option<f32> {
+        let (a, b) = (self.apply(domain.0), self.apply(domain.1));
+        if target < a.min(b) || target > a.max(b) {
+            return None;
+        }
+        match *self {
+            Self::Linear { scale, offset } if scale != 0.0 => Some((target - offset) / scale),
+            _ => {
+                // Bisection on a monotonic function
+                let increasing = b >= a;
+                let (mut lo, mut hi) = domain;
+                for _ in 0..48 {
+                    let mid = (lo + hi) / 2.0;
+                    if (self.apply(mid) < target) == increasing {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                Some((lo + hi) / 2.0)
+            }
+        }
+    }
+}
+
+/// A secondary axis mirroring the primary one in another unit.
+#[derive(Debug, Clone)]
+pub struct SecondaryAxis {
+    /// Primary to secondary mapping.
+    pub transform: AxisTransform,
+    /// Axis title.
+    pub name: Option<String>,
+    /// Approximate number of ticks.
+    pub tick_count: usize,
+    /// Tick label formatter (None = compact decimal).
+    pub formatter: Option<fn(f32) -> String>,
+}
+
+impl SecondaryAxis {
+    /// Create a secondary axis with the given transform.
+    #[must_use]
+    pub fn new(transform: AxisTransform) -> Self {
+        Self { transform, name: None, tick_count: 5, formatter: None }
+    }
+
+    /// Secondary axis for `primary * scale + offset`.
+    #[must_use]
+    pub fn linear(scale: f32, offset: f32) -> Self {
+        Self::new(AxisTransform::Linear { scale, offset })
+    }
+
+    /// Celsius primary axis shown in Fahrenheit.
+    #[must_use]
+    pub fn celsius_to_fahrenheit() -> Self {
+        Self::linear(1.8, 32.0).name("degF")
+    }
+
+    /// Byte primary axis shown in bits.
+    #[must_use]
+    pub fn bytes_to_bits() -> Self {
+        Self::linear(8.0, 0.0).name("bits")
+    }
+
+    /// Set the axis title.
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the approximate number of ticks.
+    #[must_use]
+    pub fn tick_count(mut self, count: usize) -> Self {
+        self.tick_count = count.max(2);
+        self
+    }
+
+    /// Set the tick label formatter.
+    #[must_use]
+    pub fn formatter(mut self, formatter: fn(f32) -> String) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Format a secondary value as a tick label.
+    #[must_use]
+    pub fn label(&self, value: f32) -> String {
+        match self.formatter {
+            Some(f) => f(value),
+            None => format_tick(value),
+        }
+    }
+
+    /// Ticks as `(primary value, label)` for a primary domain.
+    ///
+    /// Tick values are round numbers in the secondary unit.
+    #[must_use]
+    pub fn ticks(&self, domain: (f32, f32)) -> Vec<(f32, String)> {
+        let (a, b) = (self.transform.apply(domain.0), self.transform.apply(domain.1));
+        nice_ticks(a.min(b), a.max(b), self.tick_count)
+            .into_iter()
+            .filter_map(|t| Some((self.transform.invert(t, domain)?, self.label(t))))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_celsius_ticks_mirror_primary() {
+        let axis = SecondaryAxis::celsius_to_fahrenheit();
+        let ticks = axis.ticks((0.0, 100.0));
+        let labels: Vec<&str> = ticks.iter().map(|(_, l)| l.as_str()).collect();
+        assert_eq!(labels, vec!["50", "100", "150", "200"]);
+        // 212 degF is 100 degC; 50 degF is 10 degC
+        assert!((ticks[0].0 - 10.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_custom_transform_inverted_numerically() {
+        let t = AxisTransform::Custom(|v| v * v);
+        let p = t.invert(49.0, (0.0, 10.0)).expect("target in range");
+        assert!((p - 7.0).abs() < 1e-3);
+        assert!(t.invert(200.0, (0.0, 10.0)).is_none());
+
+        let decreasing = AxisTransform::Custom(|v| 100.0 - v);
+        let p = decreasing.invert(30.0, (0.0, 100.0)).expect("target in range");
+        assert!((p - 70.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_formatter() {
+        let axis = SecondaryAxis::linear(1.0, 0.0);
+        assert_eq!(axis.label(2.5), "2.5");
+        let axis = SecondaryAxis::bytes_to_bits().formatter(|v| format!("{v:.0}b"));
+        assert_eq!(axis.label(64.0), "64b");
+    }
+}
@@ -97,6 +97,29 @@ impl Coord {
         }
         self
     }
+
+    /// Map a normalized polar position to a Cartesian offset in screen space.
+    ///
+    /// `theta` is a fraction of a full turn (0.0 - 1.0) measured from the start
+    /// angle, where angle 0 points up (12 o'clock); `r` is the distance from
+    /// the centre. Screen y grows downwards. Returns `None` for non-polar
+    /// coordinate systems.
+    #[must_use]
+    pub fn polar_to_cartesian(&self, theta: f32, r: f32) -> Option<(f32, f32)> {
+        let Coord::Polar { start, direction } = *self else { return None };
+        let angle = start + f32::from(direction) * theta * std::f32::consts::TAU;
+        Some((r * angle.sin(), -r * angle.cos()))
+    }
+
+    /// Inverse of [`Self::polar_to_cartesian`]: returns `(theta, r)` with
+    /// `theta` in `[0, 1)`.
+    #[must_use]
+    pub fn cartesian_to_polar(&self, dx: f32, dy: f32) -> Option<(f32, f32)> {
+        let Coord::Polar { start, direction } = *self else { return None };
+        let angle = dx.atan2(-dy);
+        let theta = (f32::from(direction) * (angle - start)) / std::f32::consts::TAU;
+        Some((theta.rem_euclid(1.0), dx.hypot(dy)))
+    }
 }
 
 #[cfg(test)]
@@ -137,6 +160,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_coord_polar_transform_roundtrip() {
+        let c = Coord::polar();
+        let (x, y) = c.polar_to_cartesian(0.25, 2.0).expect("polar should transform");
+        // A quarter turn clockwise from 12 o'clock points right
+        assert!((x - 2.0).abs() < 1e-5 && y.abs() < 1e-5);
+
+        let ccw = Coord::polar().start_angle(0.3).direction(-1);
+        let (x, y) = ccw.polar_to_cartesian(0.6, 1.5).expect("polar should transform");
+        let (theta, r) = ccw.cartesian_to_polar(x, y).expect("polar should invert");
+        assert!((theta - 0.6).abs() < 1e-4 && (r - 1.5).abs() < 1e-4);
+
+        assert!(Coord::cartesian().polar_to_cartesian(0.0, 1.0).is_none());
+        assert!(Coord::fixed(1.0).cartesian_to_polar(1.0, 1.0).is_none());
+    }
+
     #[test]
     fn test_coord_fixed() {
         let c = Coord::fixed(1.5);
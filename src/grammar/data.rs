@@ -101,6 +101,12 @@ impl DataFrame {
         self.columns.insert(name.to_string(), values);
     }
 
+    /// Add a column of mixed values (e.g. with [`DataValue::Null`] gaps).
+    pub fn add_column(&mut self, name: &str, values: Vec<DataValue>) {
+        self.n_rows = self.n_rows.max(values.len());
+        self.columns.insert(name.to_string(), values);
+    }
+
     /// Get a column as f32 values.
     #[must_use]
     pub fn get_f32(&self, name: &str) -> Option<Vec<f32>> {
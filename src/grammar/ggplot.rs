@@ -4,11 +4,15 @@
 
 use crate::color::Rgba;
 use crate::error::{Error, Result};
-use crate::framebuffer::Framebuffer;
-use crate::render::{draw_circle, draw_line_aa, draw_rect, draw_rect_outline, i32_px};
+use crate::framebuffer::{BlendMode, Framebuffer};
+use crate::render::{
+    draw_circle, draw_line_aa, draw_math_text, draw_rect, draw_rect_outline, has_math, i32_px,
+    math_text_width, text_direction, wrap_text, TextAlign, TextStyle,
+};
 use crate::scale::{LinearScale, Scale};
 
 use super::aes::Aes;
+use super::axis::SecondaryAxis;
 use super::coord::Coord;
 use super::data::DataFrame;
 use super::facet::Facet;
@@ -24,13 +28,26 @@ pub struct Layer {
     pub data: Option<DataFrame>,
     /// Layer-specific aesthetics.
     pub aes: Aes,
+    /// Opacity of the whole layer (0-1), applied once to the rendered group.
+    pub alpha: f32,
+    /// How the layer combines with the layers beneath it.
+    pub blend: BlendMode,
+    /// Drawing order; lower values are drawn first, ties keep insertion order.
+    pub z_order: i32,
 }
 
 impl Layer {
     /// Create a new layer from a geometry.
     #[must_use]
     pub fn new(geom: Geom) -> Self {
-        Self { aes: geom.aes.clone().unwrap_or_default(), geom, data: None }
+        Self {
+            aes: geom.aes.clone().unwrap_or_default(),
+            geom,
+            data: None,
+            alpha: 1.0,
+            blend: BlendMode::Normal,
+            z_order: 0,
+        }
     }
 
     /// Set layer-specific data.
@@ -46,6 +63,35 @@ impl Layer {
         self.aes = aes;
         self
     }
+
+    /// Set the layer opacity (0-1).
+    ///
+    /// Unlike a translucent color, overlapping marks within the layer do not
+    /// build up: the layer is drawn opaque and then faded as one group.
+    #[must_use]
+    pub fn alpha(mut self, alpha: f32) -> Self {
+        self.alpha = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the blend mode against the layers beneath.
+    #[must_use]
+    pub fn blend(mut self, mode: BlendMode) -> Self {
+        self.blend = mode;
+        self
+    }
+
+    /// Set the drawing order (default 0; lower is drawn first).
+    #[must_use]
+    pub fn z_order(mut self, z: i32) -> Self {
+        self.z_order = z;
+        self
+    }
+
+    /// Whether the layer must be rendered offscreen and composited.
+    fn needs_group(&self) -> bool {
+        self.alpha < 1.0 || self.blend != BlendMode::Normal
+    }
 }
 
 /// Grammar of Graphics plot builder.
@@ -73,6 +119,14 @@ pub struct GGPlot {
     xlab: Option<String>,
     /// Y-axis label.
     ylab: Option<String>,
+    /// X-axis label alignment along the panel width.
+    xlab_align: TextAlign,
+    /// Y-axis label alignment across the top of the panel.
+    ylab_align: TextAlign,
+    /// Secondary x axis (top), mirroring the primary.
+    sec_x: Option<SecondaryAxis>,
+    /// Secondary y axis (right), mirroring the primary.
+    sec_y: Option<SecondaryAxis>,
 }
 
 impl Default for GGPlot {
@@ -82,21 +136,27 @@ impl Default for GGPlot {
 }
 
 impl GGPlot {
-    /// Create a new plot builder.
+    /// Create a new plot builder with size and theme from the current
+    /// [`style`](crate::style).
     #[must_use]
     pub fn new() -> Self {
+        let style = crate::style::current();
         Self {
             data: DataFrame::new(),
             aes: Aes::new(),
             layers: Vec::new(),
             coord: Coord::cartesian(),
             facet: Facet::None,
-            theme: Theme::grey(),
-            width: 800,
-            height: 600,
+            theme: style.theme,
+            width: style.width,
+            height: style.height,
             title: None,
             xlab: None,
             ylab: None,
+            xlab_align: TextAlign::Center,
+            ylab_align: TextAlign::Start,
+            sec_x: None,
+            sec_y: None,
         }
     }
 
@@ -186,6 +246,35 @@ impl GGPlot {
         self
     }
 
+    /// Set x-axis label alignment (default centered under the panel).
+    #[must_use]
+    pub fn xlab_align(mut self, align: TextAlign) -> Self {
+        self.xlab_align = align;
+        self
+    }
+
+    /// Set y-axis label alignment above the panel (default `Start`: over the
+    /// y axis for left to right labels, at the right edge for right to left).
+    #[must_use]
+    pub fn ylab_align(mut self, align: TextAlign) -> Self {
+        self.ylab_align = align;
+        self
+    }
+
+    /// Add a secondary x axis along the top showing the primary x in other units.
+    #[must_use]
+    pub fn sec_axis_x(mut self, axis: SecondaryAxis) -> Self {
+        self.sec_x = Some(axis);
+        self
+    }
+
+    /// Add a secondary y axis along the right showing the primary y in other units.
+    #[must_use]
+    pub fn sec_axis_y(mut self, axis: SecondaryAxis) -> Self {
+        self.sec_y = Some(axis);
+        self
+    }
+
     /// Build the plot.
     ///
     /// # Errors
@@ -206,6 +295,12 @@ impl GGPlot {
             width: self.width,
             height: self.height,
             title: self.title,
+            xlab: self.xlab,
+            ylab: self.ylab,
+            xlab_align: self.xlab_align,
+            ylab_align: self.ylab_align,
+            sec_x: self.sec_x,
+            sec_y: self.sec_y,
         })
     }
 }
@@ -220,8 +315,13 @@ pub struct BuiltGGPlot {
     theme: Theme,
     width: u32,
     height: u32,
-    #[allow(dead_code)]
     title: Option<String>,
+    xlab: Option<String>,
+    ylab: Option<String>,
+    xlab_align: TextAlign,
+    ylab_align: TextAlign,
+    sec_x: Option<SecondaryAxis>,
+    sec_y: Option<SecondaryAxis>,
 }
 
 impl BuiltGGPlot {
@@ -252,18 +352,7 @@ impl BuiltGGPlot {
             self.theme.panel_background,
         );
 
-        // Compute data ranges for scales
-        let (x_min, x_max, y_min, y_max) = self.compute_data_ranges();
-
-        // Apply coordinate limits if set
-        let (x_min, x_max, y_min, y_max) = match &self.coord {
-            Coord::Cartesian { xlim, ylim, .. } => {
-                let (xmin, xmax) = xlim.unwrap_or((x_min, x_max));
-                let (ymin, ymax) = ylim.unwrap_or((y_min, y_max));
-                (xmin, xmax, ymin, ymax)
-            }
-            _ => (x_min, x_max, y_min, y_max),
-        };
+        let (x_min, x_max, y_min, y_max) = self.limits();
 
         // Create scales
         let x_scale = LinearScale::new((x_min, x_max), (plot_x as f32, (plot_x + plot_w) as f32))?;
@@ -274,9 +363,24 @@ impl BuiltGGPlot {
             self.draw_grid(&mut fb, &x_scale, &y_scale, plot_x, plot_y, plot_w, plot_h);
         }
 
-        // Draw each layer
-        for layer in &self.layers {
-            self.render_layer(&mut fb, layer, &x_scale, &y_scale);
+        // Explicit limits can put data outside the panel; clip it like coord_cartesian
+        let unclipped = self.has_limits().then(|| fb.clone());
+
+        // Draw each layer in z-order
+        let mut layers: Vec<&Layer> = self.layers.iter().collect();
+        layers.sort_by_key(|layer| layer.z_order);
+        for layer in layers {
+            if layer.needs_group() {
+                let mut group = Framebuffer::new(self.width, self.height)?;
+                self.render_layer(&mut group, layer, &x_scale, &y_scale);
+                fb.composite(&group, layer.alpha, layer.blend)?;
+            } else {
+                self.render_layer(&mut fb, layer, &x_scale, &y_scale);
+            }
+        }
+
+        if let Some(background) = unclipped {
+            restore_outside(&mut fb, &background, (plot_x, plot_y, plot_w, plot_h));
         }
 
         // Draw axes
@@ -284,6 +388,8 @@ impl BuiltGGPlot {
             self.draw_axes(&mut fb, plot_x, plot_y, plot_w, plot_h);
         }
 
+        self.draw_secondary_axes(&mut fb, &x_scale, &y_scale);
+
         // Draw panel border
         if self.theme.show_panel_border {
             draw_rect_outline(
@@ -297,9 +403,110 @@ impl BuiltGGPlot {
             );
         }
 
+        self.draw_axis_titles(&mut fb, plot_x, plot_y, plot_w, plot_h);
+        self.draw_title(&mut fb);
+
         Ok(fb)
     }
 
+    /// Axis limits used when rendering: `(x_min, x_max, y_min, y_max)`.
+    ///
+    /// Cartesian limits set with [`Coord::xlim`] / [`Coord::ylim`] win; any
+    /// axis without one spans the data of all layers plus 5% padding.
+    #[must_use]
+    pub fn limits(&self) -> (f32, f32, f32, f32) {
+        let (x_min, x_max, y_min, y_max) = self.compute_data_ranges();
+        match &self.coord {
+            Coord::Cartesian { xlim, ylim, .. } => {
+                let (xmin, xmax) = xlim.unwrap_or((x_min, x_max));
+                let (ymin, ymax) = ylim.unwrap_or((y_min, y_max));
+                (xmin, xmax, ymin, ymax)
+            }
+            _ => (x_min, x_max, y_min, y_max),
+        }
+    }
+
+    /// Replace the axis limits, e.g. to pan or zoom an already built plot.
+    ///
+    /// Non-Cartesian coordinate systems are replaced by Cartesian ones.
+    pub fn set_limits(&mut self, xlim: (f32, f32), ylim: (f32, f32)) {
+        let flip = matches!(self.coord, Coord::Cartesian { flip: true, .. });
+        self.coord = Coord::Cartesian { xlim: Some(xlim), ylim: Some(ylim), flip };
+    }
+
+    /// Change the output size in pixels.
+    pub fn resize(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Whether Cartesian limits were set on either axis.
+    fn has_limits(&self) -> bool {
+        matches!(self.coord, Coord::Cartesian { xlim, ylim, .. } if xlim.is_some() || ylim.is_some())
+    }
+
+    /// Draw the title centered in the top margin, wrapped to fit the figure
+    /// width (one line when a secondary x axis also uses the margin). Titles
+    /// with math notation are drawn on one line.
+    fn draw_title(&self, fb: &mut Framebuffer) {
+        let Some(title) = &self.title else {
+            return;
+        };
+        let font = TextStyle::current().with_color(self.theme.text_color);
+        if has_math(title) {
+            let x = i32_px(self.width) / 2 - i32_px(math_text_width(title, font.scale)) / 2;
+            draw_math_text(fb, x, 6, title, font.scale, font.color);
+            return;
+        }
+        let max_lines = if self.sec_x.is_some() { 1 } else { 2 };
+        let line_height = i32_px(font.height()) + 2;
+        let lines = wrap_text(title, self.width.saturating_sub(16), font.scale, Some(max_lines));
+        for (row, line) in (0_i32..).zip(&lines) {
+            let x = i32_px(self.width) / 2 - i32_px(font.width(line)) / 2;
+            font.draw(fb, x, 6 + row * line_height, line);
+        }
+    }
+
+    /// Draw the x label below the panel and the y label above it, each
+    /// aligned per axis and in its own text direction.
+    fn draw_axis_titles(
+        &self,
+        fb: &mut Framebuffer,
+        plot_x: u32,
+        plot_y: u32,
+        plot_w: u32,
+        plot_h: u32,
+    ) {
+        let font = TextStyle::current().with_color(self.theme.text_color);
+        let th = i32_px(font.height());
+        if let Some(label) = &self.xlab {
+            let offset = self.xlab_align.offset(
+                text_direction(label),
+                math_text_width(label, font.scale),
+                plot_w,
+            );
+            let below = i32_px(self.height.saturating_sub(plot_y + plot_h));
+            let y = i32_px(plot_y + plot_h) + (below - th) / 2;
+            draw_math_text(fb, i32_px(plot_x + offset), y, label, font.scale, font.color);
+        }
+        if let Some(label) = &self.ylab {
+            let span = (plot_x + plot_w).saturating_sub(2);
+            let offset = self.ylab_align.offset(
+                text_direction(label),
+                math_text_width(label, font.scale),
+                span,
+            );
+            draw_math_text(
+                fb,
+                2 + i32_px(offset),
+                i32_px(plot_y) - th - 4,
+                label,
+                font.scale,
+                font.color,
+            );
+        }
+    }
+
     /// Compute data ranges across all layers.
     fn compute_data_ranges(&self) -> (f32, f32, f32, f32) {
         let mut x_min = f32::MAX;
@@ -411,6 +618,57 @@ impl BuiltGGPlot {
         );
     }
 
+    /// Draw secondary axes: top for x, right for y, each with ticks, labels and title.
+    fn draw_secondary_axes(
+        &self,
+        fb: &mut Framebuffer,
+        x_scale: &LinearScale,
+        y_scale: &LinearScale,
+    ) {
+        let color = self.theme.axis_color;
+        let (left, right) = x_scale.range();
+        let (bottom, top) = y_scale.range();
+        let font = TextStyle::current().with_color(color);
+        let th = i32_px(font.height());
+
+        if let Some(axis) = &self.sec_x {
+            draw_line_aa(fb, left, top, right, top, color);
+            for (value, label) in axis.ticks(x_scale.domain()) {
+                let x = x_scale.scale(value);
+                draw_line_aa(fb, x, top - 4.0, x, top, color);
+                let tw = i32_px(font.width(&label));
+                font.draw(fb, x as i32 - tw / 2, top as i32 - 6 - th, &label);
+            }
+            if let Some(name) = &axis.name {
+                let tw = i32_px(math_text_width(name, font.scale));
+                let cx = ((left + right) / 2.0) as i32;
+                draw_math_text(fb, cx - tw / 2, top as i32 - 10 - 2 * th, name, font.scale, color);
+            }
+        }
+
+        if let Some(axis) = &self.sec_y {
+            draw_line_aa(fb, right, top, right, bottom, color);
+            let mut widest = 0;
+            for (value, label) in axis.ticks(y_scale.domain()) {
+                let y = y_scale.scale(value);
+                draw_line_aa(fb, right, y, right + 4.0, y, color);
+                font.draw(fb, right as i32 + 6, y as i32 - th / 2, &label);
+                widest = widest.max(i32_px(font.width(&label)));
+            }
+            if let Some(name) = &axis.name {
+                let cy = ((top + bottom) / 2.0) as i32;
+                draw_math_text(
+                    fb,
+                    right as i32 + 10 + widest,
+                    cy - th / 2,
+                    name,
+                    font.scale,
+                    color,
+                );
+            }
+        }
+    }
+
     /// Render a single layer.
     fn render_layer(
         &self,
@@ -606,6 +864,23 @@ impl BuiltGGPlot {
     }
 }
 
+/// Copy `background` back over every pixel of `fb` outside `panel`
+/// (`x, y, w, h`), undoing layer drawing that spilled into the margins.
+fn restore_outside(fb: &mut Framebuffer, background: &Framebuffer, panel: (u32, u32, u32, u32)) {
+    let (px, py, pw, ph) = panel;
+    let width = fb.width() as usize;
+    let (left, right) = ((px as usize).min(width), ((px + pw) as usize + 1).min(width));
+    for y in 0..fb.height() {
+        let (Some(row), Some(saved)) = (fb.row_mut(y), background.row(y)) else { continue };
+        if y < py || y > py + ph {
+            row.copy_from_slice(saved);
+        } else {
+            row[..left * 4].copy_from_slice(&saved[..left * 4]);
+            row[right * 4..].copy_from_slice(&saved[right * 4..]);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -671,6 +946,29 @@ mod tests {
         assert!(fb.width() > 0);
     }
 
+    #[test]
+    fn test_ggplot_limits_clip_and_reset() {
+        let mut plot = GGPlot::new()
+            .data_xy(&[0.5, 1.2], &[0.5, 0.5])
+            .geom(Geom::point().aes(Aes::new().size_value(8.0)))
+            .dimensions(200, 200)
+            .build()
+            .expect("operation should succeed");
+        let (x_min, x_max, ..) = plot.limits();
+        assert!(x_min < 0.5 && x_max > 1.2);
+
+        plot.set_limits((0.0, 1.0), (0.0, 1.0));
+        assert_eq!(plot.limits(), (0.0, 1.0, 0.0, 1.0));
+        let fb = plot.to_framebuffer().expect("operation should succeed");
+        // x = 1.2 maps into the right margin, which must stay background
+        let background = fb.get_pixel(195, 5);
+        assert!((165..200).all(|x| fb.get_pixel(x, 100) == background));
+
+        plot.resize(120, 90);
+        let fb = plot.to_framebuffer().expect("operation should succeed");
+        assert_eq!((fb.width(), fb.height()), (120, 90));
+    }
+
     #[test]
     fn test_layer_with_aes() {
         let plot = GGPlot::new()
@@ -939,4 +1237,134 @@ mod tests {
         let fb = plot.to_framebuffer().expect("operation should succeed");
         assert!(fb.width() > 0);
     }
+
+    #[test]
+    fn test_ggplot_secondary_axis_mirrors_primary() {
+        let base = GGPlot::new()
+            .data_xy(&[0.0, 100.0], &[0.0, 100.0])
+            .geom(Geom::point())
+            .theme(Theme::minimal())
+            .dimensions(300, 200);
+        let plain = base.clone().build().expect("operation should succeed");
+        let with_sec = base
+            .sec_axis_y(SecondaryAxis::celsius_to_fahrenheit())
+            .build()
+            .expect("operation should succeed");
+
+        let a = plain.to_framebuffer().expect("operation should succeed");
+        let b = with_sec.to_framebuffer().expect("operation should succeed");
+        // Labels are drawn in the right margin, outside the panel
+        let right_margin_differs =
+            (262..300).any(|x| (0..200).any(|y| a.get_pixel(x, y) != b.get_pixel(x, y)));
+        assert!(right_margin_differs);
+    }
+
+    #[test]
+    fn test_ggplot_title_wraps_inside_figure() {
+        let title = "A rather long title that cannot possibly fit on one line of this figure";
+        let plot = GGPlot::new()
+            .data_xy(&[1.0, 2.0], &[3.0, 4.0])
+            .geom(Geom::point())
+            .theme(Theme::minimal())
+            .title(title)
+            .dimensions(200, 150)
+            .build()
+            .expect("operation should succeed");
+        let fb = plot.to_framebuffer().expect("operation should succeed");
+
+        let background = Theme::minimal().background;
+        let inked = |y: u32| (0..200).any(|x| fb.get_pixel(x, y) != Some(background));
+        // Two wrapped lines in the top margin, nothing in the outermost columns
+        assert!(inked(7) && inked(16));
+        assert!((0..40).all(|y| fb.get_pixel(0, y) == Some(background)));
+    }
+
+    #[test]
+    fn test_ggplot_axis_labels_render_math() {
+        let build = |xlab: &str| {
+            GGPlot::new()
+                .data_xy(&[1.0, 2.0], &[3.0, 4.0])
+                .geom(Geom::point())
+                .theme(Theme::minimal())
+                .xlab(xlab)
+                .ylab("$\\sigma$")
+                .dimensions(200, 150)
+                .build()
+                .expect("operation should succeed")
+                .to_framebuffer()
+                .expect("operation should succeed")
+        };
+        let fb = build("$\\lambda^2$");
+        let background = Theme::minimal().background;
+        let inked = |fb: &Framebuffer, x0: u32, x1: u32, y0: u32, y1: u32| {
+            (x0..x1).any(|x| (y0..y1).any(|y| fb.get_pixel(x, y) != Some(background)))
+        };
+        // x label centered in the bottom margin, y label above the axis
+        assert!(inked(&fb, 90, 110, 115, 150));
+        assert!(inked(&fb, 0, 10, 25, 40));
+        // The command is drawn as one glyph, not as its name
+        let literal = build("lambda^2");
+        assert!(!inked(&fb, 112, 130, 115, 150));
+        assert!(inked(&literal, 112, 130, 115, 150));
+    }
+
+    #[test]
+    fn test_ggplot_rtl_ylab_aligns_to_right_edge() {
+        let render = |ylab: &str, align: TextAlign| {
+            GGPlot::new()
+                .data_xy(&[1.0, 2.0], &[3.0, 4.0])
+                .geom(Geom::point())
+                .theme(Theme::minimal())
+                .ylab(ylab)
+                .ylab_align(align)
+                .dimensions(200, 150)
+                .build()
+                .expect("operation should succeed")
+                .to_framebuffer()
+                .expect("operation should succeed")
+        };
+        let background = Theme::minimal().background;
+        let inked = |fb: &Framebuffer, x0: u32, x1: u32| {
+            (x0..x1).any(|x| (25..36).any(|y| fb.get_pixel(x, y) != Some(background)))
+        };
+        let hebrew = render("\u{5E2}\u{5D5}\u{5DE}\u{5E1}", TextAlign::Start);
+        assert!(inked(&hebrew, 140, 160) && !inked(&hebrew, 0, 40));
+        let latin = render("load", TextAlign::Start);
+        assert!(inked(&latin, 0, 40) && !inked(&latin, 140, 160));
+        let forced = render("\u{5E2}\u{5D5}\u{5DE}\u{5E1}", TextAlign::Left);
+        assert!(inked(&forced, 0, 40));
+    }
+
+    #[test]
+    fn test_layer_alpha_blend_and_z_order() {
+        let render = |layers: Vec<Layer>| {
+            let mut plot = GGPlot::new()
+                .data_xy(&[0.0, 1.0], &[1.0, 1.0])
+                .theme(Theme::minimal())
+                .dimensions(200, 200);
+            for layer in layers {
+                plot = plot.layer(layer);
+            }
+            plot.build()
+                .expect("operation should succeed")
+                .to_framebuffer()
+                .expect("operation should succeed")
+        };
+        let red = Aes::new().color_value(Rgba::RED);
+        let blue = Aes::new().color_value(Rgba::BLUE);
+        let bars = |aes: &Aes| Layer::new(Geom::bar()).aes(aes.clone());
+        let center = |fb: &Framebuffer| fb.get_pixel(150, 120).expect("in bounds");
+
+        // Later layers draw on top unless z-order says otherwise
+        assert_eq!(center(&render(vec![bars(&red), bars(&blue)])), Rgba::BLUE);
+        assert_eq!(center(&render(vec![bars(&red).z_order(1), bars(&blue)])), Rgba::RED);
+
+        // A faint layer lets the one beneath show through
+        let faint = center(&render(vec![bars(&red), bars(&blue).alpha(0.25)]));
+        assert!(faint.r > 150 && faint.b > 40 && faint.b < 100);
+
+        // Multiply over red keeps only red's channels
+        let multiplied = center(&render(vec![bars(&red), bars(&blue).blend(BlendMode::Multiply)]));
+        assert_eq!(multiplied, Rgba::BLACK);
+    }
 }
@@ -10,6 +10,7 @@
 //! - **Statistics**: Data transformations (identity, bin, smooth, density)
 //! - **Scales**: Domain-to-range mappings
 //! - **Coordinates**: Coordinate systems (cartesian, polar)
+//! - **Secondary axes**: Unit transformations mirroring a primary axis
 //! - **Facets**: Small multiples for conditioning
 //!
 //! # Example
@@ -32,6 +33,7 @@
 //!   and Graphical Statistics.
 
 mod aes;
+mod axis;
 mod coord;
 mod data;
 mod facet;
@@ -41,6 +43,7 @@ mod stat;
 mod theme;
 
 pub use aes::Aes;
+pub use axis::{AxisTransform, SecondaryAxis};
 pub use coord::Coord;
 pub use data::{DataFrame, DataValue};
 pub use facet::Facet;
@@ -5,7 +5,7 @@
 use crate::color::Rgba;
 
 /// Theme specification.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Theme {
     /// Background color.
     pub background: Rgba,
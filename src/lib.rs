@@ -78,6 +78,12 @@ pub mod geometry;
 /// Scale functions for data-to-visual mappings.
 pub mod scale;
 
+/// Locale-aware number and date formatting.
+pub mod locale;
+
+/// Crate-wide style defaults from config file and environment.
+pub mod style;
+
 // ============================================================================
 // Visualization Modules
 // ============================================================================
@@ -101,6 +107,9 @@ pub mod output;
 /// SIMD/GPU acceleration layer.
 pub mod accel;
 
+/// Figure comparison (structural and visual diffs of rendered output).
+pub mod testing;
+
 // ============================================================================
 // Optional Integration Modules
 // ============================================================================
@@ -124,6 +133,20 @@ pub mod wasm;
 #[cfg_attr(docsrs, doc(cfg(feature = "monitor")))]
 pub mod monitor;
 
+/// HTTP render service for notebooks and web clients.
+#[cfg(feature = "server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "server")))]
+pub mod server;
+
+/// Interactive terminal chart viewer with keyboard pan/zoom.
+#[cfg(feature = "terminal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "terminal")))]
+pub mod terminal;
+
+/// Raw-mode session and input loop shared by `terminal` and `monitor`.
+#[cfg(any(feature = "terminal", feature = "monitor"))]
+mod tty;
+
 // ============================================================================
 // Error Types
 // ============================================================================
@@ -151,7 +174,7 @@ pub mod prelude {
         ConfusionMatrix, Heatmap, HeatmapPalette, Histogram, LineChart, LineSeries, LossCurve,
         PrCurve, RocCurve, ScatterPlot,
     };
-    pub use crate::scale::{ColorScale, LinearScale, LogScale, Scale};
+    pub use crate::scale::{BandScale, ColorScale, LinearScale, LogScale, Scale};
     pub use crate::widgets::{ResourceBar, RunRow, RunStatus, RunTable, Sparkline, TrendDirection};
     pub use batuta_common::display::WithDimensions;
 }
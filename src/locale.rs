@@ -0,0 +1,228 @@
+//! Locale-aware number and date formatting for tick and cell labels.
+//!
+//! A [`Locale`] controls the decimal separator, thousands grouping, month
+//! names and day/month order. Shared formatters such as tick labels read the
+//! locale of the current [`style`](crate::style), so one setting applies to
+//! every plot and output backend. Month names are ASCII so the built-in
+//! bitmap font can draw them.
+
+/// Number and date conventions for labels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    /// Identifier such as `en-US`.
+    pub name: &'static str,
+    /// Separator between integer and fractional digits.
+    pub decimal_separator: char,
+    /// Separator between groups of three integer digits (None = no grouping).
+    pub thousands_separator: Option<char>,
+    /// Abbreviated month names, January first.
+    pub month_names: [&'static str; 12],
+    /// Write dates day first (`5 Mar 2024`) instead of month first (`Mar 5 2024`).
+    pub day_first: bool,
+}
+
+const ENGLISH_MONTHS: [&str; 12] =
+    ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::en_us()
+    }
+}
+
+impl Locale {
+    /// US English: `1,234.5`, `Mar 5 2024`.
+    #[must_use]
+    pub const fn en_us() -> Self {
+        Self {
+            name: "en-US",
+            decimal_separator: '.',
+            thousands_separator: Some(','),
+            month_names: ENGLISH_MONTHS,
+            day_first: false,
+        }
+    }
+
+    /// British English: `1,234.5`, `5 Mar 2024`.
+    #[must_use]
+    pub const fn en_gb() -> Self {
+        Self { name: "en-GB", day_first: true, ..Self::en_us() }
+    }
+
+    /// German: `1.234,5`, `5 Mrz 2024`.
+    #[must_use]
+    pub const fn de_de() -> Self {
+        Self {
+            name: "de-DE",
+            decimal_separator: ',',
+            thousands_separator: Some('.'),
+            month_names: [
+                "Jan", "Feb", "Mrz", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+            ],
+            day_first: true,
+        }
+    }
+
+    /// French: `1 234,5`, `5 mars 2024`.
+    #[must_use]
+    pub const fn fr_fr() -> Self {
+        Self {
+            name: "fr-FR",
+            decimal_separator: ',',
+            thousands_separator: Some(' '),
+            month_names: [
+                "janv", "fevr", "mars", "avr", "mai", "juin", "juil", "aout", "sept", "oct", "nov",
+                "dec",
+            ],
+            day_first: true,
+        }
+    }
+
+    /// Plain digits without grouping, as produced by Rust's `{}` formatting.
+    #[must_use]
+    pub const fn posix() -> Self {
+        Self { name: "C", thousands_separator: None, ..Self::en_us() }
+    }
+
+    /// Look up a locale by name (`de_DE`, `de-DE`, `de`, ...; case-insensitive).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase().replace('_', "-");
+        let name = name.split('.').next().unwrap_or_default();
+        Some(match name {
+            "en-us" | "en" => Self::en_us(),
+            "en-gb" => Self::en_gb(),
+            "de-de" | "de" | "de-at" | "de-ch" => Self::de_de(),
+            "fr-fr" | "fr" | "fr-be" => Self::fr_fr(),
+            "c" | "posix" => Self::posix(),
+            _ => return None,
+        })
+    }
+
+    /// Format a number with a fixed count of decimals.
+    #[must_use]
+    pub fn format_number(&self, value: f64, decimals: usize) -> String {
+        self.localize(&format!("{value:.decimals$}"))
+    }
+
+    /// Format a tick value compactly: up to three decimals with trailing
+    /// zeros removed.
+    #[must_use]
+    pub fn format_tick(&self, value: f64) -> String {
+        let text = format!("{value:.3}");
+        let text = text.trim_end_matches('0').trim_end_matches('.');
+        if text == "-0" {
+            "0".to_string()
+        } else {
+            self.localize(text)
+        }
+    }
+
+    /// Abbreviated name of a month (1-12).
+    #[must_use]
+    pub fn month_name(&self, month: u32) -> &'static str {
+        let index = month.clamp(1, 12) as usize - 1;
+        self.month_names[index]
+    }
+
+    /// Format a calendar date in this locale's order.
+    #[must_use]
+    pub fn format_date(&self, year: i32, month: u32, day: u32) -> String {
+        let month = self.month_name(month);
+        if self.day_first {
+            format!("{day} {month} {year}")
+        } else {
+            format!("{month} {day} {year}")
+        }
+    }
+
+    /// Format a Unix timestamp (seconds, UTC) as a date.
+    #[must_use]
+    pub fn format_unix_date(&self, seconds: i64) -> String {
+        let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+        self.format_date(year, month, day)
+    }
+
+    /// Rewrite a `-1234.5`-style string with this locale's separators.
+    fn localize(&self, plain: &str) -> String {
+        let (sign, digits) = match plain.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", plain),
+        };
+        let (int, frac) = digits.split_once('.').map_or((digits, None), |(i, f)| (i, Some(f)));
+        if !int.bytes().all(|b| b.is_ascii_digit()) {
+            // inf / NaN
+            return plain.to_string();
+        }
+
+        let mut out = String::with_capacity(plain.len() + int.len() / 3);
+        out.push_str(sign);
+        for (i, ch) in int.chars().enumerate() {
+            if i > 0 && (int.len() - i) % 3 == 0 {
+                if let Some(sep) = self.thousands_separator {
+                    out.push(sep);
+                }
+            }
+            out.push(ch);
+        }
+        if let Some(frac) = frac {
+            out.push(self.decimal_separator);
+            out.push_str(frac);
+        }
+        out
+    }
+}
+
+/// Proleptic Gregorian `(year, month, day)` for a count of days since
+/// 1970-01-01 (Hinnant's `civil_from_days`).
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year as i32, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_separators() {
+        assert_eq!(Locale::en_us().format_number(1_234_567.891, 2), "1,234,567.89");
+        assert_eq!(Locale::de_de().format_number(-1234.5, 1), "-1.234,5");
+        assert_eq!(Locale::fr_fr().format_number(999.0, 0), "999");
+        assert_eq!(Locale::fr_fr().format_number(12_000.0, 0), "12 000");
+        assert_eq!(Locale::posix().format_number(12_000.25, 2), "12000.25");
+        assert_eq!(Locale::de_de().format_number(f64::NAN, 2), "NaN");
+    }
+
+    #[test]
+    fn test_format_tick() {
+        assert_eq!(Locale::en_us().format_tick(2.5), "2.5");
+        assert_eq!(Locale::de_de().format_tick(2.5), "2,5");
+        assert_eq!(Locale::de_de().format_tick(10_000.0), "10.000");
+        assert_eq!(Locale::de_de().format_tick(-0.0001), "0");
+    }
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(Locale::en_us().format_date(2024, 3, 5), "Mar 5 2024");
+        assert_eq!(Locale::de_de().format_date(2024, 3, 5), "5 Mrz 2024");
+        // 2024-02-29T12:00:00Z
+        assert_eq!(Locale::en_gb().format_unix_date(1_709_208_000), "29 Feb 2024");
+        assert_eq!(Locale::en_us().format_unix_date(-86_400), "Dec 31 1969");
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Locale::from_name("de_DE.UTF-8"), Some(Locale::de_de()));
+        assert_eq!(Locale::from_name("EN-gb"), Some(Locale::en_gb()));
+        assert_eq!(Locale::from_name("xx"), None);
+    }
+}
@@ -1,24 +1,35 @@
 //! Main application loop for the TUI monitor.
 
+use crate::monitor::collectors::pcie::{same_pci_address, PcieLink};
+use crate::monitor::collectors::{
+    HwErrorCollector, LimitsCollector, NetworkCollector, PcieCollector, TcpAnomaly,
+};
 use crate::monitor::config::Config;
 use crate::monitor::error::Result;
 use crate::monitor::input::{Action, InputHandler};
 use crate::monitor::layout::LayoutManager;
-use crate::monitor::panels::{CpuPanel, MemoryPanel, ProcessPanel};
+use crate::monitor::panels::notifications::{NotificationCenter, Severity, DEFAULT_CAPACITY};
+use crate::monitor::panels::{
+    CorrelationPanel, CpuPanel, GpuPanel, MemoryPanel, PowerPanel, ProcessPanel,
+};
+use crate::monitor::presets::AlertThresholds;
 use crate::monitor::state::State;
 use crate::monitor::theme::Theme;
 use crate::monitor::types::Collector;
+use batuta_common::fmt::format_bytes_rate;
+
+use crate::tty::{event_loop, LoopEvent, RawScreen};
 
-use crossterm::event::{self, Event, KeyEventKind};
-use crossterm::terminal::{
-    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
-};
-use crossterm::ExecutableCommand;
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
+use std::collections::HashSet;
 use std::io::{self, stdout};
+use std::ops::ControlFlow;
 use std::time::Duration;
 
+/// Bytes per GiB, for the memory panels.
+const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
 /// The main TUI monitoring application.
 pub struct App {
     /// Configuration.
@@ -38,16 +49,66 @@ pub struct App {
     memory_panel: MemoryPanel,
     /// Process panel.
     process_panel: ProcessPanel,
+    /// Power panel.
+    power_panel: PowerPanel,
+    /// GPU utilization, temperature and VRAM from the vendor libraries.
+    gpu_panel: GpuPanel,
+    /// Correlation insights over the metric histories.
+    correlation_panel: CorrelationPanel,
+    /// Whether correlation insights replace the process panel.
+    show_correlations: bool,
+    /// GPU PCIe link state.
+    pcie: PcieCollector,
+    /// Host and GPU memory errors.
+    hw_errors: HwErrorCollector,
+    /// Descriptor and thread limit saturation.
+    limits: LimitsCollector,
+    /// Network throughput and TCP connection states.
+    network: NetworkCollector,
+    /// Alert history, persisted between sessions.
+    notifications: NotificationCenter,
+    /// Alert thresholds from the selected profile.
+    thresholds: AlertThresholds,
+    /// Conditions (source, message) that held on the previous check.
+    active_alerts: HashSet<(String, String)>,
 }
 
 impl App {
+    /// Panel names a layout preset can place; other names render a placeholder.
+    pub const PANELS: [&'static str; 11] = [
+        "cpu",
+        "memory",
+        "swap",
+        "gpu",
+        "vram",
+        "power",
+        "network",
+        "pcie",
+        "process",
+        "correlation",
+        "notifications",
+    ];
+
     /// Creates a new application with the given configuration.
     #[must_use]
     pub fn new(config: Config) -> Self {
         let theme = Theme::default();
         let state = State::new(config.global.history_size);
         let input = InputHandler::new(config.global.vim_keys);
-        let layout = LayoutManager::new();
+        let mut layout = LayoutManager::new();
+        let mut thresholds = AlertThresholds::default();
+        if let Some(profile) = config.profile() {
+            layout.add_preset(profile.preset);
+            layout.switch_to(1);
+            thresholds = profile.thresholds;
+        }
+        // A missing history starts empty; an unreadable one is left untouched
+        let notifications = NotificationCenter::default_path()
+            .and_then(|path| NotificationCenter::load(path, DEFAULT_CAPACITY).ok())
+            .unwrap_or_default();
+
+        let power_panel = PowerPanel::new().pricing(config.power.clone());
+        let correlation_panel = CorrelationPanel::new(config.global.history_size);
 
         Self {
             config,
@@ -58,6 +119,17 @@ impl App {
             cpu_panel: CpuPanel::new(),
             memory_panel: MemoryPanel::new(),
             process_panel: ProcessPanel::new(),
+            power_panel,
+            gpu_panel: GpuPanel::new(),
+            correlation_panel,
+            show_correlations: false,
+            pcie: PcieCollector::new(),
+            hw_errors: HwErrorCollector::new(),
+            limits: LimitsCollector::new(),
+            network: NetworkCollector::new(),
+            notifications,
+            thresholds,
+            active_alerts: HashSet::new(),
         }
     }
 
@@ -67,53 +139,45 @@ impl App {
     ///
     /// Returns an error if terminal setup or rendering fails.
     pub fn run(&mut self) -> Result<()> {
-        // Setup terminal
-        enable_raw_mode()?;
-        stdout().execute(EnterAlternateScreen)?;
+        // Setup terminal; restored when the screen guard drops or on panic
+        let screen = RawScreen::enter()?;
         let backend = CrosstermBackend::new(stdout());
         let mut terminal = Terminal::new(backend)?;
 
         // Run the main loop
         let result = self.main_loop(&mut terminal);
+        drop(screen);
 
-        // Restore terminal
-        disable_raw_mode()?;
-        stdout().execute(LeaveAlternateScreen)?;
-        terminal.show_cursor()?;
-
-        result
+        let saved = self.notifications.save();
+        result.and(saved)
     }
 
     /// The main event loop.
     fn main_loop(&mut self, terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
-        let poll_timeout = Duration::from_millis(100);
-
-        loop {
-            // Render
-            terminal.draw(|frame| {
-                self.render(frame);
-            })?;
-
-            // Poll for events
-            if event::poll(poll_timeout)? {
-                if let Event::Key(key) = event::read()? {
-                    if key.kind == KeyEventKind::Press {
-                        let action = self.input.handle_key(key);
-                        self.handle_action(action);
-                    }
+        event_loop(Duration::from_millis(100), |event| {
+            match event {
+                // Render
+                LoopEvent::Draw => {
+                    terminal.draw(|frame| self.render(frame))?;
+                    return Ok(ControlFlow::Continue(()));
                 }
+                LoopEvent::Key(key) => {
+                    let action = self.input.handle_key(key);
+                    self.handle_action(action);
+                }
+                LoopEvent::Resize | LoopEvent::Idle => {}
             }
 
             // Collect metrics periodically
             self.collect_metrics();
 
             // Check for quit
-            if self.state.should_quit {
-                break;
-            }
-        }
-
-        Ok(())
+            Ok(if self.state.should_quit {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            })
+        })
     }
 
     /// Handles an input action.
@@ -122,6 +186,12 @@ impl App {
             Action::Quit => self.state.quit(),
             Action::Help => self.state.toggle_help(),
             Action::Preset(n) => self.layout.switch_to(n as usize),
+            Action::Notifications => self.state.toggle_notifications(),
+            Action::Correlations => self.show_correlations = !self.show_correlations,
+            Action::Acknowledge => self.notifications.acknowledge_selected(),
+            Action::AcknowledgeAll => self.notifications.acknowledge_all(),
+            Action::Up if self.state.show_notifications => self.notifications.select_prev(),
+            Action::Down if self.state.show_notifications => self.notifications.select_next(),
             Action::Up | Action::Down | Action::Left | Action::Right => {
                 // Navigation within panels not yet implemented - reserved for future use
             }
@@ -144,95 +214,364 @@ impl App {
                 self.state.record("memory", metrics, self.config.global.history_size);
             }
         }
+
+        // Collect energy counters
+        if self.power_panel.collector.is_available() {
+            if let Ok(metrics) = self.power_panel.collector.collect() {
+                self.state.record("energy", metrics, self.config.global.history_size);
+            }
+        }
+
+        // Collect network rates and TCP connection states
+        if self.network.is_available() {
+            if let Ok(metrics) = self.network.collect() {
+                self.state.record("network", metrics, self.config.global.history_size);
+            }
+        }
+
+        // Collect GPU utilization, temperature and VRAM
+        if self.gpu_panel.is_available() {
+            let metrics = self.gpu_panel.collect();
+            self.state.record("gpu", metrics, self.config.global.history_size);
+        }
+
+        // Collect GPU PCIe link state
+        if self.pcie.is_available() {
+            if let Ok(metrics) = self.pcie.collect() {
+                self.state.record("pcie", metrics, self.config.global.history_size);
+            }
+        }
+
+        // Collect memory error counters and XID events
+        if self.hw_errors.is_available() {
+            if let Ok(metrics) = self.hw_errors.collect() {
+                self.state.record("hw_errors", metrics, self.config.global.history_size);
+            }
+        }
+
+        // Collect descriptor and thread usage against limits
+        if self.limits.is_available() {
+            if let Ok(metrics) = self.limits.collect() {
+                self.state.record("limits", metrics, self.config.global.history_size);
+            }
+        }
+
+        self.correlation_panel.update(&self.state);
+        self.check_alerts();
     }
 
-    /// Renders the application.
+    /// Raises notifications for the latest metrics.
+    ///
+    /// Threshold conditions alert on the rising edge only: one that still
+    /// holds on the next tick is not pushed again, so acknowledging it sticks
+    /// until it clears and recurs. Hardware error events are new by nature
+    /// and always pushed.
+    fn check_alerts(&mut self) {
+        let mut active = HashSet::new();
+        for (severity, source, message) in self.alert_conditions() {
+            let key = (source, message);
+            if !self.active_alerts.contains(&key) {
+                self.notifications.push(severity, &key.0, &key.1);
+            }
+            active.insert(key);
+        }
+        self.active_alerts = active;
+
+        for event in self.hw_errors.take_new_events() {
+            let severity = if event.is_critical() { Severity::Critical } else { Severity::Warning };
+            self.notifications.push(severity, &event.source, &event.summary());
+        }
+    }
+
+    /// Threshold conditions that currently hold, as severity, source and message.
+    fn alert_conditions(&self) -> Vec<(Severity, String, String)> {
+        let limits = self.thresholds;
+        let mut alerts = Vec::new();
+        let mut raise = |severity, source: &str, message: String| {
+            alerts.push((severity, source.to_string(), message));
+        };
+        if let Some(cpu) = self.state.latest("cpu").and_then(|m| m.get_gauge("cpu.total")) {
+            if cpu >= limits.cpu_percent {
+                let message = format!("usage above {:.0}%", limits.cpu_percent);
+                raise(Severity::Warning, "cpu", message);
+            }
+        }
+        if let Some(memory) = self.state.latest("memory") {
+            let percent = |used: &str, total: &str| {
+                let total = memory.get_counter(total).unwrap_or(0);
+                let used = memory.get_counter(used).unwrap_or(0);
+                (total > 0).then(|| used as f64 / total as f64 * 100.0)
+            };
+            let ram = percent("memory.used", "memory.total");
+            let swap = percent("memory.swap.used", "memory.swap.total");
+            if ram.is_some_and(|p| p >= limits.memory_percent) {
+                let message = format!("usage above {:.0}%", limits.memory_percent);
+                raise(Severity::Critical, "memory", message);
+            }
+            if swap.is_some_and(|p| p >= limits.swap_percent) {
+                let message = format!("usage above {:.0}%", limits.swap_percent);
+                raise(Severity::Warning, "swap", message);
+            }
+        }
+        for gpu in self.gpu_panel.readings() {
+            if gpu.temperature >= limits.gpu_temp_c {
+                let message = format!("{} above {:.0}°C", gpu.name, limits.gpu_temp_c);
+                raise(Severity::Critical, "gpu", message);
+            }
+        }
+        for link in self.pcie.degraded() {
+            let message =
+                format!("{} GPU {} link at {}", link.vendor_name(), link.address, link.summary());
+            raise(Severity::Warning, "pcie", message);
+        }
+        for anomaly in self.network.tcp_anomalies() {
+            let severity = match anomaly {
+                TcpAnomaly::SynFlood { .. } => Severity::Critical,
+                TcpAnomaly::CloseWaitLeak { .. } => Severity::Warning,
+            };
+            raise(severity, "tcp", anomaly.description().to_string());
+        }
+
+        let fraction = limits.limit_percent / 100.0;
+        let system = self.limits.system();
+        if system.file_ratio().is_some_and(|r| r >= fraction) {
+            let message = format!("open files above {:.0}% of fs.file-max", limits.limit_percent);
+            raise(Severity::Critical, "limits", message);
+        }
+        if system.thread_ratio().is_some_and(|r| r >= fraction) {
+            let message =
+                format!("threads above {:.0}% of kernel.threads-max", limits.limit_percent);
+            raise(Severity::Critical, "limits", message);
+        }
+        for process in self.limits.near_limit(fraction) {
+            let (what, limit) = if process.fd_ratio().is_some_and(|r| r >= fraction) {
+                ("open file", process.fd_limit)
+            } else {
+                ("process", process.thread_limit)
+            };
+            let message = format!(
+                "{} (pid {}) near its {what} limit of {}",
+                process.name,
+                process.pid,
+                limit.unwrap_or_default()
+            );
+            raise(Severity::Warning, "limits", message);
+        }
+        alerts
+    }
+
+    /// Renders the current layout preset, one bordered panel per named slot.
     fn render(&self, frame: &mut ratatui::Frame) {
+        let preset = self.layout.current();
+        let standalone_power = preset.rows.iter().any(|r| r.panels.iter().any(|p| p == "power"));
+        for (row, areas) in preset.rows.iter().zip(preset.calculate(frame.area())) {
+            for (name, area) in row.panels.iter().zip(areas) {
+                self.render_panel(frame, name, area, standalone_power);
+            }
+        }
+
+        // Render help if visible
+        if self.state.show_help {
+            // Help overlay rendering is handled by ttop::panels::draw_help()
+        }
+    }
+
+    /// Renders the panel `name` into `area`; names outside [`App::PANELS`]
+    /// get a placeholder.
+    fn render_panel(
+        &self,
+        frame: &mut ratatui::Frame,
+        name: &str,
+        area: ratatui::layout::Rect,
+        standalone_power: bool,
+    ) {
         use ratatui::layout::{Constraint, Direction, Layout};
         use ratatui::style::{Color, Style};
         use ratatui::widgets::{Block, Borders, Paragraph};
 
-        let area = frame.area();
-
-        // Calculate layout
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Percentage(30),
-                Constraint::Percentage(25),
-                Constraint::Percentage(45),
-            ])
-            .split(area);
-
-        // Render CPU panel
-        let cpu_block = Block::default()
-            .title(" CPU ")
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan));
-
-        let cpu_content = if let Some(metrics) = self.state.latest("cpu") {
-            let percent = metrics.get_gauge("cpu.total").unwrap_or(0.0);
-            format!("CPU Usage: {percent:.1}%")
-        } else {
-            "CPU: collecting...".to_string()
+        // The process slot doubles as the notification center and correlation view
+        let name = match name {
+            "process" if self.state.show_notifications => "notifications",
+            "process" if self.show_correlations => "correlation",
+            other => other,
         };
-
-        frame.render_widget(Paragraph::new(cpu_content).block(cpu_block), chunks[0]);
-
-        // Render memory panel with detailed swap info
-        let mem_block = Block::default()
-            .title(" Memory ")
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Green));
-
-        let mem_content = if let Some(metrics) = self.state.latest("memory") {
-            let total = metrics.get_counter("memory.total").unwrap_or(0);
-            let used = metrics.get_counter("memory.used").unwrap_or(0);
-            let swap_total = metrics.get_counter("memory.swap.total").unwrap_or(0);
-            let swap_used = metrics.get_counter("memory.swap.used").unwrap_or(0);
-
-            let total_gb = total as f64 / (1024.0 * 1024.0 * 1024.0);
-            let used_gb = used as f64 / (1024.0 * 1024.0 * 1024.0);
-            let swap_total_gb = swap_total as f64 / (1024.0 * 1024.0 * 1024.0);
-            let swap_used_gb = swap_used as f64 / (1024.0 * 1024.0 * 1024.0);
-
-            // macOS-specific: show compressed memory
-            #[cfg(target_os = "macos")]
-            let extra = {
-                let compressed = metrics.get_counter("memory.compressed").unwrap_or(0);
-                let compressed_gb = compressed as f64 / (1024.0 * 1024.0 * 1024.0);
-                if compressed_gb > 0.1 {
-                    format!(" | Compressed: {:.1}G", compressed_gb)
-                } else {
-                    String::new()
-                }
-            };
-            #[cfg(not(target_os = "macos"))]
-            let extra = String::new();
-
-            format!(
-                "RAM: {used_gb:.0}/{total_gb:.0}G | Swap: {swap_used_gb:.0}/{swap_total_gb:.0}G{extra}"
-            )
-        } else {
-            "Memory: collecting...".to_string()
+        let (title, color) = match name {
+            "cpu" => (" CPU ".to_string(), Color::Cyan),
+            "memory" => (" Memory ".to_string(), Color::Green),
+            "swap" => (" Swap ".to_string(), Color::LightGreen),
+            "gpu" => (" GPU ".to_string(), Color::LightYellow),
+            "vram" => (" VRAM ".to_string(), Color::LightCyan),
+            "power" => (" Power ".to_string(), Color::LightRed),
+            "network" => (" Network ".to_string(), Color::LightBlue),
+            "pcie" => (" GPU PCIe ".to_string(), Color::LightMagenta),
+            "process" => (" Processes ".to_string(), Color::Yellow),
+            "correlation" => (" Correlations ".to_string(), Color::Blue),
+            "notifications" => (" Notifications ".to_string(), Color::Magenta),
+            other => (format!(" {other} "), Color::DarkGray),
         };
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color));
+
+        // Power shares the memory slot when energy counters are readable and
+        // the layout has no power panel of its own
+        if name == "memory" && !standalone_power && !self.power_panel.collector.domains().is_empty()
+        {
+            let halves = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(area);
+            self.render_panel(frame, "power", halves[1], true);
+            frame.render_widget(Paragraph::new(self.panel_text(name)).block(block), halves[0]);
+            return;
+        }
 
-        frame.render_widget(Paragraph::new(mem_content).block(mem_block), chunks[1]);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        match name {
+            "power" => frame.render_widget(&self.power_panel, inner),
+            "correlation" => frame.render_widget(&self.correlation_panel, inner),
+            "notifications" => frame.render_widget(&self.notifications, inner),
+            _ => frame.render_widget(Paragraph::new(self.panel_text(name)), inner),
+        }
+    }
 
-        // Render process panel
-        let proc_block = Block::default()
-            .title(" Processes ")
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Yellow));
+    /// Text content of the panels drawn as plain paragraphs.
+    fn panel_text(&self, name: &str) -> String {
+        const MIB: f64 = 1024.0 * 1024.0;
+
+        match name {
+            "cpu" => self.state.latest("cpu").map_or_else(
+                || "CPU: collecting...".to_string(),
+                |metrics| {
+                    let percent = metrics.get_gauge("cpu.total").unwrap_or(0.0);
+                    format!("CPU Usage: {percent:.1}%")
+                },
+            ),
+            "memory" => self.state.latest("memory").map_or_else(
+                || "Memory: collecting...".to_string(),
+                |metrics| {
+                    let gib = |key: &str| metrics.get_counter(key).unwrap_or(0) as f64 / GIB;
+
+                    // macOS-specific: show compressed memory
+                    #[cfg(target_os = "macos")]
+                    let extra = {
+                        let compressed_gb = gib("memory.compressed");
+                        if compressed_gb > 0.1 {
+                            format!(" | Compressed: {:.1}G", compressed_gb)
+                        } else {
+                            String::new()
+                        }
+                    };
+                    #[cfg(not(target_os = "macos"))]
+                    let extra = String::new();
+
+                    format!(
+                        "RAM: {:.0}/{:.0}G | Swap: {:.0}/{:.0}G{extra}",
+                        gib("memory.used"),
+                        gib("memory.total"),
+                        gib("memory.swap.used"),
+                        gib("memory.swap.total")
+                    )
+                },
+            ),
+            "swap" => self.state.latest("memory").map_or_else(
+                || "Swap: collecting...".to_string(),
+                |metrics| {
+                    let total = metrics.get_counter("memory.swap.total").unwrap_or(0);
+                    let used = metrics.get_counter("memory.swap.used").unwrap_or(0);
+                    if total == 0 {
+                        return "Swap: none configured".to_string();
+                    }
+                    let percent = used as f64 / total as f64 * 100.0;
+                    format!(
+                        "Swap: {:.1}/{:.1}G ({percent:.0}%)",
+                        used as f64 / GIB,
+                        total as f64 / GIB
+                    )
+                },
+            ),
+            "network" => self.state.latest("network").map_or_else(
+                || "Network: collecting...".to_string(),
+                |metrics| {
+                    let rate = |key: &str| metrics.get_gauge(key).unwrap_or(0.0) / MIB;
+                    format!(
+                        "RX: {:.1} MiB/s | TX: {:.1} MiB/s",
+                        rate("network.rx_bytes_per_sec"),
+                        rate("network.tx_bytes_per_sec")
+                    )
+                },
+            ),
+            "gpu" | "vram" if self.gpu_panel.readings().is_empty() => {
+                "No GPUs detected".to_string()
+            }
+            "gpu" => self.gpu_text(),
+            "vram" => self.vram_text(),
+            "pcie" if self.pcie.links().is_empty() => "No GPU PCIe links found".to_string(),
+            "pcie" => self
+                .pcie
+                .links()
+                .iter()
+                .map(|link| {
+                    let line =
+                        format!("{} {} {}", link.vendor_name(), link.address, link.summary());
+                    match self.pcie_throughput(link) {
+                        Some((tx, rx)) => format!(
+                            "{line} | tx {} rx {}",
+                            format_bytes_rate(tx),
+                            format_bytes_rate(rx)
+                        ),
+                        None => line,
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            "process" => format!("Processes: {}", self.process_panel.collector.count()),
+            _ => "Not available in trueno-monitor".to_string(),
+        }
+    }
 
-        let proc_content = format!("Processes: {}", self.process_panel.collector.count());
+    /// Utilization and temperature of each GPU, one per line.
+    fn gpu_text(&self) -> String {
+        self.gpu_panel
+            .readings()
+            .iter()
+            .map(|gpu| format!("{}: {:.0}% | {:.0}°C", gpu.name, gpu.util, gpu.temperature))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
 
-        frame.render_widget(Paragraph::new(proc_content).block(proc_block), chunks[2]);
+    /// VRAM usage of each GPU, one per line.
+    fn vram_text(&self) -> String {
+        self.gpu_panel
+            .readings()
+            .iter()
+            .map(|gpu| {
+                format!(
+                    "{}: {:.1}/{:.1}G ({:.0}%)",
+                    gpu.name,
+                    gpu.vram_used as f64 / GIB,
+                    gpu.vram_total as f64 / GIB,
+                    gpu.vram_percent()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
 
-        // Render help if visible
-        if self.state.show_help {
-            // Help overlay rendering is handled by ttop::panels::draw_help()
+    /// Host↔device throughput of a link in bytes/second, measured by sysfs
+    /// or else by the vendor library of the GPU at the same address.
+    fn pcie_throughput(&self, link: &PcieLink) -> Option<(f64, f64)> {
+        if let (Some(tx), Some(rx)) = (link.tx_bytes_per_sec, link.rx_bytes_per_sec) {
+            return Some((tx, rx));
         }
+        let gpu = self
+            .gpu_panel
+            .readings()
+            .iter()
+            .find(|gpu| same_pci_address(&gpu.pci_address, &link.address))?;
+        Some((gpu.pcie_tx_bytes_per_sec?, gpu.pcie_rx_bytes_per_sec?))
     }
 
     /// Returns whether the app should quit.
@@ -277,6 +616,256 @@ mod tests {
         assert!(!app.state.show_help);
     }
 
+    #[test]
+    fn test_app_notification_actions() {
+        let mut app = App::new(Config::default());
+        app.notifications = NotificationCenter::new(10);
+        app.notifications.push_at(1, Severity::Warning, "cpu", "usage above 90%");
+        app.notifications.push_at(2, Severity::Info, "net", "link up");
+
+        app.handle_action(Action::Notifications);
+        assert!(app.state.show_notifications);
+        app.handle_action(Action::Down);
+        app.handle_action(Action::Acknowledge);
+        assert_eq!(app.notifications.unacknowledged(), 1);
+        app.handle_action(Action::AcknowledgeAll);
+        assert_eq!(app.notifications.unacknowledged(), 0);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_app_warns_on_degraded_pcie_link() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let gpu = dir.path().join("0000:01:00.0");
+        std::fs::create_dir_all(&gpu).expect("device dir");
+        for (name, content) in [
+            ("class", "0x030000"),
+            ("vendor", "0x10de"),
+            ("current_link_speed", "16.0 GT/s PCIe"),
+            ("current_link_width", "4"),
+            ("max_link_width", "16"),
+        ] {
+            std::fs::write(gpu.join(name), content).expect("sysfs file");
+        }
+
+        let mut app = App::new(Config::default());
+        app.notifications = NotificationCenter::new(10);
+        app.pcie = PcieCollector::with_path(dir.path());
+        app.collect_metrics();
+
+        let alert = app.notifications.iter().find(|n| n.source == "pcie").expect("pcie alert");
+        assert_eq!(alert.severity, Severity::Warning);
+        assert_eq!(alert.message, "NVIDIA GPU 0000:01:00.0 link at Gen4 x4 (max x16)");
+    }
+
+    #[test]
+    fn test_app_alerts_on_memory_errors() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let mc0 = dir.path().join("mc0");
+        std::fs::create_dir_all(&mc0).expect("mc dir");
+        std::fs::write(mc0.join("ce_count"), "0").expect("ce_count");
+        std::fs::write(mc0.join("ue_count"), "0").expect("ue_count");
+        let kmsg = dir.path().join("kmsg");
+        std::fs::write(&kmsg, "3,1,1000,-;NVRM: Xid (PCI:0000:01:00): 31, MMU fault.\n")
+            .expect("kmsg");
+
+        let mut app = App::new(Config::default());
+        app.notifications = NotificationCenter::new(10);
+        app.hw_errors = HwErrorCollector::with_paths(dir.path(), Some(kmsg.clone()));
+        app.collect_metrics();
+        std::fs::write(mc0.join("ce_count"), "3").expect("ce_count");
+        // Only XIDs logged after startup alert
+        let mut log = std::fs::OpenOptions::new().append(true).open(&kmsg).expect("kmsg");
+        std::io::Write::write_all(
+            &mut log,
+            b"3,2,2000,-;NVRM: Xid (PCI:0000:01:00): 79, GPU has fallen off the bus.\n",
+        )
+        .expect("append");
+        app.collect_metrics();
+
+        let alerts: Vec<_> = app
+            .notifications
+            .iter()
+            .map(|n| (n.severity, n.source.as_str(), n.message.as_str()))
+            .collect();
+        assert!(alerts.contains(&(
+            Severity::Critical,
+            "0000:01:00",
+            "XID 79: GPU has fallen off the bus"
+        )));
+        assert!(alerts.contains(&(Severity::Warning, "mc0", "corrected ECC errors")));
+        assert!(!alerts.iter().any(|(_, _, message)| message.starts_with("XID 31")));
+    }
+
+    #[test]
+    fn test_app_warns_near_fd_limit() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let write = |rel: &str, content: &str| {
+            let path = dir.path().join(rel);
+            std::fs::create_dir_all(path.parent().expect("parent")).expect("dir");
+            std::fs::write(path, content).expect("write");
+        };
+        write("sys/fs/file-nr", "100\t0\t10000\n");
+        write("42/status", "Name:\tpostgres\nThreads:\t4\n");
+        write(
+            "42/limits",
+            "Max open files            10                   4096                 files\n",
+        );
+        for fd in 0..9 {
+            write(&format!("42/fd/{fd}"), "");
+        }
+
+        let mut app = App::new(Config::default());
+        app.notifications = NotificationCenter::new(10);
+        app.limits = LimitsCollector::with_proc_path(dir.path());
+        app.collect_metrics();
+
+        let alert = app.notifications.iter().find(|n| n.source == "limits").expect("alert");
+        assert_eq!(alert.severity, Severity::Warning);
+        assert_eq!(alert.message, "postgres (pid 42) near its open file limit of 10");
+    }
+
+    #[test]
+    fn test_app_alerts_on_close_wait_growth() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let tcp = dir.path().join("tcp");
+        let mut app = App::new(Config::default());
+        app.notifications = NotificationCenter::new(10);
+        app.network =
+            NetworkCollector::new().with_tcp_paths(vec![tcp.clone()]).tcp_growth_detection(3, 2);
+
+        let mut table = String::from("  sl  local_address rem_address   st\n");
+        for _ in 0..3 {
+            table.push_str("   0: 0100007F:1F90 0100007F:A2B4 08\n");
+            std::fs::write(&tcp, &table).expect("tcp");
+            app.collect_metrics();
+        }
+
+        let alert = app.notifications.iter().find(|n| n.source == "tcp").expect("alert");
+        assert_eq!(alert.severity, Severity::Warning);
+        assert!(alert.message.starts_with("CLOSE_WAIT"));
+    }
+
+    #[test]
+    fn test_app_acknowledged_alert_stays_acknowledged() {
+        let mut app = App::new(Config::default());
+        app.notifications = NotificationCenter::new(10);
+        let tick = |app: &mut App, load: f64| {
+            let mut cpu = crate::monitor::types::Metrics::new();
+            cpu.insert("cpu.total", load);
+            app.state.record("cpu", cpu, 8);
+            app.check_alerts();
+        };
+
+        tick(&mut app, 99.0);
+        assert_eq!(app.notifications.unacknowledged(), 1);
+        app.handle_action(Action::AcknowledgeAll);
+
+        // Still overloaded: the acknowledged alert is not re-armed
+        tick(&mut app, 99.0);
+        assert_eq!(app.notifications.unacknowledged(), 0);
+        assert_eq!(app.notifications.len(), 1);
+
+        // Cleared, then overloaded again: a new alert
+        tick(&mut app, 10.0);
+        tick(&mut app, 99.0);
+        assert_eq!(app.notifications.unacknowledged(), 1);
+    }
+
+    #[test]
+    fn test_app_correlation_panel() {
+        let mut app = App::new(Config::default());
+        app.handle_action(Action::Correlations);
+        assert!(app.show_correlations);
+
+        let mut cpu = crate::monitor::types::Metrics::new();
+        cpu.insert("cpu.total", 50.0);
+        app.state.record("cpu", cpu, 8);
+        app.correlation_panel.update(&app.state);
+
+        let backend = ratatui::backend::TestBackend::new(60, 30);
+        let mut terminal = Terminal::new(backend).expect("terminal");
+        terminal.draw(|frame| app.render(frame)).expect("draw");
+        let screen = format!("{:?}", terminal.backend().buffer());
+        assert!(screen.contains(" Correlations "));
+        assert!(screen.contains("collecting"));
+    }
+
+    #[test]
+    fn test_app_gpu_panels_and_temperature_alert() {
+        use crate::monitor::panels::GpuReading;
+
+        let mut app = App::new(Config::default());
+        app.notifications = NotificationCenter::new(10);
+        assert_eq!(app.panel_text("gpu"), "No GPUs detected");
+
+        let reading = |temperature: f64| GpuReading {
+            name: "RTX 4090".to_string(),
+            util: 97.0,
+            temperature,
+            vram_used: 12 << 30,
+            vram_total: 24 << 30,
+            ..GpuReading::default()
+        };
+        app.gpu_panel.set_readings(vec![reading(70.0)]);
+        assert_eq!(app.panel_text("gpu"), "RTX 4090: 97% | 70°C");
+        assert_eq!(app.panel_text("vram"), "RTX 4090: 12.0/24.0G (50%)");
+        app.check_alerts();
+        assert_eq!(app.notifications.unacknowledged(), 0);
+
+        app.gpu_panel.set_readings(vec![reading(90.0)]);
+        app.check_alerts();
+        app.gpu_panel.set_readings(vec![reading(91.0)]);
+        app.check_alerts();
+        assert_eq!(app.notifications.unacknowledged(), 1);
+    }
+
+    #[test]
+    fn test_app_uses_profile() {
+        let mut config = Config::default();
+        config.apply_profile("ml-node").expect("known profile");
+        let app = App::new(config);
+        assert!(app.layout.current().rows.iter().any(|r| r.panels.contains(&"pcie".to_string())));
+        assert!((app.thresholds.swap_percent - 10.0).abs() < f64::EPSILON);
+
+        let backend = ratatui::backend::TestBackend::new(80, 30);
+        let mut terminal = Terminal::new(backend).expect("terminal");
+        terminal.draw(|frame| app.render(frame)).expect("draw");
+        let screen = format!("{:?}", terminal.backend().buffer());
+        for title in [" GPU ", " VRAM ", " GPU PCIe ", " Swap ", " CPU ", " Memory ", " Processes "]
+        {
+            assert!(screen.contains(title), "ml-node should draw{title}");
+        }
+    }
+
+    #[test]
+    fn test_app_profiles_only_use_renderable_panels() {
+        for profile in crate::monitor::presets::all_profiles() {
+            for panel in profile.preset.rows.iter().flat_map(|r| &r.panels) {
+                assert!(App::PANELS.contains(&panel.as_str()), "{}: {panel}", profile.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_app_unknown_panel_placeholder() {
+        let mut app = App::new(Config::default());
+        app.layout.add_preset(crate::monitor::layout::Preset {
+            rows: vec![crate::monitor::layout::LayoutRow {
+                panels: vec!["training".to_string()],
+                height: ratatui::layout::Constraint::Percentage(100),
+            }],
+        });
+        app.layout.switch_to(1);
+
+        let backend = ratatui::backend::TestBackend::new(40, 6);
+        let mut terminal = Terminal::new(backend).expect("terminal");
+        terminal.draw(|frame| app.render(frame)).expect("draw");
+        let screen = format!("{:?}", terminal.backend().buffer());
+        assert!(screen.contains(" training "));
+        assert!(screen.contains("Not available"));
+    }
+
     #[test]
     fn test_app_default() {
         let app = App::default();
@@ -1,12 +1,15 @@
 //! Disk metrics collector.
 //!
 //! Parses `/proc/diskstats` and `/sys/block/` on Linux to collect disk I/O metrics.
+//! Per-device latency percentiles are estimated from the same counters (see
+//! [`super::io_latency`]).
 //!
 //! ## Falsification Criteria
 //!
 //! - #39: Disk IO matches `iostat` within ±5%
 //! - #49: Disk mount points match `df` output
 
+use super::io_latency::IoLatency;
 use crate::monitor::error::Result;
 use crate::monitor::ring_buffer::RingBuffer;
 use crate::monitor::subprocess::run_with_timeout;
@@ -124,6 +127,8 @@ pub struct DiskCollector {
     mounts: Vec<MountInfo>,
     /// Sector size (typically 512 bytes).
     sector_size: u64,
+    /// Request latency window per device.
+    latency: HashMap<String, IoLatency>,
 }
 
 impl DiskCollector {
@@ -138,6 +143,7 @@ impl DiskCollector {
             write_history: RingBuffer::new(300),
             mounts: Vec::new(),
             sector_size: 512,
+            latency: HashMap::new(),
         }
     }
 
@@ -539,6 +545,39 @@ impl DiskCollector {
         &self.mounts
     }
 
+    /// Returns the latency window of a device (e.g. "nvme0n1", "sda1").
+    #[must_use]
+    pub fn latency(&self, device: &str) -> Option<&IoLatency> {
+        self.latency.get(device)
+    }
+
+    /// Returns the latency window of the device backing a mount, falling
+    /// back to the whole disk when the partition has no samples.
+    #[must_use]
+    pub fn mount_latency(&self, mount: &MountInfo) -> Option<&IoLatency> {
+        let device = mount.device.rsplit('/').next().unwrap_or(&mount.device);
+        let disk = if device.starts_with("nvme") || device.starts_with("mmcblk") {
+            device.rsplit_once('p').map_or(device, |(disk, _)| disk)
+        } else {
+            device.trim_end_matches(|c: char| c.is_ascii_digit())
+        };
+        self.latency(device).filter(|l| !l.is_empty()).or_else(|| self.latency(disk))
+    }
+
+    /// Records each device's request latency over the last interval.
+    fn update_latency(&mut self, current: &HashMap<String, DiskStats>) {
+        self.latency.retain(|name, _| current.contains_key(name));
+        for (name, curr) in current {
+            if let Some(prev) = self.prev_stats.get(name) {
+                let ios = (curr.reads_completed + curr.writes_completed)
+                    .saturating_sub(prev.reads_completed + prev.writes_completed);
+                let busy_ms = (curr.read_time_ms + curr.write_time_ms)
+                    .saturating_sub(prev.read_time_ms + prev.write_time_ms);
+                self.latency.entry(name.clone()).or_default().record(ios, busy_ms);
+            }
+        }
+    }
+
     /// Returns total read throughput history (normalized 0-1).
     #[must_use]
     pub fn read_history(&self) -> &RingBuffer<f64> {
@@ -586,6 +625,11 @@ impl Collector for DiskCollector {
             }
         }
 
+        // macOS iostat reports no per-request service time
+        if cfg!(target_os = "linux") {
+            self.update_latency(&current_stats);
+        }
+
         // Update previous state
         self.prev_stats = current_stats;
         self.prev_time = Some(now);
@@ -613,6 +657,15 @@ impl Collector for DiskCollector {
         // Mount count
         metrics.insert("disk.mount_count", MetricValue::Counter(self.mounts.len() as u64));
 
+        // Latency percentiles per device, e.g. disk.sda.latency_p99_ms
+        for (name, latency) in &self.latency {
+            if let Some(p) = latency.percentiles() {
+                metrics.insert(format!("disk.{name}.latency_p50_ms"), MetricValue::Gauge(p.p50));
+                metrics.insert(format!("disk.{name}.latency_p95_ms"), MetricValue::Gauge(p.p95));
+                metrics.insert(format!("disk.{name}.latency_p99_ms"), MetricValue::Gauge(p.p99));
+            }
+        }
+
         Ok(metrics)
     }
 
@@ -775,6 +828,36 @@ mod tests {
         assert!((sda_rates.io_utilization - 50.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_mount_latency_falls_back_to_disk() {
+        let mut collector = DiskCollector::new();
+        let stats = |name: &str, ios: u64, ms: u64| DiskStats {
+            name: name.to_string(),
+            reads_completed: ios,
+            read_time_ms: ms,
+            ..Default::default()
+        };
+        for (name, ios, ms) in [("nvme0n1", 0, 0), ("nvme0n1p2", 0, 0)] {
+            collector.prev_stats.insert(name.to_string(), stats(name, ios, ms));
+        }
+        let current: HashMap<_, _> = [("nvme0n1", 10, 20), ("nvme0n1p2", 0, 0)]
+            .into_iter()
+            .map(|(name, ios, ms)| (name.to_string(), stats(name, ios, ms)))
+            .collect();
+        collector.update_latency(&current);
+
+        let mount = MountInfo {
+            device: "/dev/nvme0n1p2".to_string(),
+            mount_point: "/".to_string(),
+            fs_type: "ext4".to_string(),
+            total_bytes: 0,
+            used_bytes: 0,
+            available_bytes: 0,
+        };
+        let latency = collector.mount_latency(&mount).expect("disk latency");
+        assert!((latency.percentiles().expect("percentiles").p50 - 2.0).abs() < 1e-9);
+    }
+
     // ========================================================================
     // Linux-specific Tests
     // ========================================================================
@@ -0,0 +1,451 @@
+//! Energy consumption collector.
+//!
+//! Accumulates the energy drawn since the collector started, from:
+//!
+//! - **Intel RAPL** (and AMD RAPL on Linux 5.8+): `/sys/class/powercap/intel-rapl:*`
+//! - **AMD energy MSRs**: the `amd_energy` hwmon driver
+//! - **macOS SMC**: system power telemetry reported through `ioreg`
+//!
+//! Counter sources are differenced between collections (handling
+//! wrap-around); power sources are integrated over the collection interval.
+//! Nested zones (RAPL `core`/`uncore` inside a package, per-core AMD
+//! counters) are shown but not added to the total; when the platform
+//! reports `psys` it alone is the total.
+//!
+//! RAPL counters are root-only on Linux 5.10+; unreadable domains are
+//! skipped.
+
+use crate::monitor::error::Result;
+use crate::monitor::types::{Collector, MetricValue, Metrics};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Joules per kilowatt-hour.
+pub const JOULES_PER_KWH: f64 = 3_600_000.0;
+
+/// Where an energy domain is read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnergySource {
+    /// Linux powercap RAPL zone.
+    Rapl,
+    /// AMD `amd_energy` hwmon counter.
+    AmdEnergy,
+    /// macOS SMC power telemetry.
+    Smc,
+}
+
+/// A measured energy domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnergyDomain {
+    /// Domain name (e.g., "package-0", "dram", "Esocket0", "system").
+    pub name: String,
+    /// Where the domain is read from.
+    pub source: EnergySource,
+    /// Whether the domain counts towards the total.
+    pub counted: bool,
+    /// Power over the last interval in watts.
+    pub watts: f64,
+    /// Energy accumulated this session in joules.
+    pub joules: f64,
+    /// Unique id (sysfs path or source name).
+    key: String,
+}
+
+/// A single raw reading.
+#[derive(Debug, Clone, Copy)]
+enum Reading {
+    /// Cumulative counter in µJ, wrapping at `range_uj` (0 if unknown).
+    Counter { uj: u64, range_uj: u64 },
+    /// Instantaneous power (SMC only).
+    #[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+    Power { watts: f64 },
+}
+
+/// A domain discovered in one pass, with its reading.
+#[derive(Debug)]
+struct Probe {
+    key: String,
+    name: String,
+    source: EnergySource,
+    counted: bool,
+    reading: Reading,
+}
+
+/// Collector for CPU/package/system energy consumption.
+#[derive(Debug)]
+pub struct EnergyCollector {
+    /// Powercap base path.
+    powercap_path: PathBuf,
+    /// Hwmon base path.
+    hwmon_path: PathBuf,
+    /// Domains in discovery order.
+    domains: Vec<EnergyDomain>,
+    /// Last counter value per domain key.
+    last_counter: HashMap<String, u64>,
+    /// Time of the previous collection.
+    last_sample: Option<Instant>,
+    /// Time of the first collection.
+    started: Option<Instant>,
+}
+
+impl EnergyCollector {
+    /// Creates a new energy collector.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_paths("/sys/class/powercap", "/sys/class/hwmon")
+    }
+
+    /// Creates a collector reading powercap and hwmon from the given paths.
+    #[must_use]
+    pub fn with_paths(powercap: impl Into<PathBuf>, hwmon: impl Into<PathBuf>) -> Self {
+        Self {
+            powercap_path: powercap.into(),
+            hwmon_path: hwmon.into(),
+            domains: Vec::new(),
+            last_counter: HashMap::new(),
+            last_sample: None,
+            started: None,
+        }
+    }
+
+    /// Returns the measured domains.
+    #[must_use]
+    pub fn domains(&self) -> &[EnergyDomain] {
+        &self.domains
+    }
+
+    /// Returns the total power over the last interval in watts.
+    #[must_use]
+    pub fn total_watts(&self) -> f64 {
+        self.domains.iter().filter(|d| d.counted).fold(0.0, |sum, d| sum + d.watts)
+    }
+
+    /// Returns the energy used this session in joules.
+    #[must_use]
+    pub fn session_joules(&self) -> f64 {
+        self.domains.iter().filter(|d| d.counted).fold(0.0, |sum, d| sum + d.joules)
+    }
+
+    /// Returns the energy used this session in kilowatt-hours.
+    #[must_use]
+    pub fn session_kwh(&self) -> f64 {
+        self.session_joules() / JOULES_PER_KWH
+    }
+
+    /// Returns the estimated cost of this session at `price_per_kwh`.
+    #[must_use]
+    pub fn session_cost(&self, price_per_kwh: f64) -> f64 {
+        self.session_kwh() * price_per_kwh
+    }
+
+    /// Returns the time since the first collection.
+    #[must_use]
+    pub fn session_duration(&self) -> Duration {
+        self.started.map_or(Duration::ZERO, |t| t.elapsed())
+    }
+
+    /// Reads RAPL zones from powercap.
+    fn read_rapl(&self) -> Vec<Probe> {
+        let Ok(entries) = std::fs::read_dir(&self.powercap_path) else {
+            return Vec::new();
+        };
+        let mut zones: Vec<(String, PathBuf)> = entries
+            .filter_map(std::result::Result::ok)
+            .map(|e| (e.file_name().to_string_lossy().to_string(), e.path()))
+            .filter(|(name, _)| name.starts_with("intel-rapl:"))
+            .collect();
+        zones.sort();
+
+        let mut probes: Vec<Probe> = zones
+            .iter()
+            .filter_map(|(dir, path)| {
+                let uj = read_u64(&path.join("energy_uj"))?;
+                let range_uj = read_u64(&path.join("max_energy_range_uj")).unwrap_or(0);
+                let name = read_string(&path.join("name")).unwrap_or_else(|| dir.clone());
+                Some(Probe {
+                    key: path.display().to_string(),
+                    // Sub-zones ("intel-rapl:0:0") are part of their package
+                    counted: dir.matches(':').count() == 1,
+                    name,
+                    source: EnergySource::Rapl,
+                    reading: Reading::Counter { uj, range_uj },
+                })
+            })
+            .collect();
+
+        // psys covers the whole SoC, packages and DRAM included
+        if probes.iter().any(|p| p.counted && p.name == "psys") {
+            for probe in &mut probes {
+                probe.counted = probe.name == "psys";
+            }
+        }
+        probes
+    }
+
+    /// Reads `amd_energy` hwmon counters.
+    fn read_amd_energy(&self) -> Vec<Probe> {
+        let Ok(entries) = std::fs::read_dir(&self.hwmon_path) else {
+            return Vec::new();
+        };
+        let mut hwmons: Vec<PathBuf> = entries
+            .filter_map(std::result::Result::ok)
+            .map(|e| e.path())
+            .filter(|p| read_string(&p.join("name")).as_deref() == Some("amd_energy"))
+            .collect();
+        hwmons.sort();
+
+        let mut probes = Vec::new();
+        for hwmon in hwmons {
+            for n in 1.. {
+                let Some(uj) = read_u64(&hwmon.join(format!("energy{n}_input"))) else {
+                    break;
+                };
+                let name = read_string(&hwmon.join(format!("energy{n}_label")))
+                    .unwrap_or_else(|| format!("energy{n}"));
+                probes.push(Probe {
+                    key: hwmon.join(format!("energy{n}_input")).display().to_string(),
+                    counted: name.starts_with("Esocket"),
+                    name,
+                    source: EnergySource::AmdEnergy,
+                    reading: Reading::Counter { uj, range_uj: 0 },
+                });
+            }
+        }
+        probes
+    }
+
+    /// Reads system power from the SMC telemetry.
+    #[cfg(target_os = "macos")]
+    fn read_smc() -> Vec<Probe> {
+        use crate::monitor::subprocess::run_with_timeout_stdout;
+
+        run_with_timeout_stdout(
+            "ioreg",
+            &["-rw0", "-c", "AppleSmartBattery"],
+            Duration::from_millis(500),
+        )
+        .and_then(|out| parse_system_power_mw(&out))
+        .map(|mw| Probe {
+            key: "smc".to_string(),
+            name: "system".to_string(),
+            source: EnergySource::Smc,
+            counted: true,
+            reading: Reading::Power { watts: mw / 1000.0 },
+        })
+        .into_iter()
+        .collect()
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    fn read_smc() -> Vec<Probe> {
+        Vec::new()
+    }
+
+    /// Reads all sources. AMD counters are only used without RAPL, which
+    /// reports the same packages.
+    fn read_probes(&self) -> Vec<Probe> {
+        let mut probes = self.read_rapl();
+        if probes.is_empty() {
+            probes = self.read_amd_energy();
+        }
+        probes.extend(Self::read_smc());
+        probes
+    }
+
+    /// Updates domains from `probes` taken `dt` seconds after the previous
+    /// pass (`None` on the first).
+    fn update(&mut self, probes: Vec<Probe>, dt: Option<f64>) {
+        let mut domains = Vec::with_capacity(probes.len());
+        for probe in probes {
+            let previous = self.domains.iter().find(|d| d.key == probe.key);
+            let joules = previous.map_or(0.0, |d| d.joules);
+            let delta = match (probe.reading, dt) {
+                (Reading::Counter { uj, range_uj }, _) => {
+                    self.last_counter.insert(probe.key.clone(), uj).map(|last| {
+                        let delta_uj = if uj >= last {
+                            uj - last
+                        } else if range_uj > last {
+                            range_uj - last + uj
+                        } else {
+                            0
+                        };
+                        delta_uj as f64 / 1_000_000.0
+                    })
+                }
+                (Reading::Power { watts }, Some(dt)) => Some(watts * dt),
+                (Reading::Power { .. }, None) => None,
+            };
+            let watts = match (probe.reading, delta, dt) {
+                (Reading::Power { watts }, _, _) => watts,
+                (_, Some(delta), Some(dt)) if dt > 0.0 => delta / dt,
+                _ => 0.0,
+            };
+            domains.push(EnergyDomain {
+                name: probe.name,
+                source: probe.source,
+                counted: probe.counted,
+                watts,
+                joules: joules + delta.unwrap_or(0.0),
+                key: probe.key,
+            });
+        }
+        self.domains = domains;
+    }
+}
+
+/// Parses `"SystemPowerIn"=<mW>` from `ioreg -c AppleSmartBattery` output.
+#[must_use]
+pub fn parse_system_power_mw(ioreg: &str) -> Option<f64> {
+    let key = "\"SystemPowerIn\"=";
+    let rest = &ioreg[ioreg.find(key)? + key.len()..];
+    let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+    digits.parse().ok()
+}
+
+/// Reads a sysfs file as u64.
+fn read_u64(path: &Path) -> Option<u64> {
+    std::fs::read_to_string(path).ok().and_then(|s| s.trim().parse().ok())
+}
+
+/// Reads a sysfs file as a trimmed string.
+fn read_string(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path).ok().map(|s| s.trim().to_string())
+}
+
+impl Default for EnergyCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for EnergyCollector {
+    fn id(&self) -> &'static str {
+        "energy"
+    }
+
+    fn collect(&mut self) -> Result<Metrics> {
+        let now = Instant::now();
+        let dt = self.last_sample.map(|t| now.duration_since(t).as_secs_f64());
+        let probes = self.read_probes();
+        self.update(probes, dt);
+        self.last_sample = Some(now);
+        self.started.get_or_insert(now);
+
+        let mut metrics = Metrics::new();
+        metrics.insert("energy.watts", self.total_watts());
+        metrics.insert("energy.session_joules", self.session_joules());
+        metrics.insert("energy.session_kwh", self.session_kwh());
+        metrics.insert("energy.domain_count", MetricValue::Counter(self.domains.len() as u64));
+        for (i, domain) in self.domains.iter().enumerate() {
+            metrics.insert(format!("energy.{i}.watts"), domain.watts);
+            metrics.insert(format!("energy.{i}.joules"), domain.joules);
+        }
+        Ok(metrics)
+    }
+
+    fn is_available(&self) -> bool {
+        // SMC telemetry needs a subprocess; probe it only when collecting
+        cfg!(target_os = "macos")
+            || !self.read_rapl().is_empty()
+            || !self.read_amd_energy().is_empty()
+    }
+
+    fn interval_hint(&self) -> Duration {
+        Duration::from_millis(1000)
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Energy"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_zone(root: &Path, dir: &str, name: &str, uj: u64) {
+        let zone = root.join(dir);
+        std::fs::create_dir_all(&zone).expect("zone dir");
+        std::fs::write(zone.join("name"), name).expect("name");
+        std::fs::write(zone.join("energy_uj"), uj.to_string()).expect("energy_uj");
+        std::fs::write(zone.join("max_energy_range_uj"), "1000000000").expect("range");
+    }
+
+    #[test]
+    fn test_rapl_totals_skip_subzones_and_wrap() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        write_zone(dir.path(), "intel-rapl:0", "package-0", 999_000_000);
+        write_zone(dir.path(), "intel-rapl:0:0", "core", 500_000_000);
+        let mut collector = EnergyCollector::with_paths(dir.path(), "/nonexistent");
+        collector.update(collector.read_probes(), None);
+        assert!(collector.total_watts().abs() < f64::EPSILON);
+
+        // Package wraps at 1000 J: 999 J -> 19 J is 20 J over 2 s
+        write_zone(dir.path(), "intel-rapl:0", "package-0", 19_000_000);
+        write_zone(dir.path(), "intel-rapl:0:0", "core", 510_000_000);
+        collector.update(collector.read_probes(), Some(2.0));
+
+        assert!((collector.total_watts() - 10.0).abs() < 1e-9);
+        assert!((collector.session_joules() - 20.0).abs() < 1e-9);
+        let core = &collector.domains()[1];
+        assert_eq!((core.name.as_str(), core.counted), ("core", false));
+        assert!((core.joules - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_psys_is_the_total() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        write_zone(dir.path(), "intel-rapl:0", "package-0", 0);
+        write_zone(dir.path(), "intel-rapl:1", "psys", 0);
+        let collector = EnergyCollector::with_paths(dir.path(), "/nonexistent");
+        let counted: Vec<String> =
+            collector.read_probes().into_iter().filter(|p| p.counted).map(|p| p.name).collect();
+        assert_eq!(counted, vec!["psys".to_string()]);
+    }
+
+    #[test]
+    fn test_amd_energy_sockets() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let hwmon = dir.path().join("hwmon3");
+        std::fs::create_dir_all(&hwmon).expect("hwmon dir");
+        std::fs::write(hwmon.join("name"), "amd_energy\n").expect("name");
+        std::fs::write(hwmon.join("energy1_input"), "1000000").expect("input");
+        std::fs::write(hwmon.join("energy1_label"), "Ecore000").expect("label");
+        std::fs::write(hwmon.join("energy2_input"), "5000000").expect("input");
+        std::fs::write(hwmon.join("energy2_label"), "Esocket0").expect("label");
+
+        let mut collector = EnergyCollector::with_paths("/nonexistent", dir.path());
+        assert!(collector.is_available());
+        collector.update(collector.read_probes(), None);
+        std::fs::write(hwmon.join("energy2_input"), "3605000000").expect("input");
+        collector.update(collector.read_probes(), Some(1.0));
+
+        assert_eq!(collector.domains().len(), 2);
+        assert!((collector.session_kwh() - 0.001).abs() < 1e-12);
+        assert!((collector.session_cost(0.30) - 0.0003).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_power_source_integrates() {
+        let mut collector = EnergyCollector::with_paths("/nonexistent", "/nonexistent");
+        let probe = || Probe {
+            key: "smc".to_string(),
+            name: "system".to_string(),
+            source: EnergySource::Smc,
+            counted: true,
+            reading: Reading::Power { watts: 12.5 },
+        };
+        collector.update(vec![probe()], None);
+        collector.update(vec![probe()], Some(4.0));
+        assert!((collector.total_watts() - 12.5).abs() < 1e-9);
+        assert!((collector.session_joules() - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_system_power_mw() {
+        let ioreg = r#"  "PowerTelemetryData" = {"SystemPowerIn"=15234,"SystemLoad"=9000}"#;
+        assert_eq!(parse_system_power_mw(ioreg), Some(15234.0));
+        assert_eq!(parse_system_power_mw("no telemetry"), None);
+    }
+}
@@ -60,6 +60,9 @@ pub struct AmdGpuInfo {
     pub index: u32,
     /// GPU name/model.
     pub name: String,
+    /// PCI address in sysfs form (e.g., "0000:03:00.0"), matching
+    /// [`PcieLink::address`](super::PcieLink::address); empty if unknown.
+    pub pci_address: String,
     /// GPU utilization percentage (0-100).
     pub gpu_util: f64,
     /// Memory utilization percentage (0-100).
@@ -103,6 +106,7 @@ struct RocmSmi {
     rsmi_dev_power_ave_get: unsafe extern "C" fn(u32, u32, *mut u64) -> i32,
     rsmi_dev_power_cap_get: unsafe extern "C" fn(u32, u32, *mut u64) -> i32,
     rsmi_dev_pci_throughput_get: unsafe extern "C" fn(u32, *mut u64, *mut u64, *mut u64) -> i32,
+    rsmi_dev_pci_id_get: unsafe extern "C" fn(u32, *mut u64) -> i32,
 }
 
 // SAFETY: All unsafe blocks in this impl are FFI calls to the ROCm SMI library.
@@ -172,6 +176,7 @@ impl RocmSmi {
                 rsmi_dev_power_ave_get: load_fn!(rsmi_dev_power_ave_get),
                 rsmi_dev_power_cap_get: load_fn!(rsmi_dev_power_cap_get),
                 rsmi_dev_pci_throughput_get: load_fn!(rsmi_dev_pci_throughput_get),
+                rsmi_dev_pci_id_get: load_fn!(rsmi_dev_pci_id_get),
             };
 
             // Initialize the library
@@ -317,6 +322,31 @@ impl RocmSmi {
         }
         (tx, rx)
     }
+
+    fn pci_address(&self, index: u32) -> String {
+        let mut bdfid: u64 = 0;
+        // SAFETY: FFI call with a valid pointer to a stack-allocated u64.
+        let status = unsafe { (self.rsmi_dev_pci_id_get)(index, &mut bdfid) };
+        if status == rsmi_status::RSMI_STATUS_SUCCESS {
+            format_bdfid(bdfid)
+        } else {
+            String::new()
+        }
+    }
+}
+
+/// Formats a ROCm SMI BDF id as a sysfs PCI address.
+///
+/// The id packs the domain in bits 32-63, the bus in bits 8-15, the device
+/// in bits 3-7 and the function in bits 0-2.
+fn format_bdfid(bdfid: u64) -> String {
+    format!(
+        "{:04x}:{:02x}:{:02x}.{:x}",
+        (bdfid >> 32) & 0xffff_ffff,
+        (bdfid >> 8) & 0xff,
+        (bdfid >> 3) & 0x1f,
+        bdfid & 0x7
+    )
 }
 
 #[allow(unsafe_code)]
@@ -441,6 +471,7 @@ impl AmdGpuCollector {
             gpus.push(AmdGpuInfo {
                 index: i,
                 name,
+                pci_address: rsmi.pci_address(i),
                 gpu_util,
                 mem_util,
                 vram_used,
@@ -544,6 +575,7 @@ mod tests {
         let info = AmdGpuInfo {
             index: 0,
             name: "AMD RX 7900 XTX".to_string(),
+            pci_address: "0000:03:00.0".to_string(),
             gpu_util: 50.0,
             mem_util: 30.0,
             vram_used: 8 * 1024 * 1024 * 1024,
@@ -561,4 +593,11 @@ mod tests {
         assert_eq!(info.index, 0);
         assert_eq!(info.gpu_util, 50.0);
     }
+
+    #[test]
+    fn test_format_bdfid() {
+        assert_eq!(format_bdfid(0x0300), "0000:03:00.0");
+        // domain 1, bus 0xc1, device 0x1f, function 3
+        assert_eq!(format_bdfid((1 << 32) | (0xc1 << 8) | (0x1f << 3) | 3), "0001:c1:1f.3");
+    }
 }
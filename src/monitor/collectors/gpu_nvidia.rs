@@ -16,12 +16,15 @@
 //! - GPU clock speed (MHz)
 //! - Memory clock speed (MHz)
 //! - Fan speed percentage
+//! - PCIe link generation/width
+//! - ECC error counts (volatile, since driver load)
 //! - PCIe throughput (optional)
 
+use super::pcie::PcieLink;
 use crate::monitor::error::{MonitorError, Result};
 use crate::monitor::ring_buffer::RingBuffer;
 use crate::monitor::types::{Collector, MetricValue, Metrics};
-use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
+use nvml_wrapper::enum_wrappers::device::{EccCounter, MemoryError, TemperatureSensor};
 use nvml_wrapper::Nvml;
 use std::time::Duration;
 
@@ -56,6 +59,12 @@ pub struct GpuInfo {
     pub pcie_tx_kbps: Option<u32>,
     /// PCIe RX throughput in KB/s, if measured.
     pub pcie_rx_kbps: Option<u32>,
+    /// PCIe link state, if NVML reports it.
+    pub pcie_link: Option<PcieLink>,
+    /// Corrected ECC errors since driver load, if ECC is enabled.
+    pub ecc_corrected: Option<u64>,
+    /// Uncorrected ECC errors since driver load, if ECC is enabled.
+    pub ecc_uncorrected: Option<u64>,
 }
 
 /// Collector for NVIDIA GPU metrics via NVML.
@@ -211,6 +220,26 @@ impl NvidiaGpuCollector {
                 (None, None)
             };
 
+            // PCIe link state
+            let link_value =
+                |v: std::result::Result<u32, _>| v.ok().and_then(|v| u8::try_from(v).ok());
+            let current_width = link_value(device.current_pcie_link_width());
+            let pcie_link = current_width.map(|width| PcieLink {
+                address: device.pci_info().map(|p| p.bus_id).unwrap_or_default(),
+                vendor: 0x10de,
+                current_gen: link_value(device.current_pcie_link_gen()),
+                current_width: Some(width),
+                max_gen: link_value(device.max_pcie_link_gen()),
+                max_width: link_value(device.max_pcie_link_width()),
+                tx_bytes_per_sec: pcie_tx_kbps.map(|kb| f64::from(kb) * 1024.0),
+                rx_bytes_per_sec: pcie_rx_kbps.map(|kb| f64::from(kb) * 1024.0),
+            });
+
+            // ECC counters (errors when ECC is disabled or unsupported)
+            let ecc = |error| device.total_ecc_errors(error, EccCounter::Volatile).ok();
+            let ecc_corrected = ecc(MemoryError::Corrected);
+            let ecc_uncorrected = ecc(MemoryError::Uncorrected);
+
             gpus.push(GpuInfo {
                 index: i,
                 name,
@@ -226,6 +255,9 @@ impl NvidiaGpuCollector {
                 fan_speed,
                 pcie_tx_kbps,
                 pcie_rx_kbps,
+                pcie_link,
+                ecc_corrected,
+                ecc_uncorrected,
             });
         }
 
@@ -299,6 +331,32 @@ impl Collector for NvidiaGpuCollector {
                     .insert(format!("{prefix}.pcie_rx_kbps"), MetricValue::Counter(u64::from(rx)));
             }
 
+            // PCIe link state
+            if let Some(link) = &gpu.pcie_link {
+                if let Some(gen) = link.current_gen {
+                    metrics
+                        .insert(format!("{prefix}.pcie_gen"), MetricValue::Counter(u64::from(gen)));
+                }
+                if let Some(width) = link.current_width {
+                    metrics.insert(
+                        format!("{prefix}.pcie_width"),
+                        MetricValue::Counter(u64::from(width)),
+                    );
+                }
+                metrics.insert(
+                    format!("{prefix}.pcie_degraded"),
+                    MetricValue::Gauge(if link.is_degraded() { 1.0 } else { 0.0 }),
+                );
+            }
+
+            // ECC errors
+            if let Some(count) = gpu.ecc_corrected {
+                metrics.insert(format!("{prefix}.ecc_corrected"), MetricValue::Counter(count));
+            }
+            if let Some(count) = gpu.ecc_uncorrected {
+                metrics.insert(format!("{prefix}.ecc_uncorrected"), MetricValue::Counter(count));
+            }
+
             // Update history
             if let Some(history) = self.gpu_history.get_mut(i) {
                 history.push(gpu.gpu_util / 100.0);
@@ -382,10 +440,20 @@ mod tests {
             fan_speed: Some(45),
             pcie_tx_kbps: Some(1000),
             pcie_rx_kbps: Some(2000),
+            pcie_link: Some(PcieLink {
+                current_gen: Some(4),
+                current_width: Some(4),
+                max_gen: Some(4),
+                max_width: Some(16),
+                ..PcieLink::default()
+            }),
+            ecc_corrected: Some(0),
+            ecc_uncorrected: None,
         };
 
         assert_eq!(info.index, 0);
         assert_eq!(info.gpu_util, 50.0);
         assert_eq!(info.fan_speed, Some(45));
+        assert!(info.pcie_link.as_ref().is_some_and(PcieLink::is_degraded));
     }
 }
@@ -0,0 +1,494 @@
+//! Hardware memory error collector.
+//!
+//! Tracks host memory controller error counts from EDAC
+//! (`/sys/devices/system/edac/mc/`) and NVIDIA GPU XID events from the
+//! driver's kernel log records (`NVRM: Xid ...` in `/dev/kmsg`). GPU ECC
+//! counters reported by NVML (see `GpuInfo::ecc_corrected`) are fed in with
+//! [`HwErrorCollector::observe_ecc`].
+//!
+//! Corrected errors do not crash anything, which is exactly why they go
+//! unnoticed: a DIMM or HBM stack that corrects more and more errors is
+//! degrading. Every counter increase and XID is therefore kept as an event
+//! in a bounded history and handed out once via
+//! [`HwErrorCollector::take_new_events`] for alerting.
+//!
+//! Counts present when a source is first seen form its baseline and are
+//! reported in metrics, not as events. Likewise, XIDs already in the kernel
+//! ring buffer when the log is first opened go into the history without
+//! being handed out, so a restart does not re-alert on old faults.
+
+use crate::monitor::error::Result;
+use crate::monitor::types::{Collector, MetricValue, Metrics};
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::{BufRead, BufReader, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Default number of events kept in the history.
+pub const DEFAULT_HISTORY: usize = 100;
+
+/// Corrected and uncorrected error counts of one memory source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EccCounts {
+    /// Corrected (single-bit) errors.
+    pub corrected: u64,
+    /// Uncorrected (multi-bit) errors.
+    pub uncorrected: u64,
+}
+
+/// Kind of hardware error event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HwErrorKind {
+    /// Corrected memory errors.
+    Corrected,
+    /// Uncorrected memory errors.
+    Uncorrected,
+    /// NVIDIA XID event with its code.
+    Xid(u32),
+}
+
+/// A hardware error event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HwErrorEvent {
+    /// Unix time of the event, in seconds.
+    pub timestamp: u64,
+    /// Origin, e.g. "mc0" or a GPU PCI address.
+    pub source: String,
+    /// Event kind.
+    pub kind: HwErrorKind,
+    /// Number of errors (counter increase; 1 for XIDs).
+    pub count: u64,
+    /// Driver message, for XIDs.
+    pub detail: String,
+}
+
+impl HwErrorEvent {
+    /// Returns true for errors that lose data or the device.
+    #[must_use]
+    pub fn is_critical(&self) -> bool {
+        match self.kind {
+            HwErrorKind::Corrected => false,
+            HwErrorKind::Uncorrected => true,
+            HwErrorKind::Xid(code) => is_fatal_xid(code),
+        }
+    }
+
+    /// Short description, e.g. "XID 79: GPU has fallen off the bus".
+    #[must_use]
+    pub fn summary(&self) -> String {
+        match self.kind {
+            HwErrorKind::Corrected => "corrected ECC errors".to_string(),
+            HwErrorKind::Uncorrected => "uncorrected ECC errors".to_string(),
+            HwErrorKind::Xid(code) => match xid_description(code) {
+                Some(description) => format!("XID {code}: {description}"),
+                None => format!("XID {code}: {}", self.detail),
+            },
+        }
+    }
+}
+
+/// Describes well-known NVIDIA XID codes.
+#[must_use]
+pub fn xid_description(code: u32) -> Option<&'static str> {
+    match code {
+        13 => Some("graphics engine exception"),
+        31 => Some("GPU memory page fault"),
+        43 => Some("GPU stopped processing"),
+        48 => Some("double bit ECC error"),
+        61 => Some("internal micro-controller breakpoint"),
+        62 => Some("internal micro-controller halt"),
+        63 => Some("ECC page retirement or row remapping recorded"),
+        64 => Some("ECC page retirement or row remapping failure"),
+        74 => Some("NVLink error"),
+        79 => Some("GPU has fallen off the bus"),
+        92 => Some("high single-bit ECC error rate"),
+        94 => Some("contained ECC error"),
+        95 => Some("uncontained ECC error"),
+        119 => Some("GSP RPC timeout"),
+        120 => Some("GSP error"),
+        _ => None,
+    }
+}
+
+/// XIDs after which the GPU or its memory contents cannot be trusted.
+fn is_fatal_xid(code: u32) -> bool {
+    matches!(code, 48 | 62 | 64 | 74 | 79 | 95 | 119 | 120)
+}
+
+/// Parses an NVIDIA driver XID message such as
+/// `NVRM: Xid (PCI:0000:01:00): 79, pid=1234, GPU has fallen off the bus.`
+///
+/// Returns the GPU bus address, the XID code and the remaining text.
+#[must_use]
+pub fn parse_xid(message: &str) -> Option<(String, u32, String)> {
+    let rest = &message[message.find("NVRM: Xid (")? + "NVRM: Xid (".len()..];
+    let (address, rest) = rest.split_once("): ")?;
+    let (code, detail) = rest.split_once(',').unwrap_or((rest, ""));
+    let code = code.trim().parse().ok()?;
+    let address = address.trim_start_matches("PCI:").to_string();
+    Some((address, code, detail.trim().to_string()))
+}
+
+/// Collector for host and GPU memory errors.
+#[derive(Debug)]
+pub struct HwErrorCollector {
+    /// EDAC memory controllers base path.
+    edac_path: PathBuf,
+    /// Kernel log path; cleared if it cannot be opened.
+    kmsg_path: Option<PathBuf>,
+    /// Open kernel log, positioned after the last record read.
+    kmsg: Option<BufReader<File>>,
+    /// Last seen counts per source.
+    counts: HashMap<String, EccCounts>,
+    /// Event history, oldest first.
+    history: VecDeque<HwErrorEvent>,
+    /// Maximum history length.
+    capacity: usize,
+    /// Events not yet taken for alerting.
+    pending: Vec<HwErrorEvent>,
+    /// XIDs seen this session.
+    xid_count: u64,
+}
+
+impl HwErrorCollector {
+    /// Creates a new collector reading EDAC and the kernel log.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_paths("/sys/devices/system/edac/mc", Some(PathBuf::from("/dev/kmsg")))
+    }
+
+    /// Creates a collector reading the given EDAC directory and kernel log.
+    #[must_use]
+    pub fn with_paths(edac_path: impl Into<PathBuf>, kmsg_path: Option<PathBuf>) -> Self {
+        Self {
+            edac_path: edac_path.into(),
+            kmsg_path,
+            kmsg: None,
+            counts: HashMap::new(),
+            history: VecDeque::new(),
+            capacity: DEFAULT_HISTORY,
+            pending: Vec::new(),
+            xid_count: 0,
+        }
+    }
+
+    /// Sets the number of events kept in the history.
+    #[must_use]
+    pub fn history_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Returns the event history, newest first.
+    pub fn history(&self) -> impl Iterator<Item = &HwErrorEvent> {
+        self.history.iter().rev()
+    }
+
+    /// Returns the last seen counts per source.
+    #[must_use]
+    pub fn counts(&self) -> &HashMap<String, EccCounts> {
+        &self.counts
+    }
+
+    /// Returns the events recorded since the previous call.
+    pub fn take_new_events(&mut self) -> Vec<HwErrorEvent> {
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Records the current error counts of `source`, e.g. a GPU's NVML ECC
+    /// counters, adding an event for each counter that increased.
+    pub fn observe_ecc(&mut self, source: &str, counts: EccCounts) {
+        let Some(previous) = self.counts.insert(source.to_string(), counts) else {
+            return;
+        };
+        let timestamp = now();
+        for (kind, before, after) in [
+            (HwErrorKind::Corrected, previous.corrected, counts.corrected),
+            (HwErrorKind::Uncorrected, previous.uncorrected, counts.uncorrected),
+        ] {
+            if after > before {
+                self.record(HwErrorEvent {
+                    timestamp,
+                    source: source.to_string(),
+                    kind,
+                    count: after - before,
+                    detail: String::new(),
+                });
+            }
+        }
+    }
+
+    /// Adds an event to the history only.
+    fn remember(&mut self, event: HwErrorEvent) {
+        if matches!(event.kind, HwErrorKind::Xid(_)) {
+            self.xid_count += 1;
+        }
+        if self.history.len() == self.capacity {
+            self.history.pop_front();
+        }
+        self.history.push_back(event);
+    }
+
+    /// Adds an event to the history and the pending list.
+    fn record(&mut self, event: HwErrorEvent) {
+        self.remember(event.clone());
+        if self.pending.len() == self.capacity {
+            self.pending.remove(0);
+        }
+        self.pending.push(event);
+    }
+
+    /// Reads the counts of each EDAC memory controller.
+    fn read_edac(&self) -> Vec<(String, EccCounts)> {
+        let Ok(entries) = std::fs::read_dir(&self.edac_path) else {
+            return Vec::new();
+        };
+        let mut controllers: Vec<(String, EccCounts)> = entries
+            .filter_map(std::result::Result::ok)
+            .filter(|e| e.file_name().to_string_lossy().starts_with("mc"))
+            .filter_map(|e| {
+                let path = e.path();
+                let counts = EccCounts {
+                    corrected: read_u64(&path.join("ce_count"))?,
+                    uncorrected: read_u64(&path.join("ue_count"))?,
+                };
+                Some((e.file_name().to_string_lossy().to_string(), counts))
+            })
+            .collect();
+        controllers.sort_by(|a, b| a.0.cmp(&b.0));
+        controllers
+    }
+
+    /// Reads new kernel log records and returns the XID events among them.
+    fn read_kmsg(&mut self) -> Vec<HwErrorEvent> {
+        if self.kmsg.is_none() {
+            let Some(path) = self.kmsg_path.take() else {
+                return Vec::new();
+            };
+            match open_nonblocking(&path) {
+                Ok(file) => {
+                    self.kmsg = Some(BufReader::new(file));
+                    self.kmsg_path = Some(path);
+                }
+                // Not permitted (dmesg_restrict) or absent: stop trying
+                Err(_) => return Vec::new(),
+            }
+        }
+        let Some(reader) = self.kmsg.as_mut() else {
+            return Vec::new();
+        };
+
+        let boot = boot_time();
+        let mut events = Vec::new();
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) => break,
+                Ok(_) => events.extend(parse_kmsg_record(&line, boot)),
+                // Records overwritten before we read them; the next read resumes
+                Err(e) if e.kind() == ErrorKind::BrokenPipe => {}
+                Err(_) => break,
+            }
+        }
+        events
+    }
+}
+
+/// Parses one `/dev/kmsg` record (`priority,sequence,microseconds,flags;text`)
+/// into an XID event.
+fn parse_kmsg_record(record: &str, boot_time: u64) -> Option<HwErrorEvent> {
+    // Continuation lines carry key=value metadata
+    if record.starts_with(' ') {
+        return None;
+    }
+    let (header, text) = record.split_once(';')?;
+    let (source, code, detail) = parse_xid(text)?;
+    let micros: u64 = header.split(',').nth(2).and_then(|s| s.parse().ok()).unwrap_or(0);
+    Some(HwErrorEvent {
+        timestamp: boot_time + micros / 1_000_000,
+        source,
+        kind: HwErrorKind::Xid(code),
+        count: 1,
+        detail,
+    })
+}
+
+/// Opens a file for reading without blocking when no data is available.
+fn open_nonblocking(path: &Path) -> std::io::Result<File> {
+    let mut options = std::fs::OpenOptions::new();
+    options.read(true);
+    #[cfg(target_os = "linux")]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.custom_flags(libc::O_NONBLOCK);
+    }
+    options.open(path)
+}
+
+/// Reads a sysfs file as u64.
+fn read_u64(path: &Path) -> Option<u64> {
+    std::fs::read_to_string(path).ok().and_then(|s| s.trim().parse().ok())
+}
+
+/// Current Unix time in seconds.
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+/// Unix time of boot, from `/proc/uptime`; now if unavailable.
+fn boot_time() -> u64 {
+    let uptime = std::fs::read_to_string("/proc/uptime")
+        .ok()
+        .and_then(|s| s.split_whitespace().next()?.parse::<f64>().ok())
+        .unwrap_or(0.0);
+    now().saturating_sub(uptime as u64)
+}
+
+impl Default for HwErrorCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for HwErrorCollector {
+    fn id(&self) -> &'static str {
+        "hw_errors"
+    }
+
+    fn collect(&mut self) -> Result<Metrics> {
+        let mut metrics = Metrics::new();
+        let mut total = EccCounts::default();
+        for (name, counts) in self.read_edac() {
+            metrics.insert(format!("edac.{name}.ce_count"), MetricValue::Counter(counts.corrected));
+            metrics
+                .insert(format!("edac.{name}.ue_count"), MetricValue::Counter(counts.uncorrected));
+            total.corrected += counts.corrected;
+            total.uncorrected += counts.uncorrected;
+            self.observe_ecc(&name, counts);
+        }
+        metrics.insert("edac.ce_count", MetricValue::Counter(total.corrected));
+        metrics.insert("edac.ue_count", MetricValue::Counter(total.uncorrected));
+
+        // Records buffered before the log was opened are the baseline
+        let baseline = self.kmsg.is_none();
+        for event in self.read_kmsg() {
+            if baseline {
+                self.remember(event);
+            } else {
+                self.record(event);
+            }
+        }
+        metrics.insert("xid.count", MetricValue::Counter(self.xid_count));
+
+        Ok(metrics)
+    }
+
+    fn is_available(&self) -> bool {
+        self.edac_path.exists() || self.kmsg.is_some() || self.kmsg_path.is_some()
+    }
+
+    fn interval_hint(&self) -> Duration {
+        Duration::from_millis(5000)
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Memory Errors"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write as _;
+
+    #[test]
+    fn test_parse_xid() {
+        let (address, code, detail) = parse_xid(
+            "NVRM: Xid (PCI:0000:01:00): 79, pid=1234, name=python, GPU has fallen off the bus.",
+        )
+        .expect("xid line");
+        assert_eq!(address, "0000:01:00");
+        assert_eq!(code, 79);
+        assert!(detail.ends_with("GPU has fallen off the bus."));
+
+        assert_eq!(parse_xid("NVRM: Xid (0000:3b:00): 13").map(|x| x.1), Some(13));
+        assert_eq!(parse_xid("NVRM: loading NVIDIA UNIX x86_64 Kernel Module"), None);
+    }
+
+    #[test]
+    fn test_observe_ecc_baseline_and_deltas() {
+        let mut collector = HwErrorCollector::with_paths("/nonexistent", None);
+        collector.observe_ecc("gpu0", EccCounts { corrected: 5, uncorrected: 0 });
+        assert!(collector.take_new_events().is_empty());
+
+        collector.observe_ecc("gpu0", EccCounts { corrected: 8, uncorrected: 1 });
+        let events = collector.take_new_events();
+        assert_eq!(events.len(), 2);
+        assert_eq!((events[0].kind, events[0].count), (HwErrorKind::Corrected, 3));
+        assert!(!events[0].is_critical());
+        assert!(events[1].is_critical());
+        assert!(collector.take_new_events().is_empty());
+        assert_eq!(collector.history().count(), 2);
+    }
+
+    #[test]
+    fn test_history_capacity() {
+        let mut collector = HwErrorCollector::with_paths("/nonexistent", None).history_capacity(2);
+        for corrected in [0, 1, 3, 6, 10] {
+            collector.observe_ecc("mc0", EccCounts { corrected, uncorrected: 0 });
+        }
+        let counts: Vec<u64> = collector.history().map(|e| e.count).collect();
+        assert_eq!(counts, vec![4, 3]);
+        // Untaken events are bounded the same way
+        let pending: Vec<u64> = collector.take_new_events().iter().map(|e| e.count).collect();
+        assert_eq!(pending, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_collect_edac_and_kmsg() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let mc0 = dir.path().join("edac").join("mc0");
+        std::fs::create_dir_all(&mc0).expect("mc dir");
+        std::fs::write(mc0.join("ce_count"), "2\n").expect("ce_count");
+        std::fs::write(mc0.join("ue_count"), "0\n").expect("ue_count");
+        let kmsg = dir.path().join("kmsg");
+        std::fs::write(
+            &kmsg,
+            "6,100,5000000,-;usb 1-1: new device\n\
+             3,101,7000000,-;NVRM: Xid (PCI:0000:01:00): 48, An uncorrectable double bit error\n \
+             SUBSYSTEM=pci\n",
+        )
+        .expect("kmsg");
+
+        let mut collector =
+            HwErrorCollector::with_paths(dir.path().join("edac"), Some(kmsg.clone()));
+        assert!(collector.is_available());
+        let metrics = collector.collect().expect("collect");
+        assert_eq!(metrics.get_counter("edac.ce_count"), Some(2));
+        assert_eq!(metrics.get_counter("xid.count"), Some(1));
+
+        // XIDs already in the ring buffer are history, not new alerts
+        assert!(collector.take_new_events().is_empty());
+        let old = collector.history().next().expect("baseline xid");
+        assert_eq!(old.summary(), "XID 48: double bit ECC error");
+
+        std::fs::write(mc0.join("ce_count"), "6\n").expect("ce_count");
+        let mut log = std::fs::OpenOptions::new().append(true).open(&kmsg).expect("kmsg");
+        log.write_all(
+            b"3,102,9000000,-;NVRM: Xid (PCI:0000:01:00): 79, GPU has fallen off the bus.\n",
+        )
+        .expect("append");
+        let metrics = collector.collect().expect("collect");
+        assert_eq!(metrics.get_counter("xid.count"), Some(2));
+
+        let events = collector.take_new_events();
+        assert_eq!(events.len(), 2);
+        assert_eq!((events[0].source.as_str(), events[0].count), ("mc0", 4));
+        assert_eq!(events[1].source, "0000:01:00");
+        assert_eq!(events[1].summary(), "XID 79: GPU has fallen off the bus");
+        assert!(events[1].is_critical());
+        assert_eq!(collector.history().count(), 3);
+    }
+}
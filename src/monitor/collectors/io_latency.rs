@@ -0,0 +1,161 @@
+//! Block I/O latency percentile estimation.
+//!
+//! The block layer only exports cumulative counters (`/proc/diskstats`,
+//! `/sys/block/*/stat`): completed requests and milliseconds spent on them.
+//! Dividing the two over a whole run gives one average (Little's Law), which
+//! hides the tail. Instead, each sampling interval yields the mean latency
+//! of the requests completed in it, and the window of interval means,
+//! weighted by their request counts, gives p50/p95/p99 estimates.
+//!
+//! The estimates are only as fine as the sampling interval: a slow request
+//! among many fast ones in the same interval is averaged away. Per-request
+//! percentiles need block tracing (e.g. eBPF `biolatency`), which is not
+//! used here.
+
+use crate::monitor::ring_buffer::RingBuffer;
+
+/// Upper bounds of the latency distribution buckets, in milliseconds; a
+/// final bucket holds everything slower.
+pub const LATENCY_BUCKETS_MS: [f64; 8] = [0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0];
+
+/// Default number of intervals kept per device.
+pub const DEFAULT_WINDOW: usize = 120;
+
+/// Latency percentile estimates in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatencyPercentiles {
+    /// Median.
+    pub p50: f64,
+    /// 95th percentile.
+    pub p95: f64,
+    /// 99th percentile.
+    pub p99: f64,
+}
+
+/// Sliding window of per-interval I/O latencies for one device.
+#[derive(Debug, Clone)]
+pub struct IoLatency {
+    /// (mean latency in ms, completed requests) per interval.
+    samples: RingBuffer<(f64, u64)>,
+}
+
+impl IoLatency {
+    /// Creates a window of `capacity` intervals.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self { samples: RingBuffer::new(capacity) }
+    }
+
+    /// Records an interval in which `ios` requests completed, taking
+    /// `busy_ms` milliseconds in total. Idle intervals are skipped.
+    pub fn record(&mut self, ios: u64, busy_ms: u64) {
+        if ios > 0 {
+            self.samples.push((busy_ms as f64 / ios as f64, ios));
+        }
+    }
+
+    /// Returns the number of recorded (non-idle) intervals.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns true if no I/O has been recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Returns request-weighted latency percentiles over the window.
+    #[must_use]
+    pub fn percentiles(&self) -> Option<LatencyPercentiles> {
+        let mut samples: Vec<(f64, u64)> = self.samples.iter().copied().collect();
+        samples.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let total: u64 = samples.iter().map(|&(_, ios)| ios).sum();
+        if total == 0 {
+            return None;
+        }
+        let quantile = |q: f64| {
+            let rank = (q * total as f64).ceil().max(1.0) as u64;
+            let mut seen = 0;
+            samples
+                .iter()
+                .find(|&&(_, ios)| {
+                    seen += ios;
+                    seen >= rank
+                })
+                .map_or(0.0, |&(latency, _)| latency)
+        };
+        Some(LatencyPercentiles { p50: quantile(0.50), p95: quantile(0.95), p99: quantile(0.99) })
+    }
+
+    /// Returns the fraction of requests in each of the
+    /// [`LATENCY_BUCKETS_MS`] buckets, plus the overflow bucket.
+    #[must_use]
+    pub fn distribution(&self) -> [f64; LATENCY_BUCKETS_MS.len() + 1] {
+        let mut buckets = [0.0; LATENCY_BUCKETS_MS.len() + 1];
+        let total: u64 = self.samples.iter().map(|&(_, ios)| ios).sum();
+        if total == 0 {
+            return buckets;
+        }
+        for &(latency, ios) in self.samples.iter() {
+            let bucket = LATENCY_BUCKETS_MS
+                .iter()
+                .position(|&bound| latency <= bound)
+                .unwrap_or(LATENCY_BUCKETS_MS.len());
+            buckets[bucket] += ios as f64 / total as f64;
+        }
+        buckets
+    }
+}
+
+impl Default for IoLatency {
+    fn default() -> Self {
+        Self::new(DEFAULT_WINDOW)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentiles_weighted_by_requests() {
+        let mut latency = IoLatency::default();
+        assert!(latency.percentiles().is_none());
+        latency.record(0, 0); // idle
+        assert!(latency.is_empty());
+
+        // 96 requests at 1 ms, 3 at 10 ms, 1 at 200 ms
+        latency.record(96, 96);
+        latency.record(3, 30);
+        latency.record(1, 200);
+        let p = latency.percentiles().expect("percentiles");
+        assert!((p.p50 - 1.0).abs() < 1e-9);
+        assert!((p.p95 - 1.0).abs() < 1e-9);
+        assert!((p.p99 - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_distribution_buckets() {
+        let mut latency = IoLatency::new(4);
+        latency.record(2, 0); // 0 ms -> first bucket
+        latency.record(1, 4); // 4 ms -> <= 5 ms
+        latency.record(1, 500); // overflow
+        let dist = latency.distribution();
+        assert!((dist[0] - 0.5).abs() < 1e-9);
+        assert!((dist[3] - 0.25).abs() < 1e-9);
+        assert!((dist[LATENCY_BUCKETS_MS.len()] - 0.25).abs() < 1e-9);
+        assert!((dist.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_window_is_bounded() {
+        let mut latency = IoLatency::new(2);
+        latency.record(1, 100);
+        latency.record(1, 1);
+        latency.record(1, 1);
+        assert_eq!(latency.len(), 2);
+        assert!((latency.percentiles().expect("percentiles").p99 - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,302 @@
+//! File descriptor and thread limit saturation collector.
+//!
+//! Compares open file descriptors and threads against their limits, both
+//! system-wide (`/proc/sys/fs/file-nr`, `/proc/sys/kernel/threads-max`) and
+//! per process (`/proc/[pid]/fd` against `RLIMIT_NOFILE`, thread count
+//! against `RLIMIT_NPROC`). A process that runs out of descriptors fails
+//! with `EMFILE` in places that rarely handle it, so saturation is worth
+//! seeing before it happens.
+//!
+//! `RLIMIT_NPROC` caps all tasks of a user, not of one process; a single
+//! process near it is therefore already blocking the user's other work.
+//!
+//! Descriptors of other users' processes are only visible to root; such
+//! processes report no descriptor count.
+
+use crate::monitor::error::Result;
+use crate::monitor::types::{Collector, MetricValue, Metrics};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// System-wide descriptor and thread usage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SystemLimits {
+    /// Allocated file handles.
+    pub open_files: u64,
+    /// Maximum file handles (`fs.file-max`).
+    pub file_max: u64,
+    /// Tasks (threads) in the system.
+    pub threads: u64,
+    /// Maximum tasks (`kernel.threads-max`).
+    pub threads_max: u64,
+}
+
+impl SystemLimits {
+    /// File handle usage as a fraction of the maximum.
+    #[must_use]
+    pub fn file_ratio(&self) -> Option<f64> {
+        ratio(self.open_files, Some(self.file_max))
+    }
+
+    /// Thread usage as a fraction of the maximum.
+    #[must_use]
+    pub fn thread_ratio(&self) -> Option<f64> {
+        ratio(self.threads, Some(self.threads_max))
+    }
+}
+
+/// Descriptor and thread usage of one process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessLimits {
+    /// Process ID.
+    pub pid: u32,
+    /// Process name.
+    pub name: String,
+    /// Open file descriptors, if readable.
+    pub fds: Option<u64>,
+    /// Soft `RLIMIT_NOFILE`, if limited.
+    pub fd_limit: Option<u64>,
+    /// Thread count.
+    pub threads: u64,
+    /// Soft `RLIMIT_NPROC`, if limited.
+    pub thread_limit: Option<u64>,
+}
+
+impl ProcessLimits {
+    /// Open descriptors as a fraction of the limit.
+    #[must_use]
+    pub fn fd_ratio(&self) -> Option<f64> {
+        ratio(self.fds?, self.fd_limit)
+    }
+
+    /// Threads as a fraction of the limit.
+    #[must_use]
+    pub fn thread_ratio(&self) -> Option<f64> {
+        ratio(self.threads, self.thread_limit)
+    }
+}
+
+/// `used / limit`, or `None` without a (non-zero) limit.
+fn ratio(used: u64, limit: Option<u64>) -> Option<f64> {
+    limit.filter(|&l| l > 0).map(|l| used as f64 / l as f64)
+}
+
+/// Collector for descriptor and thread limit saturation.
+#[derive(Debug)]
+pub struct LimitsCollector {
+    /// procfs mount point.
+    proc_path: PathBuf,
+    /// System-wide usage.
+    system: SystemLimits,
+    /// Per-process usage, by PID.
+    processes: Vec<ProcessLimits>,
+}
+
+impl LimitsCollector {
+    /// Creates a new limits collector.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_proc_path("/proc")
+    }
+
+    /// Creates a collector reading procfs from `path`.
+    #[must_use]
+    pub fn with_proc_path(path: impl Into<PathBuf>) -> Self {
+        Self { proc_path: path.into(), system: SystemLimits::default(), processes: Vec::new() }
+    }
+
+    /// Returns the system-wide usage.
+    #[must_use]
+    pub fn system(&self) -> SystemLimits {
+        self.system
+    }
+
+    /// Returns per-process usage, by PID.
+    #[must_use]
+    pub fn processes(&self) -> &[ProcessLimits] {
+        &self.processes
+    }
+
+    /// Returns the usage of one process.
+    #[must_use]
+    pub fn process(&self, pid: u32) -> Option<&ProcessLimits> {
+        self.processes.binary_search_by_key(&pid, |p| p.pid).ok().map(|i| &self.processes[i])
+    }
+
+    /// Returns processes using at least `fraction` of their descriptor or
+    /// thread limit.
+    pub fn near_limit(&self, fraction: f64) -> impl Iterator<Item = &ProcessLimits> {
+        self.processes.iter().filter(move |p| {
+            p.fd_ratio().is_some_and(|r| r >= fraction)
+                || p.thread_ratio().is_some_and(|r| r >= fraction)
+        })
+    }
+
+    /// Reads system-wide usage.
+    fn read_system(&self) -> SystemLimits {
+        let file_nr: Vec<u64> = read_string(&self.proc_path.join("sys/fs/file-nr"))
+            .map(|s| s.split_whitespace().filter_map(|v| v.parse().ok()).collect())
+            .unwrap_or_default();
+        // Fourth loadavg field: running/total tasks
+        let threads = read_string(&self.proc_path.join("loadavg"))
+            .and_then(|s| s.split_whitespace().nth(3)?.split_once('/')?.1.parse().ok())
+            .unwrap_or(0);
+
+        SystemLimits {
+            open_files: match file_nr[..] {
+                [allocated, unused, _] => allocated.saturating_sub(unused),
+                _ => 0,
+            },
+            file_max: file_nr.get(2).copied().unwrap_or(0),
+            threads,
+            threads_max: read_string(&self.proc_path.join("sys/kernel/threads-max"))
+                .and_then(|s| s.trim().parse().ok())
+                .unwrap_or(0),
+        }
+    }
+
+    /// Reads the usage of one process.
+    fn read_process(&self, pid: u32) -> Option<ProcessLimits> {
+        let dir = self.proc_path.join(pid.to_string());
+        let status = read_string(&dir.join("status"))?;
+        let field = |key: &str| {
+            status.lines().find_map(|l| l.strip_prefix(key)).map(|v| v.trim().to_string())
+        };
+        let limits = read_string(&dir.join("limits")).unwrap_or_default();
+
+        Some(ProcessLimits {
+            pid,
+            name: field("Name:").unwrap_or_default(),
+            fds: std::fs::read_dir(dir.join("fd")).ok().map(|d| d.count() as u64),
+            fd_limit: soft_limit(&limits, "Max open files"),
+            threads: field("Threads:").and_then(|v| v.parse().ok()).unwrap_or(1),
+            thread_limit: soft_limit(&limits, "Max processes"),
+        })
+    }
+}
+
+/// Soft limit of `name` in a `/proc/[pid]/limits` table; `None` if
+/// unlimited or absent.
+fn soft_limit(limits: &str, name: &str) -> Option<u64> {
+    limits.lines().find_map(|l| l.strip_prefix(name))?.split_whitespace().next()?.parse().ok()
+}
+
+/// Reads a file to a string.
+fn read_string(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path).ok()
+}
+
+impl Default for LimitsCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for LimitsCollector {
+    fn id(&self) -> &'static str {
+        "limits"
+    }
+
+    fn collect(&mut self) -> Result<Metrics> {
+        self.system = self.read_system();
+        let mut pids: Vec<u32> = std::fs::read_dir(&self.proc_path)
+            .map(|entries| {
+                entries
+                    .filter_map(std::result::Result::ok)
+                    .filter_map(|e| e.file_name().to_string_lossy().parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        pids.sort_unstable();
+        self.processes = pids.into_iter().filter_map(|pid| self.read_process(pid)).collect();
+
+        let mut metrics = Metrics::new();
+        metrics.insert("limits.files.open", MetricValue::Counter(self.system.open_files));
+        metrics.insert("limits.files.max", MetricValue::Counter(self.system.file_max));
+        metrics.insert("limits.threads", MetricValue::Counter(self.system.threads));
+        metrics.insert("limits.threads.max", MetricValue::Counter(self.system.threads_max));
+        let worst = self.processes.iter().filter_map(ProcessLimits::fd_ratio).fold(0.0, f64::max);
+        metrics.insert("limits.process.max_fd_percent", worst * 100.0);
+        Ok(metrics)
+    }
+
+    fn is_available(&self) -> bool {
+        self.proc_path.join("sys/fs/file-nr").exists()
+    }
+
+    fn interval_hint(&self) -> Duration {
+        Duration::from_millis(5000) // Walks every process's fd table
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Limits"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMITS: &str = "\
+Limit                     Soft Limit           Hard Limit           Units
+Max processes             63412                63412                processes
+Max open files            4                    524288               files
+Max locked memory         unlimited            unlimited            bytes
+";
+
+    fn fake_proc(root: &Path) {
+        let write = |rel: &str, content: &str| {
+            let path = root.join(rel);
+            std::fs::create_dir_all(path.parent().expect("parent")).expect("dir");
+            std::fs::write(path, content).expect("write");
+        };
+        write("sys/fs/file-nr", "9000\t0\t10000\n");
+        write("sys/kernel/threads-max", "126824\n");
+        write("loadavg", "0.52 0.58 0.59 3/1467 12345\n");
+        write("42/status", "Name:\tpython\nState:\tS (sleeping)\nThreads:\t12\n");
+        write("42/limits", LIMITS);
+        for fd in 0..3 {
+            write(&format!("42/fd/{fd}"), "");
+        }
+        write("7/status", "Name:\tsshd\nThreads:\t1\n");
+    }
+
+    #[test]
+    fn test_soft_limit() {
+        assert_eq!(soft_limit(LIMITS, "Max open files"), Some(4));
+        assert_eq!(soft_limit(LIMITS, "Max processes"), Some(63412));
+        assert_eq!(soft_limit(LIMITS, "Max locked memory"), None);
+        assert_eq!(soft_limit(LIMITS, "Max stack size"), None);
+    }
+
+    #[test]
+    fn test_collect_system_and_processes() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        fake_proc(dir.path());
+        let mut collector = LimitsCollector::with_proc_path(dir.path());
+        assert!(collector.is_available());
+        let metrics = collector.collect().expect("collect");
+
+        let system = collector.system();
+        assert_eq!((system.open_files, system.file_max, system.threads), (9000, 10000, 1467));
+        assert!((system.file_ratio().expect("ratio") - 0.9).abs() < 1e-9);
+        assert_eq!(metrics.get_counter("limits.threads.max"), Some(126_824));
+
+        let python = collector.process(42).expect("python");
+        assert_eq!((python.fds, python.fd_limit, python.threads), (Some(3), Some(4), 12));
+        let sshd = collector.process(7).expect("sshd");
+        assert_eq!((sshd.fds, sshd.fd_limit), (None, None));
+        assert_eq!(metrics.get_gauge("limits.process.max_fd_percent"), Some(75.0));
+    }
+
+    #[test]
+    fn test_near_limit() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        fake_proc(dir.path());
+        let mut collector = LimitsCollector::with_proc_path(dir.path());
+        collector.collect().expect("collect");
+        let near: Vec<u32> = collector.near_limit(0.75).map(|p| p.pid).collect();
+        assert_eq!(near, vec![42]);
+        assert_eq!(collector.near_limit(0.9).count(), 0);
+    }
+}
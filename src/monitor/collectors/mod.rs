@@ -2,7 +2,10 @@
 //!
 //! This module provides collectors for gathering metrics from various sources:
 //!
-//! - **System**: CPU, memory, disk, network, processes, sensors, battery
+//! - **System**: CPU, memory, disk (with latency percentiles), network,
+//!   processes, sensors, battery, memory errors (EDAC, GPU XID), energy
+//!   (RAPL, AMD energy, SMC), descriptor and thread limits
+//! - **Bus**: PCIe link state of GPUs
 //! - **GPU**: NVIDIA (via NVML), AMD (via ROCm SMI)
 //! - **Stack**: realizar, entrenar, trueno-zram, repartir
 
@@ -13,11 +16,16 @@ pub mod cpu;
 pub mod cpu_simd;
 pub mod disk;
 pub mod disk_simd;
+pub mod energy;
 pub mod gpu_simd;
+pub mod hw_errors;
+pub mod io_latency;
+pub mod limits;
 pub mod memory;
 pub mod memory_simd;
 pub mod network;
 pub mod network_simd;
+pub mod pcie;
 pub mod process;
 pub mod process_simd;
 pub mod sensors;
@@ -28,11 +36,16 @@ pub use cpu::{CpuCollector, CpuFrequency, LoadAverage};
 pub use cpu_simd::SimdCpuCollector;
 pub use disk::DiskCollector;
 pub use disk_simd::SimdDiskCollector;
+pub use energy::{EnergyCollector, EnergyDomain, EnergySource};
 pub use gpu_simd::{GpuMetricsSoA, SimdGpuHistory};
+pub use hw_errors::{EccCounts, HwErrorCollector, HwErrorEvent, HwErrorKind};
+pub use io_latency::{IoLatency, LatencyPercentiles};
+pub use limits::{LimitsCollector, ProcessLimits, SystemLimits};
 pub use memory::MemoryCollector;
 pub use memory_simd::SimdMemoryCollector;
-pub use network::NetworkCollector;
+pub use network::{NetworkCollector, TcpAnomaly, TcpState, TcpStateCounts};
 pub use network_simd::SimdNetworkCollector;
+pub use pcie::{PcieCollector, PcieLink};
 pub use process::ProcessCollector;
 pub use process_simd::SimdProcessCollector;
 pub use sensors::SensorCollector;
@@ -1,6 +1,10 @@
 //! Network metrics collector.
 //!
-//! Parses `/proc/net/dev` on Linux to collect network interface metrics.
+//! Parses `/proc/net/dev` on Linux to collect network interface metrics,
+//! and `/proc/net/tcp{,6}` for connection counts per TCP state. The state
+//! counts are kept over time so that steady growth of half-open
+//! (`SYN_RECV`) or `CLOSE_WAIT` connections can be reported as a likely
+//! SYN flood or connection leak ([`NetworkCollector::tcp_anomalies`]).
 //!
 //! ## Falsification Criteria
 //!
@@ -13,6 +17,7 @@ use crate::monitor::ring_buffer::RingBuffer;
 use crate::monitor::subprocess::run_with_timeout;
 use crate::monitor::types::{Collector, MetricValue, Metrics};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 /// Raw statistics for a network interface.
@@ -84,6 +89,133 @@ fn format_bytes_rate(bytes_per_sec: f64) -> String {
     batuta_common::fmt::format_bytes_rate(bytes_per_sec)
 }
 
+/// TCP connection state, numbered as in `/proc/net/tcp`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TcpState {
+    /// Connection open.
+    Established,
+    /// Active open, SYN sent.
+    SynSent,
+    /// Passive open, SYN received (half-open).
+    SynRecv,
+    /// Local close, FIN sent.
+    FinWait1,
+    /// Local close, FIN acknowledged.
+    FinWait2,
+    /// Waiting out stray segments after close.
+    TimeWait,
+    /// Closed.
+    Close,
+    /// Peer closed, waiting for the local application to close.
+    CloseWait,
+    /// Waiting for the ACK of our FIN after the peer closed.
+    LastAck,
+    /// Listening socket.
+    Listen,
+    /// Simultaneous close.
+    Closing,
+}
+
+impl TcpState {
+    /// All states, in kernel order.
+    pub const ALL: [Self; 11] = [
+        Self::Established,
+        Self::SynSent,
+        Self::SynRecv,
+        Self::FinWait1,
+        Self::FinWait2,
+        Self::TimeWait,
+        Self::Close,
+        Self::CloseWait,
+        Self::LastAck,
+        Self::Listen,
+        Self::Closing,
+    ];
+
+    /// Parses the kernel state code (`st` column, 1-based).
+    #[must_use]
+    pub fn from_code(code: u8) -> Option<Self> {
+        Self::ALL.get(usize::from(code).checked_sub(1)?).copied()
+    }
+
+    /// Returns the conventional state name, e.g. "`CLOSE_WAIT`".
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Established => "ESTABLISHED",
+            Self::SynSent => "SYN_SENT",
+            Self::SynRecv => "SYN_RECV",
+            Self::FinWait1 => "FIN_WAIT1",
+            Self::FinWait2 => "FIN_WAIT2",
+            Self::TimeWait => "TIME_WAIT",
+            Self::Close => "CLOSE",
+            Self::CloseWait => "CLOSE_WAIT",
+            Self::LastAck => "LAST_ACK",
+            Self::Listen => "LISTEN",
+            Self::Closing => "CLOSING",
+        }
+    }
+}
+
+/// Connection counts per TCP state.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TcpStateCounts([u64; 11]);
+
+impl TcpStateCounts {
+    /// Returns the count for one state.
+    #[must_use]
+    pub fn get(&self, state: TcpState) -> u64 {
+        self.0[state as usize]
+    }
+
+    /// Returns the number of connections in all states.
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.0.iter().sum()
+    }
+
+    /// Adds the sockets of a `/proc/net/tcp` table.
+    fn add_table(&mut self, content: &str) {
+        for line in content.lines().skip(1) {
+            let state = line
+                .split_whitespace()
+                .nth(3)
+                .and_then(|st| u8::from_str_radix(st, 16).ok())
+                .and_then(TcpState::from_code);
+            if let Some(state) = state {
+                self.0[state as usize] += 1;
+            }
+        }
+    }
+}
+
+/// Suspicious steady growth of a TCP state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TcpAnomaly {
+    /// Half-open connections keep growing: likely a SYN flood.
+    SynFlood {
+        /// Current `SYN_RECV` count.
+        half_open: u64,
+    },
+    /// `CLOSE_WAIT` connections keep growing: an application is not
+    /// closing sockets its peers have closed.
+    CloseWaitLeak {
+        /// Current `CLOSE_WAIT` count.
+        count: u64,
+    },
+}
+
+impl TcpAnomaly {
+    /// Returns a short description for alerts.
+    #[must_use]
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::SynFlood { .. } => "half-open connections growing, possible SYN flood",
+            Self::CloseWaitLeak { .. } => "CLOSE_WAIT connections growing, possible socket leak",
+        }
+    }
+}
+
 /// Collector for network metrics.
 #[derive(Debug)]
 pub struct NetworkCollector {
@@ -103,6 +235,16 @@ pub struct NetworkCollector {
     auto_select: bool,
     /// Maximum observed throughput (for normalization).
     max_throughput: f64,
+    /// TCP socket tables to read.
+    tcp_paths: Vec<PathBuf>,
+    /// Latest connection counts per TCP state.
+    tcp_counts: TcpStateCounts,
+    /// Connection count history per TCP state.
+    tcp_history: HashMap<TcpState, RingBuffer<u64>>,
+    /// Samples of uninterrupted growth before an anomaly is reported.
+    tcp_growth_window: usize,
+    /// Minimum connection count for an anomaly.
+    tcp_growth_min: u64,
 }
 
 impl NetworkCollector {
@@ -118,9 +260,30 @@ impl NetworkCollector {
             current_interface: None,
             auto_select: true,
             max_throughput: 125_000_000.0, // 1 Gbps default max
+            tcp_paths: vec![PathBuf::from("/proc/net/tcp"), PathBuf::from("/proc/net/tcp6")],
+            tcp_counts: TcpStateCounts::default(),
+            tcp_history: HashMap::new(),
+            tcp_growth_window: 10,
+            tcp_growth_min: 100,
         }
     }
 
+    /// Sets the TCP socket tables to read (default `/proc/net/tcp{,6}`).
+    #[must_use]
+    pub fn with_tcp_paths(mut self, paths: Vec<PathBuf>) -> Self {
+        self.tcp_paths = paths;
+        self
+    }
+
+    /// Sets how many consecutive non-decreasing samples, ending at least at
+    /// `min_count` connections, make a [`TcpAnomaly`] (default 10 and 100).
+    #[must_use]
+    pub fn tcp_growth_detection(mut self, window: usize, min_count: u64) -> Self {
+        self.tcp_growth_window = window.max(2);
+        self.tcp_growth_min = min_count;
+        self
+    }
+
     /// Sets the current interface for primary display.
     pub fn set_interface(&mut self, name: impl Into<String>) {
         self.current_interface = Some(name.into());
@@ -169,6 +332,58 @@ impl NetworkCollector {
         self.current_interface.as_ref().and_then(|name| self.tx_history.get(name))
     }
 
+    /// Returns the latest connection counts per TCP state.
+    #[must_use]
+    pub fn tcp_counts(&self) -> TcpStateCounts {
+        self.tcp_counts
+    }
+
+    /// Returns the connection count history of a TCP state.
+    #[must_use]
+    pub fn tcp_history(&self, state: TcpState) -> Option<&RingBuffer<u64>> {
+        self.tcp_history.get(&state)
+    }
+
+    /// Returns TCP states whose counts grew steadily over the detection
+    /// window.
+    #[must_use]
+    pub fn tcp_anomalies(&self) -> Vec<TcpAnomaly> {
+        let growing = |state| {
+            let window = self.tcp_history.get(&state)?.last_n_cloned(self.tcp_growth_window);
+            let (&first, &last) = (window.first()?, window.last()?);
+            (window.len() == self.tcp_growth_window
+                && window.windows(2).all(|w| w[0] <= w[1])
+                && last > first
+                && last >= self.tcp_growth_min)
+                .then_some(last)
+        };
+        let mut anomalies = Vec::new();
+        if let Some(half_open) = growing(TcpState::SynRecv) {
+            anomalies.push(TcpAnomaly::SynFlood { half_open });
+        }
+        if let Some(count) = growing(TcpState::CloseWait) {
+            anomalies.push(TcpAnomaly::CloseWaitLeak { count });
+        }
+        anomalies
+    }
+
+    /// Counts sockets per state and appends them to the history.
+    fn update_tcp(&mut self) {
+        let mut counts = TcpStateCounts::default();
+        for path in &self.tcp_paths {
+            if let Ok(content) = std::fs::read_to_string(path) {
+                counts.add_table(&content);
+            }
+        }
+        self.tcp_counts = counts;
+        for state in TcpState::ALL {
+            self.tcp_history
+                .entry(state)
+                .or_insert_with(|| RingBuffer::new(300))
+                .push(counts.get(state));
+        }
+    }
+
     /// Reads network statistics from /proc/net/dev.
     #[cfg(target_os = "linux")]
     fn read_net_dev(&self) -> Result<HashMap<String, NetStats>> {
@@ -407,6 +622,7 @@ impl Collector for NetworkCollector {
         // Update previous state
         self.prev_stats = current_stats;
         self.prev_time = Some(now);
+        self.update_tcp();
 
         // Build metrics
         let mut metrics = Metrics::new();
@@ -433,6 +649,12 @@ impl Collector for NetworkCollector {
             );
         }
 
+        // Connections per TCP state, e.g. network.tcp.close_wait
+        for state in TcpState::ALL {
+            let key = format!("network.tcp.{}", state.name().to_ascii_lowercase());
+            metrics.insert(key, MetricValue::Counter(self.tcp_counts.get(state)));
+        }
+
         Ok(metrics)
     }
 
@@ -616,6 +838,74 @@ mod tests {
         assert!((eth0_rates.tx_packets_per_sec - 50.0).abs() < 0.01);
     }
 
+    const TCP_TABLE: &str = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 00000000:0016 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 1 1
+   1: 0100007F:1F90 0100007F:A2B4 08 00000000:00000000 00:00000000 00000000  1000        0 2 1
+   2: 0100007F:1F90 0100007F:A2B6 08 00000000:00000000 00:00000000 00000000  1000        0 3 1
+   3: 0100007F:A2B4 0100007F:1F90 01 00000000:00000000 00:00000000 00000000  1000        0 4 1
+";
+
+    #[test]
+    fn test_tcp_state_codes() {
+        assert_eq!(TcpState::from_code(0x01), Some(TcpState::Established));
+        assert_eq!(TcpState::from_code(0x08), Some(TcpState::CloseWait));
+        assert_eq!(TcpState::from_code(0x0B), Some(TcpState::Closing));
+        assert_eq!(TcpState::from_code(0), None);
+        assert_eq!(TcpState::from_code(0x0C), None);
+        assert_eq!(TcpState::SynRecv.name(), "SYN_RECV");
+    }
+
+    #[test]
+    fn test_tcp_state_counts() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let tcp = dir.path().join("tcp");
+        std::fs::write(&tcp, TCP_TABLE).expect("tcp");
+        let mut collector =
+            NetworkCollector::new().with_tcp_paths(vec![tcp, dir.path().join("tcp6")]);
+        let metrics = collector.collect().expect("collect");
+
+        let counts = collector.tcp_counts();
+        assert_eq!(counts.get(TcpState::Listen), 1);
+        assert_eq!(counts.get(TcpState::CloseWait), 2);
+        assert_eq!(counts.total(), 4);
+        assert_eq!(metrics.get_counter("network.tcp.close_wait"), Some(2));
+        assert_eq!(collector.tcp_history(TcpState::Established).map(RingBuffer::len), Some(1));
+    }
+
+    fn push_tcp_samples(collector: &mut NetworkCollector, samples: &[(u64, u64)]) {
+        for &(syn_recv, close_wait) in samples {
+            for (state, count) in [(TcpState::SynRecv, syn_recv), (TcpState::CloseWait, close_wait)]
+            {
+                collector
+                    .tcp_history
+                    .entry(state)
+                    .or_insert_with(|| RingBuffer::new(300))
+                    .push(count);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tcp_anomalies() {
+        let mut collector = NetworkCollector::new().tcp_growth_detection(3, 2);
+        push_tcp_samples(&mut collector, &[(1, 5), (2, 5), (4, 5)]);
+        assert_eq!(collector.tcp_anomalies(), vec![TcpAnomaly::SynFlood { half_open: 4 }]);
+
+        // A dip within the window clears the flood; CLOSE_WAIT keeps growing
+        push_tcp_samples(&mut collector, &[(1, 6), (3, 6)]);
+        assert_eq!(collector.tcp_anomalies(), vec![TcpAnomaly::CloseWaitLeak { count: 6 }]);
+    }
+
+    #[test]
+    fn test_tcp_anomalies_need_full_window_and_minimum() {
+        let mut collector = NetworkCollector::new().tcp_growth_detection(3, 50);
+        push_tcp_samples(&mut collector, &[(60, 1), (70, 2)]);
+        assert!(collector.tcp_anomalies().is_empty());
+        push_tcp_samples(&mut collector, &[(80, 3)]);
+        assert_eq!(collector.tcp_anomalies(), vec![TcpAnomaly::SynFlood { half_open: 80 }]);
+    }
+
     // ========================================================================
     // Linux-specific Tests
     // ========================================================================
@@ -0,0 +1,384 @@
+//! PCIe link-state collector for GPUs.
+//!
+//! Reads the negotiated and maximum link speed and width of display-class
+//! PCI devices from `/sys/bus/pci/devices/` on Linux. A GPU that trains
+//! below its maximum width (x4 in an x16 slot, a bad riser, a dusty
+//! contact) copies host↔device data at a fraction of the expected rate with
+//! no other symptom, so such links are flagged as degraded.
+//!
+//! Throughput is read from the amdgpu `pcie_bw` counters when enabled;
+//! NVIDIA throughput comes from NVML (see `NvidiaGpuCollector`).
+//!
+//! ## Falsification Criteria
+//!
+//! - Link generation and width match `lspci -vv` `LnkSta`/`LnkCap`
+
+use crate::monitor::error::Result;
+use crate::monitor::types::{Collector, MetricValue, Metrics};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// PCI class code prefix of display controllers (VGA, 3D, other).
+const DISPLAY_CLASS: u32 = 0x03;
+
+/// Maps a sysfs link speed such as `"16.0 GT/s PCIe"` to its generation.
+#[must_use]
+pub fn generation_from_speed(speed: &str) -> Option<u8> {
+    let gts: f64 = speed.split_whitespace().next()?.parse().ok()?;
+    match gts {
+        s if s >= 64.0 => Some(6),
+        s if s >= 32.0 => Some(5),
+        s if s >= 16.0 => Some(4),
+        s if s >= 8.0 => Some(3),
+        s if s >= 5.0 => Some(2),
+        s if s >= 2.5 => Some(1),
+        _ => None,
+    }
+}
+
+/// Compares PCI addresses ignoring case and the width of the domain field,
+/// which sysfs prints with 4 digits and NVML with 8.
+#[must_use]
+pub fn same_pci_address(a: &str, b: &str) -> bool {
+    let split = |address: &str| {
+        let (domain, rest) = address.split_once(':')?;
+        Some((u32::from_str_radix(domain, 16).ok()?, rest.to_ascii_lowercase()))
+    };
+    match (split(a), split(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a.eq_ignore_ascii_case(b),
+    }
+}
+
+/// Link state of one PCIe device.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PcieLink {
+    /// PCI address (e.g., "0000:01:00.0").
+    pub address: String,
+    /// PCI vendor ID (e.g., 0x10de for NVIDIA).
+    pub vendor: u16,
+    /// Negotiated generation.
+    pub current_gen: Option<u8>,
+    /// Negotiated lane count.
+    pub current_width: Option<u8>,
+    /// Maximum generation supported by the device and slot.
+    pub max_gen: Option<u8>,
+    /// Maximum lane count supported by the device and slot.
+    pub max_width: Option<u8>,
+    /// Host→device throughput in bytes/second (if measured).
+    pub tx_bytes_per_sec: Option<f64>,
+    /// Device→host throughput in bytes/second (if measured).
+    pub rx_bytes_per_sec: Option<f64>,
+}
+
+impl PcieLink {
+    /// Returns true if the link trained at fewer lanes than it supports.
+    ///
+    /// Generation is not compared: GPUs drop to a lower speed when idle to
+    /// save power, so only a narrower width indicates a fault.
+    #[must_use]
+    pub fn is_degraded(&self) -> bool {
+        matches!((self.current_width, self.max_width), (Some(cur), Some(max)) if cur < max)
+    }
+
+    /// Returns a short vendor name.
+    #[must_use]
+    pub fn vendor_name(&self) -> &'static str {
+        match self.vendor {
+            0x10de => "NVIDIA",
+            0x1002 => "AMD",
+            0x8086 => "Intel",
+            _ => "Unknown",
+        }
+    }
+
+    /// Formats the link state, e.g. "Gen4 x16" or "Gen4 x4 (max x16)".
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let gen = self.current_gen.map_or_else(|| "Gen?".to_string(), |g| format!("Gen{g}"));
+        let width = self.current_width.map_or_else(|| "x?".to_string(), |w| format!("x{w}"));
+        match self.max_width {
+            Some(max) if self.is_degraded() => format!("{gen} {width} (max x{max})"),
+            _ => format!("{gen} {width}"),
+        }
+    }
+}
+
+/// Collector for GPU PCIe link state.
+#[derive(Debug)]
+pub struct PcieCollector {
+    /// Links of detected display devices.
+    links: Vec<PcieLink>,
+    /// PCI devices base path.
+    devices_path: PathBuf,
+    /// Whether to read throughput counters.
+    measure_throughput: bool,
+}
+
+impl PcieCollector {
+    /// Creates a new PCIe collector.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_path("/sys/bus/pci/devices")
+    }
+
+    /// Creates a collector reading devices from `path` instead of sysfs.
+    #[must_use]
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        Self { links: Vec::new(), devices_path: path.into(), measure_throughput: false }
+    }
+
+    /// Enables reading the amdgpu `pcie_bw` counters.
+    ///
+    /// The driver samples them over a one-second window, so each collection
+    /// blocks for that long per AMD GPU.
+    pub fn enable_throughput_measurement(&mut self) {
+        self.measure_throughput = true;
+    }
+
+    /// Returns the links of detected display devices.
+    #[must_use]
+    pub fn links(&self) -> &[PcieLink] {
+        &self.links
+    }
+
+    /// Returns the link of the device at a PCI address, in sysfs
+    /// ("0000:03:00.0") or NVML ("00000000:03:00.0") form.
+    #[must_use]
+    pub fn link(&self, address: &str) -> Option<&PcieLink> {
+        self.links.iter().find(|l| same_pci_address(&l.address, address))
+    }
+
+    /// Returns the links that trained below their maximum width.
+    pub fn degraded(&self) -> impl Iterator<Item = &PcieLink> {
+        self.links.iter().filter(|l| l.is_degraded())
+    }
+
+    /// Discovers display-class PCI devices, sorted by address.
+    #[cfg(target_os = "linux")]
+    fn discover_devices(&self) -> Vec<PathBuf> {
+        let mut devices: Vec<PathBuf> = std::fs::read_dir(&self.devices_path)
+            .ok()
+            .map(|entries| {
+                entries
+                    .filter_map(std::result::Result::ok)
+                    .map(|e| e.path())
+                    .filter(|p| {
+                        read_hex(&p.join("class")).is_some_and(|c| c >> 16 == DISPLAY_CLASS)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        devices.sort();
+        devices
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn discover_devices(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
+    /// Reads the link state of one device.
+    fn read_link(&self, path: &Path) -> PcieLink {
+        let speed = |file: &str| {
+            std::fs::read_to_string(path.join(file)).ok().and_then(|s| generation_from_speed(&s))
+        };
+        let width = |file: &str| {
+            std::fs::read_to_string(path.join(file))
+                .ok()
+                .and_then(|s| s.trim().trim_start_matches('x').parse().ok())
+        };
+
+        let vendor = read_hex(&path.join("vendor")).map_or(0, |v| v as u16);
+        let (tx_bytes_per_sec, rx_bytes_per_sec) = if self.measure_throughput {
+            read_pcie_bw(&path.join("pcie_bw"))
+        } else {
+            (None, None)
+        };
+
+        PcieLink {
+            address: path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default(),
+            vendor,
+            current_gen: speed("current_link_speed"),
+            current_width: width("current_link_width"),
+            max_gen: speed("max_link_speed"),
+            max_width: width("max_link_width"),
+            tx_bytes_per_sec,
+            rx_bytes_per_sec,
+        }
+    }
+}
+
+/// Reads a sysfs hex value such as "0x030000".
+fn read_hex(path: &Path) -> Option<u32> {
+    let s = std::fs::read_to_string(path).ok()?;
+    u32::from_str_radix(s.trim().trim_start_matches("0x"), 16).ok()
+}
+
+/// Reads amdgpu `pcie_bw`: received packets, sent packets and max payload
+/// size over the last second. Bytes are estimated at full payload size.
+fn read_pcie_bw(path: &Path) -> (Option<f64>, Option<f64>) {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return (None, None);
+    };
+    let fields: Vec<u64> = content.split_whitespace().filter_map(|s| s.parse().ok()).collect();
+    match fields[..] {
+        [received, sent, mps] => {
+            (Some(sent.saturating_mul(mps) as f64), Some(received.saturating_mul(mps) as f64))
+        }
+        _ => (None, None),
+    }
+}
+
+impl Default for PcieCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for PcieCollector {
+    fn id(&self) -> &'static str {
+        "pcie"
+    }
+
+    fn collect(&mut self) -> Result<Metrics> {
+        self.links = self.discover_devices().iter().map(|p| self.read_link(p)).collect();
+
+        let mut metrics = Metrics::new();
+        metrics.insert("pcie.count", MetricValue::Counter(self.links.len() as u64));
+        metrics.insert("pcie.degraded_count", MetricValue::Counter(self.degraded().count() as u64));
+
+        for (i, link) in self.links.iter().enumerate() {
+            let prefix = format!("pcie.{i}");
+            if let Some(gen) = link.current_gen {
+                metrics.insert(format!("{prefix}.gen"), MetricValue::Counter(u64::from(gen)));
+            }
+            if let Some(width) = link.current_width {
+                metrics.insert(format!("{prefix}.width"), MetricValue::Counter(u64::from(width)));
+            }
+            if let Some(gen) = link.max_gen {
+                metrics.insert(format!("{prefix}.max_gen"), MetricValue::Counter(u64::from(gen)));
+            }
+            if let Some(width) = link.max_width {
+                metrics
+                    .insert(format!("{prefix}.max_width"), MetricValue::Counter(u64::from(width)));
+            }
+            metrics.insert(
+                format!("{prefix}.degraded"),
+                MetricValue::Gauge(if link.is_degraded() { 1.0 } else { 0.0 }),
+            );
+            if let Some(tx) = link.tx_bytes_per_sec {
+                metrics.insert(format!("{prefix}.tx_bytes_per_sec"), tx);
+            }
+            if let Some(rx) = link.rx_bytes_per_sec {
+                metrics.insert(format!("{prefix}.rx_bytes_per_sec"), rx);
+            }
+        }
+
+        Ok(metrics)
+    }
+
+    fn is_available(&self) -> bool {
+        !self.discover_devices().is_empty()
+    }
+
+    fn interval_hint(&self) -> Duration {
+        Duration::from_millis(2000)
+    }
+
+    fn display_name(&self) -> &'static str {
+        "PCIe"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_device(root: &Path, address: &str, files: &[(&str, &str)]) {
+        let dir = root.join(address);
+        std::fs::create_dir_all(&dir).expect("device dir");
+        for (name, content) in files {
+            std::fs::write(dir.join(name), content).expect("sysfs file");
+        }
+    }
+
+    #[test]
+    fn test_generation_from_speed() {
+        assert_eq!(generation_from_speed("2.5 GT/s PCIe"), Some(1));
+        assert_eq!(generation_from_speed("8.0 GT/s PCIe\n"), Some(3));
+        assert_eq!(generation_from_speed("16.0 GT/s PCIe"), Some(4));
+        assert_eq!(generation_from_speed("32.0 GT/s PCIe"), Some(5));
+        assert_eq!(generation_from_speed("Unknown"), None);
+    }
+
+    #[test]
+    fn test_same_pci_address() {
+        assert!(same_pci_address("0000:01:00.0", "00000000:01:00.0"));
+        assert!(same_pci_address("0000:c1:00.0", "0000:C1:00.0"));
+        assert!(!same_pci_address("0000:01:00.0", "0001:01:00.0"));
+        assert!(!same_pci_address("0000:01:00.0", "0000:02:00.0"));
+    }
+
+    #[test]
+    fn test_link_degraded_and_summary() {
+        let link = PcieLink {
+            current_gen: Some(4),
+            current_width: Some(4),
+            max_gen: Some(4),
+            max_width: Some(16),
+            ..PcieLink::default()
+        };
+        assert!(link.is_degraded());
+        assert_eq!(link.summary(), "Gen4 x4 (max x16)");
+
+        // Idle downclock to Gen1 at full width is not a fault
+        let idle = PcieLink { current_gen: Some(1), current_width: Some(16), ..link };
+        assert!(!idle.is_degraded());
+        assert_eq!(idle.summary(), "Gen1 x16");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_collect_reads_display_devices() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let gpu = [
+            ("class", "0x030000\n"),
+            ("vendor", "0x10de\n"),
+            ("current_link_speed", "16.0 GT/s PCIe\n"),
+            ("current_link_width", "4\n"),
+            ("max_link_speed", "16.0 GT/s PCIe\n"),
+            ("max_link_width", "16\n"),
+            ("pcie_bw", "1000 2000 256\n"),
+        ];
+        write_device(dir.path(), "0000:01:00.0", &gpu);
+        write_device(dir.path(), "0000:00:1f.3", &[("class", "0x040300\n")]);
+
+        let mut collector = PcieCollector::with_path(dir.path());
+        assert!(collector.is_available());
+        collector.enable_throughput_measurement();
+        let metrics = collector.collect().expect("collect");
+
+        assert_eq!(collector.links().len(), 1);
+        let link = &collector.links()[0];
+        assert_eq!(link.address, "0000:01:00.0");
+        assert_eq!(link.vendor_name(), "NVIDIA");
+        assert_eq!(link.tx_bytes_per_sec, Some(512_000.0));
+        assert_eq!(link.rx_bytes_per_sec, Some(256_000.0));
+        assert_eq!(metrics.get_counter("pcie.0.width"), Some(4));
+        assert_eq!(metrics.get_counter("pcie.degraded_count"), Some(1));
+        assert_eq!(metrics.get_gauge("pcie.0.degraded"), Some(1.0));
+
+        assert_eq!(collector.link("0000:01:00.0"), Some(link));
+        assert_eq!(collector.link("00000000:01:00.0"), Some(link));
+        assert!(collector.link("0000:00:1f.3").is_none());
+    }
+
+    #[test]
+    fn test_collect_missing_path() {
+        let mut collector = PcieCollector::with_path("/nonexistent/pci/devices");
+        assert!(!collector.is_available());
+        let metrics = collector.collect().expect("collect");
+        assert_eq!(metrics.get_counter("pcie.count"), Some(0));
+    }
+}
@@ -3,6 +3,7 @@
 //! Supports YAML configuration with precedence: CLI > ENV > file > defaults.
 
 use crate::monitor::error::{MonitorError, Result};
+use crate::monitor::presets::{self, Profile};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use std::time::Duration;
@@ -59,6 +60,18 @@ impl Default for GlobalConfig {
     }
 }
 
+/// Power panel settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PowerConfig {
+    /// Electricity price per kWh, for the session cost estimate.
+    #[serde(default)]
+    pub price_per_kwh: Option<f64>,
+
+    /// Currency label shown after the cost (e.g. "EUR").
+    #[serde(default)]
+    pub currency: String,
+}
+
 /// Main configuration structure.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -73,6 +86,14 @@ pub struct Config {
     /// Theme name or inline theme.
     #[serde(default = "default_theme")]
     pub theme: String,
+
+    /// Machine profile name (see [`presets::all_profiles`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+
+    /// Power panel settings.
+    #[serde(default)]
+    pub power: PowerConfig,
 }
 
 fn default_version() -> u32 {
@@ -84,7 +105,13 @@ fn default_theme() -> String {
 
 impl Default for Config {
     fn default() -> Self {
-        Self { version: default_version(), global: GlobalConfig::default(), theme: default_theme() }
+        Self {
+            version: default_version(),
+            global: GlobalConfig::default(),
+            theme: default_theme(),
+            profile: None,
+            power: PowerConfig::default(),
+        }
     }
 }
 
@@ -114,11 +141,47 @@ impl Config {
     /// # Errors
     ///
     /// Returns an error with line number if parsing fails.
+    /// A `profile` key selects a machine profile; its refresh rate applies
+    /// unless the file also sets `global.update_ms`.
     pub fn parse(yaml: &str) -> Result<Self> {
-        serde_yaml_ng::from_str(yaml).map_err(|e| {
+        let parse_error = |e: serde_yaml_ng::Error| {
             let line = e.location().map_or(0, |l| l.line());
             MonitorError::ConfigParse { line, message: e.to_string() }
-        })
+        };
+        let value: serde_yaml_ng::Value = serde_yaml_ng::from_str(yaml).map_err(parse_error)?;
+        let mut config: Self = serde_yaml_ng::from_str(yaml).map_err(parse_error)?;
+        if let Some(name) = config.profile.clone() {
+            let update_ms = config.global.update_ms;
+            config.apply_profile(&name)?;
+            if value.get("global").and_then(|g| g.get("update_ms")).is_some() {
+                config.global.update_ms = update_ms;
+            }
+        }
+        Ok(config)
+    }
+
+    /// Selects a machine profile by name and adopts its refresh rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MonitorError::ConfigInvalid`] if no profile has that name.
+    pub fn apply_profile(&mut self, name: &str) -> Result<()> {
+        let profile = presets::profile(name).ok_or_else(|| {
+            let known: Vec<&str> = presets::all_profiles().iter().map(|p| p.name).collect();
+            MonitorError::ConfigInvalid {
+                key: "profile".to_string(),
+                message: format!("unknown profile '{name}' (expected one of {})", known.join(", ")),
+            }
+        })?;
+        self.profile = Some(profile.name.to_string());
+        self.global.update_ms = profile.update_ms;
+        Ok(())
+    }
+
+    /// The selected machine profile, if any.
+    #[must_use]
+    pub fn profile(&self) -> Option<Profile> {
+        self.profile.as_deref().and_then(presets::profile)
     }
 
     /// Returns the update interval as a Duration.
@@ -204,4 +267,28 @@ global:
         let config = Config::load_or_default("/nonexistent/path");
         assert_eq!(config.version, 1);
     }
+
+    #[test]
+    fn test_config_power_price() {
+        let yaml = "power:\n  price_per_kwh: 0.32\n  currency: EUR\n";
+        let config = Config::parse(yaml).expect("parsing should succeed");
+        assert_eq!(config.power.price_per_kwh, Some(0.32));
+        assert_eq!(config.power.currency, "EUR");
+        assert_eq!(Config::default().power.price_per_kwh, None);
+    }
+
+    #[test]
+    fn test_config_profile() {
+        let config = Config::parse("profile: ml-node").expect("parsing should succeed");
+        assert_eq!(config.profile().map(|p| p.name), Some("ml-node"));
+        assert_eq!(config.global.update_ms, 500);
+
+        // An explicit refresh rate in the file wins over the profile's
+        let yaml = "profile: laptop\nglobal:\n  update_ms: 250\n";
+        let config = Config::parse(yaml).expect("parsing should succeed");
+        assert_eq!(config.global.update_ms, 250);
+
+        let err = Config::parse("profile: toaster").expect_err("unknown profile");
+        assert!(err.to_string().contains("ml-node"), "Error should list profiles: {err}");
+    }
 }
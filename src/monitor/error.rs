@@ -81,6 +81,15 @@ pub enum MonitorError {
     /// Permission denied for operation.
     #[error("permission denied: {0}")]
     PermissionDenied(String),
+
+    /// Persisted state (e.g. notification history) could not be read or written.
+    #[error("cannot persist '{path}': {message}")]
+    Persistence {
+        /// File being read or written.
+        path: String,
+        /// Error message describing the failure.
+        message: String,
+    },
 }
 
 /// Result type alias for monitoring operations.
@@ -196,6 +205,21 @@ mod tests {
         assert!(display.contains("cannot send signal"), "Error should include reason: {display}");
     }
 
+    #[test]
+    fn test_persistence_includes_path() {
+        let err = MonitorError::Persistence {
+            path: "/tmp/notifications.yaml".to_string(),
+            message: "read-only file system".to_string(),
+        };
+        let display = err.to_string();
+
+        assert!(
+            display.contains("/tmp/notifications.yaml"),
+            "Error should include path: {display}"
+        );
+        assert!(display.contains("read-only"), "Error should include message: {display}");
+    }
+
     #[test]
     fn test_terminal_error_display() {
         let io_err = io::Error::new(io::ErrorKind::PermissionDenied, "access denied");
@@ -29,6 +29,14 @@ pub enum Action {
     Kill,
     /// Refresh immediately.
     Refresh,
+    /// Toggle the notification center.
+    Notifications,
+    /// Acknowledge the selected notification.
+    Acknowledge,
+    /// Acknowledge all notifications.
+    AcknowledgeAll,
+    /// Toggle the correlation insights panel.
+    Correlations,
     /// No action.
     None,
 }
@@ -94,6 +102,14 @@ impl InputHandler {
             // Refresh
             KeyCode::Char('r') | KeyCode::F(5) => Action::Refresh,
 
+            // Notifications
+            KeyCode::Char('n') => Action::Notifications,
+            KeyCode::Char('a') => Action::Acknowledge,
+            KeyCode::Char('A') => Action::AcknowledgeAll,
+
+            // Correlation insights
+            KeyCode::Char('c') => Action::Correlations,
+
             _ => Action::None,
         }
     }
@@ -213,6 +229,15 @@ mod tests {
         assert_eq!(handler.handle_key(key_event(KeyCode::F(5))), Action::Refresh);
     }
 
+    #[test]
+    fn test_notification_actions() {
+        let handler = InputHandler::new(true);
+        assert_eq!(handler.handle_key(key_event(KeyCode::Char('n'))), Action::Notifications);
+        assert_eq!(handler.handle_key(key_event(KeyCode::Char('a'))), Action::Acknowledge);
+        assert_eq!(handler.handle_key(key_event(KeyCode::Char('A'))), Action::AcknowledgeAll);
+        assert_eq!(handler.handle_key(key_event(KeyCode::Char('c'))), Action::Correlations);
+    }
+
     #[test]
     fn test_ctrl_q_quits() {
         let handler = InputHandler::new(true);
@@ -106,6 +106,7 @@ pub mod app;
 pub mod input;
 pub mod layout;
 pub mod presets;
+pub mod report;
 pub mod state;
 
 pub use app::App;
@@ -0,0 +1,250 @@
+//! Correlation insights panel.
+//!
+//! Tracks a handful of metrics in ring buffers, one sample per collection
+//! cycle, ranks every pair by the Pearson coefficient of their histories
+//! (via [`simd_pearson_correlation`](crate::monitor::simd::simd_pearson_correlation))
+//! and plots the strongest pair with the [`Scatter`] widget, so the panel
+//! shows the shape of the relationship and not just its coefficient.
+
+use std::fmt::Write as _;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::Widget;
+
+use crate::monitor::ring_buffer::RingBuffer;
+use crate::monitor::state::State;
+use crate::monitor::types::MetricValue;
+use crate::monitor::widgets::Scatter;
+
+/// Fewest paired samples before a coefficient is shown.
+const MIN_SAMPLES: usize = 8;
+
+/// A metric sampled by the panel.
+#[derive(Debug, Clone)]
+struct TrackedMetric {
+    /// Short display name.
+    label: String,
+    /// Collector id the metric is recorded under in [`State`].
+    collector: String,
+    /// Metric key within the collector's metrics.
+    key: String,
+    /// One sample per cycle; `NaN` when the metric was unavailable.
+    history: RingBuffer<f64>,
+}
+
+/// A ranked metric pair.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorrelatedPair {
+    /// Index of the horizontal metric.
+    pub x: usize,
+    /// Index of the vertical metric.
+    pub y: usize,
+    /// Pearson coefficient of the paired samples.
+    pub coefficient: f64,
+}
+
+/// Panel ranking metric pairs by correlation.
+#[derive(Debug, Clone)]
+pub struct CorrelationPanel {
+    /// Tracked metrics.
+    metrics: Vec<TrackedMetric>,
+    /// History length per metric.
+    capacity: usize,
+}
+
+impl CorrelationPanel {
+    /// Creates a panel tracking CPU, memory, swap, network and power.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self::empty(capacity)
+            .track("cpu", "cpu", "cpu.total")
+            .track("mem", "memory", "memory.used.percent")
+            .track("swap", "memory", "memory.swap.percent")
+            .track("rx", "network", "network.rx_bytes_per_sec")
+            .track("tx", "network", "network.tx_bytes_per_sec")
+            .track("power", "energy", "energy.watts")
+    }
+
+    /// Creates a panel tracking no metrics.
+    #[must_use]
+    pub fn empty(capacity: usize) -> Self {
+        Self { metrics: Vec::new(), capacity: capacity.max(MIN_SAMPLES) }
+    }
+
+    /// Tracks `key` from the `collector` metrics, shown as `label`.
+    #[must_use]
+    pub fn track(mut self, label: &str, collector: &str, key: &str) -> Self {
+        self.metrics.push(TrackedMetric {
+            label: label.to_string(),
+            collector: collector.to_string(),
+            key: key.to_string(),
+            history: RingBuffer::new(self.capacity),
+        });
+        self
+    }
+
+    /// Labels of the tracked metrics, in tracking order.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.metrics.iter().map(|m| m.label.as_str())
+    }
+
+    /// Samples the latest value of every tracked metric; call once per
+    /// collection cycle so the histories stay aligned.
+    pub fn update(&mut self, state: &State) {
+        for metric in &mut self.metrics {
+            let value = state
+                .latest(&metric.collector)
+                .and_then(|m| m.get(&metric.key))
+                .and_then(|v| match v {
+                    MetricValue::Gauge(g) => Some(*g),
+                    MetricValue::Counter(c) => Some(*c as f64),
+                    _ => None,
+                })
+                .unwrap_or(f64::NAN);
+            metric.history.push(value);
+        }
+    }
+
+    /// History of one tracked metric, oldest first.
+    fn history(&self, index: usize) -> Vec<f64> {
+        self.metrics[index].history.iter().copied().collect()
+    }
+
+    /// Metric pairs with enough finite samples and a defined coefficient,
+    /// strongest (by absolute coefficient) first.
+    #[must_use]
+    pub fn pairs(&self) -> Vec<CorrelatedPair> {
+        let histories: Vec<Vec<f64>> = (0..self.metrics.len()).map(|i| self.history(i)).collect();
+        let mut pairs = Vec::new();
+        for (x, xs) in histories.iter().enumerate() {
+            for (y, ys) in histories.iter().enumerate().skip(x + 1) {
+                let paired = xs.iter().zip(ys).filter(|(a, b)| a.is_finite() && b.is_finite());
+                if paired.count() < MIN_SAMPLES {
+                    continue;
+                }
+                if let Some(coefficient) = Scatter::new(xs, ys).correlation() {
+                    pairs.push(CorrelatedPair { x, y, coefficient });
+                }
+            }
+        }
+        pairs.sort_by(|a, b| b.coefficient.abs().total_cmp(&a.coefficient.abs()));
+        pairs
+    }
+}
+
+impl Widget for &CorrelationPanel {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let width = area.width as usize;
+        let pairs = self.pairs();
+        let Some(top) = pairs.first() else {
+            let text = format!("collecting ({MIN_SAMPLES} samples needed)...");
+            buf.set_stringn(area.x, area.y, text, width, Style::default().fg(Color::DarkGray));
+            return;
+        };
+
+        // Runner-up pairs on the first row, the strongest one plotted below
+        let label = |i: usize| self.metrics[i].label.as_str();
+        let others = pairs.iter().skip(1).fold(String::new(), |mut out, p| {
+            let _ = write!(out, "{}~{} {:+.2}  ", label(p.y), label(p.x), p.coefficient);
+            out
+        });
+        let mut plot = area;
+        if !others.is_empty() && area.height >= 4 {
+            buf.set_stringn(
+                area.x,
+                area.y,
+                others.trim_end(),
+                width,
+                Style::default().fg(Color::Gray),
+            );
+            plot.y += 1;
+            plot.height -= 1;
+        }
+
+        let (xs, ys) = (self.history(top.x), self.history(top.y));
+        Scatter::new(&xs, &ys).labels(label(top.x), label(top.y)).render(plot, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitor::types::Metrics;
+
+    fn row(buf: &Buffer, y: u16) -> String {
+        (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect::<String>().trim_end().to_string()
+    }
+
+    /// Records `cycles` samples: power follows cpu, rx is noise, tx is absent.
+    fn panel_after(cycles: usize) -> CorrelationPanel {
+        let mut state = State::new(64);
+        let mut panel = CorrelationPanel::empty(64)
+            .track("cpu", "cpu", "cpu.total")
+            .track("power", "energy", "energy.watts")
+            .track("rx", "network", "network.rx_bytes_per_sec")
+            .track("tx", "network", "network.tx_bytes_per_sec");
+        for i in 0..cycles {
+            let load = (i * 37 % 100) as f64;
+            let mut cpu = Metrics::new();
+            cpu.insert("cpu.total", load);
+            let mut energy = Metrics::new();
+            energy.insert("energy.watts", 20.0 + load * 1.5);
+            let mut network = Metrics::new();
+            network.insert("network.rx_bytes_per_sec", (i * i % 7) as u64);
+            state.record("cpu", cpu, 64);
+            state.record("energy", energy, 64);
+            state.record("network", network, 64);
+            panel.update(&state);
+        }
+        panel
+    }
+
+    #[test]
+    fn test_pairs_ranked_by_strength() {
+        let panel = panel_after(20);
+        let pairs = panel.pairs();
+        assert_eq!((pairs[0].x, pairs[0].y), (0, 1));
+        assert!((pairs[0].coefficient - 1.0).abs() < 1e-9);
+        // tx never reported, so no pair involves it
+        assert!(pairs.iter().all(|p| p.y != 3));
+        assert_eq!(pairs.len(), 3);
+    }
+
+    #[test]
+    fn test_needs_enough_samples() {
+        assert!(panel_after(MIN_SAMPLES - 1).pairs().is_empty());
+        assert!(!panel_after(MIN_SAMPLES).pairs().is_empty());
+
+        let panel = panel_after(2);
+        let area = Rect::new(0, 0, 40, 6);
+        let mut buf = Buffer::empty(area);
+        (&panel).render(area, &mut buf);
+        assert!(row(&buf, 0).starts_with("collecting"));
+    }
+
+    #[test]
+    fn test_render_plots_strongest_pair() {
+        let panel = panel_after(20);
+        let area = Rect::new(0, 0, 40, 8);
+        let mut buf = Buffer::empty(area);
+        (&panel).render(area, &mut buf);
+
+        assert!(row(&buf, 0).contains("rx~cpu") && row(&buf, 0).contains("rx~power"));
+        assert_eq!(row(&buf, 1), "power vs cpu  r=+1.00");
+        let braille = (2..8).flat_map(|y| (0..40).map(move |x| (x, y))).any(|(x, y)| {
+            buf[(x, y)].symbol().chars().any(|c| ('\u{2801}'..='\u{28ff}').contains(&c))
+        });
+        assert!(braille);
+    }
+
+    #[test]
+    fn test_default_metrics() {
+        let labels: Vec<_> = CorrelationPanel::new(60).labels().map(str::to_string).collect();
+        assert_eq!(labels, ["cpu", "mem", "swap", "rx", "tx", "power"]);
+    }
+}
@@ -1,8 +1,17 @@
 //! Disk monitoring panel.
 //!
-//! Displays disk I/O metrics and mount point usage.
+//! Displays disk I/O metrics and mount point usage, with estimated request
+//! latency percentiles and a small latency distribution per mount.
 
-use crate::monitor::collectors::DiskCollector;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::Widget;
+
+use crate::monitor::collectors::{DiskCollector, IoLatency};
+
+/// Block characters for distribution bars, lowest to highest.
+const BAR_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
 
 /// Panel for disk metrics visualization.
 #[derive(Debug)]
@@ -25,6 +34,59 @@ impl Default for DiskPanel {
     }
 }
 
+/// One bar per latency bucket, scaled to the fullest bucket.
+fn distribution_bars(latency: &IoLatency) -> String {
+    let dist = latency.distribution();
+    let peak = dist.iter().copied().fold(0.0, f64::max);
+    dist.iter()
+        .map(|&f| {
+            if f <= 0.0 || peak <= 0.0 {
+                ' '
+            } else {
+                let level = ((f / peak) * (BAR_LEVELS.len() - 1) as f64).round() as usize;
+                BAR_LEVELS[level.min(BAR_LEVELS.len() - 1)]
+            }
+        })
+        .collect()
+}
+
+impl Widget for &DiskPanel {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let width = area.width as usize;
+        let header = format!("{:<12} {:>6} {:>6} {:>6} ms", "Mount", "p50", "p95", "p99");
+        buf.set_stringn(area.x, area.y, header, width, Style::default().fg(Color::DarkGray));
+
+        let rows = self
+            .collector
+            .mounts()
+            .iter()
+            .filter_map(|m| Some((m, self.collector.mount_latency(m)?)))
+            .filter_map(|(m, latency)| Some((m, latency, latency.percentiles()?)));
+        for (y, (mount, latency, p)) in (area.y + 1..area.y + area.height).zip(rows) {
+            // Colored by the tail: what users of the mount notice
+            let color = if p.p99 < 5.0 {
+                Color::Green
+            } else if p.p99 < 20.0 {
+                Color::Yellow
+            } else {
+                Color::Red
+            };
+            let name: String = mount.mount_point.chars().take(12).collect();
+            let line = format!(
+                "{name:<12} {:>6.1} {:>6.1} {:>6.1}    {}",
+                p.p50,
+                p.p95,
+                p.p99,
+                distribution_bars(latency)
+            );
+            buf.set_stringn(area.x, y, line, width, Style::default().fg(color));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,4 +102,30 @@ mod tests {
         let panel = DiskPanel::default();
         assert!(panel.collector.mounts().is_empty());
     }
+
+    #[test]
+    fn test_distribution_bars() {
+        let mut latency = IoLatency::default();
+        assert_eq!(distribution_bars(&latency).trim(), "");
+        latency.record(4, 0); // <= 0.5 ms
+        latency.record(2, 8); // 4 ms -> <= 5 ms
+        latency.record(1, 500); // overflow
+        let bars: Vec<char> = distribution_bars(&latency).chars().collect();
+        assert_eq!(bars.len(), 9);
+        assert_eq!(bars[0], '█');
+        assert_eq!(bars[1], ' ');
+        assert_eq!(bars[3], '▅');
+        assert_eq!(bars[8], '▃');
+    }
+
+    #[test]
+    fn test_disk_panel_renders_header() {
+        let panel = DiskPanel::new();
+        let area = Rect::new(0, 0, 40, 3);
+        let mut buf = Buffer::empty(area);
+        (&panel).render(area, &mut buf);
+        let header: String = (0..40).map(|x| buf[(x, 0)].symbol()).collect();
+        assert!(header.starts_with("Mount"));
+        assert!(header.contains("p99"));
+    }
 }
@@ -0,0 +1,185 @@
+//! GPU panel component.
+//!
+//! Gathers the vendor collectors available in this build (NVML with the
+//! `monitor-nvidia` feature, ROCm SMI on Linux) into one list of
+//! [`GpuReading`]s, shown by the monitor's `gpu` (utilization and
+//! temperature) and `vram` panels. NVML PCIe throughput is switched on, so
+//! readings carry host↔device rates for the `pcie` panel.
+
+#[cfg(target_os = "linux")]
+use crate::monitor::collectors::AmdGpuCollector;
+#[cfg(feature = "monitor-nvidia")]
+use crate::monitor::collectors::NvidiaGpuCollector;
+#[cfg(any(feature = "monitor-nvidia", target_os = "linux"))]
+use crate::monitor::types::Collector;
+use crate::monitor::types::{MetricValue, Metrics};
+
+/// Vendor-neutral state of one GPU.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GpuReading {
+    /// GPU name/model.
+    pub name: String,
+    /// PCI address, as reported by the vendor library.
+    pub pci_address: String,
+    /// GPU utilization percentage (0-100).
+    pub util: f64,
+    /// GPU temperature in Celsius.
+    pub temperature: f64,
+    /// VRAM used in bytes.
+    pub vram_used: u64,
+    /// VRAM total in bytes.
+    pub vram_total: u64,
+    /// Host→device throughput in bytes/second (if measured).
+    pub pcie_tx_bytes_per_sec: Option<f64>,
+    /// Device→host throughput in bytes/second (if measured).
+    pub pcie_rx_bytes_per_sec: Option<f64>,
+}
+
+impl GpuReading {
+    /// VRAM usage percentage (0-100), 0 when the total is unknown.
+    #[must_use]
+    pub fn vram_percent(&self) -> f64 {
+        if self.vram_total == 0 {
+            return 0.0;
+        }
+        self.vram_used as f64 / self.vram_total as f64 * 100.0
+    }
+}
+
+/// GPU monitoring panel.
+#[derive(Debug)]
+pub struct GpuPanel {
+    /// NVIDIA collector.
+    #[cfg(feature = "monitor-nvidia")]
+    pub nvidia: NvidiaGpuCollector,
+    /// AMD collector.
+    #[cfg(target_os = "linux")]
+    pub amd: AmdGpuCollector,
+    /// Readings from the last collection, NVIDIA first.
+    readings: Vec<GpuReading>,
+}
+
+impl GpuPanel {
+    /// Creates a GPU panel with every vendor collector of this build.
+    #[must_use]
+    pub fn new() -> Self {
+        #[cfg(feature = "monitor-nvidia")]
+        let nvidia = {
+            let mut collector = NvidiaGpuCollector::new();
+            collector.enable_pcie_measurement();
+            collector
+        };
+        Self {
+            #[cfg(feature = "monitor-nvidia")]
+            nvidia,
+            #[cfg(target_os = "linux")]
+            amd: AmdGpuCollector::new(),
+            readings: Vec::new(),
+        }
+    }
+
+    /// Returns whether any vendor collector found a GPU.
+    #[must_use]
+    pub fn is_available(&self) -> bool {
+        #[allow(unused_mut)]
+        let mut available = false;
+        #[cfg(feature = "monitor-nvidia")]
+        {
+            available |= Collector::is_available(&self.nvidia);
+        }
+        #[cfg(target_os = "linux")]
+        {
+            available |= Collector::is_available(&self.amd);
+        }
+        available
+    }
+
+    /// Readings from the last collection.
+    #[must_use]
+    pub fn readings(&self) -> &[GpuReading] {
+        &self.readings
+    }
+
+    /// Collects every available vendor and returns `gpu.<i>.*` metrics
+    /// numbered in reading order.
+    pub fn collect(&mut self) -> Metrics {
+        #[allow(unused_mut)]
+        let mut readings = Vec::new();
+        #[cfg(feature = "monitor-nvidia")]
+        if Collector::is_available(&self.nvidia) && Collector::collect(&mut self.nvidia).is_ok() {
+            readings.extend(self.nvidia.gpus().iter().map(|gpu| GpuReading {
+                name: gpu.name.clone(),
+                pci_address: gpu.pcie_link.as_ref().map(|l| l.address.clone()).unwrap_or_default(),
+                util: gpu.gpu_util,
+                temperature: gpu.temperature,
+                vram_used: gpu.mem_used,
+                vram_total: gpu.mem_total,
+                pcie_tx_bytes_per_sec: gpu.pcie_tx_kbps.map(|kb| f64::from(kb) * 1024.0),
+                pcie_rx_bytes_per_sec: gpu.pcie_rx_kbps.map(|kb| f64::from(kb) * 1024.0),
+            }));
+        }
+        #[cfg(target_os = "linux")]
+        if Collector::is_available(&self.amd) && Collector::collect(&mut self.amd).is_ok() {
+            readings.extend(self.amd.gpus().iter().map(|gpu| GpuReading {
+                name: gpu.name.clone(),
+                pci_address: gpu.pci_address.clone(),
+                util: gpu.gpu_util,
+                temperature: gpu.temperature,
+                vram_used: gpu.vram_used,
+                vram_total: gpu.vram_total,
+                pcie_tx_bytes_per_sec: Some(gpu.pcie_tx_kbps as f64 * 1024.0),
+                pcie_rx_bytes_per_sec: Some(gpu.pcie_rx_kbps as f64 * 1024.0),
+            }));
+        }
+        self.set_readings(readings);
+        self.metrics()
+    }
+
+    /// Replaces the readings, e.g. with ones received from a remote host.
+    pub fn set_readings(&mut self, readings: Vec<GpuReading>) {
+        self.readings = readings;
+    }
+
+    /// Metrics for the current readings.
+    fn metrics(&self) -> Metrics {
+        let mut metrics = Metrics::new();
+        metrics.insert("gpu.count", MetricValue::Counter(self.readings.len() as u64));
+        for (i, gpu) in self.readings.iter().enumerate() {
+            metrics.insert(format!("gpu.{i}.util"), gpu.util);
+            metrics.insert(format!("gpu.{i}.temp"), gpu.temperature);
+            metrics.insert(format!("gpu.{i}.vram_used"), MetricValue::Counter(gpu.vram_used));
+            metrics.insert(format!("gpu.{i}.vram_total"), MetricValue::Counter(gpu.vram_total));
+            metrics.insert(format!("gpu.{i}.vram_percent"), gpu.vram_percent());
+        }
+        metrics
+    }
+}
+
+impl Default for GpuPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gpu_panel_metrics() {
+        let mut panel = GpuPanel::new();
+        panel.set_readings(vec![GpuReading {
+            name: "RTX 4090".to_string(),
+            util: 87.0,
+            temperature: 71.0,
+            vram_used: 6 << 30,
+            vram_total: 24 << 30,
+            ..GpuReading::default()
+        }]);
+        let metrics = panel.metrics();
+        assert_eq!(metrics.get_counter("gpu.count"), Some(1));
+        assert_eq!(metrics.get_gauge("gpu.0.util"), Some(87.0));
+        assert_eq!(metrics.get_gauge("gpu.0.vram_percent"), Some(25.0));
+        assert!(GpuReading::default().vram_percent().abs() < f64::EPSILON);
+    }
+}
@@ -3,14 +3,22 @@
 //! Each panel combines widgets with collectors to display a specific
 //! category of metrics.
 
+pub mod correlation;
 pub mod cpu;
 pub mod disk;
+pub mod gpu;
 pub mod memory;
 pub mod network;
+pub mod notifications;
+pub mod power;
 pub mod process;
 
+pub use correlation::{CorrelatedPair, CorrelationPanel};
 pub use cpu::CpuPanel;
 pub use disk::DiskPanel;
+pub use gpu::{GpuPanel, GpuReading};
 pub use memory::MemoryPanel;
 pub use network::NetworkPanel;
+pub use notifications::{Notification, NotificationCenter, Severity};
+pub use power::PowerPanel;
 pub use process::ProcessPanel;
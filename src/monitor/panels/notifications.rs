@@ -0,0 +1,436 @@
+//! Notification center panel.
+//!
+//! Collects alert and anomaly events with a timestamp and severity so that a
+//! warning raised for a single refresh is still visible after the metric
+//! recovers. Events stay until acknowledged (`a`, or `A` for all) and the
+//! history is persisted to disk between sessions.
+//!
+//! A condition that keeps firing every refresh does not flood the list: an
+//! unacknowledged event with the same source and message is updated in place
+//! and its repeat count incremented.
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::Widget;
+use serde::{Deserialize, Serialize};
+
+use crate::monitor::error::{MonitorError, Result};
+
+/// Default number of notifications kept.
+pub const DEFAULT_CAPACITY: usize = 200;
+
+/// Severity of a notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Informational.
+    Info,
+    /// Needs attention.
+    Warning,
+    /// Needs action now.
+    Critical,
+}
+
+impl Severity {
+    /// Marker shown before the message.
+    #[must_use]
+    pub const fn symbol(self) -> char {
+        match self {
+            Self::Info => 'i',
+            Self::Warning => '!',
+            Self::Critical => '‼',
+        }
+    }
+
+    /// Display color.
+    #[must_use]
+    pub const fn color(self) -> Color {
+        match self {
+            Self::Info => Color::Cyan,
+            Self::Warning => Color::Yellow,
+            Self::Critical => Color::Red,
+        }
+    }
+}
+
+/// A single alert or anomaly event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Notification {
+    /// Unique id within the center.
+    pub id: u64,
+    /// Unix time of the latest occurrence, in seconds.
+    pub timestamp: u64,
+    /// Severity.
+    pub severity: Severity,
+    /// Origin, e.g. a collector or metric name.
+    pub source: String,
+    /// Human-readable description.
+    pub message: String,
+    /// Number of occurrences merged into this entry.
+    #[serde(default = "default_count")]
+    pub count: u32,
+    /// Whether the user has acknowledged it.
+    #[serde(default)]
+    pub acknowledged: bool,
+}
+
+fn default_count() -> u32 {
+    1
+}
+
+/// History of notifications, newest last, with a selection cursor.
+#[derive(Debug, Clone)]
+pub struct NotificationCenter {
+    /// Notifications, oldest first.
+    notifications: VecDeque<Notification>,
+    /// Maximum number kept; the oldest are dropped first.
+    capacity: usize,
+    /// Next id to assign.
+    next_id: u64,
+    /// Selected row, counted from the newest.
+    selected: usize,
+    /// File the history is persisted to.
+    path: Option<PathBuf>,
+}
+
+impl NotificationCenter {
+    /// Creates an empty, in-memory notification center.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            notifications: VecDeque::new(),
+            capacity: capacity.max(1),
+            next_id: 1,
+            selected: 0,
+            path: None,
+        }
+    }
+
+    /// Default history file: `notifications.yaml` in the platform state
+    /// (or data) directory under `trueno-monitor/`.
+    #[must_use]
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::state_dir()
+            .or_else(dirs::data_dir)
+            .map(|dir| dir.join("trueno-monitor").join("notifications.yaml"))
+    }
+
+    /// Loads the history from `path`, starting empty if the file does not
+    /// exist yet. Later [`save`](Self::save) calls write back to `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read or parsed.
+    pub fn load(path: impl AsRef<Path>, capacity: usize) -> Result<Self> {
+        let path = path.as_ref();
+        let mut center = Self::new(capacity);
+        center.path = Some(path.to_path_buf());
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(center),
+            Err(e) => return Err(persistence_error(path, e)),
+        };
+        let saved: Vec<Notification> =
+            serde_yaml_ng::from_str(&content).map_err(|e| persistence_error(path, e))?;
+        center.next_id = saved.iter().map(|n| n.id + 1).max().unwrap_or(1);
+        center.notifications = saved.into();
+        center.trim();
+        Ok(center)
+    }
+
+    /// Writes the history to the file it was loaded from; a no-op for an
+    /// in-memory center.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or file cannot be written.
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| persistence_error(path, e))?;
+        }
+        let yaml = serde_yaml_ng::to_string(&self.notifications)
+            .map_err(|e| persistence_error(path, e))?;
+        std::fs::write(path, yaml).map_err(|e| persistence_error(path, e))
+    }
+
+    /// Records an event now; see [`push_at`](Self::push_at).
+    pub fn push(&mut self, severity: Severity, source: &str, message: &str) -> u64 {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        self.push_at(now, severity, source, message)
+    }
+
+    /// Records an event at `timestamp` (Unix seconds) and returns its id.
+    ///
+    /// An unacknowledged event with the same source and message is updated
+    /// instead: its timestamp moves forward, its count increases and its
+    /// severity is raised if the new one is higher.
+    pub fn push_at(
+        &mut self,
+        timestamp: u64,
+        severity: Severity,
+        source: &str,
+        message: &str,
+    ) -> u64 {
+        if let Some(existing) = self
+            .notifications
+            .iter_mut()
+            .rev()
+            .find(|n| !n.acknowledged && n.source == source && n.message == message)
+        {
+            existing.timestamp = existing.timestamp.max(timestamp);
+            existing.count = existing.count.saturating_add(1);
+            existing.severity = existing.severity.max(severity);
+            return existing.id;
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.notifications.push_back(Notification {
+            id,
+            timestamp,
+            severity,
+            source: source.to_string(),
+            message: message.to_string(),
+            count: 1,
+            acknowledged: false,
+        });
+        self.trim();
+        id
+    }
+
+    /// Drops the oldest notifications beyond capacity, acknowledged first.
+    fn trim(&mut self) {
+        while self.notifications.len() > self.capacity {
+            let victim = self.notifications.iter().position(|n| n.acknowledged).unwrap_or(0);
+            self.notifications.remove(victim);
+        }
+        self.selected = self.selected.min(self.notifications.len().saturating_sub(1));
+    }
+
+    /// Notifications, newest first.
+    pub fn iter(&self) -> impl Iterator<Item = &Notification> {
+        self.notifications.iter().rev()
+    }
+
+    /// Number of notifications.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.notifications.len()
+    }
+
+    /// Whether there are no notifications.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.notifications.is_empty()
+    }
+
+    /// Number of notifications not yet acknowledged.
+    #[must_use]
+    pub fn unacknowledged(&self) -> usize {
+        self.notifications.iter().filter(|n| !n.acknowledged).count()
+    }
+
+    /// Highest severity among unacknowledged notifications.
+    #[must_use]
+    pub fn max_unacknowledged_severity(&self) -> Option<Severity> {
+        self.notifications.iter().filter(|n| !n.acknowledged).map(|n| n.severity).max()
+    }
+
+    /// The selected notification.
+    #[must_use]
+    pub fn selected(&self) -> Option<&Notification> {
+        self.iter().nth(self.selected)
+    }
+
+    /// Moves the selection towards older notifications.
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.notifications.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Moves the selection towards newer notifications.
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Acknowledges the selected notification.
+    pub fn acknowledge_selected(&mut self) {
+        let len = self.notifications.len();
+        if let Some(n) =
+            len.checked_sub(self.selected + 1).and_then(|i| self.notifications.get_mut(i))
+        {
+            n.acknowledged = true;
+        }
+    }
+
+    /// Acknowledges every notification.
+    pub fn acknowledge_all(&mut self) {
+        for n in &mut self.notifications {
+            n.acknowledged = true;
+        }
+    }
+
+    /// Removes acknowledged notifications.
+    pub fn clear_acknowledged(&mut self) {
+        self.notifications.retain(|n| !n.acknowledged);
+        self.selected = self.selected.min(self.notifications.len().saturating_sub(1));
+    }
+}
+
+impl Default for NotificationCenter {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+fn persistence_error(path: &Path, error: impl std::fmt::Display) -> MonitorError {
+    MonitorError::Persistence { path: path.display().to_string(), message: error.to_string() }
+}
+
+/// `HH:MM:SS` (UTC) of a Unix timestamp.
+fn clock(timestamp: u64) -> String {
+    let secs = timestamp % 86_400;
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+impl Widget for &NotificationCenter {
+    /// Renders a header with the unacknowledged count, then one row per
+    /// notification, newest first. Acknowledged rows are dimmed and the
+    /// selected row is reversed.
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        let header_color = self.max_unacknowledged_severity().map_or(Color::White, Severity::color);
+        let header = format!("Notifications: {} unacknowledged", self.unacknowledged());
+        buf.set_stringn(
+            area.x,
+            area.y,
+            header,
+            area.width as usize,
+            Style::default().fg(header_color).add_modifier(Modifier::BOLD),
+        );
+
+        for (row, n) in self.iter().enumerate().take(area.height as usize - 1) {
+            let repeat = if n.count > 1 { format!(" (x{})", n.count) } else { String::new() };
+            let line = format!(
+                "{} {} {}: {}{repeat}",
+                clock(n.timestamp),
+                n.severity.symbol(),
+                n.source,
+                n.message
+            );
+            let mut style = Style::default().fg(if n.acknowledged {
+                Color::DarkGray
+            } else {
+                n.severity.color()
+            });
+            if row == self.selected {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            buf.set_stringn(area.x, area.y + 1 + row as u16, line, area.width as usize, style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeats_merge_until_acknowledged() {
+        let mut center = NotificationCenter::new(10);
+        let a = center.push_at(100, Severity::Warning, "cpu", "usage above 90%");
+        let b = center.push_at(101, Severity::Critical, "cpu", "usage above 90%");
+        assert_eq!(a, b);
+        let merged = center.selected().expect("one notification");
+        assert_eq!((merged.count, merged.timestamp, merged.severity), (2, 101, Severity::Critical));
+
+        center.acknowledge_selected();
+        assert_eq!(center.unacknowledged(), 0);
+        let c = center.push_at(102, Severity::Warning, "cpu", "usage above 90%");
+        assert_ne!(a, c);
+        assert_eq!(center.len(), 2);
+        assert_eq!(center.unacknowledged(), 1);
+    }
+
+    #[test]
+    fn test_capacity_drops_acknowledged_first() {
+        let mut center = NotificationCenter::new(2);
+        center.push_at(1, Severity::Info, "a", "old");
+        center.push_at(2, Severity::Info, "b", "acked");
+        center.select_prev();
+        center.acknowledge_selected();
+        center.push_at(3, Severity::Info, "c", "new");
+        let sources: Vec<&str> = center.iter().map(|n| n.source.as_str()).collect();
+        assert_eq!(sources, ["c", "a"]);
+    }
+
+    #[test]
+    fn test_selection_and_acknowledge_all() {
+        let mut center = NotificationCenter::default();
+        center.push_at(1, Severity::Info, "disk", "mounted");
+        center.push_at(2, Severity::Warning, "mem", "swap thrashing");
+        center.select_next();
+        center.select_next();
+        assert_eq!(center.selected().map(|n| n.source.as_str()), Some("disk"));
+        center.acknowledge_selected();
+        assert_eq!(center.max_unacknowledged_severity(), Some(Severity::Warning));
+        center.acknowledge_all();
+        center.clear_acknowledged();
+        assert!(center.is_empty() && center.selected().is_none());
+    }
+
+    #[test]
+    fn test_persistence_round_trip() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("state").join("notifications.yaml");
+        let mut center = NotificationCenter::load(&path, 10).expect("missing file is empty");
+        assert!(center.is_empty());
+        center.push_at(5, Severity::Critical, "gpu0", "XID 79");
+        center.push_at(6, Severity::Info, "net", "link up");
+        center.acknowledge_selected();
+        center.save().expect("save");
+
+        let mut loaded = NotificationCenter::load(&path, 10).expect("load");
+        assert_eq!(loaded.iter().collect::<Vec<_>>(), center.iter().collect::<Vec<_>>());
+        let id = loaded.push_at(7, Severity::Info, "net", "link down");
+        assert_eq!(id, 3);
+
+        std::fs::write(&path, "not: [a list").expect("write");
+        assert!(matches!(
+            NotificationCenter::load(&path, 10),
+            Err(MonitorError::Persistence { .. })
+        ));
+    }
+
+    #[test]
+    fn test_render_rows() {
+        let mut center = NotificationCenter::new(10);
+        center.push_at(3_723, Severity::Critical, "gpu0", "XID 79");
+        center.push_at(3_724, Severity::Info, "net", "link up");
+        center.acknowledge_selected();
+        let area = Rect::new(0, 0, 40, 4);
+        let mut buf = Buffer::empty(area);
+        (&center).render(area, &mut buf);
+
+        let row = |y: u16| (0..40).map(|x| buf[(x, y)].symbol().to_string()).collect::<String>();
+        assert!(row(0).starts_with("Notifications: 1 unacknowledged"));
+        assert_eq!(buf[(0, 0)].fg, Color::Red);
+        assert!(row(1).starts_with("01:02:04 i net: link up"));
+        assert_eq!(buf[(0, 1)].fg, Color::DarkGray);
+        assert!(buf[(0, 1)].modifier.contains(Modifier::REVERSED));
+        assert!(row(2).starts_with("01:02:03 ‼ gpu0: XID 79"));
+        assert_eq!(buf[(0, 2)].fg, Color::Red);
+    }
+}
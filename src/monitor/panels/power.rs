@@ -0,0 +1,142 @@
+//! Power panel component.
+//!
+//! Shows the current draw of each energy domain and the energy used since
+//! the monitor started, with an estimated cost when an electricity price is
+//! configured (`power.price_per_kwh`).
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::Widget;
+
+use crate::monitor::collectors::EnergyCollector;
+use crate::monitor::config::PowerConfig;
+
+/// Power and energy panel.
+#[derive(Debug)]
+pub struct PowerPanel {
+    /// Energy collector.
+    pub collector: EnergyCollector,
+    /// Price and currency for the cost estimate.
+    config: PowerConfig,
+}
+
+impl PowerPanel {
+    /// Creates a new power panel without a cost estimate.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { collector: EnergyCollector::new(), config: PowerConfig::default() }
+    }
+
+    /// Sets the price per kWh used for the cost estimate.
+    #[must_use]
+    pub fn pricing(mut self, config: PowerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Session summary line, e.g. "0.1000 kWh ≈ 0.03 EUR".
+    fn session_line(&self) -> String {
+        let kwh = self.collector.session_kwh();
+        match self.config.price_per_kwh {
+            Some(price) => {
+                let cost = self.collector.session_cost(price);
+                format!("{kwh:.4} kWh ≈ {cost:.2} {}", self.config.currency).trim_end().to_string()
+            }
+            None => format!("{kwh:.4} kWh"),
+        }
+    }
+}
+
+impl Default for PowerPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Widget for &PowerPanel {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let width = area.width as usize;
+        let label = Style::default().fg(Color::DarkGray);
+
+        let total = format!("{:.1} W", self.collector.total_watts());
+        buf.set_stringn(area.x, area.y, "Now", width, label);
+        buf.set_stringn(
+            area.x + 8,
+            area.y,
+            &total,
+            width.saturating_sub(8),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        );
+        if area.height < 2 {
+            return;
+        }
+        buf.set_stringn(area.x, area.y + 1, "Session", width, label);
+        buf.set_stringn(
+            area.x + 8,
+            area.y + 1,
+            self.session_line(),
+            width.saturating_sub(8),
+            Style::default().fg(Color::White),
+        );
+
+        // Nested domains are listed dimmed: they are already in the total
+        let rows = self.collector.domains().iter().take(area.height as usize - 2);
+        for (y, domain) in (area.y + 2..).zip(rows) {
+            let style = if domain.counted {
+                Style::default().fg(Color::Cyan)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            let line = format!("{:<10} {:>7.1} W", domain.name, domain.watts);
+            buf.set_stringn(area.x, y, &line, width, style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitor::types::Collector;
+
+    fn row(buf: &Buffer, y: u16) -> String {
+        (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect::<String>().trim_end().to_string()
+    }
+
+    #[test]
+    fn test_power_panel_session_cost() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let hwmon = dir.path().join("hwmon0");
+        std::fs::create_dir_all(&hwmon).expect("hwmon dir");
+        std::fs::write(hwmon.join("name"), "amd_energy").expect("name");
+        std::fs::write(hwmon.join("energy1_label"), "Esocket0").expect("label");
+        std::fs::write(hwmon.join("energy1_input"), "0").expect("input");
+
+        let pricing = PowerConfig { price_per_kwh: Some(0.3), currency: "EUR".to_string() };
+        let mut panel = PowerPanel::new().pricing(pricing);
+        panel.collector = EnergyCollector::with_paths("/nonexistent", dir.path());
+        panel.collector.collect().expect("collect");
+        // 360 kJ = 0.1 kWh
+        std::fs::write(hwmon.join("energy1_input"), "360000000000").expect("input");
+        panel.collector.collect().expect("collect");
+
+        let area = Rect::new(0, 0, 40, 4);
+        let mut buf = Buffer::empty(area);
+        (&panel).render(area, &mut buf);
+        assert_eq!(row(&buf, 1), "Session 0.1000 kWh ≈ 0.03 EUR");
+        assert!(row(&buf, 2).starts_with("Esocket0"));
+        assert_eq!(buf[(0, 2)].fg, Color::Cyan);
+    }
+
+    #[test]
+    fn test_power_panel_without_price() {
+        let panel = PowerPanel::default();
+        assert_eq!(panel.session_line(), "0.0000 kWh");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+        (&panel).render(Rect::new(0, 0, 20, 1), &mut buf);
+        assert_eq!(row(&buf, 0), "Now     0.0 W");
+    }
+}
@@ -1,6 +1,9 @@
-//! Layout presets for the TUI monitor.
+//! Layout presets and machine profiles for the TUI monitor.
 //!
-//! Provides predefined layouts that can be selected with keys 0-9.
+//! Provides predefined layouts that can be selected with keys 0-9, and
+//! curated [`Profile`]s (panel selection, alert thresholds and refresh rate)
+//! for common kinds of machine, selected with the `profile` config key or
+//! the `--profile` flag.
 
 use crate::monitor::layout::{LayoutRow, Preset};
 use ratatui::layout::Constraint;
@@ -233,6 +236,175 @@ pub fn all_presets() -> Vec<Preset> {
     ]
 }
 
+/// Alert thresholds, in percent (temperatures in °C).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlertThresholds {
+    /// Total CPU usage raising a warning.
+    pub cpu_percent: f64,
+    /// Memory usage raising a critical alert.
+    pub memory_percent: f64,
+    /// Swap usage raising a warning (a sign of thrashing).
+    pub swap_percent: f64,
+    /// GPU temperature raising a warning.
+    pub gpu_temp_c: f64,
+    /// Descriptor or thread usage, relative to its limit, raising a warning.
+    pub limit_percent: f64,
+}
+
+impl Default for AlertThresholds {
+    fn default() -> Self {
+        Self {
+            cpu_percent: 90.0,
+            memory_percent: 95.0,
+            swap_percent: 50.0,
+            gpu_temp_c: 85.0,
+            limit_percent: 90.0,
+        }
+    }
+}
+
+/// A curated monitor setup for a kind of machine.
+#[derive(Debug, Clone)]
+pub struct Profile {
+    /// Name used in config files and on the command line.
+    pub name: &'static str,
+    /// One-line description.
+    pub description: &'static str,
+    /// Panel layout.
+    pub preset: Preset,
+    /// Alert thresholds.
+    pub thresholds: AlertThresholds,
+    /// Refresh interval in milliseconds.
+    pub update_ms: u64,
+}
+
+/// Profile for a GPU training node.
+///
+/// Emphasizes GPU utilization, VRAM, PCIe throughput and swap thrashing,
+/// which starves the data loader, refreshed twice a second. GPU readings
+/// come from NVML (`monitor-nvidia` feature) and ROCm SMI on Linux.
+///
+/// Layout:
+/// ```text
+/// ┌──────────────┬──────────────┐
+/// │  GPU (30%)   │  VRAM (30%)  │
+/// ├──────────────┼──────────────┤
+/// │  PCIe (25%)  │  Swap (25%)  │
+/// ├──────────────┼──────────────┤
+/// │   CPU (15%)  │ Memory (15%) │
+/// ├──────────────┴──────────────┤
+/// │     Correlations (15%)      │
+/// ├─────────────────────────────┤
+/// │       Processes (15%)       │
+/// └─────────────────────────────┘
+/// ```
+#[must_use]
+pub fn profile_ml_node() -> Profile {
+    Profile {
+        name: "ml-node",
+        description: "GPU training node: GPU, VRAM, PCIe throughput, swap thrashing, correlations",
+        preset: Preset {
+            rows: vec![
+                LayoutRow {
+                    panels: vec!["gpu".to_string(), "vram".to_string()],
+                    height: Constraint::Percentage(30),
+                },
+                LayoutRow {
+                    panels: vec!["pcie".to_string(), "swap".to_string()],
+                    height: Constraint::Percentage(25),
+                },
+                LayoutRow {
+                    panels: vec!["cpu".to_string(), "memory".to_string()],
+                    height: Constraint::Percentage(15),
+                },
+                LayoutRow {
+                    panels: vec!["correlation".to_string()],
+                    height: Constraint::Percentage(15),
+                },
+                LayoutRow {
+                    panels: vec!["process".to_string()],
+                    height: Constraint::Percentage(15),
+                },
+            ],
+        },
+        // Any swapping starves the data loader, so warn early
+        thresholds: AlertThresholds { swap_percent: 10.0, gpu_temp_c: 83.0, ..Default::default() },
+        update_ms: 500,
+    }
+}
+
+/// Profile for a laptop: CPU, power draw and memory at a slow refresh to
+/// save power.
+#[must_use]
+pub fn profile_laptop() -> Profile {
+    Profile {
+        name: "laptop",
+        description: "Laptop: CPU, power draw, memory and swap at a power-saving refresh",
+        preset: Preset {
+            rows: vec![
+                LayoutRow {
+                    panels: vec!["cpu".to_string(), "power".to_string()],
+                    height: Constraint::Percentage(30),
+                },
+                LayoutRow {
+                    panels: vec!["memory".to_string(), "swap".to_string()],
+                    height: Constraint::Percentage(25),
+                },
+                LayoutRow {
+                    panels: vec!["process".to_string()],
+                    height: Constraint::Percentage(45),
+                },
+            ],
+        },
+        thresholds: AlertThresholds { cpu_percent: 95.0, ..Default::default() },
+        update_ms: 2000,
+    }
+}
+
+/// Profile for a server: CPU, memory and network above the process list.
+#[must_use]
+pub fn profile_server() -> Profile {
+    Profile {
+        name: "server",
+        description: "Server: CPU, memory, swap, network and processes",
+        preset: Preset {
+            rows: vec![
+                LayoutRow {
+                    panels: vec!["cpu".to_string(), "network".to_string()],
+                    height: Constraint::Percentage(25),
+                },
+                LayoutRow {
+                    panels: vec!["memory".to_string(), "swap".to_string()],
+                    height: Constraint::Percentage(20),
+                },
+                LayoutRow {
+                    panels: vec!["process".to_string()],
+                    height: Constraint::Percentage(55),
+                },
+            ],
+        },
+        thresholds: AlertThresholds {
+            cpu_percent: 85.0,
+            memory_percent: 90.0,
+            limit_percent: 80.0,
+            ..Default::default()
+        },
+        update_ms: 1000,
+    }
+}
+
+/// Returns all machine profiles.
+#[must_use]
+pub fn all_profiles() -> Vec<Profile> {
+    vec![profile_ml_node(), profile_laptop(), profile_server()]
+}
+
+/// Looks up a profile by name (case-insensitive).
+#[must_use]
+pub fn profile(name: &str) -> Option<Profile> {
+    all_profiles().into_iter().find(|p| p.name.eq_ignore_ascii_case(name))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -314,4 +486,27 @@ mod tests {
             assert_eq!(total, 100, "Preset rows should sum to 100%");
         }
     }
+
+    #[test]
+    fn test_profiles_lookup_and_layouts() {
+        let ml = profile("ML-Node").expect("ml-node profile");
+        let panels: Vec<&str> =
+            ml.preset.rows.iter().flat_map(|r| r.panels.iter().map(String::as_str)).collect();
+        for panel in ["gpu", "vram", "pcie", "swap", "correlation"] {
+            assert!(panels.contains(&panel), "ml-node should show {panel}");
+        }
+        assert!(ml.update_ms < profile_laptop().update_ms);
+        assert!(ml.thresholds.swap_percent < AlertThresholds::default().swap_percent);
+        assert!(profile("desktop").is_none());
+
+        for p in all_profiles() {
+            let total: u16 = p
+                .preset
+                .rows
+                .iter()
+                .map(|r| if let Constraint::Percentage(v) = r.height { v } else { 0 })
+                .sum();
+            assert_eq!(total, 100, "{} rows should sum to 100%", p.name);
+        }
+    }
 }
@@ -0,0 +1,174 @@
+//! Post-run reports rendered from recorded monitor history.
+//!
+//! Bridges the gauge history kept in [`State`] to core plot types so capacity
+//! reports can be generated after a run without a terminal. Each
+//! [`LaneSource`] picks one gauge (e.g. `gpu.0.util` from the `gpu`
+//! collector) and becomes one lane of a [`UtilizationSwimlane`].
+
+use std::time::Instant;
+
+use super::ring_buffer::RingBuffer;
+use super::state::State;
+use super::types::Metrics;
+use crate::plots::{UtilizationLane, UtilizationSwimlane};
+
+/// A gauge in the recorded history to plot as one swimlane.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaneSource {
+    /// Lane label.
+    pub label: String,
+    /// Collector id the history was recorded under.
+    pub collector: String,
+    /// Gauge metric key.
+    pub metric: String,
+    /// Reading that corresponds to full utilization.
+    pub full_scale: f64,
+}
+
+impl LaneSource {
+    /// Create a source for a percentage gauge (full scale 100).
+    #[must_use]
+    pub fn new(label: &str, collector: &str, metric: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            collector: collector.to_string(),
+            metric: metric.to_string(),
+            full_scale: 100.0,
+        }
+    }
+
+    /// Set the reading that corresponds to full utilization.
+    #[must_use]
+    pub fn full_scale(mut self, full_scale: f64) -> Self {
+        self.full_scale = full_scale;
+        self
+    }
+}
+
+/// Find gauges in a collector's latest sample whose keys match
+/// `prefix*suffix`, e.g. `("gpu", "gpu.", ".util")` or `("cpu", "cpu.core.", "")`.
+///
+/// Sources are labelled with the key minus `suffix` and ordered numerically
+/// by the part between prefix and suffix where possible.
+#[must_use]
+pub fn discover_lanes(
+    state: &State,
+    collector: &str,
+    prefix: &str,
+    suffix: &str,
+) -> Vec<LaneSource> {
+    let Some(latest) = state.latest(collector) else {
+        return Vec::new();
+    };
+    let mut keys: Vec<&String> = latest
+        .iter()
+        .filter(|(key, value)| {
+            value.is_gauge()
+                && key.len() > prefix.len() + suffix.len()
+                && key.starts_with(prefix)
+                && key.ends_with(suffix)
+        })
+        .map(|(key, _)| key)
+        .collect();
+    let middle = |key: &str| key[prefix.len()..key.len() - suffix.len()].to_string();
+    keys.sort_by_key(|key| {
+        let mid = middle(key);
+        (mid.parse::<u64>().unwrap_or(u64::MAX), mid)
+    });
+    keys.into_iter()
+        .map(|key| LaneSource::new(&key[..key.len() - suffix.len()], collector, key))
+        .collect()
+}
+
+/// Convert one gauge history into a lane, with time in seconds since `origin`.
+#[must_use]
+pub fn lane_from_history(
+    source: &LaneSource,
+    history: &RingBuffer<Metrics>,
+    origin: Instant,
+) -> UtilizationLane {
+    let samples = history
+        .iter()
+        .filter_map(|m| {
+            let value = m.get_gauge(&source.metric)?;
+            let t = m.timestamp.saturating_duration_since(origin).as_secs_f32();
+            Some((t, (value / source.full_scale) as f32))
+        })
+        .filter(|(_, u)| u.is_finite())
+        .collect();
+    UtilizationLane::new(source.label.clone(), samples)
+}
+
+/// Build a utilization swimlane chart from recorded history.
+///
+/// Time zero is the oldest sample among the referenced collectors. Sources
+/// whose collector has no history produce empty lanes.
+#[must_use]
+pub fn utilization_swimlane(state: &State, sources: &[LaneSource]) -> UtilizationSwimlane {
+    let origin = sources
+        .iter()
+        .filter_map(|s| state.history.get(&s.collector)?.oldest().map(|m| m.timestamp))
+        .min()
+        .unwrap_or_else(Instant::now);
+
+    sources.iter().fold(UtilizationSwimlane::new(), |chart, source| {
+        let lane = match state.history.get(&source.collector) {
+            Some(history) => lane_from_history(source, history, origin),
+            None => UtilizationLane::new(source.label.clone(), Vec::new()),
+        };
+        chart.add_lane(lane)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn recorded() -> State {
+        let mut state = State::new(10);
+        let t0 = Instant::now();
+        for (i, (g0, g1)) in [(10.0, 90.0), (95.0, 80.0), (20.0, 0.0)].into_iter().enumerate() {
+            let mut m = Metrics::with_timestamp(t0 + Duration::from_secs(i as u64));
+            m.insert("gpu.count", 2u64);
+            m.insert("gpu.0.util", g0);
+            m.insert("gpu.1.util", g1);
+            m.insert("gpu.10.util", 0.0);
+            m.insert("gpu.0.temp", 60.0);
+            state.record("gpu", m, 10);
+        }
+        state
+    }
+
+    #[test]
+    fn test_discover_lanes_numeric_order() {
+        let lanes = discover_lanes(&recorded(), "gpu", "gpu.", ".util");
+        let labels: Vec<&str> = lanes.iter().map(|l| l.label.as_str()).collect();
+        assert_eq!(labels, vec!["gpu.0", "gpu.1", "gpu.10"]);
+        assert_eq!(lanes[1].metric, "gpu.1.util");
+        assert!(discover_lanes(&recorded(), "cpu", "cpu.core.", "").is_empty());
+    }
+
+    #[test]
+    fn test_lane_from_history() {
+        let state = recorded();
+        let history = &state.history["gpu"];
+        let origin = history.oldest().map(|m| m.timestamp).expect("history recorded");
+        let lane = lane_from_history(&LaneSource::new("g0", "gpu", "gpu.0.util"), history, origin);
+        assert_eq!(lane.samples.len(), 3);
+        assert!((lane.samples[1].0 - 1.0).abs() < 1e-3);
+        assert!((lane.samples[1].1 - 0.95).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_utilization_swimlane_from_state() {
+        let state = recorded();
+        let mut sources = discover_lanes(&state, "gpu", "gpu.", ".util");
+        sources.push(LaneSource::new("missing", "npu", "npu.util"));
+        let chart = utilization_swimlane(&state, &sources).build().expect("valid chart");
+        assert_eq!(chart.lanes().len(), 4);
+        assert!(chart.lanes()[3].samples.is_empty());
+        assert_eq!(chart.lanes()[0].bursts(0.5).len(), 1);
+        assert!(chart.to_framebuffer().is_ok());
+    }
+}
@@ -15,6 +15,8 @@ pub struct State {
     pub selected_panel: usize,
     /// Whether help is visible.
     pub show_help: bool,
+    /// Whether the notification center is visible.
+    pub show_notifications: bool,
 }
 
 impl State {
@@ -24,7 +26,13 @@ impl State {
     /// created lazily with their own size when `record()` is called.
     #[must_use]
     pub fn new(_history_size: usize) -> Self {
-        Self { history: HashMap::new(), should_quit: false, selected_panel: 0, show_help: false }
+        Self {
+            history: HashMap::new(),
+            should_quit: false,
+            selected_panel: 0,
+            show_help: false,
+            show_notifications: false,
+        }
     }
 
     /// Records metrics from a collector.
@@ -51,6 +59,11 @@ impl State {
         self.show_help = !self.show_help;
     }
 
+    /// Toggles notification center visibility.
+    pub fn toggle_notifications(&mut self) {
+        self.show_notifications = !self.show_notifications;
+    }
+
     /// Selects the next panel.
     pub fn next_panel(&mut self, panel_count: usize) {
         if panel_count > 0 {
@@ -10,6 +10,9 @@ use ratatui::{
     widgets::Widget,
 };
 
+use crate::color::Rgba;
+use crate::plots::{ConditionalFormat, FormatRule};
+
 /// Status level for status dot visualization.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum StatusLevel {
@@ -69,6 +72,18 @@ pub enum CellValue {
 }
 
 impl CellValue {
+    /// Numeric value tested by conditional formatting.
+    #[must_use]
+    pub fn numeric(&self) -> Option<f64> {
+        match self {
+            Self::Int(n) => Some(*n as f64),
+            Self::Float(f) | Self::Progress(f) | Self::Trend(f) => Some(*f),
+            Self::MicroBar { value, .. } => Some(*value),
+            Self::Sparkline(values) => values.iter().rev().find(|v| v.is_finite()).copied(),
+            Self::Null | Self::Bool(_) | Self::Text(_) | Self::Status(_) => None,
+        }
+    }
+
     /// Render cell value to string and color.
     #[must_use]
     pub fn render(&self, width: usize) -> (String, Color) {
@@ -173,13 +188,33 @@ pub struct Column {
     pub width: usize,
     /// Alignment.
     pub align: ColumnAlign,
+    /// Rules highlighting cells by value.
+    pub format: Option<ConditionalFormat>,
 }
 
 impl Column {
     /// Create a new column.
     #[must_use]
     pub fn new(name: impl Into<String>) -> Self {
-        Self { name: name.into(), values: Vec::new(), width: 10, align: ColumnAlign::default() }
+        Self {
+            name: name.into(),
+            values: Vec::new(),
+            width: 10,
+            align: ColumnAlign::default(),
+            format: None,
+        }
+    }
+
+    /// Highlight cells with conditional formatting rules.
+    #[must_use]
+    pub fn format(mut self, format: ConditionalFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// The formatting rule matching a cell, if any.
+    fn rule_for(&self, value: &CellValue) -> Option<&FormatRule> {
+        self.format.as_ref()?.evaluate(value.numeric()?)
     }
 
     /// Set column width.
@@ -211,6 +246,7 @@ impl Column {
             values: values.iter().map(|&v| CellValue::Float(v)).collect(),
             width: 10,
             align: ColumnAlign::Right,
+            format: None,
         }
     }
 
@@ -222,6 +258,7 @@ impl Column {
             values: values.iter().map(|&v| CellValue::Int(v)).collect(),
             width: 10,
             align: ColumnAlign::Right,
+            format: None,
         }
     }
 
@@ -233,6 +270,7 @@ impl Column {
             values: values.iter().map(|&s| CellValue::Text(s.to_string())).collect(),
             width: 15,
             align: ColumnAlign::Left,
+            format: None,
         }
     }
 
@@ -244,6 +282,7 @@ impl Column {
             values: rows.into_iter().map(CellValue::Sparkline).collect(),
             width: 12,
             align: ColumnAlign::Left,
+            format: None,
         }
     }
 }
@@ -358,6 +397,33 @@ impl DataFrame {
         let truncated: String = padded.chars().take(width).collect();
         (truncated, color)
     }
+
+    /// Render a cell with its column's conditional formatting applied.
+    fn render_formatted_cell(&self, col: &Column, value: &CellValue) -> (String, Style) {
+        let rule = col.rule_for(value);
+        let Some(rule) = rule else {
+            let (content, color) = self.render_cell(value, col.width, col.align);
+            return (content, Style::default().fg(color));
+        };
+        let (content, color) = match rule.icon {
+            Some(icon) => {
+                let (content, color) =
+                    self.render_cell(value, col.width.saturating_sub(2), col.align);
+                (format!("{} {content}", icon.symbol()), color)
+            }
+            None => self.render_cell(value, col.width, col.align),
+        };
+        let mut style = Style::default().fg(rule.color.map_or(color, terminal_color));
+        if let Some(bg) = rule.background {
+            style = style.bg(terminal_color(bg));
+        }
+        (content, style)
+    }
+}
+
+/// Truecolor terminal equivalent of an RGBA color.
+fn terminal_color(color: Rgba) -> Color {
+    Color::Rgb(color.r, color.g, color.b)
 }
 
 impl Widget for DataFrame {
@@ -454,10 +520,8 @@ impl Widget for DataFrame {
             // Cell values
             for col in &self.columns {
                 if let Some(value) = col.values.get(row_idx) {
-                    let (content, color) = self.render_cell(value, col.width, col.align);
-
-                    let style =
-                        if is_selected { selected_style } else { Style::default().fg(color) };
+                    let (content, style) = self.render_formatted_cell(col, value);
+                    let style = if is_selected { selected_style } else { style };
 
                     for (i, ch) in content.chars().enumerate() {
                         let cx = x + i as u16;
@@ -979,5 +1043,40 @@ mod tests {
             );
             df.render(area, &mut buf);
         }
+
+        #[test]
+        fn test_render_conditional_format() {
+            use crate::plots::RuleIcon;
+
+            let (area, mut buf) = create_test_buffer(30, 6);
+            let format = ConditionalFormat::new().rule(
+                FormatRule::above(90.0)
+                    .color(Rgba::RED)
+                    .background(Rgba::rgb(60, 0, 0))
+                    .icon(RuleIcon::Alert),
+            );
+            let df = DataFrame::new()
+                .show_row_numbers(false)
+                .column(Column::from_f64("cpu", &[95.0, 10.0]).format(format));
+            df.render(area, &mut buf);
+
+            // Header, separator, then one row per value
+            let row =
+                |y: u16| (0..10).map(|x| buf[(x, y)].symbol().to_string()).collect::<String>();
+            assert!(row(2).starts_with("! "));
+            assert!(row(2).contains("95.00"));
+            assert_eq!(buf[(0, 2)].fg, Color::Rgb(255, 0, 0));
+            assert_eq!(buf[(5, 2)].bg, Color::Rgb(60, 0, 0));
+            assert!(!row(3).contains('!'));
+            assert_eq!(buf[(9, 3)].bg, Color::Reset);
+        }
+
+        #[test]
+        fn test_numeric_values() {
+            assert_eq!(CellValue::Int(3).numeric(), Some(3.0));
+            assert_eq!(CellValue::MicroBar { value: 2.0, max: 4.0 }.numeric(), Some(2.0));
+            assert_eq!(CellValue::Sparkline(vec![1.0, f64::NAN]).numeric(), Some(1.0));
+            assert!(CellValue::Text("x".into()).numeric().is_none());
+        }
     }
 }
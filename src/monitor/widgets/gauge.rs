@@ -1,6 +1,8 @@
 //! Gauge widget for displaying percentage values.
 //!
 //! Supports arc/circular display modes for compact metric visualization.
+//! A [`GaugeCluster`] lays several small gauges out in a grid for
+//! at-a-glance summaries such as CPU / MEM / GPU / DISK.
 
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
@@ -44,6 +46,8 @@ pub struct Gauge<'a> {
     crit_color: Color,
     /// Show percentage text.
     show_percent: bool,
+    /// Color the arc by the warning/critical bands.
+    show_bands: bool,
 }
 
 impl<'a> Gauge<'a> {
@@ -61,6 +65,7 @@ impl<'a> Gauge<'a> {
             crit_threshold: 0.9,
             crit_color: Color::Red,
             show_percent: true,
+            show_bands: false,
         }
     }
 
@@ -115,6 +120,27 @@ impl<'a> Gauge<'a> {
         self
     }
 
+    /// Sets whether the arc shows the warning and critical bands.
+    #[must_use]
+    pub fn show_bands(mut self, show: bool) -> Self {
+        self.show_bands = show;
+        self
+    }
+
+    /// Arc color at `fraction` of the scale: the band color when bands are
+    /// shown, plain white otherwise.
+    fn band_color(&self, fraction: f64) -> Color {
+        if !self.show_bands {
+            Color::White
+        } else if fraction >= self.crit_threshold {
+            self.crit_color
+        } else if fraction >= self.warn_threshold {
+            self.warn_color
+        } else {
+            Color::White
+        }
+    }
+
     /// Returns the color based on current value and thresholds.
     fn current_color(&self) -> Color {
         if self.value >= self.crit_threshold {
@@ -185,14 +211,20 @@ impl<'a> Gauge<'a> {
         let arc_width = area.width.min(15);
         let arc_start = center_x.saturating_sub(arc_width / 2);
 
-        buf.set_string(arc_start, y, "╭", Style::default().fg(Color::White));
+        let bar_width = arc_width.saturating_sub(2);
+        buf.set_string(arc_start, y, "╭", Style::default().fg(self.band_color(0.0)));
         for i in 1..arc_width - 1 {
-            buf.set_string(arc_start + i, y, "─", Style::default().fg(Color::White));
+            let fraction = (f64::from(i) - 0.5) / f64::from(bar_width);
+            buf.set_string(arc_start + i, y, "─", Style::default().fg(self.band_color(fraction)));
         }
-        buf.set_string(arc_start + arc_width - 1, y, "╮", Style::default().fg(Color::White));
+        buf.set_string(
+            arc_start + arc_width - 1,
+            y,
+            "╮",
+            Style::default().fg(self.band_color(1.0)),
+        );
 
         // Fill bar
-        let bar_width = arc_width.saturating_sub(2);
         let filled = ((self.value * f64::from(bar_width)).round() as u16).min(bar_width);
 
         for i in 0..bar_width {
@@ -244,7 +276,8 @@ impl<'a> Gauge<'a> {
         // Top border
         buf.set_string(box_x, box_y, "╭", Style::default().fg(Color::White));
         for i in 1..box_width - 1 {
-            buf.set_string(box_x + i, box_y, "─", Style::default().fg(Color::White));
+            let fraction = (f64::from(i) - 0.5) / f64::from(box_width - 2);
+            buf.set_string(box_x + i, box_y, "─", Style::default().fg(self.band_color(fraction)));
         }
         buf.set_string(box_x + box_width - 1, box_y, "╮", Style::default().fg(Color::White));
 
@@ -320,6 +353,78 @@ impl Widget for Gauge<'_> {
     }
 }
 
+/// Minimum cell width for a gauge in a cluster (a half gauge needs 7).
+const CLUSTER_MIN_WIDTH: u16 = 9;
+
+/// A grid of small labelled gauges sharing one area.
+///
+/// Columns default to as many as fit side by side; further gauges wrap to
+/// new rows.
+#[derive(Debug, Clone, Default)]
+pub struct GaugeCluster<'a> {
+    /// Gauges in reading order.
+    gauges: Vec<Gauge<'a>>,
+    /// Fixed column count.
+    columns: Option<u16>,
+}
+
+impl<'a> GaugeCluster<'a> {
+    /// Creates an empty cluster.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a configured gauge.
+    #[must_use]
+    pub fn gauge(mut self, gauge: Gauge<'a>) -> Self {
+        self.gauges.push(gauge);
+        self
+    }
+
+    /// Adds a half-arc gauge with a label and warning/critical bands.
+    #[must_use]
+    pub fn metric(self, label: &'a str, value: f64) -> Self {
+        self.gauge(Gauge::new(value).label(label).mode(GaugeMode::Half).show_bands(true))
+    }
+
+    /// Fixes the number of columns.
+    #[must_use]
+    pub fn columns(mut self, columns: u16) -> Self {
+        self.columns = Some(columns.max(1));
+        self
+    }
+
+    /// Cell of each gauge within `area`, in reading order.
+    #[must_use]
+    pub fn layout(&self, area: Rect) -> Vec<Rect> {
+        let count = self.gauges.len() as u16;
+        if count == 0 || area.width == 0 || area.height == 0 {
+            return Vec::new();
+        }
+        let columns =
+            self.columns.unwrap_or_else(|| (area.width / CLUSTER_MIN_WIDTH).max(1)).min(count);
+        let rows = count.div_ceil(columns);
+        let (width, height) = (area.width / columns, (area.height / rows).max(1));
+        (0..count)
+            .map(|i| {
+                let (col, row) = (i % columns, i / columns);
+                Rect::new(area.x + col * width, area.y + row * height, width, height)
+            })
+            .filter(|cell| cell.bottom() <= area.bottom())
+            .collect()
+    }
+}
+
+impl Widget for GaugeCluster<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let cells = self.layout(area);
+        for (gauge, cell) in self.gauges.into_iter().zip(cells) {
+            gauge.render(cell, buf);
+        }
+    }
+}
+
 // ============================================================================
 // Tests (TDD - Written First)
 // ============================================================================
@@ -584,4 +689,53 @@ mod tests {
             })
             .expect("Quarter mode should render as half");
     }
+
+    #[test]
+    fn test_gauge_half_band_colors() {
+        let area = Rect::new(0, 0, 12, 3);
+        let mut buf = Buffer::empty(area);
+        Gauge::new(0.2)
+            .mode(GaugeMode::Half)
+            .show_bands(true)
+            .warn(0.5, Color::Yellow)
+            .critical(0.8, Color::Red)
+            .render(area, &mut buf);
+
+        // Arc spans x=0..12 with a 10-cell scale inside
+        assert_eq!(buf[(1, 0)].fg, Color::White);
+        assert_eq!(buf[(6, 0)].fg, Color::Yellow);
+        assert_eq!(buf[(10, 0)].fg, Color::Red);
+        assert_eq!(buf[(11, 0)].fg, Color::Red);
+    }
+
+    #[test]
+    fn test_gauge_cluster_layout_wraps() {
+        let cluster = GaugeCluster::new()
+            .metric("CPU", 0.3)
+            .metric("MEM", 0.5)
+            .metric("GPU", 0.95)
+            .metric("DISK", 0.1);
+        let wide = cluster.layout(Rect::new(0, 0, 40, 3));
+        assert_eq!(wide.len(), 4);
+        assert!(wide.iter().all(|c| c.y == 0 && c.width == 10));
+
+        let narrow = cluster.layout(Rect::new(0, 0, 20, 6));
+        assert_eq!(narrow[1], Rect::new(10, 0, 10, 3));
+        assert_eq!(narrow[2], Rect::new(0, 3, 10, 3));
+        // One column of four rows: each gauge gets a compact single line
+        let column = cluster.clone().columns(1).layout(Rect::new(0, 0, 20, 6));
+        assert_eq!(column[3], Rect::new(0, 3, 20, 1));
+    }
+
+    #[test]
+    fn test_gauge_cluster_renders_labels() {
+        let area = Rect::new(0, 0, 40, 3);
+        let mut buf = Buffer::empty(area);
+        GaugeCluster::new().metric("CPU", 0.3).metric("GPU", 0.95).render(area, &mut buf);
+
+        let row: String = (0..40).map(|x| buf[(x, 2)].symbol().to_string()).collect();
+        assert!(row.contains("CPU  30%") && row.contains("GPU  95%"), "{row}");
+        let gpu_fill = (20..40).find(|&x| buf[(x, 1)].symbol() == "█").expect("filled cell");
+        assert_eq!(buf[(gpu_fill, 1)].fg, Color::Red);
+    }
 }
@@ -6,6 +6,11 @@
 //! - **Block**: Medium resolution using block characters (▗▄▖▟▌▙█)
 //! - **TTY**: ASCII-only for pure TTY environments (░▒█)
 //!
+//! Several series can share one graph, either overlaid from the baseline or
+//! stacked (e.g. user/system/iowait CPU time), each in its own color. A
+//! [`YScale`] selects fixed, logarithmic or symmetric scaling instead of
+//! the default 0-1 input range.
+//!
 //! # Performance
 //!
 //! - Rendering is O(width × height) (Falsification criterion #2)
@@ -16,6 +21,8 @@ use ratatui::layout::Rect;
 use ratatui::style::{Color, Style};
 use ratatui::widgets::Widget;
 
+use super::scaling::{YMapping, YScale};
+
 /// Rendering mode for the graph.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum GraphMode {
@@ -28,6 +35,27 @@ pub enum GraphMode {
     Tty,
 }
 
+/// One data series of a multi-series graph.
+#[derive(Debug, Clone, Copy)]
+pub struct GraphSeries<'a> {
+    /// Data points (0.0 - 1.0 normalized).
+    pub data: &'a [f64],
+    /// Series color.
+    pub color: Color,
+}
+
+/// How multiple series share the graph area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GraphLayout {
+    /// Every series is drawn from the baseline; smaller values are drawn in
+    /// front so each one stays visible.
+    #[default]
+    Overlay,
+    /// Series are stacked in insertion order, e.g. a user/system/iowait CPU
+    /// breakdown whose total is the overall utilization.
+    Stacked,
+}
+
 /// A time-series graph widget.
 #[derive(Debug, Clone)]
 pub struct Graph<'a> {
@@ -39,13 +67,27 @@ pub struct Graph<'a> {
     color: Color,
     /// Whether to invert the graph (for upload graphs).
     inverted: bool,
+    /// Series after the primary one.
+    extra: Vec<GraphSeries<'a>>,
+    /// How series share the area.
+    layout: GraphLayout,
+    /// Value to height mapping.
+    y_scale: YScale,
 }
 
 impl<'a> Graph<'a> {
     /// Creates a new graph with the given data.
     #[must_use]
     pub fn new(data: &'a [f64]) -> Self {
-        Self { data, mode: GraphMode::default(), color: Color::Cyan, inverted: false }
+        Self {
+            data,
+            mode: GraphMode::default(),
+            color: Color::Cyan,
+            inverted: false,
+            extra: Vec::new(),
+            layout: GraphLayout::default(),
+            y_scale: YScale::default(),
+        }
     }
 
     /// Sets the rendering mode.
@@ -69,72 +111,146 @@ impl<'a> Graph<'a> {
         self
     }
 
+    /// Adds another series drawn in its own color.
+    #[must_use]
+    pub fn series(mut self, data: &'a [f64], color: Color) -> Self {
+        self.extra.push(GraphSeries { data, color });
+        self
+    }
+
+    /// Sets how multiple series share the area.
+    #[must_use]
+    pub fn layout(mut self, layout: GraphLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the y-axis scaling (default: values already normalized to 0-1).
+    #[must_use]
+    pub fn y_scale(mut self, scale: YScale) -> Self {
+        self.y_scale = scale;
+        self
+    }
+
+    /// All non-empty series, primary first.
+    fn all_series(&self) -> impl Iterator<Item = GraphSeries<'a>> + '_ {
+        std::iter::once(GraphSeries { data: self.data, color: self.color })
+            .chain(self.extra.iter().copied())
+            .filter(|s| !s.data.is_empty())
+    }
+
+    /// Resolve the y scale over every value drawn (stack totals when stacked).
+    fn mapping(&self) -> YMapping {
+        let values: Vec<f64> = match self.layout {
+            GraphLayout::Overlay => {
+                self.all_series().flat_map(|s| s.data.iter().copied()).collect()
+            }
+            GraphLayout::Stacked => {
+                let len = self.all_series().map(|s| s.data.len()).max().unwrap_or(0);
+                (0..len)
+                    .map(|i| self.all_series().filter_map(|s| s.data.get(i)).sum::<f64>())
+                    .collect()
+            }
+        };
+        let finite = values.iter().copied().filter(|v| v.is_finite());
+        let min = finite.clone().fold(f64::INFINITY, f64::min);
+        let max = finite.fold(f64::NEG_INFINITY, f64::max);
+        self.y_scale.mapping(min, max, (0.0, 1.0))
+    }
+
+    /// Raw value of a series at column `x` of `width`.
+    fn sample(data: &[f64], x: usize, width: usize) -> f64 {
+        let data_idx = (x * data.len()) / width;
+        data.get(data_idx).copied().unwrap_or(0.0)
+    }
+
+    /// Bands of one column as ascending `(top, color)` pairs: each color
+    /// fills from the previous top up to its own.
+    fn column_bands(&self, x: usize, width: usize, mapping: &YMapping) -> Vec<(f64, Color)> {
+        match self.layout {
+            GraphLayout::Overlay => {
+                let mut bands: Vec<(f64, Color)> = self
+                    .all_series()
+                    .map(|s| (mapping.apply(Self::sample(s.data, x, width)), s.color))
+                    .collect();
+                bands.sort_by(|a, b| a.0.total_cmp(&b.0));
+                bands
+            }
+            GraphLayout::Stacked => {
+                // Stack in data units, then map the running totals
+                let mut total = 0.0;
+                self.all_series()
+                    .map(|s| {
+                        total += Self::sample(s.data, x, width).max(0.0);
+                        (mapping.apply(total), s.color)
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Color of the band covering the unit `start` of `total` (counted from
+    /// the baseline).
+    fn band_color(bands: &[(f64, Color)], start: usize, total: usize) -> Color {
+        let level = (start as f64 + 0.5) / total.max(1) as f64;
+        bands
+            .iter()
+            .find(|(top, _)| *top >= level)
+            .or(bands.last())
+            .map_or(Color::Reset, |&(_, color)| color)
+    }
+
+    /// Whether there is anything to draw.
+    fn is_empty(&self) -> bool {
+        self.data.is_empty() && self.extra.iter().all(|s| s.data.is_empty())
+    }
+
     /// Renders braille characters for the data.
     fn render_braille(&self, area: Rect, buf: &mut Buffer) {
-        if self.data.is_empty() || area.width == 0 || area.height == 0 {
+        if self.is_empty() || area.width == 0 || area.height == 0 {
             return;
         }
 
+        let mapping = self.mapping();
         let width = area.width as usize;
         let height = area.height as usize;
 
-        // Each braille character represents 2x4 dots
-        let _dots_per_char_x = 2; // Only using left column currently
+        // Each braille character represents 2x4 dots; only the left column
+        // is used. Dot bits from the baseline: 7, 3, 2, 1 (or 1, 2, 3, 7
+        // when hanging from the top).
         let dots_per_char_y = 4;
+        let upward = [0x40, 0x04, 0x02, 0x01];
+        let downward = [0x01, 0x02, 0x04, 0x40];
 
         for x in 0..width {
-            // Map x position to data index
-            let data_idx = (x * self.data.len()) / width;
-            let value = self.data.get(data_idx).copied().unwrap_or(0.0).clamp(0.0, 1.0);
+            let bands = self.column_bands(x, width, &mapping);
+            let top = bands.last().map_or(0.0, |b| b.0);
 
             // Calculate the height in dots
             let max_dots = height * dots_per_char_y;
-            let filled_dots = if self.inverted {
-                ((1.0 - value) * max_dots as f64) as usize
-            } else {
-                (value * max_dots as f64) as usize
-            };
+            let filled_dots = (top * max_dots as f64) as usize;
 
-            // Render each row
+            // Render each row, counting from the baseline
             for y in 0..height {
                 let char_y = if self.inverted { y } else { height - 1 - y };
                 let dot_start = y * dots_per_char_y;
 
-                // Determine which dots in this character should be filled
                 let mut pattern: u8 = 0;
-
                 for dot in 0..dots_per_char_y {
-                    let dot_pos = dot_start + dot;
-                    let should_fill =
-                        if self.inverted { dot_pos >= filled_dots } else { dot_pos < filled_dots };
-
-                    if should_fill {
-                        // Braille dot pattern (column 0)
-                        // Dots are numbered: 1,2,3,7 in left column, 4,5,6,8 in right
-                        let bit = match dot {
-                            0 => 0x01, // dot 1
-                            1 => 0x02, // dot 2
-                            2 => 0x04, // dot 3
-                            3 => 0x40, // dot 7
-                            _ => 0,
-                        };
-                        pattern |= bit;
+                    if dot_start + dot < filled_dots {
+                        pattern |= if self.inverted { downward[dot] } else { upward[dot] };
                     }
                 }
 
                 // Convert pattern to braille character (U+2800 base)
                 let braille = char::from_u32(0x2800 + u32::from(pattern)).unwrap_or(' ');
+                let color = Self::band_color(&bands, dot_start, max_dots);
 
                 let cell_x = area.x + x as u16;
                 let cell_y = area.y + char_y as u16;
 
                 if cell_x < area.x + area.width && cell_y < area.y + area.height {
-                    buf.set_string(
-                        cell_x,
-                        cell_y,
-                        braille.to_string(),
-                        Style::default().fg(self.color),
-                    );
+                    buf.set_string(cell_x, cell_y, braille.to_string(), Style::default().fg(color));
                 }
             }
         }
@@ -142,10 +258,11 @@ impl<'a> Graph<'a> {
 
     /// Renders block characters for the data.
     fn render_block(&self, area: Rect, buf: &mut Buffer) {
-        if self.data.is_empty() || area.width == 0 || area.height == 0 {
+        if self.is_empty() || area.width == 0 || area.height == 0 {
             return;
         }
 
+        let mapping = self.mapping();
         let width = area.width as usize;
         let height = area.height as usize;
 
@@ -153,8 +270,8 @@ impl<'a> Graph<'a> {
         let blocks = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
 
         for x in 0..width {
-            let data_idx = (x * self.data.len()) / width;
-            let value = self.data.get(data_idx).copied().unwrap_or(0.0).clamp(0.0, 1.0);
+            let bands = self.column_bands(x, width, &mapping);
+            let value = bands.last().map_or(0.0, |b| b.0);
 
             // Full blocks to render
             let full_height = (value * height as f64) as usize;
@@ -170,6 +287,7 @@ impl<'a> Graph<'a> {
                 } else {
                     ' '
                 };
+                let color = Self::band_color(&bands, y * 8, height * 8);
 
                 let cell_x = area.x + x as u16;
                 let cell_y = area.y + char_y as u16;
@@ -179,7 +297,7 @@ impl<'a> Graph<'a> {
                         cell_x,
                         cell_y,
                         block_char.to_string(),
-                        Style::default().fg(self.color),
+                        Style::default().fg(color),
                     );
                 }
             }
@@ -188,10 +306,11 @@ impl<'a> Graph<'a> {
 
     /// Renders TTY-compatible ASCII characters.
     fn render_tty(&self, area: Rect, buf: &mut Buffer) {
-        if self.data.is_empty() || area.width == 0 || area.height == 0 {
+        if self.is_empty() || area.width == 0 || area.height == 0 {
             return;
         }
 
+        let mapping = self.mapping();
         let width = area.width as usize;
         let height = area.height as usize;
 
@@ -199,8 +318,8 @@ impl<'a> Graph<'a> {
         let shades = [' ', '░', '▒', '█'];
 
         for x in 0..width {
-            let data_idx = (x * self.data.len()) / width;
-            let value = self.data.get(data_idx).copied().unwrap_or(0.0).clamp(0.0, 1.0);
+            let bands = self.column_bands(x, width, &mapping);
+            let value = bands.last().map_or(0.0, |b| b.0);
 
             let filled_height = (value * height as f64) as usize;
 
@@ -215,6 +334,7 @@ impl<'a> Graph<'a> {
                 } else {
                     ' '
                 };
+                let color = Self::band_color(&bands, y, height);
 
                 let cell_x = area.x + x as u16;
                 let cell_y = area.y + char_y as u16;
@@ -224,7 +344,7 @@ impl<'a> Graph<'a> {
                         cell_x,
                         cell_y,
                         shade_char.to_string(),
-                        Style::default().fg(self.color),
+                        Style::default().fg(color),
                     );
                 }
             }
@@ -504,4 +624,75 @@ mod tests {
                 .expect("Should render with different colors");
         }
     }
+
+    #[test]
+    fn test_graph_stacked_block_colors() {
+        let user = vec![0.25; 4];
+        let system = vec![0.25; 4];
+        let area = Rect::new(0, 0, 4, 4);
+        let mut buf = Buffer::empty(area);
+        Graph::new(&user)
+            .color(Color::Green)
+            .series(&system, Color::Red)
+            .layout(GraphLayout::Stacked)
+            .mode(GraphMode::Block)
+            .render(area, &mut buf);
+
+        // Bottom row is the first series, the row above it the second
+        assert_eq!(buf[(0, 3)].symbol(), "█");
+        assert_eq!(buf[(0, 3)].fg, Color::Green);
+        assert_eq!(buf[(0, 2)].symbol(), "█");
+        assert_eq!(buf[(0, 2)].fg, Color::Red);
+        assert_eq!(buf[(0, 1)].symbol(), " ");
+    }
+
+    #[test]
+    fn test_graph_overlay_smaller_series_in_front() {
+        let large = vec![0.5; 4];
+        let small = vec![0.25; 4];
+        let area = Rect::new(0, 0, 4, 4);
+        let mut buf = Buffer::empty(area);
+        Graph::new(&large)
+            .color(Color::Red)
+            .series(&small, Color::Blue)
+            .mode(GraphMode::Tty)
+            .render(area, &mut buf);
+
+        assert_eq!(buf[(1, 3)].fg, Color::Blue);
+        assert_eq!(buf[(1, 2)].fg, Color::Red);
+        assert_eq!(buf[(1, 2)].symbol(), "█");
+    }
+
+    #[test]
+    fn test_graph_braille_fills_from_baseline() {
+        // One dot of eight: the lowest dot of the bottom cell
+        let data = vec![0.125; 2];
+        let area = Rect::new(0, 0, 2, 2);
+        let mut buf = Buffer::empty(area);
+        Graph::new(&data).render(area, &mut buf);
+        assert_eq!(buf[(0, 1)].symbol(), "\u{2840}");
+        assert_eq!(buf[(0, 0)].symbol(), "\u{2800}");
+
+        let mut buf = Buffer::empty(area);
+        Graph::new(&data).inverted(true).render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "\u{2801}");
+    }
+
+    #[test]
+    fn test_graph_log_scale_lifts_small_rates() {
+        // Network rate in bytes/s with one spike
+        let rates = [1_000.0, 1_000.0, 1_000_000.0, 1_000.0];
+        let area = Rect::new(0, 0, 4, 4);
+        let render = |scale: YScale| {
+            let mut buf = Buffer::empty(area);
+            Graph::new(&rates).mode(GraphMode::Block).y_scale(scale).render(area, &mut buf);
+            buf
+        };
+        let linear = render(YScale::Fixed { min: 0.0, max: 1_000_000.0 });
+        assert_eq!(linear[(0, 3)].symbol(), " ");
+        assert_eq!(linear[(2, 0)].symbol(), "█");
+        let log = render(YScale::Log { max: None });
+        assert_eq!(log[(0, 2)].symbol(), "█");
+        assert_eq!(log[(0, 0)].symbol(), " ");
+    }
 }
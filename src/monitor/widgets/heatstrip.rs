@@ -0,0 +1,220 @@
+//! Heatstrip widget for dense pixel-oriented time series.
+//!
+//! Each metric takes exactly one terminal row; every cell is one sample, or
+//! one bucket of samples when the history is longer than the row is wide,
+//! colored by value. Hours of one-second samples fit in a single line.
+//!
+//! # Example
+//!
+//! ```
+//! use trueno_viz::monitor::widgets::Heatstrip;
+//!
+//! let cpu0 = vec![0.1, 0.4, 0.9, 0.7];
+//! let cpu1 = vec![0.2, 0.2, 0.3, 0.8];
+//! let strip = Heatstrip::new().row("cpu0", &cpu0).row("cpu1", &cpu1);
+//! ```
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use ratatui::widgets::Widget;
+
+use super::heatmap::HeatmapPalette;
+
+/// How samples sharing a cell are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StripAggregation {
+    /// Average of the bucket.
+    #[default]
+    Mean,
+    /// Largest value, so short spikes stay visible.
+    Max,
+    /// Smallest value.
+    Min,
+}
+
+/// Dense one-row-per-metric time series widget.
+#[derive(Debug, Clone)]
+pub struct Heatstrip<'a> {
+    /// `(label, samples)` per row.
+    rows: Vec<(&'a str, &'a [f64])>,
+    /// Bucket aggregation.
+    aggregation: StripAggregation,
+    /// Color palette.
+    palette: HeatmapPalette,
+    /// Minimum value for normalization (default 0.0).
+    min_value: f64,
+    /// Maximum value for normalization (default 1.0).
+    max_value: f64,
+    /// Show row labels.
+    show_labels: bool,
+}
+
+impl Default for Heatstrip<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Heatstrip<'a> {
+    /// Creates an empty heatstrip.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            aggregation: StripAggregation::Mean,
+            palette: HeatmapPalette::default(),
+            min_value: 0.0,
+            max_value: 1.0,
+            show_labels: true,
+        }
+    }
+
+    /// Adds a metric row.
+    #[must_use]
+    pub fn row(mut self, label: &'a str, data: &'a [f64]) -> Self {
+        self.rows.push((label, data));
+        self
+    }
+
+    /// Sets how samples sharing a cell are combined.
+    #[must_use]
+    pub fn with_aggregation(mut self, aggregation: StripAggregation) -> Self {
+        self.aggregation = aggregation;
+        self
+    }
+
+    /// Sets the color palette.
+    #[must_use]
+    pub fn with_palette(mut self, palette: HeatmapPalette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Sets the data range for normalization.
+    #[must_use]
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.min_value = min;
+        self.max_value = max;
+        self
+    }
+
+    /// Shows or hides row labels.
+    #[must_use]
+    pub fn with_labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// Aggregates a row into `columns` cells, normalized to 0.0-1.0.
+    ///
+    /// Cells without finite samples are `None`.
+    #[must_use]
+    pub fn cells(&self, row: usize, columns: usize) -> Vec<Option<f64>> {
+        let Some((_, data)) = self.rows.get(row) else {
+            return Vec::new();
+        };
+        let n = data.len();
+        if n == 0 {
+            return vec![None; columns];
+        }
+        (0..columns)
+            .map(|c| {
+                let start = c * n / columns;
+                let end = ((c + 1) * n / columns).max(start + 1).min(n);
+                let bucket = data[start..end].iter().copied().filter(|v| v.is_finite());
+                let value = match self.aggregation {
+                    StripAggregation::Mean => {
+                        let (sum, count) = bucket.fold((0.0, 0usize), |(s, k), v| (s + v, k + 1));
+                        (count > 0).then(|| sum / count as f64)
+                    }
+                    StripAggregation::Max => bucket.reduce(f64::max),
+                    StripAggregation::Min => bucket.reduce(f64::min),
+                }?;
+                Some(self.normalize(value))
+            })
+            .collect()
+    }
+
+    /// Normalize a value to 0.0-1.0 range.
+    fn normalize(&self, value: f64) -> f64 {
+        let span = self.max_value - self.min_value;
+        if span.abs() < f64::EPSILON {
+            return 0.5;
+        }
+        ((value - self.min_value) / span).clamp(0.0, 1.0)
+    }
+}
+
+impl Widget for Heatstrip<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        let label_width = if self.show_labels {
+            self.rows.iter().map(|(l, _)| l.chars().count()).max().unwrap_or(0) as u16
+        } else {
+            0
+        };
+        let label_width = if label_width > 0 { (label_width + 1).min(area.width / 3) } else { 0 };
+        let columns = area.width.saturating_sub(label_width);
+
+        for (row, (label, _)) in self.rows.iter().enumerate().take(area.height as usize) {
+            let y = area.y + row as u16;
+
+            for (i, ch) in label.chars().take(label_width.saturating_sub(1) as usize).enumerate() {
+                buf[(area.x + i as u16, y)].set_char(ch).set_fg(Color::White);
+            }
+
+            for (c, cell) in self.cells(row, columns as usize).into_iter().enumerate() {
+                let x = area.x + label_width + c as u16;
+                match cell {
+                    Some(v) => buf[(x, y)].set_char('█').set_fg(self.palette.color_for(v)),
+                    None => buf[(x, y)].set_char(' '),
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cells_bucket_and_normalize() {
+        let data = [0.0, 50.0, 100.0, f64::NAN];
+        let strip = Heatstrip::new().row("cpu", &data).with_range(0.0, 100.0);
+        assert_eq!(strip.cells(0, 2), vec![Some(0.25), Some(1.0)]);
+        let max = strip.clone().with_aggregation(StripAggregation::Max);
+        assert_eq!(max.cells(0, 2), vec![Some(0.5), Some(1.0)]);
+        assert_eq!(strip.cells(0, 4)[3], None);
+        assert!(strip.cells(1, 4).is_empty());
+    }
+
+    #[test]
+    fn test_render_one_row_per_metric() {
+        let a = [1.0; 100];
+        let b = [0.0; 100];
+        let strip = Heatstrip::new()
+            .row("a", &a)
+            .row("b", &b)
+            .with_palette(HeatmapPalette::new(vec![Color::Blue, Color::Red]));
+        let area = Rect::new(0, 0, 12, 3);
+        let mut buf = Buffer::empty(area);
+        strip.render(area, &mut buf);
+
+        assert_eq!(buf[(0, 0)].symbol(), "a");
+        assert_eq!(buf[(5, 0)].fg, Color::Red);
+        assert_eq!(buf[(5, 1)].fg, Color::Blue);
+        assert_eq!(buf[(5, 2)].symbol(), " ");
+    }
+
+    #[test]
+    fn test_render_zero_area() {
+        let data = [0.5];
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Heatstrip::new().row("x", &data).render(Rect::new(0, 0, 0, 0), &mut buf);
+    }
+}
@@ -2,17 +2,22 @@
 //!
 //! This module provides reusable widgets for building monitoring panels:
 //!
-//! - [`Graph`]: Time-series visualization with braille/block/TTY modes
+//! - [`Graph`]: Time-series visualization with braille/block/TTY modes,
+//!   overlaid or stacked series
 //! - [`Meter`]: Percentage bar with gradient coloring
 //! - [`Gauge`]: Arc/circular gauge for compact display
+//! - [`GaugeCluster`]: Grid of labelled gauges with threshold bands
 //! - [`Table`]: Sortable, scrollable data table
 //! - [`Tree`]: Collapsible hierarchy view
+//! - [`Treemap`]: Squarified nested rectangles sized by value
 //! - [`Sparkline`]: Inline mini-graph
 //! - [`Heatmap`]: Grid heatmap for temperature/load visualization
 //! - [`HorizonGraph`]: Ultra-dense time-series with layered bands
+//! - [`Heatstrip`]: One row per metric, one cell per sample, color = value
 //! - [`BoxPlot`]: Box-and-whisker plot with quartiles and outliers
 //! - [`ViolinPlot`]: Distribution plot with KDE
 //! - [`DataFrame`]: Tabular data with inline visualizations
+//! - [`Scatter`]: Braille scatter of one metric against another
 //!
 //! All widgets implement the ratatui `Widget` trait for rendering.
 
@@ -22,24 +27,32 @@ pub mod dataframe;
 pub mod gauge;
 pub mod graph;
 pub mod heatmap;
+pub mod heatstrip;
 pub mod histogram;
 pub mod horizon;
 pub mod meter;
+pub mod scaling;
+pub mod scatter;
 pub mod sparkline;
 pub mod table;
 pub mod tree;
+pub mod treemap;
 pub mod violin;
 
 pub use boxplot::{BoxOrientation, BoxPlot, BoxStats};
 pub use confusion::{ConfusionMatrix, MatrixPalette, Normalization};
 pub use dataframe::{CellValue, Column, ColumnAlign, DataFrame, StatusLevel};
-pub use gauge::{Gauge, GaugeMode};
-pub use graph::{Graph, GraphMode};
+pub use gauge::{Gauge, GaugeCluster, GaugeMode};
+pub use graph::{Graph, GraphLayout, GraphMode, GraphSeries};
 pub use heatmap::{Heatmap, HeatmapCell, HeatmapPalette};
+pub use heatstrip::{Heatstrip, StripAggregation};
 pub use histogram::{BarStyle, Bin, BinStrategy, Histogram, HistogramOrientation};
 pub use horizon::{HorizonGraph, HorizonScheme};
 pub use meter::Meter;
+pub use scaling::{YMapping, YScale};
+pub use scatter::Scatter;
 pub use sparkline::MonitorSparkline;
 pub use table::{MonitorTable, SortDirection};
 pub use tree::Tree;
+pub use treemap::{squarify, Treemap, TreemapNode};
 pub use violin::{ViolinData, ViolinOrientation, ViolinPlot, ViolinStats};
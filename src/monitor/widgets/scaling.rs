@@ -0,0 +1,118 @@
+//! Y-axis scaling options shared by [`Graph`](super::Graph) and
+//! [`MonitorSparkline`](super::MonitorSparkline).
+//!
+//! Bursty metrics such as network rates are dominated by a single spike on
+//! a linear auto-range. A [`YScale`] picks the range and shape instead:
+//! fixed bounds, a `log(1 + x)` axis, or a range symmetric around zero for
+//! signed deltas.
+
+/// How values map onto the widget height.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum YScale {
+    /// The widget's default: `Graph` expects values already in 0-1,
+    /// `MonitorSparkline` spans the data minimum to maximum.
+    #[default]
+    Auto,
+    /// Linear between fixed bounds; values outside are clamped.
+    Fixed {
+        /// Value at the bottom.
+        min: f64,
+        /// Value at the top.
+        max: f64,
+    },
+    /// `log(1 + x)` from zero up to `max` (data maximum when `None`);
+    /// negative values sit on the baseline.
+    Log {
+        /// Value at the top.
+        max: Option<f64>,
+    },
+    /// Linear from `-limit` to `limit` so zero is mid-height (`limit`
+    /// defaults to the largest absolute value).
+    Symmetric {
+        /// Magnitude at the top and bottom.
+        limit: Option<f64>,
+    },
+}
+
+impl YScale {
+    /// Resolve the scale for data spanning `data_min..=data_max`, where
+    /// `auto` is the widget's default range.
+    #[must_use]
+    pub fn mapping(self, data_min: f64, data_max: f64, auto: (f64, f64)) -> YMapping {
+        let finite = |v: f64| if v.is_finite() { v } else { 0.0 };
+        let (data_min, data_max) = (finite(data_min), finite(data_max));
+        match self {
+            Self::Auto => YMapping { lo: auto.0, hi: auto.1, log: false },
+            Self::Fixed { min, max } => YMapping { lo: min, hi: max, log: false },
+            Self::Log { max } => YMapping { lo: 0.0, hi: max.unwrap_or(data_max), log: true },
+            Self::Symmetric { limit } => {
+                let limit = limit.unwrap_or_else(|| data_min.abs().max(data_max.abs()));
+                YMapping { lo: -limit, hi: limit, log: false }
+            }
+        }
+    }
+}
+
+/// A resolved [`YScale`]: maps values to 0-1 (bottom to top).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct YMapping {
+    lo: f64,
+    hi: f64,
+    log: bool,
+}
+
+impl YMapping {
+    /// Normalized height of `value`, clamped to 0-1; 0.5 when the range is
+    /// empty and 0 for non-finite values.
+    #[must_use]
+    pub fn apply(&self, value: f64) -> f64 {
+        if !value.is_finite() {
+            return 0.0;
+        }
+        let shape = |v: f64| if self.log { v.max(0.0).ln_1p() } else { v };
+        let (v, lo, hi) = (shape(value), shape(self.lo), shape(self.hi));
+        if hi > lo {
+            ((v - lo) / (hi - lo)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_auto_and_fixed() {
+        let auto = YScale::Auto.mapping(3.0, 9.0, (3.0, 9.0));
+        assert!(approx(auto.apply(6.0), 0.5));
+        let fixed = YScale::Fixed { min: 0.0, max: 100.0 }.mapping(3.0, 9.0, (3.0, 9.0));
+        assert!(approx(fixed.apply(25.0), 0.25));
+        assert!(approx(fixed.apply(250.0), 1.0));
+        assert!(approx(YScale::Auto.mapping(1.0, 1.0, (1.0, 1.0)).apply(1.0), 0.5));
+    }
+
+    #[test]
+    fn test_log_keeps_small_values_visible() {
+        let log = YScale::Log { max: None }.mapping(0.0, 1_000_000.0, (0.0, 1.0));
+        let linear = YScale::Fixed { min: 0.0, max: 1_000_000.0 }.mapping(0.0, 0.0, (0.0, 1.0));
+        assert!(log.apply(1000.0) > 0.45 && linear.apply(1000.0) < 0.01);
+        assert!(approx(log.apply(1_000_000.0), 1.0));
+        assert!(approx(log.apply(-5.0), 0.0));
+        assert!(approx(log.apply(f64::NAN), 0.0));
+    }
+
+    #[test]
+    fn test_symmetric_centers_zero() {
+        let sym = YScale::Symmetric { limit: None }.mapping(-2.0, 8.0, (0.0, 1.0));
+        assert!(approx(sym.apply(0.0), 0.5));
+        assert!(approx(sym.apply(-8.0), 0.0));
+        let fixed = YScale::Symmetric { limit: Some(4.0) }.mapping(-2.0, 8.0, (0.0, 1.0));
+        assert!(approx(fixed.apply(2.0), 0.75));
+    }
+}
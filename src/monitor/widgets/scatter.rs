@@ -0,0 +1,258 @@
+//! Braille scatter widget for metric correlation.
+//!
+//! Plots one metric against another, one point per history sample, at
+//! 2×4 braille dots per cell. Where a coefficient only says *how much* two
+//! metrics move together, the scatter shows *how*: a lag, a saturation
+//! knee or two separate clusters look very different at the same `r`.
+//!
+//! The two histories are paired by recency: when their lengths differ only
+//! the most recent common samples are used, which is what two ring buffers
+//! with different capacities hold.
+//!
+//! # Example
+//!
+//! ```
+//! use trueno_viz::monitor::widgets::Scatter;
+//!
+//! let cpu = vec![0.1, 0.4, 0.5, 0.9];
+//! let temp = vec![41.0, 55.0, 58.0, 77.0];
+//! let scatter = Scatter::new(&cpu, &temp).labels("cpu", "temp");
+//! ```
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::Widget;
+
+use crate::monitor::simd::simd_pearson_correlation;
+
+/// Braille dot bits, indexed by `[row][column]` within a cell.
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Scatter plot of one metric against another.
+#[derive(Debug, Clone)]
+pub struct Scatter<'a> {
+    /// Horizontal metric history.
+    x: &'a [f64],
+    /// Vertical metric history.
+    y: &'a [f64],
+    /// Point color.
+    color: Color,
+    /// Color of the most recent sample, if highlighted.
+    latest_color: Option<Color>,
+    /// Fixed x range (default: data extent).
+    x_range: Option<(f64, f64)>,
+    /// Fixed y range (default: data extent).
+    y_range: Option<(f64, f64)>,
+    /// Metric names for the header row.
+    labels: Option<(&'a str, &'a str)>,
+    /// Show the Pearson coefficient in the header row.
+    show_correlation: bool,
+}
+
+impl<'a> Scatter<'a> {
+    /// Creates a scatter of `y` against `x`.
+    #[must_use]
+    pub fn new(x: &'a [f64], y: &'a [f64]) -> Self {
+        Self {
+            x,
+            y,
+            color: Color::Cyan,
+            latest_color: Some(Color::Yellow),
+            x_range: None,
+            y_range: None,
+            labels: None,
+            show_correlation: true,
+        }
+    }
+
+    /// Sets the point color.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the color of the most recent sample, or `None` to draw it like
+    /// the others.
+    #[must_use]
+    pub fn latest_color(mut self, color: Option<Color>) -> Self {
+        self.latest_color = color;
+        self
+    }
+
+    /// Fixes the x range instead of fitting the data.
+    #[must_use]
+    pub fn x_range(mut self, min: f64, max: f64) -> Self {
+        self.x_range = Some((min, max));
+        self
+    }
+
+    /// Fixes the y range instead of fitting the data.
+    #[must_use]
+    pub fn y_range(mut self, min: f64, max: f64) -> Self {
+        self.y_range = Some((min, max));
+        self
+    }
+
+    /// Names the two metrics in the header row.
+    #[must_use]
+    pub fn labels(mut self, x: &'a str, y: &'a str) -> Self {
+        self.labels = Some((x, y));
+        self
+    }
+
+    /// Shows or hides the correlation coefficient.
+    #[must_use]
+    pub fn show_correlation(mut self, show: bool) -> Self {
+        self.show_correlation = show;
+        self
+    }
+
+    /// The most recent finite `(x, y)` pairs, oldest first.
+    fn points(&self) -> Vec<(f64, f64)> {
+        let n = self.x.len().min(self.y.len());
+        self.x[self.x.len() - n..]
+            .iter()
+            .zip(&self.y[self.y.len() - n..])
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .map(|(&x, &y)| (x, y))
+            .collect()
+    }
+
+    /// Pearson coefficient of the paired samples; `None` with fewer than two
+    /// finite pairs.
+    #[must_use]
+    pub fn correlation(&self) -> Option<f64> {
+        let (x, y): (Vec<f64>, Vec<f64>) = self.points().into_iter().unzip();
+        let result = simd_pearson_correlation(&x, &y);
+        (result.sample_count >= 2 && result.coefficient.is_finite()).then_some(result.coefficient)
+    }
+
+    /// Header text: metric names and coefficient.
+    fn header(&self) -> Option<String> {
+        let r = self.correlation().filter(|_| self.show_correlation);
+        match (self.labels, r) {
+            (Some((x, y)), Some(r)) => Some(format!("{y} vs {x}  r={r:+.2}")),
+            (Some((x, y)), None) => Some(format!("{y} vs {x}")),
+            (None, Some(r)) => Some(format!("r={r:+.2}")),
+            (None, None) => None,
+        }
+    }
+
+    /// Resolve an axis range, widening a degenerate one around its value.
+    fn range(fixed: Option<(f64, f64)>, data: impl Iterator<Item = f64>) -> (f64, f64) {
+        let (lo, hi) = fixed.unwrap_or_else(|| {
+            data.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| (lo.min(v), hi.max(v)))
+        });
+        if !lo.is_finite() || !hi.is_finite() {
+            (0.0, 1.0)
+        } else if hi > lo {
+            (lo, hi)
+        } else {
+            (lo - 0.5, lo + 0.5)
+        }
+    }
+}
+
+impl Widget for Scatter<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        let mut plot = area;
+        if let Some(header) = self.header().filter(|_| area.height >= 2) {
+            buf.set_stringn(
+                area.x,
+                area.y,
+                &header,
+                area.width as usize,
+                Style::default().fg(Color::White),
+            );
+            plot.y += 1;
+            plot.height -= 1;
+        }
+
+        let points = self.points();
+        let (x_lo, x_hi) = Self::range(self.x_range, points.iter().map(|p| p.0));
+        let (y_lo, y_hi) = Self::range(self.y_range, points.iter().map(|p| p.1));
+        let dots_w = f64::from(plot.width) * 2.0;
+        let dots_h = f64::from(plot.height) * 4.0;
+
+        let mut cells = vec![0u8; plot.width as usize * plot.height as usize];
+        let mut latest_cell = None;
+        for (i, &(x, y)) in points.iter().enumerate() {
+            let fx = ((x - x_lo) / (x_hi - x_lo)).clamp(0.0, 1.0);
+            let fy = ((y - y_lo) / (y_hi - y_lo)).clamp(0.0, 1.0);
+            let dx = ((fx * dots_w) as usize).min(dots_w as usize - 1);
+            let dy = (((1.0 - fy) * dots_h) as usize).min(dots_h as usize - 1);
+            let cell = (dy / 4) * plot.width as usize + dx / 2;
+            cells[cell] |= BRAILLE_DOTS[dy % 4][dx % 2];
+            if i + 1 == points.len() {
+                latest_cell = Some(cell);
+            }
+        }
+
+        for (i, &bits) in cells.iter().enumerate() {
+            if bits == 0 {
+                continue;
+            }
+            let x = plot.x + (i % plot.width as usize) as u16;
+            let y = plot.y + (i / plot.width as usize) as u16;
+            let color = match self.latest_color {
+                Some(latest) if latest_cell == Some(i) => latest,
+                _ => self.color,
+            };
+            let ch = char::from_u32(0x2800 + u32::from(bits)).unwrap_or(' ');
+            buf[(x, y)].set_char(ch).set_fg(color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_correlation_pairs_most_recent_samples() {
+        // x has an older sample that y's shorter history no longer holds
+        let x = [100.0, 1.0, 2.0, 3.0];
+        let y = [2.0, 4.0, 6.0];
+        let r = Scatter::new(&x, &y).correlation().expect("three pairs");
+        assert!((r - 1.0).abs() < 1e-9);
+        assert_eq!(Scatter::new(&[1.0], &[2.0]).correlation(), None);
+    }
+
+    #[test]
+    fn test_render_corners_and_header() {
+        let x = [0.0, 1.0];
+        let y = [0.0, 1.0];
+        let area = Rect::new(0, 0, 10, 3);
+        let mut buf = Buffer::empty(area);
+        Scatter::new(&x, &y).labels("cpu", "temp").latest_color(None).render(area, &mut buf);
+
+        assert_eq!(buf[(0, 0)].symbol(), "t");
+        // Bottom-left dot of the bottom-left cell, top-right of the top-right
+        assert_eq!(buf[(0, 2)].symbol(), "\u{2840}");
+        assert_eq!(buf[(9, 1)].symbol(), "\u{2808}");
+        assert_eq!(buf[(9, 1)].fg, Color::Cyan);
+    }
+
+    #[test]
+    fn test_render_highlights_latest_and_skips_nan() {
+        let x = [0.0, f64::NAN, 1.0];
+        let y = [0.0, 5.0, 1.0];
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buf = Buffer::empty(area);
+        Scatter::new(&x, &y).show_correlation(false).render(area, &mut buf);
+
+        assert_eq!(buf[(3, 0)].fg, Color::Yellow);
+        assert_eq!(buf[(0, 1)].fg, Color::Cyan);
+        let dots = (0..4)
+            .flat_map(|x| (0..2).map(move |y| (x, y)))
+            .filter(|&p| buf[p].symbol() != " ")
+            .count();
+        assert_eq!(dots, 2);
+    }
+}
@@ -8,6 +8,8 @@ use ratatui::layout::Rect;
 use ratatui::style::{Color, Style};
 use ratatui::widgets::Widget;
 
+use super::scaling::YScale;
+
 /// A compact inline sparkline.
 #[derive(Debug, Clone)]
 pub struct MonitorSparkline<'a> {
@@ -17,13 +19,15 @@ pub struct MonitorSparkline<'a> {
     color: Color,
     /// Whether to show a trend indicator suffix (↑↓→).
     show_trend: bool,
+    /// Value to height mapping.
+    y_scale: YScale,
 }
 
 impl<'a> MonitorSparkline<'a> {
     /// Creates a new sparkline with the given data.
     #[must_use]
     pub fn new(data: &'a [f64]) -> Self {
-        Self { data, color: Color::Cyan, show_trend: true }
+        Self { data, color: Color::Cyan, show_trend: true, y_scale: YScale::default() }
     }
 
     /// Sets the color.
@@ -40,6 +44,13 @@ impl<'a> MonitorSparkline<'a> {
         self
     }
 
+    /// Sets the y-axis scaling (default: data minimum to maximum).
+    #[must_use]
+    pub fn y_scale(mut self, scale: YScale) -> Self {
+        self.y_scale = scale;
+        self
+    }
+
     /// Calculates the trend based on recent values.
     fn trend(&self) -> char {
         if self.data.len() < 2 {
@@ -82,7 +93,7 @@ impl Widget for MonitorSparkline<'_> {
         // Find min/max for scaling
         let min = self.data.iter().copied().fold(f64::INFINITY, f64::min);
         let max = self.data.iter().copied().fold(f64::NEG_INFINITY, f64::max);
-        let range = max - min;
+        let mapping = self.y_scale.mapping(min, max, (min, max));
 
         // Reserve space for trend indicator
         let chart_width =
@@ -97,8 +108,7 @@ impl Widget for MonitorSparkline<'_> {
             };
 
             let value = self.data.get(data_idx).copied().unwrap_or(0.0);
-            let normalized =
-                if range > 0.0 { ((value - min) / range).clamp(0.0, 1.0) } else { 0.5 };
+            let normalized = mapping.apply(value);
 
             let block_idx = ((normalized * 7.0) as usize).min(7);
             let block = blocks[block_idx];
@@ -392,4 +402,19 @@ mod tests {
 
         assert_eq!(sparkline.trend(), '↑');
     }
+
+    #[test]
+    fn test_sparkline_fixed_range() {
+        // A flat 10% load on a 0-100 gauge sits near the bottom; the auto
+        // range would draw it mid-height
+        let data = vec![10.0, 10.0, 10.0, 10.0];
+        let area = Rect::new(0, 0, 4, 1);
+        let render = |scale: YScale| {
+            let mut buf = Buffer::empty(area);
+            MonitorSparkline::new(&data).show_trend(false).y_scale(scale).render(area, &mut buf);
+            buf
+        };
+        assert_eq!(render(YScale::Fixed { min: 0.0, max: 100.0 })[(0, 0)].symbol(), "▁");
+        assert_eq!(render(YScale::Auto)[(0, 0)].symbol(), "▄");
+    }
 }
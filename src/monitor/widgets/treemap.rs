@@ -0,0 +1,308 @@
+//! Treemap widget for hierarchy-by-size data.
+//!
+//! Lays out nodes as nested rectangles with area proportional to size, using
+//! the squarified algorithm (Bruls, Huizing & van Wijk, 2000) so cells stay
+//! close to square and their labels readable. Suited to per-process memory,
+//! per-directory disk usage and similar breakdowns.
+//!
+//! # Example
+//!
+//! ```
+//! use trueno_viz::monitor::widgets::{Treemap, TreemapNode};
+//!
+//! let home = TreemapNode::new("home", 0.0)
+//!     .child(TreemapNode::new("models", 40.0))
+//!     .child(TreemapNode::new("src", 5.0));
+//! let treemap = Treemap::new(vec![home, TreemapNode::new("var", 12.0)]).depth(2);
+//! ```
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::Widget;
+
+use crate::color::categorical;
+
+/// A node in the hierarchy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreemapNode {
+    /// Display label.
+    pub label: String,
+    /// Own size (e.g. bytes).
+    pub value: f64,
+    /// Fill color; cycles through the categorical palette when `None`.
+    pub color: Option<Color>,
+    /// Child nodes.
+    pub children: Vec<TreemapNode>,
+}
+
+impl TreemapNode {
+    /// Creates a leaf node.
+    #[must_use]
+    pub fn new(label: impl Into<String>, value: f64) -> Self {
+        Self { label: label.into(), value, color: None, children: Vec::new() }
+    }
+
+    /// Sets the fill color.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Adds a child node.
+    #[must_use]
+    pub fn child(mut self, child: TreemapNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Size used for layout: the node's own value, or the sum of its
+    /// children if that is larger.
+    #[must_use]
+    pub fn size(&self) -> f64 {
+        let own = if self.value.is_finite() { self.value.max(0.0) } else { 0.0 };
+        own.max(self.children.iter().map(Self::size).sum())
+    }
+}
+
+/// Squarified treemap layout of `values` within `(x, y, w, h)`.
+///
+/// Returns one `(x, y, w, h)` rectangle per value, in input order; values
+/// that are not positive get an empty rectangle.
+#[must_use]
+pub fn squarify(values: &[f64], rect: (f64, f64, f64, f64)) -> Vec<(f64, f64, f64, f64)> {
+    let (mut x, mut y, mut w, mut h) = rect;
+    let mut out = vec![(x, y, 0.0, 0.0); values.len()];
+    let mut order: Vec<usize> =
+        (0..values.len()).filter(|&i| values[i].is_finite() && values[i] > 0.0).collect();
+    order.sort_by(|&a, &b| values[b].total_cmp(&values[a]));
+    let total: f64 = order.iter().map(|&i| values[i]).sum();
+    if total <= 0.0 || w <= 0.0 || h <= 0.0 {
+        return out;
+    }
+    let scale = w * h / total;
+    let area = |i: usize| values[i] * scale;
+
+    // Worst aspect ratio of a row of areas laid along a side of length `side`
+    let worst = |sum: f64, min: f64, max: f64, side: f64| {
+        let s2 = side * side;
+        (s2 * max / (sum * sum)).max(sum * sum / (s2 * min))
+    };
+
+    let mut start = 0;
+    while start < order.len() {
+        let side = w.min(h);
+        let first = area(order[start]);
+        let (mut sum, mut min, mut max) = (first, first, first);
+        let mut end = start + 1;
+        while end < order.len() {
+            let a = area(order[end]);
+            if worst(sum + a, min.min(a), max.max(a), side) > worst(sum, min, max, side) {
+                break;
+            }
+            sum += a;
+            min = min.min(a);
+            max = max.max(a);
+            end += 1;
+        }
+
+        // Lay the row along the shorter side, then shrink the free space
+        let thickness = sum / side;
+        let mut offset = 0.0;
+        for &i in &order[start..end] {
+            let length = area(i) / thickness;
+            out[i] = if w >= h {
+                (x, y + offset, thickness, length)
+            } else {
+                (x + offset, y, length, thickness)
+            };
+            offset += length;
+        }
+        if w >= h {
+            x += thickness;
+            w -= thickness;
+        } else {
+            y += thickness;
+            h -= thickness;
+        }
+        start = end;
+    }
+    out
+}
+
+/// Squarified treemap of a node hierarchy.
+#[derive(Debug, Clone)]
+pub struct Treemap {
+    /// Top-level nodes.
+    nodes: Vec<TreemapNode>,
+    /// Number of hierarchy levels drawn (at least 1).
+    depth: usize,
+    /// Formats a node's size for a second label line.
+    value_format: Option<fn(f64) -> String>,
+}
+
+impl Treemap {
+    /// Creates a treemap of the given top-level nodes.
+    #[must_use]
+    pub fn new(nodes: Vec<TreemapNode>) -> Self {
+        Self { nodes, depth: 1, value_format: None }
+    }
+
+    /// Sets how many hierarchy levels are drawn. A parent with room for it
+    /// gets a one-row header and its children fill the rest.
+    #[must_use]
+    pub fn depth(mut self, depth: usize) -> Self {
+        self.depth = depth.max(1);
+        self
+    }
+
+    /// Shows each node's size below its label, formatted by `format`.
+    #[must_use]
+    pub fn value_format(mut self, format: fn(f64) -> String) -> Self {
+        self.value_format = Some(format);
+        self
+    }
+
+    /// Lay out `nodes` in `area` and draw them, recursing `depth` levels.
+    /// Uncolored nodes take palette entries from `palette_start` on, so
+    /// children never share their header's color.
+    fn render_level(
+        &self,
+        nodes: &[TreemapNode],
+        area: Rect,
+        depth: usize,
+        palette_start: usize,
+        buf: &mut Buffer,
+    ) {
+        let sizes: Vec<f64> = nodes.iter().map(TreemapNode::size).collect();
+        let rect =
+            (f64::from(area.x), f64::from(area.y), f64::from(area.width), f64::from(area.height));
+        for (i, (node, (x, y, w, h))) in nodes.iter().zip(squarify(&sizes, rect)).enumerate() {
+            // Snap edges to cells so neighbours share borders without gaps
+            let (x0, y0) = (x.round() as u16, y.round() as u16);
+            let (x1, y1) = ((x + w).round() as u16, (y + h).round() as u16);
+            if x1 <= x0 || y1 <= y0 {
+                continue;
+            }
+            let cell = Rect::new(x0, y0, x1 - x0, y1 - y0);
+            let color = node.color.unwrap_or_else(|| {
+                let c = categorical(palette_start + i);
+                Color::Rgb(c.r, c.g, c.b)
+            });
+            buf.set_style(cell, Style::default().bg(color).fg(contrast(color)));
+            for y in cell.top()..cell.bottom() {
+                for x in cell.left()..cell.right() {
+                    buf[(x, y)].set_char(' ');
+                }
+            }
+
+            let nested = depth > 1 && !node.children.is_empty() && cell.height >= 3;
+            self.render_label(node, cell, nested, buf);
+            if nested {
+                let inner = Rect::new(cell.x, cell.y + 1, cell.width, cell.height - 1);
+                self.render_level(&node.children, inner, depth - 1, palette_start + i + 1, buf);
+            }
+        }
+    }
+
+    /// Draw a node's label (and size, for leaves with room) at its top left.
+    fn render_label(&self, node: &TreemapNode, cell: Rect, header: bool, buf: &mut Buffer) {
+        let width = cell.width as usize;
+        buf.set_stringn(cell.x, cell.y, &node.label, width, Style::default());
+        if let (Some(format), false, true) = (self.value_format, header, cell.height >= 2) {
+            buf.set_stringn(cell.x, cell.y + 1, format(node.size()), width, Style::default());
+        }
+    }
+}
+
+/// Black or white text, whichever reads better on `background`.
+fn contrast(background: Color) -> Color {
+    match background {
+        Color::Rgb(r, g, b)
+            if 0.299 * f64::from(r) + 0.587 * f64::from(g) + 0.114 * f64::from(b) > 140.0 =>
+        {
+            Color::Black
+        }
+        _ => Color::White,
+    }
+}
+
+impl Widget for Treemap {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        self.render_level(&self.nodes, area, self.depth, 0, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_squarify_areas_and_bounds() {
+        let values = [6.0, 6.0, 4.0, 3.0, 2.0, 2.0, 1.0, 0.0];
+        let rects = squarify(&values, (0.0, 0.0, 6.0, 4.0));
+        assert_eq!(rects.len(), values.len());
+        for (v, (x, y, w, h)) in values.iter().zip(&rects) {
+            assert!((w * h - v).abs() < 1e-9, "area {} for value {v}", w * h);
+            assert!(*x >= -1e-9 && *y >= -1e-9 && x + w <= 6.0 + 1e-9 && y + h <= 4.0 + 1e-9);
+        }
+        // The two largest share the first column on the shorter side
+        assert!((rects[0].2 - 3.0).abs() < 1e-9 && (rects[1].2 - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_node_size_includes_children() {
+        let dir = TreemapNode::new("dir", 1.0)
+            .child(TreemapNode::new("a", 2.0))
+            .child(TreemapNode::new("b", 0.0).child(TreemapNode::new("c", 3.0)));
+        assert!((dir.size() - 5.0).abs() < 1e-9);
+        assert!((TreemapNode::new("x", f64::NAN).size()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_render_fills_area_proportionally() {
+        let nodes = vec![
+            TreemapNode::new("big", 3.0).color(Color::Rgb(200, 0, 0)),
+            TreemapNode::new("small", 1.0).color(Color::Rgb(0, 0, 200)),
+        ];
+        let area = Rect::new(0, 0, 20, 4);
+        let mut buf = Buffer::empty(area);
+        Treemap::new(nodes).render(area, &mut buf);
+
+        let red = (0..20).filter(|&x| buf[(x, 2)].bg == Color::Rgb(200, 0, 0)).count();
+        assert_eq!(red, 15);
+        assert_eq!(buf[(0, 0)].symbol(), "b");
+        assert_eq!(buf[(15, 0)].symbol(), "s");
+        assert_eq!(buf[(15, 0)].fg, Color::White);
+    }
+
+    #[test]
+    fn test_render_nested_header_and_values() {
+        let root = TreemapNode::new("home", 0.0)
+            .color(Color::Rgb(40, 40, 40))
+            .child(TreemapNode::new("a", 1.0).color(Color::Rgb(250, 250, 0)));
+        let area = Rect::new(0, 0, 10, 4);
+        let mut buf = Buffer::empty(area);
+        Treemap::new(vec![root])
+            .depth(2)
+            .value_format(|v| format!("{v:.0}B"))
+            .render(area, &mut buf);
+
+        assert_eq!(buf[(0, 0)].symbol(), "h");
+        assert_eq!(buf[(0, 0)].bg, Color::Rgb(40, 40, 40));
+        assert_eq!(buf[(0, 1)].symbol(), "a");
+        assert_eq!(buf[(0, 1)].fg, Color::Black);
+        assert_eq!(buf[(0, 2)].symbol(), "1");
+        assert_eq!(buf[(5, 3)].bg, Color::Rgb(250, 250, 0));
+    }
+
+    #[test]
+    fn test_render_zero_area() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Treemap::new(vec![TreemapNode::new("x", 1.0)]).render(Rect::new(0, 0, 0, 0), &mut buf);
+    }
+}
@@ -0,0 +1,243 @@
+//! Linked brushing across the charts of one HTML export.
+//!
+//! A [`LinkedBrush`] reuses the [`CursorPanel`] description of each chart.
+//! Series tagged with a data source (see
+//! [`CursorSeries::source`](super::CursorSeries::source)) carry the row of
+//! every point; dragging a rectangle over one panel selects the rows
+//! under it, and every panel showing the same source fades its other points
+//! and outlines the selected ones. A click without dragging, or `Esc`,
+//! clears the selection.
+//!
+//! [`PlotGrid::to_html`](crate::plots::PlotGrid::to_html) builds one for a
+//! whole grid of charts.
+//!
+//! # Example
+//!
+//! ```rust
+//! use trueno_viz::color::Rgba;
+//! use trueno_viz::output::{CursorPanel, CursorSeries, HtmlExporter, LinkedBrush};
+//!
+//! let (sepal, petal) = ([5.1, 4.9, 6.3], [1.4, 1.5, 4.9]);
+//! let to_px = |left: f32| move |x: f32, y: f32| (left + x * 40.0, 300.0 - y * 40.0);
+//! let a = CursorSeries::from_xy("sepal", Rgba::BLUE, &sepal, &petal, to_px(0.0));
+//! let b = CursorSeries::from_xy("petal", Rgba::RED, &petal, &sepal, to_px(300.0));
+//!
+//! let brush = LinkedBrush::new()
+//!     .panel(CursorPanel::new(0.0, 0.0, 300.0, 300.0).series(a).source("iris"))
+//!     .panel(CursorPanel::new(300.0, 0.0, 300.0, 300.0).series(b).source("iris"));
+//! let html = HtmlExporter::from_svg_string("<svg></svg>".into(), 600, 300).linked_brush(brush);
+//! assert!(html.to_html().contains("tv-brush-data"));
+//! ```
+
+use crate::color::Rgba;
+
+use super::cursor::panels_json;
+use super::svg::rgba_to_css;
+use super::CursorPanel;
+
+/// Linked selection configuration for an HTML export.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkedBrush {
+    color: Rgba,
+    panels: Vec<CursorPanel>,
+}
+
+impl Default for LinkedBrush {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LinkedBrush {
+    /// Create a brush with no panels and an orange highlight.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { color: Rgba::rgb(255, 127, 14), panels: Vec::new() }
+    }
+
+    /// Set the outline color of selected points and of the brush rectangle.
+    #[must_use]
+    pub fn color(mut self, color: Rgba) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Add a panel.
+    #[must_use]
+    pub fn panel(mut self, panel: CursorPanel) -> Self {
+        self.panels.push(panel);
+        self
+    }
+
+    /// Panels in this brush.
+    #[must_use]
+    pub fn panels(&self) -> &[CursorPanel] {
+        &self.panels
+    }
+
+    /// Serialize the brushing data read by the HTML runtime.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"color":"{}","panels":{}}}"#,
+            rgba_to_css(self.color),
+            panels_json(&self.panels)
+        )
+    }
+
+    /// The data block and runtime script appended to the HTML body.
+    pub(super) fn to_script(&self) -> String {
+        format!(
+            "<script type=\"application/json\" id=\"tv-brush-data\">{}</script>\n    \
+             <script>{BRUSH_RUNTIME}</script>",
+            self.to_json()
+        )
+    }
+}
+
+/// Browser side of linked brushing: drag to select rows, highlight them everywhere.
+const BRUSH_RUNTIME: &str = r"
+(function () {
+    const cfg = JSON.parse(document.getElementById('tv-brush-data').textContent);
+    const svg = document.querySelector('.chart-container svg');
+    if (!svg) return;
+    const NS = 'http://www.w3.org/2000/svg';
+    const layer = document.createElementNS(NS, 'g');
+    layer.setAttribute('class', 'tv-brush');
+    layer.setAttribute('pointer-events', 'none');
+    svg.appendChild(layer);
+
+    const add = (tag, attrs) => {
+        const node = document.createElementNS(NS, tag);
+        for (const [k, v] of Object.entries(attrs)) node.setAttribute(k, v);
+        layer.appendChild(node);
+        return node;
+    };
+    const toSvg = (evt) => {
+        const ctm = svg.getScreenCTM();
+        return ctm && new DOMPoint(evt.clientX, evt.clientY).matrixTransform(ctm.inverse());
+    };
+    const inside = (p, q) => q.x >= p.rect[0] && q.x <= p.rect[0] + p.rect[2]
+        && q.y >= p.rect[1] && q.y <= p.rect[1] + p.rect[3];
+    const clamp = (v, lo, hi) => Math.min(Math.max(v, lo), hi);
+
+    let drag = null;
+    const selected = new Map(); // source -> Set of rows
+
+    // Rows of every sourced series whose points fall inside the brush
+    function select(panel, a, b) {
+        const [x0, x1] = [Math.min(a.x, b.x), Math.max(a.x, b.x)];
+        const [y0, y1] = [Math.min(a.y, b.y), Math.max(a.y, b.y)];
+        selected.clear();
+        for (const s of panel.series) {
+            if (s.source == null) continue;
+            const rows = selected.get(s.source) || new Set();
+            s.points.forEach((pt, i) => {
+                if (pt[2] >= x0 && pt[2] <= x1 && pt[3] >= y0 && pt[3] <= y1) rows.add(s.rows[i]);
+            });
+            selected.set(s.source, rows);
+        }
+    }
+
+    function render() {
+        layer.replaceChildren();
+        for (const p of cfg.panels) {
+            const linked = p.series.filter((s) => s.source != null && selected.has(s.source));
+            if (!linked.length) continue;
+            const [x, y, w, h] = p.rect;
+            add('rect', { x, y, width: w, height: h, fill: 'rgba(255,255,255,0.65)' });
+            for (const s of linked) {
+                const rows = selected.get(s.source);
+                s.points.forEach((pt, i) => {
+                    if (!rows.has(s.rows[i])) return;
+                    add('circle', { cx: pt[2], cy: pt[3], r: 3.5, fill: s.color, stroke: cfg.color, 'stroke-width': 1.5 });
+                });
+            }
+        }
+        if (drag) {
+            const [a, b] = [drag.start, drag.end];
+            add('rect', {
+                x: Math.min(a.x, b.x), y: Math.min(a.y, b.y),
+                width: Math.abs(b.x - a.x), height: Math.abs(b.y - a.y),
+                fill: 'rgba(0,0,0,0.06)', stroke: cfg.color, 'stroke-dasharray': '4 2',
+            });
+        }
+    }
+
+    svg.addEventListener('mousedown', (evt) => {
+        const q = toSvg(evt);
+        const panel = q && cfg.panels.find((p) => inside(p, q));
+        if (!panel) return;
+        evt.preventDefault();
+        drag = { panel, start: q, end: q };
+    });
+    window.addEventListener('mousemove', (evt) => {
+        const q = drag && toSvg(evt);
+        if (!q) return;
+        const [x, y, w, h] = drag.panel.rect;
+        drag.end = { x: clamp(q.x, x, x + w), y: clamp(q.y, y, y + h) };
+        select(drag.panel, drag.start, drag.end);
+        render();
+    });
+    window.addEventListener('mouseup', () => {
+        if (!drag) return;
+        const moved = Math.abs(drag.end.x - drag.start.x) + Math.abs(drag.end.y - drag.start.y);
+        if (moved < 3) selected.clear();
+        drag = null;
+        render();
+    });
+    window.addEventListener('keydown', (evt) => {
+        if (evt.key !== 'Escape') return;
+        selected.clear();
+        render();
+    });
+})();
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::CursorSeries;
+
+    #[test]
+    fn test_rows_follow_input_order() {
+        let series = CursorSeries::from_xy(
+            "s",
+            Rgba::BLUE,
+            &[0.0, f32::NAN, 2.0, 3.0],
+            &[1.0, 1.0, 1.0, 1.0],
+            |x, y| (x, y),
+        )
+        .source("runs");
+        let json = LinkedBrush::new()
+            .panel(CursorPanel::new(0.0, 0.0, 10.0, 10.0).series(series))
+            .to_json();
+
+        assert!(json.starts_with(r#"{"color":"rgb(255,127,14)","panels":[{"rect":[0,0,10,10]"#));
+        assert!(json.contains(r#""source":"runs","rows":[0,2,3]}"#));
+    }
+
+    #[test]
+    fn test_panel_source_keeps_explicit_sources() {
+        let panel = CursorPanel::new(0.0, 0.0, 10.0, 10.0)
+            .series(CursorSeries::new("a", Rgba::RED).point(1.0, 1.0, 1.0, 1.0))
+            .series(CursorSeries::new("b", Rgba::RED).source("other"))
+            .source("main");
+        let json = LinkedBrush::new().color(Rgba::BLACK).panel(panel).to_json();
+
+        assert!(json.contains(r#""name":"a","color":"rgb(255,0,0)","points":[[1,1,1.00,1.00]],"source":"main","rows":[0]"#));
+        assert!(json.contains(
+            r#""name":"b","color":"rgb(255,0,0)","points":[],"source":"other","rows":[]"#
+        ));
+        assert!(json.starts_with(r#"{"color":"rgb(0,0,0)""#));
+    }
+
+    #[test]
+    fn test_unsourced_series_have_no_rows() {
+        let panel = CursorPanel::new(0.0, 0.0, 10.0, 10.0)
+            .series(CursorSeries::new("a", Rgba::RED).point(1.0, 1.0, 1.0, 1.0));
+        let json = LinkedBrush::new().panel(panel).to_json();
+        assert!(json.contains(r#""points":[[1,1,1.00,1.00]]}"#));
+        assert!(!json.contains("rows"));
+    }
+}
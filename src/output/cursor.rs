@@ -0,0 +1,492 @@
+//! Data cursor (crosshair read-out) for interactive HTML exports.
+//!
+//! A [`Crosshair`] describes where the data points of each plot panel sit in
+//! the exported figure. [`HtmlExporter::crosshair`](super::HtmlExporter::crosshair)
+//! embeds it as JSON together with a small script: hovering a panel snaps a
+//! crosshair to the nearest data point and shows its series name and x/y
+//! values. Panels that share a sync group (e.g. stacked facets over the same
+//! time axis) show a cursor at the same x in every panel at once.
+//!
+//! # Example
+//!
+//! ```rust
+//! use trueno_viz::color::Rgba;
+//! use trueno_viz::output::{Crosshair, CursorMode, CursorPanel, CursorSeries, HtmlExporter};
+//!
+//! // Two 400x150 facets stacked vertically, sharing the x axis
+//! let to_px = |top: f32| move |x: f32, y: f32| (40.0 + x * 32.0, top + 150.0 - y * 15.0);
+//! let t = [0.0, 5.0, 10.0];
+//! let cpu = CursorSeries::from_xy("cpu", Rgba::BLUE, &t, &[2.0, 7.0, 4.0], to_px(0.0));
+//! let mem = CursorSeries::from_xy("mem", Rgba::RED, &t, &[6.0, 6.5, 9.0], to_px(160.0));
+//!
+//! let crosshair = Crosshair::new()
+//!     .mode(CursorMode::Nearest)
+//!     .panel(CursorPanel::new(40.0, 0.0, 320.0, 150.0).series(cpu).sync("time"))
+//!     .panel(CursorPanel::new(40.0, 160.0, 320.0, 150.0).series(mem).sync("time"));
+//!
+//! let html = HtmlExporter::from_svg_string("<svg></svg>".into(), 400, 310)
+//!     .crosshair(crosshair)
+//!     .to_html();
+//! assert!(html.contains("tv-cursor-data"));
+//! ```
+
+use std::fmt::Write as _;
+
+use crate::color::Rgba;
+
+use super::svg::rgba_to_css;
+
+/// How the cursor picks a data point inside the hovered panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CursorMode {
+    /// Snap to the point closest to the mouse; draws both crosshair lines.
+    #[default]
+    Nearest,
+    /// Snap to the closest x and read out every series at that x.
+    X,
+}
+
+impl CursorMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Nearest => "nearest",
+            Self::X => "x",
+        }
+    }
+}
+
+/// A named series of data points with their pixel positions in the figure.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorSeries {
+    name: String,
+    color: Rgba,
+    /// `[x, y, px, py]`: data values and figure coordinates.
+    points: Vec<[f32; 4]>,
+    /// Data source the points' rows belong to (for linked brushing).
+    source: Option<String>,
+    /// Row of each point in its data source.
+    rows: Vec<usize>,
+    /// Row assigned to the next point added.
+    next_row: usize,
+}
+
+impl CursorSeries {
+    /// Create an empty series.
+    #[must_use]
+    pub fn new(name: impl Into<String>, color: Rgba) -> Self {
+        Self {
+            name: name.into(),
+            color,
+            points: Vec::new(),
+            source: None,
+            rows: Vec::new(),
+            next_row: 0,
+        }
+    }
+
+    /// Build a series from data columns, placing each point with `to_px`
+    /// (usually the chart's x and y scales). Non-finite points are skipped.
+    #[must_use]
+    pub fn from_xy(
+        name: impl Into<String>,
+        color: Rgba,
+        x: &[f32],
+        y: &[f32],
+        to_px: impl Fn(f32, f32) -> (f32, f32),
+    ) -> Self {
+        x.iter().zip(y).fold(Self::new(name, color), |series, (&x, &y)| {
+            let (px, py) = to_px(x, y);
+            series.point(x, y, px, py)
+        })
+    }
+
+    /// Add a point: data value `(x, y)` drawn at figure pixel `(px, py)`.
+    /// Points are numbered as rows of the data source in the order they are
+    /// added; non-finite points are skipped but still use up their row.
+    #[must_use]
+    pub fn point(mut self, x: f32, y: f32, px: f32, py: f32) -> Self {
+        let point = [x, y, px, py];
+        if point.iter().all(|v| v.is_finite()) {
+            self.points.push(point);
+            self.rows.push(self.next_row);
+        }
+        self.next_row += 1;
+        self
+    }
+
+    /// Name the data source whose rows these points are, so brushing them
+    /// highlights the same rows in other charts (see [`LinkedBrush`](super::LinkedBrush)).
+    #[must_use]
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Series name shown in the read-out.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Number of points.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Whether the series has no points.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+}
+
+/// A plot area (in figure pixels) and the series drawn in it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorPanel {
+    rect: [f32; 4],
+    sync: Option<String>,
+    series: Vec<CursorSeries>,
+}
+
+impl CursorPanel {
+    /// Create a panel covering the plot area at `(x, y)` of `width` x `height`.
+    #[must_use]
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self { rect: [x, y, width, height], sync: None, series: Vec::new() }
+    }
+
+    /// Add a series.
+    #[must_use]
+    pub fn series(mut self, series: CursorSeries) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Join a sync group: hovering any panel of the group shows a cursor at
+    /// the same x in all of them.
+    #[must_use]
+    pub fn sync(mut self, group: impl Into<String>) -> Self {
+        self.sync = Some(group.into());
+        self
+    }
+
+    /// Shift the panel and its points, e.g. into its cell of a
+    /// [`PlotGrid`](crate::plots::PlotGrid).
+    #[must_use]
+    pub fn offset(mut self, dx: f32, dy: f32) -> Self {
+        self.rect[0] += dx;
+        self.rect[1] += dy;
+        for point in self.series.iter_mut().flat_map(|s| &mut s.points) {
+            point[2] += dx;
+            point[3] += dy;
+        }
+        self
+    }
+
+    /// Set the data source of every series that has none yet.
+    #[must_use]
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        let source = source.into();
+        for series in self.series.iter_mut().filter(|s| s.source.is_none()) {
+            series.source = Some(source.clone());
+        }
+        self
+    }
+
+    /// Series in this panel.
+    #[must_use]
+    pub fn series_list(&self) -> &[CursorSeries] {
+        &self.series
+    }
+}
+
+/// Crosshair configuration for an HTML export.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Crosshair {
+    mode: CursorMode,
+    precision: usize,
+    panels: Vec<CursorPanel>,
+}
+
+impl Default for Crosshair {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Crosshair {
+    /// Create a crosshair with no panels (nearest-point mode, 4 significant digits).
+    #[must_use]
+    pub fn new() -> Self {
+        Self { mode: CursorMode::default(), precision: 4, panels: Vec::new() }
+    }
+
+    /// Set the snapping mode.
+    #[must_use]
+    pub fn mode(mut self, mode: CursorMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Significant digits shown for x/y values (1-17).
+    #[must_use]
+    pub fn precision(mut self, digits: usize) -> Self {
+        self.precision = digits.clamp(1, 17);
+        self
+    }
+
+    /// Add a panel.
+    #[must_use]
+    pub fn panel(mut self, panel: CursorPanel) -> Self {
+        self.panels.push(panel);
+        self
+    }
+
+    /// Panels in this crosshair.
+    #[must_use]
+    pub fn panels(&self) -> &[CursorPanel] {
+        &self.panels
+    }
+
+    /// Serialize the cursor data read by the HTML runtime.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"mode":"{}","precision":{},"panels":{}}}"#,
+            self.mode.as_str(),
+            self.precision,
+            panels_json(&self.panels)
+        )
+    }
+
+    /// The data block and runtime script appended to the HTML body.
+    pub(super) fn to_script(&self) -> String {
+        format!(
+            "<script type=\"application/json\" id=\"tv-cursor-data\">{}</script>\n    \
+             <script>{CURSOR_RUNTIME}</script>",
+            self.to_json()
+        )
+    }
+}
+
+/// JSON array of panels shared by the cursor and brushing runtimes.
+pub(super) fn panels_json(panels: &[CursorPanel]) -> String {
+    let mut json = String::from("[");
+    for (i, panel) in panels.iter().enumerate() {
+        let [x, y, w, h] = panel.rect;
+        let sync = panel.sync.as_deref().map_or_else(|| "null".to_string(), json_string);
+        let _ = write!(
+            json,
+            r#"{}{{"rect":[{x},{y},{w},{h}],"sync":{sync},"series":["#,
+            if i == 0 { "" } else { "," }
+        );
+        for (j, series) in panel.series.iter().enumerate() {
+            let _ = write!(
+                json,
+                r#"{}{{"name":{},"color":"{}","points":["#,
+                if j == 0 { "" } else { "," },
+                json_string(&series.name),
+                rgba_to_css(series.color)
+            );
+            for (k, [x, y, px, py]) in series.points.iter().enumerate() {
+                let sep = if k == 0 { "" } else { "," };
+                let _ = write!(json, "{sep}[{x},{y},{px:.2},{py:.2}]");
+            }
+            json.push(']');
+            if let Some(source) = &series.source {
+                let rows: Vec<String> = series.rows.iter().map(usize::to_string).collect();
+                let _ = write!(
+                    json,
+                    r#","source":{},"rows":[{}]"#,
+                    json_string(source),
+                    rows.join(",")
+                );
+            }
+            json.push('}');
+        }
+        json.push_str("]}");
+    }
+    json.push(']');
+    json
+}
+
+/// Quote a string for JSON embedded in a `<script>` element.
+pub(super) fn json_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            // `<` and `&` are escaped so the data cannot close its script tag
+            '<' | '>' | '&' => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            }
+            c if u32::from(c) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Browser side of the crosshair: snaps to data, draws into an overlay group.
+const CURSOR_RUNTIME: &str = r"
+(function () {
+    const cfg = JSON.parse(document.getElementById('tv-cursor-data').textContent);
+    const svg = document.querySelector('.chart-container svg');
+    if (!svg) return;
+    const NS = 'http://www.w3.org/2000/svg';
+    const layer = document.createElementNS(NS, 'g');
+    layer.setAttribute('class', 'tv-cursor');
+    layer.setAttribute('pointer-events', 'none');
+    svg.appendChild(layer);
+
+    const fmt = (v) => String(+v.toPrecision(cfg.precision));
+    const add = (tag, attrs, parent) => {
+        const node = document.createElementNS(NS, tag);
+        for (const [k, v] of Object.entries(attrs)) node.setAttribute(k, v);
+        (parent || layer).appendChild(node);
+        return node;
+    };
+    const inside = (p, q) => q.x >= p.rect[0] && q.x <= p.rect[0] + p.rect[2]
+        && q.y >= p.rect[1] && q.y <= p.rect[1] + p.rect[3];
+
+    // Closest point of any series, on screen (or by x only)
+    function nearest(panel, q, xOnly) {
+        let best = null;
+        for (const s of panel.series) {
+            for (const pt of s.points) {
+                const d = xOnly ? Math.abs(pt[2] - q.x) : Math.hypot(pt[2] - q.x, pt[3] - q.y);
+                if (!best || d < best.d) best = { d, s, pt };
+            }
+        }
+        return best;
+    }
+
+    // For each series, the point whose data x is closest to x
+    function atX(panel, x) {
+        const hits = [];
+        for (const s of panel.series) {
+            let best = null;
+            for (const pt of s.points) {
+                const d = Math.abs(pt[0] - x);
+                if (!best || d < best.d) best = { d, s, pt };
+            }
+            if (best) hits.push(best);
+        }
+        return hits;
+    }
+
+    function draw(panel, hits, horizontal) {
+        const [x0, y0, w, h] = panel.rect;
+        const [px, py] = [hits[0].pt[2], hits[0].pt[3]];
+        const rule = { stroke: '#555', 'stroke-width': 1, 'stroke-dasharray': '3 3' };
+        add('line', { x1: px, y1: y0, x2: px, y2: y0 + h, ...rule });
+        if (horizontal) add('line', { x1: x0, y1: py, x2: x0 + w, y2: py, ...rule });
+        for (const hit of hits) {
+            add('circle', { cx: hit.pt[2], cy: hit.pt[3], r: 4, fill: hit.s.color, stroke: '#fff' });
+        }
+        const box = add('rect', { fill: 'rgba(255,255,255,0.92)', stroke: '#999', rx: 3 });
+        const text = add('text', { 'font-size': 11, 'font-family': 'sans-serif', fill: '#222' });
+        hits.forEach((hit, i) => {
+            const line = add('tspan', { x: 0, dy: i ? '1.25em' : '1em' }, text);
+            line.textContent = `${hit.s.name}: x=${fmt(hit.pt[0])}, y=${fmt(hit.pt[1])}`;
+        });
+        // Beside the cursor, flipped left when it would leave the panel
+        const bb = text.getBBox();
+        const lx = px + bb.width + 20 > x0 + w ? px - bb.width - 16 : px + 8;
+        const ly = y0 + 4;
+        text.setAttribute('transform', `translate(${lx + 4 - bb.x},${ly + 2 - bb.y})`);
+        for (const [k, v] of Object.entries({ x: lx, y: ly, width: bb.width + 8, height: bb.height + 4 })) {
+            box.setAttribute(k, v);
+        }
+    }
+
+    const clear = () => layer.replaceChildren();
+    svg.addEventListener('mousemove', (evt) => {
+        clear();
+        const ctm = svg.getScreenCTM();
+        if (!ctm) return;
+        const q = new DOMPoint(evt.clientX, evt.clientY).matrixTransform(ctm.inverse());
+        const panel = cfg.panels.find((p) => inside(p, q));
+        const hit = panel && nearest(panel, q, cfg.mode === 'x');
+        if (!hit) return;
+        for (const p of cfg.panels) {
+            if (p === panel) {
+                draw(p, cfg.mode === 'x' ? atX(p, hit.pt[0]) : [hit], cfg.mode !== 'x');
+            } else if (panel.sync !== null && p.sync === panel.sync) {
+                const hits = atX(p, hit.pt[0]);
+                if (hits.length) draw(p, hits, false);
+            }
+        }
+    });
+    svg.addEventListener('mouseleave', clear);
+})();
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facet(top: f32, name: &str) -> CursorPanel {
+        let series =
+            CursorSeries::from_xy(name, Rgba::BLUE, &[0.0, 1.0, 2.0], &[5.0, 6.0, 7.0], |x, y| {
+                (10.0 + x * 50.0, top + 100.0 - y * 10.0)
+            });
+        CursorPanel::new(10.0, top, 100.0, 100.0).series(series)
+    }
+
+    #[test]
+    fn test_series_from_xy_skips_non_finite() {
+        let series = CursorSeries::from_xy(
+            "s",
+            Rgba::RED,
+            &[0.0, 1.0, f32::NAN, 3.0],
+            &[1.0, f32::INFINITY, 2.0, 4.0],
+            |x, y| (x * 2.0, y * 2.0),
+        );
+        assert_eq!(series.len(), 2);
+        let json =
+            Crosshair::new().panel(CursorPanel::new(0.0, 0.0, 1.0, 1.0).series(series)).to_json();
+        assert!(json.contains(r#""points":[[0,1,0.00,2.00],[3,4,6.00,8.00]]"#));
+        assert!(CursorSeries::new("empty", Rgba::RED).is_empty());
+    }
+
+    #[test]
+    fn test_offset_moves_rect_and_points() {
+        let panel = facet(0.0, "a").offset(5.0, 200.0);
+        let json = Crosshair::new().panel(panel).to_json();
+        assert!(json.contains(r#""rect":[15,200,100,100]"#));
+        assert!(json.contains(r#""points":[[0,5,15.00,250.00],"#));
+    }
+
+    #[test]
+    fn test_json_layout_and_sync_groups() {
+        let json = Crosshair::new()
+            .mode(CursorMode::X)
+            .precision(3)
+            .panel(facet(0.0, "cpu").sync("time"))
+            .panel(facet(110.0, "mem"))
+            .to_json();
+
+        assert!(json.starts_with(r#"{"mode":"x","precision":3,"panels":[{"rect":[10,0,100,100]"#));
+        assert!(json.contains(r#""sync":"time","series":[{"name":"cpu","color":"rgb(0,0,255)""#));
+        assert!(
+            json.contains(r#""points":[[0,5,10.00,50.00],[1,6,60.00,40.00],[2,7,110.00,30.00]]"#)
+        );
+        assert!(json.contains(r#""sync":null"#));
+        assert!(json.ends_with("]}]}]}"));
+    }
+
+    #[test]
+    fn test_json_string_cannot_close_script() {
+        assert_eq!(json_string("a\"b\\c"), r#""a\"b\\c""#);
+        let quoted = json_string("</script><b>&\n");
+        assert!(!quoted.contains('<') && !quoted.contains('\n'));
+        assert_eq!(quoted, r#""\u003c/script\u003e\u003cb\u003e\u0026\u000a""#);
+    }
+}
@@ -0,0 +1,211 @@
+//! Click-to-zoom runtime for flame graph HTML exports.
+//!
+//! [`FlameGraph::to_html`](crate::plots::FlameGraph::to_html) embeds every
+//! frame of the graph with its sample range. In the browser, clicking a frame
+//! widens it to the full plot width and redraws its callees to scale, with its
+//! callers kept full-width underneath; clicking the bottom frame or pressing
+//! `Esc` resets the view. `/` prompts for a search term and highlights the
+//! matching frames.
+
+use std::fmt::Write as _;
+
+use crate::color::Rgba;
+
+use super::cursor::json_string;
+use super::svg::rgba_to_css;
+
+/// One frame of a zoomable flame graph.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ZoomFrame {
+    /// Frame name.
+    pub name: String,
+    /// Hover tooltip.
+    pub tip: String,
+    /// Fill color.
+    pub color: Rgba,
+    /// Stack depth below the displayed root (0 = root).
+    pub depth: usize,
+    /// First sample of the frame, counted from the root's first sample.
+    pub start: u64,
+    /// Samples covered by the frame.
+    pub samples: u64,
+}
+
+/// Frame layout read by the flame graph HTML runtime.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FlameZoom {
+    /// Left edge of the plot area in pixels.
+    pub left: f32,
+    /// Plot area width in pixels.
+    pub width: f32,
+    /// Bottom edge of the root frame in pixels.
+    pub bottom: f32,
+    /// Row height in pixels.
+    pub row: f32,
+    /// Frames narrower than this many pixels are not drawn.
+    pub min_width: f32,
+    /// Frames in depth-first order, root first.
+    pub frames: Vec<ZoomFrame>,
+}
+
+impl FlameZoom {
+    /// Serialize the layout read by the HTML runtime.
+    pub(crate) fn to_json(&self) -> String {
+        let frames = self.frames.iter().enumerate().fold(String::new(), |mut out, (i, f)| {
+            if i > 0 {
+                out.push(',');
+            }
+            let _ = write!(
+                out,
+                r#"{{"name":{},"tip":{},"color":"{}","depth":{},"start":{},"samples":{}}}"#,
+                json_string(&f.name),
+                json_string(&f.tip),
+                rgba_to_css(f.color),
+                f.depth,
+                f.start,
+                f.samples
+            );
+            out
+        });
+        format!(
+            r#"{{"left":{},"width":{},"bottom":{},"row":{},"minWidth":{},"frames":[{frames}]}}"#,
+            self.left, self.width, self.bottom, self.row, self.min_width
+        )
+    }
+
+    /// The data block and runtime script appended to the HTML body.
+    pub(super) fn to_script(&self) -> String {
+        format!(
+            "<script type=\"application/json\" id=\"tv-flame-data\">{}</script>\n    \
+             <script>{FLAME_RUNTIME}</script>",
+            self.to_json()
+        )
+    }
+}
+
+/// Browser side of flame graph zoom: redraw the frames scaled to the clicked one.
+const FLAME_RUNTIME: &str = r"
+(function () {
+    const cfg = JSON.parse(document.getElementById('tv-flame-data').textContent);
+    const svg = document.querySelector('.chart-container svg');
+    if (!svg || !cfg.frames.length) return;
+    const NS = 'http://www.w3.org/2000/svg';
+    const FONT = 11;
+
+    // The runtime draws its own frames; drop the static ones
+    for (const g of svg.querySelectorAll('g')) {
+        if (g.querySelector('title') && g.querySelector('rect')) g.remove();
+    }
+    const layer = document.createElementNS(NS, 'g');
+    layer.setAttribute('class', 'tv-flame');
+    svg.appendChild(layer);
+
+    const add = (parent, tag, attrs) => {
+        const node = document.createElementNS(NS, tag);
+        for (const [k, v] of Object.entries(attrs)) node.setAttribute(k, v);
+        parent.appendChild(node);
+        return node;
+    };
+    const fit = (name, w) => {
+        const max = Math.floor((w - 6) / (FONT * 0.6));
+        if (max < 3) return null;
+        return [...name].length <= max ? name : [...name].slice(0, max - 2).join('') + '..';
+    };
+
+    let focus = cfg.frames[0];
+    let term = '';
+
+    function render() {
+        layer.replaceChildren();
+        const scale = cfg.width / focus.samples;
+        const end = focus.start + focus.samples;
+        for (const f of cfg.frames) {
+            let x, w;
+            if (f.depth < focus.depth) {
+                // Callers of the focused frame span the whole width
+                if (f.start > focus.start || f.start + f.samples < end) continue;
+                [x, w] = [cfg.left, cfg.width];
+            } else {
+                if (f.start < focus.start || f.start + f.samples > end) continue;
+                [x, w] = [cfg.left + (f.start - focus.start) * scale, f.samples * scale];
+            }
+            if (w < cfg.minWidth) continue;
+            const y = cfg.bottom - (f.depth + 1) * cfg.row;
+            const hit = term && f.name.includes(term);
+            const g = add(layer, 'g', { cursor: 'pointer' });
+            add(g, 'title', {}).textContent = f.tip;
+            add(g, 'rect', {
+                x, y, width: Math.max(w - 0.5, 0.5), height: Math.max(cfg.row - 1, 0.5),
+                fill: hit ? 'rgb(230,0,230)' : f.color,
+                opacity: f.depth < focus.depth ? 0.6 : 1,
+            });
+            const label = cfg.row >= FONT && fit(f.name, w);
+            if (label) {
+                add(g, 'text', {
+                    x: x + 3, y: y + cfg.row / 2 + FONT * 0.35, 'font-size': FONT,
+                    fill: 'rgb(30,30,30)', 'pointer-events': 'none',
+                }).textContent = label;
+            }
+            g.addEventListener('click', () => {
+                focus = f;
+                render();
+            });
+        }
+    }
+
+    window.addEventListener('keydown', (evt) => {
+        if (evt.key === 'Escape') {
+            [focus, term] = [cfg.frames[0], ''];
+        } else if (evt.key === '/') {
+            evt.preventDefault();
+            term = window.prompt('Search frames', term) || '';
+        } else {
+            return;
+        }
+        render();
+    });
+    render();
+})();
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zoom() -> FlameZoom {
+        let frame = |name: &str, depth, start, samples| ZoomFrame {
+            name: name.into(),
+            tip: format!("{name} ({samples} samples)"),
+            color: Rgba::RED,
+            depth,
+            start,
+            samples,
+        };
+        FlameZoom {
+            left: 10.0,
+            width: 280.0,
+            bottom: 190.0,
+            row: 16.0,
+            min_width: 1.0,
+            frames: vec![frame("main", 0, 0, 10), frame("<init>", 1, 0, 4)],
+        }
+    }
+
+    #[test]
+    fn test_flame_zoom_json() {
+        let json = zoom().to_json();
+        assert!(json.starts_with(
+            r#"{"left":10,"width":280,"bottom":190,"row":16,"minWidth":1,"frames":[{"name":"main""#
+        ));
+        assert!(json.contains(
+            r#"{"name":"\u003cinit\u003e","tip":"\u003cinit\u003e (4 samples)","color":"rgb(255,0,0)","depth":1,"start":0,"samples":4}]}"#
+        ));
+    }
+
+    #[test]
+    fn test_flame_zoom_script() {
+        let script = zoom().to_script();
+        assert!(script.starts_with(r#"<script type="application/json" id="tv-flame-data">{"#));
+        assert!(script.contains("getElementById('tv-flame-data')"));
+    }
+}
@@ -23,7 +23,8 @@ use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
-use super::SvgEncoder;
+use super::svg::element_to_svg;
+use super::{Crosshair, FlameZoom, LinkedBrush, SvgEncoder, Watermark};
 
 /// HTML exporter for self-contained chart files.
 #[derive(Debug, Clone)]
@@ -34,13 +35,18 @@ pub struct HtmlExporter {
     svg_content: String,
     /// Chart width
     width: u32,
-    /// Chart height (reserved for future use)
-    #[allow(dead_code)]
+    /// Chart height
     height: u32,
     /// Enable dark mode support
     dark_mode: bool,
     /// Enable responsive scaling
     responsive: bool,
+    /// Data cursor runtime
+    crosshair: Option<Crosshair>,
+    /// Linked brushing runtime
+    brush: Option<LinkedBrush>,
+    /// Flame graph zoom runtime
+    flame_zoom: Option<FlameZoom>,
 }
 
 impl HtmlExporter {
@@ -54,6 +60,9 @@ impl HtmlExporter {
             height: svg.height(),
             dark_mode: false,
             responsive: true,
+            crosshair: None,
+            brush: None,
+            flame_zoom: None,
         }
     }
 
@@ -67,6 +76,9 @@ impl HtmlExporter {
             height,
             dark_mode: false,
             responsive: true,
+            crosshair: None,
+            brush: None,
+            flame_zoom: None,
         }
     }
 
@@ -91,6 +103,43 @@ impl HtmlExporter {
         self
     }
 
+    /// Stamp a watermark onto the embedded chart.
+    ///
+    /// The watermark is injected as the last element of the SVG so it is drawn
+    /// above all chart content. Has no effect if the content has no `</svg>` tag.
+    #[must_use]
+    pub fn watermark(mut self, watermark: &Watermark) -> Self {
+        if let (Some(element), Some(pos)) =
+            (watermark.to_svg_element(self.width, self.height), self.svg_content.rfind("</svg>"))
+        {
+            self.svg_content.insert_str(pos, &format!("  {}\n", element_to_svg(&element)));
+        }
+        self
+    }
+
+    /// Add a data cursor: hovering a plot panel snaps a crosshair to the
+    /// nearest data point and shows its series name and x/y values.
+    #[must_use]
+    pub fn crosshair(mut self, crosshair: Crosshair) -> Self {
+        self.crosshair = Some(crosshair);
+        self
+    }
+
+    /// Add linked brushing: dragging over points in one panel highlights the
+    /// same data rows in every panel that shares their data source.
+    #[must_use]
+    pub fn linked_brush(mut self, brush: LinkedBrush) -> Self {
+        self.brush = Some(brush);
+        self
+    }
+
+    /// Make the flame graph in this export zoomable.
+    #[must_use]
+    pub(crate) fn flame_zoom(mut self, zoom: FlameZoom) -> Self {
+        self.flame_zoom = Some(zoom);
+        self
+    }
+
     /// Generate the HTML string.
     #[must_use]
     pub fn to_html(&self) -> String {
@@ -163,7 +212,7 @@ impl HtmlExporter {
     <div class="chart-container">
         <h1 class="chart-title">{title}</h1>
         {svg}
-    </div>
+    </div>{scripts}
     <!-- Generated by trueno-viz -->
     <!-- https://github.com/paiml/trueno-viz -->
 </body>
@@ -174,9 +223,22 @@ impl HtmlExporter {
             svg = self.svg_content,
             dark_mode_css = dark_mode_css,
             responsive_css = responsive_css,
+            scripts = self.scripts(),
         )
     }
 
+    /// Data blocks and runtimes of the enabled interactions.
+    fn scripts(&self) -> String {
+        let cursor = self.crosshair.as_ref().map(Crosshair::to_script);
+        let brush = self.brush.as_ref().map(LinkedBrush::to_script);
+        let flame = self.flame_zoom.as_ref().map(FlameZoom::to_script);
+        cursor.into_iter().chain(brush).chain(flame).fold(String::new(), |mut out, script| {
+            out.push_str("\n    ");
+            out.push_str(&script);
+            out
+        })
+    }
+
     /// Write the HTML to a file.
     ///
     /// # Errors
@@ -200,6 +262,7 @@ impl std::fmt::Display for HtmlExporter {
 mod tests {
     use super::*;
     use crate::color::Rgba;
+    use crate::output::{CursorPanel, CursorSeries};
 
     #[test]
     fn test_html_exporter_basic() {
@@ -294,6 +357,54 @@ mod tests {
         assert_eq!(exporter.to_html(), cloned.to_html());
     }
 
+    #[test]
+    fn test_html_exporter_watermark() {
+        let svg = SvgEncoder::new(400, 300);
+        let html = HtmlExporter::from_svg(&svg).watermark(&Watermark::text("ACME Labs")).to_html();
+
+        let mark = html.find("ACME Labs").expect("watermark text should be present");
+        let close = html.find("</svg>").expect("svg should be closed");
+        assert!(mark < close);
+    }
+
+    #[test]
+    fn test_html_exporter_watermark_without_svg() {
+        let exporter = HtmlExporter::from_svg_string("<p>no chart</p>".to_string(), 10, 10)
+            .watermark(&Watermark::text("ACME Labs"));
+        assert!(!exporter.to_html().contains("ACME Labs"));
+    }
+
+    #[test]
+    fn test_html_exporter_crosshair() {
+        let svg = SvgEncoder::new(400, 300);
+        assert!(!HtmlExporter::from_svg(&svg).to_html().contains("<script"));
+
+        let panel = CursorPanel::new(40.0, 40.0, 320.0, 220.0)
+            .series(CursorSeries::new("a</script>", Rgba::BLUE).point(1.0, 2.0, 50.0, 60.0));
+        let html = HtmlExporter::from_svg(&svg).crosshair(Crosshair::new().panel(panel)).to_html();
+
+        let data = html.find("id=\"tv-cursor-data\"").expect("cursor data should be embedded");
+        assert!(data > html.find("</svg>").expect("svg should be present"));
+        assert!(html.contains("a\\u003c/script\\u003e"));
+        assert_eq!(html.matches("</script>").count(), 2);
+    }
+
+    #[test]
+    fn test_html_exporter_crosshair_and_brush() {
+        let panel = CursorPanel::new(0.0, 0.0, 100.0, 100.0)
+            .series(CursorSeries::new("a", Rgba::BLUE).point(1.0, 2.0, 5.0, 6.0))
+            .source("runs");
+        let html = HtmlExporter::from_svg(&SvgEncoder::new(100, 100))
+            .crosshair(Crosshair::new().panel(panel.clone()))
+            .linked_brush(LinkedBrush::new().panel(panel))
+            .to_html();
+
+        let cursor = html.find("id=\"tv-cursor-data\"").expect("cursor data should be embedded");
+        let brush = html.find("id=\"tv-brush-data\"").expect("brush data should be embedded");
+        assert!(cursor < brush);
+        assert_eq!(html.matches("</script>").count(), 4);
+    }
+
     #[test]
     fn test_html_exporter_all_options() {
         let svg = SvgEncoder::new(800, 600).rect(0.0, 0.0, 800.0, 600.0, Rgba::WHITE);
@@ -1,11 +1,19 @@
 //! Output encoders (PNG, SVG, HTML, terminal).
 
+mod brush;
+mod cursor;
+mod flame_zoom;
 mod html;
 mod png_encoder;
 mod svg;
 mod terminal;
+mod watermark;
 
+pub use brush::LinkedBrush;
+pub use cursor::{Crosshair, CursorMode, CursorPanel, CursorSeries};
+pub(crate) use flame_zoom::{FlameZoom, ZoomFrame};
 pub use html::HtmlExporter;
 pub use png_encoder::PngEncoder;
 pub use svg::{SvgElement, SvgEncoder, TextAnchor};
 pub use terminal::{TerminalEncoder, TerminalMode};
+pub use watermark::{Watermark, WatermarkContent, WatermarkPosition};
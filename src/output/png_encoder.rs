@@ -8,6 +8,8 @@ use std::fs::File;
 use std::io::BufWriter;
 use std::path::Path;
 
+use super::Watermark;
+
 /// PNG encoder for framebuffer output.
 pub struct PngEncoder;
 
@@ -52,6 +54,32 @@ impl PngEncoder {
 
         Ok(buffer)
     }
+
+    /// Write a framebuffer to a PNG file with a watermark stamped on a copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if file creation or PNG encoding fails.
+    pub fn write_to_file_with_watermark<P: AsRef<Path>>(
+        fb: &Framebuffer,
+        path: P,
+        watermark: &Watermark,
+    ) -> Result<()> {
+        let mut marked = fb.clone();
+        watermark.apply(&mut marked);
+        Self::write_to_file(&marked, path)
+    }
+
+    /// Encode a framebuffer to PNG bytes with a watermark stamped on a copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if PNG encoding fails.
+    pub fn to_bytes_with_watermark(fb: &Framebuffer, watermark: &Watermark) -> Result<Vec<u8>> {
+        let mut marked = fb.clone();
+        watermark.apply(&mut marked);
+        Self::to_bytes(&marked)
+    }
 }
 
 #[cfg(test)]
@@ -111,4 +139,19 @@ mod tests {
             assert_eq!(&bytes[0..8], &[137, 80, 78, 71, 13, 10, 26, 10]);
         }
     }
+
+    #[test]
+    fn test_png_to_bytes_with_watermark() {
+        let mut fb = Framebuffer::new(60, 30).expect("framebuffer creation should succeed");
+        fb.clear(Rgba::WHITE);
+
+        let mark = Watermark::text("ACME");
+        let marked =
+            PngEncoder::to_bytes_with_watermark(&fb, &mark).expect("encoding should succeed");
+        let plain = PngEncoder::to_bytes(&fb).expect("encoding should succeed");
+
+        assert_ne!(marked, plain);
+        // Source framebuffer is left untouched
+        assert_eq!(fb.get_pixel(50, 20), Some(Rgba::WHITE));
+    }
 }
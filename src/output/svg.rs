@@ -6,12 +6,15 @@
 use crate::color::Rgba;
 use crate::error::Result;
 use crate::framebuffer::Framebuffer;
+use crate::render::{text_direction, TextDirection};
 use base64::{engine::general_purpose::STANDARD, Engine};
 use std::fmt::Write as FmtWrite;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
+use super::Watermark;
+
 /// SVG encoder for framebuffer and vector output.
 #[derive(Debug, Clone)]
 pub struct SvgEncoder {
@@ -23,6 +26,8 @@ pub struct SvgEncoder {
     background: Option<Rgba>,
     /// SVG elements
     elements: Vec<SvgElement>,
+    /// Watermark stamped on top of all elements at render time
+    watermark: Option<Watermark>,
 }
 
 /// An SVG element.
@@ -53,6 +58,8 @@ pub enum SvgElement {
     Text { x: f32, y: f32, text: String, font_size: f32, fill: Rgba, anchor: TextAnchor },
     /// Embedded raster image (base64 PNG)
     Image { x: f32, y: f32, width: f32, height: f32, data: String },
+    /// Group of elements with an optional hover tooltip (`<title>`)
+    Group { title: Option<String>, children: Vec<SvgElement> },
 }
 
 /// Text anchor position for SVG text alignment.
@@ -78,7 +85,7 @@ impl SvgEncoder {
     /// Create a new SVG encoder with given dimensions.
     #[must_use]
     pub fn new(width: u32, height: u32) -> Self {
-        Self { width, height, background: Some(Rgba::WHITE), elements: Vec::new() }
+        Self { width, height, background: Some(Rgba::WHITE), elements: Vec::new(), watermark: None }
     }
 
     /// Create from a framebuffer (embeds as raster image).
@@ -113,6 +120,13 @@ impl SvgEncoder {
         self
     }
 
+    /// Set a watermark drawn above all other elements.
+    #[must_use]
+    pub fn watermark(mut self, watermark: Watermark) -> Self {
+        self.watermark = Some(watermark);
+        self
+    }
+
     /// Add a rectangle.
     #[must_use]
     pub fn rect(mut self, x: f32, y: f32, width: f32, height: f32, fill: Rgba) -> Self {
@@ -277,6 +291,13 @@ impl SvgEncoder {
         self
     }
 
+    /// Add a group of elements with an optional hover tooltip.
+    #[must_use]
+    pub fn group(mut self, title: Option<&str>, children: Vec<SvgElement>) -> Self {
+        self.elements.push(SvgElement::Group { title: title.map(str::to_string), children });
+        self
+    }
+
     /// Add a raw element.
     pub fn add_element(&mut self, element: SvgElement) {
         self.elements.push(element);
@@ -317,6 +338,13 @@ impl SvgEncoder {
             let _ = writeln!(svg, "  {}", element_to_svg(element));
         }
 
+        // Watermark (always on top)
+        if let Some(element) =
+            self.watermark.as_ref().and_then(|w| w.to_svg_element(self.width, self.height))
+        {
+            let _ = writeln!(svg, "  {}", element_to_svg(&element));
+        }
+
         // Close SVG
         svg.push_str("</svg>\n");
         svg
@@ -335,7 +363,7 @@ impl SvgEncoder {
 }
 
 /// Convert RGBA to CSS color string.
-fn rgba_to_css(color: Rgba) -> String {
+pub(super) fn rgba_to_css(color: Rgba) -> String {
     if color.a == 255 {
         format!("rgb({},{},{})", color.r, color.g, color.b)
     } else {
@@ -344,7 +372,7 @@ fn rgba_to_css(color: Rgba) -> String {
 }
 
 /// Convert an SVG element to its string representation.
-fn element_to_svg(element: &SvgElement) -> String {
+pub(super) fn element_to_svg(element: &SvgElement) -> String {
     match element {
         SvgElement::Rect { x, y, width, height, fill, stroke, stroke_width } => {
             let stroke_attr = stroke
@@ -393,14 +421,14 @@ fn element_to_svg(element: &SvgElement) -> String {
                 TextAnchor::Middle => "middle",
                 TextAnchor::End => "end",
             };
-            // Escape XML special characters
-            let escaped_text = text
-                .replace('&', "&amp;")
-                .replace('<', "&lt;")
-                .replace('>', "&gt;")
-                .replace('"', "&quot;");
+            let escaped_text = escape_xml(text);
+            // Text stays in logical order; the viewer applies the bidi algorithm
+            let direction = match text_direction(text) {
+                TextDirection::Ltr => "",
+                TextDirection::Rtl => r#" direction="rtl""#,
+            };
             format!(
-                r#"<text x="{x}" y="{y}" font-size="{font_size}" fill="{}" text-anchor="{anchor_str}" font-family="sans-serif">{escaped_text}</text>"#,
+                r#"<text x="{x}" y="{y}" font-size="{font_size}" fill="{}" text-anchor="{anchor_str}"{direction} font-family="sans-serif">{escaped_text}</text>"#,
                 rgba_to_css(*fill)
             )
         }
@@ -409,9 +437,25 @@ fn element_to_svg(element: &SvgElement) -> String {
                 r#"<image x="{x}" y="{y}" width="{width}" height="{height}" xlink:href="{data}"/>"#
             )
         }
+        SvgElement::Group { title, children } => {
+            let mut out = String::from("<g>");
+            if let Some(title) = title {
+                let _ = write!(out, "<title>{}</title>", escape_xml(title));
+            }
+            for child in children {
+                out.push_str(&element_to_svg(child));
+            }
+            out.push_str("</g>");
+            out
+        }
     }
 }
 
+/// Escape XML special characters.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
 impl std::fmt::Display for SvgEncoder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.render())
@@ -717,4 +761,43 @@ mod tests {
         assert!(display_str.contains("width=\"400\""));
         assert!(display_str.contains("height=\"300\""));
     }
+
+    #[test]
+    fn test_svg_watermark_rendered_last() {
+        let svg = SvgEncoder::new(200, 100)
+            .watermark(Watermark::text("ACME"))
+            .rect(0.0, 0.0, 10.0, 10.0, Rgba::RED)
+            .render();
+
+        let rect = svg.find("<rect x=").expect("rect should be present");
+        let mark = svg.find(">ACME</text>").expect("watermark should be present");
+        assert!(mark > rect);
+    }
+
+    #[test]
+    fn test_svg_group_title() {
+        let child = SvgElement::Circle {
+            cx: 5.0,
+            cy: 5.0,
+            r: 2.0,
+            fill: Rgba::BLUE,
+            stroke: None,
+            stroke_width: 0.0,
+        };
+        let svg = SvgEncoder::new(10, 10).group(Some("a<b"), vec![child]).render();
+
+        assert!(svg.contains("<g><title>a&lt;b</title><circle"));
+        assert!(svg.contains("</g>"));
+    }
+
+    #[test]
+    fn test_svg_rtl_text_direction() {
+        let svg = SvgEncoder::new(100, 20)
+            .text(90.0, 10.0, "\u{5E9}\u{5DC}\u{5D5}\u{5DD} 42", 12.0, Rgba::BLACK)
+            .text(10.0, 10.0, "hello", 12.0, Rgba::BLACK)
+            .render();
+
+        assert_eq!(svg.matches(r#"direction="rtl""#).count(), 1);
+        assert!(svg.contains("\u{5E9}\u{5DC}\u{5D5}\u{5DD} 42</text>"));
+    }
 }
@@ -0,0 +1,334 @@
+//! Watermark and attribution layer for exported figures.
+//!
+//! A [`Watermark`] stamps text or a small image onto a figure at export time.
+//! The same watermark can be applied to PNG (raster), SVG, and HTML outputs so
+//! every artifact generated from one figure carries identical attribution.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use trueno_viz::output::{PngEncoder, Watermark, WatermarkPosition};
+//!
+//! let mark = Watermark::text("(c) ACME Research")
+//!     .position(WatermarkPosition::BottomRight)
+//!     .opacity(0.5);
+//!
+//! PngEncoder::write_to_file_with_watermark(&fb, "figure.png", &mark)?;
+//! ```
+
+use crate::color::Rgba;
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_text, i32_px, text_height, text_width};
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use super::{PngEncoder, SvgElement, TextAnchor};
+
+/// Corner (or center) of the figure where a watermark is anchored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WatermarkPosition {
+    /// Top-left corner.
+    TopLeft,
+    /// Top-right corner.
+    TopRight,
+    /// Bottom-left corner.
+    BottomLeft,
+    /// Bottom-right corner.
+    #[default]
+    BottomRight,
+    /// Centered on the figure.
+    Center,
+}
+
+/// Watermark payload.
+#[derive(Debug, Clone)]
+pub enum WatermarkContent {
+    /// Attribution text.
+    Text(String),
+    /// Raster image (e.g. a logo).
+    Image(Framebuffer),
+}
+
+/// Watermark configuration applied at export time.
+#[derive(Debug, Clone)]
+pub struct Watermark {
+    /// Text or image payload.
+    content: WatermarkContent,
+    /// Anchor position.
+    position: WatermarkPosition,
+    /// Opacity multiplier (0.0 - 1.0).
+    opacity: f32,
+    /// Distance from the figure edge in pixels.
+    margin: u32,
+    /// Text color.
+    color: Rgba,
+    /// Text font size in pixels.
+    font_size: f32,
+}
+
+impl Watermark {
+    /// Create a text watermark.
+    #[must_use]
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::with_content(WatermarkContent::Text(text.into()))
+    }
+
+    /// Create an image watermark from a framebuffer.
+    #[must_use]
+    pub fn image(image: Framebuffer) -> Self {
+        Self::with_content(WatermarkContent::Image(image))
+    }
+
+    fn with_content(content: WatermarkContent) -> Self {
+        Self {
+            content,
+            position: WatermarkPosition::default(),
+            opacity: 0.6,
+            margin: 8,
+            color: Rgba::rgb(80, 80, 80),
+            font_size: 14.0,
+        }
+    }
+
+    /// Set the anchor position.
+    #[must_use]
+    pub fn position(mut self, position: WatermarkPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Set the opacity (0.0 - 1.0).
+    #[must_use]
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the distance from the figure edge in pixels.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Set the text color.
+    #[must_use]
+    pub fn color(mut self, color: Rgba) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the text font size in pixels.
+    #[must_use]
+    pub fn font_size(mut self, size: f32) -> Self {
+        self.font_size = size.max(1.0);
+        self
+    }
+
+    /// Get the watermark content.
+    #[must_use]
+    pub fn content(&self) -> &WatermarkContent {
+        &self.content
+    }
+
+    /// Integer glyph scale used for raster text.
+    fn raster_scale(&self) -> u32 {
+        ((self.font_size / text_height(1) as f32).round() as u32).max(1)
+    }
+
+    /// Top-left origin for a box of `w`×`h` inside a `canvas_w`×`canvas_h` figure.
+    fn origin(&self, canvas_w: u32, canvas_h: u32, w: u32, h: u32) -> (i32, i32) {
+        let m = i32_px(self.margin);
+        let (cw, ch, w, h) = (i32_px(canvas_w), i32_px(canvas_h), i32_px(w), i32_px(h));
+        match self.position {
+            WatermarkPosition::TopLeft => (m, m),
+            WatermarkPosition::TopRight => (cw - w - m, m),
+            WatermarkPosition::BottomLeft => (m, ch - h - m),
+            WatermarkPosition::BottomRight => (cw - w - m, ch - h - m),
+            WatermarkPosition::Center => ((cw - w) / 2, (ch - h) / 2),
+        }
+    }
+
+    /// Stamp the watermark onto a framebuffer (used for PNG export).
+    pub fn apply(&self, fb: &mut Framebuffer) {
+        match &self.content {
+            WatermarkContent::Text(text) => {
+                let scale = self.raster_scale();
+                let (x, y) = self.origin(
+                    fb.width(),
+                    fb.height(),
+                    text_width(text, scale),
+                    text_height(scale),
+                );
+                let alpha = (f32::from(self.color.a) * self.opacity) as u8;
+                draw_text(fb, x, y, text, scale, self.color.with_alpha(alpha));
+            }
+            WatermarkContent::Image(image) => {
+                let (x0, y0) = self.origin(fb.width(), fb.height(), image.width(), image.height());
+                for iy in 0..image.height() {
+                    for ix in 0..image.width() {
+                        let (x, y) = (x0 + i32_px(ix), y0 + i32_px(iy));
+                        if x < 0 || y < 0 {
+                            continue;
+                        }
+                        if let Some(px) = image.get_pixel(ix, iy) {
+                            let alpha = (f32::from(px.a) * self.opacity) as u8;
+                            fb.blend_pixel(x as u32, y as u32, px.with_alpha(alpha));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Build the SVG element for this watermark on a `width`×`height` canvas.
+    ///
+    /// Returns `None` if an image watermark cannot be encoded.
+    #[must_use]
+    pub fn to_svg_element(&self, width: u32, height: u32) -> Option<SvgElement> {
+        match &self.content {
+            WatermarkContent::Text(text) => {
+                let m = self.margin as f32;
+                let (w, h) = (width as f32, height as f32);
+                let (x, anchor) = match self.position {
+                    WatermarkPosition::TopLeft | WatermarkPosition::BottomLeft => {
+                        (m, TextAnchor::Start)
+                    }
+                    WatermarkPosition::TopRight | WatermarkPosition::BottomRight => {
+                        (w - m, TextAnchor::End)
+                    }
+                    WatermarkPosition::Center => (w / 2.0, TextAnchor::Middle),
+                };
+                let y = match self.position {
+                    WatermarkPosition::TopLeft | WatermarkPosition::TopRight => m + self.font_size,
+                    WatermarkPosition::BottomLeft | WatermarkPosition::BottomRight => h - m,
+                    WatermarkPosition::Center => (h + self.font_size) / 2.0,
+                };
+                let alpha = (f32::from(self.color.a) * self.opacity) as u8;
+                Some(SvgElement::Text {
+                    x,
+                    y,
+                    text: text.clone(),
+                    font_size: self.font_size,
+                    fill: self.color.with_alpha(alpha),
+                    anchor,
+                })
+            }
+            WatermarkContent::Image(image) => {
+                // Bake opacity into the image alpha so no extra SVG attributes are needed
+                let mut faded = image.clone();
+                for iy in 0..faded.height() {
+                    for ix in 0..faded.width() {
+                        if let Some(px) = image.get_pixel(ix, iy) {
+                            let alpha = (f32::from(px.a) * self.opacity) as u8;
+                            faded.set_pixel(ix, iy, px.with_alpha(alpha));
+                        }
+                    }
+                }
+                let png = PngEncoder::to_bytes(&faded).ok()?;
+                let (x, y) = self.origin(width, height, image.width(), image.height());
+                Some(SvgElement::Image {
+                    x: x as f32,
+                    y: y as f32,
+                    width: image.width() as f32,
+                    height: image.height() as f32,
+                    data: format!("data:image/png;base64,{}", STANDARD.encode(&png)),
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn white_fb(w: u32, h: u32) -> Framebuffer {
+        let mut fb = Framebuffer::new(w, h).expect("framebuffer creation should succeed");
+        fb.clear(Rgba::WHITE);
+        fb
+    }
+
+    fn has_non_white(fb: &Framebuffer, x0: u32, y0: u32, x1: u32, y1: u32) -> bool {
+        (y0..y1).any(|y| (x0..x1).any(|x| fb.get_pixel(x, y) != Some(Rgba::WHITE)))
+    }
+
+    #[test]
+    fn test_watermark_defaults() {
+        let mark = Watermark::text("ACME");
+        assert_eq!(mark.position, WatermarkPosition::BottomRight);
+        assert!((mark.opacity - 0.6).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_watermark_opacity_clamped() {
+        assert!((Watermark::text("x").opacity(2.0).opacity - 1.0).abs() < f32::EPSILON);
+        assert!(Watermark::text("x").opacity(-1.0).opacity.abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_text_watermark_bottom_right() {
+        let mut fb = white_fb(100, 60);
+        Watermark::text("ACME").opacity(1.0).color(Rgba::BLACK).apply(&mut fb);
+
+        assert!(has_non_white(&fb, 50, 30, 100, 60));
+        assert!(!has_non_white(&fb, 0, 0, 50, 30));
+    }
+
+    #[test]
+    fn test_text_watermark_top_left() {
+        let mut fb = white_fb(100, 60);
+        Watermark::text("ACME")
+            .position(WatermarkPosition::TopLeft)
+            .opacity(1.0)
+            .color(Rgba::BLACK)
+            .apply(&mut fb);
+
+        assert!(has_non_white(&fb, 0, 0, 50, 30));
+        assert!(!has_non_white(&fb, 50, 30, 100, 60));
+    }
+
+    #[test]
+    fn test_zero_opacity_is_noop() {
+        let mut fb = white_fb(50, 50);
+        Watermark::text("ACME").opacity(0.0).apply(&mut fb);
+        assert!(!has_non_white(&fb, 0, 0, 50, 50));
+    }
+
+    #[test]
+    fn test_image_watermark_centered() {
+        let mut logo = Framebuffer::new(4, 4).expect("framebuffer creation should succeed");
+        logo.clear(Rgba::RED);
+
+        let mut fb = white_fb(20, 20);
+        Watermark::image(logo).position(WatermarkPosition::Center).opacity(1.0).apply(&mut fb);
+
+        assert_eq!(fb.get_pixel(10, 10), Some(Rgba::RED));
+        assert_eq!(fb.get_pixel(0, 0), Some(Rgba::WHITE));
+    }
+
+    #[test]
+    fn test_text_svg_element() {
+        let el = Watermark::text("ACME")
+            .position(WatermarkPosition::TopRight)
+            .to_svg_element(200, 100)
+            .expect("text watermark should produce an element");
+
+        match el {
+            SvgElement::Text { x, anchor, text, .. } => {
+                assert!((x - 192.0).abs() < f32::EPSILON);
+                assert!(matches!(anchor, TextAnchor::End));
+                assert_eq!(text, "ACME");
+            }
+            other => panic!("unexpected element {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_image_svg_element() {
+        let logo = Framebuffer::new(4, 4).expect("framebuffer creation should succeed");
+        let el = Watermark::image(logo)
+            .to_svg_element(100, 100)
+            .expect("image watermark should produce an element");
+        assert!(matches!(el, SvgElement::Image { .. }));
+    }
+}
@@ -125,6 +125,7 @@ impl BoxPlot {
     /// Create a new box plot builder.
     #[must_use]
     pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(600, 400);
         Self {
             groups: Vec::new(),
             labels: Vec::new(),
@@ -132,8 +133,8 @@ impl BoxPlot {
             outline_color: Rgba::BLACK,
             median_color: Rgba::new(255, 140, 0, 255), // Dark orange
             outlier_color: Rgba::new(200, 50, 50, 255),
-            width: 600,
-            height: 400,
+            width,
+            height,
             margin: 50,
             box_width: 0.6,
             show_outliers: true,
@@ -493,6 +494,7 @@ impl ViolinPlot {
     /// Create a new violin plot builder.
     #[must_use]
     pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(600, 400);
         Self {
             groups: Vec::new(),
             labels: Vec::new(),
@@ -500,8 +502,8 @@ impl ViolinPlot {
             outline_color: Rgba::BLACK,
             show_box: true,
             bandwidth: None,
-            width: 600,
-            height: 400,
+            width,
+            height,
             margin: 50,
             violin_width: 0.8,
         }
@@ -583,7 +585,11 @@ impl ViolinPlot {
 }
 
 /// Compute kernel density estimation using Gaussian kernel.
-fn compute_kde(data: &[f32], bandwidth: Option<f32>, n_points: usize) -> Vec<(f32, f32)> {
+pub(crate) fn compute_kde(
+    data: &[f32],
+    bandwidth: Option<f32>,
+    n_points: usize,
+) -> Vec<(f32, f32)> {
     if data.is_empty() {
         return Vec::new();
     }
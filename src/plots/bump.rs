@@ -0,0 +1,444 @@
+//! Bump chart (slope graph) for ranking changes over time.
+//!
+//! Converts per-step scores into ranks and draws each category's trajectory,
+//! with labels at both endpoints. Useful for leaderboard-style experiment
+//! tracking where the ordering matters more than the raw values.
+//!
+//! # References
+//!
+//! - Tufte, E. R. (1983). *The Visual Display of Quantitative Information*.
+//!   Graphics Press. (Slope graphs)
+
+use crate::color::{categorical, Rgba};
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_circle, draw_line_aa, i32_px, TextStyle};
+
+/// A category tracked across time steps.
+#[derive(Debug, Clone)]
+pub struct BumpSeries {
+    /// Category name.
+    pub name: String,
+    /// Score per time step (NaN = absent at that step).
+    pub values: Vec<f32>,
+    /// Line color (None = categorical palette).
+    pub color: Option<Rgba>,
+}
+
+impl BumpSeries {
+    /// Create a new series.
+    #[must_use]
+    pub fn new(name: impl Into<String>, values: &[f32]) -> Self {
+        Self { name: name.into(), values: values.to_vec(), color: None }
+    }
+
+    /// Set the line color.
+    #[must_use]
+    pub fn color(mut self, color: Rgba) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// Builder for creating bump charts.
+#[derive(Debug, Clone)]
+pub struct BumpChart {
+    /// Categories.
+    series: Vec<BumpSeries>,
+    /// Rank ascending values first (e.g. loss) instead of descending.
+    lower_is_better: bool,
+    /// Highlighted category names (others are dimmed).
+    highlight: Vec<String>,
+    /// Color for dimmed categories.
+    dim_color: Rgba,
+    /// Line thickness in pixels.
+    thickness: u32,
+    /// Marker radius in pixels.
+    marker_radius: i32,
+    /// Show endpoint labels.
+    show_labels: bool,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the chart.
+    margin: u32,
+}
+
+impl Default for BumpChart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BumpChart {
+    /// Create a new bump chart builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 600);
+        Self {
+            series: Vec::new(),
+            lower_is_better: false,
+            highlight: Vec::new(),
+            dim_color: Rgba::rgb(210, 210, 210),
+            thickness: 2,
+            marker_radius: 4,
+            show_labels: true,
+            width,
+            height,
+            margin: 20,
+        }
+    }
+
+    /// Add a category.
+    #[must_use]
+    pub fn add_series(mut self, series: BumpSeries) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Add a category from a name and scores (convenience method).
+    #[must_use]
+    pub fn series(self, name: impl Into<String>, values: &[f32]) -> Self {
+        self.add_series(BumpSeries::new(name, values))
+    }
+
+    /// Set whether lower scores rank higher (default: higher is better).
+    #[must_use]
+    pub fn lower_is_better(mut self, lower: bool) -> Self {
+        self.lower_is_better = lower;
+        self
+    }
+
+    /// Highlight a category; all non-highlighted categories are dimmed.
+    #[must_use]
+    pub fn highlight(mut self, name: impl Into<String>) -> Self {
+        self.highlight.push(name.into());
+        self
+    }
+
+    /// Set the color used for dimmed categories.
+    #[must_use]
+    pub fn dim_color(mut self, color: Rgba) -> Self {
+        self.dim_color = color;
+        self
+    }
+
+    /// Set the line thickness.
+    #[must_use]
+    pub fn thickness(mut self, thickness: u32) -> Self {
+        self.thickness = thickness.max(1);
+        self
+    }
+
+    /// Set the marker radius (0 disables markers).
+    #[must_use]
+    pub fn marker_radius(mut self, radius: i32) -> Self {
+        self.marker_radius = radius.max(0);
+        self
+    }
+
+    /// Enable or disable endpoint labels.
+    #[must_use]
+    pub fn labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// Set the margin around the chart.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the bump chart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no categories, no time steps, or the
+    /// categories have different numbers of time steps.
+    pub fn build(self) -> Result<Self> {
+        let first = self.series.first().ok_or(Error::EmptyData)?;
+        let steps = first.values.len();
+        if steps == 0 {
+            return Err(Error::EmptyData);
+        }
+
+        for series in &self.series {
+            if series.values.len() != steps {
+                return Err(Error::DataLengthMismatch { x_len: steps, y_len: series.values.len() });
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Get the number of categories.
+    #[must_use]
+    pub fn series_count(&self) -> usize {
+        self.series.len()
+    }
+
+    /// Get the number of time steps.
+    #[must_use]
+    pub fn step_count(&self) -> usize {
+        self.series.iter().map(|s| s.values.len()).max().unwrap_or(0)
+    }
+
+    /// Compute 1-based ranks for every category at every step.
+    ///
+    /// Returns `ranks[series][step]`; `None` where the score is not finite.
+    /// Ties keep the order in which categories were added.
+    #[must_use]
+    pub fn ranks(&self) -> Vec<Vec<Option<usize>>> {
+        let steps = self.step_count();
+        let mut ranks = vec![vec![None; steps]; self.series.len()];
+        let orders: Vec<Vec<usize>> = (0..steps).map(|step| self.step_order(step)).collect();
+
+        for (step, order) in orders.iter().enumerate() {
+            for (rank, &i) in order.iter().enumerate() {
+                ranks[i][step] = Some(rank + 1);
+            }
+        }
+
+        ranks
+    }
+
+    /// Indices of the series with a finite score at `step`, best first.
+    fn step_order(&self, step: usize) -> Vec<usize> {
+        let mut present: Vec<(usize, f32)> = self
+            .series
+            .iter()
+            .enumerate()
+            .filter_map(|(i, s)| s.values.get(step).filter(|v| v.is_finite()).map(|&v| (i, v)))
+            .collect();
+
+        present.sort_by(|a, b| {
+            let ord = a.1.total_cmp(&b.1);
+            if self.lower_is_better {
+                ord
+            } else {
+                ord.reverse()
+            }
+        });
+
+        present.into_iter().map(|(i, _)| i).collect()
+    }
+
+    /// Whether a category is drawn in full color.
+    fn is_emphasized(&self, name: &str) -> bool {
+        self.highlight.is_empty() || self.highlight.iter().any(|h| h == name)
+    }
+
+    /// Color for a category.
+    fn series_color(&self, index: usize) -> Rgba {
+        let series = &self.series[index];
+        if self.is_emphasized(&series.name) {
+            series.color.unwrap_or_else(|| categorical(index))
+        } else {
+            self.dim_color
+        }
+    }
+
+    /// Width reserved for endpoint labels on each side.
+    fn label_gutter(&self) -> u32 {
+        if !self.show_labels {
+            return 0;
+        }
+        let font = TextStyle::current();
+        self.series.iter().map(|s| font.width(&s.name)).max().map_or(0, |w| w + 8)
+    }
+
+    /// Render the bump chart to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let font = TextStyle::current();
+        let steps = self.step_count();
+        let n = self.series.len();
+        if steps == 0 || n == 0 {
+            return Ok(());
+        }
+
+        let gutter = self.label_gutter();
+        let left = (self.margin + gutter) as f32;
+        let right = self.width.saturating_sub(self.margin + gutter) as f32;
+        let top = self.margin as f32;
+        let bottom = self.height.saturating_sub(self.margin) as f32;
+
+        let x_at = |step: usize| {
+            if steps == 1 {
+                (left + right) / 2.0
+            } else {
+                left + (right - left) * step as f32 / (steps - 1) as f32
+            }
+        };
+        let y_at = |rank: usize| {
+            if n == 1 {
+                (top + bottom) / 2.0
+            } else {
+                top + (bottom - top) * (rank - 1) as f32 / (n - 1) as f32
+            }
+        };
+
+        let ranks = self.ranks();
+
+        // Dimmed categories first so highlighted ones sit on top
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by_key(|&i| self.is_emphasized(&self.series[i].name));
+
+        for i in order {
+            let color = self.series_color(i);
+            let points: Vec<(usize, f32, f32)> = ranks[i]
+                .iter()
+                .enumerate()
+                .filter_map(|(step, r)| r.map(|r| (step, x_at(step), y_at(r))))
+                .collect();
+
+            // Connect consecutive steps only (gaps break the line)
+            for pair in points.windows(2) {
+                if pair[1].0 == pair[0].0 + 1 {
+                    self.draw_thick_line(fb, pair[0].1, pair[0].2, pair[1].1, pair[1].2, color);
+                }
+            }
+
+            for &(_, x, y) in &points {
+                draw_circle(fb, x as i32, y as i32, self.marker_radius, color);
+            }
+
+            if self.show_labels {
+                let name = &self.series[i].name;
+                let half_h = i32_px(font.height()) / 2;
+                if let Some(&(_, x, y)) = points.first() {
+                    let lx = x as i32 - i32_px(font.width(name)) - 8;
+                    font.with_color(color).draw(fb, lx, y as i32 - half_h, name);
+                }
+                if let Some(&(_, x, y)) = points.last() {
+                    font.with_color(color).draw(fb, x as i32 + 8, y as i32 - half_h, name);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draw an anti-aliased line thickened by vertical offsets.
+    fn draw_thick_line(&self, fb: &mut Framebuffer, x0: f32, y0: f32, x1: f32, y1: f32, c: Rgba) {
+        let half = self.thickness as f32 / 2.0;
+        for k in 0..self.thickness {
+            let dy = k as f32 - half + 0.5;
+            draw_line_aa(fb, x0, y0 + dy, x1, y1 + dy, c);
+        }
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+impl batuta_common::display::WithDimensions for BumpChart {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn sample() -> BumpChart {
+        BumpChart::new()
+            .series("a", &[0.9, 0.7, 0.5])
+            .series("b", &[0.8, 0.9, 0.6])
+            .series("c", &[0.1, 0.2, 0.95])
+    }
+
+    #[test]
+    fn test_bump_builder() {
+        let chart = sample().build().expect("builder should produce valid result");
+        assert_eq!(chart.series_count(), 3);
+        assert_eq!(chart.step_count(), 3);
+    }
+
+    #[test]
+    fn test_bump_validation() {
+        assert!(BumpChart::new().build().is_err());
+        assert!(BumpChart::new().series("a", &[]).build().is_err());
+        assert!(BumpChart::new().series("a", &[1.0, 2.0]).series("b", &[1.0]).build().is_err());
+    }
+
+    #[test]
+    fn test_bump_ranks_higher_is_better() {
+        let ranks = sample().ranks();
+        assert_eq!(ranks[0], vec![Some(1), Some(2), Some(3)]);
+        assert_eq!(ranks[1], vec![Some(2), Some(1), Some(2)]);
+        assert_eq!(ranks[2], vec![Some(3), Some(3), Some(1)]);
+    }
+
+    #[test]
+    fn test_bump_ranks_lower_is_better() {
+        let ranks = sample().lower_is_better(true).ranks();
+        assert_eq!(ranks[2], vec![Some(1), Some(1), Some(3)]);
+    }
+
+    #[test]
+    fn test_bump_ranks_missing_and_ties() {
+        let ranks = BumpChart::new().series("a", &[1.0, f32::NAN]).series("b", &[1.0, 2.0]).ranks();
+        assert_eq!(ranks[0], vec![Some(1), None]);
+        assert_eq!(ranks[1], vec![Some(2), Some(1)]);
+    }
+
+    #[test]
+    fn test_bump_highlight_dims_others() {
+        let chart = sample().highlight("b");
+        assert_eq!(chart.series_color(0), chart.dim_color);
+        assert_eq!(chart.series_color(1), categorical(1));
+    }
+
+    #[test]
+    fn test_bump_custom_color() {
+        let chart = BumpChart::new().add_series(BumpSeries::new("a", &[1.0]).color(Rgba::RED));
+        assert_eq!(chart.series_color(0), Rgba::RED);
+    }
+
+    #[test]
+    fn test_bump_render() {
+        let chart = sample()
+            .highlight("c")
+            .thickness(3)
+            .dimensions(200, 100)
+            .build()
+            .expect("builder should produce valid result");
+
+        let fb = chart.to_framebuffer().expect("rendering should succeed");
+        let highlighted = categorical(2);
+        let found = (0..fb.height())
+            .any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(highlighted)));
+        assert!(found);
+    }
+
+    #[test]
+    fn test_bump_render_single_step() {
+        let chart = BumpChart::new()
+            .series("only", &[1.0])
+            .labels(false)
+            .dimensions(50, 50)
+            .build()
+            .expect("builder should produce valid result");
+
+        assert!(chart.to_framebuffer().is_ok());
+    }
+}
@@ -0,0 +1,296 @@
+//! Colorbar legend for plots that encode values as color.
+//!
+//! A colorbar draws the color ramp of a [`ColorScale`] as a gradient bar with
+//! tick marks, tick labels and an optional title. Logarithmic scales get
+//! ticks at powers of ten. Plots such as [`Heatmap`](super::Heatmap) and
+//! [`Histogram2D`](super::Histogram2D) reserve room for it beside the data
+//! panel; it can also be drawn standalone with [`Colorbar::render`].
+
+use crate::color::Rgba;
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_text, ellipsize, i32_px, text_height, text_width};
+use crate::scale::{format_tick, log_ticks, nice_ticks, ColorScale, Scale};
+
+/// Tick mark length in pixels.
+const TICK_LENGTH: u32 = 3;
+/// Gap in pixels between tick marks, labels and title.
+const LABEL_GAP: u32 = 2;
+
+/// Direction of the color ramp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorbarOrientation {
+    /// Low values at the bottom, labels to the right.
+    #[default]
+    Vertical,
+    /// Low values on the left, labels below.
+    Horizontal,
+}
+
+/// Colorbar legend configuration.
+#[derive(Debug, Clone)]
+pub struct Colorbar {
+    /// Ramp direction.
+    orientation: ColorbarOrientation,
+    /// Title drawn above (vertical) or below (horizontal) the bar.
+    label: Option<String>,
+    /// Bar thickness in pixels.
+    thickness: u32,
+    /// Approximate number of ticks on linear scales.
+    tick_count: usize,
+    /// Tick and label color.
+    text_color: Rgba,
+    /// Bitmap font scale for labels.
+    font_scale: u32,
+}
+
+impl Default for Colorbar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Colorbar {
+    /// Create a vertical colorbar.
+    #[must_use]
+    pub fn new() -> Self {
+        let style = crate::style::current();
+        Self {
+            orientation: ColorbarOrientation::Vertical,
+            label: None,
+            thickness: 12,
+            tick_count: 5,
+            text_color: style.text_color,
+            font_scale: style.font_scale,
+        }
+    }
+
+    /// Create a horizontal colorbar.
+    #[must_use]
+    pub fn horizontal() -> Self {
+        Self::new().orientation(ColorbarOrientation::Horizontal)
+    }
+
+    /// Set the ramp direction.
+    #[must_use]
+    pub fn orientation(mut self, orientation: ColorbarOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the title.
+    #[must_use]
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Set the bar thickness in pixels.
+    #[must_use]
+    pub fn thickness(mut self, thickness: u32) -> Self {
+        self.thickness = thickness.max(1);
+        self
+    }
+
+    /// Set the approximate number of ticks on linear scales.
+    #[must_use]
+    pub fn tick_count(mut self, count: usize) -> Self {
+        self.tick_count = count.max(2);
+        self
+    }
+
+    /// Set the tick and label color.
+    #[must_use]
+    pub fn text_color(mut self, color: Rgba) -> Self {
+        self.text_color = color;
+        self
+    }
+
+    /// Whether the ramp runs vertically.
+    #[must_use]
+    pub(crate) fn is_vertical(&self) -> bool {
+        self.orientation == ColorbarOrientation::Vertical
+    }
+
+    /// Set the bitmap font scale for labels.
+    #[must_use]
+    pub fn font_scale(mut self, scale: u32) -> Self {
+        self.font_scale = scale.max(1);
+        self
+    }
+
+    /// Approximate number of ticks on linear scales.
+    #[must_use]
+    pub(crate) const fn tick_target(&self) -> usize {
+        self.tick_count
+    }
+
+    /// Ticks for a color scale as `(ramp position 0-1, label)`.
+    #[must_use]
+    pub fn ticks(&self, scale: &ColorScale) -> Vec<(f32, String)> {
+        let (a, b) = scale.domain();
+        let (min, max) = (a.min(b), a.max(b));
+        let values = if scale.is_log() {
+            log_ticks(min, max)
+        } else {
+            nice_ticks(min, max, self.tick_count)
+        };
+        values.into_iter().map(|v| (scale.normalize(v), format_tick(v))).collect()
+    }
+
+    /// Space in pixels the colorbar needs across its bar, including labels
+    /// and (for horizontal bars) the title.
+    #[must_use]
+    pub fn footprint(&self, ticks: &[(f32, String)]) -> u32 {
+        let th = text_height(self.font_scale);
+        let base = self.thickness + TICK_LENGTH + LABEL_GAP;
+        match self.orientation {
+            ColorbarOrientation::Vertical => {
+                base + ticks.iter().map(|(_, l)| text_width(l, self.font_scale)).max().unwrap_or(0)
+            }
+            ColorbarOrientation::Horizontal => {
+                base + th + self.label.as_ref().map_or(0, |_| LABEL_GAP * 2 + th)
+            }
+        }
+    }
+
+    /// Space in pixels the title takes along the bar of a vertical colorbar.
+    #[must_use]
+    pub fn title_space(&self) -> u32 {
+        match (&self.label, self.orientation) {
+            (Some(_), ColorbarOrientation::Vertical) => {
+                text_height(self.font_scale) + LABEL_GAP * 2
+            }
+            _ => 0,
+        }
+    }
+
+    /// Draw the colorbar for `scale` with its bar starting at `(x, y)` and
+    /// running `length` pixels along its orientation.
+    pub fn render(&self, fb: &mut Framebuffer, scale: &ColorScale, x: u32, y: u32, length: u32) {
+        let ticks = self.ticks(scale);
+        self.draw(fb, x, y, length, |t| scale.at(t), &ticks);
+    }
+
+    /// Draw with an arbitrary ramp, for plots that color a derived quantity.
+    pub(crate) fn draw(
+        &self,
+        fb: &mut Framebuffer,
+        x: u32,
+        y: u32,
+        length: u32,
+        color_at: impl Fn(f32) -> Rgba,
+        ticks: &[(f32, String)],
+    ) {
+        if length == 0 {
+            return;
+        }
+        let th = i32_px(text_height(self.font_scale));
+        let thick = self.thickness;
+
+        match self.orientation {
+            ColorbarOrientation::Vertical => {
+                let title_space = self.title_space();
+                let y = y + title_space;
+                let length = length.saturating_sub(title_space);
+                if length == 0 {
+                    return;
+                }
+                let span = length.saturating_sub(1).max(1) as f32;
+                for i in 0..length {
+                    fb.fill_rect(x, y + length - 1 - i, thick, 1, color_at(i as f32 / span));
+                }
+                for (t, label) in ticks {
+                    let ty = y + length - 1 - (t.clamp(0.0, 1.0) * span).round() as u32;
+                    fb.fill_rect(x + thick, ty, TICK_LENGTH, 1, self.text_color);
+                    let lx = i32_px(x + thick + TICK_LENGTH + LABEL_GAP);
+                    draw_text(fb, lx, i32_px(ty) - th / 2, label, self.font_scale, self.text_color);
+                }
+                if let Some(title) = &self.label {
+                    let room = self.footprint(ticks).max(self.thickness);
+                    let title = ellipsize(title, room, self.font_scale);
+                    let ty = i32_px(y) - i32_px(title_space) + i32_px(LABEL_GAP);
+                    draw_text(fb, i32_px(x), ty, &title, self.font_scale, self.text_color);
+                }
+            }
+            ColorbarOrientation::Horizontal => {
+                let span = length.saturating_sub(1).max(1) as f32;
+                for i in 0..length {
+                    fb.fill_rect(x + i, y, 1, thick, color_at(i as f32 / span));
+                }
+                let label_y = i32_px(y + thick + TICK_LENGTH + LABEL_GAP);
+                for (t, label) in ticks {
+                    let tx = x + (t.clamp(0.0, 1.0) * span).round() as u32;
+                    fb.fill_rect(tx, y + thick, 1, TICK_LENGTH, self.text_color);
+                    let half = i32_px(text_width(label, self.font_scale)) / 2;
+                    draw_text(
+                        fb,
+                        i32_px(tx) - half,
+                        label_y,
+                        label,
+                        self.font_scale,
+                        self.text_color,
+                    );
+                }
+                if let Some(title) = &self.label {
+                    let title = ellipsize(title, length, self.font_scale);
+                    let tw = i32_px(text_width(&title, self.font_scale));
+                    let tx = i32_px(x) + i32_px(length) / 2 - tw / 2;
+                    let ty = label_y + th + i32_px(LABEL_GAP * 2);
+                    draw_text(fb, tx, ty, &title, self.font_scale, self.text_color);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn black_white(domain: (f32, f32)) -> ColorScale {
+        ColorScale::new(vec![Rgba::BLACK, Rgba::WHITE], domain)
+            .expect("color scale should be valid")
+    }
+
+    #[test]
+    fn test_linear_and_log_ticks() {
+        let bar = Colorbar::new();
+        let ticks = bar.ticks(&black_white((0.0, 10.0)));
+        assert_eq!(ticks.len(), 6);
+        assert_eq!(ticks[1], (0.2, "2".to_string()));
+
+        let log = black_white((1.0, 1000.0)).logarithmic().expect("positive domain");
+        let labels: Vec<String> = bar.ticks(&log).into_iter().map(|(_, l)| l).collect();
+        assert_eq!(labels.len(), 4);
+        assert_eq!(labels[2], "100");
+    }
+
+    #[test]
+    fn test_footprint_by_orientation() {
+        let ticks = vec![(0.0, "0".to_string()), (1.0, "1000".to_string())];
+        let vertical = Colorbar::new().thickness(10).font_scale(1);
+        assert_eq!(vertical.footprint(&ticks), 10 + 3 + 2 + text_width("1000", 1));
+        let horizontal = Colorbar::horizontal().thickness(10).font_scale(1);
+        assert_eq!(horizontal.footprint(&ticks), 10 + 3 + 2 + text_height(1));
+        assert!(horizontal.clone().label("count").footprint(&ticks) > horizontal.footprint(&ticks));
+        assert_eq!(horizontal.label("count").title_space(), 0);
+    }
+
+    #[test]
+    fn test_render_vertical_gradient() {
+        let mut fb = Framebuffer::new(60, 60).expect("framebuffer creation should succeed");
+        fb.clear(Rgba::rgb(128, 0, 0));
+        Colorbar::new().render(&mut fb, &black_white((0.0, 1.0)), 5, 5, 50);
+        assert_eq!(fb.get_pixel(8, 54), Some(Rgba::BLACK));
+        assert_eq!(fb.get_pixel(8, 5), Some(Rgba::WHITE));
+    }
+
+    #[test]
+    fn test_render_horizontal_gradient() {
+        let mut fb = Framebuffer::new(60, 40).expect("framebuffer creation should succeed");
+        fb.clear(Rgba::rgb(128, 0, 0));
+        Colorbar::horizontal().label("x").render(&mut fb, &black_white((0.0, 1.0)), 5, 5, 50);
+        assert_eq!(fb.get_pixel(5, 8), Some(Rgba::BLACK));
+        assert_eq!(fb.get_pixel(54, 8), Some(Rgba::WHITE));
+    }
+}
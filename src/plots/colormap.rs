@@ -0,0 +1,614 @@
+//! Custom colormaps and their preview figure.
+//!
+//! A [`Colormap`] is built from control points and interpolated in OKLab, so
+//! equal steps between stops look equally different. [`Colormap::analyze`]
+//! checks perceptual uniformity, gamut and legibility under simulated color
+//! vision deficiencies, and [`ColormapPreview`] renders the standard strips a
+//! team can review before adopting a palette: the ramp as seen with normal
+//! vision and each deficiency, its lightness in grey, a ripple test that
+//! exposes flat or banded regions, and the lightness / step-size profile.
+//!
+//! # Example
+//!
+//! ```rust
+//! use trueno_viz::color::Rgba;
+//! use trueno_viz::plots::Colormap;
+//!
+//! let ocean = Colormap::new("ocean")
+//!     .stop(0.0, Rgba::rgb(10, 20, 60))
+//!     .stop(0.3, Rgba::rgb(30, 120, 160))
+//!     .stop(1.0, Rgba::rgb(230, 245, 220))
+//!     .build()
+//!     .unwrap()
+//!     .equalize();
+//! let report = ocean.analyze();
+//! assert!(report.is_perceptually_uniform() && report.is_colorblind_safe(), "{report}");
+//! let preview = ocean.preview().build().unwrap().to_framebuffer().unwrap();
+//! assert_eq!(preview.width(), 720);
+//! ```
+//!
+//! # References
+//!
+//! - Kovesi, P. (2015). "Good Colour Maps: How to Design Them." arXiv:1509.03700.
+//! - Ottosson, B. (2020). "A perceptual color space for image processing."
+
+use std::fmt;
+
+use crate::color::{ColorVisionDeficiency, Oklab, Rgba};
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_line, draw_rect_outline, i32_px, TextStyle};
+use crate::scale::ColorScale;
+use batuta_common::display::WithDimensions;
+
+/// Samples used to measure step sizes along the ramp.
+const ANALYSIS_SAMPLES: usize = 64;
+
+/// Evenly spaced probes compared pairwise for legibility.
+const PROBES: usize = 9;
+
+/// Smallest probe-to-probe OKLab distance considered distinguishable.
+pub const MIN_PROBE_DISTANCE: f32 = 0.05;
+
+/// Smallest slowest-to-fastest step ratio considered perceptually uniform.
+pub const UNIFORMITY_THRESHOLD: f32 = 0.8;
+
+/// Colors in a [`ColorScale`] made from a colormap.
+const COLOR_SCALE_SAMPLES: usize = 64;
+
+const TITLE_HEIGHT: u32 = 28;
+const LABEL_WIDTH: u32 = 100;
+const PADDING: u32 = 8;
+const MUTED_COLOR: Rgba = Rgba::rgb(110, 110, 110);
+const AXIS_COLOR: Rgba = Rgba::rgb(200, 200, 200);
+const LIGHTNESS_COLOR: Rgba = Rgba::rgb(40, 40, 40);
+const STEP_COLOR: Rgba = Rgba::rgb(214, 39, 40);
+
+/// Color of a preview strip pixel at `(x, y)` within the strip.
+type StripColor<'a> = dyn Fn(u32, u32) -> Rgba + 'a;
+
+/// A colormap interpolated in OKLab between control points.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Colormap {
+    name: String,
+    /// Control points as (position in 0.0-1.0, color), sorted after `build`.
+    stops: Vec<(f32, Oklab)>,
+}
+
+impl Colormap {
+    /// Start an empty colormap.
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_string(), stops: Vec::new() }
+    }
+
+    /// Colormap with `colors` spaced evenly from 0.0 to 1.0.
+    #[must_use]
+    pub fn from_colors(name: &str, colors: &[Rgba]) -> Self {
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        colors.iter().enumerate().fold(Self::new(name), |map, (i, &c)| map.stop(i as f32 / last, c))
+    }
+
+    /// Add an sRGB control point at `position` (0.0-1.0).
+    #[must_use]
+    pub fn stop(self, position: f32, color: Rgba) -> Self {
+        self.stop_oklab(position, Oklab::from(color))
+    }
+
+    /// Add an OKLab control point at `position` (0.0-1.0).
+    #[must_use]
+    pub fn stop_oklab(mut self, position: f32, color: Oklab) -> Self {
+        self.stops.push((position, color));
+        self
+    }
+
+    /// Validate and sort the control points.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with fewer than two stops, or positions outside
+    /// 0.0-1.0 or repeated.
+    pub fn build(mut self) -> Result<Self> {
+        if self.stops.len() < 2 {
+            return Err(Error::ScaleDomain("Colormap requires at least two stops".into()));
+        }
+        if let Some((p, _)) = self.stops.iter().find(|(p, _)| !(0.0..=1.0).contains(p)) {
+            return Err(Error::ScaleDomain(format!("Colormap stop position {p} is outside 0..1")));
+        }
+        self.stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if self.stops.windows(2).any(|w| (w[1].0 - w[0].0).abs() < f32::EPSILON) {
+            return Err(Error::ScaleDomain("Colormap stop positions must be distinct".into()));
+        }
+        Ok(self)
+    }
+
+    /// Colormap name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Control points as `(position, color)`.
+    #[must_use]
+    pub fn stops(&self) -> &[(f32, Oklab)] {
+        &self.stops
+    }
+
+    /// Respace the stops so every step along the ramp is equally large in
+    /// OKLab, keeping the colors and their order.
+    #[must_use]
+    pub fn equalize(mut self) -> Self {
+        let mut lengths = vec![0.0];
+        for w in self.stops.windows(2) {
+            lengths.push(lengths[lengths.len() - 1] + w[0].1.distance(w[1].1));
+        }
+        let total = lengths[lengths.len() - 1];
+        if total > f32::EPSILON {
+            for ((position, _), length) in self.stops.iter_mut().zip(lengths) {
+                *position = length / total;
+            }
+        }
+        self
+    }
+
+    /// Interpolated OKLab color at `t` (0.0-1.0).
+    #[must_use]
+    pub fn at_oklab(&self, t: f32) -> Oklab {
+        let t = t.clamp(0.0, 1.0);
+        let Some(&(first_pos, first)) = self.stops.first() else {
+            return Oklab::default();
+        };
+        if t <= first_pos {
+            return first;
+        }
+        for w in self.stops.windows(2) {
+            let ((p0, c0), (p1, c1)) = (w[0], w[1]);
+            if t <= p1 {
+                return c0.lerp(c1, (t - p0) / (p1 - p0));
+            }
+        }
+        self.stops[self.stops.len() - 1].1
+    }
+
+    /// sRGB color at `t` (0.0-1.0), clipped to the gamut.
+    #[must_use]
+    pub fn at(&self, t: f32) -> Rgba {
+        self.at_oklab(t).to_rgba()
+    }
+
+    /// `n` evenly spaced colors from start to end.
+    #[must_use]
+    pub fn sample(&self, n: usize) -> Vec<Rgba> {
+        let last = n.saturating_sub(1).max(1) as f32;
+        (0..n).map(|i| self.at(i as f32 / last)).collect()
+    }
+
+    /// Color scale over `domain` for use with heatmaps and colorbars.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the domain is empty.
+    pub fn to_color_scale(&self, domain: (f32, f32)) -> Result<ColorScale> {
+        ColorScale::new(self.sample(COLOR_SCALE_SAMPLES), domain)
+    }
+
+    /// Measure uniformity, gamut and colorblind legibility.
+    #[must_use]
+    pub fn analyze(&self) -> ColormapReport {
+        let last = (ANALYSIS_SAMPLES - 1) as f32;
+        let samples: Vec<Oklab> =
+            (0..ANALYSIS_SAMPLES).map(|i| self.at_oklab(i as f32 / last)).collect();
+        let steps: Vec<f32> = samples.windows(2).map(|w| w[0].distance(w[1])).collect();
+        let (slowest, fastest) =
+            steps.iter().fold((f32::INFINITY, 0.0_f32), |(lo, hi), &s| (lo.min(s), hi.max(s)));
+        let rising = samples.windows(2).all(|w| w[1].l > w[0].l);
+        let falling = samples.windows(2).all(|w| w[1].l < w[0].l);
+
+        let probes = self.sample(PROBES);
+        let length = |colors: &[Rgba]| {
+            colors.windows(2).map(|w| Oklab::from(w[0]).distance(Oklab::from(w[1]))).sum::<f32>()
+        };
+        let normal_length = length(&self.sample(ANALYSIS_SAMPLES));
+        let cvd = ColorVisionDeficiency::ALL
+            .iter()
+            .map(|&deficiency| {
+                let seen: Vec<Rgba> = probes.iter().map(|&c| deficiency.simulate(c)).collect();
+                let dense: Vec<Rgba> = self
+                    .sample(ANALYSIS_SAMPLES)
+                    .into_iter()
+                    .map(|c| deficiency.simulate(c))
+                    .collect();
+                let min_distance = min_pairwise_distance(&seen);
+                CvdCheck {
+                    deficiency,
+                    min_distance,
+                    length_ratio: if normal_length > 0.0 {
+                        length(&dense) / normal_length
+                    } else {
+                        0.0
+                    },
+                    safe: min_distance >= MIN_PROBE_DISTANCE,
+                }
+            })
+            .collect();
+
+        ColormapReport {
+            name: self.name.clone(),
+            uniformity: if fastest > 0.0 { slowest / fastest } else { 0.0 },
+            lightness: (samples[0].l, samples[samples.len() - 1].l),
+            lightness_monotonic: rising || falling,
+            out_of_gamut: samples.iter().filter(|c| !c.in_gamut()).count(),
+            min_distance: min_pairwise_distance(&probes),
+            cvd,
+        }
+    }
+
+    /// Preview figure builder for this colormap.
+    #[must_use]
+    pub fn preview(&self) -> ColormapPreview {
+        ColormapPreview::new(self.clone())
+    }
+}
+
+/// Smallest OKLab distance between any two of `colors`.
+fn min_pairwise_distance(colors: &[Rgba]) -> f32 {
+    let lab: Vec<Oklab> = colors.iter().map(|&c| Oklab::from(c)).collect();
+    let mut min = f32::INFINITY;
+    for (i, a) in lab.iter().enumerate() {
+        for b in &lab[i + 1..] {
+            min = min.min(a.distance(*b));
+        }
+    }
+    min
+}
+
+/// Legibility of a colormap under one color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CvdCheck {
+    /// Simulated deficiency.
+    pub deficiency: ColorVisionDeficiency,
+    /// Smallest OKLab distance between any two of nine evenly spaced probes.
+    pub min_distance: f32,
+    /// Perceived ramp length relative to normal vision.
+    pub length_ratio: f32,
+    /// Whether every pair of probes stays distinguishable.
+    pub safe: bool,
+}
+
+/// Result of [`Colormap::analyze`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColormapReport {
+    /// Colormap name.
+    pub name: String,
+    /// Slowest / fastest perceptual step along the ramp (1.0 = uniform).
+    pub uniformity: f32,
+    /// OKLab lightness at the start and end.
+    pub lightness: (f32, f32),
+    /// Whether lightness strictly rises or falls (expected of sequential maps).
+    pub lightness_monotonic: bool,
+    /// Analysis samples outside the sRGB gamut (clipped when drawn).
+    pub out_of_gamut: usize,
+    /// Smallest OKLab distance between any two of nine probes (normal vision).
+    pub min_distance: f32,
+    /// Per-deficiency checks.
+    pub cvd: Vec<CvdCheck>,
+}
+
+impl ColormapReport {
+    /// Even steps and no gamut clipping.
+    #[must_use]
+    pub fn is_perceptually_uniform(&self) -> bool {
+        self.uniformity >= UNIFORMITY_THRESHOLD && self.out_of_gamut == 0
+    }
+
+    /// Every probe pair stays distinguishable under all simulated deficiencies.
+    #[must_use]
+    pub fn is_colorblind_safe(&self) -> bool {
+        self.cvd.iter().all(|c| c.safe)
+    }
+}
+
+impl fmt::Display for ColormapReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = |ok: bool| if ok { "pass" } else { "FAIL" };
+        writeln!(f, "colormap {}", self.name)?;
+        writeln!(
+            f,
+            "  uniformity {:.2} ({}), out of gamut {}/{}",
+            self.uniformity,
+            verdict(self.is_perceptually_uniform()),
+            self.out_of_gamut,
+            ANALYSIS_SAMPLES
+        )?;
+        writeln!(
+            f,
+            "  lightness {:.2} -> {:.2}{}",
+            self.lightness.0,
+            self.lightness.1,
+            if self.lightness_monotonic { " (monotonic)" } else { "" }
+        )?;
+        write!(f, "  min probe distance {:.3}", self.min_distance)?;
+        for check in &self.cvd {
+            write!(
+                f,
+                "\n  {}: min distance {:.3}, length {:.0}% ({})",
+                check.deficiency.name(),
+                check.min_distance,
+                check.length_ratio * 100.0,
+                verdict(check.safe)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Standard review figure for a [`Colormap`].
+#[derive(Debug, Clone)]
+pub struct ColormapPreview {
+    colormap: Colormap,
+    width: u32,
+    height: u32,
+}
+
+impl ColormapPreview {
+    /// Preview of `colormap` at the default 720x440 size, scaled to the
+    /// styled figure size.
+    #[must_use]
+    pub fn new(colormap: Colormap) -> Self {
+        let (width, height) = crate::style::current().scaled_size(720, 440);
+        Self { colormap, width, height }
+    }
+
+    /// Validate the colormap and figure size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the colormap has fewer than two stops or the
+    /// figure is too small for its strips.
+    pub fn build(self) -> Result<Self> {
+        if self.colormap.stops.len() < 2 {
+            return Err(Error::ScaleDomain("Colormap requires at least two stops".into()));
+        }
+        if self.width < LABEL_WIDTH + 4 * PADDING || self.height < TITLE_HEIGHT + 120 {
+            return Err(Error::InvalidDimensions { width: self.width, height: self.height });
+        }
+        Ok(self)
+    }
+
+    /// Render the preview to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the framebuffer cannot be created.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        let report = self.colormap.analyze();
+        let font = TextStyle::current();
+        // Title at twice the label size, in a band TITLE_HEIGHT tall at scale 1
+        let title_font = TextStyle { scale: font.scale * 2, ..font };
+        let title_height = TITLE_HEIGHT - 14 + title_font.height();
+
+        title_font.draw(&mut fb, i32_px(PADDING), 8, &format!("Colormap: {}", report.name));
+        let verdict = format!(
+            "{} / {}",
+            if report.is_perceptually_uniform() { "uniform" } else { "non-uniform" },
+            if report.is_colorblind_safe() { "colorblind-safe" } else { "not colorblind-safe" }
+        );
+        let verdict_x = self.width.saturating_sub(font.width(&verdict) + PADDING);
+        font.with_color(MUTED_COLOR).draw(&mut fb, i32_px(verdict_x), 12, &verdict);
+
+        // Six strips share the top 60% of the body; the profile chart takes the rest
+        let label_width = LABEL_WIDTH.max(font.width("deuteranopia !") + 2 * PADDING);
+        let strip_x = label_width;
+        let strip_w = self.width.saturating_sub(label_width + PADDING).max(1);
+        let body_h = self.height.saturating_sub(title_height + PADDING);
+        let strips_h = body_h * 3 / 5;
+        let row_h = strips_h / 6;
+        let strip_h = row_h.saturating_sub(4).max(1);
+        let ramp: Vec<Rgba> = (0..strip_w)
+            .map(|x| self.colormap.at(x as f32 / (strip_w - 1).max(1) as f32))
+            .collect();
+        let ramp = ramp.as_slice();
+
+        let mut rows: Vec<(String, Box<StripColor<'_>>)> =
+            vec![("normal".into(), Box::new(move |x, _| ramp[x as usize]))];
+        for check in &report.cvd {
+            let deficiency = check.deficiency;
+            let label = format!("{}{}", deficiency.name(), if check.safe { "" } else { " !" });
+            rows.push((label, Box::new(move |x, _| deficiency.simulate(ramp[x as usize]))));
+        }
+        rows.push((
+            "lightness".into(),
+            Box::new(move |x, _| Oklab::new(Oklab::from(ramp[x as usize]).l, 0.0, 0.0).to_rgba()),
+        ));
+        rows.push((
+            "ripple test".into(),
+            Box::new(move |x, y| {
+                // Kovesi's test: a sine ripple fading from top to bottom
+                let fade = 1.0 - y as f32 / strip_h as f32;
+                let ripple = (x as f32 * std::f32::consts::TAU / 8.0).sin() * 0.04 * fade * fade;
+                self.colormap.at(x as f32 / (strip_w - 1).max(1) as f32 + ripple)
+            }),
+        ));
+
+        for (i, (label, color)) in rows.iter().enumerate() {
+            let y0 = title_height + i as u32 * row_h;
+            let label_y = i32_px(y0 + strip_h / 2) - i32_px(font.height()) / 2;
+            font.draw(&mut fb, i32_px(PADDING), label_y, label);
+            for y in 0..strip_h {
+                for x in 0..strip_w {
+                    fb.set_pixel(strip_x + x, y0 + y, color(x, y));
+                }
+            }
+        }
+
+        self.draw_profile(&mut fb, &report, title_height + strips_h + PADDING, strip_x, strip_w);
+        Ok(fb)
+    }
+
+    /// Lightness curve and relative step size along the ramp.
+    fn draw_profile(
+        &self,
+        fb: &mut Framebuffer,
+        report: &ColormapReport,
+        y0: u32,
+        x0: u32,
+        w: u32,
+    ) {
+        let h = self.height.saturating_sub(y0 + PADDING);
+        if h < 20 {
+            return;
+        }
+        draw_rect_outline(fb, i32_px(x0), i32_px(y0), w, h, AXIS_COLOR, 1);
+        let font = TextStyle::current();
+        let line = i32_px(font.height()) + 5;
+        let y = i32_px(y0) + 2;
+        font.with_color(LIGHTNESS_COLOR).draw(fb, i32_px(PADDING), y, "lightness");
+        font.with_color(STEP_COLOR).draw(fb, i32_px(PADDING), y + line, "step size");
+        font.with_color(MUTED_COLOR).draw(
+            fb,
+            i32_px(PADDING),
+            y + 2 * line + 4,
+            &format!("uniformity {:.2}", report.uniformity),
+        );
+
+        let last = (w - 1).max(1) as f32;
+        let lab: Vec<Oklab> = (0..w).map(|x| self.colormap.at_oklab(x as f32 / last)).collect();
+        let steps: Vec<f32> = lab.windows(2).map(|p| p[0].distance(p[1])).collect();
+        let fastest = steps.iter().copied().fold(0.0_f32, f32::max).max(f32::EPSILON);
+        let to_y = |v: f32| i32_px(y0 + h - 2) - (v.clamp(0.0, 1.0) * (h - 4) as f32) as i32;
+
+        for x in 1..w {
+            let (px, cx) = (i32_px(x0 + x - 1), i32_px(x0 + x));
+            let i = x as usize;
+            // Two pixels thick so the curves read at a glance
+            for dy in 0..2 {
+                let (l0, l1) = (to_y(lab[i - 1].l) + dy, to_y(lab[i].l) + dy);
+                draw_line(fb, px, l0, cx, l1, LIGHTNESS_COLOR);
+                if i >= 2 {
+                    let (a, b) = (steps[i - 2] / fastest, steps[i - 1] / fastest);
+                    draw_line(fb, px, to_y(a) + dy, cx, to_y(b) + dy, STEP_COLOR);
+                }
+            }
+        }
+    }
+
+    /// Render into an existing framebuffer at the origin.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the preview cannot be rendered.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let figure = self.to_framebuffer()?;
+        fb.blit(&figure, 0, 0);
+        Ok(())
+    }
+}
+
+impl WithDimensions for ColormapPreview {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequential() -> Colormap {
+        Colormap::new("ink")
+            .stop(0.0, Rgba::rgb(20, 24, 60))
+            .stop(0.5, Rgba::rgb(40, 140, 140))
+            .stop(1.0, Rgba::rgb(250, 240, 180))
+            .build()
+            .expect("valid colormap")
+    }
+
+    #[test]
+    fn test_build_validates_stops() {
+        assert!(Colormap::new("one").stop(0.0, Rgba::BLACK).build().is_err());
+        assert!(Colormap::from_colors("dup", &[Rgba::BLACK, Rgba::WHITE])
+            .stop(1.0, Rgba::RED)
+            .build()
+            .is_err());
+        assert!(Colormap::new("out")
+            .stop(0.0, Rgba::BLACK)
+            .stop(1.5, Rgba::WHITE)
+            .build()
+            .is_err());
+
+        let map = Colormap::new("unsorted")
+            .stop(1.0, Rgba::WHITE)
+            .stop(0.0, Rgba::BLACK)
+            .build()
+            .expect("valid colormap");
+        assert_eq!(map.at(0.0), Rgba::BLACK);
+        assert_eq!(map.at(1.0), Rgba::WHITE);
+        // OKLab midpoint of black and white is perceptual mid-grey, not sRGB 127
+        let mid = map.at(0.5);
+        assert!(mid.r == mid.g && mid.g == mid.b && mid.r > 90 && mid.r < 110);
+    }
+
+    #[test]
+    fn test_equalize_makes_steps_uniform() {
+        let lopsided = Colormap::new("lopsided")
+            .stop(0.0, Rgba::rgb(20, 24, 60))
+            .stop(0.1, Rgba::rgb(40, 140, 140))
+            .stop(1.0, Rgba::rgb(250, 240, 180))
+            .build()
+            .expect("valid colormap");
+        let before = lopsided.analyze();
+        assert!(before.uniformity < 0.3);
+        assert!(!before.is_perceptually_uniform());
+
+        let after = lopsided.equalize().analyze();
+        assert!(after.uniformity > 0.9, "{after}");
+        assert!(after.lightness_monotonic);
+    }
+
+    #[test]
+    fn test_colorblind_checks() {
+        let report = sequential().equalize().analyze();
+        assert!(report.is_colorblind_safe(), "{report}");
+        assert_eq!(report.cvd.len(), 3);
+
+        // Red-to-green at equal lightness collapses for red-green deficiencies
+        let traffic = Colormap::new("traffic")
+            .stop_oklab(0.0, Oklab::new(0.6, 0.15, 0.08))
+            .stop_oklab(1.0, Oklab::new(0.6, -0.15, 0.08))
+            .build()
+            .expect("valid colormap")
+            .analyze();
+        assert!(!traffic.is_colorblind_safe());
+        let deutan = traffic
+            .cvd
+            .iter()
+            .find(|c| c.deficiency == ColorVisionDeficiency::Deuteranopia)
+            .expect("deuteranopia check");
+        assert!(!deutan.safe && deutan.length_ratio < 0.5);
+        assert!(traffic.to_string().contains("deuteranopia"));
+    }
+
+    #[test]
+    fn test_preview_figure() {
+        let map = sequential();
+        let fb = map
+            .preview()
+            .dimensions(600, 360)
+            .build()
+            .expect("valid preview")
+            .to_framebuffer()
+            .expect("preview renders");
+        assert_eq!((fb.width(), fb.height()), (600, 360));
+        // The normal strip starts with the first stop and ends with the last
+        let y = TITLE_HEIGHT + 2;
+        assert_eq!(fb.get_pixel(LABEL_WIDTH, y), Some(map.at(0.0)));
+        assert_eq!(fb.get_pixel(600 - PADDING - 1, y), Some(map.at(1.0)));
+
+        let scale = map.to_color_scale((0.0, 10.0)).expect("color scale");
+        assert_eq!(scale.at(1.0), map.at(1.0));
+        assert!(map.preview().dimensions(50, 50).build().is_err());
+    }
+}
@@ -0,0 +1,230 @@
+//! Declarative conditional formatting for table cells.
+//!
+//! A [`ConditionalFormat`] is an ordered list of [`FormatRule`]s, each
+//! mapping a value range to a text color, a background and/or an icon. The
+//! first matching rule wins. The same rules drive the static
+//! [`MetricTable`](super::MetricTable) and the monitor `DataFrame` widget,
+//! so a dashboard and its exported report highlight the same cells.
+//!
+//! # Example
+//!
+//! ```
+//! use trueno_viz::color::Rgba;
+//! use trueno_viz::plots::{ConditionalFormat, FormatRule, RuleIcon};
+//!
+//! let latency = ConditionalFormat::new()
+//!     .rule(FormatRule::above(500.0).color(Rgba::RED).icon(RuleIcon::Alert))
+//!     .rule(FormatRule::above(200.0).color(Rgba::rgb(200, 140, 0)));
+//! assert!(latency.evaluate(750.0).is_some());
+//! assert!(latency.evaluate(20.0).is_none());
+//! ```
+
+use crate::color::Rgba;
+
+/// Value range a rule applies to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Condition {
+    /// Strictly greater than the threshold.
+    Above(f64),
+    /// Strictly less than the threshold.
+    Below(f64),
+    /// Within `[min, max]`, inclusive.
+    Between(f64, f64),
+    /// Outside `[min, max]`.
+    Outside(f64, f64),
+    /// Equal to the value.
+    Equals(f64),
+    /// NaN or infinite.
+    NotFinite,
+}
+
+impl Condition {
+    /// Whether `value` satisfies the condition.
+    #[must_use]
+    pub fn matches(self, value: f64) -> bool {
+        if !value.is_finite() {
+            return self == Self::NotFinite;
+        }
+        match self {
+            Self::Above(t) => value > t,
+            Self::Below(t) => value < t,
+            Self::Between(lo, hi) => value >= lo.min(hi) && value <= lo.max(hi),
+            Self::Outside(lo, hi) => value < lo.min(hi) || value > lo.max(hi),
+            Self::Equals(v) => (value - v).abs() <= f64::EPSILON * v.abs().max(1.0),
+            Self::NotFinite => false,
+        }
+    }
+}
+
+/// Marker drawn at the start of a highlighted cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleIcon {
+    /// Filled dot.
+    Dot,
+    /// Upward triangle.
+    Up,
+    /// Downward triangle.
+    Down,
+    /// Exclamation mark.
+    Alert,
+}
+
+impl RuleIcon {
+    /// Terminal symbol for the icon.
+    #[must_use]
+    pub const fn symbol(self) -> char {
+        match self {
+            Self::Dot => '●',
+            Self::Up => '▲',
+            Self::Down => '▼',
+            Self::Alert => '!',
+        }
+    }
+}
+
+/// A value range and the styling applied to cells in it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatRule {
+    /// When the rule applies.
+    pub condition: Condition,
+    /// Text (or chart) color.
+    pub color: Option<Rgba>,
+    /// Cell background.
+    pub background: Option<Rgba>,
+    /// Leading icon.
+    pub icon: Option<RuleIcon>,
+}
+
+impl FormatRule {
+    /// Create a rule for a condition with no styling yet.
+    #[must_use]
+    pub fn new(condition: Condition) -> Self {
+        Self { condition, color: None, background: None, icon: None }
+    }
+
+    /// Rule for values above `threshold`.
+    #[must_use]
+    pub fn above(threshold: f64) -> Self {
+        Self::new(Condition::Above(threshold))
+    }
+
+    /// Rule for values below `threshold`.
+    #[must_use]
+    pub fn below(threshold: f64) -> Self {
+        Self::new(Condition::Below(threshold))
+    }
+
+    /// Rule for values in `[min, max]`.
+    #[must_use]
+    pub fn between(min: f64, max: f64) -> Self {
+        Self::new(Condition::Between(min, max))
+    }
+
+    /// Rule for values outside `[min, max]`.
+    #[must_use]
+    pub fn outside(min: f64, max: f64) -> Self {
+        Self::new(Condition::Outside(min, max))
+    }
+
+    /// Set the text color.
+    #[must_use]
+    pub fn color(mut self, color: Rgba) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the cell background.
+    #[must_use]
+    pub fn background(mut self, color: Rgba) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Set the leading icon.
+    #[must_use]
+    pub fn icon(mut self, icon: RuleIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+}
+
+/// Ordered formatting rules; the first match wins.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConditionalFormat {
+    rules: Vec<FormatRule>,
+}
+
+impl ConditionalFormat {
+    /// Create an empty rule set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Two-level thresholds: amber above `warn`, red with an alert icon
+    /// above `critical`.
+    #[must_use]
+    pub fn thresholds(warn: f64, critical: f64) -> Self {
+        Self::new()
+            .rule(FormatRule::above(critical).color(Rgba::rgb(200, 30, 30)).icon(RuleIcon::Alert))
+            .rule(FormatRule::above(warn).color(Rgba::rgb(200, 140, 0)))
+    }
+
+    /// Append a rule (lower priority than the rules before it).
+    #[must_use]
+    pub fn rule(mut self, rule: FormatRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// The rules in priority order.
+    #[must_use]
+    pub fn rules(&self) -> &[FormatRule] {
+        &self.rules
+    }
+
+    /// Whether any rule draws an icon (tables reserve room for it).
+    #[must_use]
+    pub fn has_icons(&self) -> bool {
+        self.rules.iter().any(|r| r.icon.is_some())
+    }
+
+    /// The first rule matching `value`.
+    #[must_use]
+    pub fn evaluate(&self, value: f64) -> Option<&FormatRule> {
+        self.rules.iter().find(|r| r.condition.matches(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conditions() {
+        assert!(Condition::Above(1.0).matches(1.5) && !Condition::Above(1.0).matches(1.0));
+        assert!(Condition::Below(0.0).matches(-1.0));
+        assert!(
+            Condition::Between(5.0, 1.0).matches(5.0) && Condition::Between(1.0, 5.0).matches(1.0)
+        );
+        assert!(
+            Condition::Outside(1.0, 5.0).matches(6.0) && !Condition::Outside(1.0, 5.0).matches(3.0)
+        );
+        assert!(Condition::Equals(0.3).matches(0.1 + 0.2));
+        assert!(
+            Condition::NotFinite.matches(f64::NAN) && !Condition::Above(0.0).matches(f64::INFINITY)
+        );
+    }
+
+    #[test]
+    fn test_first_match_wins() {
+        let format = ConditionalFormat::thresholds(70.0, 90.0);
+        let critical = format.evaluate(95.0).expect("above critical");
+        assert_eq!(critical.icon, Some(RuleIcon::Alert));
+        let warn = format.evaluate(80.0).expect("above warn");
+        assert_eq!(warn.icon, None);
+        assert!(format.evaluate(50.0).is_none());
+        assert!(format.has_icons());
+        assert!(!ConditionalFormat::new().rule(FormatRule::below(0.0)).has_icons());
+    }
+}
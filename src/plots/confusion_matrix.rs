@@ -66,13 +66,14 @@ impl ConfusionMatrix {
     /// Create a new confusion matrix builder.
     #[must_use]
     pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(600, 600);
         Self {
             data: Vec::new(),
             num_classes: 0,
             labels: Vec::new(),
             normalization: Normalization::default(),
-            width: 600,
-            height: 600,
+            width,
+            height,
             margin: 60,
             show_borders: true,
             border_color: Rgba::rgb(100, 100, 100),
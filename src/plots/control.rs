@@ -0,0 +1,576 @@
+//! Statistical process control charts.
+//!
+//! Plots a metric over time against its center line and control limits, and
+//! highlights points that violate the Western Electric run rules. Two modes
+//! are supported:
+//!
+//! - **Shewhart** individuals chart: limits at `center ± k·σ`, with σ
+//!   estimated from the average moving range (`MR̄ / 1.128`).
+//! - **EWMA**: plots the exponentially weighted moving average
+//!   `z_i = λ·x_i + (1-λ)·z_{i-1}` with time-varying limits
+//!   `center ± k·σ·sqrt(λ/(2-λ)·(1-(1-λ)^{2i}))`.
+//!
+//! # References
+//!
+//! - Shewhart, W. A. (1931). *Economic Control of Quality of Manufactured
+//!   Product*. Van Nostrand.
+//! - Western Electric Company (1956). *Statistical Quality Control Handbook*.
+//! - Roberts, S. W. (1959). "Control Chart Tests Based on Geometric Moving
+//!   Averages". *Technometrics*, 1(3), 239-250.
+//! - Montgomery, D. C. (2019). *Introduction to Statistical Quality Control*
+//!   (8th ed.). Wiley.
+
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_circle, draw_line_aa};
+use crate::scale::{LinearScale, Scale};
+
+/// d2 bias-correction constant for moving ranges of span 2.
+const D2_SPAN_2: f32 = 1.128;
+
+/// Control chart statistic.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ControlMode {
+    /// Shewhart individuals chart (default).
+    #[default]
+    Shewhart,
+    /// Exponentially weighted moving average with smoothing factor `lambda`.
+    Ewma {
+        /// Weight of the newest observation (0.0 - 1.0, typically 0.05 - 0.3).
+        lambda: f32,
+    },
+}
+
+/// Western Electric run rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WesternElectricRule {
+    /// Rule 1: one point beyond the 3σ control limits.
+    BeyondLimits,
+    /// Rule 2: two of three consecutive points beyond 2σ on the same side.
+    TwoOfThreeBeyond2Sigma,
+    /// Rule 3: four of five consecutive points beyond 1σ on the same side.
+    FourOfFiveBeyond1Sigma,
+    /// Rule 4: eight consecutive points on the same side of the center line.
+    EightSameSide,
+}
+
+impl WesternElectricRule {
+    /// All four rules, in handbook order.
+    pub const ALL: [Self; 4] = [
+        Self::BeyondLimits,
+        Self::TwoOfThreeBeyond2Sigma,
+        Self::FourOfFiveBeyond1Sigma,
+        Self::EightSameSide,
+    ];
+}
+
+/// A point flagged by a run rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleViolation {
+    /// Index of the observation that completed the violating pattern.
+    pub index: usize,
+    /// Rule that was violated.
+    pub rule: WesternElectricRule,
+}
+
+/// Center line and limits of a control chart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ControlLimits {
+    /// Center line.
+    pub center: f32,
+    /// Process standard deviation.
+    pub sigma: f32,
+    /// Upper control limit (steady-state for EWMA).
+    pub ucl: f32,
+    /// Lower control limit (steady-state for EWMA).
+    pub lcl: f32,
+}
+
+/// Builder for creating control charts.
+#[derive(Debug, Clone)]
+pub struct ControlChart {
+    /// Observations in time order.
+    data: Vec<f32>,
+    /// Charted statistic.
+    mode: ControlMode,
+    /// Center line override (defaults to the sample mean).
+    center: Option<f32>,
+    /// Sigma override (defaults to the moving-range estimate).
+    sigma: Option<f32>,
+    /// Width of the control limits in sigmas.
+    limit_sigmas: f32,
+    /// Enabled run rules.
+    rules: Vec<WesternElectricRule>,
+    /// Series line color.
+    line_color: Rgba,
+    /// Center line color.
+    center_color: Rgba,
+    /// Control limit color.
+    limit_color: Rgba,
+    /// Highlight color for violating points.
+    violation_color: Rgba,
+    /// Draw faint 1σ/2σ zone lines.
+    show_zones: bool,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the plot.
+    margin: u32,
+}
+
+impl Default for ControlChart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ControlChart {
+    /// Create a new control chart builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 400);
+        Self {
+            data: Vec::new(),
+            mode: ControlMode::default(),
+            center: None,
+            sigma: None,
+            limit_sigmas: 3.0,
+            rules: WesternElectricRule::ALL.to_vec(),
+            line_color: Rgba::rgb(31, 119, 180),
+            center_color: Rgba::rgb(44, 160, 44),
+            limit_color: Rgba::rgb(214, 39, 40),
+            violation_color: Rgba::rgb(255, 127, 14),
+            show_zones: true,
+            width,
+            height,
+            margin: 40,
+        }
+    }
+
+    /// Set the observations.
+    #[must_use]
+    pub fn data(mut self, data: &[f32]) -> Self {
+        self.data = data.to_vec();
+        self
+    }
+
+    /// Set the charted statistic.
+    #[must_use]
+    pub fn mode(mut self, mode: ControlMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Use an EWMA chart with the given smoothing factor.
+    #[must_use]
+    pub fn ewma(self, lambda: f32) -> Self {
+        self.mode(ControlMode::Ewma { lambda })
+    }
+
+    /// Fix the center line (e.g. a known process target).
+    #[must_use]
+    pub fn center(mut self, center: f32) -> Self {
+        self.center = Some(center);
+        self
+    }
+
+    /// Fix the process standard deviation.
+    #[must_use]
+    pub fn sigma(mut self, sigma: f32) -> Self {
+        self.sigma = Some(sigma);
+        self
+    }
+
+    /// Set the control limit width in sigmas (default: 3).
+    #[must_use]
+    pub fn limit_sigmas(mut self, k: f32) -> Self {
+        self.limit_sigmas = k;
+        self
+    }
+
+    /// Set the enabled run rules (Shewhart mode only).
+    #[must_use]
+    pub fn rules(mut self, rules: &[WesternElectricRule]) -> Self {
+        self.rules = rules.to_vec();
+        self
+    }
+
+    /// Set the series line color.
+    #[must_use]
+    pub fn line_color(mut self, color: Rgba) -> Self {
+        self.line_color = color;
+        self
+    }
+
+    /// Set the violation highlight color.
+    #[must_use]
+    pub fn violation_color(mut self, color: Rgba) -> Self {
+        self.violation_color = color;
+        self
+    }
+
+    /// Enable or disable 1σ/2σ zone lines.
+    #[must_use]
+    pub fn zones(mut self, show: bool) -> Self {
+        self.show_zones = show;
+        self
+    }
+
+    /// Set the margin around the plot.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the chart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are fewer than two observations, any value
+    /// is non-finite, the EWMA λ is outside (0, 1], or a sigma override or
+    /// limit width is not positive.
+    pub fn build(self) -> Result<Self> {
+        if self.data.len() < 2 {
+            return Err(Error::EmptyData);
+        }
+        if self.data.iter().any(|v| !v.is_finite()) {
+            return Err(Error::Rendering("Control chart data must be finite".to_string()));
+        }
+        if let ControlMode::Ewma { lambda } = self.mode {
+            if !(lambda > 0.0 && lambda <= 1.0) {
+                return Err(Error::Rendering(format!(
+                    "EWMA lambda must be in (0, 1], got {lambda}"
+                )));
+            }
+        }
+        let positive = |v: f32| v.is_finite() && v > 0.0;
+        if self.sigma.is_some_and(|s| !positive(s)) || !positive(self.limit_sigmas) {
+            return Err(Error::Rendering(
+                "Control chart sigma and limit width must be positive".to_string(),
+            ));
+        }
+        Ok(self)
+    }
+
+    /// Compute the center line, sigma and (steady-state) control limits.
+    #[must_use]
+    pub fn limits(&self) -> ControlLimits {
+        let n = self.data.len().max(1) as f32;
+        let center = self.center.unwrap_or_else(|| self.data.iter().sum::<f32>() / n);
+
+        let sigma = self.sigma.unwrap_or_else(|| {
+            let ranges = self.data.len().saturating_sub(1);
+            if ranges == 0 {
+                return 0.0;
+            }
+            let mr: f32 =
+                self.data.windows(2).map(|w| (w[1] - w[0]).abs()).sum::<f32>() / ranges as f32;
+            mr / D2_SPAN_2
+        });
+
+        let half_width = match self.mode {
+            ControlMode::Shewhart => self.limit_sigmas * sigma,
+            ControlMode::Ewma { lambda } => {
+                self.limit_sigmas * sigma * (lambda / (2.0 - lambda)).sqrt()
+            }
+        };
+
+        ControlLimits { center, sigma, ucl: center + half_width, lcl: center - half_width }
+    }
+
+    /// The charted statistic: raw data (Shewhart) or the EWMA series.
+    #[must_use]
+    pub fn statistic(&self) -> Vec<f32> {
+        match self.mode {
+            ControlMode::Shewhart => self.data.clone(),
+            ControlMode::Ewma { lambda } => {
+                let mut z = self.limits().center;
+                self.data
+                    .iter()
+                    .map(|&x| {
+                        z = lambda * x + (1.0 - lambda) * z;
+                        z
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Lower and upper control limits at observation `index`.
+    ///
+    /// Constant for Shewhart charts; widens towards the steady state for EWMA.
+    #[must_use]
+    pub fn limits_at(&self, index: usize) -> (f32, f32) {
+        let limits = self.limits();
+        match self.mode {
+            ControlMode::Shewhart => (limits.lcl, limits.ucl),
+            ControlMode::Ewma { lambda } => {
+                let exp = i32::try_from(2 * (index + 1)).unwrap_or(i32::MAX);
+                let factor = (1.0 - (1.0 - lambda).powi(exp)).sqrt();
+                let half = (limits.ucl - limits.center) * factor;
+                (limits.center - half, limits.center + half)
+            }
+        }
+    }
+
+    /// Evaluate the enabled run rules.
+    ///
+    /// EWMA charts only evaluate [`WesternElectricRule::BeyondLimits`]
+    /// against their time-varying limits, since the smoothed statistic is
+    /// autocorrelated by construction.
+    #[must_use]
+    pub fn violations(&self) -> Vec<RuleViolation> {
+        let stat = self.statistic();
+        let limits = self.limits();
+        let mut out = Vec::new();
+
+        if self.rules.contains(&WesternElectricRule::BeyondLimits) {
+            for (i, &v) in stat.iter().enumerate() {
+                let (lcl, ucl) = self.limits_at(i);
+                if v > ucl || v < lcl {
+                    out.push(RuleViolation { index: i, rule: WesternElectricRule::BeyondLimits });
+                }
+            }
+        }
+
+        if matches!(self.mode, ControlMode::Ewma { .. }) || limits.sigma <= 0.0 {
+            return out;
+        }
+
+        let z: Vec<f32> = stat.iter().map(|&v| (v - limits.center) / limits.sigma).collect();
+        let windowed = [
+            (WesternElectricRule::TwoOfThreeBeyond2Sigma, 3, 2, 2.0),
+            (WesternElectricRule::FourOfFiveBeyond1Sigma, 5, 4, 1.0),
+            (WesternElectricRule::EightSameSide, 8, 8, 0.0),
+        ];
+
+        for (rule, window, needed, bound) in windowed {
+            if !self.rules.contains(&rule) {
+                continue;
+            }
+            for end in window - 1..z.len() {
+                let slice = &z[end + 1 - window..=end];
+                let above = slice.iter().filter(|&&v| v > bound).count();
+                let below = slice.iter().filter(|&&v| v < -bound).count();
+                if above >= needed || below >= needed {
+                    out.push(RuleViolation { index: end, rule });
+                }
+            }
+        }
+
+        out.sort_by_key(|v| v.index);
+        out
+    }
+
+    /// Render the chart to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if scale creation fails.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let stat = self.statistic();
+        let limits = self.limits();
+        let n = stat.len();
+
+        let (mut y_min, mut y_max) = (limits.lcl, limits.ucl);
+        for &v in &stat {
+            y_min = y_min.min(v);
+            y_max = y_max.max(v);
+        }
+        let pad = ((y_max - y_min) * 0.05).max(1e-3);
+
+        let left = self.margin as f32;
+        let right = self.width.saturating_sub(self.margin) as f32;
+        let top = self.margin as f32;
+        let bottom = self.height.saturating_sub(self.margin) as f32;
+
+        let x_scale = LinearScale::new((0.0, n.saturating_sub(1).max(1) as f32), (left, right))?;
+        let y_scale = LinearScale::new((y_min - pad, y_max + pad), (bottom, top))?;
+
+        let hline = |fb: &mut Framebuffer, y: f32, color: Rgba, dashed: bool| {
+            let yy = y_scale.scale(y) as u32;
+            let step = if dashed { 8 } else { 1 };
+            let dash = if dashed { 5 } else { 1 };
+            let mut x = left as u32;
+            while (x as f32) < right {
+                fb.fill_rect(x, yy, dash.min(right as u32 - x), 1, color);
+                x += step;
+            }
+        };
+
+        if self.show_zones && matches!(self.mode, ControlMode::Shewhart) {
+            let zone = Rgba::rgb(220, 220, 220);
+            for k in [-2.0, -1.0, 1.0, 2.0] {
+                hline(fb, limits.center + k * limits.sigma, zone, false);
+            }
+        }
+
+        hline(fb, limits.center, self.center_color, false);
+
+        match self.mode {
+            ControlMode::Shewhart => {
+                hline(fb, limits.ucl, self.limit_color, true);
+                hline(fb, limits.lcl, self.limit_color, true);
+            }
+            ControlMode::Ewma { .. } => {
+                for i in 1..n {
+                    let (l0, u0) = self.limits_at(i - 1);
+                    let (l1, u1) = self.limits_at(i);
+                    let (x0, x1) = (x_scale.scale((i - 1) as f32), x_scale.scale(i as f32));
+                    let c = self.limit_color;
+                    draw_line_aa(fb, x0, y_scale.scale(u0), x1, y_scale.scale(u1), c);
+                    draw_line_aa(fb, x0, y_scale.scale(l0), x1, y_scale.scale(l1), c);
+                }
+            }
+        }
+
+        let points: Vec<(f32, f32)> = stat
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| (x_scale.scale(i as f32), y_scale.scale(v)))
+            .collect();
+        for pair in points.windows(2) {
+            draw_line_aa(fb, pair[0].0, pair[0].1, pair[1].0, pair[1].1, self.line_color);
+        }
+
+        let violations = self.violations();
+        for (i, &(px, py)) in points.iter().enumerate() {
+            let flagged = violations.iter().any(|v| v.index == i);
+            let (radius, color) =
+                if flagged { (4, self.violation_color) } else { (2, self.line_color) };
+            draw_circle(fb, px as i32, py as i32, radius, color);
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+impl batuta_common::display::WithDimensions for ControlChart {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn rules_for(chart: &ControlChart, rule: WesternElectricRule) -> Vec<usize> {
+        chart.violations().into_iter().filter(|v| v.rule == rule).map(|v| v.index).collect()
+    }
+
+    #[test]
+    fn test_control_limits_moving_range() {
+        // Alternating series: every moving range is 2, mean is 1.
+        let chart = ControlChart::new()
+            .data(&[0.0, 2.0, 0.0, 2.0])
+            .build()
+            .expect("builder should produce valid result");
+        let limits = chart.limits();
+        assert!((limits.center - 1.0).abs() < 1e-5);
+        assert!((limits.sigma - 2.0 / D2_SPAN_2).abs() < 1e-5);
+        assert!((limits.ucl - (1.0 + 3.0 * limits.sigma)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_control_rule_beyond_limits() {
+        let chart = ControlChart::new()
+            .data(&[0.0, 0.5, 0.0, 10.0, 0.0])
+            .center(0.0)
+            .sigma(1.0)
+            .build()
+            .expect("builder should produce valid result");
+        assert_eq!(rules_for(&chart, WesternElectricRule::BeyondLimits), vec![3]);
+    }
+
+    #[test]
+    fn test_control_run_rules() {
+        let chart = ControlChart::new()
+            .data(&[0.0, 2.5, 0.0, 2.5, 1.5, 1.5, 1.5, 1.5, 0.5, 0.5, 0.5])
+            .center(0.0)
+            .sigma(1.0)
+            .build()
+            .expect("builder should produce valid result");
+
+        assert_eq!(rules_for(&chart, WesternElectricRule::TwoOfThreeBeyond2Sigma), vec![3]);
+        assert!(rules_for(&chart, WesternElectricRule::FourOfFiveBeyond1Sigma).contains(&7));
+        assert_eq!(rules_for(&chart, WesternElectricRule::EightSameSide), vec![10]);
+        assert!(rules_for(&chart, WesternElectricRule::BeyondLimits).is_empty());
+    }
+
+    #[test]
+    fn test_control_rules_subset() {
+        let chart = ControlChart::new()
+            .data(&[1.0; 10])
+            .center(0.0)
+            .sigma(1.0)
+            .rules(&[WesternElectricRule::BeyondLimits])
+            .build()
+            .expect("builder should produce valid result");
+        assert!(chart.violations().is_empty());
+    }
+
+    #[test]
+    fn test_control_ewma() {
+        let chart = ControlChart::new()
+            .data(&[0.0, 0.0, 5.0, 5.0, 5.0])
+            .center(0.0)
+            .sigma(1.0)
+            .ewma(0.2)
+            .build()
+            .expect("builder should produce valid result");
+
+        let stat = chart.statistic();
+        assert!((stat[2] - 1.0).abs() < 1e-5);
+        assert!((stat[3] - 1.8).abs() < 1e-5);
+
+        // Limits widen over time towards the steady state.
+        let (_, u0) = chart.limits_at(0);
+        let (_, u9) = chart.limits_at(9);
+        assert!(u0 < u9 && u9 <= chart.limits().ucl + 1e-6);
+
+        let flagged: Vec<usize> = chart.violations().iter().map(|v| v.index).collect();
+        assert!(flagged.contains(&2));
+        assert!(!flagged.contains(&1));
+    }
+
+    #[test]
+    fn test_control_validation() {
+        assert!(ControlChart::new().data(&[1.0]).build().is_err());
+        assert!(ControlChart::new().data(&[1.0, f32::NAN]).build().is_err());
+        assert!(ControlChart::new().data(&[1.0, 2.0]).ewma(0.0).build().is_err());
+        assert!(ControlChart::new().data(&[1.0, 2.0]).sigma(-1.0).build().is_err());
+    }
+
+    #[test]
+    fn test_control_render() {
+        for mode in [ControlMode::Shewhart, ControlMode::Ewma { lambda: 0.3 }] {
+            let chart = ControlChart::new()
+                .data(&[1.0, 1.2, 0.9, 1.1, 5.0, 1.0, 0.8, 1.1])
+                .mode(mode)
+                .violation_color(Rgba::RED)
+                .dimensions(200, 120)
+                .build()
+                .expect("builder should produce valid result");
+            let fb = chart.to_framebuffer().expect("rendering should succeed");
+            assert_eq!(fb.width(), 200);
+        }
+    }
+}
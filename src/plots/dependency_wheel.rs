@@ -0,0 +1,448 @@
+//! Dependency wheel for module / crate / service dependency graphs.
+//!
+//! Places nodes around a circle, grouped into contiguous arcs by package,
+//! and draws each dependency as a chord bent through the centre. Chords take
+//! the color of the dependent's package and end in a dot on the dependency,
+//! so both direction and cross-package coupling read at a glance.
+//!
+//! # References
+//!
+//! - Holten, D. (2006). "Hierarchical Edge Bundles: Visualization of
+//!   Adjacency Relations in Hierarchical Data". *IEEE TVCG*, 12(5), 741-748.
+
+use std::collections::BTreeMap;
+use std::f32::consts::{PI, TAU};
+
+use crate::color::{categorical, Rgba};
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::output::{SvgEncoder, TextAnchor};
+use crate::render::{draw_circle, draw_line_aa, i32_px, TextStyle};
+
+/// Line segments used to approximate each chord in raster output.
+const CHORD_SEGMENTS: usize = 32;
+
+/// A node placed on the wheel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WheelNode {
+    /// Node name.
+    pub name: String,
+    /// Package (group) the node belongs to; empty if ungrouped.
+    pub group: String,
+    /// Angle in radians, clockwise from 12 o'clock.
+    pub angle: f32,
+}
+
+/// Builder for creating dependency-wheel plots.
+#[derive(Debug, Clone)]
+pub struct DependencyWheel {
+    /// Directed edges `(dependent, dependency)`; duplicates add weight.
+    edges: Vec<(String, String)>,
+    /// Package per node.
+    groups: BTreeMap<String, String>,
+    /// Explicit package colors.
+    group_colors: Vec<(String, Rgba)>,
+    /// Gap between packages, in node slots.
+    group_gap: f32,
+    /// Chord opacity (0.0 - 1.0).
+    chord_alpha: f32,
+    /// Node marker radius in pixels.
+    node_radius: i32,
+    /// Draw node labels.
+    show_labels: bool,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the wheel.
+    margin: u32,
+}
+
+impl Default for DependencyWheel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DependencyWheel {
+    /// Create a new dependency-wheel builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(600, 600);
+        Self {
+            edges: Vec::new(),
+            groups: BTreeMap::new(),
+            group_colors: Vec::new(),
+            group_gap: 1.0,
+            chord_alpha: 0.6,
+            node_radius: 3,
+            show_labels: true,
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Add a dependency: `from` depends on `to`.
+    #[must_use]
+    pub fn edge(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.edges.push((from.into(), to.into()));
+        self
+    }
+
+    /// Add dependencies from an edge list.
+    #[must_use]
+    pub fn edges(mut self, edges: &[(impl AsRef<str>, impl AsRef<str>)]) -> Self {
+        self.edges
+            .extend(edges.iter().map(|(a, b)| (a.as_ref().to_string(), b.as_ref().to_string())));
+        self
+    }
+
+    /// Assign a node to a package.
+    #[must_use]
+    pub fn group(mut self, node: impl Into<String>, package: impl Into<String>) -> Self {
+        self.groups.insert(node.into(), package.into());
+        self
+    }
+
+    /// Set the color for a package (others use the categorical palette).
+    #[must_use]
+    pub fn group_color(mut self, package: impl Into<String>, color: Rgba) -> Self {
+        let package = package.into();
+        self.group_colors.retain(|(p, _)| *p != package);
+        self.group_colors.push((package, color));
+        self
+    }
+
+    /// Set the gap between packages, in node slots.
+    #[must_use]
+    pub fn group_gap(mut self, slots: f32) -> Self {
+        self.group_gap = slots.max(0.0);
+        self
+    }
+
+    /// Set the chord opacity (0.0 - 1.0).
+    #[must_use]
+    pub fn chord_alpha(mut self, alpha: f32) -> Self {
+        self.chord_alpha = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the node marker radius in pixels.
+    #[must_use]
+    pub fn node_radius(mut self, radius: i32) -> Self {
+        self.node_radius = radius.max(1);
+        self
+    }
+
+    /// Enable or disable node labels.
+    #[must_use]
+    pub fn labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// Set the margin around the wheel.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the wheel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no nodes or a node name is empty.
+    pub fn build(self) -> Result<Self> {
+        if self.edges.is_empty() && self.groups.is_empty() {
+            return Err(Error::EmptyData);
+        }
+        if self.edges.iter().any(|(a, b)| a.is_empty() || b.is_empty()) {
+            return Err(Error::Rendering(
+                "Dependency wheel node names cannot be empty".to_string(),
+            ));
+        }
+        Ok(self)
+    }
+
+    /// Packages in wheel order (sorted, ungrouped nodes last).
+    #[must_use]
+    pub fn packages(&self) -> Vec<String> {
+        let mut packages: Vec<String> = Vec::new();
+        for node in self.layout() {
+            if !packages.contains(&node.group) {
+                packages.push(node.group);
+            }
+        }
+        packages
+    }
+
+    /// Color for a package.
+    #[must_use]
+    pub fn color_for(&self, package: &str) -> Rgba {
+        if let Some((_, color)) = self.group_colors.iter().find(|(p, _)| p == package) {
+            return *color;
+        }
+        let mut named: Vec<&String> = self.groups.values().collect();
+        named.sort();
+        named.dedup();
+        named.iter().position(|p| *p == package).map_or(Rgba::rgb(120, 120, 120), categorical)
+    }
+
+    /// Place nodes on the wheel, sorted by package then name.
+    #[must_use]
+    pub fn layout(&self) -> Vec<WheelNode> {
+        let mut names: Vec<&String> =
+            self.edges.iter().flat_map(|(a, b)| [a, b]).chain(self.groups.keys()).collect();
+        names.sort();
+        names.dedup();
+
+        let group_of = |name: &str| self.groups.get(name).cloned().unwrap_or_default();
+        // Ungrouped nodes ("" package) sort after every named package
+        names.sort_by_key(|n| {
+            let g = group_of(n);
+            (g.is_empty(), g)
+        });
+
+        let package_count = {
+            let mut gs: Vec<String> = names.iter().map(|n| group_of(n)).collect();
+            gs.dedup();
+            gs.len()
+        };
+        let slots = names.len() as f32 + self.group_gap * package_count as f32;
+        let step = if slots > 0.0 { TAU / slots } else { 0.0 };
+
+        let mut nodes = Vec::with_capacity(names.len());
+        let mut position = self.group_gap / 2.0;
+        let mut previous: Option<String> = None;
+        for name in names {
+            let group = group_of(name);
+            if previous.as_ref().is_some_and(|p| *p != group) {
+                position += self.group_gap;
+            }
+            nodes.push(WheelNode {
+                name: name.clone(),
+                group: group.clone(),
+                angle: position * step,
+            });
+            position += 1.0;
+            previous = Some(group);
+        }
+        nodes
+    }
+
+    /// Aggregated edges `(from index, to index, weight)` into [`Self::layout`].
+    fn weighted_edges(&self, nodes: &[WheelNode]) -> Vec<(usize, usize, usize)> {
+        let index = |name: &str| nodes.iter().position(|n| n.name == name);
+        let mut weights: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+        for (a, b) in &self.edges {
+            if let (Some(i), Some(j)) = (index(a), index(b)) {
+                if i != j {
+                    *weights.entry((i, j)).or_insert(0) += 1;
+                }
+            }
+        }
+        weights.into_iter().map(|((i, j), w)| (i, j, w)).collect()
+    }
+
+    /// Wheel centre and radius, leaving room for labels.
+    fn geometry(&self, nodes: &[WheelNode]) -> (f32, f32, f32) {
+        let label_room = if self.show_labels {
+            let font = TextStyle::current();
+            nodes.iter().map(|n| font.width(&n.name)).max().unwrap_or(0) as f32 + 8.0
+        } else {
+            0.0
+        };
+        let cx = self.width as f32 / 2.0;
+        let cy = self.height as f32 / 2.0;
+        let half = (self.width.min(self.height) as f32 / 2.0) - self.margin as f32;
+        (cx, cy, (half - label_room).max(half * 0.4).max(1.0))
+    }
+
+    fn point(cx: f32, cy: f32, r: f32, angle: f32) -> (f32, f32) {
+        (cx + r * angle.sin(), cy - r * angle.cos())
+    }
+
+    /// Render the wheel to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no nodes.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let nodes = self.layout();
+        if nodes.is_empty() {
+            return Err(Error::EmptyData);
+        }
+        let (cx, cy, radius) = self.geometry(&nodes);
+        let alpha = (self.chord_alpha * 255.0) as u8;
+
+        for (i, j, _) in self.weighted_edges(&nodes) {
+            let color = self.color_for(&nodes[i].group).with_alpha(alpha);
+            let p0 = Self::point(cx, cy, radius, nodes[i].angle);
+            let p1 = Self::point(cx, cy, radius, nodes[j].angle);
+            let mut prev = p0;
+            for s in 1..=CHORD_SEGMENTS {
+                let t = s as f32 / CHORD_SEGMENTS as f32;
+                let u = 1.0 - t;
+                // Quadratic Bézier with the wheel centre as control point
+                let x = u * u * p0.0 + 2.0 * u * t * cx + t * t * p1.0;
+                let y = u * u * p0.1 + 2.0 * u * t * cy + t * t * p1.1;
+                draw_line_aa(fb, prev.0, prev.1, x, y, color);
+                prev = (x, y);
+            }
+            draw_circle(fb, p1.0 as i32, p1.1 as i32, 2, color);
+        }
+
+        let font = TextStyle::current();
+        for node in &nodes {
+            let color = self.color_for(&node.group);
+            let (x, y) = Self::point(cx, cy, radius, node.angle);
+            draw_circle(fb, x as i32, y as i32, self.node_radius, color);
+
+            if self.show_labels {
+                let (lx, ly) = Self::point(cx, cy, radius + 6.0, node.angle);
+                let tw = i32_px(font.width(&node.name));
+                let x = if node.angle > PI { lx as i32 - tw } else { lx as i32 };
+                font.draw(fb, x, ly as i32 - i32_px(font.height()) / 2, &node.name);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+
+    /// Render as vector SVG; chord width grows with the edge multiplicity.
+    #[must_use]
+    pub fn to_svg(&self) -> SvgEncoder {
+        let nodes = self.layout();
+        let mut svg = SvgEncoder::new(self.width, self.height);
+        if nodes.is_empty() {
+            return svg;
+        }
+        let (cx, cy, radius) = self.geometry(&nodes);
+        let alpha = (self.chord_alpha * 255.0) as u8;
+
+        for (i, j, weight) in self.weighted_edges(&nodes) {
+            let color = self.color_for(&nodes[i].group).with_alpha(alpha);
+            let (x0, y0) = Self::point(cx, cy, radius, nodes[i].angle);
+            let (x1, y1) = Self::point(cx, cy, radius, nodes[j].angle);
+            let d = format!("M {x0:.2} {y0:.2} Q {cx:.2} {cy:.2} {x1:.2} {y1:.2}");
+            svg = svg.path(&d, None, Some(color), 1.0 + (weight as f32).ln());
+            svg = svg.circle(x1, y1, 2.0, color);
+        }
+
+        let font = TextStyle::current();
+        for node in &nodes {
+            let (x, y) = Self::point(cx, cy, radius, node.angle);
+            svg = svg.circle(x, y, self.node_radius as f32, self.color_for(&node.group));
+            if self.show_labels {
+                let (lx, ly) = Self::point(cx, cy, radius + 6.0, node.angle);
+                let anchor = if node.angle > PI { TextAnchor::End } else { TextAnchor::Start };
+                svg = svg.text_anchored(
+                    lx,
+                    ly + 4.0,
+                    &node.name,
+                    font.font_size(11.0),
+                    font.color,
+                    anchor,
+                );
+            }
+        }
+        svg
+    }
+}
+
+impl batuta_common::display::WithDimensions for DependencyWheel {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn sample() -> DependencyWheel {
+        DependencyWheel::new()
+            .edges(&[("viz", "core"), ("viz", "render"), ("render", "core"), ("cli", "viz")])
+            .edge("cli", "viz")
+            .group("core", "base")
+            .group("render", "base")
+            .group("viz", "app")
+    }
+
+    #[test]
+    fn test_wheel_layout_grouped() {
+        let wheel = sample();
+        let names: Vec<_> = wheel.layout().into_iter().map(|n| n.name).collect();
+        // "app" < "base", ungrouped ("cli") last
+        assert_eq!(names, vec!["viz", "core", "render", "cli"]);
+        assert_eq!(wheel.packages(), vec!["app", "base", ""]);
+
+        let angles: Vec<f32> = wheel.layout().iter().map(|n| n.angle).collect();
+        assert!(angles.windows(2).all(|w| w[1] > w[0]));
+        assert!(angles.iter().all(|a| (0.0..TAU).contains(a)));
+        // Within a package nodes are one slot apart; across packages the gap is added
+        let step = TAU / 7.0;
+        assert!((angles[2] - angles[1] - step).abs() < 1e-4);
+        assert!((angles[1] - angles[0] - 2.0 * step).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_wheel_edge_weights() {
+        let wheel = sample().edge("core", "core");
+        let nodes = wheel.layout();
+        let edges = wheel.weighted_edges(&nodes);
+        assert_eq!(edges.len(), 4);
+        assert!(edges.contains(&(3, 0, 2)));
+    }
+
+    #[test]
+    fn test_wheel_validation() {
+        assert!(DependencyWheel::new().build().is_err());
+        assert!(DependencyWheel::new().edge("", "a").build().is_err());
+        assert!(DependencyWheel::new().group("solo", "pkg").build().is_ok());
+    }
+
+    #[test]
+    fn test_wheel_colors() {
+        let wheel = sample().group_color("base", Rgba::RED);
+        assert_eq!(wheel.color_for("base"), Rgba::RED);
+        assert_eq!(wheel.color_for("app"), categorical(0));
+        assert_eq!(wheel.color_for(""), Rgba::rgb(120, 120, 120));
+    }
+
+    #[test]
+    fn test_wheel_render() {
+        let wheel = sample()
+            .group_color("app", Rgba::BLUE)
+            .dimensions(240, 240)
+            .build()
+            .expect("builder should produce valid result");
+        let fb = wheel.to_framebuffer().expect("rendering should succeed");
+        let blue = (0..fb.height())
+            .any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(Rgba::BLUE)));
+        assert!(blue);
+    }
+
+    #[test]
+    fn test_wheel_svg() {
+        let svg = sample().labels(false).to_svg().render();
+        assert_eq!(svg.matches("<path").count(), 4);
+        assert!(!svg.contains("<text"));
+    }
+}
@@ -0,0 +1,693 @@
+//! Flame graphs from folded stack samples.
+//!
+//! Parses the folded-stack text format emitted by `perf script` +
+//! `stackcollapse`, `inferno-collapse-*` and most sampling profilers
+//! (`frame;frame;frame count` per line), merges it into a [`StackTree`] and
+//! draws one box per frame, with width proportional to the number of samples
+//! the frame appears in. Roots sit at the bottom and callees stack upwards.
+//!
+//! Output is available as PNG (via [`Framebuffer`]), vector SVG, and
+//! interactive HTML with per-frame hover tooltips, click-to-zoom and search.
+//!
+//! # References
+//!
+//! - Gregg, B. (2016). "The Flame Graph". *Communications of the ACM*, 59(6), 48-57.
+
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::output::{FlameZoom, HtmlExporter, SvgElement, SvgEncoder, TextAnchor, ZoomFrame};
+use crate::render::{i32_px, TextStyle, GLYPH_ADVANCE};
+
+/// Font size used for SVG frame labels.
+const SVG_FONT_SIZE: f32 = 11.0;
+
+/// Label color on the palette-filled cells, kept dark for contrast.
+const CELL_TEXT_COLOR: Rgba = Rgba::rgb(30, 30, 30);
+
+/// A frame in a merged call-stack hierarchy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackNode {
+    /// Frame (function) name.
+    pub name: String,
+    /// Samples in which this frame appears (self + descendants).
+    pub total: u64,
+    /// Samples in which this frame is the leaf.
+    pub self_count: u64,
+    /// Callees, sorted by name.
+    pub children: Vec<StackNode>,
+}
+
+impl StackNode {
+    /// Create an empty node.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), total: 0, self_count: 0, children: Vec::new() }
+    }
+
+    /// Find a direct callee by name.
+    #[must_use]
+    pub fn child(&self, name: &str) -> Option<&StackNode> {
+        self.children
+            .binary_search_by(|c| c.name.as_str().cmp(name))
+            .ok()
+            .map(|i| &self.children[i])
+    }
+
+    /// Number of frame levels in this subtree, including this node.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        1 + self.children.iter().map(StackNode::depth).max().unwrap_or(0)
+    }
+
+    fn child_mut(&mut self, name: &str) -> &mut StackNode {
+        let index = match self.children.binary_search_by(|c| c.name.as_str().cmp(name)) {
+            Ok(i) => i,
+            Err(i) => {
+                self.children.insert(i, StackNode::new(name));
+                i
+            }
+        };
+        &mut self.children[index]
+    }
+}
+
+/// Merged call-stack samples, rooted at a synthetic `all` frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackTree {
+    /// Synthetic root frame.
+    root: StackNode,
+    /// Folded lines that could not be parsed.
+    skipped: usize,
+}
+
+impl Default for StackTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StackTree {
+    /// Create an empty tree.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { root: StackNode::new("all"), skipped: 0 }
+    }
+
+    /// Parse folded-stack text (`a;b;c 42` per line).
+    ///
+    /// Blank lines and `#` comments are ignored; lines without a trailing
+    /// integer count are skipped and counted in [`Self::skipped_lines`].
+    #[must_use]
+    pub fn from_folded(text: &str) -> Self {
+        let mut tree = Self::new();
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parsed = line.rsplit_once(char::is_whitespace).and_then(|(stack, count)| {
+                count.parse::<u64>().ok().map(|count| (stack.trim_end(), count))
+            });
+            match parsed {
+                Some((stack, count)) if !stack.is_empty() => {
+                    let frames: Vec<&str> = stack.split(';').filter(|f| !f.is_empty()).collect();
+                    tree.add_stack(&frames, count);
+                }
+                _ => tree.skipped += 1,
+            }
+        }
+        tree
+    }
+
+    /// Add `count` samples of a stack, outermost frame first.
+    pub fn add_stack(&mut self, frames: &[impl AsRef<str>], count: u64) {
+        let mut node = &mut self.root;
+        node.total += count;
+        for frame in frames {
+            node = node.child_mut(frame.as_ref());
+            node.total += count;
+        }
+        node.self_count += count;
+    }
+
+    /// Synthetic root frame.
+    #[must_use]
+    pub fn root(&self) -> &StackNode {
+        &self.root
+    }
+
+    /// Total number of samples.
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.root.total
+    }
+
+    /// Number of folded lines that could not be parsed.
+    #[must_use]
+    pub fn skipped_lines(&self) -> usize {
+        self.skipped
+    }
+
+    /// Find the node at a frame path below the root (empty path = root).
+    #[must_use]
+    pub fn find(&self, path: &[impl AsRef<str>]) -> Option<&StackNode> {
+        path.iter().try_fold(&self.root, |node, name| node.child(name.as_ref()))
+    }
+}
+
+/// Frame coloring scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FlamePalette {
+    /// Red/orange/yellow, hashed by frame name (classic CPU flame graph).
+    #[default]
+    Hot,
+    /// Blues and greens, hashed by frame name (memory/off-CPU style).
+    Cool,
+    /// Single fixed color.
+    Fixed(Rgba),
+}
+
+impl FlamePalette {
+    /// Color for a frame. Hashed palettes are stable across runs.
+    #[must_use]
+    pub fn color(self, name: &str) -> Rgba {
+        // FNV-1a so the same function keeps its color between graphs
+        let hash =
+            name.bytes().fold(0x811c_9dc5_u32, |h, b| (h ^ u32::from(b)).wrapping_mul(0x0100_0193));
+        let v1 = (hash & 0xff) as f32 / 255.0;
+        let v2 = ((hash >> 8) & 0xff) as f32 / 255.0;
+        let v3 = ((hash >> 16) & 0xff) as f32 / 255.0;
+        match self {
+            Self::Hot => {
+                Rgba::rgb((205.0 + 50.0 * v3) as u8, (230.0 * v1) as u8, (55.0 * v2) as u8)
+            }
+            Self::Cool => Rgba::rgb(
+                (50.0 + 60.0 * v2) as u8,
+                (130.0 + 80.0 * v1) as u8,
+                (190.0 + 60.0 * v3) as u8,
+            ),
+            Self::Fixed(color) => color,
+        }
+    }
+}
+
+/// A laid-out frame box.
+#[derive(Debug, Clone)]
+struct FrameRect {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    name: String,
+    total: u64,
+}
+
+/// Builder for creating flame graphs.
+#[derive(Debug, Clone)]
+pub struct FlameGraph {
+    /// Merged stack samples.
+    tree: StackTree,
+    /// Frame path of the subtree to zoom into (empty = whole tree).
+    zoom: Vec<String>,
+    /// Frame coloring.
+    palette: FlamePalette,
+    /// Optional title drawn above the graph.
+    title: Option<String>,
+    /// Preferred frame height in pixels (shrinks to fit deep stacks).
+    frame_height: u32,
+    /// Frames narrower than this many pixels are omitted.
+    min_width: f32,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the graph.
+    margin: u32,
+}
+
+impl Default for FlameGraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FlameGraph {
+    /// Create a new flame graph builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(1200, 600);
+        Self {
+            tree: StackTree::new(),
+            zoom: Vec::new(),
+            palette: FlamePalette::Hot,
+            title: None,
+            frame_height: 16,
+            min_width: 1.0,
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Set the samples from folded-stack text.
+    #[must_use]
+    pub fn folded(mut self, text: &str) -> Self {
+        self.tree = StackTree::from_folded(text);
+        self
+    }
+
+    /// Set the samples from an already merged tree.
+    #[must_use]
+    pub fn tree(mut self, tree: StackTree) -> Self {
+        self.tree = tree;
+        self
+    }
+
+    /// Zoom into the subtree at a frame path (outermost frame first).
+    #[must_use]
+    pub fn zoom(mut self, path: &[impl AsRef<str>]) -> Self {
+        self.zoom = path.iter().map(|f| f.as_ref().to_string()).collect();
+        self
+    }
+
+    /// Set the frame coloring.
+    #[must_use]
+    pub fn palette(mut self, palette: FlamePalette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Set the title.
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the preferred frame height in pixels.
+    #[must_use]
+    pub fn frame_height(mut self, height: u32) -> Self {
+        self.frame_height = height.max(1);
+        self
+    }
+
+    /// Omit frames narrower than `pixels`.
+    #[must_use]
+    pub fn min_width(mut self, pixels: f32) -> Self {
+        self.min_width = pixels.max(0.0);
+        self
+    }
+
+    /// Set the margin around the graph.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the flame graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no samples or the zoom path doesn't exist.
+    pub fn build(self) -> Result<Self> {
+        if self.tree.total() == 0 {
+            return Err(Error::EmptyData);
+        }
+        if self.tree.find(&self.zoom).is_none() {
+            return Err(Error::Rendering(format!(
+                "Flame graph zoom path '{}' not found",
+                self.zoom.join(";")
+            )));
+        }
+        Ok(self)
+    }
+
+    /// The merged stack samples.
+    #[must_use]
+    pub fn stack_tree(&self) -> &StackTree {
+        &self.tree
+    }
+
+    fn title_height(&self) -> f32 {
+        if self.title.is_some() {
+            (TextStyle::current().height() + 8) as f32
+        } else {
+            0.0
+        }
+    }
+
+    /// Plot area `(left, width, bottom, row height)` for the displayed root.
+    fn geometry(&self, root: &StackNode) -> (f32, f32, f32, f32) {
+        let left = self.margin as f32;
+        let plot_width = self.width.saturating_sub(2 * self.margin) as f32;
+        let top = self.margin as f32 + self.title_height();
+        let bottom = self.height.saturating_sub(self.margin) as f32;
+        let row = (self.frame_height as f32).min((bottom - top).max(1.0) / root.depth() as f32);
+        (left, plot_width, bottom, row)
+    }
+
+    /// Every frame below the displayed root with its sample range, for the
+    /// HTML zoom runtime.
+    fn zoom_frames(&self) -> FlameZoom {
+        let root = self.tree.find(&self.zoom);
+        let (left, width, bottom, row) = root.map_or((0.0, 0.0, 0.0, 0.0), |r| self.geometry(r));
+        let mut frames = Vec::new();
+        let mut stack: Vec<(&StackNode, usize, u64)> =
+            root.into_iter().map(|r| (r, 0, 0)).collect();
+        while let Some((node, depth, start)) = stack.pop() {
+            if node.total == 0 {
+                continue;
+            }
+            frames.push(ZoomFrame {
+                name: node.name.clone(),
+                tip: self.describe_frame(&node.name, node.total),
+                color: self.palette.color(&node.name),
+                depth,
+                start,
+                samples: node.total,
+            });
+            let mut child_start = start;
+            for child in &node.children {
+                stack.push((child, depth + 1, child_start));
+                child_start += child.total;
+            }
+        }
+        FlameZoom { left, width, bottom, row, min_width: self.min_width, frames }
+    }
+
+    /// Lay out frame boxes, root at the bottom.
+    fn frame_rects(&self) -> Vec<FrameRect> {
+        let Some(root) = self.tree.find(&self.zoom) else { return Vec::new() };
+        if root.total == 0 {
+            return Vec::new();
+        }
+
+        let (left, plot_width, bottom, row) = self.geometry(root);
+        let px_per_sample = plot_width / root.total as f32;
+
+        let mut rects = Vec::new();
+        let mut stack = vec![(root, left, 0usize)];
+        while let Some((node, x, depth)) = stack.pop() {
+            let width = node.total as f32 * px_per_sample;
+            if width < self.min_width {
+                continue;
+            }
+            rects.push(FrameRect {
+                x,
+                y: bottom - (depth + 1) as f32 * row,
+                width,
+                height: row,
+                name: node.name.clone(),
+                total: node.total,
+            });
+            let mut child_x = x;
+            for child in &node.children {
+                stack.push((child, child_x, depth + 1));
+                child_x += child.total as f32 * px_per_sample;
+            }
+        }
+        rects
+    }
+
+    /// Tooltip text for a frame.
+    fn describe(&self, rect: &FrameRect) -> String {
+        self.describe_frame(&rect.name, rect.total)
+    }
+
+    fn describe_frame(&self, name: &str, total: u64) -> String {
+        let pct = 100.0 * total as f64 / self.tree.total().max(1) as f64;
+        format!("{name} ({total} samples, {pct:.2}%)")
+    }
+
+    /// Render the flame graph to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the zoom path doesn't exist.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        if self.tree.find(&self.zoom).is_none() {
+            return Err(Error::EmptyData);
+        }
+
+        let font = TextStyle::current();
+        if let Some(title) = &self.title {
+            let title = font.ellipsize(title, self.width.saturating_sub(2 * self.margin));
+            let x = i32_px(self.width) / 2 - i32_px(font.width(&title)) / 2;
+            font.draw(fb, x, i32_px(self.margin), &title);
+        }
+
+        let font = font.with_color(CELL_TEXT_COLOR);
+
+        for rect in self.frame_rects() {
+            let x0 = rect.x.round() as u32;
+            let x1 = (rect.x + rect.width).round() as u32;
+            let w = x1.saturating_sub(x0).saturating_sub(1).max(1);
+            let h = (rect.height.round() as u32).saturating_sub(1).max(1);
+            fb.fill_rect(x0, rect.y.round() as u32, w, h, self.palette.color(&rect.name));
+
+            if rect.height >= font.height() as f32 + 2.0 {
+                let max_chars = (w.saturating_sub(4) / (GLYPH_ADVANCE * font.scale)) as usize;
+                if let Some(label) = fit_label(&rect.name, max_chars) {
+                    let ty = rect.y as i32 + (i32_px(h) - i32_px(font.height())) / 2;
+                    font.draw(fb, i32_px(x0) + 2, ty, &label);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+
+    /// Render as vector SVG with a hover tooltip on every frame.
+    #[must_use]
+    pub fn to_svg(&self) -> SvgEncoder {
+        let font = TextStyle::current();
+        let font_size = font.font_size(SVG_FONT_SIZE);
+        let mut svg = SvgEncoder::new(self.width, self.height);
+        if let Some(title) = &self.title {
+            svg = svg.text_anchored(
+                self.width as f32 / 2.0,
+                self.margin as f32 + font_size,
+                title,
+                font.font_size(SVG_FONT_SIZE + 2.0),
+                font.color,
+                TextAnchor::Middle,
+            );
+        }
+
+        for rect in self.frame_rects() {
+            let mut children = vec![SvgElement::Rect {
+                x: rect.x,
+                y: rect.y,
+                width: (rect.width - 0.5).max(0.5),
+                height: (rect.height - 1.0).max(0.5),
+                fill: self.palette.color(&rect.name),
+                stroke: None,
+                stroke_width: 0.0,
+            }];
+            let max_chars = ((rect.width - 6.0) / (font_size * 0.6)).max(0.0) as usize;
+            if rect.height >= font_size {
+                if let Some(label) = fit_label(&rect.name, max_chars) {
+                    children.push(SvgElement::Text {
+                        x: rect.x + 3.0,
+                        y: rect.y + rect.height / 2.0 + font_size * 0.35,
+                        text: label,
+                        font_size,
+                        fill: CELL_TEXT_COLOR,
+                        anchor: TextAnchor::Start,
+                    });
+                }
+            }
+            svg = svg.group(Some(&self.describe(&rect)), children);
+        }
+        svg
+    }
+
+    /// Export as a standalone interactive HTML page.
+    ///
+    /// Hovering a frame shows its name, sample count and share of all
+    /// samples. Clicking a frame zooms into it, `Esc` resets the zoom and `/`
+    /// highlights frames matching a search term. Without JavaScript the page
+    /// shows the static SVG.
+    #[must_use]
+    pub fn to_html(&self) -> HtmlExporter {
+        HtmlExporter::from_svg(&self.to_svg())
+            .title(self.title.clone().unwrap_or_else(|| "Flame Graph".to_string()))
+            .flame_zoom(self.zoom_frames())
+    }
+}
+
+/// Truncate a frame label to `max_chars`, or `None` if fewer than 3 fit.
+pub(super) fn fit_label(name: &str, max_chars: usize) -> Option<String> {
+    if max_chars < 3 {
+        return None;
+    }
+    let count = name.chars().count();
+    if count <= max_chars {
+        return Some(name.to_string());
+    }
+    let keep: String = name.chars().take(max_chars - 2).collect();
+    Some(format!("{keep}.."))
+}
+
+impl batuta_common::display::WithDimensions for FlameGraph {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    const FOLDED: &str = "\
+main;parse;lex 30
+main;parse 10
+main;render;draw_text 50
+# comment
+
+main;render;draw_text 10
+idle 0
+broken line
+";
+
+    #[test]
+    fn test_stack_tree_from_folded() {
+        let tree = StackTree::from_folded(FOLDED);
+        assert_eq!(tree.total(), 100);
+        assert_eq!(tree.skipped_lines(), 1);
+
+        let parse = tree.find(&["main", "parse"]).expect("parse frame should exist");
+        assert_eq!(parse.total, 40);
+        assert_eq!(parse.self_count, 10);
+        assert_eq!(tree.find(&["main", "render", "draw_text"]).map(|n| n.total), Some(60));
+        assert!(tree.find(&["main", "missing"]).is_none());
+        // all -> main -> render -> draw_text
+        assert_eq!(tree.root().depth(), 4);
+    }
+
+    #[test]
+    fn test_stack_tree_children_sorted() {
+        let tree = StackTree::from_folded("a;z 1\na;b 1\na;m 1\n");
+        let names: Vec<_> = tree
+            .find(&["a"])
+            .map(|n| n.children.iter().map(|c| c.name.clone()).collect())
+            .unwrap_or_default();
+        assert_eq!(names, vec!["b", "m", "z"]);
+    }
+
+    #[test]
+    fn test_flame_validation() {
+        assert!(FlameGraph::new().build().is_err());
+        assert!(FlameGraph::new().folded(FOLDED).zoom(&["nope"]).build().is_err());
+        assert!(FlameGraph::new().folded(FOLDED).zoom(&["main", "render"]).build().is_ok());
+    }
+
+    #[test]
+    fn test_flame_layout_widths() {
+        let graph = FlameGraph::new().folded(FOLDED).margin(0).dimensions(1000, 200);
+        let rects = graph.frame_rects();
+        let width_of = |name: &str| rects.iter().find(|r| r.name == name).map(|r| r.width);
+        assert_eq!(width_of("all"), Some(1000.0));
+        assert_eq!(width_of("render"), Some(600.0));
+        assert_eq!(width_of("lex"), Some(300.0));
+        // Root sits at the bottom.
+        let all = rects.iter().find(|r| r.name == "all").expect("root should be laid out");
+        assert!(rects.iter().all(|r| r.y <= all.y));
+    }
+
+    #[test]
+    fn test_flame_zoom_and_min_width() {
+        let graph = FlameGraph::new()
+            .folded(FOLDED)
+            .zoom(&["main", "parse"])
+            .margin(0)
+            .dimensions(400, 100);
+        let rects = graph.frame_rects();
+        assert_eq!(rects[0].name, "parse");
+        assert!((rects[0].width - 400.0).abs() < 1e-3);
+
+        let pruned =
+            FlameGraph::new().folded(FOLDED).min_width(500.0).margin(0).dimensions(1000, 100);
+        assert!(pruned.frame_rects().iter().all(|r| r.width >= 500.0));
+    }
+
+    #[test]
+    fn test_flame_palette_stable() {
+        assert_eq!(FlamePalette::Hot.color("main"), FlamePalette::Hot.color("main"));
+        assert!(FlamePalette::Hot.color("main").r >= 205);
+        assert_eq!(FlamePalette::Fixed(Rgba::RED).color("x"), Rgba::RED);
+    }
+
+    #[test]
+    fn test_fit_label() {
+        assert_eq!(fit_label("render", 10).as_deref(), Some("render"));
+        assert_eq!(fit_label("render", 5).as_deref(), Some("ren.."));
+        assert_eq!(fit_label("render", 2), None);
+    }
+
+    #[test]
+    fn test_flame_render() {
+        let graph = FlameGraph::new()
+            .folded(FOLDED)
+            .palette(FlamePalette::Fixed(Rgba::RED))
+            .title("cpu")
+            .dimensions(300, 120)
+            .build()
+            .expect("builder should produce valid result");
+        let fb = graph.to_framebuffer().expect("rendering should succeed");
+        let red = (0..fb.height())
+            .any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(Rgba::RED)));
+        assert!(red);
+    }
+
+    #[test]
+    fn test_flame_svg_and_html() {
+        let graph = FlameGraph::new().folded(FOLDED).dimensions(600, 200);
+        let svg = graph.to_svg().render();
+        assert!(svg.contains("<title>draw_text (60 samples, 60.00%)</title>"));
+
+        let html = graph.to_html().to_html();
+        assert!(html.contains("<title>Flame Graph</title>"));
+        assert!(html.contains("render (60 samples"));
+        assert!(html.contains("id=\"tv-flame-data\""));
+    }
+
+    #[test]
+    fn test_flame_zoom_frames() {
+        let graph = FlameGraph::new().folded(FOLDED).min_width(500.0).dimensions(600, 200);
+        let zoom = graph.zoom_frames();
+        // min_width only applies when drawing: every frame is embedded
+        let names: Vec<_> = zoom.frames.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names.len(), 6);
+        assert!(names.contains(&"lex") && names.contains(&"draw_text"));
+        assert_eq!((zoom.frames[0].depth, zoom.frames[0].start), (0, 0));
+
+        // Siblings are laid out side by side in name order
+        let main = graph.stack_tree().root().child("main").expect("main frame");
+        let starts: Vec<_> = main
+            .children
+            .iter()
+            .map(|c| {
+                let frame = zoom.frames.iter().find(|f| f.name == c.name).expect("frame");
+                (frame.depth, frame.start)
+            })
+            .collect();
+        assert_eq!(starts[0], (2, 0));
+        assert_eq!(starts[1], (2, main.children[0].total));
+    }
+}
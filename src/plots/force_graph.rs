@@ -152,11 +152,12 @@ impl ForceGraph {
     /// Create a new force-directed graph.
     #[must_use]
     pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(600, 400);
         Self {
             nodes: Vec::new(),
             edges: Vec::new(),
-            width: 600,
-            height: 400,
+            width,
+            height,
             margin: 40,
             iterations: 100,
             repulsion: 10000.0,
@@ -0,0 +1,383 @@
+//! Plot grid layout.
+//!
+//! Arranges independently rendered panels in a rows × columns grid and
+//! composites them into a single figure. Row heights and column widths can be
+//! weighted so that composite figures (e.g. a scatter plot with marginal
+//! strips) give more room to the main panel.
+
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::output::{CursorPanel, HtmlExporter, LinkedBrush, SvgEncoder};
+
+/// Pixel rectangle of a grid cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellRect {
+    /// Left edge in pixels.
+    pub x: u32,
+    /// Top edge in pixels.
+    pub y: u32,
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+}
+
+/// Builder for a grid of plot panels.
+#[derive(Debug, Clone)]
+pub struct PlotGrid {
+    /// Relative row heights (one entry per row).
+    row_weights: Vec<f32>,
+    /// Relative column widths (one entry per column).
+    col_weights: Vec<f32>,
+    /// Gap between adjacent cells in pixels.
+    gap: u32,
+    /// Background color of the composed figure.
+    background: Rgba,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the grid.
+    margin: u32,
+}
+
+impl PlotGrid {
+    /// Create a grid with equally sized cells.
+    #[must_use]
+    pub fn new(rows: usize, cols: usize) -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 800);
+        Self {
+            row_weights: vec![1.0; rows],
+            col_weights: vec![1.0; cols],
+            gap: 4,
+            background: Rgba::WHITE,
+            width,
+            height,
+            margin: 0,
+        }
+    }
+
+    /// Set relative row heights (also sets the row count).
+    #[must_use]
+    pub fn row_heights(mut self, weights: &[f32]) -> Self {
+        self.row_weights = weights.to_vec();
+        self
+    }
+
+    /// Set relative column widths (also sets the column count).
+    #[must_use]
+    pub fn col_widths(mut self, weights: &[f32]) -> Self {
+        self.col_weights = weights.to_vec();
+        self
+    }
+
+    /// Set the gap between adjacent cells in pixels.
+    #[must_use]
+    pub fn gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the background color.
+    #[must_use]
+    pub fn background(mut self, color: Rgba) -> Self {
+        self.background = color;
+        self
+    }
+
+    /// Set the margin around the grid.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the grid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no rows or columns, a weight is not
+    /// positive, or the gaps and margin leave no room for the cells.
+    pub fn build(self) -> Result<Self> {
+        if self.row_weights.is_empty() || self.col_weights.is_empty() {
+            return Err(Error::EmptyData);
+        }
+        if self.row_weights.iter().chain(&self.col_weights).any(|w| !w.is_finite() || *w <= 0.0) {
+            return Err(Error::Rendering("Grid weights must be positive".to_string()));
+        }
+        if self.inner_width() < self.col_weights.len() as u32
+            || self.inner_height() < self.row_weights.len() as u32
+        {
+            return Err(Error::InvalidDimensions { width: self.width, height: self.height });
+        }
+        Ok(self)
+    }
+
+    /// Number of rows.
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.row_weights.len()
+    }
+
+    /// Number of columns.
+    #[must_use]
+    pub fn cols(&self) -> usize {
+        self.col_weights.len()
+    }
+
+    /// Figure width in pixels.
+    #[must_use]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Figure height in pixels.
+    #[must_use]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    fn inner_width(&self) -> u32 {
+        let gaps = self.gap * (self.col_weights.len().saturating_sub(1) as u32);
+        self.width.saturating_sub(2 * self.margin + gaps)
+    }
+
+    fn inner_height(&self) -> u32 {
+        let gaps = self.gap * (self.row_weights.len().saturating_sub(1) as u32);
+        self.height.saturating_sub(2 * self.margin + gaps)
+    }
+
+    /// Split `total` pixels among `weights`, returning (offset, size) pairs.
+    ///
+    /// Offsets are rounded from cumulative weights so sizes always sum to
+    /// `total` regardless of rounding.
+    fn split(total: u32, weights: &[f32], gap: u32, start: u32) -> Vec<(u32, u32)> {
+        let sum: f32 = weights.iter().sum();
+        let mut acc = 0.0;
+        let mut prev = 0;
+        let mut out = Vec::with_capacity(weights.len());
+        for (i, w) in weights.iter().enumerate() {
+            acc += w;
+            let edge = (acc / sum * total as f32).round() as u32;
+            out.push((start + prev + gap * i as u32, edge - prev));
+            prev = edge;
+        }
+        out
+    }
+
+    /// Get the pixel rectangle of a cell.
+    #[must_use]
+    pub fn cell_rect(&self, row: usize, col: usize) -> Option<CellRect> {
+        let (y, height) =
+            *Self::split(self.inner_height(), &self.row_weights, self.gap, self.margin).get(row)?;
+        let (x, width) =
+            *Self::split(self.inner_width(), &self.col_weights, self.gap, self.margin).get(col)?;
+        Some(CellRect { x, y, width, height })
+    }
+
+    /// Copy a rendered panel into a cell, clipping it to the cell bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cell is outside the grid.
+    pub fn place(
+        &self,
+        fb: &mut Framebuffer,
+        row: usize,
+        col: usize,
+        panel: &Framebuffer,
+    ) -> Result<()> {
+        let rect = self
+            .cell_rect(row, col)
+            .ok_or_else(|| Error::Rendering(format!("Grid cell ({row}, {col}) out of range")))?;
+
+        if panel.width() <= rect.width && panel.height() <= rect.height {
+            fb.blit(panel, rect.x, rect.y);
+            return Ok(());
+        }
+
+        let mut clipped = Framebuffer::new(rect.width.max(1), rect.height.max(1))?;
+        clipped.clear(self.background);
+        clipped.blit(panel, 0, 0);
+        fb.blit(&clipped, rect.x, rect.y);
+        Ok(())
+    }
+
+    /// Compose a figure by rendering every cell with `render_cell`.
+    ///
+    /// The callback receives `(row, col, width, height)` and returns the
+    /// panel for that cell, or `None` to leave it blank.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the figure cannot be allocated or a cell callback
+    /// fails.
+    pub fn compose<F>(&self, mut render_cell: F) -> Result<Framebuffer>
+    where
+        F: FnMut(usize, usize, u32, u32) -> Result<Option<Framebuffer>>,
+    {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(self.background);
+
+        for row in 0..self.rows() {
+            for col in 0..self.cols() {
+                let Some(rect) = self.cell_rect(row, col) else { continue };
+                if rect.width == 0 || rect.height == 0 {
+                    continue;
+                }
+                if let Some(panel) = render_cell(row, col, rect.width, rect.height)? {
+                    self.place(&mut fb, row, col, &panel)?;
+                }
+            }
+        }
+
+        Ok(fb)
+    }
+
+    /// Compose the grid into an HTML page with linked brushing.
+    ///
+    /// Like [`compose`](Self::compose), but each cell also returns the
+    /// [`CursorPanel`] of its chart in cell coordinates (e.g. from
+    /// [`ScatterPlot::cursor_panel`](super::ScatterPlot::cursor_panel)).
+    /// Dragging over points in one cell highlights the same rows in every
+    /// cell whose series share the data source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if composing fails or the figure cannot be encoded.
+    pub fn to_html<F>(&self, mut render_cell: F) -> Result<HtmlExporter>
+    where
+        F: FnMut(usize, usize, u32, u32) -> Result<Option<(Framebuffer, CursorPanel)>>,
+    {
+        let mut panels = Vec::new();
+        let fb = self.compose(|row, col, width, height| {
+            let Some((figure, panel)) = render_cell(row, col, width, height)? else {
+                return Ok(None);
+            };
+            if let Some(rect) = self.cell_rect(row, col) {
+                panels.push(panel.offset(rect.x as f32, rect.y as f32));
+            }
+            Ok(Some(figure))
+        })?;
+        let brush = panels.into_iter().fold(LinkedBrush::new(), LinkedBrush::panel);
+        Ok(HtmlExporter::from_svg(&SvgEncoder::from_framebuffer(&fb)?).linked_brush(brush))
+    }
+}
+
+impl batuta_common::display::WithDimensions for PlotGrid {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plots::ScatterPlot;
+    use batuta_common::display::WithDimensions;
+
+    #[test]
+    fn test_grid_equal_cells() {
+        let grid = PlotGrid::new(2, 2)
+            .gap(10)
+            .dimensions(210, 110)
+            .build()
+            .expect("builder should produce valid result");
+
+        assert_eq!(grid.cell_rect(0, 0), Some(CellRect { x: 0, y: 0, width: 100, height: 50 }));
+        assert_eq!(grid.cell_rect(1, 1), Some(CellRect { x: 110, y: 60, width: 100, height: 50 }));
+        assert_eq!(grid.cell_rect(2, 0), None);
+    }
+
+    #[test]
+    fn test_grid_weighted_cells() {
+        let grid = PlotGrid::new(1, 1)
+            .col_widths(&[3.0, 1.0])
+            .gap(0)
+            .margin(5)
+            .dimensions(410, 100)
+            .build()
+            .expect("builder should produce valid result");
+
+        let main = grid.cell_rect(0, 0).expect("cell should exist");
+        let side = grid.cell_rect(0, 1).expect("cell should exist");
+        assert_eq!((main.x, main.width), (5, 300));
+        assert_eq!((side.x, side.width), (305, 100));
+    }
+
+    #[test]
+    fn test_grid_validation() {
+        assert!(PlotGrid::new(0, 2).build().is_err());
+        assert!(PlotGrid::new(1, 1).row_heights(&[0.0]).build().is_err());
+        assert!(PlotGrid::new(3, 3).gap(50).dimensions(100, 100).build().is_err());
+    }
+
+    #[test]
+    fn test_grid_compose() {
+        let grid = PlotGrid::new(1, 2)
+            .gap(2)
+            .background(Rgba::BLACK)
+            .dimensions(22, 10)
+            .build()
+            .expect("builder should produce valid result");
+
+        let fb = grid
+            .compose(|_, col, w, h| {
+                if col == 1 {
+                    return Ok(None);
+                }
+                let mut panel = Framebuffer::new(w, h)?;
+                panel.clear(Rgba::RED);
+                Ok(Some(panel))
+            })
+            .expect("compose should succeed");
+
+        assert_eq!(fb.get_pixel(0, 0), Some(Rgba::RED));
+        assert_eq!(fb.get_pixel(10, 5), Some(Rgba::BLACK));
+        assert_eq!(fb.get_pixel(15, 5), Some(Rgba::BLACK));
+    }
+
+    #[test]
+    fn test_grid_to_html_links_cells() {
+        let grid = PlotGrid::new(1, 2)
+            .gap(10)
+            .dimensions(210, 100)
+            .build()
+            .expect("builder should produce valid result");
+        let (x, y, z) = ([1.0, 2.0, 3.0], [3.0, 1.0, 2.0], [0.5, 0.1, 0.9]);
+
+        let html = grid
+            .to_html(|_, col, w, h| {
+                let other = if col == 0 { &y } else { &z };
+                let plot = ScatterPlot::new().x(&x).y(other).margin(10).dimensions(w, h).build()?;
+                Ok(Some((plot.to_framebuffer()?, plot.cursor_panel()?.source("runs"))))
+            })
+            .expect("export should succeed")
+            .to_html();
+
+        assert!(html.contains(r#"<script type="application/json" id="tv-brush-data">"#));
+        // The right cell's panel is shifted by its cell offset (100 + gap)
+        assert!(html.contains(r#""rect":[10,10,80,80]"#));
+        assert!(html.contains(r#""rect":[120,10,80,80]"#));
+        assert_eq!(html.matches(r#""source":"runs","rows":[0,1,2]"#).count(), 2);
+    }
+
+    #[test]
+    fn test_grid_place_clips_oversized_panel() {
+        let grid = PlotGrid::new(1, 2)
+            .gap(0)
+            .dimensions(20, 10)
+            .build()
+            .expect("builder should produce valid result");
+        let mut fb = Framebuffer::new(20, 10).expect("framebuffer creation should succeed");
+        fb.clear(Rgba::WHITE);
+        let mut big = Framebuffer::new(20, 10).expect("framebuffer creation should succeed");
+        big.clear(Rgba::RED);
+
+        grid.place(&mut fb, 0, 0, &big).expect("place should succeed");
+        assert_eq!(fb.get_pixel(9, 0), Some(Rgba::RED));
+        assert_eq!(fb.get_pixel(10, 0), Some(Rgba::WHITE));
+    }
+}
@@ -8,11 +8,15 @@
 //! - Borland, D., & Taylor, R. M. (2007). "Rainbow Color Map (Still) Considered Harmful."
 //!   IEEE Computer Graphics and Applications.
 
+use super::colorbar::Colorbar;
 use crate::color::Rgba;
 use crate::error::{Error, Result};
 use crate::framebuffer::Framebuffer;
 use crate::scale::{ColorScale, Scale};
 
+/// Gap in pixels between the cells and the colorbar.
+const COLORBAR_GAP: u32 = 10;
+
 /// Color palette type for heatmaps.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum HeatmapPalette {
@@ -31,6 +35,20 @@ pub enum HeatmapPalette {
     Greyscale,
 }
 
+impl HeatmapPalette {
+    /// Color scale for this palette over a value domain.
+    pub(crate) fn color_scale(self, domain: (f32, f32)) -> Option<ColorScale> {
+        match self {
+            Self::Viridis => ColorScale::viridis(domain),
+            Self::Blues => ColorScale::blues(domain),
+            Self::RedBlue => ColorScale::red_blue(domain),
+            Self::Magma => ColorScale::magma(domain),
+            Self::Heat => ColorScale::heat(domain),
+            Self::Greyscale => ColorScale::greyscale(domain),
+        }
+    }
+}
+
 /// Builder for creating heatmaps.
 #[derive(Debug, Clone)]
 pub struct Heatmap {
@@ -44,6 +62,10 @@ pub struct Heatmap {
     palette: HeatmapPalette,
     /// Custom color scale (overrides palette if set).
     custom_scale: Option<ColorScale>,
+    /// Map palette colors logarithmically.
+    log_scale: bool,
+    /// Colorbar legend.
+    colorbar: Option<Colorbar>,
     /// Output width in pixels.
     width: u32,
     /// Output height in pixels.
@@ -68,14 +90,17 @@ impl Heatmap {
     /// Create a new heatmap builder.
     #[must_use]
     pub fn new() -> Self {
+        let style = crate::style::current();
         Self {
             data: Vec::new(),
             rows: 0,
             cols: 0,
-            palette: HeatmapPalette::default(),
+            palette: style.palette,
             custom_scale: None,
-            width: 800,
-            height: 600,
+            log_scale: false,
+            colorbar: None,
+            width: style.width,
+            height: style.height,
             margin: 40,
             show_borders: true,
             border_color: Rgba::rgb(200, 200, 200),
@@ -121,6 +146,22 @@ impl Heatmap {
         self
     }
 
+    /// Map the palette logarithmically (requires positive data).
+    ///
+    /// Ignored when a custom color scale is set.
+    #[must_use]
+    pub fn log_scale(mut self, enabled: bool) -> Self {
+        self.log_scale = enabled;
+        self
+    }
+
+    /// Draw a colorbar legend beside (vertical) or below (horizontal) the cells.
+    #[must_use]
+    pub fn colorbar(mut self, colorbar: Colorbar) -> Self {
+        self.colorbar = Some(colorbar);
+        self
+    }
+
     /// Set the margin around the heatmap.
     #[must_use]
     pub fn margin(mut self, margin: u32) -> Self {
@@ -153,7 +194,8 @@ impl Heatmap {
     ///
     /// # Errors
     ///
-    /// Returns an error if data is empty or dimensions don't match.
+    /// Returns an error if data is empty, dimensions don't match, or a log
+    /// scale is requested for non-positive data.
     pub fn build(self) -> Result<Self> {
         if self.data.is_empty() {
             return Err(Error::EmptyData);
@@ -171,6 +213,12 @@ impl Heatmap {
             return Err(Error::DataLengthMismatch { x_len: expected_len, y_len: self.data.len() });
         }
 
+        if self.log_scale && self.custom_scale.is_none() && self.data_extent().0 <= 0.0 {
+            return Err(Error::ScaleDomain(
+                "Log heatmap scale requires positive values".to_string(),
+            ));
+        }
+
         Ok(self)
     }
 
@@ -193,13 +241,11 @@ impl Heatmap {
             return Some(custom.clone());
         }
 
-        match self.palette {
-            HeatmapPalette::Viridis => ColorScale::viridis((min, max)),
-            HeatmapPalette::Blues => ColorScale::blues((min, max)),
-            HeatmapPalette::RedBlue => ColorScale::red_blue((min, max)),
-            HeatmapPalette::Magma => ColorScale::magma((min, max)),
-            HeatmapPalette::Heat => ColorScale::heat((min, max)),
-            HeatmapPalette::Greyscale => ColorScale::greyscale((min, max)),
+        let scale = self.palette.color_scale((min, max))?;
+        if self.log_scale {
+            scale.logarithmic().ok()
+        } else {
+            Some(scale)
         }
     }
 
@@ -211,9 +257,19 @@ impl Heatmap {
     pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
         let color_scale = self.create_color_scale().ok_or(Error::EmptyData)?;
 
-        // Calculate plot area
-        let plot_width = self.width - 2 * self.margin;
-        let plot_height = self.height - 2 * self.margin;
+        // Calculate plot area, leaving room for the colorbar
+        let mut plot_width = self.width - 2 * self.margin;
+        let mut plot_height = self.height - 2 * self.margin;
+        let colorbar = self.colorbar.as_ref().map(|bar| {
+            let ticks = bar.ticks(&color_scale);
+            let space = bar.footprint(&ticks) + COLORBAR_GAP;
+            if bar.is_vertical() {
+                plot_width = plot_width.saturating_sub(space);
+            } else {
+                plot_height = plot_height.saturating_sub(space);
+            }
+            (bar, ticks)
+        });
 
         // Calculate cell dimensions
         if self.cols == 0 || self.rows == 0 {
@@ -242,6 +298,17 @@ impl Heatmap {
             }
         }
 
+        if let Some((bar, ticks)) = colorbar {
+            let grid_w = cell_width * self.cols as u32;
+            let grid_h = cell_height * self.rows as u32;
+            let (x, y, length) = if bar.is_vertical() {
+                (self.margin + grid_w + COLORBAR_GAP, self.margin, grid_h)
+            } else {
+                (self.margin, self.margin + grid_h + COLORBAR_GAP, grid_w)
+            };
+            bar.draw(fb, x, y, length, |t| color_scale.at(t), &ticks);
+        }
+
         Ok(())
     }
 
@@ -489,4 +556,34 @@ mod tests {
         let fb = heatmap.to_framebuffer();
         assert!(fb.is_ok());
     }
+
+    #[test]
+    fn test_heatmap_log_scale() {
+        let data = vec![1.0, 10.0, 100.0, 1000.0];
+        let heatmap = Heatmap::new().data(&data, 1, 4).log_scale(true).build().expect("valid");
+        let scale = heatmap.create_color_scale().expect("scale");
+        assert!(scale.is_log());
+        assert!((scale.normalize(10.0) - 1.0 / 3.0).abs() < 1e-4);
+
+        assert!(Heatmap::new().data(&[0.0, 1.0], 1, 2).log_scale(true).build().is_err());
+    }
+
+    #[test]
+    fn test_heatmap_colorbar_reserves_space() {
+        let data = vec![0.0, 1.0, 2.0, 3.0];
+        let base = Heatmap::new().data(&data, 2, 2).borders(false).margin(10).dimensions(200, 100);
+        let plain = base.clone().build().expect("valid").to_framebuffer().expect("render");
+        let with_bar = base
+            .colorbar(Colorbar::new().label("value"))
+            .build()
+            .expect("valid")
+            .to_framebuffer()
+            .expect("render");
+        // Without a colorbar the cells reach the right margin
+        assert_ne!(plain.get_pixel(185, 50), Some(Rgba::WHITE));
+        assert_eq!(with_bar.get_pixel(185, 50), Some(Rgba::WHITE));
+        // Only the top-left cell and the bottom of the bar use the lowest color
+        let low = ColorScale::viridis((0.0, 3.0)).expect("viridis").at(0.0);
+        assert!((100..200).any(|x| with_bar.get_pixel(x, 89) == Some(low)));
+    }
 }
@@ -0,0 +1,355 @@
+//! Dense pixel-oriented time series ("heatstrip").
+//!
+//! Each metric is a single row in which every pixel column is one sample
+//! (or one bucket of samples when there are more samples than pixels) and
+//! color encodes the value. Days of one-second metrics fit in a row a few
+//! pixels tall, so many metrics can be compared at a glance.
+//!
+//! # References
+//!
+//! - Keim, D. A. (2000). Designing pixel-oriented visualization techniques:
+//!   theory and applications. *IEEE TVCG*, 6(1), 59-78.
+
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{i32_px, TextStyle};
+use crate::scale::{ColorScale, Scale};
+
+/// How samples sharing a pixel column are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StripAggregation {
+    /// Average of the bucket.
+    #[default]
+    Mean,
+    /// Largest value, so short spikes stay visible.
+    Max,
+    /// Smallest value.
+    Min,
+}
+
+impl StripAggregation {
+    /// Combine finite values; `None` when there are none.
+    fn apply(self, values: &[f32]) -> Option<f32> {
+        let mut finite = values.iter().copied().filter(|v| v.is_finite()).peekable();
+        finite.peek()?;
+        Some(match self {
+            Self::Mean => {
+                let (sum, n) = finite.fold((0.0, 0), |(s, n), v| (s + v, n + 1));
+                sum / n as f32
+            }
+            Self::Max => finite.fold(f32::MIN, f32::max),
+            Self::Min => finite.fold(f32::MAX, f32::min),
+        })
+    }
+}
+
+/// Builder for heatstrip plots.
+#[derive(Debug, Clone)]
+pub struct Heatstrip {
+    /// `(label, samples)` per row.
+    rows: Vec<(String, Vec<f32>)>,
+    /// Bucket aggregation when samples exceed pixel columns.
+    aggregation: StripAggregation,
+    /// Color scale over normalized values 0-1 (None = viridis).
+    color_scale: Option<ColorScale>,
+    /// Explicit value range shared by all rows.
+    value_range: Option<(f32, f32)>,
+    /// Normalize each row to its own extent.
+    normalize_rows: bool,
+    /// Row height in pixels.
+    row_height: u32,
+    /// Gap between rows in pixels.
+    row_gap: u32,
+    /// Color for missing samples.
+    missing_color: Rgba,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the plot.
+    margin: u32,
+}
+
+impl Default for Heatstrip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Heatstrip {
+    /// Create a new heatstrip builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 200);
+        Self {
+            rows: Vec::new(),
+            aggregation: StripAggregation::Mean,
+            color_scale: None,
+            value_range: None,
+            normalize_rows: false,
+            row_height: 12,
+            row_gap: 2,
+            missing_color: Rgba::rgb(235, 235, 235),
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Add a metric row.
+    #[must_use]
+    pub fn row(mut self, label: &str, values: &[f32]) -> Self {
+        self.rows.push((label.to_string(), values.to_vec()));
+        self
+    }
+
+    /// Set how samples sharing a pixel column are combined.
+    #[must_use]
+    pub fn aggregation(mut self, aggregation: StripAggregation) -> Self {
+        self.aggregation = aggregation;
+        self
+    }
+
+    /// Set the color scale (domain 0-1).
+    #[must_use]
+    pub fn color_scale(mut self, scale: ColorScale) -> Self {
+        self.color_scale = Some(scale);
+        self
+    }
+
+    /// Fix the value range shared by all rows.
+    #[must_use]
+    pub fn value_range(mut self, min: f32, max: f32) -> Self {
+        self.value_range = Some((min, max));
+        self
+    }
+
+    /// Normalize each row to its own extent instead of a shared one.
+    #[must_use]
+    pub fn normalize_rows(mut self, normalize: bool) -> Self {
+        self.normalize_rows = normalize;
+        self
+    }
+
+    /// Set the row height in pixels.
+    #[must_use]
+    pub fn row_height(mut self, height: u32) -> Self {
+        self.row_height = height.max(1);
+        self
+    }
+
+    /// Set the gap between rows in pixels.
+    #[must_use]
+    pub fn row_gap(mut self, gap: u32) -> Self {
+        self.row_gap = gap;
+        self
+    }
+
+    /// Set the color used for columns without finite samples.
+    #[must_use]
+    pub fn missing_color(mut self, color: Rgba) -> Self {
+        self.missing_color = color;
+        self
+    }
+
+    /// Set margin.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Validate and build the plot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no rows or the value range is invalid.
+    pub fn build(self) -> Result<Self> {
+        if self.rows.is_empty() || self.rows.iter().all(|(_, v)| v.is_empty()) {
+            return Err(Error::EmptyData);
+        }
+        if let Some((min, max)) = self.value_range {
+            if !min.is_finite() || !max.is_finite() || max <= min {
+                return Err(Error::Rendering(format!("Invalid value range: {min}..{max}")));
+            }
+        }
+        Ok(self)
+    }
+
+    /// Height in pixels needed to show every row at the configured row height.
+    #[must_use]
+    pub fn required_height(&self) -> u32 {
+        let n = self.rows.len() as u32;
+        2 * self.margin + n * self.row_height + n.saturating_sub(1) * self.row_gap
+    }
+
+    /// Aggregate a row into `columns` pixel columns.
+    ///
+    /// With fewer samples than columns each sample is stretched over several
+    /// columns; otherwise each column combines its bucket of samples.
+    #[must_use]
+    pub fn columns(&self, row: usize, columns: usize) -> Vec<Option<f32>> {
+        let Some((_, values)) = self.rows.get(row) else {
+            return Vec::new();
+        };
+        let n = values.len();
+        if n == 0 {
+            return vec![None; columns];
+        }
+        (0..columns)
+            .map(|c| {
+                let start = c * n / columns;
+                let end = ((c + 1) * n / columns).max(start + 1).min(n);
+                self.aggregation.apply(&values[start..end])
+            })
+            .collect()
+    }
+
+    /// Value domain used to normalize a row.
+    fn domain(&self, row: usize) -> (f32, f32) {
+        if let Some(range) = self.value_range {
+            return range;
+        }
+        let values: Box<dyn Iterator<Item = &f32>> = if self.normalize_rows {
+            Box::new(self.rows.get(row).into_iter().flat_map(|(_, v)| v))
+        } else {
+            Box::new(self.rows.iter().flat_map(|(_, v)| v))
+        };
+        let (min, max) = values
+            .filter(|v| v.is_finite())
+            .fold((f32::MAX, f32::MIN), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+        if min > max {
+            (0.0, 1.0)
+        } else if (max - min).abs() < f32::EPSILON {
+            (min - 0.5, max + 0.5)
+        } else {
+            (min, max)
+        }
+    }
+
+    /// Render the heatstrip to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the plot area is too small.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let font = TextStyle::current();
+        // Long labels are ellipsized to at most a quarter of the width
+        let max_label = self.width / 4;
+        let gutter = self.rows.iter().map(|(l, _)| font.width(l)).max().unwrap_or(0);
+        let gutter = if gutter > 0 { gutter.min(max_label) + 6 } else { 0 };
+        let left = self.margin + gutter;
+        let right = self.width.saturating_sub(self.margin);
+        if right <= left {
+            return Err(Error::Rendering("Plot area too small".into()));
+        }
+        let columns = (right - left) as usize;
+
+        let scale = match &self.color_scale {
+            Some(scale) => scale.clone(),
+            None => ColorScale::viridis((0.0, 1.0)).ok_or(Error::EmptyData)?,
+        };
+
+        for row in 0..self.rows.len() {
+            let y = self.margin + row as u32 * (self.row_height + self.row_gap);
+            if y >= self.height.saturating_sub(self.margin) {
+                break;
+            }
+            let (lo, hi) = self.domain(row);
+            for (c, value) in self.columns(row, columns).into_iter().enumerate() {
+                let color = match value {
+                    Some(v) => scale.scale(((v - lo) / (hi - lo)).clamp(0.0, 1.0)),
+                    None => self.missing_color,
+                };
+                fb.fill_rect(left + c as u32, y, 1, self.row_height, color);
+            }
+
+            let label = font.ellipsize(&self.rows[row].0, max_label);
+            let ly = i32_px(y + self.row_height / 2) - i32_px(font.height()) / 2;
+            let lx = i32_px(left) - 6 - i32_px(font.width(&label));
+            font.draw(fb, lx, ly, &label);
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+impl batuta_common::display::WithDimensions for Heatstrip {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    #[test]
+    fn test_aggregation() {
+        let v = [1.0, f32::NAN, 5.0];
+        assert_eq!(StripAggregation::Mean.apply(&v), Some(3.0));
+        assert_eq!(StripAggregation::Max.apply(&v), Some(5.0));
+        assert_eq!(StripAggregation::Min.apply(&v), Some(1.0));
+        assert_eq!(StripAggregation::Mean.apply(&[f32::NAN]), None);
+    }
+
+    #[test]
+    fn test_columns_bucket_and_stretch() {
+        let strip = Heatstrip::new().row("cpu", &[1.0, 3.0, 2.0, 8.0]);
+        assert_eq!(strip.columns(0, 2), vec![Some(2.0), Some(5.0)]);
+        assert_eq!(
+            strip.clone().aggregation(StripAggregation::Max).columns(0, 2),
+            vec![Some(3.0), Some(8.0)]
+        );
+        assert_eq!(strip.columns(0, 8)[..3], [Some(1.0), Some(1.0), Some(3.0)]);
+        assert!(strip.columns(1, 4).is_empty());
+    }
+
+    #[test]
+    fn test_domain_shared_and_per_row() {
+        let strip = Heatstrip::new().row("a", &[0.0, 10.0]).row("b", &[5.0, 6.0]);
+        assert_eq!(strip.domain(1), (0.0, 10.0));
+        assert_eq!(strip.clone().normalize_rows(true).domain(1), (5.0, 6.0));
+        assert_eq!(strip.value_range(0.0, 100.0).domain(0), (0.0, 100.0));
+    }
+
+    #[test]
+    fn test_validation_and_height() {
+        assert!(Heatstrip::new().build().is_err());
+        assert!(Heatstrip::new().row("a", &[1.0]).value_range(1.0, 0.0).build().is_err());
+        let strip = Heatstrip::new().row("a", &[1.0]).row("b", &[2.0]).row_height(10).margin(5);
+        assert_eq!(strip.required_height(), 10 + 20 + 2);
+    }
+
+    #[test]
+    fn test_render_one_column_per_sample() {
+        let scale = ColorScale::new(vec![Rgba::BLUE, Rgba::RED], (0.0, 1.0))
+            .expect("color scale should be valid");
+        let samples: Vec<f32> = (0..80).map(|i| if i < 40 { 0.0 } else { 1.0 }).collect();
+        let strip = Heatstrip::new()
+            .row("", &samples)
+            .color_scale(scale)
+            .margin(0)
+            .dimensions(80, 12)
+            .build()
+            .expect("valid plot");
+        let fb = strip.to_framebuffer().expect("rendering should succeed");
+        assert_eq!(fb.get_pixel(39, 5), Some(Rgba::BLUE));
+        assert_eq!(fb.get_pixel(40, 5), Some(Rgba::RED));
+    }
+}
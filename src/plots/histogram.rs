@@ -42,12 +42,13 @@ impl Histogram {
     /// Create a new histogram builder.
     #[must_use]
     pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 600);
         Self {
             data: Vec::new(),
             bin_strategy: BinStrategy::default(),
             color: Rgba::rgb(70, 130, 180), // Steel blue
-            width: 800,
-            height: 600,
+            width,
+            height,
             margin: 40,
             normalize: false,
         }
@@ -154,16 +155,9 @@ impl Histogram {
         Ok(self)
     }
 
-    /// Render to a new framebuffer.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if rendering fails.
-    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
-        let mut fb = Framebuffer::new(self.width, self.height)?;
-        fb.clear(Rgba::WHITE);
-
-        // Calculate bins
+    /// Bin the data: returns `(first bin start, bin width, count per bin)`.
+    #[must_use]
+    pub fn bin_counts(&self) -> (f32, f32, Vec<usize>) {
         let bin_count = self.bin_count();
         let min = self.data.iter().copied().fold(f32::INFINITY, f32::min);
         let max = self.data.iter().copied().fold(f32::NEG_INFINITY, f32::max);
@@ -177,6 +171,20 @@ impl Histogram {
             let bin = bin.min(bin_count - 1);
             counts[bin] += 1;
         }
+        (min, bin_width, counts)
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+
+        let (_, _, counts) = self.bin_counts();
+        let bin_count = counts.len();
 
         // Find max count for scaling
         let max_count = *counts.iter().max().unwrap_or(&1);
@@ -0,0 +1,519 @@
+//! Rectangular 2D histogram with optional marginal profiles.
+//!
+//! Bins `(x, y)` pairs on a regular grid and colors each non-empty cell by
+//! its count. Log-count coloring keeps sparse tails visible next to a dense
+//! core, as in particle-physics style occupancy plots. Optional profile
+//! curves above and to the right show the x and y projections of the same
+//! bins.
+//!
+//! # References
+//!
+//! - Scott, D. W. (2015). *Multivariate Density Estimation* (2nd ed.). Wiley.
+//!   (Bivariate histograms)
+
+use super::colorbar::Colorbar;
+use super::heatmap::HeatmapPalette;
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::draw_line_aa;
+use crate::scale::{format_tick, log_ticks, nice_ticks, ColorScale, Scale};
+
+/// Gap in pixels between the panels and the colorbar.
+const COLORBAR_GAP: u32 = 8;
+
+/// Panel rectangle `(x, y, width, height)` in pixels.
+type PanelRect = (f32, f32, f32, f32);
+
+/// Builder for creating 2D histograms.
+#[derive(Debug, Clone)]
+pub struct Histogram2D {
+    /// X values.
+    x: Vec<f32>,
+    /// Y values.
+    y: Vec<f32>,
+    /// Number of bins along x.
+    bins_x: usize,
+    /// Number of bins along y.
+    bins_y: usize,
+    /// Fixed x range (None = data extent).
+    x_range: Option<(f32, f32)>,
+    /// Fixed y range (None = data extent).
+    y_range: Option<(f32, f32)>,
+    /// Color cells by `ln(1 + count)` instead of count.
+    log_color: bool,
+    /// Color palette.
+    palette: HeatmapPalette,
+    /// Custom color scale over normalized intensity 0-1 (overrides palette).
+    custom_scale: Option<ColorScale>,
+    /// Draw x/y marginal profile curves.
+    show_profiles: bool,
+    /// Profile strip size as a fraction of the figure (0.05 - 0.4).
+    profile_fraction: f32,
+    /// Profile curve color.
+    profile_color: Rgba,
+    /// Colorbar legend labelled in counts.
+    colorbar: Option<Colorbar>,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the plot.
+    margin: u32,
+}
+
+impl Default for Histogram2D {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Histogram2D {
+    /// Create a new 2D histogram builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let style = crate::style::current();
+        let (width, height) = style.scaled_size(600, 600);
+        Self {
+            x: Vec::new(),
+            y: Vec::new(),
+            bins_x: 32,
+            bins_y: 32,
+            x_range: None,
+            y_range: None,
+            log_color: false,
+            palette: style.palette,
+            custom_scale: None,
+            show_profiles: false,
+            profile_fraction: 0.18,
+            profile_color: Rgba::rgb(70, 130, 180),
+            colorbar: None,
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Set the x and y data.
+    #[must_use]
+    pub fn data(mut self, x: &[f32], y: &[f32]) -> Self {
+        self.x = x.to_vec();
+        self.y = y.to_vec();
+        self
+    }
+
+    /// Set the number of bins along each axis.
+    #[must_use]
+    pub fn bins(mut self, bins_x: usize, bins_y: usize) -> Self {
+        self.bins_x = bins_x.max(1);
+        self.bins_y = bins_y.max(1);
+        self
+    }
+
+    /// Fix the binned x range; points outside are dropped.
+    #[must_use]
+    pub fn x_range(mut self, min: f32, max: f32) -> Self {
+        self.x_range = Some((min, max));
+        self
+    }
+
+    /// Fix the binned y range; points outside are dropped.
+    #[must_use]
+    pub fn y_range(mut self, min: f32, max: f32) -> Self {
+        self.y_range = Some((min, max));
+        self
+    }
+
+    /// Color cells by log count (`ln(1 + n)`).
+    #[must_use]
+    pub fn log_color(mut self, enabled: bool) -> Self {
+        self.log_color = enabled;
+        self
+    }
+
+    /// Set the color palette.
+    #[must_use]
+    pub fn palette(mut self, palette: HeatmapPalette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Set a custom color scale over the normalized intensity domain `(0, 1)`.
+    #[must_use]
+    pub fn color_scale(mut self, scale: ColorScale) -> Self {
+        self.custom_scale = Some(scale);
+        self
+    }
+
+    /// Enable or disable the marginal profile curves.
+    #[must_use]
+    pub fn profiles(mut self, show: bool) -> Self {
+        self.show_profiles = show;
+        self
+    }
+
+    /// Set the profile strip size as a fraction of the figure (0.05 - 0.4).
+    #[must_use]
+    pub fn profile_size(mut self, fraction: f32) -> Self {
+        self.profile_fraction = fraction.clamp(0.05, 0.4);
+        self
+    }
+
+    /// Set the profile curve color.
+    #[must_use]
+    pub fn profile_color(mut self, color: Rgba) -> Self {
+        self.profile_color = color;
+        self
+    }
+
+    /// Draw a colorbar legend labelled in counts.
+    #[must_use]
+    pub fn colorbar(mut self, colorbar: Colorbar) -> Self {
+        self.colorbar = Some(colorbar);
+        self
+    }
+
+    /// Set the margin around the plot.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the histogram.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if data is empty, x and y lengths differ, or a fixed
+    /// range is not increasing.
+    pub fn build(self) -> Result<Self> {
+        if self.x.is_empty() {
+            return Err(Error::EmptyData);
+        }
+        if self.x.len() != self.y.len() {
+            return Err(Error::DataLengthMismatch { x_len: self.x.len(), y_len: self.y.len() });
+        }
+        for (lo, hi) in [self.x_range, self.y_range].into_iter().flatten() {
+            if !lo.is_finite() || !hi.is_finite() || hi <= lo {
+                return Err(Error::ScaleDomain(format!(
+                    "Histogram2D range must be increasing, got {lo}..{hi}"
+                )));
+            }
+        }
+        Ok(self)
+    }
+
+    /// Binned extent `((x_min, x_max), (y_min, y_max))`.
+    #[must_use]
+    pub fn extent(&self) -> ((f32, f32), (f32, f32)) {
+        let pairs = || self.x.iter().zip(&self.y).filter(|(x, y)| x.is_finite() && y.is_finite());
+        let span = |range: Option<(f32, f32)>, values: Vec<f32>| {
+            range.unwrap_or_else(|| {
+                let lo = values.iter().copied().fold(f32::INFINITY, f32::min);
+                let hi = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+                if !lo.is_finite() {
+                    (0.0, 1.0)
+                } else if (hi - lo).abs() < f32::EPSILON {
+                    (lo - 0.5, hi + 0.5)
+                } else {
+                    (lo, hi)
+                }
+            })
+        };
+        (
+            span(self.x_range, pairs().map(|(x, _)| *x).collect()),
+            span(self.y_range, pairs().map(|(_, y)| *y).collect()),
+        )
+    }
+
+    /// Bin counts indexed `[y_bin][x_bin]`, with y bin 0 at the lowest y.
+    #[must_use]
+    pub fn counts(&self) -> Vec<Vec<u32>> {
+        let ((x0, x1), (y0, y1)) = self.extent();
+        let mut counts = vec![vec![0u32; self.bins_x]; self.bins_y];
+        let bin = |v: f32, lo: f32, hi: f32, n: usize| -> Option<usize> {
+            if !v.is_finite() || v < lo || v > hi {
+                return None;
+            }
+            Some((((v - lo) / (hi - lo) * n as f32) as usize).min(n - 1))
+        };
+        for (&x, &y) in self.x.iter().zip(&self.y) {
+            if let (Some(bx), Some(by)) = (bin(x, x0, x1, self.bins_x), bin(y, y0, y1, self.bins_y))
+            {
+                counts[by][bx] += 1;
+            }
+        }
+        counts
+    }
+
+    /// Counts per x bin, summed over y.
+    #[must_use]
+    pub fn x_profile(&self) -> Vec<u32> {
+        let counts = self.counts();
+        (0..self.bins_x).map(|bx| counts.iter().map(|row| row[bx]).sum()).collect()
+    }
+
+    /// Counts per y bin, summed over x.
+    #[must_use]
+    pub fn y_profile(&self) -> Vec<u32> {
+        self.counts().iter().map(|row| row.iter().sum()).collect()
+    }
+
+    /// Normalized color intensity (0-1) for a count given the maximum count.
+    #[must_use]
+    pub fn intensity(&self, count: u32, max: u32) -> f32 {
+        if count == 0 || max == 0 {
+            return 0.0;
+        }
+        if self.log_color {
+            (count as f32).ln_1p() / (max as f32).ln_1p()
+        } else {
+            count as f32 / max as f32
+        }
+    }
+
+    /// Colorbar ticks in counts as `(ramp position, label)`, placed by the
+    /// same intensity mapping as the cells.
+    fn colorbar_ticks(&self, bar: &Colorbar, max: u32) -> Vec<(f32, String)> {
+        let values = if self.log_color {
+            log_ticks(1.0, max as f32)
+        } else {
+            nice_ticks(0.0, max as f32, bar.tick_target())
+        };
+        values
+            .into_iter()
+            .filter(|v| v.fract().abs() < f32::EPSILON)
+            .map(|v| (self.intensity(v as u32, max), format_tick(v)))
+            .collect()
+    }
+
+    /// Main panel and optional (top, right) profile strips, leaving
+    /// `reserve = (right, bottom)` pixels free for a colorbar.
+    fn panels(&self, reserve: (u32, u32)) -> (PanelRect, Option<(PanelRect, PanelRect)>) {
+        let left = self.margin as f32;
+        let top = self.margin as f32;
+        let w = self.width.saturating_sub(2 * self.margin + reserve.0) as f32;
+        let h = self.height.saturating_sub(2 * self.margin + reserve.1) as f32;
+        if !self.show_profiles {
+            return ((left, top, w, h), None);
+        }
+        let strip_w = w * self.profile_fraction;
+        let strip_h = h * self.profile_fraction;
+        let gap = 2.0;
+        let main = (left, top + strip_h + gap, w - strip_w - gap, h - strip_h - gap);
+        let top_strip = (main.0, top, main.2, strip_h);
+        let right_strip = (main.0 + main.2 + gap, main.1, strip_w, main.3);
+        (main, Some((top_strip, right_strip)))
+    }
+
+    /// Render the histogram to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no color scale is available.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let scale = match &self.custom_scale {
+            Some(scale) => scale.clone(),
+            None => self.palette.color_scale((0.0, 1.0)).ok_or(Error::EmptyData)?,
+        };
+        let counts = self.counts();
+        let max = counts.iter().flatten().copied().max().unwrap_or(0);
+        let colorbar = self.colorbar.as_ref().map(|bar| {
+            let ticks = self.colorbar_ticks(bar, max);
+            let space = bar.footprint(&ticks) + COLORBAR_GAP;
+            (bar, ticks, space)
+        });
+        let reserve = match &colorbar {
+            Some((bar, _, space)) if bar.is_vertical() => (*space, 0),
+            Some((_, _, space)) => (0, *space),
+            None => (0, 0),
+        };
+        let ((mx, my, mw, mh), strips) = self.panels(reserve);
+        let cell_w = mw / self.bins_x as f32;
+        let cell_h = mh / self.bins_y as f32;
+
+        for (by, row) in counts.iter().enumerate() {
+            for (bx, &count) in row.iter().enumerate() {
+                if count == 0 {
+                    continue;
+                }
+                // Snap to pixel edges so adjacent cells tile without gaps
+                let x0 = (mx + bx as f32 * cell_w).round() as u32;
+                let x1 = (mx + (bx + 1) as f32 * cell_w).round() as u32;
+                let y0 = (my + mh - (by + 1) as f32 * cell_h).round() as u32;
+                let y1 = (my + mh - by as f32 * cell_h).round() as u32;
+                let color = scale.scale(self.intensity(count, max));
+                fb.fill_rect(
+                    x0,
+                    y0,
+                    x1.saturating_sub(x0).max(1),
+                    y1.saturating_sub(y0).max(1),
+                    color,
+                );
+            }
+        }
+
+        if let Some(((tx, ty, tw, th), (rx, ry, rw, rh))) = strips {
+            let x_profile = self.x_profile();
+            let x_max = x_profile.iter().copied().max().unwrap_or(0).max(1) as f32;
+            let step = tw / self.bins_x as f32;
+            let points: Vec<(f32, f32)> = x_profile
+                .iter()
+                .enumerate()
+                .map(|(i, &c)| (tx + (i as f32 + 0.5) * step, ty + th - c as f32 / x_max * th))
+                .collect();
+            for pair in points.windows(2) {
+                draw_line_aa(fb, pair[0].0, pair[0].1, pair[1].0, pair[1].1, self.profile_color);
+            }
+
+            let y_profile = self.y_profile();
+            let y_max = y_profile.iter().copied().max().unwrap_or(0).max(1) as f32;
+            let step = rh / self.bins_y as f32;
+            let points: Vec<(f32, f32)> = y_profile
+                .iter()
+                .enumerate()
+                .map(|(i, &c)| (rx + c as f32 / y_max * rw, ry + rh - (i as f32 + 0.5) * step))
+                .collect();
+            for pair in points.windows(2) {
+                draw_line_aa(fb, pair[0].0, pair[0].1, pair[1].0, pair[1].1, self.profile_color);
+            }
+        }
+
+        if let Some((bar, ticks, _)) = colorbar {
+            let (x, y, length) = if bar.is_vertical() {
+                let right = self.width.saturating_sub(self.margin + reserve.0);
+                (right + COLORBAR_GAP, my as u32, mh as u32)
+            } else {
+                let bottom = self.height.saturating_sub(self.margin + reserve.1);
+                (mx as u32, bottom + COLORBAR_GAP, mw as u32)
+            };
+            bar.draw(fb, x, y, length, |t| scale.scale(t), &ticks);
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+impl batuta_common::display::WithDimensions for Histogram2D {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn sample() -> Histogram2D {
+        Histogram2D::new()
+            .data(&[0.0, 0.1, 0.2, 0.9, 1.0, f32::NAN], &[0.0, 0.1, 0.2, 0.9, 1.0, 0.5])
+            .bins(2, 2)
+    }
+
+    #[test]
+    fn test_hist2d_counts() {
+        let hist = sample().build().expect("builder should produce valid result");
+        assert_eq!(hist.extent(), ((0.0, 1.0), (0.0, 1.0)));
+        assert_eq!(hist.counts(), vec![vec![3, 0], vec![0, 2]]);
+        assert_eq!(hist.x_profile(), vec![3, 2]);
+        assert_eq!(hist.y_profile(), vec![3, 2]);
+    }
+
+    #[test]
+    fn test_hist2d_fixed_range_drops_outside() {
+        let hist = sample().x_range(0.0, 0.5).y_range(0.0, 0.5);
+        let total: u32 = hist.counts().iter().flatten().sum();
+        assert_eq!(total, 3);
+    }
+
+    #[test]
+    fn test_hist2d_validation() {
+        assert!(Histogram2D::new().build().is_err());
+        assert!(Histogram2D::new().data(&[1.0, 2.0], &[1.0]).build().is_err());
+        assert!(sample().x_range(1.0, 1.0).build().is_err());
+    }
+
+    #[test]
+    fn test_hist2d_log_intensity() {
+        let linear = sample();
+        let log = sample().log_color(true);
+        assert!((linear.intensity(10, 100) - 0.1).abs() < 1e-6);
+        assert!(log.intensity(10, 100) > 0.5);
+        assert!((log.intensity(100, 100) - 1.0).abs() < 1e-6);
+        assert!(log.intensity(0, 100).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_hist2d_render() {
+        let scale = ColorScale::new(vec![Rgba::RED, Rgba::RED], (0.0, 1.0))
+            .expect("color scale should be valid");
+        let hist = sample()
+            .color_scale(scale)
+            .profiles(true)
+            .profile_color(Rgba::BLUE)
+            .dimensions(120, 120)
+            .build()
+            .expect("builder should produce valid result");
+        let fb = hist.to_framebuffer().expect("rendering should succeed");
+        let has = |c: Rgba| {
+            (0..fb.height()).any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(c)))
+        };
+        assert!(has(Rgba::RED));
+        assert!(has(Rgba::BLUE));
+    }
+
+    #[test]
+    fn test_hist2d_palette_render() {
+        let hist = sample().log_color(true).palette(HeatmapPalette::Magma).dimensions(80, 60);
+        assert!(hist.to_framebuffer().is_ok());
+    }
+
+    #[test]
+    fn test_hist2d_colorbar_ticks_in_counts() {
+        let bar = Colorbar::new();
+        let linear = sample();
+        let ticks = linear.colorbar_ticks(&bar, 4);
+        let labels: Vec<&str> = ticks.iter().map(|(_, l)| l.as_str()).collect();
+        assert_eq!(labels, vec!["0", "1", "2", "3", "4"]);
+        assert!((ticks[2].0 - 0.5).abs() < 1e-6);
+
+        let log = sample().log_color(true);
+        let ticks = log.colorbar_ticks(&bar, 1000);
+        assert_eq!(ticks.len(), 4);
+        assert!((ticks[3].0 - 1.0).abs() < 1e-6);
+        assert!((ticks[1].0 - log.intensity(10, 1000)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_hist2d_colorbar_render() {
+        let scale = ColorScale::new(vec![Rgba::RED, Rgba::BLUE], (0.0, 1.0))
+            .expect("color scale should be valid");
+        let hist = sample()
+            .color_scale(scale)
+            .colorbar(Colorbar::horizontal())
+            .dimensions(120, 120)
+            .build()
+            .expect("builder should produce valid result");
+        let fb = hist.to_framebuffer().expect("rendering should succeed");
+        // The ramp runs red to blue along the bottom strip
+        let has_in_rows = |c: Rgba, rows: std::ops::Range<u32>| {
+            rows.clone().any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(c)))
+        };
+        assert!(has_in_rows(Rgba::RED, 90..120));
+        assert!(has_in_rows(Rgba::BLUE, 90..120));
+    }
+}
@@ -0,0 +1,465 @@
+//! Horizon chart for ultra-dense time-series comparison.
+//!
+//! Each series occupies a single thin row. Values are split into bands of
+//! equal size which are folded onto each other, with darker shades for higher
+//! bands. Negative values are drawn in a second hue, either mirrored down from
+//! the top of the row or offset up from the baseline.
+//!
+//! # References
+//!
+//! - Heer, J., Kong, N., & Agrawala, M. (2009). "Sizing the Horizon: The Effects
+//!   of Chart Size and Layering on the Graphical Perception of Time Series
+//!   Visualizations." CHI '09.
+
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::output::{SvgEncoder, TextAnchor};
+use crate::render::{i32_px, TextStyle};
+
+/// How negative values are placed within a row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NegativeMode {
+    /// Flip negative bands to hang down from the top of the row.
+    #[default]
+    Mirror,
+    /// Draw negative bands up from the baseline, like positive ones.
+    Offset,
+}
+
+/// A single named series in a horizon chart.
+#[derive(Debug, Clone)]
+pub struct HorizonSeries {
+    /// Series label.
+    pub name: String,
+    /// Values (one per column).
+    pub values: Vec<f32>,
+}
+
+impl HorizonSeries {
+    /// Create a new series.
+    #[must_use]
+    pub fn new(name: impl Into<String>, values: &[f32]) -> Self {
+        Self { name: name.into(), values: values.to_vec() }
+    }
+}
+
+/// A filled band segment in output coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct BandRect {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    color: Rgba,
+}
+
+/// Builder for creating horizon charts.
+#[derive(Debug, Clone)]
+pub struct HorizonChart {
+    /// Series, one per row.
+    series: Vec<HorizonSeries>,
+    /// Number of folded bands.
+    bands: u8,
+    /// Base color for positive values.
+    positive_color: Rgba,
+    /// Base color for negative values.
+    negative_color: Rgba,
+    /// Negative value placement.
+    negative_mode: NegativeMode,
+    /// Fixed absolute extent (None = derive from data).
+    extent: Option<f32>,
+    /// Gap between rows in pixels.
+    row_gap: u32,
+    /// Show series labels.
+    show_labels: bool,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the chart.
+    margin: u32,
+}
+
+impl Default for HorizonChart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HorizonChart {
+    /// Create a new horizon chart builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 400);
+        Self {
+            series: Vec::new(),
+            bands: 3,
+            positive_color: Rgba::rgb(33, 102, 172),
+            negative_color: Rgba::rgb(178, 24, 43),
+            negative_mode: NegativeMode::default(),
+            extent: None,
+            row_gap: 2,
+            show_labels: true,
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Add a series.
+    #[must_use]
+    pub fn add_series(mut self, series: HorizonSeries) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Add a series from a name and values (convenience method).
+    #[must_use]
+    pub fn series(self, name: impl Into<String>, values: &[f32]) -> Self {
+        self.add_series(HorizonSeries::new(name, values))
+    }
+
+    /// Set the number of bands (1-8).
+    #[must_use]
+    pub fn bands(mut self, bands: u8) -> Self {
+        self.bands = bands.clamp(1, 8);
+        self
+    }
+
+    /// Set the base color for positive values.
+    #[must_use]
+    pub fn positive_color(mut self, color: Rgba) -> Self {
+        self.positive_color = color;
+        self
+    }
+
+    /// Set the base color for negative values.
+    #[must_use]
+    pub fn negative_color(mut self, color: Rgba) -> Self {
+        self.negative_color = color;
+        self
+    }
+
+    /// Set how negative values are placed.
+    #[must_use]
+    pub fn negative_mode(mut self, mode: NegativeMode) -> Self {
+        self.negative_mode = mode;
+        self
+    }
+
+    /// Fix the absolute value extent shared by all rows.
+    ///
+    /// By default the extent is the largest absolute value across all series.
+    #[must_use]
+    pub fn extent(mut self, extent: f32) -> Self {
+        self.extent = Some(extent.abs());
+        self
+    }
+
+    /// Set the gap between rows.
+    #[must_use]
+    pub fn row_gap(mut self, gap: u32) -> Self {
+        self.row_gap = gap;
+        self
+    }
+
+    /// Enable or disable series labels.
+    #[must_use]
+    pub fn labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// Set the margin around the chart.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the horizon chart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no series or any series is empty.
+    pub fn build(self) -> Result<Self> {
+        if self.series.is_empty() || self.series.iter().any(|s| s.values.is_empty()) {
+            return Err(Error::EmptyData);
+        }
+
+        Ok(self)
+    }
+
+    /// Get the number of series (rows).
+    #[must_use]
+    pub fn series_count(&self) -> usize {
+        self.series.len()
+    }
+
+    /// Get the absolute extent mapped to the full band stack.
+    #[must_use]
+    pub fn value_extent(&self) -> f32 {
+        let extent = self.extent.unwrap_or_else(|| {
+            self.series
+                .iter()
+                .flat_map(|s| s.values.iter())
+                .filter(|v| v.is_finite())
+                .fold(0.0_f32, |acc, v| acc.max(v.abs()))
+        });
+
+        if extent > f32::EPSILON {
+            extent
+        } else {
+            1.0
+        }
+    }
+
+    /// Band colors from lightest (lowest band) to darkest (highest band).
+    fn band_colors(&self, base: Rgba) -> Vec<Rgba> {
+        let bands = f32::from(self.bands);
+        (0..self.bands).map(|b| Rgba::WHITE.lerp(base, (f32::from(b) + 1.0) / bands)).collect()
+    }
+
+    /// Width of the label gutter in pixels.
+    fn label_gutter(&self) -> u32 {
+        if !self.show_labels {
+            return 0;
+        }
+        let font = TextStyle::current();
+        self.series.iter().map(|s| font.width(&s.name)).max().map_or(0, |w| w + 6)
+    }
+
+    /// Compute the top y coordinate and height of each row.
+    fn row_layout(&self) -> Vec<(f32, f32)> {
+        let n = self.series.len() as u32;
+        if n == 0 {
+            return Vec::new();
+        }
+        let usable = self.height.saturating_sub(2 * self.margin + self.row_gap * (n - 1));
+        let row_height = usable as f32 / n as f32;
+        (0..n)
+            .map(|i| {
+                (self.margin as f32 + i as f32 * (row_height + self.row_gap as f32), row_height)
+            })
+            .collect()
+    }
+
+    /// Compute every band segment in output coordinates.
+    fn band_rects(&self) -> Vec<BandRect> {
+        let extent = self.value_extent();
+        let band_size = extent / f32::from(self.bands);
+        let pos_colors = self.band_colors(self.positive_color);
+        let neg_colors = self.band_colors(self.negative_color);
+
+        let left = (self.margin + self.label_gutter()) as f32;
+        let plot_width = (self.width as f32 - left - self.margin as f32).max(0.0);
+
+        let mut rects = Vec::new();
+        for (series, (row_top, row_height)) in self.series.iter().zip(self.row_layout()) {
+            let col_width = plot_width / series.values.len() as f32;
+            let baseline = row_top + row_height;
+
+            for (i, &value) in series.values.iter().enumerate() {
+                if !value.is_finite() || value == 0.0 {
+                    continue;
+                }
+                let x = left + i as f32 * col_width;
+                let magnitude = value.abs().min(extent);
+                let (colors, hang) = if value > 0.0 {
+                    (&pos_colors, false)
+                } else {
+                    (&neg_colors, self.negative_mode == NegativeMode::Mirror)
+                };
+
+                for (band, &color) in colors.iter().enumerate() {
+                    let fill = ((magnitude - band as f32 * band_size) / band_size).clamp(0.0, 1.0);
+                    if fill <= 0.0 {
+                        break;
+                    }
+                    let h = fill * row_height;
+                    let y = if hang { row_top } else { baseline - h };
+                    rects.push(BandRect { x, y, width: col_width, height: h, color });
+                }
+            }
+        }
+        rects
+    }
+
+    /// Render the horizon chart to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        for rect in self.band_rects() {
+            // Snap to pixel edges so adjacent columns tile without gaps
+            let x0 = rect.x.round() as u32;
+            let x1 = (rect.x + rect.width).round() as u32;
+            let y0 = rect.y.round() as u32;
+            let y1 = (rect.y + rect.height).round() as u32;
+            fb.fill_rect(x0, y0, x1.saturating_sub(x0).max(1), y1.saturating_sub(y0), rect.color);
+        }
+
+        if self.show_labels {
+            let font = TextStyle::current();
+            for (series, (row_top, row_height)) in self.series.iter().zip(self.row_layout()) {
+                let y = (row_top + (row_height - font.height() as f32) / 2.0) as i32;
+                font.draw(fb, i32_px(self.margin), y, &series.name);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+
+    /// Render as vector SVG (also usable with [`crate::output::HtmlExporter`]).
+    #[must_use]
+    pub fn to_svg(&self) -> SvgEncoder {
+        let mut svg = SvgEncoder::new(self.width, self.height);
+        for rect in self.band_rects() {
+            svg = svg.rect(rect.x, rect.y, rect.width, rect.height, rect.color);
+        }
+
+        if self.show_labels {
+            let font = TextStyle::current();
+            for (series, (row_top, row_height)) in self.series.iter().zip(self.row_layout()) {
+                svg = svg.text_anchored(
+                    self.margin as f32,
+                    row_top + row_height / 2.0 + 4.0,
+                    &series.name,
+                    font.font_size(11.0),
+                    font.color,
+                    TextAnchor::Start,
+                );
+            }
+        }
+        svg
+    }
+}
+
+impl batuta_common::display::WithDimensions for HorizonChart {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    #[test]
+    fn test_horizon_builder() {
+        let chart = HorizonChart::new()
+            .series("cpu0", &[0.1, 0.5, 0.9])
+            .series("cpu1", &[0.2, 0.4, 0.6])
+            .bands(4)
+            .build()
+            .expect("builder should produce valid result");
+
+        assert_eq!(chart.series_count(), 2);
+        assert_eq!(chart.bands, 4);
+    }
+
+    #[test]
+    fn test_horizon_empty() {
+        assert!(HorizonChart::new().build().is_err());
+        assert!(HorizonChart::new().series("a", &[]).build().is_err());
+    }
+
+    #[test]
+    fn test_horizon_bands_clamped() {
+        assert_eq!(HorizonChart::new().bands(0).bands, 1);
+        assert_eq!(HorizonChart::new().bands(20).bands, 8);
+    }
+
+    #[test]
+    fn test_horizon_value_extent() {
+        let chart = HorizonChart::new().series("a", &[1.0, -4.0, 2.0]);
+        assert!((chart.value_extent() - 4.0).abs() < f32::EPSILON);
+
+        let fixed = chart.extent(10.0);
+        assert!((fixed.value_extent() - 10.0).abs() < f32::EPSILON);
+
+        let zeros = HorizonChart::new().series("z", &[0.0, 0.0]);
+        assert!((zeros.value_extent() - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_horizon_band_folding() {
+        // Value at 2.5 / 3.0 extent with 3 bands: two full bands + half of the third
+        let chart = HorizonChart::new()
+            .series("a", &[2.5])
+            .extent(3.0)
+            .labels(false)
+            .margin(0)
+            .row_gap(0)
+            .dimensions(10, 100);
+
+        let rects = chart.band_rects();
+        assert_eq!(rects.len(), 3);
+        assert!((rects[0].height - 100.0).abs() < 1e-3);
+        assert!((rects[1].height - 100.0).abs() < 1e-3);
+        assert!((rects[2].height - 50.0).abs() < 1e-3);
+        assert!((rects[2].y - 50.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_horizon_negative_mirror_and_offset() {
+        let base = HorizonChart::new()
+            .series("a", &[-0.5])
+            .extent(1.0)
+            .bands(1)
+            .labels(false)
+            .margin(0)
+            .dimensions(10, 100);
+
+        let mirrored = base.clone().band_rects();
+        assert_eq!(mirrored.len(), 1);
+        assert!(mirrored[0].y.abs() < 1e-3);
+        assert_eq!(mirrored[0].color, base.negative_color);
+
+        let offset = base.negative_mode(NegativeMode::Offset).band_rects();
+        assert!((offset[0].y - 50.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_horizon_render() {
+        let chart = HorizonChart::new()
+            .series("cpu0", &[0.1, -0.5, 0.9, f32::NAN])
+            .series("cpu1", &[0.2, 0.4, -0.6, 0.0])
+            .dimensions(120, 40)
+            .build()
+            .expect("builder should produce valid result");
+
+        let fb = chart.to_framebuffer().expect("rendering should succeed");
+        assert_eq!(fb.width(), 120);
+    }
+
+    #[test]
+    fn test_horizon_to_svg() {
+        let svg = HorizonChart::new()
+            .series("cpu0", &[0.5, 1.0])
+            .dimensions(100, 40)
+            .build()
+            .expect("builder should produce valid result")
+            .to_svg()
+            .render();
+
+        assert!(svg.contains("<rect x="));
+        assert!(svg.contains(">cpu0</text>"));
+    }
+}
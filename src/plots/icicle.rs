@@ -0,0 +1,407 @@
+//! Icicle and partition diagrams for hierarchical profiling data.
+//!
+//! Uses the same [`StackTree`] input as [`super::FlameGraph`], but lays the
+//! hierarchy out from the root outwards: top-down (icicle) or left-to-right
+//! (partition). Each level fills an equal share of the depth axis, so a
+//! depth limit combined with a zoom path gives a readable drill-down into
+//! one subtree of a large profile.
+//!
+//! # References
+//!
+//! - Kruskal, J. B., & Landwehr, J. M. (1983). "Icicle Plots: Better Displays
+//!   for Hierarchical Clustering". *The American Statistician*, 37(2), 162-168.
+
+use super::flame::{fit_label, FlamePalette, StackNode, StackTree};
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::output::{HtmlExporter, SvgElement, SvgEncoder, TextAnchor};
+use crate::render::{i32_px, TextStyle, GLYPH_ADVANCE};
+
+/// Font size used for SVG cell labels.
+const SVG_FONT_SIZE: f32 = 11.0;
+
+/// Label color on the palette-filled cells, kept dark for contrast.
+const CELL_TEXT_COLOR: Rgba = Rgba::rgb(30, 30, 30);
+
+/// Direction in which the hierarchy grows away from the root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IcicleOrientation {
+    /// Root on top, children below (icicle).
+    #[default]
+    TopDown,
+    /// Root on the left, children to the right (partition).
+    LeftToRight,
+}
+
+/// A laid-out hierarchy cell.
+#[derive(Debug, Clone)]
+struct CellBox {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    name: String,
+    total: u64,
+}
+
+/// Builder for creating icicle and partition diagrams.
+#[derive(Debug, Clone)]
+pub struct Icicle {
+    /// Merged hierarchy.
+    tree: StackTree,
+    /// Frame path of the subtree to zoom into (empty = whole tree).
+    zoom: Vec<String>,
+    /// Number of levels to draw below (and including) the zoom root.
+    max_depth: Option<usize>,
+    /// Layout direction.
+    orientation: IcicleOrientation,
+    /// Cell coloring.
+    palette: FlamePalette,
+    /// Cells narrower than this many pixels (along the breadth axis) are omitted.
+    min_size: f32,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the diagram.
+    margin: u32,
+}
+
+impl Default for Icicle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Icicle {
+    /// Create a new icicle diagram builder (top-down).
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 400);
+        Self {
+            tree: StackTree::new(),
+            zoom: Vec::new(),
+            max_depth: None,
+            orientation: IcicleOrientation::TopDown,
+            palette: FlamePalette::Cool,
+            min_size: 1.0,
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Create a new partition diagram builder (left-to-right).
+    #[must_use]
+    pub fn partition() -> Self {
+        Self::new().orientation(IcicleOrientation::LeftToRight)
+    }
+
+    /// Set the hierarchy from folded-stack text.
+    #[must_use]
+    pub fn folded(mut self, text: &str) -> Self {
+        self.tree = StackTree::from_folded(text);
+        self
+    }
+
+    /// Set the hierarchy from an already merged tree.
+    #[must_use]
+    pub fn tree(mut self, tree: StackTree) -> Self {
+        self.tree = tree;
+        self
+    }
+
+    /// Zoom into the subtree at a frame path (outermost frame first).
+    #[must_use]
+    pub fn zoom(mut self, path: &[impl AsRef<str>]) -> Self {
+        self.zoom = path.iter().map(|f| f.as_ref().to_string()).collect();
+        self
+    }
+
+    /// Limit the number of levels drawn, counting the zoom root as one.
+    #[must_use]
+    pub fn max_depth(mut self, levels: usize) -> Self {
+        self.max_depth = Some(levels.max(1));
+        self
+    }
+
+    /// Set the layout direction.
+    #[must_use]
+    pub fn orientation(mut self, orientation: IcicleOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the cell coloring.
+    #[must_use]
+    pub fn palette(mut self, palette: FlamePalette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Omit cells smaller than `pixels` along the breadth axis.
+    #[must_use]
+    pub fn min_size(mut self, pixels: f32) -> Self {
+        self.min_size = pixels.max(0.0);
+        self
+    }
+
+    /// Set the margin around the diagram.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the diagram.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hierarchy is empty or the zoom path doesn't exist.
+    pub fn build(self) -> Result<Self> {
+        if self.tree.total() == 0 {
+            return Err(Error::EmptyData);
+        }
+        if self.tree.find(&self.zoom).is_none() {
+            return Err(Error::Rendering(format!(
+                "Icicle zoom path '{}' not found",
+                self.zoom.join(";")
+            )));
+        }
+        Ok(self)
+    }
+
+    /// The merged hierarchy.
+    #[must_use]
+    pub fn stack_tree(&self) -> &StackTree {
+        &self.tree
+    }
+
+    /// Number of levels that will be drawn.
+    #[must_use]
+    pub fn visible_depth(&self) -> usize {
+        let depth = self.tree.find(&self.zoom).map_or(0, StackNode::depth);
+        self.max_depth.map_or(depth, |limit| depth.min(limit))
+    }
+
+    /// Lay out cells from the zoom root outwards.
+    fn cell_boxes(&self) -> Vec<CellBox> {
+        let Some(root) = self.tree.find(&self.zoom) else { return Vec::new() };
+        let levels = self.visible_depth();
+        if root.total == 0 || levels == 0 {
+            return Vec::new();
+        }
+
+        let left = self.margin as f32;
+        let top = self.margin as f32;
+        let plot_w = self.width.saturating_sub(2 * self.margin) as f32;
+        let plot_h = self.height.saturating_sub(2 * self.margin) as f32;
+        let (breadth, depth_extent) = match self.orientation {
+            IcicleOrientation::TopDown => (plot_w, plot_h),
+            IcicleOrientation::LeftToRight => (plot_h, plot_w),
+        };
+        let level = depth_extent / levels as f32;
+        let per_sample = breadth / root.total as f32;
+
+        let mut cells = Vec::new();
+        let mut stack = vec![(root, 0.0_f32, 0usize)];
+        while let Some((node, offset, depth)) = stack.pop() {
+            let size = node.total as f32 * per_sample;
+            if size < self.min_size || depth >= levels {
+                continue;
+            }
+            let along = depth as f32 * level;
+            let (x, y, width, height) = match self.orientation {
+                IcicleOrientation::TopDown => (left + offset, top + along, size, level),
+                IcicleOrientation::LeftToRight => (left + along, top + offset, level, size),
+            };
+            cells.push(CellBox { x, y, width, height, name: node.name.clone(), total: node.total });
+
+            let mut child_offset = offset;
+            for child in &node.children {
+                stack.push((child, child_offset, depth + 1));
+                child_offset += child.total as f32 * per_sample;
+            }
+        }
+        cells
+    }
+
+    /// Tooltip text for a cell.
+    fn describe(&self, cell: &CellBox) -> String {
+        let pct = 100.0 * cell.total as f64 / self.tree.total().max(1) as f64;
+        format!("{} ({} samples, {pct:.2}%)", cell.name, cell.total)
+    }
+
+    /// Render the diagram to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the zoom path doesn't exist.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        if self.tree.find(&self.zoom).is_none() {
+            return Err(Error::EmptyData);
+        }
+
+        let font = TextStyle::current().with_color(CELL_TEXT_COLOR);
+        for cell in self.cell_boxes() {
+            let x0 = cell.x.round() as u32;
+            let y0 = cell.y.round() as u32;
+            let w = ((cell.x + cell.width).round() as u32).saturating_sub(x0).saturating_sub(1);
+            let h = ((cell.y + cell.height).round() as u32).saturating_sub(y0).saturating_sub(1);
+            fb.fill_rect(x0, y0, w.max(1), h.max(1), self.palette.color(&cell.name));
+
+            if h >= font.height() + 2 {
+                let max_chars = (w.saturating_sub(4) / (GLYPH_ADVANCE * font.scale)) as usize;
+                if let Some(label) = fit_label(&cell.name, max_chars) {
+                    let ty = i32_px(y0) + (i32_px(h) - i32_px(font.height())) / 2;
+                    font.draw(fb, i32_px(x0) + 2, ty, &label);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+
+    /// Render as vector SVG with a hover tooltip on every cell.
+    #[must_use]
+    pub fn to_svg(&self) -> SvgEncoder {
+        let font_size = TextStyle::current().font_size(SVG_FONT_SIZE);
+        let mut svg = SvgEncoder::new(self.width, self.height);
+        for cell in self.cell_boxes() {
+            let mut children = vec![SvgElement::Rect {
+                x: cell.x,
+                y: cell.y,
+                width: (cell.width - 1.0).max(0.5),
+                height: (cell.height - 1.0).max(0.5),
+                fill: self.palette.color(&cell.name),
+                stroke: None,
+                stroke_width: 0.0,
+            }];
+            let max_chars = ((cell.width - 6.0) / (font_size * 0.6)).max(0.0) as usize;
+            if cell.height >= font_size {
+                if let Some(label) = fit_label(&cell.name, max_chars) {
+                    children.push(SvgElement::Text {
+                        x: cell.x + 3.0,
+                        y: cell.y + cell.height / 2.0 + font_size * 0.35,
+                        text: label,
+                        font_size,
+                        fill: CELL_TEXT_COLOR,
+                        anchor: TextAnchor::Start,
+                    });
+                }
+            }
+            svg = svg.group(Some(&self.describe(&cell)), children);
+        }
+        svg
+    }
+
+    /// Export as a standalone HTML page with hover tooltips.
+    #[must_use]
+    pub fn to_html(&self) -> HtmlExporter {
+        let title = match self.orientation {
+            IcicleOrientation::TopDown => "Icicle",
+            IcicleOrientation::LeftToRight => "Partition",
+        };
+        HtmlExporter::from_svg(&self.to_svg()).title(title)
+    }
+}
+
+impl batuta_common::display::WithDimensions for Icicle {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    const FOLDED: &str = "\
+main;parse;lex 30
+main;parse 10
+main;render;draw_text;blit 60
+";
+
+    #[test]
+    fn test_icicle_validation() {
+        assert!(Icicle::new().build().is_err());
+        assert!(Icicle::new().folded(FOLDED).zoom(&["main", "nope"]).build().is_err());
+        assert!(Icicle::partition().folded(FOLDED).build().is_ok());
+    }
+
+    #[test]
+    fn test_icicle_top_down_layout() {
+        let icicle = Icicle::new().folded(FOLDED).margin(0).dimensions(1000, 500);
+        let cells = icicle.cell_boxes();
+        // all, main, parse, lex, render, draw_text, blit
+        assert_eq!(cells.len(), 7);
+        assert_eq!(cells[0].name, "all");
+        assert!(cells[0].y.abs() < 1e-3 && (cells[0].height - 100.0).abs() < 1e-3);
+        let render = cells.iter().find(|c| c.name == "render").expect("render laid out");
+        assert!((render.width - 600.0).abs() < 1e-3);
+        assert!((render.x - 400.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_partition_layout() {
+        let partition = Icicle::partition().folded(FOLDED).margin(0).dimensions(500, 1000);
+        let cells = partition.cell_boxes();
+        let parse = cells.iter().find(|c| c.name == "parse").expect("parse laid out");
+        assert!((parse.height - 400.0).abs() < 1e-3);
+        assert!((parse.x - 200.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_icicle_zoom_depth_limit() {
+        let icicle = Icicle::new()
+            .folded(FOLDED)
+            .zoom(&["main", "render"])
+            .max_depth(2)
+            .margin(0)
+            .dimensions(400, 200);
+        assert_eq!(icicle.visible_depth(), 2);
+        let names: Vec<_> = icicle.cell_boxes().into_iter().map(|c| c.name).collect();
+        assert_eq!(names, vec!["render", "draw_text"]);
+        let cells = icicle.cell_boxes();
+        assert!((cells[0].width - 400.0).abs() < 1e-3);
+        assert!((cells[1].height - 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_icicle_render() {
+        for icicle in [Icicle::new(), Icicle::partition()] {
+            let icicle = icicle
+                .folded(FOLDED)
+                .palette(FlamePalette::Fixed(Rgba::RED))
+                .dimensions(200, 120)
+                .build()
+                .expect("builder should produce valid result");
+            let fb = icicle.to_framebuffer().expect("rendering should succeed");
+            let red = (0..fb.height())
+                .any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(Rgba::RED)));
+            assert!(red);
+        }
+    }
+
+    #[test]
+    fn test_icicle_html() {
+        let html = Icicle::partition().folded(FOLDED).to_html().to_html();
+        assert!(html.contains("<title>Partition</title>"));
+        assert!(html.contains("<title>lex (30 samples, 30.00%)</title>"));
+    }
+}
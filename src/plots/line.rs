@@ -18,8 +18,11 @@ use crate::color::Rgba;
 use crate::error::{Error, Result};
 use crate::framebuffer::Framebuffer;
 use crate::geometry::Point;
+use crate::output::{Crosshair, CursorPanel, CursorSeries, HtmlExporter, SvgEncoder};
 use crate::render::{draw_line, draw_line_aa};
-use crate::scale::{LinearScale, Scale};
+use crate::scale::{BrokenScale, Scale};
+
+use super::scatter::draw_axis_breaks;
 
 // ============================================================================
 // Douglas-Peucker Line Simplification
@@ -199,6 +202,12 @@ pub struct LineChart {
     show_markers: bool,
     /// Marker size.
     marker_size: f32,
+    /// Omitted x value ranges.
+    x_breaks: Vec<(f32, f32)>,
+    /// Omitted y value ranges.
+    y_breaks: Vec<(f32, f32)>,
+    /// Pixel width of each axis-break gap.
+    break_gap: f32,
 }
 
 impl Default for LineChart {
@@ -211,14 +220,18 @@ impl LineChart {
     /// Create a new line chart builder.
     #[must_use]
     pub fn new() -> Self {
+        let style = crate::style::current();
         Self {
             series: Vec::new(),
-            width: 800,
-            height: 600,
+            width: style.width,
+            height: style.height,
             margin: 40,
             simplify_epsilon: 0.0,
             show_markers: false,
             marker_size: 4.0,
+            x_breaks: Vec::new(),
+            y_breaks: Vec::new(),
+            break_gap: 12.0,
         }
     }
 
@@ -279,6 +292,27 @@ impl LineChart {
         self
     }
 
+    /// Omit an x value range, leaving a marked gap in the axis.
+    #[must_use]
+    pub fn x_break(mut self, from: f32, to: f32) -> Self {
+        self.x_breaks.push((from, to));
+        self
+    }
+
+    /// Omit a y value range, leaving a marked gap in the axis.
+    #[must_use]
+    pub fn y_break(mut self, from: f32, to: f32) -> Self {
+        self.y_breaks.push((from, to));
+        self
+    }
+
+    /// Set the pixel width of each axis-break gap.
+    #[must_use]
+    pub fn break_gap(mut self, gap: f32) -> Self {
+        self.break_gap = gap.max(0.0);
+        self
+    }
+
     /// Build and validate the line chart.
     ///
     /// # Errors
@@ -302,6 +336,15 @@ impl LineChart {
             }
         }
 
+        if let Some(&(from, to)) = self
+            .x_breaks
+            .iter()
+            .chain(&self.y_breaks)
+            .find(|(from, to)| !from.is_finite() || !to.is_finite() || to <= from)
+        {
+            return Err(Error::ScaleDomain(format!("Invalid axis break: {from}..{to}")));
+        }
+
         Ok(self)
     }
 
@@ -332,6 +375,21 @@ impl LineChart {
     ///
     /// Returns an error if rendering fails.
     pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let (x_scale, y_scale) = self.scales()?;
+
+        // Render each series
+        for series in &self.series {
+            self.render_series(fb, series, &x_scale, &y_scale);
+        }
+
+        // Segments crossing a break are cut by the blank gap
+        draw_axis_breaks(fb, &x_scale, &y_scale, Rgba::WHITE);
+
+        Ok(())
+    }
+
+    /// Pixel scales of the plot area.
+    fn scales(&self) -> Result<(BrokenScale, BrokenScale)> {
         let ((x_min, x_max), (y_min, y_max)) = self.data_extent();
 
         // Calculate plot area
@@ -339,21 +397,19 @@ impl LineChart {
         let plot_height = self.height - 2 * self.margin;
 
         // Create scales
-        let x_scale = LinearScale::new(
+        let x_scale = BrokenScale::new(
             (x_min, x_max),
             (self.margin as f32, (self.margin + plot_width) as f32),
+            &self.x_breaks,
+            self.break_gap,
         )?;
-        let y_scale = LinearScale::new(
+        let y_scale = BrokenScale::new(
             (y_min, y_max),
             ((self.margin + plot_height) as f32, self.margin as f32),
+            &self.y_breaks,
+            self.break_gap,
         )?;
-
-        // Render each series
-        for series in &self.series {
-            self.render_series(fb, series, &x_scale, &y_scale);
-        }
-
-        Ok(())
+        Ok((x_scale, y_scale))
     }
 
     /// Render a single series.
@@ -361,8 +417,8 @@ impl LineChart {
         &self,
         fb: &mut Framebuffer,
         series: &LineSeries,
-        x_scale: &LinearScale,
-        y_scale: &LinearScale,
+        x_scale: &BrokenScale,
+        y_scale: &BrokenScale,
     ) {
         let point_count = series.point_count();
         if point_count < 2 {
@@ -430,6 +486,45 @@ impl LineChart {
         Ok(fb)
     }
 
+    /// Data cursor panel for this chart: the plot area and every series at
+    /// its rendered position (see [`Crosshair`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the axis scales cannot be built.
+    pub fn cursor_panel(&self) -> Result<CursorPanel> {
+        let (x_scale, y_scale) = self.scales()?;
+        let side = (2 * self.margin) as f32;
+        let panel = CursorPanel::new(
+            self.margin as f32,
+            self.margin as f32,
+            self.width as f32 - side,
+            self.height as f32 - side,
+        );
+        Ok(self.series.iter().fold(panel, |panel, series| {
+            panel.series(CursorSeries::from_xy(
+                series.name.clone(),
+                series.color,
+                &series.x_data,
+                &series.y_data,
+                |x, y| (x_scale.scale(x), y_scale.scale(y)),
+            ))
+        }))
+    }
+
+    /// Export as a standalone HTML page with a data cursor that snaps to the
+    /// nearest point and shows its series, x and y.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering or PNG encoding fails.
+    pub fn to_html(&self) -> Result<HtmlExporter> {
+        let svg = SvgEncoder::from_framebuffer(&self.to_framebuffer()?)?;
+        Ok(HtmlExporter::from_svg(&svg)
+            .title("Line Chart")
+            .crosshair(Crosshair::new().panel(self.cursor_panel()?)))
+    }
+
     /// Get the total number of points across all series.
     #[must_use]
     pub fn total_points(&self) -> usize {
@@ -604,6 +699,45 @@ mod tests {
         assert!(fb.is_ok());
     }
 
+    #[test]
+    fn test_line_chart_axis_break() {
+        assert!(LineChart::new().data(&[0.0, 1.0], &[0.0, 1.0]).y_break(2.0, 1.0).build().is_err());
+
+        let chart = LineChart::new()
+            .data(&[0.0, 1.0, 2.0, 3.0], &[1.0, 2.0, 1000.0, 1.5])
+            .y_break(3.0, 900.0)
+            .margin(10)
+            .dimensions(100, 100)
+            .build()
+            .expect("builder should produce valid result");
+        let fb = chart.to_framebuffer().expect("rendering should succeed");
+        // Gap band (y ~ 50) is blank apart from the zig-zag edges and the marker column
+        let dark_in_gap = (30..70).filter(|&x| fb.get_pixel(x, 48) == Some(Rgba::BLUE)).count();
+        assert_eq!(dark_in_gap, 0);
+    }
+
+    #[test]
+    fn test_line_chart_cursor_panel() {
+        let chart = LineChart::new()
+            .add_series(LineSeries::new("train").data(&[0.0, 10.0], &[0.0, 5.0]))
+            .add_series(LineSeries::new("val").data(&[0.0, 10.0], &[1.0, 4.0]).color(Rgba::RED))
+            .margin(20)
+            .dimensions(220, 120)
+            .build()
+            .expect("builder should produce valid result");
+
+        let panel = chart.cursor_panel().expect("scales should build");
+        let json = Crosshair::new().panel(panel).to_json();
+        assert!(json.contains(r#""rect":[20,20,180,80]"#));
+        // (0, 0) is the bottom-left and (10, 5) the top-right of the plot area
+        assert!(json.contains(r#""name":"train","color":"rgb(0,0,255)","points":[[0,0,20.00,100.00],[10,5,200.00,20.00]]"#));
+        assert!(json.contains(r#""name":"val","color":"rgb(255,0,0)""#));
+
+        let html = chart.to_html().expect("export should succeed").to_html();
+        assert!(html.contains("data:image/png;base64,"));
+        assert!(html.contains(r#"<script type="application/json" id="tv-cursor-data">"#));
+    }
+
     #[test]
     fn test_perpendicular_distance() {
         // Point directly on the line should have distance 0
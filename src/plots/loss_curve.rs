@@ -195,10 +195,11 @@ impl LossCurve {
     /// Create a new loss curve builder.
     #[must_use]
     pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 400);
         Self {
             series: Vec::new(),
-            width: 800,
-            height: 400,
+            width,
+            height,
             margin: 40,
             show_best_markers: true,
             marker_size: 6.0,
@@ -0,0 +1,323 @@
+//! Scatter plot with marginal distributions.
+//!
+//! Attaches a histogram or KDE strip of the x values above a [`ScatterPlot`]
+//! and of the y values to its right. The strips reuse the scatter plot's own
+//! scales, so bins line up with the points they summarize, and the three
+//! panels are composed into a single figure with [`PlotGrid`].
+//!
+//! # References
+//!
+//! - Tukey, J. W. (1977). *Exploratory Data Analysis*. Addison-Wesley.
+//! - Waskom, M. (2021). "seaborn: statistical data visualization". *Journal of
+//!   Open Source Software*, 6(60), 3021. (`jointplot`)
+
+use super::boxplot::compute_kde;
+use super::grid::PlotGrid;
+use super::scatter::ScatterPlot;
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::draw_line_aa;
+use crate::scale::Scale;
+use batuta_common::display::WithDimensions;
+
+/// Number of evaluation points for marginal KDE curves.
+const KDE_POINTS: usize = 64;
+
+/// Distribution summary drawn in the marginal strips.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarginalKind {
+    /// Histogram (Sturges bins unless overridden).
+    #[default]
+    Histogram,
+    /// Gaussian kernel density estimate.
+    Kde,
+}
+
+/// Builder for a scatter plot with top/right marginal distributions.
+#[derive(Debug, Clone)]
+pub struct MarginalScatter {
+    /// Main scatter panel.
+    scatter: ScatterPlot,
+    /// Marginal summary type.
+    kind: MarginalKind,
+    /// Fixed histogram bin count (None = Sturges).
+    bins: Option<usize>,
+    /// Strip size as a fraction of the figure (0.05 - 0.5).
+    strip_fraction: f32,
+    /// Marginal fill / line color.
+    color: Rgba,
+    /// Gap between the scatter and the strips in pixels.
+    gap: u32,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+}
+
+impl MarginalScatter {
+    /// Attach marginal strips to a scatter plot.
+    #[must_use]
+    pub fn new(scatter: ScatterPlot) -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 800);
+        Self {
+            scatter,
+            kind: MarginalKind::default(),
+            bins: None,
+            strip_fraction: 0.2,
+            color: Rgba::rgb(70, 130, 180),
+            gap: 2,
+            width,
+            height,
+        }
+    }
+
+    /// Set the marginal summary type.
+    #[must_use]
+    pub fn kind(mut self, kind: MarginalKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Use a fixed number of histogram bins.
+    #[must_use]
+    pub fn bins(mut self, bins: usize) -> Self {
+        self.bins = Some(bins.max(1));
+        self
+    }
+
+    /// Set the strip size as a fraction of the figure (0.05 - 0.5).
+    #[must_use]
+    pub fn strip_size(mut self, fraction: f32) -> Self {
+        self.strip_fraction = fraction.clamp(0.05, 0.5);
+        self
+    }
+
+    /// Set the marginal color.
+    #[must_use]
+    pub fn color(mut self, color: Rgba) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the gap between the scatter and the strips in pixels.
+    #[must_use]
+    pub fn gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Build and validate the figure.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scatter plot is invalid or the figure is too
+    /// small for the layout.
+    pub fn build(self) -> Result<Self> {
+        let scatter = self.scatter.clone().build()?;
+        let built = Self { scatter, ..self };
+        built.grid()?;
+        Ok(built)
+    }
+
+    /// The 2 × 2 layout: top strip, blank corner, scatter, right strip.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimensions are too small.
+    pub fn grid(&self) -> Result<PlotGrid> {
+        let f = self.strip_fraction;
+        PlotGrid::new(2, 2)
+            .row_heights(&[f, 1.0 - f])
+            .col_widths(&[1.0 - f, f])
+            .gap(self.gap)
+            .dimensions(self.width, self.height)
+            .build()
+    }
+
+    /// Histogram counts over `(lo, hi)` as `(bin_lo, bin_hi, count)`.
+    fn histogram(&self, data: &[f32]) -> Vec<(f32, f32, f32)> {
+        let finite: Vec<f32> = data.iter().copied().filter(|v| v.is_finite()).collect();
+        if finite.is_empty() {
+            return Vec::new();
+        }
+        let lo = finite.iter().copied().fold(f32::INFINITY, f32::min);
+        let hi = finite.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let bins =
+            self.bins.unwrap_or_else(|| (finite.len() as f32).log2().ceil() as usize + 1).max(1);
+        let width = if hi > lo { (hi - lo) / bins as f32 } else { 1.0 };
+
+        let mut counts = vec![0.0_f32; bins];
+        for v in finite {
+            let b = (((v - lo) / width) as usize).min(bins - 1);
+            counts[b] += 1.0;
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| (lo + width * i as f32, lo + width * (i + 1) as f32, c))
+            .collect()
+    }
+
+    /// Render one strip. `horizontal` strips run along x and grow upwards;
+    /// vertical strips run along y and grow to the right.
+    fn render_strip(
+        &self,
+        data: &[f32],
+        to_px: impl Fn(f32) -> f32,
+        w: u32,
+        h: u32,
+        horizontal: bool,
+    ) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(w, h)?;
+        fb.clear(Rgba::WHITE);
+        let depth = if horizontal { h as f32 - 2.0 } else { w as f32 - 2.0 }.max(1.0);
+
+        match self.kind {
+            MarginalKind::Histogram => {
+                let bins = self.histogram(data);
+                let max = bins.iter().map(|b| b.2).fold(0.0_f32, f32::max).max(1.0);
+                let fill = self.color.with_alpha(190);
+                for (lo, hi, count) in bins {
+                    let (a, b) = (to_px(lo), to_px(hi));
+                    let (start, len) = (a.min(b), (a - b).abs().max(1.0));
+                    let extent = count / max * depth;
+                    if horizontal {
+                        let y = h as f32 - extent;
+                        fb.fill_rect(start as u32, y as u32, len as u32, extent as u32, fill);
+                    } else {
+                        fb.fill_rect(0, start as u32, extent as u32, len as u32, fill);
+                    }
+                }
+            }
+            MarginalKind::Kde => {
+                let kde = compute_kde(data, None, KDE_POINTS);
+                let max = kde.iter().map(|p| p.1).fold(0.0_f32, f32::max);
+                if max > 0.0 {
+                    let pts: Vec<(f32, f32)> = kde
+                        .iter()
+                        .map(|&(v, d)| {
+                            let along = to_px(v);
+                            let extent = d / max * depth;
+                            if horizontal {
+                                (along, h as f32 - 1.0 - extent)
+                            } else {
+                                (extent, along)
+                            }
+                        })
+                        .collect();
+                    for pair in pts.windows(2) {
+                        draw_line_aa(
+                            &mut fb, pair[0].0, pair[0].1, pair[1].0, pair[1].1, self.color,
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(fb)
+    }
+
+    /// Render the figure to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the layout or any panel fails to render.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let figure = self.to_framebuffer()?;
+        fb.blit(&figure, 0, 0);
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the layout or any panel fails to render.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let grid = self.grid()?;
+        let main = grid
+            .cell_rect(1, 0)
+            .ok_or_else(|| Error::Rendering("Marginal layout is missing the main cell".into()))?;
+        let scatter = self.scatter.clone().dimensions(main.width, main.height);
+        let (x_scale, y_scale) = scatter.scales()?;
+
+        grid.compose(|row, col, w, h| match (row, col) {
+            (1, 0) => scatter.to_framebuffer().map(Some),
+            (0, 0) => {
+                self.render_strip(scatter.x_data(), |v| x_scale.scale(v), w, h, true).map(Some)
+            }
+            (1, 1) => {
+                self.render_strip(scatter.y_data(), |v| y_scale.scale(v), w, h, false).map(Some)
+            }
+            _ => Ok(None),
+        })
+    }
+}
+
+impl WithDimensions for MarginalScatter {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scatter() -> ScatterPlot {
+        ScatterPlot::new()
+            .x(&[0.0, 1.0, 1.0, 2.0, 2.0, 2.0, 3.0, 4.0])
+            .y(&[1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 4.0, 5.0])
+            .margin(5)
+    }
+
+    #[test]
+    fn test_marginal_builder() {
+        let fig = MarginalScatter::new(scatter())
+            .strip_size(0.25)
+            .dimensions(200, 200)
+            .build()
+            .expect("builder should produce valid result");
+        let grid = fig.grid().expect("grid should build");
+        let top = grid.cell_rect(0, 0).expect("cell");
+        let main = grid.cell_rect(1, 0).expect("cell");
+        // Top strip shares the scatter's column, so x axes line up.
+        assert_eq!((top.x, top.width), (main.x, main.width));
+    }
+
+    #[test]
+    fn test_marginal_histogram_bins() {
+        let fig = MarginalScatter::new(scatter()).bins(4);
+        let bins = fig.histogram(&[0.0, 1.0, 1.0, 2.0, 4.0]);
+        assert_eq!(bins.len(), 4);
+        let counts: Vec<f32> = bins.iter().map(|b| b.2).collect();
+        assert_eq!(counts, vec![1.0, 2.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_marginal_validation() {
+        assert!(MarginalScatter::new(ScatterPlot::new()).build().is_err());
+        assert!(MarginalScatter::new(scatter()).dimensions(2, 2).build().is_err());
+    }
+
+    #[test]
+    fn test_marginal_render() {
+        for kind in [MarginalKind::Histogram, MarginalKind::Kde] {
+            let fig = MarginalScatter::new(scatter())
+                .kind(kind)
+                .color(Rgba::RED)
+                .dimensions(160, 160)
+                .build()
+                .expect("builder should produce valid result");
+            let fb = fig.to_framebuffer().expect("rendering should succeed");
+
+            let top = fig.grid().expect("grid").cell_rect(0, 0).expect("cell");
+            let drawn = (top.y..top.y + top.height).any(|y| {
+                (top.x..top.x + top.width).any(|x| fb.get_pixel(x, y) != Some(Rgba::WHITE))
+            });
+            assert!(drawn);
+        }
+    }
+}
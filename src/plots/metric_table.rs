@@ -0,0 +1,638 @@
+//! Metric table with inline micro-charts for static reports.
+//!
+//! The static-export counterpart of the monitor `DataFrame` widget: a
+//! column-oriented table whose cells can be text, numbers, sparklines or
+//! bullet charts, rendered to a single image sized to fit its content.
+//!
+//! # References
+//!
+//! - Few, S. (2006). *Bullet Graph Design Specification*. Perceptual Edge.
+//! - Tufte, E. R. (2006). *Beautiful Evidence*, ch. "Sparklines". Graphics Press.
+
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_circle, draw_line, draw_line_aa, draw_text, i32_px, TextStyle};
+
+use super::conditional::{ConditionalFormat, FormatRule, RuleIcon};
+
+/// Side of a conditional-format icon in pixels.
+const ICON_SIZE: u32 = 7;
+/// Gap between an icon and the cell content.
+const ICON_GAP: u32 = 3;
+
+/// A bullet chart cell: a value bar over qualitative bands with an optional target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BulletCell {
+    /// Measured value.
+    pub value: f32,
+    /// Target marker.
+    pub target: Option<f32>,
+    /// Value at the right end of the scale (scale starts at zero).
+    pub max: f32,
+    /// Upper bounds of the qualitative bands (e.g. poor / ok), ascending.
+    pub bands: Vec<f32>,
+}
+
+impl BulletCell {
+    /// Create a bullet cell on a `0..max` scale.
+    #[must_use]
+    pub fn new(value: f32, max: f32) -> Self {
+        Self { value, target: None, max, bands: Vec::new() }
+    }
+
+    /// Set the target marker.
+    #[must_use]
+    pub fn target(mut self, target: f32) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Set the qualitative band boundaries.
+    #[must_use]
+    pub fn bands(mut self, bands: &[f32]) -> Self {
+        self.bands = bands.to_vec();
+        self
+    }
+
+    /// Position of `v` along the scale as a 0-1 fraction.
+    fn fraction(&self, v: f32) -> f32 {
+        if self.max > 0.0 {
+            (v / self.max).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// A table cell.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum MetricCell {
+    /// Empty cell.
+    #[default]
+    Empty,
+    /// Left-aligned text.
+    Text(String),
+    /// Right-aligned number with a fixed count of decimals.
+    Number {
+        /// Value.
+        value: f64,
+        /// Decimal places.
+        decimals: usize,
+    },
+    /// Line sparkline with the last point marked.
+    Sparkline(Vec<f32>),
+    /// Bullet chart.
+    Bullet(BulletCell),
+}
+
+impl MetricCell {
+    /// Whether the cell is drawn as a chart rather than text.
+    fn is_chart(&self) -> bool {
+        matches!(self, Self::Sparkline(_) | Self::Bullet(_))
+    }
+
+    /// Value tested by conditional formatting: the number, the bullet
+    /// value, or the last finite sparkline point.
+    #[must_use]
+    pub fn value(&self) -> Option<f64> {
+        match self {
+            Self::Number { value, .. } => Some(*value),
+            Self::Bullet(bullet) => Some(f64::from(bullet.value)),
+            Self::Sparkline(series) => {
+                series.iter().rev().find(|v| v.is_finite()).map(|&v| f64::from(v))
+            }
+            Self::Empty | Self::Text(_) => None,
+        }
+    }
+
+    /// Text content for text-like cells.
+    fn text(&self) -> Option<String> {
+        match self {
+            Self::Text(s) => Some(s.clone()),
+            Self::Number { value, decimals } => {
+                Some(crate::style::locale().format_number(*value, *decimals))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A named column of cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricColumn {
+    /// Header text.
+    pub name: String,
+    /// Cells top to bottom.
+    pub cells: Vec<MetricCell>,
+    /// Rules highlighting cells by value.
+    pub format: Option<ConditionalFormat>,
+}
+
+impl MetricColumn {
+    /// Create a column from cells.
+    #[must_use]
+    pub fn new(name: impl Into<String>, cells: Vec<MetricCell>) -> Self {
+        Self { name: name.into(), cells, format: None }
+    }
+
+    /// Highlight cells with conditional formatting rules.
+    #[must_use]
+    pub fn format(mut self, format: ConditionalFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// The formatting rule matching a cell, if any.
+    fn rule_for(&self, cell: &MetricCell) -> Option<&FormatRule> {
+        self.format.as_ref()?.evaluate(cell.value()?)
+    }
+
+    /// Pixels reserved before cell content for icons.
+    fn icon_room(&self) -> u32 {
+        if self.format.as_ref().is_some_and(ConditionalFormat::has_icons) {
+            ICON_SIZE + ICON_GAP
+        } else {
+            0
+        }
+    }
+
+    /// Create a text column.
+    #[must_use]
+    pub fn text(name: impl Into<String>, values: &[&str]) -> Self {
+        Self::new(name, values.iter().map(|s| MetricCell::Text((*s).to_string())).collect())
+    }
+
+    /// Create a numeric column.
+    #[must_use]
+    pub fn numbers(name: impl Into<String>, values: &[f64], decimals: usize) -> Self {
+        Self::new(
+            name,
+            values.iter().map(|&value| MetricCell::Number { value, decimals }).collect(),
+        )
+    }
+
+    /// Create a sparkline column, one series per row.
+    #[must_use]
+    pub fn sparklines(name: impl Into<String>, series: Vec<Vec<f32>>) -> Self {
+        Self::new(name, series.into_iter().map(MetricCell::Sparkline).collect())
+    }
+
+    /// Create a bullet chart column.
+    #[must_use]
+    pub fn bullets(name: impl Into<String>, bullets: Vec<BulletCell>) -> Self {
+        Self::new(name, bullets.into_iter().map(MetricCell::Bullet).collect())
+    }
+}
+
+/// Builder for metric tables.
+#[derive(Debug, Clone)]
+pub struct MetricTable {
+    /// Columns left to right.
+    columns: Vec<MetricColumn>,
+    /// Width of sparkline and bullet cells in pixels.
+    chart_width: u32,
+    /// Row height in pixels.
+    row_height: u32,
+    /// Horizontal cell padding in pixels.
+    cell_padding: u32,
+    /// Shade alternate rows.
+    zebra: bool,
+    /// Text color.
+    text_color: Rgba,
+    /// Sparkline and bullet value color.
+    chart_color: Rgba,
+    /// Bullet target marker color.
+    target_color: Rgba,
+    /// Output width in pixels (0 = fit content).
+    width: u32,
+    /// Output height in pixels (0 = fit content).
+    height: u32,
+    /// Margin around the table.
+    margin: u32,
+}
+
+impl Default for MetricTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetricTable {
+    /// Create a new metric table builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let font = TextStyle::current();
+        Self {
+            columns: Vec::new(),
+            chart_width: 80,
+            row_height: font.height() + 11,
+            cell_padding: 6,
+            zebra: true,
+            text_color: font.color,
+            chart_color: Rgba::rgb(70, 130, 180),
+            target_color: Rgba::rgb(200, 40, 40),
+            width: 0,
+            height: 0,
+            margin: 8,
+        }
+    }
+
+    /// Add a column.
+    #[must_use]
+    pub fn column(mut self, column: MetricColumn) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Set the width of sparkline and bullet cells in pixels.
+    #[must_use]
+    pub fn chart_width(mut self, width: u32) -> Self {
+        self.chart_width = width.max(8);
+        self
+    }
+
+    /// Set the row height in pixels.
+    #[must_use]
+    pub fn row_height(mut self, height: u32) -> Self {
+        self.row_height = height.max(TextStyle::current().height() + 2);
+        self
+    }
+
+    /// Set horizontal cell padding in pixels.
+    #[must_use]
+    pub fn cell_padding(mut self, padding: u32) -> Self {
+        self.cell_padding = padding;
+        self
+    }
+
+    /// Enable or disable alternate row shading.
+    #[must_use]
+    pub fn zebra(mut self, zebra: bool) -> Self {
+        self.zebra = zebra;
+        self
+    }
+
+    /// Set the text color.
+    #[must_use]
+    pub fn text_color(mut self, color: Rgba) -> Self {
+        self.text_color = color;
+        self
+    }
+
+    /// Set the sparkline and bullet value color.
+    #[must_use]
+    pub fn chart_color(mut self, color: Rgba) -> Self {
+        self.chart_color = color;
+        self
+    }
+
+    /// Set the bullet target marker color.
+    #[must_use]
+    pub fn target_color(mut self, color: Rgba) -> Self {
+        self.target_color = color;
+        self
+    }
+
+    /// Set margin.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Validate and build the table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no columns.
+    pub fn build(self) -> Result<Self> {
+        if self.columns.is_empty() {
+            return Err(Error::EmptyData);
+        }
+        Ok(self)
+    }
+
+    /// Number of data rows (the longest column).
+    #[must_use]
+    pub fn row_count(&self) -> usize {
+        self.columns.iter().map(|c| c.cells.len()).max().unwrap_or(0)
+    }
+
+    /// Pixel width of each column including padding.
+    #[must_use]
+    pub fn column_widths(&self) -> Vec<u32> {
+        let font = TextStyle::current();
+        self.columns
+            .iter()
+            .map(|col| {
+                let content = col
+                    .cells
+                    .iter()
+                    .map(|cell| match cell.text() {
+                        Some(text) => font.width(&text),
+                        None if cell.is_chart() => self.chart_width,
+                        None => 0,
+                    })
+                    .map(|w| w + col.icon_room())
+                    .chain(std::iter::once(font.width(&col.name)))
+                    .max()
+                    .unwrap_or(0);
+                content + 2 * self.cell_padding
+            })
+            .collect()
+    }
+
+    /// Image size that fits every column and row.
+    #[must_use]
+    pub fn natural_size(&self) -> (u32, u32) {
+        let width = self.column_widths().iter().sum::<u32>() + 2 * self.margin;
+        let height = (self.row_count() as u32 + 1) * self.row_height + 2 * self.margin;
+        (width, height)
+    }
+
+    /// Render the table to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let widths = self.column_widths();
+        let table_width: u32 = widths.iter().sum();
+        let font = TextStyle::current().with_color(self.text_color);
+        let th = font.height();
+        let text_y = |top: u32| i32_px(top + (self.row_height - th.min(self.row_height)) / 2);
+
+        if self.zebra {
+            for row in (1..self.row_count()).step_by(2) {
+                let y = self.margin + (row as u32 + 1) * self.row_height;
+                fb.fill_rect(
+                    self.margin,
+                    y,
+                    table_width,
+                    self.row_height,
+                    Rgba::rgb(245, 245, 245),
+                );
+            }
+        }
+
+        let mut x = self.margin;
+        for (col, &width) in self.columns.iter().zip(&widths) {
+            let pad_left = x + self.cell_padding;
+            let inner = width - 2 * self.cell_padding;
+            font.draw(fb, i32_px(pad_left), text_y(self.margin), &col.name);
+
+            for (row, cell) in col.cells.iter().enumerate() {
+                let top = self.margin + (row as u32 + 1) * self.row_height;
+                let rule = col.rule_for(cell);
+                if let Some(bg) = rule.and_then(|r| r.background) {
+                    fb.fill_rect(x, top, width, self.row_height, bg);
+                }
+                let text_color = rule.and_then(|r| r.color).unwrap_or(self.text_color);
+                let chart_color = rule.and_then(|r| r.color).unwrap_or(self.chart_color);
+                if let Some(icon) = rule.and_then(|r| r.icon) {
+                    Self::render_icon(fb, icon, pad_left, text_y(top), text_color);
+                }
+                let room = col.icon_room();
+                let (left, inner) = (pad_left + room, inner - room);
+                match cell {
+                    MetricCell::Empty => {}
+                    MetricCell::Text(text) => {
+                        font.with_color(text_color).draw(fb, i32_px(left), text_y(top), text);
+                    }
+                    MetricCell::Number { .. } => {
+                        let text = cell.text().unwrap_or_default();
+                        let tx = left + inner - font.width(&text);
+                        font.with_color(text_color).draw(fb, i32_px(tx), text_y(top), &text);
+                    }
+                    MetricCell::Sparkline(series) => {
+                        self.render_sparkline(fb, series, (left, top, inner), chart_color);
+                    }
+                    MetricCell::Bullet(bullet) => {
+                        self.render_bullet(fb, bullet, (left, top, inner), chart_color);
+                    }
+                }
+            }
+            x += width;
+        }
+
+        // Header rule
+        let rule_y = i32_px(self.margin + self.row_height - 1);
+        draw_line(
+            fb,
+            i32_px(self.margin),
+            rule_y,
+            i32_px(self.margin + table_width),
+            rule_y,
+            self.text_color,
+        );
+
+        Ok(())
+    }
+
+    /// Draw a conditional-format icon with its top-left corner at `(x, y)`.
+    fn render_icon(fb: &mut Framebuffer, icon: RuleIcon, x: u32, y: i32, color: Rgba) {
+        let half = i32_px(ICON_SIZE / 2);
+        match icon {
+            RuleIcon::Dot => draw_circle(fb, i32_px(x) + half, y + half, half - 1, color),
+            RuleIcon::Up | RuleIcon::Down => {
+                let y = y.max(0) as u32;
+                for row in 0..ICON_SIZE {
+                    let half_width =
+                        if icon == RuleIcon::Up { row / 2 } else { (ICON_SIZE - 1 - row) / 2 };
+                    fb.fill_rect(
+                        x + ICON_SIZE / 2 - half_width,
+                        y + row,
+                        2 * half_width + 1,
+                        1,
+                        color,
+                    );
+                }
+            }
+            RuleIcon::Alert => draw_text(fb, i32_px(x) + 1, y, "!", 1, color),
+        }
+    }
+
+    /// Draw a sparkline scaled to its own extent, marking the last point.
+    fn render_sparkline(
+        &self,
+        fb: &mut Framebuffer,
+        series: &[f32],
+        (x0, top, w): (u32, u32, u32),
+        color: Rgba,
+    ) {
+        let finite: Vec<(usize, f32)> =
+            series.iter().copied().enumerate().filter(|(_, v)| v.is_finite()).collect();
+        if finite.is_empty() {
+            return;
+        }
+        let (lo, hi) =
+            finite.iter().fold((f32::MAX, f32::MIN), |(lo, hi), &(_, v)| (lo.min(v), hi.max(v)));
+        let span = if hi > lo { hi - lo } else { 1.0 };
+        let inset = 3.0;
+        let h = self.row_height as f32 - 2.0 * inset;
+        let step = if series.len() > 1 { w as f32 / (series.len() - 1) as f32 } else { 0.0 };
+        let points: Vec<(f32, f32)> = finite
+            .iter()
+            .map(|&(i, v)| {
+                (x0 as f32 + i as f32 * step, top as f32 + inset + h * (1.0 - (v - lo) / span))
+            })
+            .collect();
+        for pair in points.windows(2) {
+            draw_line_aa(fb, pair[0].0, pair[0].1, pair[1].0, pair[1].1, color);
+        }
+        if let Some(&(x, y)) = points.last() {
+            let (mx, my) = (x.round() as u32, y.round() as u32);
+            fb.fill_rect(mx.saturating_sub(1), my.saturating_sub(1), 3, 3, self.target_color);
+        }
+    }
+
+    /// Draw a horizontal bullet chart.
+    fn render_bullet(
+        &self,
+        fb: &mut Framebuffer,
+        bullet: &BulletCell,
+        (x0, top, w): (u32, u32, u32),
+        color: Rgba,
+    ) {
+        let band_h = self.row_height.saturating_sub(6).max(3);
+        let band_y = top + (self.row_height - band_h) / 2;
+        let px = |v: f32| (bullet.fraction(v) * w as f32).round() as u32;
+
+        // Qualitative bands from dark (low) to light, the last reaching full scale
+        let mut bounds: Vec<f32> =
+            bullet.bands.iter().copied().filter(|b| *b > 0.0 && *b < bullet.max).collect();
+        bounds.push(bullet.max);
+        let steps = (bounds.len() as u32).saturating_sub(1).max(1);
+        let mut start = 0;
+        for (i, bound) in bounds.iter().enumerate() {
+            let end = px(*bound);
+            let shade = (195 + 45 * i as u32 / steps) as u8;
+            if end > start {
+                fb.fill_rect(
+                    x0 + start,
+                    band_y,
+                    end - start,
+                    band_h,
+                    Rgba::rgb(shade, shade, shade),
+                );
+            }
+            start = end;
+        }
+
+        let bar_h = (band_h / 3).max(1);
+        fb.fill_rect(x0, band_y + (band_h - bar_h) / 2, px(bullet.value), bar_h, color);
+
+        if let Some(target) = bullet.target {
+            let tx = (x0 + px(target)).min(x0 + w.saturating_sub(1));
+            fb.fill_rect(tx, band_y + 1, 2, band_h.saturating_sub(2), self.target_color);
+        }
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// Unset dimensions fit the content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let (natural_w, natural_h) = self.natural_size();
+        let width = if self.width == 0 { natural_w } else { self.width };
+        let height = if self.height == 0 { natural_h } else { self.height };
+        let mut fb = Framebuffer::new(width, height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+impl batuta_common::display::WithDimensions for MetricTable {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::text_width;
+
+    fn sample() -> MetricTable {
+        MetricTable::new()
+            .column(MetricColumn::text("metric", &["cpu", "memory"]))
+            .column(MetricColumn::numbers("p99", &[12.345, 7.0], 1))
+            .column(MetricColumn::sparklines(
+                "trend",
+                vec![vec![1.0, 3.0, 2.0, 4.0], vec![5.0, 5.0, f32::NAN, 4.0]],
+            ))
+            .column(MetricColumn::bullets(
+                "load",
+                vec![BulletCell::new(0.7, 1.0).target(0.8).bands(&[0.5, 0.9])],
+            ))
+    }
+
+    #[test]
+    fn test_metric_table_layout() {
+        let table = sample().chart_width(60).cell_padding(4).margin(2);
+        assert_eq!(table.row_count(), 2);
+        let widths = table.column_widths();
+        assert_eq!(widths[0], text_width("memory", 1) + 8);
+        assert_eq!(widths[1], text_width("12.3", 1) + 8);
+        assert_eq!(widths[2], 68);
+        assert_eq!(table.natural_size(), (widths.iter().sum::<u32>() + 4, 3 * 18 + 4));
+    }
+
+    #[test]
+    fn test_metric_cell_text() {
+        let cell = MetricCell::Number { value: 2.0 / 3.0, decimals: 2 };
+        assert_eq!(cell.text().as_deref(), Some("0.67"));
+        assert!(MetricCell::Sparkline(vec![]).text().is_none());
+        assert!(MetricCell::Bullet(BulletCell::new(1.0, 2.0)).is_chart());
+    }
+
+    #[test]
+    fn test_bullet_fraction_clamped() {
+        let bullet = BulletCell::new(5.0, 4.0);
+        assert!((bullet.fraction(2.0) - 0.5).abs() < f32::EPSILON);
+        assert!((bullet.fraction(9.0) - 1.0).abs() < f32::EPSILON);
+        assert!(BulletCell::new(1.0, 0.0).fraction(1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_metric_table_render_fits_content() {
+        assert!(MetricTable::new().build().is_err());
+        let table = sample().target_color(Rgba::RED).build().expect("valid table");
+        let fb = table.to_framebuffer().expect("rendering should succeed");
+        assert_eq!((fb.width(), fb.height()), table.natural_size());
+        // Bullet target and sparkline end markers are drawn in the target color
+        let red = (0..fb.height())
+            .filter(|&y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(Rgba::RED)))
+            .count();
+        assert!(red > 3);
+    }
+
+    #[test]
+    fn test_conditional_format_highlights_cells() {
+        let highlight = Rgba::rgb(255, 220, 220);
+        let format = ConditionalFormat::new().rule(
+            FormatRule::above(10.0).color(Rgba::RED).background(highlight).icon(RuleIcon::Up),
+        );
+        let plain = MetricTable::new().column(MetricColumn::numbers("n", &[12.0, 3.0], 0));
+        let table = MetricTable::new()
+            .zebra(false)
+            .column(MetricColumn::numbers("n", &[12.0, 3.0], 0).format(format));
+        // Icons widen the column
+        assert_eq!(table.column_widths()[0], plain.column_widths()[0] + ICON_SIZE + ICON_GAP);
+        assert_eq!(MetricCell::Sparkline(vec![1.0, 4.0, f32::NAN]).value(), Some(4.0));
+
+        let fb = table.to_framebuffer().expect("rendering should succeed");
+        let row_has = |row: u32, color: Rgba| {
+            let top = 8 + row * 18;
+            (top..top + 18).any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(color)))
+        };
+        assert!(row_has(1, highlight) && row_has(1, Rgba::RED));
+        assert!(!row_has(2, highlight) && !row_has(2, Rgba::RED));
+    }
+}
@@ -3,21 +3,77 @@
 //! Provides ready-to-use visualization types with builder APIs.
 
 mod boxplot;
+mod bump;
+mod colorbar;
+mod colormap;
+mod conditional;
 mod confusion_matrix;
+mod control;
+mod dependency_wheel;
+mod flame;
 mod force_graph;
+mod grid;
 mod heatmap;
+mod heatstrip;
 mod histogram;
+mod histogram2d;
+mod horizon;
+mod icicle;
 mod line;
 mod loss_curve;
+mod marginal;
+mod metric_table;
+mod pareto;
+mod polar_heatmap;
+mod profile;
+mod punchcard;
+mod raincloud;
 mod roc_pr;
 mod scatter;
+mod spark_matrix;
+mod splom;
+mod swimlane;
+mod ternary;
+mod timeline;
+mod upset;
+mod venn;
+mod waffle;
 
 pub use boxplot::{BoxPlot, BoxStats, BuiltBoxPlot, BuiltViolinPlot, ViolinPlot};
+pub use bump::{BumpChart, BumpSeries};
+pub use colorbar::{Colorbar, ColorbarOrientation};
+pub use colormap::{
+    Colormap, ColormapPreview, ColormapReport, CvdCheck, MIN_PROBE_DISTANCE, UNIFORMITY_THRESHOLD,
+};
+pub use conditional::{Condition, ConditionalFormat, FormatRule, RuleIcon};
 pub use confusion_matrix::{ConfusionMatrix, ConfusionMatrixMetrics, Normalization};
+pub use control::{ControlChart, ControlLimits, ControlMode, RuleViolation, WesternElectricRule};
+pub use dependency_wheel::{DependencyWheel, WheelNode};
+pub use flame::{FlameGraph, FlamePalette, StackNode, StackTree};
 pub use force_graph::{BuiltForceGraph, ForceGraph, GraphEdge, GraphNode};
+pub use grid::{CellRect, PlotGrid};
 pub use heatmap::{Heatmap, HeatmapPalette};
+pub use heatstrip::{Heatstrip, StripAggregation};
 pub use histogram::{BinStrategy, Histogram};
+pub use histogram2d::Histogram2D;
+pub use horizon::{HorizonChart, HorizonSeries, NegativeMode};
+pub use icicle::{Icicle, IcicleOrientation};
 pub use line::{douglas_peucker, LineChart, LineSeries};
 pub use loss_curve::{LossCurve, MetricSeries, SeriesSummary};
+pub use marginal::{MarginalKind, MarginalScatter};
+pub use metric_table::{BulletCell, MetricCell, MetricColumn, MetricTable};
+pub use pareto::{ParetoCategory, ParetoChart};
+pub use polar_heatmap::PolarHeatmap;
+pub use profile::{profile, ColumnProfile, ColumnSummary, DataProfile};
+pub use punchcard::PunchCard;
+pub use raincloud::Raincloud;
 pub use roc_pr::{compute_pr, compute_roc, PrCurve, PrData, RocCurve, RocData};
 pub use scatter::ScatterPlot;
+pub use spark_matrix::{SparkMatrix, SparkScale};
+pub use splom::{Splom, SplomDiagonal};
+pub use swimlane::{UtilizationLane, UtilizationSwimlane};
+pub use ternary::{TernaryKind, TernaryPlot};
+pub use timeline::{Timeline, TimelineEvent};
+pub use upset::{UpSet, UpSetIntersection, UpSetOrder};
+pub use venn::{circle_overlap_area, Venn, VennCircle};
+pub use waffle::{WaffleCategory, WaffleChart, WaffleRounding, WaffleShape};
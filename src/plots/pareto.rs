@@ -0,0 +1,382 @@
+//! Pareto chart.
+//!
+//! Combines bars sorted by descending value with a cumulative-percentage line
+//! plotted against a secondary (0–100%) axis. A threshold guide (80% by
+//! default) highlights the "vital few" categories that account for most of
+//! the total — the classic view for defect and error analysis.
+//!
+//! # References
+//!
+//! - Juran, J. M. (1951). *Quality Control Handbook*. McGraw-Hill.
+//! - Wilkinson, L. (2006). "Revising the Pareto Chart". *The American
+//!   Statistician*, 60(4), 332-334.
+
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_circle, draw_line_aa, i32_px, TextStyle};
+use crate::scale::{BandScale, Scale};
+
+/// A single category in a Pareto chart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParetoCategory {
+    /// Category label.
+    pub label: String,
+    /// Category value (count, cost, ...).
+    pub value: f32,
+}
+
+/// Builder for creating Pareto charts.
+#[derive(Debug, Clone)]
+pub struct ParetoChart {
+    /// Categories in insertion order.
+    categories: Vec<ParetoCategory>,
+    /// Cumulative-percentage threshold in percent (default: 80).
+    threshold: f32,
+    /// Bar color for categories inside the threshold.
+    bar_color: Rgba,
+    /// Bar color for categories beyond the threshold.
+    tail_color: Rgba,
+    /// Cumulative line color.
+    line_color: Rgba,
+    /// Threshold guide color.
+    threshold_color: Rgba,
+    /// Gap between bars as a fraction of the band step (0.0 - 0.9).
+    bar_padding: f32,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the plot.
+    margin: u32,
+}
+
+impl Default for ParetoChart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParetoChart {
+    /// Create a new Pareto chart builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 400);
+        Self {
+            categories: Vec::new(),
+            threshold: 80.0,
+            bar_color: Rgba::rgb(31, 119, 180),
+            tail_color: Rgba::rgb(174, 199, 232),
+            line_color: Rgba::rgb(214, 39, 40),
+            threshold_color: Rgba::rgb(127, 127, 127),
+            bar_padding: 0.2,
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Add a category.
+    #[must_use]
+    pub fn category(mut self, label: &str, value: f32) -> Self {
+        self.categories.push(ParetoCategory { label: label.to_string(), value });
+        self
+    }
+
+    /// Set all categories from parallel label and value slices.
+    ///
+    /// Extra labels or values beyond the shorter slice are ignored.
+    #[must_use]
+    pub fn data(mut self, labels: &[impl AsRef<str>], values: &[f32]) -> Self {
+        self.categories = labels
+            .iter()
+            .zip(values)
+            .map(|(l, &v)| ParetoCategory { label: l.as_ref().to_string(), value: v })
+            .collect();
+        self
+    }
+
+    /// Set the cumulative-percentage threshold (clamped to 0-100).
+    #[must_use]
+    pub fn threshold(mut self, percent: f32) -> Self {
+        self.threshold = percent.clamp(0.0, 100.0);
+        self
+    }
+
+    /// Set the bar color for categories inside the threshold.
+    #[must_use]
+    pub fn bar_color(mut self, color: Rgba) -> Self {
+        self.bar_color = color;
+        self
+    }
+
+    /// Set the bar color for categories beyond the threshold.
+    #[must_use]
+    pub fn tail_color(mut self, color: Rgba) -> Self {
+        self.tail_color = color;
+        self
+    }
+
+    /// Set the cumulative line color.
+    #[must_use]
+    pub fn line_color(mut self, color: Rgba) -> Self {
+        self.line_color = color;
+        self
+    }
+
+    /// Set the threshold guide color.
+    #[must_use]
+    pub fn threshold_color(mut self, color: Rgba) -> Self {
+        self.threshold_color = color;
+        self
+    }
+
+    /// Set the gap between bars as a fraction of the band step (0.0 - 0.9).
+    #[must_use]
+    pub fn bar_padding(mut self, padding: f32) -> Self {
+        self.bar_padding = padding.clamp(0.0, 0.9);
+        self
+    }
+
+    /// Set the margin around the plot.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the chart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no categories, any value is negative or
+    /// non-finite, or all values are zero.
+    pub fn build(self) -> Result<Self> {
+        if self.categories.is_empty() {
+            return Err(Error::EmptyData);
+        }
+
+        if let Some(bad) = self.categories.iter().find(|c| !c.value.is_finite() || c.value < 0.0) {
+            return Err(Error::Rendering(format!(
+                "Pareto value for '{}' must be finite and non-negative, got {}",
+                bad.label, bad.value
+            )));
+        }
+
+        if self.total() <= 0.0 {
+            return Err(Error::Rendering("Pareto chart total must be positive".to_string()));
+        }
+
+        Ok(self)
+    }
+
+    /// Sum of all category values.
+    #[must_use]
+    pub fn total(&self) -> f32 {
+        self.categories.iter().map(|c| c.value).sum()
+    }
+
+    /// Categories sorted by descending value (stable for ties).
+    #[must_use]
+    pub fn sorted(&self) -> Vec<ParetoCategory> {
+        let mut sorted = self.categories.clone();
+        sorted.sort_by(|a, b| b.value.total_cmp(&a.value));
+        sorted
+    }
+
+    /// Cumulative percentages (0-100) following the sorted order.
+    #[must_use]
+    pub fn cumulative_percent(&self) -> Vec<f32> {
+        let total = self.total();
+        if total <= 0.0 {
+            return vec![0.0; self.categories.len()];
+        }
+        let mut running = 0.0;
+        self.sorted()
+            .iter()
+            .map(|c| {
+                running += c.value;
+                running / total * 100.0
+            })
+            .collect()
+    }
+
+    /// Number of leading sorted categories needed to reach the threshold.
+    #[must_use]
+    pub fn vital_few(&self) -> usize {
+        let cumulative = self.cumulative_percent();
+        // Small tolerance so that exactly hitting the threshold counts.
+        cumulative
+            .iter()
+            .position(|&p| p >= self.threshold - 1e-3)
+            .map_or(cumulative.len(), |i| i + 1)
+    }
+
+    /// Render the chart to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no categories.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let font = TextStyle::current();
+        let sorted = self.sorted();
+        let cumulative = self.cumulative_percent();
+        let vital = self.vital_few();
+        let max_value = sorted.first().map_or(0.0, |c| c.value);
+
+        // Right gutter holds the secondary percentage axis labels.
+        let axis_label_w = font.width("100%") + 6;
+        let label_h = font.height() + 6;
+
+        let left = self.margin as f32;
+        let right = self.width.saturating_sub(self.margin + axis_label_w) as f32;
+        let top = (self.margin + font.height() / 2) as f32;
+        let bottom = self.height.saturating_sub(self.margin + label_h) as f32;
+        let plot_h = (bottom - top).max(1.0);
+
+        let labels: Vec<&str> = sorted.iter().map(|c| c.label.as_str()).collect();
+        let x_scale = BandScale::new(&labels, (left, right))?.padding(self.bar_padding);
+        let axis_color = Rgba::rgb(60, 60, 60);
+
+        // Bars (primary axis: 0..max_value).
+        for (i, cat) in sorted.iter().enumerate() {
+            let h = if max_value > 0.0 { cat.value / max_value * plot_h } else { 0.0 };
+            let color = if i < vital { self.bar_color } else { self.tail_color };
+            let x0 = x_scale.scale(i);
+            fb.fill_rect(
+                x0.round() as u32,
+                (bottom - h).round() as u32,
+                x_scale.bandwidth().round().max(1.0) as u32,
+                h.round() as u32,
+                color,
+            );
+
+            let label_x = x_scale.center(i) as i32 - i32_px(font.width(&cat.label)) / 2;
+            font.draw(fb, label_x, bottom as i32 + 4, &cat.label);
+        }
+
+        // Secondary axis (0-100%).
+        let pct_y = |p: f32| bottom - p / 100.0 * plot_h;
+        fb.fill_rect(right as u32, top as u32, 1, plot_h as u32, axis_color);
+        for tick in [0.0_f32, 25.0, 50.0, 75.0, 100.0] {
+            let y = pct_y(tick);
+            fb.fill_rect(right as u32, y as u32, 4, 1, axis_color);
+            let text = format!("{tick:.0}%");
+            font.draw(fb, right as i32 + 6, y as i32 - 3, &text);
+        }
+
+        // Threshold guide (dashed).
+        let ty = pct_y(self.threshold) as u32;
+        let mut x = left as u32;
+        while (x as f32) < right {
+            let dash = 6.min(right as u32 - x);
+            fb.fill_rect(x, ty, dash, 1, self.threshold_color);
+            x += 10;
+        }
+
+        // Cumulative line with point markers.
+        let points: Vec<(f32, f32)> =
+            cumulative.iter().enumerate().map(|(i, &p)| (x_scale.center(i), pct_y(p))).collect();
+        for pair in points.windows(2) {
+            draw_line_aa(fb, pair[0].0, pair[0].1, pair[1].0, pair[1].1, self.line_color);
+        }
+        for &(px, py) in &points {
+            draw_circle(fb, px as i32, py as i32, 3, self.line_color);
+        }
+
+        // Vertical cut after the last vital category.
+        if vital > 0 && vital < sorted.len() {
+            let cut = (x_scale.center(vital - 1) + x_scale.center(vital)) / 2.0;
+            fb.fill_rect(cut as u32, top as u32, 1, plot_h as u32, self.threshold_color);
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+impl batuta_common::display::WithDimensions for ParetoChart {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn defects() -> ParetoChart {
+        ParetoChart::new()
+            .category("scratch", 10.0)
+            .category("dent", 50.0)
+            .category("crack", 30.0)
+            .category("stain", 10.0)
+    }
+
+    #[test]
+    fn test_pareto_sorted_and_cumulative() {
+        let chart = defects().build().expect("builder should produce valid result");
+        let labels: Vec<String> = chart.sorted().into_iter().map(|c| c.label).collect();
+        assert_eq!(labels, vec!["dent", "crack", "scratch", "stain"]);
+
+        let cumulative = chart.cumulative_percent();
+        assert!((cumulative[0] - 50.0).abs() < 1e-4);
+        assert!((cumulative[1] - 80.0).abs() < 1e-4);
+        assert!((cumulative[3] - 100.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_pareto_vital_few() {
+        let chart = defects().build().expect("builder should produce valid result");
+        assert_eq!(chart.vital_few(), 2);
+        assert_eq!(defects().threshold(50.0).vital_few(), 1);
+        assert_eq!(defects().threshold(95.0).vital_few(), 4);
+    }
+
+    #[test]
+    fn test_pareto_data() {
+        let chart = ParetoChart::new()
+            .data(&["a", "b"], &[1.0, 3.0])
+            .build()
+            .expect("builder should produce valid result");
+        assert!((chart.total() - 4.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_pareto_validation() {
+        assert!(ParetoChart::new().build().is_err());
+        assert!(ParetoChart::new().category("a", -1.0).build().is_err());
+        assert!(ParetoChart::new().category("a", f32::NAN).build().is_err());
+        assert!(ParetoChart::new().category("a", 0.0).build().is_err());
+    }
+
+    #[test]
+    fn test_pareto_render() {
+        let chart = defects()
+            .bar_color(Rgba::BLUE)
+            .line_color(Rgba::RED)
+            .dimensions(300, 200)
+            .build()
+            .expect("builder should produce valid result");
+
+        let fb = chart.to_framebuffer().expect("rendering should succeed");
+        let has = |c: Rgba| {
+            (0..fb.height()).any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(c)))
+        };
+        assert!(has(Rgba::BLUE));
+        assert!(has(Rgba::RED));
+    }
+}
@@ -0,0 +1,360 @@
+//! Polar heatmap for data indexed by angle and radius.
+//!
+//! Renders a grid of `radial × angular` cells as annular sectors, using the
+//! Grammar of Graphics polar coordinate transform ([`Coord::polar`]) to map
+//! each pixel back to its cell. Suited to antenna gain patterns, wind roses
+//! and cyclic time (hour of day, day of week) against a magnitude.
+//!
+//! # References
+//!
+//! - Wilkinson, L. (2005). *The Grammar of Graphics*. Springer. (Polar coordinates)
+
+use super::heatmap::HeatmapPalette;
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::grammar::Coord;
+use crate::render::draw_line_aa;
+use crate::scale::{ColorScale, Scale};
+
+/// Builder for creating polar heatmaps.
+#[derive(Debug, Clone)]
+pub struct PolarHeatmap {
+    /// Values in row-major order: one row per radial bin (inner first),
+    /// one column per angular bin.
+    data: Vec<f32>,
+    /// Number of radial bins.
+    radial: usize,
+    /// Number of angular bins.
+    angular: usize,
+    /// Polar coordinate system (start angle and direction).
+    coord: Coord,
+    /// Inner hole radius as a fraction of the outer radius (0.0 - 0.9).
+    inner_radius: f32,
+    /// Color palette.
+    palette: HeatmapPalette,
+    /// Custom color scale (overrides palette).
+    custom_scale: Option<ColorScale>,
+    /// Draw ring and spoke guides.
+    show_grid: bool,
+    /// Guide line color.
+    grid_color: Rgba,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the plot.
+    margin: u32,
+}
+
+impl Default for PolarHeatmap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PolarHeatmap {
+    /// Create a new polar heatmap builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(500, 500);
+        Self {
+            data: Vec::new(),
+            radial: 0,
+            angular: 0,
+            coord: Coord::polar(),
+            inner_radius: 0.0,
+            palette: HeatmapPalette::Viridis,
+            custom_scale: None,
+            show_grid: false,
+            grid_color: Rgba::rgb(255, 255, 255),
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Set the values (row-major, `radial` rows of `angular` columns).
+    #[must_use]
+    pub fn data(mut self, data: &[f32], radial: usize, angular: usize) -> Self {
+        self.data = data.to_vec();
+        self.radial = radial;
+        self.angular = angular;
+        self
+    }
+
+    /// Set the values from a 2D vector (`values[radial][angular]`).
+    #[must_use]
+    pub fn data_2d(mut self, values: &[Vec<f32>]) -> Self {
+        self.radial = values.len();
+        self.angular = values.first().map_or(0, Vec::len);
+        self.data = values.iter().flatten().copied().collect();
+        self
+    }
+
+    /// Set the start angle in radians (0 = 12 o'clock).
+    #[must_use]
+    pub fn start_angle(mut self, radians: f32) -> Self {
+        self.coord = self.coord.start_angle(radians);
+        self
+    }
+
+    /// Set the angular direction (1 = clockwise, -1 = counter-clockwise).
+    #[must_use]
+    pub fn direction(mut self, direction: i8) -> Self {
+        self.coord = self.coord.direction(direction);
+        self
+    }
+
+    /// Set the inner hole radius as a fraction of the outer radius.
+    #[must_use]
+    pub fn inner_radius(mut self, fraction: f32) -> Self {
+        self.inner_radius = fraction.clamp(0.0, 0.9);
+        self
+    }
+
+    /// Set the color palette.
+    #[must_use]
+    pub fn palette(mut self, palette: HeatmapPalette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Set a custom color scale.
+    #[must_use]
+    pub fn color_scale(mut self, scale: ColorScale) -> Self {
+        self.custom_scale = Some(scale);
+        self
+    }
+
+    /// Enable or disable ring and spoke guides between cells.
+    #[must_use]
+    pub fn grid(mut self, show: bool) -> Self {
+        self.show_grid = show;
+        self
+    }
+
+    /// Set the guide line color.
+    #[must_use]
+    pub fn grid_color(mut self, color: Rgba) -> Self {
+        self.grid_color = color;
+        self
+    }
+
+    /// Set the margin around the plot.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the heatmap.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if data is empty, either bin count is zero, or the
+    /// data length doesn't equal `radial * angular`.
+    pub fn build(self) -> Result<Self> {
+        if self.data.is_empty() {
+            return Err(Error::EmptyData);
+        }
+        if self.radial == 0 || self.angular == 0 {
+            return Err(Error::InvalidDimensions {
+                width: self.angular as u32,
+                height: self.radial as u32,
+            });
+        }
+        let expected = self.radial * self.angular;
+        if self.data.len() != expected {
+            return Err(Error::DataLengthMismatch { x_len: expected, y_len: self.data.len() });
+        }
+        Ok(self)
+    }
+
+    /// Value at a (radial, angular) cell.
+    #[must_use]
+    pub fn value(&self, radial: usize, angular: usize) -> Option<f32> {
+        if angular >= self.angular {
+            return None;
+        }
+        self.data.get(radial * self.angular + angular).copied()
+    }
+
+    /// Cell `(radial, angular)` under a pixel offset from the centre, for an
+    /// outer radius of `radius` pixels.
+    #[must_use]
+    pub fn cell_at(&self, dx: f32, dy: f32, radius: f32) -> Option<(usize, usize)> {
+        if self.radial == 0 || self.angular == 0 || radius <= 0.0 {
+            return None;
+        }
+        let (theta, r) = self.coord.cartesian_to_polar(dx, dy)?;
+        let r = r / radius;
+        if r < self.inner_radius || r > 1.0 {
+            return None;
+        }
+        let ring = (r - self.inner_radius) / (1.0 - self.inner_radius);
+        let ri = ((ring * self.radial as f32) as usize).min(self.radial - 1);
+        let ai = ((theta * self.angular as f32) as usize).min(self.angular - 1);
+        Some((ri, ai))
+    }
+
+    fn color_scale_for_data(&self) -> Option<ColorScale> {
+        if let Some(scale) = &self.custom_scale {
+            return Some(scale.clone());
+        }
+        let finite = self.data.iter().copied().filter(|v| v.is_finite());
+        let min = finite.clone().fold(f32::INFINITY, f32::min);
+        let max = finite.fold(f32::NEG_INFINITY, f32::max);
+        if !min.is_finite() {
+            return None;
+        }
+        let (min, max) =
+            if (max - min).abs() < f32::EPSILON { (min - 0.5, max + 0.5) } else { (min, max) };
+        self.palette.color_scale((min, max))
+    }
+
+    /// Render the heatmap to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no finite values.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let scale = self.color_scale_for_data().ok_or(Error::EmptyData)?;
+        let cx = self.width as f32 / 2.0;
+        let cy = self.height as f32 / 2.0;
+        let radius = (self.width.min(self.height) as f32 / 2.0 - self.margin as f32).max(1.0);
+
+        let x0 = (cx - radius).floor().max(0.0) as u32;
+        let y0 = (cy - radius).floor().max(0.0) as u32;
+        let x1 = ((cx + radius).ceil() as u32).min(fb.width());
+        let y1 = ((cy + radius).ceil() as u32).min(fb.height());
+        for py in y0..y1 {
+            for px in x0..x1 {
+                let (dx, dy) = (px as f32 + 0.5 - cx, py as f32 + 0.5 - cy);
+                let Some((ri, ai)) = self.cell_at(dx, dy, radius) else { continue };
+                match self.value(ri, ai) {
+                    Some(v) if v.is_finite() => fb.set_pixel(px, py, scale.scale(v)),
+                    _ => {}
+                }
+            }
+        }
+
+        if self.show_grid {
+            let inner = radius * self.inner_radius;
+            let ring_step = (radius - inner) / self.radial as f32;
+            for a in 0..self.angular {
+                let theta = a as f32 / self.angular as f32;
+                if let (Some(p0), Some(p1)) = (
+                    self.coord.polar_to_cartesian(theta, inner),
+                    self.coord.polar_to_cartesian(theta, radius),
+                ) {
+                    draw_line_aa(fb, cx + p0.0, cy + p0.1, cx + p1.0, cy + p1.1, self.grid_color);
+                }
+            }
+            let segments = (self.angular * 8).max(64);
+            for ring in 1..self.radial {
+                let r = inner + ring as f32 * ring_step;
+                let points: Vec<(f32, f32)> = (0..=segments)
+                    .filter_map(|s| self.coord.polar_to_cartesian(s as f32 / segments as f32, r))
+                    .collect();
+                for pair in points.windows(2) {
+                    draw_line_aa(
+                        fb,
+                        cx + pair[0].0,
+                        cy + pair[0].1,
+                        cx + pair[1].0,
+                        cy + pair[1].1,
+                        self.grid_color,
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+impl batuta_common::display::WithDimensions for PolarHeatmap {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn sample() -> PolarHeatmap {
+        // 2 rings × 4 sectors
+        PolarHeatmap::new().data(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0], 2, 4)
+    }
+
+    #[test]
+    fn test_polar_heatmap_builder() {
+        let heatmap = sample().build().expect("builder should produce valid result");
+        assert_eq!(heatmap.value(1, 2), Some(7.0));
+        assert_eq!(heatmap.value(0, 4), None);
+    }
+
+    #[test]
+    fn test_polar_heatmap_validation() {
+        assert!(PolarHeatmap::new().build().is_err());
+        assert!(PolarHeatmap::new().data(&[1.0, 2.0, 3.0], 2, 2).build().is_err());
+        assert!(PolarHeatmap::new().data(&[1.0], 0, 1).build().is_err());
+        let from_2d = PolarHeatmap::new().data_2d(&[vec![1.0, 2.0], vec![3.0, 4.0]]);
+        assert!(from_2d.build().is_ok());
+    }
+
+    #[test]
+    fn test_polar_heatmap_cell_lookup() {
+        let heatmap = sample();
+        // Straight up, outer ring -> first sector, ring 1
+        assert_eq!(heatmap.cell_at(0.0, -90.0, 100.0), Some((1, 0)));
+        // Right (quarter turn clockwise), inner ring -> sector 1
+        assert_eq!(heatmap.cell_at(20.0, 0.1, 100.0), Some((0, 1)));
+        assert_eq!(heatmap.cell_at(200.0, 0.0, 100.0), None);
+
+        let ccw = sample().direction(-1);
+        assert_eq!(ccw.cell_at(20.0, 0.1, 100.0), Some((0, 2)));
+
+        let donut = sample().inner_radius(0.5);
+        assert_eq!(donut.cell_at(0.0, -10.0, 100.0), None);
+        assert_eq!(donut.cell_at(0.0, -60.0, 100.0), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_polar_heatmap_render() {
+        let scale = ColorScale::new(vec![Rgba::RED, Rgba::RED], (0.0, 10.0))
+            .expect("color scale should be valid");
+        let heatmap = sample()
+            .color_scale(scale)
+            .grid(true)
+            .grid_color(Rgba::BLUE)
+            .dimensions(120, 120)
+            .build()
+            .expect("builder should produce valid result");
+        let fb = heatmap.to_framebuffer().expect("rendering should succeed");
+        assert_eq!(fb.get_pixel(65, 40), Some(Rgba::RED));
+        assert_eq!(fb.get_pixel(2, 2), Some(Rgba::WHITE));
+    }
+
+    #[test]
+    fn test_polar_heatmap_all_nan() {
+        let heatmap = PolarHeatmap::new().data(&[f32::NAN], 1, 1).dimensions(50, 50);
+        assert!(heatmap.to_framebuffer().is_err());
+    }
+}
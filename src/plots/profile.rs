@@ -0,0 +1,509 @@
+//! Dataset profile figure.
+//!
+//! One call turns a [`DataFrame`] into a one-page overview for exploratory
+//! analysis: a panel per column (histogram for numeric columns, bar chart of
+//! the most frequent values for text columns), a missingness bar per column,
+//! and a correlation heatmap of the numeric columns.
+//!
+//! Columns are shown in name order. A column is numeric when every present
+//! value is a number; `Null`, NaN and rows past the end of a shorter column
+//! count as missing.
+
+use super::grid::PlotGrid;
+use super::heatmap::Heatmap;
+use super::splom::Splom;
+use crate::color::{categorical, Rgba};
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::grammar::{DataFrame, DataValue};
+use crate::render::{draw_rect_outline, i32_px, TextStyle};
+use crate::scale::{format_tick, ColorScale};
+use batuta_common::display::WithDimensions;
+
+/// Inner padding of each panel in pixels.
+const PANEL_PADDING: u32 = 6;
+
+const MUTED_COLOR: Rgba = Rgba::rgb(110, 110, 110);
+const MISSING_COLOR: Rgba = Rgba::rgb(214, 39, 40);
+
+/// Summary of one column.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnSummary {
+    /// All present values are numbers.
+    Numeric {
+        /// Present (finite) values.
+        values: Vec<f32>,
+    },
+    /// Text (or mixed) values, most frequent first.
+    Categorical {
+        /// Distinct values with their counts, descending by count.
+        counts: Vec<(String, usize)>,
+    },
+}
+
+/// Profile of one data frame column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnProfile {
+    /// Column name.
+    pub name: String,
+    /// Missing values (null, NaN or absent).
+    pub missing: usize,
+    /// Values by kind.
+    pub summary: ColumnSummary,
+}
+
+impl ColumnProfile {
+    fn from_values(name: &str, values: &[DataValue], n_rows: usize) -> Self {
+        let absent = n_rows.saturating_sub(values.len());
+        let is_missing = |v: &DataValue| match v {
+            DataValue::Null => true,
+            DataValue::Number(n) => !n.is_finite(),
+            DataValue::Text(_) => false,
+        };
+        let missing = absent + values.iter().filter(|v| is_missing(v)).count();
+
+        let summary = if values.iter().all(|v| !matches!(v, DataValue::Text(_))) {
+            ColumnSummary::Numeric {
+                values: values
+                    .iter()
+                    .filter_map(DataValue::as_f32)
+                    .filter(|v| v.is_finite())
+                    .collect(),
+            }
+        } else {
+            let mut counts: Vec<(String, usize)> = Vec::new();
+            for value in values.iter().filter(|v| !is_missing(v)) {
+                let label = match value {
+                    DataValue::Text(s) => s.clone(),
+                    DataValue::Number(n) => format_tick(*n),
+                    DataValue::Null => continue,
+                };
+                match counts.iter_mut().find(|(l, _)| *l == label) {
+                    Some((_, n)) => *n += 1,
+                    None => counts.push((label, 1)),
+                }
+            }
+            counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            ColumnSummary::Categorical { counts }
+        };
+
+        Self { name: name.to_string(), missing, summary }
+    }
+
+    /// True for numeric columns.
+    #[must_use]
+    pub fn is_numeric(&self) -> bool {
+        matches!(self.summary, ColumnSummary::Numeric { .. })
+    }
+}
+
+/// Builder for dataset profile figures.
+#[derive(Debug, Clone)]
+pub struct DataProfile {
+    /// Per-column profiles in name order.
+    columns: Vec<ColumnProfile>,
+    /// Row count of the data frame.
+    rows: usize,
+    /// Numeric columns as aligned series (NaN for missing), for correlations.
+    numeric: Vec<(String, Vec<f32>)>,
+    /// Column panels per row.
+    panels_per_row: usize,
+    /// Categories shown per text column before folding into "other".
+    max_categories: usize,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+}
+
+impl DataProfile {
+    /// Profile every column of a data frame.
+    #[must_use]
+    pub fn new(df: &DataFrame) -> Self {
+        let mut names = df.columns();
+        names.sort_unstable();
+        let rows = df.nrow();
+
+        let mut columns = Vec::with_capacity(names.len());
+        let mut numeric = Vec::new();
+        for name in names {
+            let values = df.get(name).unwrap_or_default();
+            let profile = ColumnProfile::from_values(name, values, rows);
+            if profile.is_numeric() {
+                let aligned = (0..rows)
+                    .map(|i| values.get(i).and_then(DataValue::as_f32).unwrap_or(f32::NAN))
+                    .collect();
+                numeric.push((name.to_string(), aligned));
+            }
+            columns.push(profile);
+        }
+
+        let (width, height) = crate::style::current().scaled_size(1200, 900);
+        Self { columns, rows, numeric, panels_per_row: 4, max_categories: 8, width, height }
+    }
+
+    /// Set the number of column panels per row.
+    #[must_use]
+    pub fn panels_per_row(mut self, n: usize) -> Self {
+        self.panels_per_row = n.max(1);
+        self
+    }
+
+    /// Set how many categories text columns show before "other".
+    #[must_use]
+    pub fn max_categories(mut self, n: usize) -> Self {
+        self.max_categories = n.max(1);
+        self
+    }
+
+    /// Validate the profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data frame has no columns or no rows.
+    pub fn build(self) -> Result<Self> {
+        if self.columns.is_empty() || self.rows == 0 {
+            return Err(Error::EmptyData);
+        }
+        Ok(self)
+    }
+
+    /// Per-column profiles in name order.
+    #[must_use]
+    pub fn columns(&self) -> &[ColumnProfile] {
+        &self.columns
+    }
+
+    /// Pearson correlation matrix of the numeric columns (row-major, using
+    /// pairwise-complete rows), with the column names.
+    #[must_use]
+    pub fn correlations(&self) -> (Vec<&str>, Vec<f32>) {
+        let names: Vec<&str> = self.numeric.iter().map(|(n, _)| n.as_str()).collect();
+        let splom = self.numeric.iter().fold(Splom::new(), |s, (n, v)| s.column(n, v));
+        let k = names.len();
+        let matrix = (0..k * k)
+            .map(|i| if i / k == i % k { 1.0 } else { splom.correlation_between(i / k, i % k) })
+            .collect();
+        (names, matrix)
+    }
+
+    fn render_numeric(fb: &mut Framebuffer, values: &[f32]) {
+        let (w, h) = (fb.width(), fb.height());
+        let muted = TextStyle::current().with_color(MUTED_COLOR);
+        let line = muted.height() + 4;
+        if values.is_empty() {
+            muted.draw(
+                fb,
+                i32_px(PANEL_PADDING),
+                i32_px(PANEL_PADDING + 2 * line + 2),
+                "no values",
+            );
+            return;
+        }
+        let (lo, hi) = values
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+        let mean = values.iter().map(|&v| f64::from(v)).sum::<f64>() / values.len() as f64;
+        let stats =
+            format!("{}..{}  mean {}", format_tick(lo), format_tick(hi), format_tick(mean as f32));
+        muted.draw(fb, i32_px(PANEL_PADDING), i32_px(PANEL_PADDING + line), &stats);
+
+        // Sturges bins, as on the SPLOM diagonal
+        let bins = ((values.len() as f32).log2().ceil() as usize + 1).max(1);
+        let span = (hi - lo).max(f32::EPSILON);
+        let mut counts = vec![0_u32; bins];
+        for &v in values {
+            counts[(((v - lo) / span * bins as f32) as usize).min(bins - 1)] += 1;
+        }
+        let top = PANEL_PADDING + 2 * line + 2;
+        let bottom = h.saturating_sub(PANEL_PADDING);
+        let plot_h = bottom.saturating_sub(top).max(1) as f32;
+        let plot_w = w.saturating_sub(2 * PANEL_PADDING) as f32;
+        let max = counts.iter().copied().max().unwrap_or(1).max(1) as f32;
+        let fill = categorical(0).with_alpha(200);
+        for (i, &c) in counts.iter().enumerate() {
+            let x0 = PANEL_PADDING as f32 + plot_w * i as f32 / bins as f32;
+            let x1 = PANEL_PADDING as f32 + plot_w * (i + 1) as f32 / bins as f32;
+            let bh = (c as f32 / max * plot_h).round() as u32;
+            fb.fill_rect(x0.round() as u32, bottom - bh, (x1 - x0 - 1.0).max(1.0) as u32, bh, fill);
+        }
+    }
+
+    fn render_categorical(&self, fb: &mut Framebuffer, counts: &[(String, usize)]) {
+        let (w, h) = (fb.width(), fb.height());
+        let font = TextStyle::current();
+        let line = font.height() + 4;
+        let levels = format!("{} distinct", counts.len());
+        font.with_color(MUTED_COLOR).draw(
+            fb,
+            i32_px(PANEL_PADDING),
+            i32_px(PANEL_PADDING + line),
+            &levels,
+        );
+
+        let mut bars: Vec<(String, usize)> =
+            counts.iter().take(self.max_categories).cloned().collect();
+        let other: usize = counts.iter().skip(self.max_categories).map(|c| c.1).sum();
+        if other > 0 {
+            bars.push(("other".to_string(), other));
+        }
+        if bars.is_empty() {
+            return;
+        }
+
+        let top = PANEL_PADDING + 2 * line + 2;
+        let row_h = (h.saturating_sub(top + PANEL_PADDING) / bars.len() as u32).max(1);
+        let label_w = (w / 3).min(bars.iter().map(|(l, _)| font.width(l)).max().unwrap_or(0) + 4);
+        let bar_w = w.saturating_sub(label_w + 2 * PANEL_PADDING) as f32;
+        let max = bars.iter().map(|b| b.1).max().unwrap_or(1).max(1) as f32;
+        let fill = categorical(1).with_alpha(200);
+        for (i, (label, count)) in bars.iter().enumerate() {
+            let y = top + row_h * i as u32;
+            let text_y = y + row_h.saturating_sub(font.height()) / 2;
+            font.draw(fb, i32_px(PANEL_PADDING), i32_px(text_y), label);
+            let len = (*count as f32 / max * bar_w).round().max(1.0) as u32;
+            fb.fill_rect(PANEL_PADDING + label_w, y + 1, len, row_h.saturating_sub(2).max(1), fill);
+        }
+    }
+
+    fn render_column(&self, column: &ColumnProfile, w: u32, h: u32) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(w, h)?;
+        fb.clear(Rgba::WHITE);
+        TextStyle::current().draw(
+            &mut fb,
+            i32_px(PANEL_PADDING),
+            i32_px(PANEL_PADDING),
+            &column.name,
+        );
+        match &column.summary {
+            ColumnSummary::Numeric { values } => Self::render_numeric(&mut fb, values),
+            ColumnSummary::Categorical { counts } => self.render_categorical(&mut fb, counts),
+        }
+        draw_rect_outline(&mut fb, 0, 0, w, h, Rgba::rgb(200, 200, 200), 1);
+        Ok(fb)
+    }
+
+    fn render_missingness(&self, w: u32, h: u32) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(w, h)?;
+        fb.clear(Rgba::WHITE);
+        let font = TextStyle::current();
+        font.draw(&mut fb, i32_px(PANEL_PADDING), i32_px(PANEL_PADDING), "Missing values");
+
+        let top = PANEL_PADDING + font.height() + 9;
+        let row_h =
+            (h.saturating_sub(top + PANEL_PADDING) / self.columns.len() as u32).clamp(1, 24);
+        let label_w =
+            (w / 3).min(self.columns.iter().map(|c| font.width(&c.name)).max().unwrap_or(0) + 4);
+        let pct_w = font.width("100%") + 8;
+        let bar_w = w.saturating_sub(label_w + 2 * PANEL_PADDING + pct_w) as f32;
+        for (i, column) in self.columns.iter().enumerate() {
+            let y = top + row_h * i as u32;
+            let text_y = i32_px(y + row_h.saturating_sub(font.height()) / 2);
+            font.draw(&mut fb, i32_px(PANEL_PADDING), text_y, &column.name);
+            let x = PANEL_PADDING + label_w;
+            let bar_h = row_h.saturating_sub(2).max(1);
+            fb.fill_rect(x, y + 1, bar_w as u32, bar_h, Rgba::rgb(235, 235, 235));
+            let frac = column.missing as f32 / self.rows as f32;
+            if column.missing > 0 {
+                fb.fill_rect(
+                    x,
+                    y + 1,
+                    (frac * bar_w).round().max(1.0) as u32,
+                    bar_h,
+                    MISSING_COLOR,
+                );
+            }
+            let pct = format!("{:.0}%", frac * 100.0);
+            font.with_color(MUTED_COLOR).draw(&mut fb, i32_px(x + bar_w as u32 + 4), text_y, &pct);
+        }
+        draw_rect_outline(&mut fb, 0, 0, w, h, Rgba::rgb(200, 200, 200), 1);
+        Ok(fb)
+    }
+
+    fn render_correlation(&self, w: u32, h: u32) -> Result<Framebuffer> {
+        let (names, matrix) = self.correlations();
+        let font = TextStyle::current();
+        let top = PANEL_PADDING + font.height() + 9;
+        let mut fb = Framebuffer::new(w, h)?;
+        fb.clear(Rgba::WHITE);
+        if names.len() >= 2 {
+            let scale = ColorScale::red_blue((-1.0, 1.0)).ok_or(Error::EmptyData)?;
+            let mut heatmap =
+                Heatmap::new().data(&matrix, names.len(), names.len()).color_scale(scale).margin(0);
+            let side = w.min(h).saturating_sub(PANEL_PADDING + top).max(1);
+            heatmap.set_dimensions(side, side);
+            let cells = heatmap.build()?.to_framebuffer()?;
+            fb.blit(&cells, (w - side) / 2, top);
+        } else {
+            font.with_color(MUTED_COLOR).draw(
+                &mut fb,
+                i32_px(PANEL_PADDING),
+                i32_px(top),
+                "needs 2+ numeric columns",
+            );
+        }
+        let title = format!("Correlation ({})", names.join(", "));
+        font.draw(&mut fb, i32_px(PANEL_PADDING), i32_px(PANEL_PADDING), &title);
+        draw_rect_outline(&mut fb, 0, 0, w, h, Rgba::rgb(200, 200, 200), 1);
+        Ok(fb)
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the figure is too small for the layout or a
+    /// panel fails to render.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let panel_rows = self.columns.len().div_ceil(self.panels_per_row);
+        // Title at twice the label size, in a band with 6px above it
+        let base = TextStyle::current();
+        let title_font = TextStyle { scale: base.scale * 2, ..base };
+        let title_height = title_font.height() + 14;
+        let body_h = self.height.saturating_sub(title_height);
+        // Column panels share the body with a summary row twice their height
+        let outer = PlotGrid::new(2, 1)
+            .row_heights(&[panel_rows as f32, 2.0])
+            .gap(8)
+            .dimensions(self.width, body_h)
+            .build()?;
+        let body = outer.compose(|row, _, w, h| {
+            let inner = if row == 0 {
+                PlotGrid::new(panel_rows, self.panels_per_row).dimensions(w, h).build()?
+            } else {
+                PlotGrid::new(1, 2).dimensions(w, h).build()?
+            };
+            inner
+                .compose(|r, c, w, h| match (row, c) {
+                    (0, _) => self
+                        .columns
+                        .get(r * self.panels_per_row + c)
+                        .map(|column| self.render_column(column, w, h))
+                        .transpose(),
+                    (_, 0) => self.render_missingness(w, h).map(Some),
+                    _ => self.render_correlation(w, h).map(Some),
+                })
+                .map(Some)
+        })?;
+
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        let title = format!("Dataset profile: {} rows x {} columns", self.rows, self.columns.len());
+        title_font.draw(&mut fb, 4, 6, &title);
+        fb.blit(&body, 0, title_height);
+        Ok(fb)
+    }
+
+    /// Render into an existing framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the layout or any panel fails to render.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let figure = self.to_framebuffer()?;
+        fb.blit(&figure, 0, 0);
+        Ok(())
+    }
+}
+
+impl WithDimensions for DataProfile {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+/// One-call overview figure of a data frame (see [`DataProfile`]).
+///
+/// # Errors
+///
+/// Returns an error if the data frame is empty.
+///
+/// # Example
+///
+/// ```
+/// use trueno_viz::grammar::DataFrame;
+/// use trueno_viz::plots::profile;
+///
+/// let mut df = DataFrame::from_xy(&[1.0, 2.0, 3.0, 4.0], &[2.0, 4.1, 5.9, 8.2]);
+/// df.add_column_str("group", &["a", "b", "a", "a"]);
+/// let fb = profile(&df).unwrap();
+/// assert_eq!(fb.width(), 1200);
+/// ```
+pub fn profile(df: &DataFrame) -> Result<Framebuffer> {
+    DataProfile::new(df).build()?.to_framebuffer()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> DataFrame {
+        let mut df = DataFrame::from_xy(&[1.0, 2.0, 3.0, 4.0, 5.0], &[2.0, 4.0, 6.0, 8.0, 10.0]);
+        df.add_column(
+            "z",
+            vec![
+                DataValue::Number(5.0),
+                DataValue::Null,
+                DataValue::Number(3.0),
+                DataValue::Number(f32::NAN),
+            ],
+        );
+        df.add_column_str("group", &["a", "b", "a", "c", "a"]);
+        df
+    }
+
+    #[test]
+    fn test_column_profiles() {
+        let profile = DataProfile::new(&sample()).build().expect("profile should build");
+        let names: Vec<&str> = profile.columns().iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["group", "x", "y", "z"]);
+
+        let group = &profile.columns()[0];
+        assert!(!group.is_numeric());
+        assert_eq!(
+            group.summary,
+            ColumnSummary::Categorical {
+                counts: vec![("a".into(), 3), ("b".into(), 1), ("c".into(), 1)]
+            }
+        );
+        // Null, NaN and the absent fifth row
+        let z = &profile.columns()[3];
+        assert_eq!(z.missing, 3);
+        assert_eq!(z.summary, ColumnSummary::Numeric { values: vec![5.0, 3.0] });
+    }
+
+    #[test]
+    fn test_correlations() {
+        let profile = DataProfile::new(&sample());
+        let (names, matrix) = profile.correlations();
+        assert_eq!(names, ["x", "y", "z"]);
+        assert!((matrix[1] - 1.0).abs() < 1e-5);
+        assert!((matrix[3] - 1.0).abs() < 1e-5);
+        assert!((matrix[4] - 1.0).abs() < 1e-5);
+        // x vs z over the two complete rows (1, 5) and (3, 3)
+        assert!((matrix[2] + 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_profile_render() {
+        let fb = profile(&sample()).expect("profile should render");
+        assert_eq!((fb.width(), fb.height()), (1200, 900));
+        // Missingness bar for z is drawn in the summary row
+        let has_missing = (0..fb.height())
+            .any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(MISSING_COLOR)));
+        assert!(has_missing);
+
+        let small =
+            DataProfile::new(&sample()).panels_per_row(2).max_categories(1).dimensions(500, 600);
+        assert!(small.build().expect("profile should build").to_framebuffer().is_ok());
+    }
+
+    #[test]
+    fn test_profile_empty() {
+        assert!(profile(&DataFrame::new()).is_err());
+        let mut df = DataFrame::new();
+        df.add_column_str("only", &["x"]);
+        assert!(profile(&df).is_ok());
+    }
+}
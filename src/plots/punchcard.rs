@@ -0,0 +1,323 @@
+//! Punch-card (matrix bubble) plot.
+//!
+//! Maps two categorical axes to a grid of circles whose area is proportional
+//! to the cell value, in the style of GitHub's weekday × hour punch card.
+//!
+//! # References
+//!
+//! - Wilkinson, L. (2005). *The Grammar of Graphics*. Springer. (Area encoding)
+
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_circle, i32_px, TextStyle};
+use crate::scale::{BandScale, ColorScale, Scale};
+
+/// Builder for creating punch-card plots.
+#[derive(Debug, Clone)]
+pub struct PunchCard {
+    /// Values in row-major order (`rows` = y categories, `cols` = x categories).
+    data: Vec<f32>,
+    /// X-axis category labels (columns).
+    x_labels: Vec<String>,
+    /// Y-axis category labels (rows).
+    y_labels: Vec<String>,
+    /// Marker color.
+    color: Rgba,
+    /// Optional color scale by value (overrides `color`).
+    color_scale: Option<ColorScale>,
+    /// Largest marker radius as a fraction of the cell size (0.0 - 0.5).
+    max_radius_fraction: f32,
+    /// Draw light guide lines through each row.
+    show_guides: bool,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the plot.
+    margin: u32,
+}
+
+impl Default for PunchCard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PunchCard {
+    /// Create a new punch-card builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 300);
+        Self {
+            data: Vec::new(),
+            x_labels: Vec::new(),
+            y_labels: Vec::new(),
+            color: Rgba::rgb(50, 50, 50),
+            color_scale: None,
+            max_radius_fraction: 0.45,
+            show_guides: true,
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Set the values matrix (row-major, one row per y category).
+    #[must_use]
+    pub fn data(mut self, data: &[f32]) -> Self {
+        self.data = data.to_vec();
+        self
+    }
+
+    /// Set the values from a 2D vector (`values[y][x]`).
+    #[must_use]
+    pub fn data_2d(mut self, values: &[Vec<f32>]) -> Self {
+        self.data = values.iter().flatten().copied().collect();
+        self
+    }
+
+    /// Set the x-axis (column) categories.
+    #[must_use]
+    pub fn x_labels(mut self, labels: &[impl AsRef<str>]) -> Self {
+        self.x_labels = labels.iter().map(|s| s.as_ref().to_string()).collect();
+        self
+    }
+
+    /// Set the y-axis (row) categories.
+    #[must_use]
+    pub fn y_labels(mut self, labels: &[impl AsRef<str>]) -> Self {
+        self.y_labels = labels.iter().map(|s| s.as_ref().to_string()).collect();
+        self
+    }
+
+    /// Set the marker color.
+    #[must_use]
+    pub fn color(mut self, color: Rgba) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Color markers by value using a color scale.
+    #[must_use]
+    pub fn color_scale(mut self, scale: ColorScale) -> Self {
+        self.color_scale = Some(scale);
+        self
+    }
+
+    /// Set the largest marker radius as a fraction of the cell size (0.05 - 0.5).
+    #[must_use]
+    pub fn max_radius(mut self, fraction: f32) -> Self {
+        self.max_radius_fraction = fraction.clamp(0.05, 0.5);
+        self
+    }
+
+    /// Enable or disable row guide lines.
+    #[must_use]
+    pub fn guides(mut self, show: bool) -> Self {
+        self.show_guides = show;
+        self
+    }
+
+    /// Set the margin around the plot.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the punch card.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if data or either axis is empty, or the data length
+    /// doesn't equal `x_labels.len() * y_labels.len()`.
+    pub fn build(self) -> Result<Self> {
+        if self.data.is_empty() || self.x_labels.is_empty() || self.y_labels.is_empty() {
+            return Err(Error::EmptyData);
+        }
+
+        let expected = self.x_labels.len() * self.y_labels.len();
+        if self.data.len() != expected {
+            return Err(Error::DataLengthMismatch { x_len: expected, y_len: self.data.len() });
+        }
+
+        Ok(self)
+    }
+
+    /// Get the value at a (row, column) cell.
+    #[must_use]
+    pub fn value(&self, row: usize, col: usize) -> Option<f32> {
+        if col >= self.x_labels.len() {
+            return None;
+        }
+        self.data.get(row * self.x_labels.len() + col).copied()
+    }
+
+    /// Largest finite value (used to normalize marker area).
+    fn max_value(&self) -> f32 {
+        self.data.iter().copied().filter(|v| v.is_finite()).fold(0.0, f32::max)
+    }
+
+    /// Marker radius for a value given the maximum allowed radius.
+    ///
+    /// Radius grows with the square root of the value so that circle *area*
+    /// is proportional to the value.
+    #[must_use]
+    pub fn radius_for(&self, value: f32, max_radius: f32) -> f32 {
+        let max = self.max_value();
+        if !value.is_finite() || value <= 0.0 || max <= 0.0 {
+            return 0.0;
+        }
+        (value / max).sqrt() * max_radius
+    }
+
+    /// Render the punch card to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either axis is empty.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let font = TextStyle::current();
+        let gutter_left = self.y_labels.iter().map(|l| font.width(l)).max().unwrap_or(0) + 8;
+        let gutter_bottom = font.height() + 8;
+
+        let left = (self.margin + gutter_left) as f32;
+        let right = self.width.saturating_sub(self.margin) as f32;
+        let top = self.margin as f32;
+        let bottom = self.height.saturating_sub(self.margin + gutter_bottom) as f32;
+
+        let x_scale = BandScale::new(&self.x_labels, (left, right))?;
+        let y_scale = BandScale::new(&self.y_labels, (top, bottom))?;
+        let max_radius = x_scale.bandwidth().min(y_scale.bandwidth()) * self.max_radius_fraction;
+
+        let half_text = i32_px(font.height()) / 2;
+
+        for (row, label) in self.y_labels.iter().enumerate() {
+            let cy = y_scale.center(row);
+
+            if self.show_guides {
+                fb.fill_rect(
+                    left as u32,
+                    cy as u32,
+                    (right - left) as u32,
+                    1,
+                    Rgba::rgb(230, 230, 230),
+                );
+            }
+
+            let lx = i32_px(self.margin + gutter_left - 8) - i32_px(font.width(label));
+            font.draw(fb, lx, cy as i32 - half_text, label);
+
+            for col in 0..self.x_labels.len() {
+                let value = self.value(row, col).unwrap_or(0.0);
+                let r = self.radius_for(value, max_radius);
+                if r <= 0.0 {
+                    continue;
+                }
+                let color = self.color_scale.as_ref().map_or(self.color, |s| s.scale(value));
+                draw_circle(fb, x_scale.center(col) as i32, cy as i32, r.round() as i32, color);
+            }
+        }
+
+        for (col, label) in self.x_labels.iter().enumerate() {
+            let lx = x_scale.center(col) as i32 - i32_px(font.width(label)) / 2;
+            font.draw(fb, lx, bottom as i32 + 6, label);
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+impl batuta_common::display::WithDimensions for PunchCard {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn sample() -> PunchCard {
+        PunchCard::new()
+            .x_labels(&["0h", "6h", "12h", "18h"])
+            .y_labels(&["Mon", "Tue"])
+            .data(&[0.0, 1.0, 4.0, 2.0, 3.0, 0.0, 16.0, 1.0])
+    }
+
+    #[test]
+    fn test_punchcard_builder() {
+        let card = sample().build().expect("builder should produce valid result");
+        assert_eq!(card.value(1, 2), Some(16.0));
+        assert_eq!(card.value(0, 9), None);
+    }
+
+    #[test]
+    fn test_punchcard_validation() {
+        assert!(PunchCard::new().build().is_err());
+        assert!(sample().data(&[1.0, 2.0]).build().is_err());
+        assert!(sample().x_labels(&[] as &[&str]).build().is_err());
+    }
+
+    #[test]
+    fn test_punchcard_area_scaling() {
+        let card = sample();
+        // Max is 16; value 4 should have half the radius (quarter the area)
+        assert!((card.radius_for(16.0, 10.0) - 10.0).abs() < 1e-4);
+        assert!((card.radius_for(4.0, 10.0) - 5.0).abs() < 1e-4);
+        assert!(card.radius_for(0.0, 10.0).abs() < f32::EPSILON);
+        assert!(card.radius_for(f32::NAN, 10.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_punchcard_data_2d() {
+        let card = PunchCard::new()
+            .x_labels(&["a", "b"])
+            .y_labels(&["r"])
+            .data_2d(&[vec![1.0, 2.0]])
+            .build()
+            .expect("builder should produce valid result");
+        assert_eq!(card.value(0, 1), Some(2.0));
+    }
+
+    #[test]
+    fn test_punchcard_render() {
+        let card = sample()
+            .color(Rgba::RED)
+            .dimensions(200, 100)
+            .build()
+            .expect("builder should produce valid result");
+
+        let fb = card.to_framebuffer().expect("rendering should succeed");
+        let red = (0..fb.height())
+            .any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(Rgba::RED)));
+        assert!(red);
+    }
+
+    #[test]
+    fn test_punchcard_color_scale() {
+        let scale = ColorScale::viridis((0.0, 16.0)).expect("viridis should be available");
+        let card = sample()
+            .color_scale(scale)
+            .guides(false)
+            .dimensions(200, 100)
+            .build()
+            .expect("builder should produce valid result");
+        assert!(card.to_framebuffer().is_ok());
+    }
+}
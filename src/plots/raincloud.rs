@@ -0,0 +1,435 @@
+//! Raincloud plot combining a half violin, a box and jittered raw points.
+//!
+//! Each group occupies one horizontal row. The "cloud" is a one-sided
+//! kernel density estimate above the baseline, a narrow box plot sits just
+//! below it, and the "rain" of raw observations falls underneath with a
+//! deterministic vertical jitter so renders are reproducible.
+//!
+//! # References
+//!
+//! - Allen, M., Poggiali, D., Whitaker, K., Marshall, T. R., & Kievit, R. A.
+//!   (2019). Raincloud plots: a multi-platform tool for robust data
+//!   visualization. *Wellcome Open Research*, 4, 63.
+
+use super::boxplot::{compute_kde, BoxStats};
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_circle, draw_line, draw_rect_outline, i32_px, TextStyle};
+use crate::scale::{LinearScale, Scale};
+
+/// Number of points sampled along each density curve.
+const KDE_POINTS: usize = 128;
+
+/// Builder for creating raincloud plots.
+#[derive(Debug, Clone)]
+pub struct Raincloud {
+    /// Data groups, drawn top to bottom.
+    groups: Vec<Vec<f32>>,
+    /// Group labels.
+    labels: Vec<String>,
+    /// Per-group color overrides (None = categorical palette).
+    colors: Vec<Option<Rgba>>,
+    /// KDE bandwidth (None = Silverman's rule).
+    bandwidth: Option<f32>,
+    /// Fraction of each row used by the cloud.
+    cloud_height: f32,
+    /// Box height in pixels.
+    box_height: u32,
+    /// Fraction of the rain band used by the jitter.
+    jitter: f32,
+    /// Raw point radius in pixels.
+    point_radius: i32,
+    /// Jitter seed.
+    seed: u64,
+    /// Explicit value range (None = data extent).
+    value_range: Option<(f32, f32)>,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the plot.
+    margin: u32,
+}
+
+impl Default for Raincloud {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Raincloud {
+    /// Create a new raincloud plot builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(600, 400);
+        Self {
+            groups: Vec::new(),
+            labels: Vec::new(),
+            colors: Vec::new(),
+            bandwidth: None,
+            cloud_height: 0.5,
+            box_height: 8,
+            jitter: 0.8,
+            point_radius: 2,
+            seed: 42,
+            value_range: None,
+            width,
+            height,
+            margin: 20,
+        }
+    }
+
+    /// Add a data group.
+    #[must_use]
+    pub fn add_group(mut self, data: &[f32], label: &str) -> Self {
+        self.groups.push(data.to_vec());
+        self.labels.push(label.to_string());
+        self.colors.push(None);
+        self
+    }
+
+    /// Add a data group with an explicit color.
+    #[must_use]
+    pub fn add_group_colored(mut self, data: &[f32], label: &str, color: Rgba) -> Self {
+        self.groups.push(data.to_vec());
+        self.labels.push(label.to_string());
+        self.colors.push(Some(color));
+        self
+    }
+
+    /// Set the KDE bandwidth (default: Silverman's rule of thumb).
+    #[must_use]
+    pub fn bandwidth(mut self, bandwidth: f32) -> Self {
+        self.bandwidth = Some(bandwidth);
+        self
+    }
+
+    /// Set the fraction of each row used by the cloud (0.1 to 0.8).
+    #[must_use]
+    pub fn cloud_height(mut self, fraction: f32) -> Self {
+        self.cloud_height = fraction.clamp(0.1, 0.8);
+        self
+    }
+
+    /// Set the box height in pixels.
+    #[must_use]
+    pub fn box_height(mut self, height: u32) -> Self {
+        self.box_height = height;
+        self
+    }
+
+    /// Set the fraction of the rain band used by the jitter (0.0 to 1.0).
+    #[must_use]
+    pub fn jitter(mut self, fraction: f32) -> Self {
+        self.jitter = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the raw point radius in pixels.
+    #[must_use]
+    pub fn point_radius(mut self, radius: i32) -> Self {
+        self.point_radius = radius.max(0);
+        self
+    }
+
+    /// Set the jitter seed.
+    #[must_use]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Fix the value axis range.
+    #[must_use]
+    pub fn value_range(mut self, min: f32, max: f32) -> Self {
+        self.value_range = Some((min, max));
+        self
+    }
+
+    /// Set margin.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Validate and build the plot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no groups or a group has no finite values.
+    pub fn build(self) -> Result<Self> {
+        if self.groups.is_empty() {
+            return Err(Error::EmptyData);
+        }
+        for (data, label) in self.groups.iter().zip(&self.labels) {
+            if !data.iter().any(|v| v.is_finite()) {
+                return Err(Error::Rendering(format!("Group '{label}' has no finite values")));
+            }
+        }
+        if let Some((min, max)) = self.value_range {
+            if !min.is_finite() || !max.is_finite() || max <= min {
+                return Err(Error::Rendering(format!("Invalid value range: {min}..{max}")));
+            }
+        }
+        Ok(self)
+    }
+
+    /// Get number of groups.
+    #[must_use]
+    pub fn num_groups(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Box plot statistics for a group.
+    #[must_use]
+    pub fn stats(&self, group: usize) -> Option<BoxStats> {
+        self.groups.get(group).and_then(|data| BoxStats::from_data(data))
+    }
+
+    /// Value axis extent, padded when all values are equal.
+    #[must_use]
+    pub fn value_extent(&self) -> (f32, f32) {
+        if let Some(range) = self.value_range {
+            return range;
+        }
+        let (min, max) = self
+            .groups
+            .iter()
+            .flatten()
+            .filter(|v| v.is_finite())
+            .fold((f32::MAX, f32::MIN), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+        if min > max {
+            (0.0, 1.0)
+        } else if (max - min).abs() < f32::EPSILON {
+            (min - 0.5, max + 0.5)
+        } else {
+            (min, max)
+        }
+    }
+
+    /// Deterministic jitter offsets in `[-0.5, 0.5)` for each value of a group.
+    #[must_use]
+    pub fn jitter_offsets(&self, group: usize) -> Vec<f32> {
+        let Some(data) = self.groups.get(group) else {
+            return Vec::new();
+        };
+        let mut state = self.seed ^ (group as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        data.iter()
+            .map(|_| {
+                // 64-bit LCG (Knuth MMIX constants), top 24 bits as the fraction
+                state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                (state >> 40) as f32 / (1u64 << 24) as f32 - 0.5
+            })
+            .collect()
+    }
+
+    fn color_for(&self, group: usize) -> Rgba {
+        self.colors
+            .get(group)
+            .copied()
+            .flatten()
+            .unwrap_or_else(|| crate::color::categorical(group))
+    }
+
+    /// Linearly interpolated normalized density at `value`.
+    fn density_at(kde: &[(f32, f32)], value: f32) -> f32 {
+        match kde {
+            // A single-point estimate (constant data) has no width to draw
+            [] | [_] => 0.0,
+            _ => {
+                let i = kde.partition_point(|&(x, _)| x < value);
+                if i == 0 || i == kde.len() {
+                    return 0.0;
+                }
+                let ((x0, d0), (x1, d1)) = (kde[i - 1], kde[i]);
+                let t = if x1 > x0 { (value - x0) / (x1 - x0) } else { 0.0 };
+                d0 + (d1 - d0) * t
+            }
+        }
+    }
+
+    /// Render onto an existing framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the plot area is too small.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let font = TextStyle::current();
+        let label_width = self.labels.iter().map(|l| font.width(l)).max().unwrap_or(0);
+        let left = self.margin + label_width + 6;
+        let th = font.height();
+        let right = self.width.saturating_sub(self.margin);
+        let bottom = self.height.saturating_sub(self.margin + th + 6);
+        if right <= left + 1 || bottom <= self.margin {
+            return Err(Error::Rendering("Plot area too small".into()));
+        }
+
+        let (vmin, vmax) = self.value_extent();
+        let x_scale = LinearScale::new((vmin, vmax), (left as f32, right as f32))?;
+        let row_height = (bottom - self.margin) as f32 / self.groups.len() as f32;
+        let axis = Rgba::rgb(60, 60, 60);
+
+        for (g, data) in self.groups.iter().enumerate() {
+            let color = self.color_for(g);
+            let top = self.margin as f32 + g as f32 * row_height;
+            let baseline = top + row_height * self.cloud_height;
+
+            // Cloud: one-sided density above the baseline
+            let kde = compute_kde(data, self.bandwidth, KDE_POINTS);
+            let cloud_max = (row_height * self.cloud_height - 2.0).max(0.0);
+            let cloud_color = color.with_alpha(170);
+            for px in left..right {
+                let value = vmin + (px - left) as f32 / (right - left) as f32 * (vmax - vmin);
+                let h = (Self::density_at(&kde, value) * cloud_max).round() as u32;
+                if h > 0 {
+                    fb.fill_rect(px, baseline as u32 - h, 1, h, cloud_color);
+                }
+            }
+            draw_line(
+                fb,
+                i32_px(left),
+                baseline as i32,
+                i32_px(right),
+                baseline as i32,
+                color.with_alpha(90),
+            );
+
+            // Box: quartiles, median and whiskers just below the baseline
+            let box_top = baseline as u32 + 2;
+            let box_mid = i32_px(box_top + self.box_height / 2);
+            if let Some(stats) = BoxStats::from_data(data) {
+                let px = |v: f32| x_scale.scale(v.clamp(vmin, vmax)).round() as i32;
+                draw_line(fb, px(stats.min), box_mid, px(stats.q1), box_mid, axis);
+                draw_line(fb, px(stats.q3), box_mid, px(stats.max), box_mid, axis);
+                let (q1, q3) = (px(stats.q1), px(stats.q3));
+                let box_width = (q3 - q1).max(1) as u32;
+                fb.fill_rect(q1.max(0) as u32, box_top, box_width, self.box_height, color);
+                draw_rect_outline(fb, q1, i32_px(box_top), box_width, self.box_height, axis, 1);
+                let med = px(stats.median);
+                draw_line(
+                    fb,
+                    med,
+                    i32_px(box_top),
+                    med,
+                    i32_px(box_top + self.box_height),
+                    Rgba::WHITE,
+                );
+            }
+
+            // Rain: raw points jittered within the rest of the row
+            let rain_top = (box_top + self.box_height + 2) as f32 + self.point_radius as f32;
+            let rain_bottom = top + row_height - 2.0 - self.point_radius as f32;
+            let rain_center = (rain_top + rain_bottom) / 2.0;
+            let spread = (rain_bottom - rain_top).max(0.0) * self.jitter;
+            for (&v, offset) in data.iter().zip(self.jitter_offsets(g)) {
+                if !v.is_finite() || v < vmin || v > vmax {
+                    continue;
+                }
+                let x = x_scale.scale(v).round() as i32;
+                let y = (rain_center + offset * spread).round() as i32;
+                draw_circle(fb, x, y, self.point_radius, color.with_alpha(200));
+            }
+
+            if let Some(label) = self.labels.get(g) {
+                let lx = i32_px(left - 6 - font.width(label));
+                font.draw(fb, lx, baseline as i32 - i32_px(th) / 2, label);
+            }
+        }
+
+        // Value axis with extent labels
+        draw_line(fb, i32_px(left), i32_px(bottom), i32_px(right), i32_px(bottom), axis);
+        let (lo, hi) = (format!("{vmin}"), format!("{vmax}"));
+        font.draw(fb, i32_px(left), i32_px(bottom + 4), &lo);
+        font.draw(fb, i32_px(right - font.width(&hi)), i32_px(bottom + 4), &hi);
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+impl batuta_common::display::WithDimensions for Raincloud {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn sample() -> Raincloud {
+        Raincloud::new()
+            .add_group(&[1.0, 2.0, 2.5, 3.0, 3.2, 4.0, 5.0], "control")
+            .add_group(&[3.0, 4.0, 4.5, 5.0, 5.5, 6.0, 9.0], "treated")
+    }
+
+    #[test]
+    fn test_raincloud_validation() {
+        assert!(Raincloud::new().build().is_err());
+        assert!(Raincloud::new().add_group(&[f32::NAN], "a").build().is_err());
+        assert!(sample().value_range(1.0, 1.0).build().is_err());
+        assert!(sample().build().is_ok());
+    }
+
+    #[test]
+    fn test_raincloud_extent_and_stats() {
+        let plot = sample();
+        assert_eq!(plot.num_groups(), 2);
+        assert_eq!(plot.value_extent(), (1.0, 9.0));
+        let stats = plot.stats(1).expect("group should have stats");
+        assert!((stats.median - 5.0).abs() < 1e-6);
+        assert!(plot.stats(2).is_none());
+        let flat = Raincloud::new().add_group(&[2.0, 2.0], "flat");
+        assert_eq!(flat.value_extent(), (1.5, 2.5));
+    }
+
+    #[test]
+    fn test_raincloud_jitter_deterministic() {
+        let plot = sample();
+        let a = plot.jitter_offsets(0);
+        assert_eq!(a.len(), 7);
+        assert!(a.iter().all(|o| (-0.5..0.5).contains(o)));
+        assert_eq!(a, plot.jitter_offsets(0));
+        assert_ne!(a, plot.jitter_offsets(1));
+        assert_ne!(a, sample().seed(7).jitter_offsets(0));
+    }
+
+    #[test]
+    fn test_raincloud_density_at() {
+        let kde = [(0.0, 0.0), (1.0, 1.0), (2.0, 0.5)];
+        assert!((Raincloud::density_at(&kde, 0.5) - 0.5).abs() < 1e-6);
+        assert!((Raincloud::density_at(&kde, 1.5) - 0.75).abs() < 1e-6);
+        assert!(Raincloud::density_at(&kde, 3.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_raincloud_render() {
+        let plot = Raincloud::new()
+            .add_group_colored(&[1.0, 2.0, 2.0, 3.0, 4.0], "a", Rgba::RED)
+            .dimensions(300, 120)
+            .build()
+            .expect("builder should produce valid result");
+        let fb = plot.to_framebuffer().expect("rendering should succeed");
+        // Box fill is drawn in the opaque group color
+        let red = (0..fb.height())
+            .any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(Rgba::RED)));
+        assert!(red);
+    }
+}
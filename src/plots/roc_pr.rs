@@ -239,13 +239,14 @@ impl RocCurve {
     /// Create a new ROC curve builder.
     #[must_use]
     pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(600, 600);
         Self {
             data: None,
             color: Rgba::BLUE,
             show_diagonal: true,
             diagonal_color: Rgba::rgb(200, 200, 200),
-            width: 600,
-            height: 600,
+            width,
+            height,
             margin: 40,
         }
     }
@@ -374,14 +375,15 @@ impl PrCurve {
     /// Create a new PR curve builder.
     #[must_use]
     pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(600, 600);
         Self {
             data: None,
             color: Rgba::rgb(0, 128, 0), // Green
             show_baseline: true,
             baseline_color: Rgba::rgb(200, 200, 200),
             positive_rate: 0.5,
-            width: 600,
-            height: 600,
+            width,
+            height,
             margin: 40,
         }
     }
@@ -5,7 +5,9 @@
 use crate::color::Rgba;
 use crate::error::{Error, Result};
 use crate::framebuffer::Framebuffer;
-use crate::scale::{LinearScale, Scale};
+use crate::output::{CursorPanel, CursorSeries};
+use crate::render::draw_zigzag;
+use crate::scale::{BrokenScale, Scale};
 
 /// Builder for creating scatter plots.
 #[derive(Debug, Clone)]
@@ -15,6 +17,9 @@ pub struct ScatterPlot {
     color: Rgba,
     point_size: f32,
     alpha: f32,
+    x_breaks: Vec<(f32, f32)>,
+    y_breaks: Vec<(f32, f32)>,
+    break_gap: f32,
     width: u32,
     height: u32,
     margin: u32,
@@ -30,14 +35,18 @@ impl ScatterPlot {
     /// Create a new scatter plot builder.
     #[must_use]
     pub fn new() -> Self {
+        let style = crate::style::current();
         Self {
             x_data: Vec::new(),
             y_data: Vec::new(),
             color: Rgba::BLUE,
             point_size: 3.0,
             alpha: 1.0,
-            width: 800,
-            height: 600,
+            x_breaks: Vec::new(),
+            y_breaks: Vec::new(),
+            break_gap: 12.0,
+            width: style.width,
+            height: style.height,
             margin: 40,
         }
     }
@@ -77,6 +86,34 @@ impl ScatterPlot {
         self
     }
 
+    /// Omit an x value range, leaving a marked gap in the axis.
+    #[must_use]
+    pub fn x_break(mut self, from: f32, to: f32) -> Self {
+        self.x_breaks.push((from, to));
+        self
+    }
+
+    /// Omit a y value range, leaving a marked gap in the axis.
+    #[must_use]
+    pub fn y_break(mut self, from: f32, to: f32) -> Self {
+        self.y_breaks.push((from, to));
+        self
+    }
+
+    /// Set the pixel width of each axis-break gap.
+    #[must_use]
+    pub fn break_gap(mut self, gap: f32) -> Self {
+        self.break_gap = gap.max(0.0);
+        self
+    }
+
+    /// Set the margin around the plot.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
     /// Get the number of points.
     #[must_use]
     pub fn point_count(&self) -> usize {
@@ -87,7 +124,8 @@ impl ScatterPlot {
     ///
     /// # Errors
     ///
-    /// Returns an error if data is empty or x/y lengths don't match.
+    /// Returns an error if data is empty, x/y lengths don't match, or an axis
+    /// break is not an increasing interval.
     pub fn build(self) -> Result<Self> {
         if self.x_data.is_empty() || self.y_data.is_empty() {
             return Err(Error::EmptyData);
@@ -100,31 +138,62 @@ impl ScatterPlot {
             });
         }
 
+        if let Some(&(from, to)) = self
+            .x_breaks
+            .iter()
+            .chain(&self.y_breaks)
+            .find(|(from, to)| !from.is_finite() || !to.is_finite() || to <= from)
+        {
+            return Err(Error::ScaleDomain(format!("Invalid axis break: {from}..{to}")));
+        }
+
         Ok(self)
     }
 
-    /// Render the scatter plot to a framebuffer.
+    /// Data-to-pixel scales for the current dimensions and margin.
     ///
-    /// # Errors
-    ///
-    /// Returns an error if rendering fails.
-    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+    /// Shared with composite figures (e.g. marginal plots) so that attached
+    /// panels line up with the scatter axes.
+    pub(crate) fn scales(&self) -> Result<(BrokenScale, BrokenScale)> {
         // Calculate plot area (saturating to prevent overflow)
         let plot_width = self.width.saturating_sub(2 * self.margin);
         let plot_height = self.height.saturating_sub(2 * self.margin);
 
         // Create scales from data
-        let x_scale = LinearScale::from_data(
-            &self.x_data,
+        let x_scale = BrokenScale::new(
+            data_extent(&self.x_data).ok_or(Error::EmptyData)?,
             (self.margin as f32, (self.margin + plot_width) as f32),
-        )
-        .ok_or(Error::EmptyData)?;
+            &self.x_breaks,
+            self.break_gap,
+        )?;
 
-        let y_scale = LinearScale::from_data(
-            &self.y_data,
+        let y_scale = BrokenScale::new(
+            data_extent(&self.y_data).ok_or(Error::EmptyData)?,
             ((self.margin + plot_height) as f32, self.margin as f32),
-        )
-        .ok_or(Error::EmptyData)?;
+            &self.y_breaks,
+            self.break_gap,
+        )?;
+
+        Ok((x_scale, y_scale))
+    }
+
+    /// X-axis data.
+    pub(crate) fn x_data(&self) -> &[f32] {
+        &self.x_data
+    }
+
+    /// Y-axis data.
+    pub(crate) fn y_data(&self) -> &[f32] {
+        &self.y_data
+    }
+
+    /// Render the scatter plot to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let (x_scale, y_scale) = self.scales()?;
 
         // Apply alpha to color
         let color = self.color.with_alpha((self.alpha * 255.0) as u8);
@@ -132,6 +201,9 @@ impl ScatterPlot {
         // Render each point
         let point_count = self.point_count();
         for i in 0..point_count {
+            if x_scale.is_omitted(self.x_data[i]) || y_scale.is_omitted(self.y_data[i]) {
+                continue;
+            }
             let px = x_scale.scale(self.x_data[i]) as i32;
             let py = y_scale.scale(self.y_data[i]) as i32;
 
@@ -152,6 +224,8 @@ impl ScatterPlot {
             }
         }
 
+        draw_axis_breaks(fb, &x_scale, &y_scale, Rgba::WHITE);
+
         Ok(())
     }
 
@@ -166,6 +240,73 @@ impl ScatterPlot {
         self.render(&mut fb)?;
         Ok(fb)
     }
+
+    /// Data cursor / brushing panel for this plot: the plot area and one
+    /// `"points"` series whose rows are the data indices. Tag it with
+    /// [`CursorPanel::source`] to link it to other charts of the same data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the plot has no data.
+    pub fn cursor_panel(&self) -> Result<CursorPanel> {
+        let (x_scale, y_scale) = self.scales()?;
+        let series =
+            CursorSeries::from_xy("points", self.color, &self.x_data, &self.y_data, |x, y| {
+                if x_scale.is_omitted(x) || y_scale.is_omitted(y) {
+                    (f32::NAN, f32::NAN)
+                } else {
+                    (x_scale.scale(x), y_scale.scale(y))
+                }
+            });
+        let side = (2 * self.margin) as f32;
+        Ok(CursorPanel::new(
+            self.margin as f32,
+            self.margin as f32,
+            self.width as f32 - side,
+            self.height as f32 - side,
+        )
+        .series(series))
+    }
+}
+
+/// Min/max of the data, or `None` when it is empty.
+fn data_extent(data: &[f32]) -> Option<(f32, f32)> {
+    if data.is_empty() {
+        return None;
+    }
+    let min = data.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = data.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    Some((min, max))
+}
+
+/// Blank each axis-break gap across the plot area and mark both edges with
+/// zig-zags, splitting the plot into panels.
+pub(crate) fn draw_axis_breaks(
+    fb: &mut Framebuffer,
+    x_scale: &BrokenScale,
+    y_scale: &BrokenScale,
+    background: Rgba,
+) {
+    let (left, right) = x_scale.range();
+    let (bottom, top) = y_scale.range();
+    let marker = Rgba::rgb(90, 90, 90);
+
+    for (a, b) in x_scale.gaps() {
+        let x = a.min(b).round().max(0.0) as u32;
+        let w = (b - a).abs().round() as u32;
+        fb.fill_rect(x, top as u32, w, (bottom - top) as u32, background);
+        for edge in [a, b] {
+            draw_zigzag(fb, (edge, top), (edge, bottom), 2.0, 8.0, marker);
+        }
+    }
+    for (a, b) in y_scale.gaps() {
+        let y = a.min(b).round().max(0.0) as u32;
+        let h = (b - a).abs().round() as u32;
+        fb.fill_rect(left as u32, y, (right - left) as u32, h, background);
+        for edge in [a, b] {
+            draw_zigzag(fb, (left, edge), (right, edge), 2.0, 8.0, marker);
+        }
+    }
 }
 
 impl batuta_common::display::WithDimensions for ScatterPlot {
@@ -218,6 +359,45 @@ mod tests {
         assert!(fb.is_ok());
     }
 
+    #[test]
+    fn test_scatter_plot_axis_break() {
+        assert!(ScatterPlot::new().x(&[1.0]).y(&[1.0]).x_break(3.0, 3.0).build().is_err());
+
+        let plot = ScatterPlot::new()
+            .x(&[0.0, 1.0, 2.0, 100.0])
+            .y(&[0.0, 1.0, 2.0, 3.0])
+            .x_break(3.0, 99.0)
+            .margin(0)
+            .dimensions(100, 100)
+            .build()
+            .expect("builder should produce valid result");
+        let (x_scale, _) = plot.scales().expect("scales should be valid");
+        // Without the break x = 2 would sit at 2px; the kept ranges share the width
+        assert!(x_scale.scale(2.0) > 40.0);
+        assert_eq!(x_scale.gaps().len(), 1);
+        let fb = plot.to_framebuffer().expect("rendering should succeed");
+        assert_eq!(fb.get_pixel(99, 0), Some(Rgba::BLUE));
+    }
+
+    #[test]
+    fn test_scatter_plot_cursor_panel_skips_omitted() {
+        let plot = ScatterPlot::new()
+            .x(&[0.0, 1.0, 2.0, 3.0])
+            .y(&[1.0, 2.0, 20.0, 50.0])
+            .y_break(10.0, 40.0)
+            .margin(10)
+            .dimensions(100, 100)
+            .build()
+            .expect("builder should produce valid result");
+
+        let panel = plot.cursor_panel().expect("panel should build").source("data");
+        let json = crate::output::LinkedBrush::new().panel(panel).to_json();
+        assert!(json.contains(r#""rect":[10,10,80,80]"#));
+        assert!(json.contains(r#""name":"points""#));
+        assert!(json.contains(r#""rows":[0,1,3]"#));
+        assert!(ScatterPlot::new().cursor_panel().is_err());
+    }
+
     #[test]
     fn test_scatter_plot_default() {
         let plot = ScatterPlot::default();
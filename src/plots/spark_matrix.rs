@@ -0,0 +1,500 @@
+//! Small-multiples sparkline matrix.
+//!
+//! Renders an R×C grid of tiny line charts for comparing many metrics across
+//! experiments at a glance. Cells share a y-scale (globally or per row) so
+//! magnitudes remain comparable along the shared dimension.
+//!
+//! # References
+//!
+//! - Tufte, E. R. (1983). *The Visual Display of Quantitative Information*.
+//!   Graphics Press. (Small multiples)
+//! - Tufte, E. R. (2006). *Beautiful Evidence*. Graphics Press. (Sparklines)
+
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_line_aa, draw_rect_outline, i32_px, TextStyle};
+use crate::scale::{LinearScale, Scale};
+
+/// How y-scales are shared between cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SparkScale {
+    /// One y-scale across the whole matrix.
+    #[default]
+    Shared,
+    /// One y-scale per row (compare experiments within a metric).
+    PerRow,
+}
+
+/// Builder for a small-multiples sparkline matrix.
+#[derive(Debug, Clone)]
+pub struct SparkMatrix {
+    /// Series per cell in row-major order.
+    cells: Vec<Vec<f32>>,
+    /// Number of rows.
+    rows: usize,
+    /// Number of columns.
+    cols: usize,
+    /// Row labels (drawn left of each row).
+    row_labels: Vec<String>,
+    /// Column labels (drawn above each column).
+    col_labels: Vec<String>,
+    /// Y-scale sharing mode.
+    scale_mode: SparkScale,
+    /// Line color.
+    line_color: Rgba,
+    /// Cell frame color (None to disable).
+    frame_color: Option<Rgba>,
+    /// Label color.
+    label_color: Rgba,
+    /// Inner padding of each cell in pixels.
+    cell_padding: u32,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the matrix.
+    margin: u32,
+}
+
+impl Default for SparkMatrix {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkMatrix {
+    /// Create a new sparkline matrix builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let style = crate::style::current();
+        let (width, height) = style.scaled_size(800, 600);
+        Self {
+            cells: Vec::new(),
+            rows: 0,
+            cols: 0,
+            row_labels: Vec::new(),
+            col_labels: Vec::new(),
+            scale_mode: SparkScale::default(),
+            line_color: Rgba::rgb(31, 119, 180),
+            frame_color: Some(Rgba::rgb(220, 220, 220)),
+            label_color: style.text_color,
+            cell_padding: 3,
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Set the cell series in row-major order.
+    #[must_use]
+    pub fn data(mut self, cells: &[Vec<f32>], rows: usize, cols: usize) -> Self {
+        self.cells = cells.to_vec();
+        self.rows = rows;
+        self.cols = cols;
+        self
+    }
+
+    /// Set the cell series from a 2D grid (`grid[row][col]`).
+    #[must_use]
+    pub fn data_2d(mut self, grid: &[Vec<Vec<f32>>]) -> Self {
+        if grid.is_empty() {
+            return self;
+        }
+
+        self.rows = grid.len();
+        self.cols = grid[0].len();
+        self.cells = grid.iter().flatten().cloned().collect();
+        self
+    }
+
+    /// Set row labels.
+    #[must_use]
+    pub fn row_labels(mut self, labels: &[impl AsRef<str>]) -> Self {
+        self.row_labels = labels.iter().map(|s| s.as_ref().to_string()).collect();
+        self
+    }
+
+    /// Set column labels.
+    #[must_use]
+    pub fn col_labels(mut self, labels: &[impl AsRef<str>]) -> Self {
+        self.col_labels = labels.iter().map(|s| s.as_ref().to_string()).collect();
+        self
+    }
+
+    /// Set the y-scale sharing mode.
+    #[must_use]
+    pub fn scale_mode(mut self, mode: SparkScale) -> Self {
+        self.scale_mode = mode;
+        self
+    }
+
+    /// Set the line color.
+    #[must_use]
+    pub fn color(mut self, color: Rgba) -> Self {
+        self.line_color = color;
+        self
+    }
+
+    /// Set the cell frame color (None to disable frames).
+    #[must_use]
+    pub fn frame_color(mut self, color: Option<Rgba>) -> Self {
+        self.frame_color = color;
+        self
+    }
+
+    /// Set the label color.
+    #[must_use]
+    pub fn label_color(mut self, color: Rgba) -> Self {
+        self.label_color = color;
+        self
+    }
+
+    /// Set the inner padding of each cell.
+    #[must_use]
+    pub fn cell_padding(mut self, padding: u32) -> Self {
+        self.cell_padding = padding;
+        self
+    }
+
+    /// Set the margin around the matrix.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if every cell is empty, the grid is zero-sized, or the
+    /// cell/label counts don't match the dimensions.
+    pub fn build(self) -> Result<Self> {
+        if self.rows == 0 || self.cols == 0 {
+            return Err(Error::InvalidDimensions {
+                width: self.cols as u32,
+                height: self.rows as u32,
+            });
+        }
+
+        let expected = self.rows * self.cols;
+        if self.cells.len() != expected {
+            return Err(Error::DataLengthMismatch { x_len: expected, y_len: self.cells.len() });
+        }
+
+        if self.cells.iter().all(Vec::is_empty) {
+            return Err(Error::EmptyData);
+        }
+
+        if !self.row_labels.is_empty() && self.row_labels.len() != self.rows {
+            return Err(Error::DataLengthMismatch {
+                x_len: self.rows,
+                y_len: self.row_labels.len(),
+            });
+        }
+
+        if !self.col_labels.is_empty() && self.col_labels.len() != self.cols {
+            return Err(Error::DataLengthMismatch {
+                x_len: self.cols,
+                y_len: self.col_labels.len(),
+            });
+        }
+
+        Ok(self)
+    }
+
+    /// Get the number of rows.
+    #[must_use]
+    pub const fn row_count(&self) -> usize {
+        self.rows
+    }
+
+    /// Get the number of columns.
+    #[must_use]
+    pub const fn col_count(&self) -> usize {
+        self.cols
+    }
+
+    /// Get the series for a cell.
+    #[must_use]
+    pub fn cell(&self, row: usize, col: usize) -> Option<&[f32]> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.cells.get(row * self.cols + col).map(Vec::as_slice)
+    }
+
+    /// Get the y-extent used for cells in `row` (padded if flat).
+    #[must_use]
+    pub fn y_extent(&self, row: usize) -> (f32, f32) {
+        let cells: &[Vec<f32>] = match self.scale_mode {
+            SparkScale::Shared => &self.cells,
+            SparkScale::PerRow => {
+                let start = (row * self.cols).min(self.cells.len());
+                let end = (start + self.cols).min(self.cells.len());
+                &self.cells[start..end]
+            }
+        };
+
+        let values = cells.iter().flatten().copied().filter(|v| v.is_finite());
+        let (min, max) =
+            values.fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), v| (lo.min(v), hi.max(v)));
+
+        if !min.is_finite() {
+            (0.0, 1.0)
+        } else if (max - min).abs() < f32::EPSILON {
+            (min - 0.5, max + 0.5)
+        } else {
+            (min, max)
+        }
+    }
+
+    /// Width of the row-label gutter in pixels.
+    fn row_gutter(&self) -> u32 {
+        let font = TextStyle::current();
+        self.row_labels.iter().map(|l| font.width(l)).max().map_or(0, |w| w + 6)
+    }
+
+    /// Height of the column-label gutter in pixels.
+    fn col_gutter(&self) -> u32 {
+        if self.col_labels.is_empty() {
+            0
+        } else {
+            TextStyle::current().height() + 6
+        }
+    }
+
+    /// Render the matrix to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let font = TextStyle::current().with_color(self.label_color);
+        if self.rows == 0 || self.cols == 0 {
+            return Ok(());
+        }
+
+        let left = self.margin + self.row_gutter();
+        let top = self.margin + self.col_gutter();
+        let grid_width = self.width.saturating_sub(left + self.margin);
+        let grid_height = self.height.saturating_sub(top + self.margin);
+        let cell_width = grid_width / self.cols as u32;
+        let cell_height = grid_height / self.rows as u32;
+
+        // Column labels (centered above each column)
+        for (col, label) in self.col_labels.iter().enumerate() {
+            let cx = left + col as u32 * cell_width + cell_width / 2;
+            let x = i32_px(cx) - i32_px(font.width(label)) / 2;
+            font.draw(fb, x, i32_px(self.margin), label);
+        }
+
+        for row in 0..self.rows {
+            let y0 = top + row as u32 * cell_height;
+
+            // Row label (vertically centered)
+            if let Some(label) = self.row_labels.get(row) {
+                let y = i32_px(y0 + cell_height / 2) - i32_px(font.height()) / 2;
+                font.draw(fb, i32_px(self.margin), y, label);
+            }
+
+            let (y_min, y_max) = self.y_extent(row);
+            let inner_top = (y0 + self.cell_padding) as f32;
+            let inner_bottom = (y0 + cell_height).saturating_sub(self.cell_padding + 1) as f32;
+            let y_scale = LinearScale::new((y_min, y_max), (inner_bottom, inner_top))?;
+
+            for col in 0..self.cols {
+                let x0 = left + col as u32 * cell_width;
+
+                if let Some(frame) = self.frame_color {
+                    draw_rect_outline(
+                        fb,
+                        i32_px(x0),
+                        i32_px(y0),
+                        cell_width.saturating_sub(1),
+                        cell_height.saturating_sub(1),
+                        frame,
+                        1,
+                    );
+                }
+
+                let series = self.cell(row, col).unwrap_or_default();
+                self.render_cell(fb, series, x0, cell_width, &y_scale);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render one cell's sparkline.
+    fn render_cell(
+        &self,
+        fb: &mut Framebuffer,
+        series: &[f32],
+        x0: u32,
+        cell_width: u32,
+        y_scale: &LinearScale,
+    ) {
+        if series.len() < 2 {
+            return;
+        }
+
+        let inner_left = (x0 + self.cell_padding) as f32;
+        let inner_width = cell_width.saturating_sub(2 * self.cell_padding + 1) as f32;
+        let step = inner_width / (series.len() - 1) as f32;
+
+        // Non-finite samples are gaps: the polyline breaks instead of bridging them
+        let points: Vec<Option<(f32, f32)>> = series
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| v.is_finite().then(|| (inner_left + i as f32 * step, y_scale.scale(v))))
+            .collect();
+
+        for pair in points.windows(2) {
+            if let [Some((x1, y1)), Some((x2, y2))] = *pair {
+                draw_line_aa(fb, x1, y1, x2, y2, self.line_color);
+            }
+        }
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+impl batuta_common::display::WithDimensions for SparkMatrix {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn sample_cells() -> Vec<Vec<f32>> {
+        vec![
+            vec![1.0, 2.0, 3.0],
+            vec![3.0, 2.0, 1.0],
+            vec![10.0, 20.0, 30.0],
+            vec![30.0, 25.0, 20.0],
+        ]
+    }
+
+    #[test]
+    fn test_spark_matrix_builder() {
+        let matrix = SparkMatrix::new()
+            .data(&sample_cells(), 2, 2)
+            .row_labels(&["loss", "acc"])
+            .col_labels(&["run-a", "run-b"])
+            .build()
+            .expect("builder should produce valid result");
+
+        assert_eq!(matrix.row_count(), 2);
+        assert_eq!(matrix.col_count(), 2);
+        assert_eq!(matrix.cell(1, 0), Some(&[10.0, 20.0, 30.0][..]));
+        assert_eq!(matrix.cell(2, 0), None);
+    }
+
+    #[test]
+    fn test_spark_matrix_data_2d() {
+        let grid = vec![vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]];
+        let matrix =
+            SparkMatrix::new().data_2d(&grid).build().expect("builder should produce valid result");
+
+        assert_eq!(matrix.row_count(), 1);
+        assert_eq!(matrix.col_count(), 3);
+    }
+
+    #[test]
+    fn test_spark_matrix_validation() {
+        assert!(SparkMatrix::new().build().is_err());
+        assert!(SparkMatrix::new().data(&sample_cells(), 3, 2).build().is_err());
+        assert!(SparkMatrix::new().data(&[vec![], vec![]], 1, 2).build().is_err());
+        assert!(SparkMatrix::new().data(&sample_cells(), 2, 2).row_labels(&["a"]).build().is_err());
+        assert!(SparkMatrix::new()
+            .data(&sample_cells(), 2, 2)
+            .col_labels(&["a", "b", "c"])
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_spark_matrix_shared_scale() {
+        let matrix = SparkMatrix::new().data(&sample_cells(), 2, 2);
+        assert_eq!(matrix.y_extent(0), (1.0, 30.0));
+        assert_eq!(matrix.y_extent(1), (1.0, 30.0));
+    }
+
+    #[test]
+    fn test_spark_matrix_per_row_scale() {
+        let matrix = SparkMatrix::new().data(&sample_cells(), 2, 2).scale_mode(SparkScale::PerRow);
+        assert_eq!(matrix.y_extent(0), (1.0, 3.0));
+        assert_eq!(matrix.y_extent(1), (10.0, 30.0));
+    }
+
+    #[test]
+    fn test_spark_matrix_flat_extent_padded() {
+        let matrix = SparkMatrix::new().data(&[vec![5.0, 5.0]], 1, 1);
+        assert_eq!(matrix.y_extent(0), (4.5, 5.5));
+    }
+
+    #[test]
+    fn test_spark_matrix_render() {
+        let matrix = SparkMatrix::new()
+            .data(&sample_cells(), 2, 2)
+            .row_labels(&["loss", "acc"])
+            .col_labels(&["a", "b"])
+            .color(Rgba::RED)
+            .dimensions(200, 120)
+            .build()
+            .expect("builder should produce valid result");
+
+        let fb = matrix.to_framebuffer().expect("rendering should succeed");
+        let drawn = (0..fb.height())
+            .flat_map(|y| (0..fb.width()).map(move |x| (x, y)))
+            .filter(|&(x, y)| fb.get_pixel(x, y) != Some(Rgba::WHITE))
+            .count();
+        assert!(drawn > 0);
+    }
+
+    #[test]
+    fn test_spark_matrix_render_with_gaps() {
+        let cells = vec![vec![1.0, f32::NAN, 3.0], vec![], vec![2.0], vec![4.0, 5.0]];
+        let matrix = SparkMatrix::new()
+            .data(&cells, 2, 2)
+            .frame_color(None)
+            .dimensions(100, 100)
+            .build()
+            .expect("builder should produce valid result");
+
+        assert!(matrix.to_framebuffer().is_ok());
+    }
+
+    #[test]
+    fn test_spark_matrix_gap_splits_line() {
+        let matrix = SparkMatrix::new()
+            .data(&[vec![1.0, 1.0, f32::NAN, 1.0, 1.0]], 1, 1)
+            .frame_color(None)
+            .color(Rgba::RED)
+            .dimensions(100, 60)
+            .build()
+            .expect("builder should produce valid result");
+
+        let fb = matrix.to_framebuffer().expect("rendering should succeed");
+        let drawn = |x: u32| (0..fb.height()).any(|y| fb.get_pixel(x, y) != Some(Rgba::WHITE));
+        assert!(!drawn(49) && !drawn(50));
+        assert!((0..fb.width()).any(drawn));
+    }
+}
@@ -0,0 +1,422 @@
+//! Scatterplot matrix (SPLOM).
+//!
+//! Renders every pairwise scatter plot of N numeric columns in an N × N
+//! [`PlotGrid`]. The diagonal shows each column's univariate distribution
+//! (histogram or KDE) and the upper triangle can show Pearson correlation
+//! coefficients instead of the mirrored scatter plots.
+//!
+//! # References
+//!
+//! - Cleveland, W. S. (1993). *Visualizing Data*. Hobart Press.
+//! - Emerson, J. W., et al. (2013). "The Generalized Pairs Plot". *Journal of
+//!   Computational and Graphical Statistics*, 22(1), 79-91.
+
+use super::boxplot::compute_kde;
+use super::grid::PlotGrid;
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_line_aa, draw_point, draw_rect_outline, i32_px, TextStyle};
+use crate::scale::{LinearScale, Scale};
+
+/// Number of evaluation points for diagonal KDE curves.
+const KDE_POINTS: usize = 64;
+
+/// Inner padding of each panel in pixels.
+const PANEL_PADDING: f32 = 4.0;
+
+/// What to draw on the SPLOM diagonal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SplomDiagonal {
+    /// Histogram of the column (Sturges bins).
+    #[default]
+    Histogram,
+    /// Gaussian kernel density estimate.
+    Kde,
+    /// Column name only.
+    Blank,
+}
+
+/// Builder for scatterplot matrices.
+#[derive(Debug, Clone)]
+pub struct Splom {
+    /// Column names.
+    names: Vec<String>,
+    /// Column values (all the same length after validation).
+    columns: Vec<Vec<f32>>,
+    /// Diagonal panel type.
+    diagonal: SplomDiagonal,
+    /// Show correlation coefficients in the upper triangle.
+    show_correlation: bool,
+    /// Point / bar color.
+    color: Rgba,
+    /// Point size in pixels.
+    point_size: f32,
+    /// Gap between panels in pixels.
+    gap: u32,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the matrix.
+    margin: u32,
+}
+
+impl Default for Splom {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Splom {
+    /// Create a new SPLOM builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 800);
+        Self {
+            names: Vec::new(),
+            columns: Vec::new(),
+            diagonal: SplomDiagonal::default(),
+            show_correlation: false,
+            color: Rgba::rgb(31, 119, 180),
+            point_size: 3.0,
+            gap: 4,
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Add a named column.
+    #[must_use]
+    pub fn column(mut self, name: &str, data: &[f32]) -> Self {
+        self.names.push(name.to_string());
+        self.columns.push(data.to_vec());
+        self
+    }
+
+    /// Set the diagonal panel type.
+    #[must_use]
+    pub fn diagonal(mut self, diagonal: SplomDiagonal) -> Self {
+        self.diagonal = diagonal;
+        self
+    }
+
+    /// Show Pearson correlation coefficients in the upper triangle.
+    #[must_use]
+    pub fn correlation(mut self, show: bool) -> Self {
+        self.show_correlation = show;
+        self
+    }
+
+    /// Set the point and bar color.
+    #[must_use]
+    pub fn color(mut self, color: Rgba) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the point size in pixels.
+    #[must_use]
+    pub fn point_size(mut self, size: f32) -> Self {
+        self.point_size = size;
+        self
+    }
+
+    /// Set the gap between panels in pixels.
+    #[must_use]
+    pub fn gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the margin around the matrix.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the SPLOM.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no columns, a column is empty, or the
+    /// columns differ in length.
+    pub fn build(self) -> Result<Self> {
+        let Some(first) = self.columns.first() else {
+            return Err(Error::EmptyData);
+        };
+        if first.is_empty() {
+            return Err(Error::EmptyData);
+        }
+        if let Some(bad) = self.columns.iter().find(|c| c.len() != first.len()) {
+            return Err(Error::DataLengthMismatch { x_len: first.len(), y_len: bad.len() });
+        }
+        Ok(self)
+    }
+
+    /// Number of columns (the matrix is `column_count × column_count`).
+    #[must_use]
+    pub fn column_count(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Pearson correlation between columns `a` and `b`.
+    ///
+    /// Returns 0.0 for out-of-range indices or constant columns.
+    #[must_use]
+    pub fn correlation_between(&self, a: usize, b: usize) -> f32 {
+        let (Some(x), Some(y)) = (self.columns.get(a), self.columns.get(b)) else {
+            return 0.0;
+        };
+        let pairs: Vec<(f32, f32)> = x
+            .iter()
+            .zip(y)
+            .filter(|(u, v)| u.is_finite() && v.is_finite())
+            .map(|(&u, &v)| (u, v))
+            .collect();
+        if pairs.len() < 2 {
+            return 0.0;
+        }
+        let n = pairs.len() as f32;
+        let mx = pairs.iter().map(|p| p.0).sum::<f32>() / n;
+        let my = pairs.iter().map(|p| p.1).sum::<f32>() / n;
+        let (mut cov, mut vx, mut vy) = (0.0, 0.0, 0.0);
+        for &(u, v) in &pairs {
+            cov += (u - mx) * (v - my);
+            vx += (u - mx) * (u - mx);
+            vy += (v - my) * (v - my);
+        }
+        let denom = (vx * vy).sqrt();
+        if denom < f32::EPSILON {
+            0.0
+        } else {
+            (cov / denom).clamp(-1.0, 1.0)
+        }
+    }
+
+    /// The grid layout used to arrange panels.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimensions are too small for the matrix.
+    pub fn grid(&self) -> Result<PlotGrid> {
+        let n = self.columns.len();
+        let mut grid = PlotGrid::new(n, n).gap(self.gap).margin(self.margin);
+        batuta_common::display::WithDimensions::set_dimensions(&mut grid, self.width, self.height);
+        grid.build()
+    }
+
+    /// Finite (min, max) of a column, padded by 5% (or ±0.5 if constant).
+    fn extent(&self, col: usize) -> (f32, f32) {
+        let (min, max) = self.columns[col]
+            .iter()
+            .filter(|v| v.is_finite())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+        if !min.is_finite() {
+            return (0.0, 1.0);
+        }
+        let span = max - min;
+        if span <= f32::EPSILON {
+            (min - 0.5, max + 0.5)
+        } else {
+            (min - span * 0.05, max + span * 0.05)
+        }
+    }
+
+    fn panel_scales(
+        &self,
+        col: usize,
+        row: usize,
+        w: u32,
+        h: u32,
+    ) -> Result<(LinearScale, LinearScale)> {
+        let x = LinearScale::new(self.extent(col), (PANEL_PADDING, w as f32 - PANEL_PADDING))?;
+        let y = LinearScale::new(self.extent(row), (h as f32 - PANEL_PADDING, PANEL_PADDING))?;
+        Ok((x, y))
+    }
+
+    fn render_scatter(&self, fb: &mut Framebuffer, row: usize, col: usize) -> Result<()> {
+        let (xs, ys) = self.panel_scales(col, row, fb.width(), fb.height())?;
+        for (&x, &y) in self.columns[col].iter().zip(&self.columns[row]) {
+            if x.is_finite() && y.is_finite() {
+                draw_point(fb, xs.scale(x), ys.scale(y), self.point_size, self.color);
+            }
+        }
+        Ok(())
+    }
+
+    fn render_diagonal(&self, fb: &mut Framebuffer, col: usize) -> Result<()> {
+        let (w, h) = (fb.width(), fb.height());
+        let (xs, _) = self.panel_scales(col, col, w, h)?;
+        let bottom = h as f32 - PANEL_PADDING;
+        let plot_h = (bottom - PANEL_PADDING - 10.0).max(1.0);
+        let data: Vec<f32> = self.columns[col].iter().copied().filter(|v| v.is_finite()).collect();
+
+        match self.diagonal {
+            SplomDiagonal::Histogram if !data.is_empty() => {
+                let (lo, hi) = self.extent(col);
+                let bins = ((data.len() as f32).log2().ceil() as usize + 1).max(1);
+                let bin_w = (hi - lo) / bins as f32;
+                let mut counts = vec![0_u32; bins];
+                for &v in &data {
+                    let b = (((v - lo) / bin_w) as usize).min(bins - 1);
+                    counts[b] += 1;
+                }
+                let max = counts.iter().copied().max().unwrap_or(1).max(1) as f32;
+                let fill = self.color.with_alpha(170);
+                for (i, &c) in counts.iter().enumerate() {
+                    let x0 = xs.scale(lo + bin_w * i as f32);
+                    let x1 = xs.scale(lo + bin_w * (i + 1) as f32);
+                    let bh = c as f32 / max * plot_h;
+                    fb.fill_rect(
+                        x0.round() as u32,
+                        (bottom - bh).round() as u32,
+                        (x1 - x0 - 1.0).max(1.0) as u32,
+                        bh.round() as u32,
+                        fill,
+                    );
+                }
+            }
+            SplomDiagonal::Kde if !data.is_empty() => {
+                let kde = compute_kde(&data, None, KDE_POINTS);
+                let max = kde.iter().map(|p| p.1).fold(0.0_f32, f32::max);
+                if max > 0.0 {
+                    let pts: Vec<(f32, f32)> = kde
+                        .iter()
+                        .map(|&(x, d)| (xs.scale(x), bottom - d / max * plot_h))
+                        .collect();
+                    for pair in pts.windows(2) {
+                        draw_line_aa(fb, pair[0].0, pair[0].1, pair[1].0, pair[1].1, self.color);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        let name = &self.names[col];
+        TextStyle::current().draw(fb, PANEL_PADDING as i32, PANEL_PADDING as i32, name);
+        Ok(())
+    }
+
+    fn render_correlation(&self, fb: &mut Framebuffer, row: usize, col: usize) {
+        let r = self.correlation_between(row, col);
+        let label = format!("r={r:.2}");
+        // Blue for positive, red for negative; saturation grows with |r|.
+        let strength = (r.abs() * 200.0) as u8;
+        let color = if r >= 0.0 {
+            Rgba::rgb(200 - strength, 200 - strength, 220)
+        } else {
+            Rgba::rgb(220, 200 - strength, 200 - strength)
+        };
+        // Twice the label size when the panel is wide enough
+        let base = TextStyle::current().with_color(color);
+        let large = TextStyle { scale: base.scale * 2, ..base };
+        let font = if large.width(&label) + 4 <= fb.width() { large } else { base };
+        let x = (i32_px(fb.width()) - i32_px(font.width(&label))) / 2;
+        let y = (i32_px(fb.height()) - i32_px(font.height())) / 2;
+        font.draw(fb, x, y, &label);
+    }
+
+    /// Render a single panel of the matrix.
+    fn render_panel(&self, row: usize, col: usize, w: u32, h: u32) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(w, h)?;
+        fb.clear(Rgba::WHITE);
+
+        if row == col {
+            self.render_diagonal(&mut fb, col)?;
+        } else if row < col && self.show_correlation {
+            self.render_correlation(&mut fb, row, col);
+        } else {
+            self.render_scatter(&mut fb, row, col)?;
+        }
+
+        draw_rect_outline(&mut fb, 0, 0, w, h, Rgba::rgb(200, 200, 200), 1);
+        Ok(fb)
+    }
+
+    /// Render the matrix to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the layout or any panel fails to render.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let figure = self.to_framebuffer()?;
+        fb.blit(&figure, 0, 0);
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the layout or any panel fails to render.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        self.grid()?.compose(|row, col, w, h| self.render_panel(row, col, w, h).map(Some))
+    }
+}
+
+impl batuta_common::display::WithDimensions for Splom {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn iris_like() -> Splom {
+        Splom::new()
+            .column("a", &[1.0, 2.0, 3.0, 4.0, 5.0])
+            .column("b", &[2.0, 4.0, 6.0, 8.0, 10.0])
+            .column("c", &[5.0, 4.0, 3.0, 2.0, 1.0])
+    }
+
+    #[test]
+    fn test_splom_builder() {
+        let splom = iris_like().build().expect("builder should produce valid result");
+        assert_eq!(splom.column_count(), 3);
+        assert_eq!(splom.grid().expect("grid should build").rows(), 3);
+    }
+
+    #[test]
+    fn test_splom_validation() {
+        assert!(Splom::new().build().is_err());
+        assert!(Splom::new().column("a", &[]).build().is_err());
+        assert!(Splom::new().column("a", &[1.0]).column("b", &[1.0, 2.0]).build().is_err());
+    }
+
+    #[test]
+    fn test_splom_correlation() {
+        let splom = iris_like();
+        assert!((splom.correlation_between(0, 1) - 1.0).abs() < 1e-5);
+        assert!((splom.correlation_between(0, 2) + 1.0).abs() < 1e-5);
+        assert!(splom.correlation_between(0, 9).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_splom_render() {
+        for diagonal in [SplomDiagonal::Histogram, SplomDiagonal::Kde, SplomDiagonal::Blank] {
+            let splom = iris_like()
+                .diagonal(diagonal)
+                .correlation(true)
+                .color(Rgba::RED)
+                .dimensions(240, 240)
+                .build()
+                .expect("builder should produce valid result");
+            let fb = splom.to_framebuffer().expect("rendering should succeed");
+            assert_eq!((fb.width(), fb.height()), (240, 240));
+
+            // The lower-left panel (row 2, col 0) contains red scatter points.
+            let rect = splom.grid().expect("grid should build").cell_rect(2, 0).expect("cell");
+            let red = (rect.y..rect.y + rect.height).any(|y| {
+                (rect.x..rect.x + rect.width).any(|x| fb.get_pixel(x, y) == Some(Rgba::RED))
+            });
+            assert!(red);
+        }
+    }
+}
@@ -0,0 +1,434 @@
+//! Gantt-style resource utilization swimlanes.
+//!
+//! Each lane is a resource (CPU core, GPU, process) sampled over time. The
+//! upper part of a lane is a heat strip of the sampled utilization; the lower
+//! part shows contiguous bursts at or above a threshold as Gantt bars, which
+//! makes idle gaps and contention easy to spot in post-run capacity reports.
+
+use super::timeline::format_time;
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_line, i32_px, TextStyle};
+use crate::scale::{BandScale, ColorScale, LinearScale, Scale};
+
+/// One resource lane of utilization samples.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UtilizationLane {
+    /// Lane label.
+    pub name: String,
+    /// `(time, utilization)` samples; utilization is a 0-1 fraction.
+    pub samples: Vec<(f32, f32)>,
+}
+
+impl UtilizationLane {
+    /// Create a lane from samples, sorting them by time.
+    #[must_use]
+    pub fn new(name: impl Into<String>, mut samples: Vec<(f32, f32)>) -> Self {
+        samples.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { name: name.into(), samples }
+    }
+
+    /// Create a lane from evenly spaced readings, such as a monitor history
+    /// buffer. Readings are divided by `full_scale` (e.g. `100.0` for percent).
+    #[must_use]
+    pub fn from_series(
+        name: impl Into<String>,
+        values: &[f64],
+        interval_secs: f32,
+        full_scale: f64,
+    ) -> Self {
+        let samples = values
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.is_finite())
+            .map(|(i, v)| (i as f32 * interval_secs, (v / full_scale) as f32))
+            .collect();
+        Self { name: name.into(), samples }
+    }
+
+    /// Mean utilization, weighting each sample by the time until the next one.
+    ///
+    /// The last sample is weighted like the median sample spacing so that a
+    /// single trailing burst is not dropped.
+    #[must_use]
+    pub fn mean(&self) -> f32 {
+        let spans = self.spans();
+        let total: f32 = spans.iter().map(|&(t0, t1, _)| t1 - t0).sum();
+        if total <= 0.0 {
+            return self.samples.first().map_or(0.0, |s| s.1);
+        }
+        spans.iter().map(|&(t0, t1, u)| (t1 - t0) * u).sum::<f32>() / total
+    }
+
+    /// Time intervals where utilization stays at or above `threshold`.
+    #[must_use]
+    pub fn bursts(&self, threshold: f32) -> Vec<(f32, f32)> {
+        let mut bursts: Vec<(f32, f32)> = Vec::new();
+        for (t0, t1, u) in self.spans() {
+            if u < threshold {
+                continue;
+            }
+            match bursts.last_mut() {
+                Some(last) if (last.1 - t0).abs() < f32::EPSILON => last.1 = t1,
+                _ => bursts.push((t0, t1)),
+            }
+        }
+        bursts
+    }
+
+    /// Sample intervals `(start, end, utilization)`.
+    fn spans(&self) -> Vec<(f32, f32, f32)> {
+        let mut gaps: Vec<f32> = self.samples.windows(2).map(|w| w[1].0 - w[0].0).collect();
+        gaps.sort_by(f32::total_cmp);
+        let last_gap = gaps.get(gaps.len().saturating_sub(1) / 2).copied().unwrap_or(0.0);
+        self.samples
+            .iter()
+            .enumerate()
+            .map(|(i, &(t, u))| {
+                let end = self.samples.get(i + 1).map_or(t + last_gap, |next| next.0);
+                (t, end, u.clamp(0.0, 1.0))
+            })
+            .collect()
+    }
+}
+
+/// Builder for resource utilization swimlane charts.
+#[derive(Debug, Clone)]
+pub struct UtilizationSwimlane {
+    /// Lanes, drawn top to bottom.
+    lanes: Vec<UtilizationLane>,
+    /// Burst threshold (0-1).
+    threshold: f32,
+    /// Heat strip color scale over 0-1 (None = heat).
+    color_scale: Option<ColorScale>,
+    /// Burst bar color.
+    burst_color: Rgba,
+    /// Explicit time range (None = sample extent).
+    time_range: Option<(f32, f32)>,
+    /// Show mean utilization per lane.
+    show_mean: bool,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the plot.
+    margin: u32,
+}
+
+impl Default for UtilizationSwimlane {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UtilizationSwimlane {
+    /// Create a new swimlane builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 400);
+        Self {
+            lanes: Vec::new(),
+            threshold: 0.5,
+            color_scale: None,
+            burst_color: Rgba::rgb(70, 130, 180),
+            time_range: None,
+            show_mean: true,
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Add a lane from `(time, utilization)` samples.
+    #[must_use]
+    pub fn lane(mut self, name: &str, samples: &[(f32, f32)]) -> Self {
+        self.lanes.push(UtilizationLane::new(name, samples.to_vec()));
+        self
+    }
+
+    /// Add a prebuilt lane.
+    #[must_use]
+    pub fn add_lane(mut self, lane: UtilizationLane) -> Self {
+        self.lanes.push(lane);
+        self
+    }
+
+    /// Set the utilization at or above which a sample counts as a burst.
+    #[must_use]
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the heat strip color scale (domain 0-1).
+    #[must_use]
+    pub fn color_scale(mut self, scale: ColorScale) -> Self {
+        self.color_scale = Some(scale);
+        self
+    }
+
+    /// Set the burst bar color.
+    #[must_use]
+    pub fn burst_color(mut self, color: Rgba) -> Self {
+        self.burst_color = color;
+        self
+    }
+
+    /// Fix the time axis range.
+    #[must_use]
+    pub fn time_range(mut self, start: f32, end: f32) -> Self {
+        self.time_range = Some((start, end));
+        self
+    }
+
+    /// Show or hide the mean utilization column.
+    #[must_use]
+    pub fn show_mean(mut self, show: bool) -> Self {
+        self.show_mean = show;
+        self
+    }
+
+    /// Set margin.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Validate and build the chart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no samples or a sample is not finite.
+    pub fn build(self) -> Result<Self> {
+        if self.lanes.iter().all(|l| l.samples.is_empty()) {
+            return Err(Error::EmptyData);
+        }
+        for lane in &self.lanes {
+            if lane.samples.iter().any(|(t, u)| !t.is_finite() || !u.is_finite()) {
+                return Err(Error::Rendering(format!(
+                    "Lane '{}' has non-finite samples",
+                    lane.name
+                )));
+            }
+        }
+        if let Some((start, end)) = self.time_range {
+            if !start.is_finite() || !end.is_finite() || end <= start {
+                return Err(Error::Rendering(format!("Invalid time range: {start}..{end}")));
+            }
+        }
+        Ok(self)
+    }
+
+    /// Lanes in drawing order.
+    #[must_use]
+    pub fn lanes(&self) -> &[UtilizationLane] {
+        &self.lanes
+    }
+
+    /// Time axis extent, padded when all samples share one timestamp.
+    #[must_use]
+    pub fn time_extent(&self) -> (f32, f32) {
+        if let Some(range) = self.time_range {
+            return range;
+        }
+        let (min, max) = self
+            .lanes
+            .iter()
+            .flat_map(UtilizationLane::spans)
+            .fold((f32::MAX, f32::MIN), |(lo, hi), (t0, t1, _)| (lo.min(t0), hi.max(t1)));
+        if min > max {
+            (0.0, 1.0)
+        } else if (max - min).abs() < f32::EPSILON {
+            (min, min + 1.0)
+        } else {
+            (min, max)
+        }
+    }
+
+    /// Render the swimlanes to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the plot area is too small.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let font = TextStyle::current();
+        let axis_color = Rgba::rgb(60, 60, 60);
+        let names: Vec<String> = self.lanes.iter().map(|l| l.name.clone()).collect();
+        let gutter_left = names.iter().map(|l| font.width(l)).max().unwrap_or(0) + 8;
+        let gutter_right = if self.show_mean { font.width("100%") + 8 } else { 0 };
+        let gutter_bottom = font.height() + 8;
+
+        let left = (self.margin + gutter_left) as f32;
+        let right = self.width.saturating_sub(self.margin + gutter_right) as f32;
+        let top = self.margin as f32;
+        let bottom = self.height.saturating_sub(self.margin + gutter_bottom) as f32;
+        if right - left < 2.0 || bottom - top < 2.0 {
+            return Err(Error::Rendering("Plot area too small".into()));
+        }
+
+        let scale = match &self.color_scale {
+            Some(scale) => scale.clone(),
+            None => ColorScale::heat((0.0, 1.0)).ok_or(Error::EmptyData)?,
+        };
+        let lane_scale = BandScale::new(&names, (top, bottom))?.padding(0.15);
+        let (t0, t1) = self.time_extent();
+        let time_scale = LinearScale::new((t0, t1), (left, right))?;
+        let band = lane_scale.bandwidth();
+        let strip_h = (band * 0.65).round().max(1.0);
+        let bar_h = (band - strip_h - 2.0).round().max(1.0);
+
+        for (row, lane) in self.lanes.iter().enumerate() {
+            let y = lane_scale.scale(row).round();
+            fb.fill_rect(
+                left as u32,
+                y as u32,
+                (right - left) as u32,
+                strip_h as u32,
+                Rgba::rgb(240, 240, 240),
+            );
+
+            for (s0, s1, u) in lane.spans() {
+                if s1 < t0 || s0 > t1 {
+                    continue;
+                }
+                let x0 = time_scale.scale(s0).clamp(left, right).round();
+                let x1 = time_scale.scale(s1).clamp(left, right).round();
+                fb.fill_rect(
+                    x0 as u32,
+                    y as u32,
+                    ((x1 - x0) as u32).max(1),
+                    strip_h as u32,
+                    scale.scale(u),
+                );
+            }
+
+            let bar_y = (y + strip_h + 2.0) as u32;
+            for (b0, b1) in lane.bursts(self.threshold) {
+                let x0 = time_scale.scale(b0).clamp(left, right).round();
+                let x1 = time_scale.scale(b1).clamp(left, right).round();
+                if x1 > x0 {
+                    fb.fill_rect(
+                        x0 as u32,
+                        bar_y,
+                        (x1 - x0) as u32,
+                        bar_h as u32,
+                        self.burst_color,
+                    );
+                }
+            }
+
+            let ly = lane_scale.center(row) as i32 - i32_px(font.height()) / 2;
+            let lx = i32_px(self.margin + gutter_left - 8) - i32_px(font.width(&lane.name));
+            font.draw(fb, lx, ly, &lane.name);
+            if self.show_mean {
+                let text = format!("{:.0}%", lane.mean() * 100.0);
+                font.draw(fb, right as i32 + 8, ly, &text);
+            }
+        }
+
+        let axis_y = bottom as i32;
+        draw_line(fb, left as i32, axis_y, right as i32, axis_y, axis_color);
+        for t in [t0, (t0 + t1) / 2.0, t1] {
+            let x = time_scale.scale(t) as i32;
+            draw_line(fb, x, axis_y, x, axis_y + 3, axis_color);
+            let text = format_time(t);
+            let tw = i32_px(font.width(&text));
+            let tx = (x - tw / 2).clamp(left as i32, (right as i32 - tw).max(left as i32));
+            font.draw(fb, tx, axis_y + 6, &text);
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+impl batuta_common::display::WithDimensions for UtilizationSwimlane {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn gpu() -> UtilizationLane {
+        UtilizationLane::new(
+            "gpu0",
+            vec![(0.0, 0.1), (1.0, 0.9), (2.0, 0.8), (3.0, 0.2), (4.0, 1.0)],
+        )
+    }
+
+    #[test]
+    fn test_lane_bursts_merge_adjacent_samples() {
+        assert_eq!(gpu().bursts(0.5), vec![(1.0, 3.0), (4.0, 5.0)]);
+        assert!(gpu().bursts(1.5).is_empty());
+    }
+
+    #[test]
+    fn test_lane_mean_is_time_weighted() {
+        let lane = UtilizationLane::new("cpu", vec![(0.0, 1.0), (3.0, 0.0), (4.0, 0.0)]);
+        // 3s at 100%, then 1s + trailing median gap (1s) at 0%
+        assert!((lane.mean() - 0.6).abs() < 1e-6);
+        let single = UtilizationLane::new("one", vec![(5.0, 0.4)]);
+        assert!((single.mean() - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_lane_from_series() {
+        let lane = UtilizationLane::from_series("core0", &[50.0, f64::NAN, 100.0], 0.5, 100.0);
+        assert_eq!(lane.samples, vec![(0.0, 0.5), (1.0, 1.0)]);
+    }
+
+    #[test]
+    fn test_lane_sorts_samples() {
+        let lane = UtilizationLane::new("x", vec![(2.0, 0.5), (0.0, 0.1)]);
+        assert_eq!(lane.samples[0], (0.0, 0.1));
+    }
+
+    #[test]
+    fn test_swimlane_validation_and_extent() {
+        assert!(UtilizationSwimlane::new().build().is_err());
+        assert!(UtilizationSwimlane::new().lane("a", &[(f32::NAN, 0.5)]).build().is_err());
+        assert!(UtilizationSwimlane::new()
+            .lane("a", &[(0.0, 0.5)])
+            .time_range(2.0, 1.0)
+            .build()
+            .is_err());
+        let chart = UtilizationSwimlane::new().add_lane(gpu()).build().expect("valid chart");
+        assert_eq!(chart.time_extent(), (0.0, 5.0));
+    }
+
+    #[test]
+    fn test_swimlane_render_bursts() {
+        let chart = UtilizationSwimlane::new()
+            .add_lane(gpu())
+            .lane("cpu0", &[(0.0, 0.0), (5.0, 0.0)])
+            .burst_color(Rgba::RED)
+            .dimensions(300, 120)
+            .build()
+            .expect("valid chart");
+        let fb = chart.to_framebuffer().expect("rendering should succeed");
+        let red_rows: Vec<u32> = (0..fb.height())
+            .filter(|&y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(Rgba::RED)))
+            .collect();
+        // Only the gpu lane (top half) has bursts
+        assert!(!red_rows.is_empty());
+        assert!(red_rows.iter().all(|&y| y < 60));
+    }
+}
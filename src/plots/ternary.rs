@@ -0,0 +1,410 @@
+//! Ternary plot for three-component compositional data.
+//!
+//! Each observation `(a, b, c)` is normalized to sum to one and placed by
+//! barycentric coordinates inside an equilateral triangle: `a` at the top
+//! vertex, `b` bottom-left and `c` bottom-right. Gridlines run parallel to
+//! each edge at fixed fractions. Points can be drawn individually or
+//! aggregated into a triangular density grid.
+//!
+//! # References
+//!
+//! - Aitchison, J. (1986). *The Statistical Analysis of Compositional Data*.
+//!   Chapman & Hall.
+
+use std::collections::HashMap;
+
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_circle, draw_line_aa, i32_px, TextStyle};
+use crate::scale::{ColorScale, Scale};
+
+/// How observations are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TernaryKind {
+    /// One marker per observation.
+    #[default]
+    Scatter,
+    /// Counts over a triangular grid with `resolution` divisions per edge.
+    Density {
+        /// Divisions per triangle edge.
+        resolution: usize,
+    },
+}
+
+/// Builder for creating ternary plots.
+#[derive(Debug, Clone)]
+pub struct TernaryPlot {
+    /// Compositions as `(a, b, c)`; normalized on use.
+    points: Vec<[f32; 3]>,
+    /// Vertex labels (top, bottom-left, bottom-right).
+    labels: [String; 3],
+    /// Scatter or density.
+    kind: TernaryKind,
+    /// Gridline divisions per edge (0 = no grid).
+    grid_divisions: usize,
+    /// Marker color.
+    color: Rgba,
+    /// Marker radius in pixels.
+    point_radius: i32,
+    /// Density color scale over normalized counts 0-1 (None = viridis).
+    density_scale: Option<ColorScale>,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the plot.
+    margin: u32,
+}
+
+impl Default for TernaryPlot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TernaryPlot {
+    /// Create a new ternary plot builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(500, 460);
+        Self {
+            points: Vec::new(),
+            labels: ["A".to_string(), "B".to_string(), "C".to_string()],
+            kind: TernaryKind::Scatter,
+            grid_divisions: 10,
+            color: Rgba::rgb(70, 130, 180),
+            point_radius: 3,
+            density_scale: None,
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Set the compositions from three component slices.
+    #[must_use]
+    pub fn data(mut self, a: &[f32], b: &[f32], c: &[f32]) -> Self {
+        self.points = a.iter().zip(b).zip(c).map(|((&a, &b), &c)| [a, b, c]).collect();
+        self
+    }
+
+    /// Add a single composition.
+    #[must_use]
+    pub fn point(mut self, a: f32, b: f32, c: f32) -> Self {
+        self.points.push([a, b, c]);
+        self
+    }
+
+    /// Set the vertex labels (top, bottom-left, bottom-right).
+    #[must_use]
+    pub fn labels(
+        mut self,
+        a: impl Into<String>,
+        b: impl Into<String>,
+        c: impl Into<String>,
+    ) -> Self {
+        self.labels = [a.into(), b.into(), c.into()];
+        self
+    }
+
+    /// Set scatter or density drawing.
+    #[must_use]
+    pub fn kind(mut self, kind: TernaryKind) -> Self {
+        self.kind = match kind {
+            TernaryKind::Density { resolution } => {
+                TernaryKind::Density { resolution: resolution.max(1) }
+            }
+            TernaryKind::Scatter => TernaryKind::Scatter,
+        };
+        self
+    }
+
+    /// Set the gridline divisions per edge (0 disables the grid).
+    #[must_use]
+    pub fn grid(mut self, divisions: usize) -> Self {
+        self.grid_divisions = divisions;
+        self
+    }
+
+    /// Set the marker color.
+    #[must_use]
+    pub fn color(mut self, color: Rgba) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the marker radius in pixels.
+    #[must_use]
+    pub fn point_radius(mut self, radius: i32) -> Self {
+        self.point_radius = radius.max(1);
+        self
+    }
+
+    /// Set the density color scale over normalized counts `(0, 1)`.
+    #[must_use]
+    pub fn density_scale(mut self, scale: ColorScale) -> Self {
+        self.density_scale = Some(scale);
+        self
+    }
+
+    /// Set the margin around the plot.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the plot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no compositions, or any component is
+    /// negative or non-finite, or a composition sums to zero.
+    pub fn build(self) -> Result<Self> {
+        if self.points.is_empty() {
+            return Err(Error::EmptyData);
+        }
+        for p in &self.points {
+            if p.iter().any(|v| !v.is_finite() || *v < 0.0) || p.iter().sum::<f32>() <= 0.0 {
+                return Err(Error::Rendering(format!(
+                    "Ternary components must be finite, non-negative and not all zero: {p:?}"
+                )));
+            }
+        }
+        Ok(self)
+    }
+
+    /// Compositions normalized to sum to one (invalid rows are dropped).
+    #[must_use]
+    pub fn normalized(&self) -> Vec<[f32; 3]> {
+        self.points
+            .iter()
+            .filter_map(|p| {
+                let sum: f32 = p.iter().sum();
+                (sum > 0.0 && p.iter().all(|v| v.is_finite() && *v >= 0.0))
+                    .then(|| [p[0] / sum, p[1] / sum, p[2] / sum])
+            })
+            .collect()
+    }
+
+    /// Triangle vertices (top, bottom-left, bottom-right) in pixels.
+    fn vertices(&self) -> [(f32, f32); 3] {
+        let label_h = (TextStyle::current().height() + 6) as f32;
+        let w = self.width.saturating_sub(2 * self.margin) as f32;
+        let h = self.height.saturating_sub(2 * self.margin) as f32 - 2.0 * label_h;
+        // Largest equilateral triangle that fits
+        let side = w.min(h.max(0.0) * 2.0 / 3.0_f32.sqrt());
+        let tri_h = side * 3.0_f32.sqrt() / 2.0;
+        let cx = self.width as f32 / 2.0;
+        let top = self.margin as f32 + label_h + (h - tri_h).max(0.0) / 2.0;
+        [(cx, top), (cx - side / 2.0, top + tri_h), (cx + side / 2.0, top + tri_h)]
+    }
+
+    /// Pixel position of a normalized composition.
+    fn project(vertices: &[(f32, f32); 3], p: [f32; 3]) -> (f32, f32) {
+        (
+            p[0] * vertices[0].0 + p[1] * vertices[1].0 + p[2] * vertices[2].0,
+            p[0] * vertices[0].1 + p[1] * vertices[1].1 + p[2] * vertices[2].1,
+        )
+    }
+
+    /// Triangular grid cell key for a normalized composition.
+    fn density_cell(p: [f32; 3], resolution: usize) -> (usize, usize, usize) {
+        let n = resolution as f32;
+        let f = |v: f32| ((v * n).floor() as usize).min(resolution - 1);
+        (f(p[0]), f(p[1]), f(p[2]))
+    }
+
+    /// Counts per triangular density cell.
+    #[must_use]
+    pub fn density_counts(&self, resolution: usize) -> Vec<((usize, usize, usize), usize)> {
+        let resolution = resolution.max(1);
+        let mut counts: Vec<((usize, usize, usize), usize)> = Vec::new();
+        for p in self.normalized() {
+            let key = Self::density_cell(p, resolution);
+            match counts.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, c)) => *c += 1,
+                None => counts.push((key, 1)),
+            }
+        }
+        counts
+    }
+
+    /// Render the plot to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no density color scale is available.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let v = self.vertices();
+        let edge = Rgba::rgb(60, 60, 60);
+
+        if let TernaryKind::Density { resolution } = self.kind {
+            let scale = match &self.density_scale {
+                Some(scale) => scale.clone(),
+                None => ColorScale::viridis((0.0, 1.0)).ok_or(Error::EmptyData)?,
+            };
+            let counts: HashMap<_, _> = self.density_counts(resolution).into_iter().collect();
+            let max = counts.values().copied().max().unwrap_or(0).max(1) as f32;
+
+            // Barycentric coordinates per pixel inside the triangle
+            let det = (v[1].1 - v[2].1) * (v[0].0 - v[2].0) + (v[2].0 - v[1].0) * (v[0].1 - v[2].1);
+            let y0 = v[0].1.floor().max(0.0) as u32;
+            let y1 = (v[1].1.ceil() as u32).min(fb.height());
+            let x0 = v[1].0.floor().max(0.0) as u32;
+            let x1 = (v[2].0.ceil() as u32).min(fb.width());
+            for py in y0..y1 {
+                for px in x0..x1 {
+                    let (x, y) = (px as f32 + 0.5, py as f32 + 0.5);
+                    let a =
+                        ((v[1].1 - v[2].1) * (x - v[2].0) + (v[2].0 - v[1].0) * (y - v[2].1)) / det;
+                    let b =
+                        ((v[2].1 - v[0].1) * (x - v[2].0) + (v[0].0 - v[2].0) * (y - v[2].1)) / det;
+                    let c = 1.0 - a - b;
+                    if a < 0.0 || b < 0.0 || c < 0.0 {
+                        continue;
+                    }
+                    let key = Self::density_cell([a, b, c], resolution);
+                    if let Some(count) = counts.get(&key) {
+                        fb.set_pixel(px, py, scale.scale(*count as f32 / max));
+                    }
+                }
+            }
+        }
+
+        if self.grid_divisions > 1 {
+            let grid = Rgba::rgb(215, 215, 215);
+            for i in 1..self.grid_divisions {
+                let t = i as f32 / self.grid_divisions as f32;
+                // Lines of constant a, b and c
+                for (from, to) in [
+                    ([t, 1.0 - t, 0.0], [t, 0.0, 1.0 - t]),
+                    ([1.0 - t, t, 0.0], [0.0, t, 1.0 - t]),
+                    ([1.0 - t, 0.0, t], [0.0, 1.0 - t, t]),
+                ] {
+                    let (p0, p1) = (Self::project(&v, from), Self::project(&v, to));
+                    draw_line_aa(fb, p0.0, p0.1, p1.0, p1.1, grid);
+                }
+            }
+        }
+
+        for (i, j) in [(0, 1), (1, 2), (2, 0)] {
+            draw_line_aa(fb, v[i].0, v[i].1, v[j].0, v[j].1, edge);
+        }
+
+        if self.kind == TernaryKind::Scatter {
+            for p in self.normalized() {
+                let (x, y) = Self::project(&v, p);
+                draw_circle(fb, x.round() as i32, y.round() as i32, self.point_radius, self.color);
+            }
+        }
+
+        let font = TextStyle::current();
+        let th = i32_px(font.height());
+        let [a, b, c] = &self.labels;
+        font.draw(fb, v[0].0 as i32 - i32_px(font.width(a)) / 2, v[0].1 as i32 - th - 4, a);
+        font.draw(fb, v[1].0 as i32, v[1].1 as i32 + 4, b);
+        font.draw(fb, v[2].0 as i32 - i32_px(font.width(c)), v[2].1 as i32 + 4, c);
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+impl batuta_common::display::WithDimensions for TernaryPlot {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn sample() -> TernaryPlot {
+        TernaryPlot::new()
+            .data(&[2.0, 0.0, 1.0], &[1.0, 0.0, 1.0], &[1.0, 5.0, 1.0])
+            .labels("sand", "silt", "clay")
+    }
+
+    #[test]
+    fn test_ternary_normalized() {
+        let plot = sample().build().expect("builder should produce valid result");
+        let norm = plot.normalized();
+        let close = |p: [f32; 3], q: [f32; 3]| p.iter().zip(q).all(|(a, b)| (a - b).abs() < 1e-6);
+        assert!(close(norm[0], [0.5, 0.25, 0.25]));
+        assert!(close(norm[1], [0.0, 0.0, 1.0]));
+        assert!(norm.iter().all(|p| (p.iter().sum::<f32>() - 1.0).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_ternary_validation() {
+        assert!(TernaryPlot::new().build().is_err());
+        assert!(TernaryPlot::new().point(-1.0, 1.0, 1.0).build().is_err());
+        assert!(TernaryPlot::new().point(0.0, 0.0, 0.0).build().is_err());
+        assert!(TernaryPlot::new().point(f32::NAN, 1.0, 1.0).build().is_err());
+    }
+
+    #[test]
+    fn test_ternary_projection() {
+        let plot = sample();
+        let v = plot.vertices();
+        // Pure components land on the vertices
+        assert_eq!(TernaryPlot::project(&v, [1.0, 0.0, 0.0]), v[0]);
+        assert_eq!(TernaryPlot::project(&v, [0.0, 0.0, 1.0]), v[2]);
+        // Triangle is equilateral
+        let side = |i: usize, j: usize| (v[i].0 - v[j].0).hypot(v[i].1 - v[j].1);
+        assert!((side(0, 1) - side(1, 2)).abs() < 1e-3);
+        assert!((side(1, 2) - side(2, 0)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_ternary_density_counts() {
+        let plot =
+            TernaryPlot::new().point(0.5, 0.3, 0.2).point(0.45, 0.3, 0.25).point(1.0, 0.0, 0.0);
+        let counts = plot.density_counts(3);
+        assert_eq!(counts, vec![((1, 0, 0), 2), ((2, 0, 0), 1)]);
+    }
+
+    #[test]
+    fn test_ternary_render_scatter() {
+        let plot = sample()
+            .color(Rgba::RED)
+            .dimensions(200, 200)
+            .build()
+            .expect("builder should produce valid result");
+        let fb = plot.to_framebuffer().expect("rendering should succeed");
+        let red = (0..fb.height())
+            .any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(Rgba::RED)));
+        assert!(red);
+    }
+
+    #[test]
+    fn test_ternary_render_density() {
+        let scale = ColorScale::new(vec![Rgba::BLUE, Rgba::BLUE], (0.0, 1.0))
+            .expect("color scale should be valid");
+        let plot = sample()
+            .kind(TernaryKind::Density { resolution: 4 })
+            .density_scale(scale)
+            .grid(0)
+            .dimensions(200, 200);
+        let fb = plot.to_framebuffer().expect("rendering should succeed");
+        let blue = (0..fb.height())
+            .any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(Rgba::BLUE)));
+        assert!(blue);
+    }
+}
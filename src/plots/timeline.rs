@@ -0,0 +1,462 @@
+//! Timeline (event strip) plot with categorical lanes.
+//!
+//! Places instantaneous events and intervals on named lanes over a shared
+//! time axis, colored by event type. Useful for training job phases
+//! (load / train / eval / checkpoint) or monitor alert histories.
+//!
+//! # References
+//!
+//! - Wilkinson, L. (2005). *The Grammar of Graphics*. Springer. (Interval elements)
+
+use crate::color::{categorical, Rgba};
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_line, i32_px, TextStyle};
+use crate::scale::{BandScale, LinearScale, Scale};
+
+/// A single event or interval on a lane.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineEvent {
+    /// Lane (row) name.
+    pub lane: String,
+    /// Start time (or the instant, for point events).
+    pub start: f32,
+    /// End time (None = instantaneous event).
+    pub end: Option<f32>,
+    /// Event type used for coloring.
+    pub kind: String,
+}
+
+impl TimelineEvent {
+    /// Create an instantaneous event.
+    #[must_use]
+    pub fn instant(lane: impl Into<String>, time: f32, kind: impl Into<String>) -> Self {
+        Self { lane: lane.into(), start: time, end: None, kind: kind.into() }
+    }
+
+    /// Create an interval spanning `start..end`.
+    #[must_use]
+    pub fn interval(
+        lane: impl Into<String>,
+        start: f32,
+        end: f32,
+        kind: impl Into<String>,
+    ) -> Self {
+        Self { lane: lane.into(), start, end: Some(end), kind: kind.into() }
+    }
+
+    /// Whether this is an instantaneous event.
+    #[must_use]
+    pub fn is_instant(&self) -> bool {
+        self.end.is_none()
+    }
+
+    /// Last time covered by the event.
+    #[must_use]
+    pub fn stop(&self) -> f32 {
+        self.end.unwrap_or(self.start)
+    }
+}
+
+/// Builder for creating timeline plots.
+#[derive(Debug, Clone)]
+pub struct Timeline {
+    /// Events and intervals.
+    events: Vec<TimelineEvent>,
+    /// Explicit lane order (lanes not listed are appended in first-seen order).
+    lane_order: Vec<String>,
+    /// Explicit colors per event type.
+    kind_colors: Vec<(String, Rgba)>,
+    /// Fixed time range (None = data extent).
+    time_range: Option<(f32, f32)>,
+    /// Interval bar height as a fraction of the lane height (0.1 - 1.0).
+    bar_fraction: f32,
+    /// Instant marker half-size in pixels.
+    marker_size: i32,
+    /// Draw the time axis and lane labels.
+    show_axes: bool,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the plot.
+    margin: u32,
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Timeline {
+    /// Create a new timeline builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 300);
+        Self {
+            events: Vec::new(),
+            lane_order: Vec::new(),
+            kind_colors: Vec::new(),
+            time_range: None,
+            bar_fraction: 0.6,
+            marker_size: 4,
+            show_axes: true,
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Add an event or interval.
+    #[must_use]
+    pub fn add_event(mut self, event: TimelineEvent) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    /// Add an instantaneous event (convenience method).
+    #[must_use]
+    pub fn event(self, lane: impl Into<String>, time: f32, kind: impl Into<String>) -> Self {
+        self.add_event(TimelineEvent::instant(lane, time, kind))
+    }
+
+    /// Add an interval (convenience method).
+    #[must_use]
+    pub fn interval(
+        self,
+        lane: impl Into<String>,
+        start: f32,
+        end: f32,
+        kind: impl Into<String>,
+    ) -> Self {
+        self.add_event(TimelineEvent::interval(lane, start, end, kind))
+    }
+
+    /// Set the lane order, top to bottom.
+    #[must_use]
+    pub fn lanes(mut self, lanes: &[impl AsRef<str>]) -> Self {
+        self.lane_order = lanes.iter().map(|l| l.as_ref().to_string()).collect();
+        self
+    }
+
+    /// Set the color for an event type (others use the categorical palette).
+    #[must_use]
+    pub fn kind_color(mut self, kind: impl Into<String>, color: Rgba) -> Self {
+        let kind = kind.into();
+        self.kind_colors.retain(|(k, _)| *k != kind);
+        self.kind_colors.push((kind, color));
+        self
+    }
+
+    /// Fix the visible time range instead of using the data extent.
+    #[must_use]
+    pub fn time_range(mut self, start: f32, end: f32) -> Self {
+        self.time_range = Some((start, end));
+        self
+    }
+
+    /// Set the interval bar height as a fraction of the lane height.
+    #[must_use]
+    pub fn bar_height(mut self, fraction: f32) -> Self {
+        self.bar_fraction = fraction.clamp(0.1, 1.0);
+        self
+    }
+
+    /// Set the instant marker half-size in pixels.
+    #[must_use]
+    pub fn marker_size(mut self, size: i32) -> Self {
+        self.marker_size = size.max(1);
+        self
+    }
+
+    /// Enable or disable the time axis and lane labels.
+    #[must_use]
+    pub fn axes(mut self, show: bool) -> Self {
+        self.show_axes = show;
+        self
+    }
+
+    /// Set the margin around the plot.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the timeline.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no events, a time is not finite, an
+    /// interval ends before it starts, or the time range is empty.
+    pub fn build(self) -> Result<Self> {
+        if self.events.is_empty() {
+            return Err(Error::EmptyData);
+        }
+        for event in &self.events {
+            if !event.start.is_finite() || !event.stop().is_finite() {
+                return Err(Error::Rendering(format!(
+                    "Timeline event on lane '{}' has a non-finite time",
+                    event.lane
+                )));
+            }
+            if event.stop() < event.start {
+                return Err(Error::Rendering(format!(
+                    "Timeline interval on lane '{}' ends before it starts",
+                    event.lane
+                )));
+            }
+        }
+        if let Some((start, end)) = self.time_range {
+            if !start.is_finite() || !end.is_finite() || end <= start {
+                return Err(Error::ScaleDomain(format!(
+                    "Timeline range must be increasing, got {start}..{end}"
+                )));
+            }
+        }
+        Ok(self)
+    }
+
+    /// Lanes in display order (explicit order first, then first-seen).
+    #[must_use]
+    pub fn lane_names(&self) -> Vec<String> {
+        let mut lanes = self.lane_order.clone();
+        for event in &self.events {
+            if !lanes.contains(&event.lane) {
+                lanes.push(event.lane.clone());
+            }
+        }
+        lanes
+    }
+
+    /// Event types in first-seen order.
+    #[must_use]
+    pub fn kinds(&self) -> Vec<String> {
+        let mut kinds: Vec<String> = Vec::new();
+        for event in &self.events {
+            if !kinds.contains(&event.kind) {
+                kinds.push(event.kind.clone());
+            }
+        }
+        kinds
+    }
+
+    /// Color for an event type.
+    #[must_use]
+    pub fn color_for(&self, kind: &str) -> Rgba {
+        if let Some((_, color)) = self.kind_colors.iter().find(|(k, _)| k == kind) {
+            return *color;
+        }
+        let index = self.kinds().iter().position(|k| k == kind).unwrap_or(0);
+        categorical(index)
+    }
+
+    /// Visible time range (explicit, or the data extent padded if degenerate).
+    #[must_use]
+    pub fn time_extent(&self) -> (f32, f32) {
+        if let Some(range) = self.time_range {
+            return range;
+        }
+        let min = self.events.iter().map(|e| e.start).fold(f32::INFINITY, f32::min);
+        let max = self.events.iter().map(TimelineEvent::stop).fold(f32::NEG_INFINITY, f32::max);
+        if !min.is_finite() || !max.is_finite() {
+            return (0.0, 1.0);
+        }
+        if (max - min).abs() < f32::EPSILON {
+            return (min - 0.5, max + 0.5);
+        }
+        (min, max)
+    }
+
+    /// Render the timeline to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no events.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let lanes = self.lane_names();
+        let font = TextStyle::current();
+        let axis_color = Rgba::rgb(60, 60, 60);
+
+        let (gutter_left, gutter_bottom) = if self.show_axes {
+            (lanes.iter().map(|l| font.width(l)).max().unwrap_or(0) + 8, font.height() + 8)
+        } else {
+            (0, 0)
+        };
+
+        let left = (self.margin + gutter_left) as f32;
+        let right = self.width.saturating_sub(self.margin) as f32;
+        let top = self.margin as f32;
+        let bottom = self.height.saturating_sub(self.margin + gutter_bottom) as f32;
+
+        let lane_scale = BandScale::new(&lanes, (top, bottom))?.padding(0.1);
+        let time_scale = LinearScale::new(self.time_extent(), (left, right))?;
+
+        // Alternate lane shading so long lanes stay readable
+        for row in (0..lanes.len()).step_by(2) {
+            let y = lane_scale.scale(row);
+            fb.fill_rect(
+                left as u32,
+                y as u32,
+                (right - left) as u32,
+                lane_scale.bandwidth() as u32,
+                Rgba::rgb(245, 245, 245),
+            );
+        }
+
+        let bar_h = (lane_scale.bandwidth() * self.bar_fraction).max(1.0);
+
+        // Intervals first so instants stay visible on top
+        for event in self.events.iter().filter(|e| !e.is_instant()) {
+            let Some(row) = lanes.iter().position(|l| *l == event.lane) else { continue };
+            let x0 = time_scale.scale(event.start).clamp(left, right);
+            let x1 = time_scale.scale(event.stop()).clamp(left, right);
+            let y = lane_scale.center(row) - bar_h / 2.0;
+            fb.fill_rect(
+                x0.round() as u32,
+                y.round() as u32,
+                ((x1 - x0).round() as u32).max(1),
+                bar_h.round() as u32,
+                self.color_for(&event.kind),
+            );
+        }
+
+        for event in self.events.iter().filter(|e| e.is_instant()) {
+            let Some(row) = lanes.iter().position(|l| *l == event.lane) else { continue };
+            let x = time_scale.scale(event.start);
+            if x < left || x > right {
+                continue;
+            }
+            let (cx, cy) = (x.round() as i32, lane_scale.center(row).round() as i32);
+            let color = self.color_for(&event.kind);
+            // Filled diamond
+            for dy in -self.marker_size..=self.marker_size {
+                let half = self.marker_size - dy.abs();
+                draw_line(fb, cx - half, cy + dy, cx + half, cy + dy, color);
+            }
+        }
+
+        if self.show_axes {
+            for (row, lane) in lanes.iter().enumerate() {
+                let lx = i32_px(self.margin + gutter_left - 8) - i32_px(font.width(lane));
+                let ly = lane_scale.center(row) as i32 - i32_px(font.height()) / 2;
+                font.draw(fb, lx, ly, lane);
+            }
+
+            let axis_y = bottom as i32;
+            draw_line(fb, left as i32, axis_y, right as i32, axis_y, axis_color);
+            let (t0, t1) = self.time_extent();
+            for t in [t0, (t0 + t1) / 2.0, t1] {
+                let x = time_scale.scale(t) as i32;
+                draw_line(fb, x, axis_y, x, axis_y + 3, axis_color);
+                let text = format_time(t);
+                let tw = i32_px(font.width(&text));
+                let tx = (x - tw / 2).clamp(left as i32, (right as i32 - tw).max(left as i32));
+                font.draw(fb, tx, axis_y + 6, &text);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+/// Compact tick label in the current locale: integers without decimals,
+/// otherwise one decimal.
+pub(super) fn format_time(t: f32) -> String {
+    let fractional = (t - t.round()).abs() >= 1e-3;
+    let decimals = usize::from(fractional);
+    crate::style::locale().format_number(f64::from(t), decimals)
+}
+
+impl batuta_common::display::WithDimensions for Timeline {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn sample() -> Timeline {
+        Timeline::new()
+            .interval("job", 0.0, 40.0, "train")
+            .interval("job", 40.0, 50.0, "eval")
+            .event("alerts", 12.0, "warn")
+            .event("job", 25.0, "checkpoint")
+    }
+
+    #[test]
+    fn test_timeline_builder() {
+        let timeline = sample().build().expect("builder should produce valid result");
+        assert_eq!(timeline.lane_names(), vec!["job", "alerts"]);
+        assert_eq!(timeline.kinds(), vec!["train", "eval", "warn", "checkpoint"]);
+        assert_eq!(timeline.time_extent(), (0.0, 50.0));
+    }
+
+    #[test]
+    fn test_timeline_lane_order() {
+        let timeline = sample().lanes(&["alerts", "gpu"]);
+        assert_eq!(timeline.lane_names(), vec!["alerts", "gpu", "job"]);
+    }
+
+    #[test]
+    fn test_timeline_validation() {
+        assert!(Timeline::new().build().is_err());
+        assert!(sample().interval("job", 5.0, 1.0, "bad").build().is_err());
+        assert!(sample().event("job", f32::NAN, "bad").build().is_err());
+        assert!(sample().time_range(10.0, 10.0).build().is_err());
+    }
+
+    #[test]
+    fn test_timeline_colors() {
+        let timeline = sample().kind_color("eval", Rgba::RED);
+        assert_eq!(timeline.color_for("eval"), Rgba::RED);
+        assert_eq!(timeline.color_for("train"), categorical(0));
+        assert_eq!(timeline.color_for("warn"), categorical(2));
+    }
+
+    #[test]
+    fn test_timeline_single_instant_extent() {
+        let timeline = Timeline::new().event("a", 3.0, "x");
+        assert_eq!(timeline.time_extent(), (2.5, 3.5));
+        assert!(TimelineEvent::instant("a", 3.0, "x").is_instant());
+    }
+
+    #[test]
+    fn test_timeline_render() {
+        let timeline = sample()
+            .kind_color("train", Rgba::RED)
+            .kind_color("warn", Rgba::BLUE)
+            .dimensions(300, 120)
+            .build()
+            .expect("builder should produce valid result");
+        let fb = timeline.to_framebuffer().expect("rendering should succeed");
+        let has = |c: Rgba| {
+            (0..fb.height()).any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(c)))
+        };
+        assert!(has(Rgba::RED));
+        assert!(has(Rgba::BLUE));
+    }
+
+    #[test]
+    fn test_timeline_no_axes() {
+        let timeline = sample().axes(false).time_range(10.0, 30.0).dimensions(200, 80);
+        assert!(timeline.to_framebuffer().is_ok());
+    }
+}
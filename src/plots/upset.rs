@@ -0,0 +1,385 @@
+//! UpSet plot for set intersections.
+//!
+//! Shows the sizes of exclusive set intersections as a bar chart above a
+//! membership matrix (one row per set, one column per intersection, filled
+//! dots joined by a line for the participating sets). Set sizes are drawn as
+//! horizontal bars to the left of the matrix. Unlike Venn diagrams this scales
+//! to many sets, which makes it useful for comparing feature sets or
+//! train/validation/test splits.
+//!
+//! # References
+//!
+//! - Lex, A., Gehlenborg, N., Strobelt, H., Vuillemot, R., & Pfister, H.
+//!   (2014). "UpSet: Visualization of Intersecting Sets". *IEEE Transactions
+//!   on Visualization and Computer Graphics*, 20(12), 1983-1992.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_circle, i32_px, TextStyle};
+use crate::scale::{BandScale, Scale};
+
+/// Maximum number of sets (membership is tracked in a `u64` bitmask).
+const MAX_SETS: usize = 64;
+
+/// Ordering of intersection columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UpSetOrder {
+    /// Largest intersections first (default).
+    #[default]
+    Size,
+    /// Fewest participating sets first, then by size.
+    Degree,
+}
+
+/// An exclusive intersection: elements in exactly these sets and no others.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpSetIntersection {
+    /// Indices of participating sets, ascending.
+    pub sets: Vec<usize>,
+    /// Number of elements.
+    pub size: usize,
+}
+
+/// Builder for UpSet plots.
+#[derive(Debug, Clone)]
+pub struct UpSet {
+    /// Set names in insertion order.
+    names: Vec<String>,
+    /// Set members.
+    members: Vec<Vec<String>>,
+    /// Column ordering.
+    order: UpSetOrder,
+    /// Show at most this many intersections.
+    max_intersections: Option<usize>,
+    /// Hide intersections smaller than this.
+    min_size: usize,
+    /// Bar and filled-dot color.
+    color: Rgba,
+    /// Empty-dot color.
+    inactive_color: Rgba,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the plot.
+    margin: u32,
+}
+
+impl Default for UpSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UpSet {
+    /// Create a new UpSet builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(800, 500);
+        Self {
+            names: Vec::new(),
+            members: Vec::new(),
+            order: UpSetOrder::default(),
+            max_intersections: None,
+            min_size: 1,
+            color: Rgba::rgb(50, 50, 50),
+            inactive_color: Rgba::rgb(220, 220, 220),
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Add a named set. Duplicate members are counted once.
+    #[must_use]
+    pub fn set(mut self, name: &str, members: &[impl AsRef<str>]) -> Self {
+        self.names.push(name.to_string());
+        self.members.push(members.iter().map(|m| m.as_ref().to_string()).collect());
+        self
+    }
+
+    /// Set the intersection ordering.
+    #[must_use]
+    pub fn order(mut self, order: UpSetOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Show at most `n` intersections.
+    #[must_use]
+    pub fn max_intersections(mut self, n: usize) -> Self {
+        self.max_intersections = Some(n);
+        self
+    }
+
+    /// Hide intersections with fewer than `n` elements (default: 1).
+    #[must_use]
+    pub fn min_size(mut self, n: usize) -> Self {
+        self.min_size = n;
+        self
+    }
+
+    /// Set the bar and active-dot color.
+    #[must_use]
+    pub fn color(mut self, color: Rgba) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the margin around the plot.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the plot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no sets or more than 64 sets.
+    pub fn build(self) -> Result<Self> {
+        if self.names.is_empty() {
+            return Err(Error::EmptyData);
+        }
+        if self.names.len() > MAX_SETS {
+            return Err(Error::Rendering(format!(
+                "UpSet supports at most {MAX_SETS} sets, got {}",
+                self.names.len()
+            )));
+        }
+        Ok(self)
+    }
+
+    /// Number of sets.
+    #[must_use]
+    pub fn set_count(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Number of distinct members in each set.
+    #[must_use]
+    pub fn set_sizes(&self) -> Vec<usize> {
+        self.members
+            .iter()
+            .map(|m| {
+                let mut unique: Vec<&String> = m.iter().collect();
+                unique.sort();
+                unique.dedup();
+                unique.len()
+            })
+            .collect()
+    }
+
+    /// Exclusive intersections after filtering and ordering.
+    #[must_use]
+    pub fn intersections(&self) -> Vec<UpSetIntersection> {
+        let mut membership: HashMap<&str, u64> = HashMap::new();
+        for (i, members) in self.members.iter().enumerate().take(MAX_SETS) {
+            for m in members {
+                *membership.entry(m.as_str()).or_insert(0) |= 1 << i;
+            }
+        }
+
+        let mut counts: BTreeMap<u64, usize> = BTreeMap::new();
+        for mask in membership.into_values() {
+            *counts.entry(mask).or_insert(0) += 1;
+        }
+
+        let mut out: Vec<UpSetIntersection> = counts
+            .into_iter()
+            .filter(|&(_, size)| size >= self.min_size)
+            .map(|(mask, size)| UpSetIntersection {
+                sets: (0..self.names.len()).filter(|&i| mask & (1 << i) != 0).collect(),
+                size,
+            })
+            .collect();
+
+        match self.order {
+            UpSetOrder::Size => out.sort_by(|a, b| b.size.cmp(&a.size).then(a.sets.cmp(&b.sets))),
+            UpSetOrder::Degree => out.sort_by(|a, b| {
+                a.sets.len().cmp(&b.sets.len()).then(b.size.cmp(&a.size)).then(a.sets.cmp(&b.sets))
+            }),
+        }
+
+        if let Some(n) = self.max_intersections {
+            out.truncate(n);
+        }
+        out
+    }
+
+    /// Render the plot to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no sets.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let intersections = self.intersections();
+        let set_sizes = self.set_sizes();
+        let font = TextStyle::current();
+
+        // Layout: [set-size bars | names | matrix], bars above the matrix.
+        let label_w = self.names.iter().map(|n| font.width(n)).max().unwrap_or(0) + 8;
+        let left = self.margin as f32;
+        let right = self.width.saturating_sub(self.margin) as f32;
+        let top = (self.margin + font.height() + 2) as f32;
+        let bottom = self.height.saturating_sub(self.margin) as f32;
+
+        let set_bar_w = ((right - left) * 0.2).max(10.0);
+        let matrix_left = left + set_bar_w + label_w as f32;
+        let row_h = ((bottom - top) * 0.45 / self.names.len() as f32).clamp(6.0, 24.0);
+        let matrix_top = bottom - row_h * self.names.len() as f32;
+        let bars_bottom = matrix_top - 4.0;
+
+        let column_keys: Vec<String> = (0..intersections.len()).map(|i| i.to_string()).collect();
+        if column_keys.is_empty() {
+            return Ok(());
+        }
+        let cols = BandScale::new(&column_keys, (matrix_left, right))?.padding(0.3);
+        let rows = BandScale::new(&self.names, (matrix_top, bottom))?;
+        let dot_r = (cols.bandwidth().min(rows.bandwidth()) * 0.35).max(2.0) as i32;
+
+        // Intersection size bars with count labels.
+        let max_size = intersections.iter().map(|s| s.size).max().unwrap_or(1).max(1) as f32;
+        let bar_space = (bars_bottom - top).max(1.0);
+        for (i, inter) in intersections.iter().enumerate() {
+            let h = inter.size as f32 / max_size * bar_space;
+            let x = cols.scale(i);
+            fb.fill_rect(
+                x as u32,
+                (bars_bottom - h) as u32,
+                cols.bandwidth().max(1.0) as u32,
+                h as u32,
+                self.color,
+            );
+            let label = inter.size.to_string();
+            let lx = cols.center(i) as i32 - i32_px(font.width(&label)) / 2;
+            let ly = (bars_bottom - h) as i32 - i32_px(font.height()) - 2;
+            font.draw(fb, lx, ly, &label);
+        }
+
+        // Set rows: zebra stripe, size bar (growing leftwards), name.
+        let max_set = set_sizes.iter().copied().max().unwrap_or(1).max(1) as f32;
+        for (r, name) in self.names.iter().enumerate() {
+            let y = rows.scale(r);
+            if r % 2 == 0 {
+                fb.fill_rect(
+                    matrix_left as u32,
+                    y as u32,
+                    (right - matrix_left) as u32,
+                    rows.bandwidth() as u32,
+                    Rgba::rgb(245, 245, 245),
+                );
+            }
+
+            let w = set_sizes[r] as f32 / max_set * (set_bar_w - 4.0);
+            let bar_h = (rows.bandwidth() * 0.6).max(1.0);
+            let bar_y = rows.center(r) - bar_h / 2.0;
+            fb.fill_rect(
+                (left + set_bar_w - 4.0 - w) as u32,
+                bar_y as u32,
+                w as u32,
+                bar_h as u32,
+                self.color,
+            );
+
+            let ty = rows.center(r) as i32 - i32_px(font.height()) / 2;
+            font.draw(fb, (left + set_bar_w) as i32, ty, name);
+        }
+
+        // Membership matrix.
+        for (i, inter) in intersections.iter().enumerate() {
+            let cx = cols.center(i) as i32;
+            for r in 0..self.names.len() {
+                let color = if inter.sets.contains(&r) { self.color } else { self.inactive_color };
+                draw_circle(fb, cx, rows.center(r) as i32, dot_r, color);
+            }
+            if let (Some(&first), Some(&last)) = (inter.sets.first(), inter.sets.last()) {
+                let y0 = rows.center(first);
+                let y1 = rows.center(last);
+                fb.fill_rect((cx - 1).max(0) as u32, y0 as u32, 3, (y1 - y0) as u32, self.color);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+impl batuta_common::display::WithDimensions for UpSet {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn features() -> UpSet {
+        UpSet::new()
+            .set("A", &["x1", "x2", "x3", "x4"])
+            .set("B", &["x3", "x4", "x5"])
+            .set("C", &["x4", "x6", "x6"])
+    }
+
+    #[test]
+    fn test_upset_set_sizes() {
+        assert_eq!(features().set_sizes(), vec![4, 3, 2]);
+    }
+
+    #[test]
+    fn test_upset_exclusive_intersections() {
+        let inter = features().intersections();
+        // A only: x1, x2 | A∩B only: x3 | A∩B∩C: x4 | B only: x5 | C only: x6
+        assert_eq!(inter[0], UpSetIntersection { sets: vec![0], size: 2 });
+        let total: usize = inter.iter().map(|i| i.size).sum();
+        assert_eq!(total, 6);
+        assert!(inter.contains(&UpSetIntersection { sets: vec![0, 1, 2], size: 1 }));
+    }
+
+    #[test]
+    fn test_upset_order_and_filter() {
+        let by_degree = features().order(UpSetOrder::Degree).intersections();
+        assert!(by_degree.windows(2).all(|w| w[0].sets.len() <= w[1].sets.len()));
+
+        assert_eq!(features().min_size(2).intersections().len(), 1);
+        assert_eq!(features().max_intersections(3).intersections().len(), 3);
+    }
+
+    #[test]
+    fn test_upset_validation() {
+        assert!(UpSet::new().build().is_err());
+        let many = (0..65).fold(UpSet::new(), |u, i| u.set(&format!("s{i}"), &["a"]));
+        assert!(many.build().is_err());
+    }
+
+    #[test]
+    fn test_upset_render() {
+        let plot = features()
+            .color(Rgba::RED)
+            .dimensions(300, 200)
+            .build()
+            .expect("builder should produce valid result");
+        let fb = plot.to_framebuffer().expect("rendering should succeed");
+        let red = (0..fb.height())
+            .any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(Rgba::RED)));
+        assert!(red);
+    }
+}
@@ -0,0 +1,413 @@
+//! Venn / Euler diagrams for two or three sets.
+//!
+//! In proportional (Euler) mode circle areas match the set sizes and the
+//! centre distances are solved so that each pairwise overlap matches the
+//! size of the pairwise intersection. Three-set layouts place the third
+//! circle by triangulation from the two pairwise distances, which is exact
+//! for pairwise overlaps but only approximate for the triple overlap. Each
+//! non-empty region is labelled with its exclusive element count.
+//!
+//! # References
+//!
+//! - Venn, J. (1880). "On the Diagrammatic and Mechanical Representation of
+//!   Propositions and Reasonings". *Philosophical Magazine*, 10(59), 1-18.
+//! - Chow, S., & Ruskey, F. (2004). "Drawing Area-Proportional Venn and Euler
+//!   Diagrams". *Graph Drawing*, LNCS 2912, 466-477.
+
+use std::collections::HashMap;
+use std::f32::consts::PI;
+
+use crate::color::{categorical, Rgba};
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::render::{draw_circle_outline, i32_px, TextStyle};
+
+/// Bisection iterations when solving circle distances.
+const SOLVE_ITERATIONS: usize = 48;
+
+/// A circle in layout (unit) coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VennCircle {
+    /// Centre x.
+    pub x: f32,
+    /// Centre y.
+    pub y: f32,
+    /// Radius.
+    pub r: f32,
+}
+
+/// Area of the lens where two circles with radii `r1`, `r2` at distance `d`
+/// overlap.
+#[must_use]
+pub fn circle_overlap_area(r1: f32, r2: f32, d: f32) -> f32 {
+    if d >= r1 + r2 {
+        return 0.0;
+    }
+    if d <= (r1 - r2).abs() {
+        let r = r1.min(r2);
+        return PI * r * r;
+    }
+    let a1 = ((d * d + r1 * r1 - r2 * r2) / (2.0 * d * r1)).clamp(-1.0, 1.0).acos();
+    let a2 = ((d * d + r2 * r2 - r1 * r1) / (2.0 * d * r2)).clamp(-1.0, 1.0).acos();
+    let k = (-d + r1 + r2) * (d + r1 - r2) * (d - r1 + r2) * (d + r1 + r2);
+    r1 * r1 * a1 + r2 * r2 * a2 - 0.5 * k.max(0.0).sqrt()
+}
+
+/// Centre distance at which two circles overlap by `area` (bisection).
+fn distance_for_overlap(r1: f32, r2: f32, area: f32) -> f32 {
+    let (mut lo, mut hi) = ((r1 - r2).abs(), r1 + r2);
+    if area <= 0.0 {
+        // Leave a small visual gap between disjoint sets.
+        return hi * 1.05;
+    }
+    for _ in 0..SOLVE_ITERATIONS {
+        let mid = 0.5 * (lo + hi);
+        if circle_overlap_area(r1, r2, mid) > area {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+/// Number of elements in every set selected by `mask`, from exclusive counts.
+fn inclusive_count(counts: &HashMap<u8, usize>, mask: u8) -> usize {
+    counts.iter().filter(|(&m, _)| m & mask == mask).map(|(_, &c)| c).sum()
+}
+
+/// Builder for Venn / Euler diagrams.
+#[derive(Debug, Clone)]
+pub struct Venn {
+    /// Set names.
+    names: Vec<String>,
+    /// Set members.
+    members: Vec<Vec<String>>,
+    /// Area-proportional layout (Euler) vs. classic symmetric layout.
+    proportional: bool,
+    /// Optional per-set colors (defaults to the categorical palette).
+    colors: Vec<Option<Rgba>>,
+    /// Fill opacity (0.0 - 1.0).
+    fill_alpha: f32,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the plot.
+    margin: u32,
+}
+
+impl Default for Venn {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Venn {
+    /// Create a new Venn diagram builder.
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(500, 400);
+        Self {
+            names: Vec::new(),
+            members: Vec::new(),
+            proportional: true,
+            colors: Vec::new(),
+            fill_alpha: 0.35,
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Add a named set. Duplicate members are counted once.
+    #[must_use]
+    pub fn set(mut self, name: &str, members: &[impl AsRef<str>]) -> Self {
+        self.names.push(name.to_string());
+        self.members.push(members.iter().map(|m| m.as_ref().to_string()).collect());
+        self.colors.push(None);
+        self
+    }
+
+    /// Add a named set with an explicit color.
+    #[must_use]
+    pub fn set_colored(mut self, name: &str, members: &[impl AsRef<str>], color: Rgba) -> Self {
+        self = self.set(name, members);
+        if let Some(last) = self.colors.last_mut() {
+            *last = Some(color);
+        }
+        self
+    }
+
+    /// Use an area-proportional (Euler) layout (default: true).
+    #[must_use]
+    pub fn proportional(mut self, proportional: bool) -> Self {
+        self.proportional = proportional;
+        self
+    }
+
+    /// Set the circle fill opacity (0.0 - 1.0).
+    #[must_use]
+    pub fn fill_alpha(mut self, alpha: f32) -> Self {
+        self.fill_alpha = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the margin around the plot.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the diagram.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error unless there are two or three sets, each non-empty.
+    pub fn build(self) -> Result<Self> {
+        if !(2..=3).contains(&self.names.len()) {
+            return Err(Error::Rendering(format!(
+                "Venn diagrams need 2 or 3 sets, got {}",
+                self.names.len()
+            )));
+        }
+        if self.members.iter().any(Vec::is_empty) {
+            return Err(Error::EmptyData);
+        }
+        Ok(self)
+    }
+
+    /// Exclusive region counts keyed by membership bitmask (bit `i` = set `i`).
+    #[must_use]
+    pub fn region_counts(&self) -> HashMap<u8, usize> {
+        let mut membership: HashMap<&str, u8> = HashMap::new();
+        for (i, members) in self.members.iter().enumerate().take(3) {
+            for m in members {
+                *membership.entry(m.as_str()).or_insert(0) |= 1 << i;
+            }
+        }
+        let mut counts = HashMap::new();
+        for mask in membership.into_values() {
+            *counts.entry(mask).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Circle layout in unit coordinates.
+    #[must_use]
+    pub fn layout(&self) -> Vec<VennCircle> {
+        let n = self.names.len().min(3);
+        if !self.proportional {
+            let positions: &[(f32, f32)] = if n == 2 {
+                &[(-0.5, 0.0), (0.5, 0.0)]
+            } else {
+                &[(-0.5, -0.35), (0.5, -0.35), (0.0, 0.5)]
+            };
+            return positions.iter().map(|&(x, y)| VennCircle { x, y, r: 0.8 }).collect();
+        }
+
+        let counts = self.region_counts();
+        let radius = |i: usize| (inclusive_count(&counts, 1 << i) as f32 / PI).sqrt();
+        let distance = |i: usize, j: usize| {
+            let overlap = inclusive_count(&counts, (1 << i) | (1 << j)) as f32;
+            distance_for_overlap(radius(i), radius(j), overlap)
+        };
+
+        let d01 = distance(0, 1);
+        let mut circles = vec![
+            VennCircle { x: 0.0, y: 0.0, r: radius(0) },
+            VennCircle { x: d01, y: 0.0, r: radius(1) },
+        ];
+
+        if n == 3 {
+            let (d02, d12) = (distance(0, 2), distance(1, 2));
+            // Law of cosines; clamp when the three distances can't form a triangle.
+            let x = if d01 > 0.0 { (d02 * d02 - d12 * d12 + d01 * d01) / (2.0 * d01) } else { 0.0 };
+            let y = (d02 * d02 - x * x).max(0.0).sqrt();
+            circles.push(VennCircle { x, y, r: radius(2) });
+        }
+        circles
+    }
+
+    fn set_color(&self, i: usize) -> Rgba {
+        self.colors.get(i).copied().flatten().unwrap_or_else(|| categorical(i))
+    }
+
+    /// Render the diagram to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the layout is degenerate.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let circles = self.layout();
+        if circles.is_empty() {
+            return Err(Error::EmptyData);
+        }
+
+        // Fit the layout into the plot box, leaving room for set names.
+        let font = TextStyle::current();
+        let label_h = font.height() as f32 + 4.0;
+        let (min_x, max_x, min_y, max_y) = circles.iter().fold(
+            (f32::INFINITY, f32::NEG_INFINITY, f32::INFINITY, f32::NEG_INFINITY),
+            |(a, b, c, d), k| {
+                (a.min(k.x - k.r), b.max(k.x + k.r), c.min(k.y - k.r), d.max(k.y + k.r))
+            },
+        );
+        let avail_w = (self.width.saturating_sub(2 * self.margin) as f32).max(1.0);
+        let avail_h = (self.height.saturating_sub(2 * self.margin) as f32 - 2.0 * label_h).max(1.0);
+        let scale = (avail_w / (max_x - min_x)).min(avail_h / (max_y - min_y));
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(Error::Rendering("Degenerate Venn layout".to_string()));
+        }
+        let ox = self.margin as f32 + (avail_w - (max_x - min_x) * scale) / 2.0 - min_x * scale;
+        let oy = self.margin as f32 + label_h + (avail_h - (max_y - min_y) * scale) / 2.0
+            - min_y * scale;
+        let px: Vec<VennCircle> = circles
+            .iter()
+            .map(|c| VennCircle { x: ox + c.x * scale, y: oy + c.y * scale, r: c.r * scale })
+            .collect();
+
+        // Fill pass: blend each set's color and accumulate region centroids.
+        let alpha = (self.fill_alpha * 255.0) as u8;
+        let mut centroids: HashMap<u8, (f32, f32, f32)> = HashMap::new();
+        for y in 0..fb.height() {
+            for x in 0..fb.width() {
+                let (fx, fy) = (x as f32 + 0.5, y as f32 + 0.5);
+                let mut mask = 0_u8;
+                for (i, c) in px.iter().enumerate() {
+                    if (fx - c.x).powi(2) + (fy - c.y).powi(2) <= c.r * c.r {
+                        mask |= 1 << i;
+                        fb.blend_pixel(x, y, self.set_color(i).with_alpha(alpha));
+                    }
+                }
+                if mask != 0 {
+                    let e = centroids.entry(mask).or_insert((0.0, 0.0, 0.0));
+                    *e = (e.0 + fx, e.1 + fy, e.2 + 1.0);
+                }
+            }
+        }
+
+        for (i, c) in px.iter().enumerate() {
+            let color = self.set_color(i);
+            draw_circle_outline(fb, c.x as i32, c.y as i32, c.r as i32, color);
+
+            // Name above the circle (below for the lower circle of a triple).
+            let name = &self.names[i];
+            let below = px.len() == 3 && i == 2 && c.y > px[0].y;
+            let ny = if below { c.y + c.r + 3.0 } else { c.y - c.r - label_h };
+            let nx = c.x as i32 - i32_px(font.width(name)) / 2;
+            font.with_color(color).draw(fb, nx, ny as i32, name);
+        }
+
+        for (mask, count) in self.region_counts() {
+            let Some(&(sx, sy, n)) = centroids.get(&mask) else { continue };
+            let label = count.to_string();
+            let lx = (sx / n) as i32 - i32_px(font.width(&label)) / 2;
+            let ly = (sy / n) as i32 - i32_px(font.height()) / 2;
+            font.draw(fb, lx, ly, &label);
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+}
+
+impl batuta_common::display::WithDimensions for Venn {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    fn two() -> Venn {
+        Venn::new().set("train", &["a", "b", "c", "d"]).set("test", &["c", "d", "e"])
+    }
+
+    #[test]
+    fn test_overlap_area_limits() {
+        assert!(circle_overlap_area(1.0, 1.0, 3.0).abs() < f32::EPSILON);
+        assert!((circle_overlap_area(1.0, 2.0, 0.5) - PI).abs() < 1e-5);
+        let half = circle_overlap_area(1.0, 1.0, 1.0);
+        assert!(half > 0.0 && half < PI);
+    }
+
+    #[test]
+    fn test_distance_solves_overlap() {
+        let d = distance_for_overlap(1.0, 1.5, 1.2);
+        assert!((circle_overlap_area(1.0, 1.5, d) - 1.2).abs() < 1e-3);
+        assert!(distance_for_overlap(1.0, 1.0, 0.0) > 2.0);
+    }
+
+    #[test]
+    fn test_venn_region_counts() {
+        let counts = two().region_counts();
+        assert_eq!(counts.get(&0b01), Some(&2));
+        assert_eq!(counts.get(&0b11), Some(&2));
+        assert_eq!(counts.get(&0b10), Some(&1));
+    }
+
+    #[test]
+    fn test_venn_proportional_layout() {
+        let venn = two();
+        let circles = venn.layout();
+        // Areas match set sizes (4 and 3 elements).
+        assert!((PI * circles[0].r.powi(2) - 4.0).abs() < 1e-4);
+        assert!((PI * circles[1].r.powi(2) - 3.0).abs() < 1e-4);
+        let d = circles[1].x - circles[0].x;
+        assert!((circle_overlap_area(circles[0].r, circles[1].r, d) - 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_venn_three_sets() {
+        let venn = two()
+            .set("val", &["a", "e", "f"])
+            .build()
+            .expect("builder should produce valid result");
+        let circles = venn.layout();
+        assert_eq!(circles.len(), 3);
+        assert!(circles[2].y > 0.0);
+        assert_eq!(venn.region_counts().values().sum::<usize>(), 6);
+    }
+
+    #[test]
+    fn test_venn_validation() {
+        assert!(Venn::new().set("a", &["x"]).build().is_err());
+        assert!(Venn::new().set("a", &["x"]).set("b", &[] as &[&str]).build().is_err());
+        let four = two().set("c", &["x"]).set("d", &["y"]);
+        assert!(four.build().is_err());
+    }
+
+    #[test]
+    fn test_venn_render() {
+        for proportional in [true, false] {
+            let venn = two()
+                .set_colored("val", &["a", "z"], Rgba::RED)
+                .proportional(proportional)
+                .fill_alpha(1.0)
+                .dimensions(200, 160)
+                .build()
+                .expect("builder should produce valid result");
+            let fb = venn.to_framebuffer().expect("rendering should succeed");
+            let red = (0..fb.height())
+                .any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(Rgba::RED)));
+            assert!(red);
+        }
+    }
+}
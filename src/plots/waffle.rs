@@ -0,0 +1,496 @@
+//! Waffle (pictogram) chart for part-to-whole proportions.
+//!
+//! Represents each category as a count of unit cells in a fixed grid
+//! (10×10 by default, so one cell ≈ 1%). Cells are squares, circles, or a
+//! custom icon outline. Easier to read than a pie chart because counting and
+//! comparing areas of equal units is more accurate than comparing angles.
+//!
+//! # References
+//!
+//! - Cleveland, W. S., & McGill, R. (1984). "Graphical Perception: Theory,
+//!   Experimentation, and Application to the Development of Graphical Methods."
+//!   *Journal of the American Statistical Association*, 79(387), 531-554.
+
+use std::fmt::Write as FmtWrite;
+
+use crate::color::{categorical, Rgba};
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+use crate::output::SvgEncoder;
+use crate::render::{draw_circle, i32_px};
+
+/// How fractional cell counts are turned into whole cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WaffleRounding {
+    /// Largest-remainder (Hamilton) method; counts always sum to the grid size.
+    #[default]
+    LargestRemainder,
+    /// Round each category independently (may over- or under-fill the grid).
+    Nearest,
+    /// Floor each category (never over-fills; leftover cells stay empty).
+    Floor,
+}
+
+/// Shape drawn for each unit cell.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum WaffleShape {
+    /// Filled square.
+    #[default]
+    Square,
+    /// Filled circle.
+    Circle,
+    /// Custom icon as a closed outline in unit-square coordinates (0.0-1.0).
+    Path(Vec<(f32, f32)>),
+}
+
+/// A category in a waffle chart.
+#[derive(Debug, Clone)]
+pub struct WaffleCategory {
+    /// Category name.
+    pub name: String,
+    /// Category value (any non-negative magnitude).
+    pub value: f32,
+    /// Cell color (None = categorical palette).
+    pub color: Option<Rgba>,
+}
+
+/// Builder for creating waffle charts.
+#[derive(Debug, Clone)]
+pub struct WaffleChart {
+    /// Categories in fill order.
+    categories: Vec<WaffleCategory>,
+    /// Grid rows.
+    rows: usize,
+    /// Grid columns.
+    cols: usize,
+    /// Rounding policy.
+    rounding: WaffleRounding,
+    /// Cell shape.
+    shape: WaffleShape,
+    /// Color of unassigned cells.
+    empty_color: Rgba,
+    /// Gap between cells in pixels.
+    gap: u32,
+    /// Output width in pixels.
+    width: u32,
+    /// Output height in pixels.
+    height: u32,
+    /// Margin around the grid.
+    margin: u32,
+}
+
+impl Default for WaffleChart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WaffleChart {
+    /// Create a new waffle chart builder (10×10 grid).
+    #[must_use]
+    pub fn new() -> Self {
+        let (width, height) = crate::style::current().scaled_size(400, 400);
+        Self {
+            categories: Vec::new(),
+            rows: 10,
+            cols: 10,
+            rounding: WaffleRounding::default(),
+            shape: WaffleShape::default(),
+            empty_color: Rgba::rgb(235, 235, 235),
+            gap: 2,
+            width,
+            height,
+            margin: 10,
+        }
+    }
+
+    /// Add a category.
+    #[must_use]
+    pub fn category(mut self, name: impl Into<String>, value: f32) -> Self {
+        self.categories.push(WaffleCategory { name: name.into(), value, color: None });
+        self
+    }
+
+    /// Add a category with an explicit color.
+    #[must_use]
+    pub fn category_colored(mut self, name: impl Into<String>, value: f32, color: Rgba) -> Self {
+        self.categories.push(WaffleCategory { name: name.into(), value, color: Some(color) });
+        self
+    }
+
+    /// Set the grid size.
+    #[must_use]
+    pub fn grid(mut self, rows: usize, cols: usize) -> Self {
+        self.rows = rows;
+        self.cols = cols;
+        self
+    }
+
+    /// Set the rounding policy.
+    #[must_use]
+    pub fn rounding(mut self, rounding: WaffleRounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Set the cell shape.
+    #[must_use]
+    pub fn shape(mut self, shape: WaffleShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Set the color of unassigned cells.
+    #[must_use]
+    pub fn empty_color(mut self, color: Rgba) -> Self {
+        self.empty_color = color;
+        self
+    }
+
+    /// Set the gap between cells.
+    #[must_use]
+    pub fn gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the margin around the grid.
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Build and validate the waffle chart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no categories, the grid is empty, any
+    /// value is negative or non-finite, or all values are zero.
+    pub fn build(self) -> Result<Self> {
+        if self.categories.is_empty() {
+            return Err(Error::EmptyData);
+        }
+
+        if self.rows == 0 || self.cols == 0 {
+            return Err(Error::InvalidDimensions {
+                width: self.cols as u32,
+                height: self.rows as u32,
+            });
+        }
+
+        if let Some(bad) = self.categories.iter().find(|c| !c.value.is_finite() || c.value < 0.0) {
+            return Err(Error::ScaleDomain(format!(
+                "waffle value for '{}' must be finite and non-negative, got {}",
+                bad.name, bad.value
+            )));
+        }
+
+        if self.categories.iter().all(|c| c.value <= 0.0) {
+            return Err(Error::EmptyData);
+        }
+
+        Ok(self)
+    }
+
+    /// Get the total number of cells in the grid.
+    #[must_use]
+    pub fn cell_count(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    /// Compute whole-cell counts per category according to the rounding policy.
+    ///
+    /// The sum never exceeds [`Self::cell_count`]; with `Nearest` rounding
+    /// overflow is trimmed from the last categories.
+    #[must_use]
+    pub fn cell_counts(&self) -> Vec<usize> {
+        let total_cells = self.cell_count();
+        let total: f32 = self.categories.iter().map(|c| c.value.max(0.0)).sum();
+        if total <= 0.0 || total_cells == 0 {
+            return vec![0; self.categories.len()];
+        }
+
+        let exact: Vec<f32> =
+            self.categories.iter().map(|c| c.value.max(0.0) / total * total_cells as f32).collect();
+
+        let mut counts: Vec<usize> = match self.rounding {
+            WaffleRounding::Floor | WaffleRounding::LargestRemainder => {
+                exact.iter().map(|e| e.floor() as usize).collect()
+            }
+            WaffleRounding::Nearest => exact.iter().map(|e| e.round() as usize).collect(),
+        };
+
+        if self.rounding == WaffleRounding::LargestRemainder {
+            let assigned: usize = counts.iter().sum();
+            let mut order: Vec<usize> = (0..exact.len()).collect();
+            // Stable sort keeps earlier categories first on equal remainders
+            order.sort_by(|&a, &b| {
+                (exact[b] - exact[b].floor()).total_cmp(&(exact[a] - exact[a].floor()))
+            });
+            for &i in order.iter().take(total_cells.saturating_sub(assigned)) {
+                counts[i] += 1;
+            }
+        }
+
+        // Trim any overflow from the end
+        let mut excess = counts.iter().sum::<usize>().saturating_sub(total_cells);
+        for count in counts.iter_mut().rev() {
+            let take = excess.min(*count);
+            *count -= take;
+            excess -= take;
+        }
+
+        counts
+    }
+
+    /// Color assigned to each cell in row-major fill order.
+    fn cell_colors(&self) -> Vec<Rgba> {
+        let mut colors = Vec::with_capacity(self.cell_count());
+        for (i, (cat, count)) in self.categories.iter().zip(self.cell_counts()).enumerate() {
+            let color = cat.color.unwrap_or_else(|| categorical(i));
+            colors.extend(std::iter::repeat(color).take(count));
+        }
+        colors.resize(self.cell_count(), self.empty_color);
+        colors
+    }
+
+    /// Cell pitch and grid origin for the current dimensions.
+    fn layout(&self) -> (f32, f32, f32) {
+        let avail_w = self.width.saturating_sub(2 * self.margin) as f32;
+        let avail_h = self.height.saturating_sub(2 * self.margin) as f32;
+        let pitch = (avail_w / self.cols.max(1) as f32).min(avail_h / self.rows.max(1) as f32);
+        let x0 = self.margin as f32 + (avail_w - pitch * self.cols as f32) / 2.0;
+        let y0 = self.margin as f32 + (avail_h - pitch * self.rows as f32) / 2.0;
+        (pitch, x0, y0)
+    }
+
+    /// Render the waffle chart to a framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn render(&self, fb: &mut Framebuffer) -> Result<()> {
+        let (pitch, x0, y0) = self.layout();
+        let size = (pitch - self.gap as f32).max(1.0);
+
+        for (idx, color) in self.cell_colors().into_iter().enumerate() {
+            let x = x0 + (idx % self.cols) as f32 * pitch;
+            let y = y0 + (idx / self.cols) as f32 * pitch;
+
+            match &self.shape {
+                WaffleShape::Square => {
+                    fb.fill_rect(x as u32, y as u32, size as u32, size as u32, color);
+                }
+                WaffleShape::Circle => {
+                    let r = size / 2.0;
+                    draw_circle(fb, (x + r) as i32, (y + r) as i32, r as i32, color);
+                }
+                WaffleShape::Path(outline) => {
+                    fill_unit_polygon(fb, outline, x, y, size, color);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render to a new framebuffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rendering fails.
+    pub fn to_framebuffer(&self) -> Result<Framebuffer> {
+        let mut fb = Framebuffer::new(self.width, self.height)?;
+        fb.clear(Rgba::WHITE);
+        self.render(&mut fb)?;
+        Ok(fb)
+    }
+
+    /// Render as vector SVG.
+    #[must_use]
+    pub fn to_svg(&self) -> SvgEncoder {
+        let (pitch, x0, y0) = self.layout();
+        let size = (pitch - self.gap as f32).max(1.0);
+        let mut svg = SvgEncoder::new(self.width, self.height);
+
+        for (idx, color) in self.cell_colors().into_iter().enumerate() {
+            let x = x0 + (idx % self.cols) as f32 * pitch;
+            let y = y0 + (idx / self.cols) as f32 * pitch;
+
+            svg = match &self.shape {
+                WaffleShape::Square => svg.rect(x, y, size, size, color),
+                WaffleShape::Circle => {
+                    svg.circle(x + size / 2.0, y + size / 2.0, size / 2.0, color)
+                }
+                WaffleShape::Path(outline) => {
+                    let mut d = String::new();
+                    for (i, &(px, py)) in outline.iter().enumerate() {
+                        let cmd = if i == 0 { 'M' } else { 'L' };
+                        let _ = write!(d, "{cmd}{} {} ", x + px * size, y + py * size);
+                    }
+                    d.push('Z');
+                    svg.path(&d, Some(color), None, 0.0)
+                }
+            };
+        }
+        svg
+    }
+}
+
+/// Fill a polygon given in unit-square coordinates, scaled into a `size` box at (`x`, `y`).
+///
+/// Uses an even-odd point-in-polygon test at each pixel center.
+fn fill_unit_polygon(
+    fb: &mut Framebuffer,
+    outline: &[(f32, f32)],
+    x: f32,
+    y: f32,
+    size: f32,
+    c: Rgba,
+) {
+    if outline.len() < 3 {
+        return;
+    }
+
+    let x_start = x.floor().max(0.0) as i32;
+    let y_start = y.floor().max(0.0) as i32;
+    let extent = size.ceil() as i32;
+
+    for py in y_start..y_start + extent {
+        for px in x_start..x_start + extent {
+            let u = (px as f32 + 0.5 - x) / size;
+            let v = (py as f32 + 0.5 - y) / size;
+            if point_in_polygon(outline, u, v)
+                && px < i32_px(fb.width())
+                && py < i32_px(fb.height())
+            {
+                fb.set_pixel(px as u32, py as u32, c);
+            }
+        }
+    }
+}
+
+/// Even-odd ray-casting point-in-polygon test.
+fn point_in_polygon(poly: &[(f32, f32)], x: f32, y: f32) -> bool {
+    let mut inside = false;
+    let mut j = poly.len() - 1;
+    for i in 0..poly.len() {
+        let (xi, yi) = poly[i];
+        let (xj, yj) = poly[j];
+        if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+impl batuta_common::display::WithDimensions for WaffleChart {
+    fn set_dimensions(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use batuta_common::display::WithDimensions;
+
+    #[test]
+    fn test_waffle_builder() {
+        let chart = WaffleChart::new()
+            .category("pass", 70.0)
+            .category("fail", 30.0)
+            .build()
+            .expect("builder should produce valid result");
+
+        assert_eq!(chart.cell_count(), 100);
+        assert_eq!(chart.cell_counts(), vec![70, 30]);
+    }
+
+    #[test]
+    fn test_waffle_validation() {
+        assert!(WaffleChart::new().build().is_err());
+        assert!(WaffleChart::new().category("a", 0.0).build().is_err());
+        assert!(WaffleChart::new().category("a", -1.0).build().is_err());
+        assert!(WaffleChart::new().category("a", f32::NAN).build().is_err());
+        assert!(WaffleChart::new().category("a", 1.0).grid(0, 10).build().is_err());
+    }
+
+    #[test]
+    fn test_waffle_largest_remainder_sums_to_grid() {
+        let chart = WaffleChart::new().category("a", 1.0).category("b", 1.0).category("c", 1.0);
+        let counts = chart.cell_counts();
+        assert_eq!(counts.iter().sum::<usize>(), 100);
+        assert_eq!(counts, vec![34, 33, 33]);
+    }
+
+    #[test]
+    fn test_waffle_floor_leaves_empty() {
+        let chart = WaffleChart::new()
+            .category("a", 1.0)
+            .category("b", 1.0)
+            .category("c", 1.0)
+            .rounding(WaffleRounding::Floor);
+        assert_eq!(chart.cell_counts(), vec![33, 33, 33]);
+
+        let colors = chart.cell_colors();
+        assert_eq!(colors[99], chart.empty_color);
+    }
+
+    #[test]
+    fn test_waffle_nearest_trims_overflow() {
+        // 0.5 + 0.5 of a 1-cell grid would round to 2 cells
+        let chart = WaffleChart::new()
+            .category("a", 1.0)
+            .category("b", 1.0)
+            .grid(1, 1)
+            .rounding(WaffleRounding::Nearest);
+        assert_eq!(chart.cell_counts(), vec![1, 0]);
+    }
+
+    #[test]
+    fn test_waffle_custom_color() {
+        let chart = WaffleChart::new().category_colored("a", 1.0, Rgba::RED).grid(2, 2);
+        assert!(chart.cell_colors().iter().all(|&c| c == Rgba::RED));
+    }
+
+    #[test]
+    fn test_waffle_render_shapes() {
+        let triangle = vec![(0.5, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        for shape in [WaffleShape::Square, WaffleShape::Circle, WaffleShape::Path(triangle)] {
+            let chart = WaffleChart::new()
+                .category_colored("a", 60.0, Rgba::RED)
+                .category("b", 40.0)
+                .shape(shape)
+                .dimensions(120, 120)
+                .build()
+                .expect("builder should produce valid result");
+
+            let fb = chart.to_framebuffer().expect("rendering should succeed");
+            let red = (0..fb.height())
+                .any(|y| (0..fb.width()).any(|x| fb.get_pixel(x, y) == Some(Rgba::RED)));
+            assert!(red);
+        }
+    }
+
+    #[test]
+    fn test_waffle_to_svg() {
+        let svg = WaffleChart::new()
+            .category("a", 1.0)
+            .grid(1, 2)
+            .shape(WaffleShape::Path(vec![(0.0, 0.0), (1.0, 0.0), (0.5, 1.0)]))
+            .to_svg()
+            .render();
+        assert_eq!(svg.matches("<path").count(), 2);
+    }
+
+    #[test]
+    fn test_point_in_polygon() {
+        let square = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        assert!(point_in_polygon(&square, 0.5, 0.5));
+        assert!(!point_in_polygon(&square, 1.5, 0.5));
+    }
+}
@@ -0,0 +1,386 @@
+//! Batch rendering of many plot specs (e.g. one figure per experiment).
+//!
+//! With the `parallel` feature figures render on a rayon pool; otherwise
+//! they render in order on the calling thread. Either way every spec is
+//! attempted: failures are collected in the [`BatchReport`] instead of
+//! aborting the sweep.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::PlotSpec;
+use crate::error::Error;
+use crate::framebuffer::Framebuffer;
+use crate::output::PngEncoder;
+
+/// Longest title fragment used in output file names.
+const MAX_SLUG_LEN: usize = 40;
+
+/// Progress of a running batch, passed to [`BatchOptions::on_progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchProgress {
+    /// Index of the spec that just finished.
+    pub index: usize,
+    /// Specs finished so far, including this one.
+    pub completed: usize,
+    /// Specs in the batch.
+    pub total: usize,
+    /// Whether this spec rendered (and saved) successfully.
+    pub ok: bool,
+}
+
+type ProgressFn = dyn Fn(&BatchProgress) + Send + Sync;
+
+/// Options for [`render_batch`].
+#[derive(Clone, Default)]
+pub struct BatchOptions {
+    threads: Option<usize>,
+    output_dir: Option<PathBuf>,
+    keep_figures: Option<bool>,
+    progress: Option<Arc<ProgressFn>>,
+    #[cfg(feature = "sidecar")]
+    sidecar: Option<super::SidecarFormat>,
+}
+
+impl fmt::Debug for BatchOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("BatchOptions");
+        debug
+            .field("threads", &self.threads)
+            .field("output_dir", &self.output_dir)
+            .field("keep_figures", &self.keep_figures)
+            .field("progress", &self.progress.is_some());
+        #[cfg(feature = "sidecar")]
+        debug.field("sidecar", &self.sidecar);
+        debug.finish()
+    }
+}
+
+impl BatchOptions {
+    /// Create default options (all cores, figures kept in memory).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render on a dedicated pool of `threads` workers instead of the global
+    /// rayon pool. Ignored without the `parallel` feature.
+    #[must_use]
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = Some(threads.max(1));
+        self
+    }
+
+    /// Save each figure as PNG under `dir` (created if missing), named
+    /// `0007.png` or `0007-<title>.png` after the spec's index and title.
+    #[must_use]
+    pub fn output_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.output_dir = Some(dir.into());
+        self
+    }
+
+    /// Keep rendered framebuffers in the report. Defaults to `true`, or
+    /// `false` when an output directory is set so large sweeps stream to disk.
+    #[must_use]
+    pub fn keep_figures(mut self, keep: bool) -> Self {
+        self.keep_figures = Some(keep);
+        self
+    }
+
+    /// Call `callback` as each spec finishes. With the `parallel` feature it
+    /// runs on worker threads, in completion order.
+    #[must_use]
+    pub fn on_progress(
+        mut self,
+        callback: impl Fn(&BatchProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+
+    /// Also save each figure's [`Sidecar`](super::Sidecar) in `format` next to
+    /// its PNG (`0007.png` → `0007.csv`). Ignored without an output directory.
+    #[cfg(feature = "sidecar")]
+    #[must_use]
+    pub fn sidecar(mut self, format: super::SidecarFormat) -> Self {
+        self.sidecar = Some(format);
+        self
+    }
+}
+
+/// Outcome of [`render_batch`], indexed like the input specs.
+#[derive(Debug)]
+pub struct BatchReport {
+    /// Rendered figure per spec (`None` on failure or when not kept).
+    pub figures: Vec<Option<Framebuffer>>,
+    /// Saved PNG per spec when an output directory is set.
+    pub paths: Vec<Option<PathBuf>>,
+    /// Failed specs as `(index, error)`, in index order.
+    pub failures: Vec<(usize, Error)>,
+    /// Wall-clock time for the whole batch.
+    pub elapsed: Duration,
+}
+
+impl BatchReport {
+    /// Number of specs in the batch.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.figures.len()
+    }
+
+    /// Number of specs that rendered successfully.
+    #[must_use]
+    pub fn succeeded(&self) -> usize {
+        self.total() - self.failures.len()
+    }
+
+    /// Whether every spec rendered.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl fmt::Display for BatchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "rendered {}/{} figures in {:.2}s",
+            self.succeeded(),
+            self.total(),
+            self.elapsed.as_secs_f64()
+        )?;
+        for (index, error) in &self.failures {
+            write!(f, "\n  #{index}: {error}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Render every spec, reporting progress and collecting per-spec failures.
+///
+/// # Example
+///
+/// ```rust
+/// use trueno_viz::prompt::{parse_prompt, render_batch, BatchOptions};
+///
+/// let specs = vec![
+///     parse_prompt("histogram data=[1,2,2,3] width=200 height=150").unwrap(),
+///     parse_prompt("scatter x=[1,2,3] y=[3,1,2] width=200 height=150").unwrap(),
+///     parse_prompt("heatmap matrix=[[1,2],[3,4]] width=0 height=150").unwrap(),
+/// ];
+/// let report = render_batch(&specs, &BatchOptions::new().on_progress(|p| {
+///     eprintln!("{}/{} done", p.completed, p.total);
+/// }));
+/// assert_eq!(report.succeeded(), 2);
+/// assert_eq!(report.failures[0].0, 2);
+/// ```
+#[must_use]
+pub fn render_batch(specs: &[PlotSpec], opts: &BatchOptions) -> BatchReport {
+    let start = Instant::now();
+    let completed = AtomicUsize::new(0);
+    let keep = opts.keep_figures.unwrap_or(opts.output_dir.is_none());
+    let dir_error = opts.output_dir.as_deref().and_then(|dir| std::fs::create_dir_all(dir).err());
+
+    let render_one = |(index, spec): (usize, &PlotSpec)| {
+        let outcome = match (&dir_error, &opts.output_dir) {
+            (Some(err), Some(dir)) => {
+                Err(Error::Rendering(format!("Cannot create {}: {err}", dir.display())))
+            }
+            _ => render_and_save(index, spec, opts, keep),
+        };
+        if let Some(callback) = &opts.progress {
+            callback(&BatchProgress {
+                index,
+                completed: completed.fetch_add(1, Ordering::Relaxed) + 1,
+                total: specs.len(),
+                ok: outcome.is_ok(),
+            });
+        }
+        outcome
+    };
+
+    #[cfg(feature = "parallel")]
+    let outcomes: Vec<_> = {
+        use rayon::prelude::*;
+        let run = || specs.par_iter().enumerate().map(&render_one).collect();
+        match opts.threads.map(|n| rayon::ThreadPoolBuilder::new().num_threads(n).build()) {
+            Some(Ok(pool)) => pool.install(run),
+            _ => run(),
+        }
+    };
+    #[cfg(not(feature = "parallel"))]
+    let outcomes: Vec<_> = specs.iter().enumerate().map(render_one).collect();
+
+    let mut report = BatchReport {
+        figures: Vec::with_capacity(specs.len()),
+        paths: Vec::with_capacity(specs.len()),
+        failures: Vec::new(),
+        elapsed: Duration::ZERO,
+    };
+    for (index, outcome) in outcomes.into_iter().enumerate() {
+        match outcome {
+            Ok((figure, path)) => {
+                report.figures.push(figure);
+                report.paths.push(path);
+            }
+            Err(err) => {
+                report.figures.push(None);
+                report.paths.push(None);
+                report.failures.push((index, err));
+            }
+        }
+    }
+    report.elapsed = start.elapsed();
+    report
+}
+
+/// Render one spec, optionally saving it (and its sidecar), and keep the framebuffer if asked.
+fn render_and_save(
+    index: usize,
+    spec: &PlotSpec,
+    opts: &BatchOptions,
+    keep: bool,
+) -> crate::error::Result<(Option<Framebuffer>, Option<PathBuf>)> {
+    let fb = spec.render()?;
+    let path = match opts.output_dir.as_deref() {
+        Some(dir) => {
+            let path = dir.join(file_name(index, spec.title.as_deref()));
+            PngEncoder::write_to_file(&fb, &path)?;
+            #[cfg(feature = "sidecar")]
+            if let Some(format) = opts.sidecar {
+                super::Sidecar::from_spec(spec)?
+                    .write(path.with_extension(format.extension()), format)?;
+            }
+            Some(path)
+        }
+        None => None,
+    };
+    Ok((keep.then_some(fb), path))
+}
+
+/// `0007.png`, or `0007-loss-vs-epoch.png` for a titled spec.
+fn file_name(index: usize, title: Option<&str>) -> String {
+    let mut slug = String::new();
+    for c in title.unwrap_or_default().chars().flat_map(char::to_lowercase) {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        if slug.len() >= MAX_SLUG_LEN {
+            break;
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        format!("{index:04}.png")
+    } else {
+        format!("{index:04}-{slug}.png")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn spec(prompt: &str) -> PlotSpec {
+        super::super::parse_prompt(prompt).expect("parsing should succeed")
+    }
+
+    fn sweep() -> Vec<PlotSpec> {
+        (1..=6)
+            .map(|i| spec(&format!("line x=[0,1,2,3] y=[0,{i},1,{i}] width=160 height=120")))
+            .collect()
+    }
+
+    #[test]
+    fn test_renders_all_specs_with_progress() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&seen);
+        let report = render_batch(
+            &sweep(),
+            &BatchOptions::new().threads(2).on_progress(move |p| {
+                log.lock().expect("progress lock").push(*p);
+            }),
+        );
+
+        assert!(report.is_complete());
+        assert_eq!(report.total(), 6);
+        assert!(report.figures.iter().all(|f| f.as_ref().is_some_and(|fb| fb.width() == 160)));
+        assert!(report.paths.iter().all(Option::is_none));
+
+        let mut seen = seen.lock().expect("progress lock").clone();
+        assert_eq!(seen.len(), 6);
+        assert!(seen.iter().all(|p| p.total == 6 && p.ok));
+        seen.sort_by_key(|p| p.completed);
+        assert_eq!(seen.iter().map(|p| p.completed).collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_partial_failures_are_reported() {
+        let mut specs = sweep();
+        specs[1] = PlotSpec { plot_type: "sankey".into(), ..PlotSpec::default() };
+        specs[4].width = 0;
+        let failed = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&failed);
+        let report = render_batch(
+            &specs,
+            &BatchOptions::new().on_progress(move |p| {
+                if !p.ok {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            }),
+        );
+
+        assert_eq!(report.succeeded(), 4);
+        assert_eq!(report.failures.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [1, 4]);
+        assert!(report.figures[1].is_none() && report.figures[2].is_some());
+        assert_eq!(failed.load(Ordering::Relaxed), 2);
+        let summary = report.to_string();
+        assert!(summary.starts_with("rendered 4/6 figures"));
+        assert!(summary.contains("#1: Rendering error: Unknown plot type: sankey"));
+    }
+
+    #[test]
+    fn test_output_dir_streams_pngs() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let out = dir.path().join("sweep");
+        let mut specs = sweep();
+        specs[0].title = Some("Loss vs. Epoch (run #1)".into());
+
+        let report = render_batch(&specs, &BatchOptions::new().output_dir(&out));
+        assert!(report.is_complete());
+        assert!(report.figures.iter().all(Option::is_none));
+        let first = report.paths[0].as_ref().expect("saved path");
+        assert_eq!(
+            first.file_name().and_then(|n| n.to_str()),
+            Some("0000-loss-vs-epoch-run-1.png")
+        );
+        assert!(out.join("0005.png").is_file());
+
+        let kept =
+            render_batch(&specs[..1], &BatchOptions::new().output_dir(&out).keep_figures(true));
+        assert!(kept.figures[0].is_some());
+    }
+
+    #[cfg(feature = "sidecar")]
+    #[test]
+    fn test_output_dir_writes_sidecars() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let opts =
+            BatchOptions::new().output_dir(dir.path()).sidecar(super::super::SidecarFormat::Json);
+        let report = render_batch(&sweep()[..2], &opts);
+        assert!(report.is_complete());
+
+        let text = std::fs::read_to_string(dir.path().join("0001.json")).expect("sidecar");
+        let sidecar = super::super::Sidecar::parse(&text).expect("parse");
+        assert_eq!(sidecar.columns, ["x", "y"]);
+        assert_eq!(sidecar.rows.len(), 4);
+        assert!(format!("{opts:?}").contains("sidecar: Some(Json)"));
+    }
+}
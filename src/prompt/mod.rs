@@ -37,8 +37,23 @@ use crate::framebuffer::Framebuffer;
 use crate::plots::{BoxPlot, Heatmap, Histogram, LineChart, LineSeries, ScatterPlot};
 use batuta_common::display::WithDimensions;
 
+mod batch;
+mod recommend;
+#[cfg(feature = "sidecar")]
+mod sidecar;
+
+pub use batch::{render_batch, BatchOptions, BatchProgress, BatchReport};
+pub use recommend::{recommend_plot, PlotRecommendation};
+#[cfg(feature = "sidecar")]
+pub use sidecar::{Cell, Sidecar, SidecarFormat};
+
 /// A parsed plot specification.
+///
+/// With the `serde` feature it (de)serializes field-by-field, with missing
+/// fields taking their [`Default`] values.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct PlotSpec {
     /// Plot type (scatter, line, histogram, heatmap, boxplot)
     pub plot_type: String,
@@ -0,0 +1,496 @@
+//! Chart-type recommendation from data shape.
+//!
+//! Inspects the type and cardinality of selected [`DataFrame`] columns and
+//! proposes ranked, ready-to-render [`PlotSpec`]s using the plot types the
+//! prompt DSL understands (scatter, line, histogram, heatmap, boxplot).
+//!
+//! | Columns               | Proposals                                          |
+//! |-----------------------|----------------------------------------------------|
+//! | 1 numeric             | histogram, boxplot                                 |
+//! | 2 numeric             | scatter, line (ordered x), binned heatmap (dense)  |
+//! | 3+ numeric            | correlation heatmap, side-by-side boxplot          |
+//! | categorical + numeric | boxplot per category                               |
+//! | 2 categorical         | heatmap of co-occurrence counts                    |
+
+use std::collections::BTreeMap;
+
+use super::PlotSpec;
+use crate::error::{Error, Result};
+use crate::grammar::{DataFrame, DataValue};
+use crate::plots::Splom;
+
+/// Most categories a boxplot splits a numeric column into.
+const MAX_GROUPS: usize = 12;
+
+/// Most levels per axis of a categorical co-occurrence heatmap.
+const MAX_LEVELS: usize = 24;
+
+/// Above this many points a scatter is also offered as a binned heatmap.
+const DENSE_POINTS: usize = 5000;
+
+/// Bins per axis of the binned-density heatmap.
+const DENSITY_BINS: usize = 32;
+
+/// A proposed plot for a set of columns.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlotRecommendation {
+    /// Plot specification with its data filled in from the frame.
+    pub spec: PlotSpec,
+    /// Columns the plot encodes, in encoding order.
+    pub columns: Vec<String>,
+    /// Suitability in `[0, 1]`; recommendations are sorted by it.
+    pub score: f32,
+    /// Short human-readable rationale.
+    pub reason: String,
+}
+
+/// An inspected column, row-aligned with the frame.
+enum Column<'a> {
+    /// Numbers, with `NaN` marking missing rows.
+    Numeric { name: &'a str, values: Vec<f32> },
+    /// Text, with the sorted distinct levels.
+    Categorical { name: &'a str, values: Vec<Option<&'a str>>, levels: Vec<&'a str> },
+}
+
+impl<'a> Column<'a> {
+    /// Classify a column; `None` when it holds no values at all.
+    fn inspect(name: &'a str, values: &'a [DataValue], rows: usize) -> Option<Self> {
+        let cell = |i: usize| values.get(i).unwrap_or(&DataValue::Null);
+        if values.iter().any(|v| matches!(v, DataValue::Text(_))) {
+            let values: Vec<Option<&str>> = (0..rows).map(|i| cell(i).as_str()).collect();
+            let mut levels: Vec<&str> = values.iter().flatten().copied().collect();
+            levels.sort_unstable();
+            levels.dedup();
+            return Some(Self::Categorical { name, values, levels });
+        }
+        let values: Vec<f32> = (0..rows).map(|i| cell(i).as_f32().unwrap_or(f32::NAN)).collect();
+        values.iter().any(|v| v.is_finite()).then_some(Self::Numeric { name, values })
+    }
+
+    fn name(&self) -> &'a str {
+        match self {
+            Self::Numeric { name, .. } | Self::Categorical { name, .. } => name,
+        }
+    }
+}
+
+/// Propose suitable plots for `columns` of `df`, best first.
+///
+/// An empty `columns` slice considers every column (in name order). Plots
+/// that encode exactly the selected columns rank above partial views such as
+/// a histogram of one column out of several. Missing values are dropped
+/// pairwise. A lone text column yields no proposals, since the DSL has no
+/// bar chart.
+///
+/// # Errors
+///
+/// Returns an error if a named column does not exist.
+///
+/// # Example
+///
+/// ```rust
+/// use trueno_viz::grammar::DataFrame;
+/// use trueno_viz::prompt::recommend_plot;
+///
+/// let df = DataFrame::from_xy(&[1.0, 2.0, 3.0, 4.0], &[2.0, 4.0, 5.0, 4.5]);
+/// let ranked = recommend_plot(&df, &["x", "y"]).unwrap();
+/// assert_eq!(ranked[0].spec.plot_type, "line");
+/// let fb = ranked[0].spec.render().unwrap();
+/// assert_eq!(fb.width(), 600);
+/// ```
+pub fn recommend_plot(df: &DataFrame, columns: &[&str]) -> Result<Vec<PlotRecommendation>> {
+    let mut names: Vec<&str> = columns.to_vec();
+    if names.is_empty() {
+        names = df.columns();
+        names.sort_unstable();
+    }
+
+    let mut selected = Vec::with_capacity(names.len());
+    for name in names {
+        let values =
+            df.get(name).ok_or_else(|| Error::Rendering(format!("Unknown column: {name}")))?;
+        selected.extend(Column::inspect(name, values, df.nrow()));
+    }
+
+    let numeric: Vec<(&str, &[f32])> = selected
+        .iter()
+        .filter_map(|c| match c {
+            Column::Numeric { name, values } => Some((*name, values.as_slice())),
+            Column::Categorical { .. } => None,
+        })
+        .collect();
+    let categorical: Vec<&Column<'_>> =
+        selected.iter().filter(|c| matches!(c, Column::Categorical { .. })).collect();
+
+    // Full score for plots covering the whole selection, less for partial views
+    let fit = |uses: usize, score: f32| if uses == selected.len() { score } else { score * 0.6 };
+    let mut out = Vec::new();
+
+    for &(name, values) in &numeric {
+        let data: Vec<f32> = values.iter().copied().filter(|v| v.is_finite()).collect();
+        out.push(recommendation(
+            PlotSpec {
+                plot_type: "histogram".into(),
+                data: Some(data.clone()),
+                title: Some(format!("Distribution of {name}")),
+                ..PlotSpec::default()
+            },
+            &[name],
+            fit(1, 0.9),
+            "Histogram shows the shape of a single numeric column",
+        ));
+        out.push(recommendation(
+            PlotSpec {
+                plot_type: "boxplot".into(),
+                groups: Some(vec![data]),
+                title: Some(format!("Spread of {name}")),
+                ..PlotSpec::default()
+            },
+            &[name],
+            fit(1, 0.5),
+            "Boxplot summarizes median, quartiles and outliers",
+        ));
+    }
+
+    for (i, &(x_name, x_values)) in numeric.iter().enumerate() {
+        for &(y_name, y_values) in &numeric[i + 1..] {
+            out.extend(pair_recommendations(x_name, x_values, y_name, y_values, fit(2, 1.0)));
+        }
+    }
+
+    if numeric.len() >= 3 {
+        let top = fit(numeric.len(), 1.0);
+        out.extend(overview_recommendations(&numeric, top));
+    }
+
+    for category in &categorical {
+        let Column::Categorical { name: c_name, values: labels, levels } = category else {
+            continue;
+        };
+        if !(2..=MAX_GROUPS).contains(&levels.len()) {
+            continue;
+        }
+        for &(name, values) in &numeric {
+            let groups = levels
+                .iter()
+                .map(|level| {
+                    labels
+                        .iter()
+                        .zip(values)
+                        .filter(|(l, v)| **l == Some(*level) && v.is_finite())
+                        .map(|(_, v)| *v)
+                        .collect()
+                })
+                .collect();
+            out.push(recommendation(
+                PlotSpec {
+                    plot_type: "boxplot".into(),
+                    groups: Some(groups),
+                    title: Some(format!("{name} by {c_name} ({})", levels.join(", "))),
+                    ..PlotSpec::default()
+                },
+                &[name, c_name],
+                fit(2, 0.9),
+                &format!("Boxplot per {c_name} compares {name} across {} groups", levels.len()),
+            ));
+        }
+    }
+
+    for (i, rows) in categorical.iter().enumerate() {
+        for cols in &categorical[i + 1..] {
+            out.extend(crosstab_recommendation(rows, cols, fit(2, 0.85)));
+        }
+    }
+
+    out.sort_by(|a, b| b.score.total_cmp(&a.score));
+    Ok(out)
+}
+
+/// Correlation heatmap and side-by-side boxplot for three or more numeric columns.
+fn overview_recommendations(numeric: &[(&str, &[f32])], top: f32) -> Vec<PlotRecommendation> {
+    let names: Vec<&str> = numeric.iter().map(|(n, _)| *n).collect();
+    let splom = numeric.iter().fold(Splom::new(), |s, (n, v)| s.column(n, v));
+    let k = numeric.len();
+    let matrix = (0..k)
+        .map(|a| {
+            (0..k).map(|b| if a == b { 1.0 } else { splom.correlation_between(a, b) }).collect()
+        })
+        .collect();
+    vec![
+        recommendation(
+            PlotSpec {
+                plot_type: "heatmap".into(),
+                matrix: Some(matrix),
+                title: Some(format!("Correlation of {}", names.join(", "))),
+                ..PlotSpec::default()
+            },
+            &names,
+            top * 0.9,
+            "Correlation heatmap compares every pair of numeric columns at once",
+        ),
+        recommendation(
+            PlotSpec {
+                plot_type: "boxplot".into(),
+                groups: Some(
+                    numeric
+                        .iter()
+                        .map(|(_, v)| v.iter().copied().filter(|v| v.is_finite()).collect())
+                        .collect(),
+                ),
+                title: Some(format!("Spread of {}", names.join(", "))),
+                ..PlotSpec::default()
+            },
+            &names,
+            top * 0.6,
+            "Side-by-side boxplots compare the numeric columns' ranges",
+        ),
+    ]
+}
+
+fn recommendation(
+    spec: PlotSpec,
+    columns: &[&str],
+    score: f32,
+    reason: &str,
+) -> PlotRecommendation {
+    PlotRecommendation {
+        spec,
+        columns: columns.iter().map(|c| (*c).to_string()).collect(),
+        score,
+        reason: reason.to_string(),
+    }
+}
+
+/// Scatter, line and binned-density proposals for a numeric pair.
+fn pair_recommendations(
+    x_name: &str,
+    x_values: &[f32],
+    y_name: &str,
+    y_values: &[f32],
+    top: f32,
+) -> Vec<PlotRecommendation> {
+    let (x, y): (Vec<f32>, Vec<f32>) =
+        x_values.iter().zip(y_values).filter(|(u, v)| u.is_finite() && v.is_finite()).unzip();
+    if x.len() < 2 {
+        return Vec::new();
+    }
+    let columns = [x_name, y_name];
+    let title = format!("{y_name} vs {x_name}");
+    let ordered = x.windows(2).all(|w| w[0] <= w[1]) && x[0] < x[x.len() - 1];
+    let dense = x.len() > DENSE_POINTS;
+
+    let mut out = Vec::new();
+    if ordered {
+        out.push(recommendation(
+            PlotSpec {
+                plot_type: "line".into(),
+                x_data: Some(x.clone()),
+                y_data: Some(y.clone()),
+                size: 2.0,
+                title: Some(title.clone()),
+                ..PlotSpec::default()
+            },
+            &columns,
+            top * 0.95,
+            &format!("{x_name} is ordered, so a line shows {y_name} changing along it"),
+        ));
+    }
+    if dense {
+        out.push(recommendation(
+            PlotSpec {
+                plot_type: "heatmap".into(),
+                matrix: Some(density_matrix(&x, &y)),
+                title: Some(format!("{title} (density)")),
+                ..PlotSpec::default()
+            },
+            &columns,
+            top * 0.85,
+            &format!("{} points would overplot; binned counts show density", x.len()),
+        ));
+    }
+    let scatter_score = match (ordered, dense) {
+        (false, false) => top * 0.9,
+        _ => top * 0.7,
+    };
+    out.push(recommendation(
+        PlotSpec {
+            plot_type: "scatter".into(),
+            x_data: Some(x),
+            y_data: Some(y),
+            size: if dense { 1.0 } else { 4.0 },
+            title: Some(title),
+            ..PlotSpec::default()
+        },
+        &columns,
+        scatter_score,
+        "Scatter plot shows the relationship between two numeric columns",
+    ));
+    out
+}
+
+/// 2D histogram of `(x, y)` with high `y` in the top row.
+fn density_matrix(x: &[f32], y: &[f32]) -> Vec<Vec<f32>> {
+    let range = |v: &[f32]| {
+        let (lo, hi) = v
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+        (lo, (hi - lo).max(f32::EPSILON))
+    };
+    let bin = |v: f32, (lo, span): (f32, f32)| {
+        let b = ((v - lo) / span * DENSITY_BINS as f32) as usize;
+        b.min(DENSITY_BINS - 1)
+    };
+    let (x_range, y_range) = (range(x), range(y));
+    let mut matrix = vec![vec![0.0; DENSITY_BINS]; DENSITY_BINS];
+    for (&u, &v) in x.iter().zip(y) {
+        matrix[DENSITY_BINS - 1 - bin(v, y_range)][bin(u, x_range)] += 1.0;
+    }
+    matrix
+}
+
+/// Co-occurrence counts of two categorical columns as a heatmap.
+fn crosstab_recommendation(
+    rows: &Column<'_>,
+    cols: &Column<'_>,
+    score: f32,
+) -> Option<PlotRecommendation> {
+    let (
+        Column::Categorical { values: row_values, levels: row_levels, .. },
+        Column::Categorical { values: col_values, levels: col_levels, .. },
+    ) = (rows, cols)
+    else {
+        return None;
+    };
+    if row_levels.len() > MAX_LEVELS || col_levels.len() > MAX_LEVELS {
+        return None;
+    }
+    let mut counts: BTreeMap<(&str, &str), f32> = BTreeMap::new();
+    for (r, c) in row_values.iter().zip(col_values) {
+        if let (Some(r), Some(c)) = (r, c) {
+            *counts.entry((r, c)).or_default() += 1.0;
+        }
+    }
+    let matrix = row_levels
+        .iter()
+        .map(|r| col_levels.iter().map(|c| counts.get(&(*r, *c)).copied().unwrap_or(0.0)).collect())
+        .collect();
+    let (row_name, col_name) = (rows.name(), cols.name());
+    Some(recommendation(
+        PlotSpec {
+            plot_type: "heatmap".into(),
+            matrix: Some(matrix),
+            title: Some(format!("{row_name} x {col_name} counts")),
+            ..PlotSpec::default()
+        },
+        &[row_name, col_name],
+        score,
+        &format!(
+            "Heatmap of counts shows how {} {row_name} and {} {col_name} levels co-occur",
+            row_levels.len(),
+            col_levels.len()
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plot_types(recs: &[PlotRecommendation]) -> Vec<&str> {
+        recs.iter().map(|r| r.spec.plot_type.as_str()).collect()
+    }
+
+    #[test]
+    fn test_single_numeric_prefers_histogram() {
+        let df = DataFrame::from_data(&[1.0, 2.0, 2.0, 3.0, 7.0]);
+        let recs = recommend_plot(&df, &[]).expect("recommendation should succeed");
+        assert_eq!(plot_types(&recs), ["histogram", "boxplot"]);
+        assert_eq!(recs[0].columns, ["data"]);
+        recs[0].spec.render().expect("spec should render");
+    }
+
+    #[test]
+    fn test_numeric_pair_ordering_and_density() {
+        let x: Vec<f32> = (0..20).map(|i| i as f32).collect();
+        let y: Vec<f32> = x.iter().map(|v| v.sin()).collect();
+        let recs = recommend_plot(&DataFrame::from_xy(&x, &y), &["x", "y"])
+            .expect("recommendation should succeed");
+        assert_eq!(recs[0].spec.plot_type, "line");
+        assert!(recs.windows(2).all(|w| w[0].score >= w[1].score));
+
+        let x: Vec<f32> = (0..6000).map(|i| ((i * 7919) % 6000) as f32).collect();
+        let y: Vec<f32> = x.iter().map(|v| v * 0.5).collect();
+        let recs = recommend_plot(&DataFrame::from_xy(&x, &y), &["x", "y"])
+            .expect("recommendation should succeed");
+        assert_eq!(plot_types(&recs)[..2], ["heatmap", "scatter"]);
+        let matrix = recs[0].spec.matrix.as_ref().expect("density matrix");
+        let total: f32 = matrix.iter().flatten().sum();
+        assert!((total - 6000.0).abs() < 0.5);
+        // Positive relationship: the top row's mass sits on the right
+        assert!(matrix[0][DENSITY_BINS - 1] > 0.0);
+    }
+
+    #[test]
+    fn test_categorical_with_numeric_groups_boxplot() {
+        let mut df = DataFrame::new();
+        df.add_column_str("region", &["eu", "us", "eu", "ap", "us", "eu"]);
+        df.add_column(
+            "latency",
+            vec![
+                DataValue::Number(10.0),
+                DataValue::Number(30.0),
+                DataValue::Null,
+                DataValue::Number(50.0),
+                DataValue::Number(35.0),
+                DataValue::Number(12.0),
+            ],
+        );
+        let recs =
+            recommend_plot(&df, &["latency", "region"]).expect("recommendation should succeed");
+        let top = &recs[0];
+        assert_eq!(top.spec.plot_type, "boxplot");
+        assert_eq!(top.columns, ["latency", "region"]);
+        let groups = top.spec.groups.as_ref().expect("groups");
+        assert_eq!(groups, &[vec![50.0], vec![10.0, 12.0], vec![30.0, 35.0]]);
+        // Partial views rank below the grouped plot
+        assert!(recs[1..].iter().all(|r| r.score < top.score));
+    }
+
+    #[test]
+    fn test_multi_column_selections() {
+        let mut df = DataFrame::new();
+        df.add_column_f32("a", &[1.0, 2.0, 3.0, 4.0]);
+        df.add_column_f32("b", &[2.0, 1.0, 4.0, 3.0]);
+        df.add_column_f32("c", &[4.0, 3.0, 2.0, 1.0]);
+        df.add_column_str("kind", &["x", "y", "x", "y"]);
+        df.add_column_str("size", &["s", "s", "l", "l"]);
+
+        let recs = recommend_plot(&df, &["c", "a", "b"]).expect("recommendation should succeed");
+        assert_eq!(recs[0].spec.plot_type, "heatmap");
+        assert_eq!(recs[0].columns, ["c", "a", "b"]);
+        let matrix = recs[0].spec.matrix.as_ref().expect("correlation matrix");
+        assert!((matrix[0][1] + 1.0).abs() < 1e-5);
+
+        let recs = recommend_plot(&df, &["kind", "size"]).expect("recommendation should succeed");
+        assert_eq!(plot_types(&recs), ["heatmap"]);
+        let counts = recs[0].spec.matrix.as_ref().expect("count matrix");
+        assert_eq!(counts, &[vec![1.0, 1.0], vec![1.0, 1.0]]);
+
+        assert!(recommend_plot(&df, &["kind"]).expect("recommendation should succeed").is_empty());
+        assert!(recommend_plot(&df, &["missing"]).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_recommendation_round_trips_through_json() {
+        let df = DataFrame::from_xy(&[1.0, 3.0, 2.0], &[4.0, 5.0, 6.0]);
+        let recs = recommend_plot(&df, &[]).expect("recommendation should succeed");
+        let json = serde_json::to_string(&recs).expect("serialize");
+        assert!(json.contains("\"plot_type\":\"scatter\""));
+        let back: Vec<PlotRecommendation> = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(back.len(), recs.len());
+        assert_eq!(back[0].spec.x_data, recs[0].spec.x_data);
+        assert_eq!(back[0].spec.color, recs[0].spec.color);
+    }
+}
@@ -0,0 +1,559 @@
+//! Data sidecars: the table behind a figure, saved next to it.
+//!
+//! A [`Sidecar`] holds the exact data a [`PlotSpec`] draws after its
+//! transforms (histogram bins, box plot statistics, ...) together with the
+//! spec itself, so a published figure can be audited and regenerated later.
+//! It is written as CSV, with the spec on a `# spec:` comment line, or as
+//! JSON; [`Sidecar::parse`] reads either back. With the `sidecar-arrow`
+//! feature it can also be written as an Arrow IPC file for dataframe tools
+//! (write-only; the spec goes in the schema metadata).
+//!
+//! Sidecars exist only for figures described by a [`PlotSpec`], i.e. the
+//! plot types below. Plots built directly with the builders in
+//! [`crate::plots`] (`ScatterPlot::new()`, ...) have no sidecar; describe
+//! the figure as a [`PlotSpec`] to get one.
+//!
+//! | Plot type | Columns |
+//! |-----------|---------|
+//! | `scatter`, `line` | `x`, `y` |
+//! | `histogram` | `bin_start`, `bin_end`, `count` |
+//! | `heatmap` | `row`, `col`, `value` |
+//! | `boxplot` | `group`, `stat`, `value` (`min`, `q1`, `median`, `q3`, `max`, `outlier`) |
+//!
+//! # Example
+//!
+//! ```rust
+//! use trueno_viz::prompt::{parse_prompt, Sidecar};
+//!
+//! let spec = parse_prompt("histogram data=[1,2,2,3,3,3] width=200 height=150").unwrap();
+//! let sidecar = Sidecar::from_spec(&spec).unwrap();
+//! assert_eq!(sidecar.columns, ["bin_start", "bin_end", "count"]);
+//!
+//! let restored = Sidecar::parse(&sidecar.to_csv()).unwrap();
+//! assert_eq!(restored.rows, sidecar.rows);
+//! assert!(restored.spec.render().is_ok());
+//! ```
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::PlotSpec;
+use crate::error::{Error, Result};
+use crate::plots::{BoxStats, Heatmap, Histogram};
+
+/// File format of a [`Sidecar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SidecarFormat {
+    /// Comma-separated table with the spec in a leading comment.
+    #[default]
+    Csv,
+    /// One JSON object with `generator`, `spec`, `columns` and `rows`.
+    Json,
+    /// Arrow IPC file with `generator` and `spec` in the schema metadata.
+    #[cfg(feature = "sidecar-arrow")]
+    Arrow,
+}
+
+impl SidecarFormat {
+    /// File extension for this format, without the dot.
+    #[must_use]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+            #[cfg(feature = "sidecar-arrow")]
+            Self::Arrow => "arrow",
+        }
+    }
+}
+
+/// One value of a sidecar table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Cell {
+    /// Numeric value.
+    Number(f32),
+    /// Text value (group labels, statistic names).
+    Text(String),
+    /// Missing or non-finite value; an empty CSV field or JSON `null`.
+    Missing,
+}
+
+impl From<f32> for Cell {
+    fn from(value: f32) -> Self {
+        if value.is_finite() {
+            Self::Number(value)
+        } else {
+            Self::Missing
+        }
+    }
+}
+
+impl From<usize> for Cell {
+    fn from(value: usize) -> Self {
+        Self::Number(value as f32)
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/// The data table and spec behind one figure.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sidecar {
+    /// Library and version that produced the sidecar.
+    pub generator: String,
+    /// The spec the figure was rendered from.
+    pub spec: PlotSpec,
+    /// Column names.
+    pub columns: Vec<String>,
+    /// Table rows, one cell per column.
+    pub rows: Vec<Vec<Cell>>,
+}
+
+impl Sidecar {
+    /// Build the sidecar for `spec` from the data its figure draws.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error rendering `spec` would fail with for missing or
+    /// inconsistent data, or for an unknown plot type.
+    pub fn from_spec(spec: &PlotSpec) -> Result<Self> {
+        let (columns, rows) = match spec.plot_type.as_str() {
+            "scatter" | "line" => xy_table(spec)?,
+            "histogram" => histogram_table(spec)?,
+            "heatmap" => heatmap_table(spec)?,
+            "boxplot" => boxplot_table(spec)?,
+            _ => return Err(Error::Rendering(format!("Unknown plot type: {}", spec.plot_type))),
+        };
+        Ok(Self {
+            generator: concat!("trueno-viz ", env!("CARGO_PKG_VERSION")).to_string(),
+            spec: spec.clone(),
+            columns: columns.iter().map(ToString::to_string).collect(),
+            rows,
+        })
+    }
+
+    /// Serialize as CSV: a generator comment, a `# spec:` comment holding the
+    /// spec as JSON, then the header and rows.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut out = format!("# {} sidecar\n", self.generator);
+        let _ = writeln!(out, "# spec: {}", serde_json::to_string(&self.spec).unwrap_or_default());
+        let header: Vec<Cell> = self.columns.iter().map(|c| Cell::Text(c.clone())).collect();
+        for row in std::iter::once(&header).chain(&self.rows) {
+            for (i, cell) in row.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                match cell {
+                    Cell::Number(v) => {
+                        let _ = write!(out, "{v}");
+                    }
+                    Cell::Text(s) if s.contains([',', '"', '\n']) || s.parse::<f32>().is_ok() => {
+                        let _ = write!(out, "\"{}\"", s.replace('"', "\"\""));
+                    }
+                    Cell::Text(s) => out.push_str(s),
+                    Cell::Missing => {}
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Serialize as pretty-printed JSON.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Serialize as an Arrow IPC file holding one record batch: columns
+    /// without text cells become nullable `Float32`, the others nullable
+    /// `Utf8`, and `generator` and `spec` (as JSON) go in the schema metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Arrow writer fails.
+    #[cfg(feature = "sidecar-arrow")]
+    pub fn to_arrow(&self) -> Result<Vec<u8>> {
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        use arrow_array::{ArrayRef, Float32Array, RecordBatch, StringArray};
+        use arrow_ipc::writer::FileWriter;
+        use arrow_schema::{ArrowError, DataType, Field, Schema};
+
+        let arrow_err = |e: ArrowError| Error::Rendering(format!("Arrow encoding failed: {e}"));
+        let mut fields = Vec::with_capacity(self.columns.len());
+        let mut arrays: Vec<ArrayRef> = Vec::with_capacity(self.columns.len());
+        for (i, name) in self.columns.iter().enumerate() {
+            let cells = self.rows.iter().map(|row| row.get(i).unwrap_or(&Cell::Missing));
+            if cells.clone().any(|cell| matches!(cell, Cell::Text(_))) {
+                fields.push(Field::new(name, DataType::Utf8, true));
+                arrays.push(Arc::new(
+                    cells
+                        .map(|cell| match cell {
+                            Cell::Number(v) => Some(v.to_string()),
+                            Cell::Text(s) => Some(s.clone()),
+                            Cell::Missing => None,
+                        })
+                        .collect::<StringArray>(),
+                ));
+            } else {
+                fields.push(Field::new(name, DataType::Float32, true));
+                arrays.push(Arc::new(
+                    cells
+                        .map(|cell| match cell {
+                            Cell::Number(v) => Some(*v),
+                            _ => None,
+                        })
+                        .collect::<Float32Array>(),
+                ));
+            }
+        }
+        let metadata = HashMap::from([
+            ("generator".to_string(), self.generator.clone()),
+            ("spec".to_string(), serde_json::to_string(&self.spec).unwrap_or_default()),
+        ]);
+        let schema = Arc::new(Schema::new_with_metadata(fields, metadata));
+        let batch = RecordBatch::try_new(Arc::clone(&schema), arrays).map_err(arrow_err)?;
+
+        let mut writer = FileWriter::try_new(Vec::new(), &schema).map_err(arrow_err)?;
+        writer.write(&batch).map_err(arrow_err)?;
+        writer.finish().map_err(arrow_err)?;
+        writer.into_inner().map_err(arrow_err)
+    }
+
+    /// Serialize in `format`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Arrow writer fails; text formats cannot fail.
+    pub fn to_bytes(&self, format: SidecarFormat) -> Result<Vec<u8>> {
+        match format {
+            SidecarFormat::Csv => Ok(self.to_csv().into_bytes()),
+            SidecarFormat::Json => Ok(self.to_json().into_bytes()),
+            #[cfg(feature = "sidecar-arrow")]
+            SidecarFormat::Arrow => self.to_arrow(),
+        }
+    }
+
+    /// Parse a sidecar written by [`to_csv`](Self::to_csv) or
+    /// [`to_json`](Self::to_json); the format is detected from the content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is not a valid sidecar.
+    pub fn parse(text: &str) -> Result<Self> {
+        if text.trim_start().starts_with('{') {
+            serde_json::from_str(text).map_err(|e| invalid(&e.to_string()))
+        } else {
+            parse_csv(text)
+        }
+    }
+
+    /// Write the sidecar to `path` in `format`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sidecar cannot be encoded or the file cannot
+    /// be written.
+    pub fn write(&self, path: impl AsRef<Path>, format: SidecarFormat) -> Result<()> {
+        std::fs::write(path, self.to_bytes(format)?)?;
+        Ok(())
+    }
+}
+
+impl PlotSpec {
+    /// Render and save the figure as PNG at `path`, plus its [`Sidecar`] at
+    /// the same path with the format's extension (`fig.png` → `fig.csv`).
+    ///
+    /// Returns the sidecar path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the spec cannot be rendered or either file cannot
+    /// be written.
+    pub fn save_with_sidecar(
+        &self,
+        path: impl AsRef<Path>,
+        format: SidecarFormat,
+    ) -> Result<std::path::PathBuf> {
+        let path = path.as_ref();
+        let sidecar = Sidecar::from_spec(self)?;
+        crate::output::PngEncoder::write_to_file(&self.render()?, path)?;
+        let sidecar_path = path.with_extension(format.extension());
+        sidecar.write(&sidecar_path, format)?;
+        Ok(sidecar_path)
+    }
+}
+
+type Table = (&'static [&'static str], Vec<Vec<Cell>>);
+
+fn xy_table(spec: &PlotSpec) -> Result<Table> {
+    let x = spec.x_data.as_ref().ok_or(Error::EmptyData)?;
+    let y = spec.y_data.as_ref().ok_or(Error::EmptyData)?;
+    if x.len() != y.len() {
+        return Err(Error::DataLengthMismatch { x_len: x.len(), y_len: y.len() });
+    }
+    let rows = x.iter().zip(y).map(|(&x, &y)| vec![x.into(), y.into()]).collect();
+    Ok((&["x", "y"], rows))
+}
+
+fn histogram_table(spec: &PlotSpec) -> Result<Table> {
+    let data = spec.data.as_ref().ok_or(Error::EmptyData)?;
+    let (min, width, counts) = Histogram::new().data(data).build()?.bin_counts();
+    let rows = counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| {
+            let start = min + i as f32 * width;
+            vec![start.into(), (start + width).into(), count.into()]
+        })
+        .collect();
+    Ok((&["bin_start", "bin_end", "count"], rows))
+}
+
+fn heatmap_table(spec: &PlotSpec) -> Result<Table> {
+    let matrix = spec.matrix.as_ref().ok_or(Error::EmptyData)?;
+    Heatmap::new().data_2d(matrix).build()?;
+    let rows = matrix
+        .iter()
+        .enumerate()
+        .flat_map(|(r, row)| {
+            row.iter().enumerate().map(move |(c, &v)| vec![r.into(), c.into(), v.into()])
+        })
+        .collect();
+    Ok((&["row", "col", "value"], rows))
+}
+
+fn boxplot_table(spec: &PlotSpec) -> Result<Table> {
+    let groups = spec.groups.as_ref().ok_or(Error::EmptyData)?;
+    let mut rows = Vec::new();
+    for (i, group) in groups.iter().enumerate() {
+        let Some(stats) = BoxStats::from_data(group) else { continue };
+        let label = format!("Group {}", i + 1);
+        let summary = [
+            ("min", stats.min),
+            ("q1", stats.q1),
+            ("median", stats.median),
+            ("q3", stats.q3),
+            ("max", stats.max),
+        ];
+        let outliers = stats.outliers.iter().map(|&v| ("outlier", v));
+        for (stat, value) in summary.into_iter().chain(outliers) {
+            rows.push(vec![label.as_str().into(), stat.into(), value.into()]);
+        }
+    }
+    if rows.is_empty() {
+        return Err(Error::EmptyData);
+    }
+    Ok((&["group", "stat", "value"], rows))
+}
+
+fn invalid(reason: &str) -> Error {
+    Error::Rendering(format!("Invalid sidecar: {reason}"))
+}
+
+fn parse_csv(text: &str) -> Result<Sidecar> {
+    let mut generator = None;
+    let mut spec = None;
+    let mut lines = text.lines().filter(|l| !l.trim().is_empty()).peekable();
+    while let Some(comment) = lines.next_if(|l| l.starts_with('#')) {
+        let comment = comment.trim_start_matches('#').trim();
+        if let Some(json) = comment.strip_prefix("spec:") {
+            spec = Some(serde_json::from_str(json.trim()).map_err(|e| invalid(&e.to_string()))?);
+        } else if let Some(name) = comment.strip_suffix(" sidecar") {
+            generator = Some(name.to_string());
+        }
+    }
+    let spec = spec.ok_or_else(|| invalid("missing `# spec:` line"))?;
+    let columns: Vec<String> = lines
+        .next()
+        .map(split_fields)
+        .ok_or_else(|| invalid("missing header"))?
+        .into_iter()
+        .map(|(field, _)| field)
+        .collect();
+
+    let mut rows = Vec::new();
+    for line in lines {
+        let fields = split_fields(line);
+        if fields.len() != columns.len() {
+            return Err(invalid(&format!("expected {} fields in `{line}`", columns.len())));
+        }
+        rows.push(
+            fields
+                .into_iter()
+                .map(|(field, quoted)| match field.parse::<f32>() {
+                    _ if quoted => Cell::Text(field),
+                    Ok(v) => v.into(),
+                    Err(_) if field.is_empty() => Cell::Missing,
+                    Err(_) => Cell::Text(field),
+                })
+                .collect(),
+        );
+    }
+    Ok(Sidecar { generator: generator.unwrap_or_default(), spec, columns, rows })
+}
+
+/// Split one CSV line into `(field, was_quoted)` pairs.
+fn split_fields(line: &str) -> Vec<(String, bool)> {
+    let mut fields = Vec::new();
+    let (mut field, mut quoted, mut in_quotes) = (String::new(), false, false);
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => {
+                in_quotes = !in_quotes;
+                quoted = true;
+            }
+            ',' if !in_quotes => {
+                fields.push((std::mem::take(&mut field), quoted));
+                quoted = false;
+            }
+            _ => field.push(c),
+        }
+    }
+    fields.push((field, quoted));
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prompt::parse_prompt;
+
+    fn spec(prompt: &str) -> PlotSpec {
+        parse_prompt(prompt).expect("parsing should succeed")
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let spec = spec("scatter x=[1,2.5,3] y=[4,5,6] color=red title=\"Runs, seed 7\"");
+        let sidecar = Sidecar::from_spec(&spec).expect("sidecar");
+        let csv = sidecar.to_csv();
+        assert!(csv.starts_with(concat!("# trueno-viz ", env!("CARGO_PKG_VERSION"), " sidecar\n")));
+        assert!(csv.ends_with("x,y\n1,4\n2.5,5\n3,6\n"));
+
+        let restored = Sidecar::parse(&csv).expect("parse");
+        assert_eq!(restored.generator, sidecar.generator);
+        assert_eq!(restored.columns, ["x", "y"]);
+        assert_eq!(restored.rows, sidecar.rows);
+        assert_eq!(restored.spec.title.as_deref(), Some("Runs, seed 7"));
+        assert_eq!(restored.spec.color, spec.color);
+    }
+
+    #[test]
+    fn test_json_round_trip_and_missing_values() {
+        let spec = spec("line x=[0,1,2] y=[1,0,1]");
+        let mut sidecar = Sidecar::from_spec(&spec).expect("sidecar");
+        sidecar.rows[1][1] = f32::NAN.into();
+        let json = sidecar.to_json();
+        assert!(json.contains("null"));
+
+        let restored = Sidecar::parse(&json).expect("parse");
+        assert_eq!(restored.rows, sidecar.rows);
+        assert_eq!(restored.rows[1][1], Cell::Missing);
+        assert_eq!(restored.spec.plot_type, "line");
+
+        let from_csv = Sidecar::parse(&sidecar.to_csv()).expect("parse csv");
+        assert_eq!(from_csv.rows, sidecar.rows);
+    }
+
+    #[test]
+    fn test_transformed_tables() {
+        let hist = Sidecar::from_spec(&spec("histogram data=[0,1,2,3,4]")).expect("histogram");
+        let counts: usize = hist
+            .rows
+            .iter()
+            .map(|row| match row[2] {
+                Cell::Number(c) => c as usize,
+                _ => 0,
+            })
+            .sum();
+        assert_eq!(counts, 5);
+        assert_eq!(hist.rows[0][0], Cell::Number(0.0));
+
+        let heat = Sidecar::from_spec(&spec("heatmap matrix=[[1,2],[3,4]]")).expect("heatmap");
+        assert_eq!(heat.rows[2], vec![Cell::Number(1.0), Cell::Number(0.0), Cell::Number(3.0)]);
+
+        let boxes =
+            Sidecar::from_spec(&spec("boxplot groups=[[1,2,3,4,100],[5,6,7]]")).expect("boxplot");
+        assert_eq!(boxes.rows.len(), 11);
+        assert_eq!(boxes.rows[5], vec!["Group 1".into(), "outlier".into(), Cell::Number(100.0)]);
+        let csv = boxes.to_csv();
+        assert!(csv.contains("Group 2,median,6\n"));
+        assert_eq!(Sidecar::parse(&csv).expect("parse").rows, boxes.rows);
+    }
+
+    #[test]
+    fn test_invalid_specs_and_text() {
+        let mismatch = PlotSpec {
+            plot_type: "scatter".into(),
+            x_data: Some(vec![1.0, 2.0]),
+            y_data: Some(vec![1.0]),
+            ..PlotSpec::default()
+        };
+        assert!(matches!(Sidecar::from_spec(&mismatch), Err(Error::DataLengthMismatch { .. })));
+        let unknown = PlotSpec { plot_type: "sankey".into(), ..PlotSpec::default() };
+        assert!(Sidecar::from_spec(&unknown).is_err());
+        assert!(Sidecar::parse("x,y\n1,2\n").is_err());
+        assert!(Sidecar::parse("# spec: {}\nx,y\n1\n").is_err());
+    }
+
+    #[test]
+    fn test_save_with_sidecar() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let png = dir.path().join("fig.png");
+        let written = spec("histogram data=[1,2,2,3] width=200 height=150")
+            .save_with_sidecar(&png, SidecarFormat::Json)
+            .expect("save");
+        assert_eq!(written, dir.path().join("fig.json"));
+        assert!(png.is_file());
+        let text = std::fs::read_to_string(&written).expect("read sidecar");
+        let sidecar = Sidecar::parse(&text).expect("parse");
+        assert_eq!(sidecar.spec.width, 200);
+        assert!(sidecar.spec.render().is_ok());
+    }
+
+    #[cfg(feature = "sidecar-arrow")]
+    #[test]
+    fn test_arrow_file() {
+        use arrow_array::{Array, Float32Array, StringArray};
+        use arrow_schema::DataType;
+
+        let sidecar =
+            Sidecar::from_spec(&spec("boxplot groups=[[1,2,3,4,50],[5,6,7]]")).expect("sidecar");
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("fig.arrow");
+        sidecar.write(&path, SidecarFormat::Arrow).expect("write");
+
+        let file = std::fs::File::open(&path).expect("open");
+        let mut reader = arrow_ipc::reader::FileReader::try_new(file, None).expect("reader");
+        let schema = reader.schema();
+        let types: Vec<&DataType> = schema.fields().iter().map(|f| f.data_type()).collect();
+        assert_eq!(types, [&DataType::Utf8, &DataType::Utf8, &DataType::Float32]);
+        let spec_json = schema.metadata().get("spec").expect("spec metadata");
+        let restored: PlotSpec = serde_json::from_str(spec_json).expect("spec json");
+        assert_eq!(restored.plot_type, "boxplot");
+
+        let batch = reader.next().expect("one batch").expect("batch");
+        assert_eq!(batch.num_rows(), sidecar.rows.len());
+        let stats = batch.column(1).as_any().downcast_ref::<StringArray>().expect("utf8");
+        let values = batch.column(2).as_any().downcast_ref::<Float32Array>().expect("f32");
+        assert_eq!((stats.value(2), values.value(2)), ("median", 3.0));
+        assert!(stats.iter().any(|s| s == Some("outlier")));
+        assert_eq!(values.null_count(), 0);
+    }
+}
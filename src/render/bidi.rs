@@ -0,0 +1,342 @@
+//! Bidirectional text for labels.
+//!
+//! Labels are stored in logical order, the order they are typed and read.
+//! Hebrew and Arabic are written right to left, so a raster backend must
+//! reorder each line into visual order before placing glyphs left to right.
+//! [`visual_order`] implements the implicit part of the Unicode
+//! Bidirectional Algorithm (UAX #9): paragraph direction from the first
+//! strong character, weak and neutral type resolution, number runs kept
+//! left to right, and bracket mirroring. Explicit embeddings and isolates
+//! are not supported. SVG output keeps logical order and marks right to left
+//! labels with `direction="rtl"` so the viewer's text engine does the same.
+
+use std::borrow::Cow;
+
+/// Base direction of a paragraph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextDirection {
+    /// Left to right (Latin, Greek, CJK, ...).
+    #[default]
+    Ltr,
+    /// Right to left (Hebrew, Arabic, ...).
+    Rtl,
+}
+
+/// Horizontal alignment of a label within the space it is laid out in.
+///
+/// `Start` and `End` follow the label's direction: `Start` is the left edge
+/// for left to right text and the right edge for right to left text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextAlign {
+    /// Leading edge for the label's direction.
+    #[default]
+    Start,
+    /// Centered.
+    Center,
+    /// Trailing edge for the label's direction.
+    End,
+    /// Left edge regardless of direction.
+    Left,
+    /// Right edge regardless of direction.
+    Right,
+}
+
+impl TextAlign {
+    /// Offset of a label `text_width` pixels wide from the left of a `span`
+    /// pixels wide slot, for text with the given direction.
+    #[must_use]
+    pub fn offset(self, direction: TextDirection, text_width: u32, span: u32) -> u32 {
+        let free = span.saturating_sub(text_width);
+        match (self, direction) {
+            (Self::Left, _)
+            | (Self::Start, TextDirection::Ltr)
+            | (Self::End, TextDirection::Rtl) => 0,
+            (Self::Right, _)
+            | (Self::End, TextDirection::Ltr)
+            | (Self::Start, TextDirection::Rtl) => free,
+            (Self::Center, _) => free / 2,
+        }
+    }
+}
+
+/// Bidirectional character types (UAX #9), reduced to what labels need.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    /// Strong left to right.
+    L,
+    /// Strong right to left (Hebrew).
+    R,
+    /// Strong right to left (Arabic letters).
+    Al,
+    /// European number.
+    En,
+    /// Arabic number.
+    An,
+    /// Number separator or sign (`+ - , . : /`).
+    Sep,
+    /// Number terminator (`# $ % °`, currency).
+    Et,
+    /// Non-spacing mark.
+    Nsm,
+    /// Whitespace.
+    Ws,
+    /// Other neutral.
+    On,
+}
+
+fn class(ch: char) -> Class {
+    let c = ch as u32;
+    match c {
+        0x200E => Class::L,
+        0x200F | 0x0590..=0x05FF | 0x07C0..=0x085F | 0xFB1D..=0xFB4F => {
+            if super::char_columns(ch) == 0 && c != 0x200F {
+                Class::Nsm
+            } else {
+                Class::R
+            }
+        }
+        0x0660..=0x0669 | 0x066B | 0x066C => Class::An,
+        0x06F0..=0x06F9 => Class::En,
+        0x0600..=0x06FF | 0x0750..=0x077F | 0x08A0..=0x08FF | 0xFB50..=0xFDFF | 0xFE70..=0xFEFE => {
+            if super::char_columns(ch) == 0 {
+                Class::Nsm
+            } else {
+                Class::Al
+            }
+        }
+        _ if ch.is_ascii_digit() || matches!(ch, '\u{B2}' | '\u{B3}' | '\u{B9}') => Class::En,
+        _ if matches!(ch, '+' | '-' | ',' | '.' | ':' | '/' | '\u{A0}') => Class::Sep,
+        _ if matches!(ch, '#' | '$' | '%' | '\u{B0}' | '\u{B1}' | '\u{A2}'..='\u{A5}')
+            || (0x20A0..=0x20CF).contains(&c) =>
+        {
+            Class::Et
+        }
+        _ if ch.is_whitespace() => Class::Ws,
+        _ if super::char_columns(ch) == 0 && !ch.is_control() => Class::Nsm,
+        _ if ch.is_alphabetic() => Class::L,
+        _ => Class::On,
+    }
+}
+
+/// Whether a character has a strong or numeric right to left type.
+fn is_rtl_char(ch: char) -> bool {
+    matches!(class(ch), Class::R | Class::Al | Class::An)
+}
+
+/// Direction of the first strong character, left to right if there is none.
+#[must_use]
+pub fn text_direction(text: &str) -> TextDirection {
+    text.chars()
+        .map(class)
+        .find_map(|c| match c {
+            Class::L => Some(TextDirection::Ltr),
+            Class::R | Class::Al => Some(TextDirection::Rtl),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// Resolve embedding levels for one paragraph (rules W1-W7, N1-N2, I1-I2, L1).
+fn levels(classes: &mut [Class], base: u8) -> Vec<u8> {
+    let sos = if base == 1 { Class::R } else { Class::L };
+
+    // W1: marks take the type of the preceding character
+    let mut prev = sos;
+    for c in classes.iter_mut() {
+        if *c == Class::Nsm {
+            *c = prev;
+        }
+        prev = *c;
+    }
+    // W2, W3: numbers after Arabic letters are Arabic numbers
+    let mut strong = sos;
+    for c in classes.iter_mut() {
+        match *c {
+            Class::L | Class::R | Class::Al => strong = *c,
+            Class::En if strong == Class::Al => *c = Class::An,
+            _ => {}
+        }
+        if *c == Class::Al {
+            *c = Class::R;
+        }
+    }
+    // W4: a single separator between two numbers of one type joins them
+    for i in 1..classes.len().saturating_sub(1) {
+        let (before, after) = (classes[i - 1], classes[i + 1]);
+        if classes[i] == Class::Sep && before == after && matches!(before, Class::En | Class::An) {
+            classes[i] = before;
+        }
+    }
+    // W5: terminators adjacent to European numbers become numbers
+    for i in 0..classes.len() {
+        if classes[i] != Class::En {
+            continue;
+        }
+        let mut j = i;
+        while j > 0 && classes[j - 1] == Class::Et {
+            j -= 1;
+            classes[j] = Class::En;
+        }
+        let mut j = i + 1;
+        while j < classes.len() && classes[j] == Class::Et {
+            classes[j] = Class::En;
+            j += 1;
+        }
+    }
+    // W6, W7: leftover separators are neutral; numbers in a left to right
+    // context are left to right
+    let mut strong = sos;
+    for c in classes.iter_mut() {
+        match *c {
+            Class::Sep | Class::Et => *c = Class::On,
+            Class::L | Class::R => strong = *c,
+            Class::En if strong == Class::L => *c = Class::L,
+            _ => {}
+        }
+    }
+    // N1, N2: neutrals between same-direction text take that direction,
+    // otherwise the paragraph direction
+    let direction = |c: Class| match c {
+        Class::L => Some(Class::L),
+        Class::R | Class::En | Class::An => Some(Class::R),
+        _ => None,
+    };
+    let mut i = 0;
+    while i < classes.len() {
+        if direction(classes[i]).is_some() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < classes.len() && direction(classes[i]).is_none() {
+            i += 1;
+        }
+        let before = if start == 0 { sos } else { direction(classes[start - 1]).unwrap_or(sos) };
+        let after = classes.get(i).and_then(|&c| direction(c)).unwrap_or(sos);
+        let resolved = if before == after { before } else { sos };
+        classes[start..i].fill(resolved);
+    }
+    // I1, I2
+    classes
+        .iter()
+        .map(|&c| match (base, c) {
+            (0, Class::R) | (1, Class::L | Class::En | Class::An) => base + 1,
+            (0, Class::En | Class::An) => base + 2,
+            _ => base,
+        })
+        .collect()
+}
+
+/// Mirrored form of a paired bracket drawn in right to left context.
+fn mirror(ch: char) -> char {
+    match ch {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        '\u{AB}' => '\u{BB}',
+        '\u{BB}' => '\u{AB}',
+        '\u{2264}' => '\u{2265}',
+        '\u{2265}' => '\u{2264}',
+        _ => ch,
+    }
+}
+
+/// Reorder a single line from logical to visual (left to right) order.
+///
+/// Text without right to left characters is returned unchanged.
+#[must_use]
+pub fn visual_order(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(is_rtl_char) {
+        return Cow::Borrowed(text);
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let base = u8::from(text_direction(text) == TextDirection::Rtl);
+    let mut classes: Vec<Class> = chars.iter().map(|&c| class(c)).collect();
+    let original = classes.clone();
+    let mut levels = levels(&mut classes, base);
+
+    // L1: trailing whitespace returns to the paragraph level
+    for (level, class) in levels.iter_mut().zip(&original).rev() {
+        if *class != Class::Ws {
+            break;
+        }
+        *level = base;
+    }
+
+    // L2: reverse every run at or above each level, highest first
+    let mut order: Vec<usize> = (0..chars.len()).collect();
+    let highest = levels.iter().copied().max().unwrap_or(0);
+    let lowest_odd = levels.iter().copied().filter(|l| l % 2 == 1).min().unwrap_or(highest + 1);
+    for level in (lowest_odd..=highest).rev() {
+        let mut i = 0;
+        while i < order.len() {
+            if levels[order[i]] < level {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < order.len() && levels[order[i]] >= level {
+                i += 1;
+            }
+            order[start..i].reverse();
+        }
+    }
+
+    // L4: mirror brackets at right to left levels
+    Cow::Owned(
+        order
+            .into_iter()
+            .map(|i| if levels[i] % 2 == 1 { mirror(chars[i]) } else { chars[i] })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(text_direction("Latency (ms)"), TextDirection::Ltr);
+        assert_eq!(text_direction("123 \u{5E9}\u{5DC}\u{5D5}\u{5DD}"), TextDirection::Rtl);
+        assert_eq!(text_direction("\u{627}\u{644}\u{648}\u{642}\u{62A}"), TextDirection::Rtl);
+        assert_eq!(text_direction("42"), TextDirection::Ltr);
+    }
+
+    #[test]
+    fn test_visual_order_reverses_rtl_runs() {
+        // Pure Hebrew is reversed
+        assert_eq!(visual_order("\u{5D0}\u{5D1}\u{5D2}"), "\u{5D2}\u{5D1}\u{5D0}");
+        // An embedded Hebrew word in an English label
+        assert_eq!(visual_order("abc \u{5D0}\u{5D1}\u{5D2} def"), "abc \u{5D2}\u{5D1}\u{5D0} def");
+        // Plain ASCII is borrowed, not copied
+        assert!(matches!(visual_order("CPU %"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_numbers_stay_left_to_right() {
+        assert_eq!(visual_order("\u{5D0}\u{5D1} 12.5%"), "12.5% \u{5D1}\u{5D0}");
+        // Arabic-Indic digits after Arabic letters
+        assert_eq!(visual_order("\u{645} \u{661}\u{662}"), "\u{661}\u{662} \u{645}");
+    }
+
+    #[test]
+    fn test_brackets_mirror_in_rtl() {
+        assert_eq!(visual_order("\u{5D0} (\u{5D1})"), "(\u{5D1}) \u{5D0}");
+        assert_eq!(visual_order("\u{5D0} (ms)"), "(ms) \u{5D0}");
+    }
+
+    #[test]
+    fn test_align_offset() {
+        assert_eq!(TextAlign::Start.offset(TextDirection::Ltr, 10, 100), 0);
+        assert_eq!(TextAlign::Start.offset(TextDirection::Rtl, 10, 100), 90);
+        assert_eq!(TextAlign::End.offset(TextDirection::Rtl, 10, 100), 0);
+        assert_eq!(TextAlign::Center.offset(TextDirection::Rtl, 10, 100), 45);
+        assert_eq!(TextAlign::Right.offset(TextDirection::Ltr, 120, 100), 0);
+    }
+}
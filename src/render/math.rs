@@ -0,0 +1,355 @@
+//! TeX-like math notation in labels.
+//!
+//! Text between `$` delimiters is math: `^` and `_` raise or lower the next
+//! character or `{group}`, and commands such as `\alpha`, `\Delta`, `\pm`
+//! or `\infty` become symbols. Outside math, text is literal and `\$` is a
+//! dollar sign. For example `"Energy $E = mc^2$ at $\lambda_{max}$"`.
+//!
+//! Raster output draws scripts with the bitmap font, raised or lowered (and
+//! smaller at text scales above 1); [`math_to_unicode`] gives a plain-string
+//! rendering for SVG, HTML and terminal backends.
+
+use crate::color::Rgba;
+use crate::framebuffer::Framebuffer;
+
+use super::i32_px;
+use super::text::{draw_text, text_columns, text_height, GLYPH_ADVANCE};
+
+/// Vertical placement of a math run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathShift {
+    /// On the baseline.
+    Normal,
+    /// Raised.
+    Superscript,
+    /// Lowered.
+    Subscript,
+}
+
+/// A run of text with one vertical placement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MathRun {
+    /// Text with commands already replaced by symbols.
+    pub text: String,
+    /// Placement.
+    pub shift: MathShift,
+}
+
+/// Map a command name (without backslash) to its symbol.
+fn symbol(name: &str) -> Option<char> {
+    Some(match name {
+        "alpha" => '\u{3B1}',
+        "beta" => '\u{3B2}',
+        "gamma" => '\u{3B3}',
+        "delta" => '\u{3B4}',
+        "epsilon" | "varepsilon" => '\u{3B5}',
+        "eta" => '\u{3B7}',
+        "theta" => '\u{3B8}',
+        "kappa" => '\u{3BA}',
+        "lambda" => '\u{3BB}',
+        "mu" => '\u{3BC}',
+        "nu" => '\u{3BD}',
+        "xi" => '\u{3BE}',
+        "pi" => '\u{3C0}',
+        "rho" => '\u{3C1}',
+        "sigma" => '\u{3C3}',
+        "tau" => '\u{3C4}',
+        "phi" | "varphi" => '\u{3C6}',
+        "chi" => '\u{3C7}',
+        "psi" => '\u{3C8}',
+        "omega" => '\u{3C9}',
+        "Gamma" => '\u{393}',
+        "Delta" => '\u{394}',
+        "Theta" => '\u{398}',
+        "Lambda" => '\u{39B}',
+        "Xi" => '\u{39E}',
+        "Pi" => '\u{3A0}',
+        "Sigma" => '\u{3A3}',
+        "Phi" => '\u{3A6}',
+        "Psi" => '\u{3A8}',
+        "Omega" => '\u{3A9}',
+        "circ" | "degree" => '\u{B0}',
+        "pm" => '\u{B1}',
+        "cdot" => '\u{B7}',
+        "times" => '\u{D7}',
+        "to" | "rightarrow" => '\u{2192}',
+        "partial" => '\u{2202}',
+        "nabla" => '\u{2207}',
+        "sqrt" => '\u{221A}',
+        "infty" => '\u{221E}',
+        "approx" => '\u{2248}',
+        "neq" | "ne" => '\u{2260}',
+        "leq" | "le" => '\u{2264}',
+        "geq" | "ge" => '\u{2265}',
+        _ => return None,
+    })
+}
+
+/// Append `text` to the last run if it has the same shift, else start a run.
+fn push(runs: &mut Vec<MathRun>, text: &str, shift: MathShift) {
+    match runs.last_mut() {
+        Some(last) if last.shift == shift => last.text.push_str(text),
+        _ => runs.push(MathRun { text: text.to_string(), shift }),
+    }
+}
+
+/// Read a `\command` at the start of `chars`, returning its expansion.
+fn command(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut name = String::new();
+    while let Some(&c) = chars.peek() {
+        if !c.is_ascii_alphabetic() {
+            break;
+        }
+        name.push(c);
+        chars.next();
+    }
+    if name.is_empty() {
+        // Escaped single character such as `\{` or `\$`
+        return chars.next().map(String::from).unwrap_or_default();
+    }
+    // As in TeX, a space after a command name only terminates it
+    if chars.peek() == Some(&' ') {
+        chars.next();
+    }
+    symbol(&name).map_or(name, String::from)
+}
+
+/// Read a script argument: `{group}`, `\command` or a single character.
+fn script_argument(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    match chars.next() {
+        Some('{') => {
+            let mut out = String::new();
+            let mut depth = 1;
+            while let Some(c) = chars.next() {
+                match c {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    '\\' => {
+                        out.push_str(&command(chars));
+                        continue;
+                    }
+                    // Nested scripts stay at the same level
+                    '^' | '_' => continue,
+                    _ => {}
+                }
+                if depth > 0 && c != '{' {
+                    out.push(c);
+                }
+            }
+            out
+        }
+        Some('\\') => command(chars),
+        Some(c) => c.to_string(),
+        None => String::new(),
+    }
+}
+
+/// Split a label into runs, resolving `$...$` math segments.
+#[must_use]
+pub fn parse_math(text: &str) -> Vec<MathRun> {
+    let mut runs = Vec::new();
+    let mut chars = text.chars().peekable();
+    let mut in_math = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '$' => in_math = !in_math,
+            '\\' if !in_math && chars.peek() == Some(&'$') => {
+                chars.next();
+                push(&mut runs, "$", MathShift::Normal);
+            }
+            '\\' if in_math => push(&mut runs, &command(&mut chars), MathShift::Normal),
+            '^' if in_math => {
+                push(&mut runs, &script_argument(&mut chars), MathShift::Superscript);
+            }
+            '_' if in_math => push(&mut runs, &script_argument(&mut chars), MathShift::Subscript),
+            '{' | '}' if in_math => {}
+            _ => {
+                let mut buf = [0u8; 4];
+                push(&mut runs, c.encode_utf8(&mut buf), MathShift::Normal);
+            }
+        }
+    }
+    runs.retain(|r| !r.text.is_empty());
+    runs
+}
+
+/// Whether a label contains math notation.
+#[must_use]
+pub fn has_math(text: &str) -> bool {
+    text.contains('$')
+}
+
+/// Glyph scale used for scripts at a given text scale.
+fn script_scale(scale: u32) -> u32 {
+    (scale.max(1) * 2 / 3).max(1)
+}
+
+/// Pixels a superscript is raised or a subscript lowered.
+fn script_offset(scale: u32) -> u32 {
+    (text_height(scale) * 2).div_ceil(5)
+}
+
+fn run_scale(shift: MathShift, scale: u32) -> u32 {
+    match shift {
+        MathShift::Normal => scale.max(1),
+        _ => script_scale(scale),
+    }
+}
+
+/// Pixel width of a label with math notation.
+#[must_use]
+pub fn math_text_width(text: &str, scale: u32) -> u32 {
+    let runs = parse_math(text);
+    let advance: u32 = runs
+        .iter()
+        .map(|r| text_columns(&r.text) * GLYPH_ADVANCE * run_scale(r.shift, scale))
+        .sum();
+    // No spacing after the last glyph
+    let trailing = runs.last().map_or(0, |r| run_scale(r.shift, scale));
+    advance.saturating_sub(trailing)
+}
+
+/// Pixel height of a label with math notation, including script offsets.
+#[must_use]
+pub fn math_text_height(text: &str, scale: u32) -> u32 {
+    let runs = parse_math(text);
+    let raise = runs.iter().any(|r| r.shift == MathShift::Superscript);
+    let lower = runs.iter().any(|r| r.shift == MathShift::Subscript);
+    text_height(scale) + script_offset(scale) * (u32::from(raise) + u32::from(lower))
+}
+
+/// Draw a label with math notation with its top-left corner at (`x`, `y`).
+pub fn draw_math_text(fb: &mut Framebuffer, x: i32, y: i32, text: &str, scale: u32, color: Rgba) {
+    let runs = parse_math(text);
+    let offset = i32_px(script_offset(scale));
+    let base_y =
+        if runs.iter().any(|r| r.shift == MathShift::Superscript) { y + offset } else { y };
+    let base_height = i32_px(text_height(scale));
+
+    let mut pen_x = x;
+    for run in &runs {
+        let s = run_scale(run.shift, scale);
+        let h = i32_px(text_height(s));
+        let ry = match run.shift {
+            MathShift::Normal => base_y,
+            MathShift::Superscript => base_y - offset,
+            MathShift::Subscript => base_y + base_height + offset - h,
+        };
+        draw_text(fb, pen_x, ry, &run.text, s, color);
+        pen_x += i32_px(text_columns(&run.text) * GLYPH_ADVANCE * s);
+    }
+}
+
+/// Render a label as plain Unicode, using superscript and subscript
+/// characters where they exist and `^(...)`/`_(...)` otherwise.
+#[must_use]
+pub fn math_to_unicode(text: &str) -> String {
+    const SUPER: [(char, char); 13] = [
+        ('0', '\u{2070}'),
+        ('1', '\u{B9}'),
+        ('2', '\u{B2}'),
+        ('3', '\u{B3}'),
+        ('4', '\u{2074}'),
+        ('5', '\u{2075}'),
+        ('6', '\u{2076}'),
+        ('7', '\u{2077}'),
+        ('8', '\u{2078}'),
+        ('9', '\u{2079}'),
+        ('+', '\u{207A}'),
+        ('-', '\u{207B}'),
+        ('n', '\u{207F}'),
+    ];
+    let sub = |c: char| match c {
+        '0'..='9' => char::from_u32(0x2080 + (c as u32 - '0' as u32)),
+        '+' => Some('\u{208A}'),
+        '-' => Some('\u{208B}'),
+        _ => None,
+    };
+    let sup = |c: char| SUPER.iter().find(|(k, _)| *k == c).map(|(_, v)| *v);
+
+    let mut out = String::new();
+    for run in parse_math(text) {
+        let (map, marker): (&dyn Fn(char) -> Option<char>, char) = match run.shift {
+            MathShift::Normal => {
+                out.push_str(&run.text);
+                continue;
+            }
+            MathShift::Superscript => (&sup, '^'),
+            MathShift::Subscript => (&sub, '_'),
+        };
+        if let Some(mapped) = run.text.chars().map(map).collect::<Option<String>>() {
+            out.push_str(&mapped);
+        } else {
+            out.push(marker);
+            out.push('(');
+            out.push_str(&run.text);
+            out.push(')');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(text: &str, shift: MathShift) -> MathRun {
+        MathRun { text: text.to_string(), shift }
+    }
+
+    #[test]
+    fn test_parse_scripts_and_symbols() {
+        assert_eq!(
+            parse_math("$E = mc^2$"),
+            vec![run("E = mc", MathShift::Normal), run("2", MathShift::Superscript)]
+        );
+        assert_eq!(
+            parse_math("$\\lambda_{max}$ (nm)"),
+            vec![
+                run("\u{3BB}", MathShift::Normal),
+                run("max", MathShift::Subscript),
+                run(" (nm)", MathShift::Normal),
+            ]
+        );
+        assert_eq!(parse_math("$x^{\\alpha}$")[1], run("\u{3B1}", MathShift::Superscript));
+        // Outside math everything is literal
+        assert_eq!(parse_math("a_b^c \\$5"), vec![run("a_b^c $5", MathShift::Normal)]);
+        assert_eq!(parse_math("$\\unknown$"), vec![run("unknown", MathShift::Normal)]);
+    }
+
+    #[test]
+    fn test_unicode_rendering() {
+        assert_eq!(math_to_unicode("$x^2 + y_1$"), "x\u{B2} + y\u{2081}");
+        assert_eq!(math_to_unicode("$e^{i\\pi}$"), "e^(i\u{3C0})");
+        assert_eq!(math_to_unicode("$\\Delta T \\pm 0.5\\circ$C"), "\u{394}T \u{B1}0.5\u{B0}C");
+    }
+
+    #[test]
+    fn test_measurement() {
+        // "x" + superscript "2" at scale 1: two glyph advances minus spacing
+        assert_eq!(math_text_width("$x^2$", 1), 11);
+        assert_eq!(math_text_width("$\\alpha$", 1), 5);
+        assert_eq!(math_text_height("x", 1), 7);
+        assert_eq!(math_text_height("$x^2_i$", 1), 7 + 3 + 3);
+        // Scripts shrink at larger scales
+        assert_eq!(math_text_width("$x^2$", 3), 6 * 3 + 6 * 2 - 2);
+    }
+
+    #[test]
+    fn test_draw_superscript_raised() {
+        let mut fb = Framebuffer::new(20, 12).expect("framebuffer creation should succeed");
+        fb.clear(Rgba::WHITE);
+        draw_math_text(&mut fb, 0, 0, "$x^1$", 1, Rgba::BLACK);
+        let inked =
+            |x0: u32, x1: u32, y: u32| (x0..x1).any(|x| fb.get_pixel(x, y) != Some(Rgba::WHITE));
+        // The base "x" starts 3px down, the superscript "1" at the top
+        assert!(!inked(0, 5, 0) && inked(6, 11, 0));
+        assert!(inked(0, 5, 9) && !inked(6, 11, 9));
+    }
+}
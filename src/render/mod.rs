@@ -7,15 +7,31 @@
 //! - **Wu's Anti-aliased Line**: Smooth line rendering with sub-pixel accuracy
 //! - **Bresenham's Line**: Fast non-antialiased line drawing
 //! - **Midpoint Circle**: Filled and outlined circle rendering
+//! - **Bitmap Text**: 5x7 ASCII glyphs for raster labels, with Unicode-aware
+//!   measurement, wrapping and ellipsis
+//! - **Bidirectional Text**: right to left labels reordered for display (UAX #9)
+//! - **Math Labels**: TeX-like `$x^2$`, `\alpha` and subscripts in titles
 //!
 //! # References
 //!
 //! - Wu, X. (1991). "An Efficient Antialiasing Technique." SIGGRAPH '91.
 //! - Bresenham, J. E. (1965). "Algorithm for computer control of a digital plotter."
 
+mod bidi;
+mod math;
 mod primitives;
+mod text;
 
+pub use bidi::{text_direction, visual_order, TextAlign, TextDirection};
+pub use math::{
+    draw_math_text, has_math, math_text_height, math_text_width, math_to_unicode, parse_math,
+    MathRun, MathShift,
+};
 pub use primitives::{
     draw_circle, draw_circle_outline, draw_line, draw_line_aa, draw_point, draw_rect,
-    draw_rect_outline, i32_px, Drawable,
+    draw_rect_outline, draw_zigzag, i32_px, Drawable,
+};
+pub use text::{
+    char_columns, draw_text, ellipsize, text_columns, text_height, text_width, wrap_text,
+    TextStyle, GLYPH_ADVANCE, GLYPH_HEIGHT, GLYPH_WIDTH,
 };
@@ -322,6 +322,42 @@ pub fn draw_point(fb: &mut Framebuffer, x: f32, y: f32, size: f32, color: Rgba)
     draw_circle(fb, x as i32, y as i32, radius, color);
 }
 
+/// Draw a zig-zag line from `(x0, y0)` to `(x1, y1)`.
+///
+/// Used for axis-break markers: teeth alternate `amplitude` pixels either
+/// side of the straight segment, one tooth every `period` pixels.
+pub fn draw_zigzag(
+    fb: &mut Framebuffer,
+    (x0, y0): (f32, f32),
+    (x1, y1): (f32, f32),
+    amplitude: f32,
+    period: f32,
+    color: Rgba,
+) {
+    let (dx, dy) = (x1 - x0, y1 - y0);
+    let len = dx.hypot(dy);
+    if len < f32::EPSILON {
+        return;
+    }
+    // Unit normal to the segment
+    let (nx, ny) = (-dy / len, dx / len);
+    let teeth = ((len / period.max(1.0)).round() as usize).max(1) * 2;
+    let mut prev = (x0, y0);
+    for i in 1..=teeth {
+        let t = i as f32 / teeth as f32;
+        let offset = if i == teeth {
+            0.0
+        } else if i % 2 == 1 {
+            amplitude
+        } else {
+            -amplitude
+        };
+        let next = (x0 + dx * t + nx * offset, y0 + dy * t + ny * offset);
+        draw_line_aa(fb, prev.0, prev.1, next.0, next.1, color);
+        prev = next;
+    }
+}
+
 /// Helper to draw a horizontal line (used by filled circle).
 #[inline]
 fn draw_horizontal_line(fb: &mut Framebuffer, x1: i32, x2: i32, y: i32, color: Rgba) {
@@ -528,4 +564,18 @@ mod tests {
         // Zero radius should just draw a single point
         assert_eq!(fb.get_pixel(50, 50), Some(Rgba::RED));
     }
+
+    #[test]
+    fn test_zigzag_stays_within_amplitude() {
+        let mut fb = Framebuffer::new(100, 40).expect("framebuffer creation should succeed");
+        fb.clear(Rgba::WHITE);
+
+        draw_zigzag(&mut fb, (10.0, 20.0), (90.0, 20.0), 4.0, 10.0, Rgba::BLACK);
+
+        let touched: Vec<u32> = (0..40)
+            .filter(|&y| (0..100).any(|x| fb.get_pixel(x, y) != Some(Rgba::WHITE)))
+            .collect();
+        assert!(touched.contains(&16) && touched.contains(&24));
+        assert!(touched.iter().all(|&y| (15..=25).contains(&y)));
+    }
 }
@@ -0,0 +1,563 @@
+//! Bitmap text rasterization.
+//!
+//! Provides a compact 5x7 ASCII bitmap font so raster outputs (PNG, terminal)
+//! can carry short labels without a font-loading dependency. Greek letters
+//! and a few math symbols have glyphs too; other characters outside
+//! printable ASCII are drawn as `?`.
+//!
+//! Measurement is Unicode-aware: combining marks and other zero-width code
+//! points take no space, and East Asian wide characters (CJK, Hangul,
+//! fullwidth forms, emoji) take two cells, as in a terminal. [`wrap_text`]
+//! and [`ellipsize`] use the same widths to keep titles and labels inside
+//! the figure.
+
+use crate::color::Rgba;
+use crate::framebuffer::Framebuffer;
+
+use super::bidi::visual_order;
+use super::i32_px;
+
+/// Glyph width in pixels (at scale 1).
+pub const GLYPH_WIDTH: u32 = 5;
+
+/// Glyph height in pixels (at scale 1).
+pub const GLYPH_HEIGHT: u32 = 7;
+
+/// Horizontal advance per character in pixels (at scale 1), including spacing.
+pub const GLYPH_ADVANCE: u32 = GLYPH_WIDTH + 1;
+
+/// 5x7 glyphs for ASCII 32..=126. Each row stores 5 bits, MSB on the left.
+const FONT_5X7: [[u8; 7]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // space
+    [0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x04], // !
+    [0x0A, 0x0A, 0x0A, 0x00, 0x00, 0x00, 0x00], // "
+    [0x0A, 0x0A, 0x1F, 0x0A, 0x1F, 0x0A, 0x0A], // #
+    [0x04, 0x0F, 0x14, 0x0E, 0x05, 0x1E, 0x04], // $
+    [0x18, 0x19, 0x02, 0x04, 0x08, 0x13, 0x03], // %
+    [0x0C, 0x12, 0x14, 0x08, 0x15, 0x12, 0x0D], // &
+    [0x04, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00], // '
+    [0x02, 0x04, 0x08, 0x08, 0x08, 0x04, 0x02], // (
+    [0x08, 0x04, 0x02, 0x02, 0x02, 0x04, 0x08], // )
+    [0x00, 0x04, 0x15, 0x0E, 0x15, 0x04, 0x00], // *
+    [0x00, 0x04, 0x04, 0x1F, 0x04, 0x04, 0x00], // +
+    [0x00, 0x00, 0x00, 0x00, 0x0C, 0x04, 0x08], // ,
+    [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00], // -
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C], // .
+    [0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00], // /
+    [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E], // 0
+    [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E], // 1
+    [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F], // 2
+    [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E], // 3
+    [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02], // 4
+    [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E], // 5
+    [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E], // 6
+    [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08], // 7
+    [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E], // 8
+    [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C], // 9
+    [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00], // :
+    [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x04, 0x08], // ;
+    [0x02, 0x04, 0x08, 0x10, 0x08, 0x04, 0x02], // <
+    [0x00, 0x00, 0x1F, 0x00, 0x1F, 0x00, 0x00], // =
+    [0x08, 0x04, 0x02, 0x01, 0x02, 0x04, 0x08], // >
+    [0x0E, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04], // ?
+    [0x0E, 0x11, 0x01, 0x0D, 0x15, 0x15, 0x0E], // @
+    [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11], // A
+    [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E], // B
+    [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E], // C
+    [0x1C, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1C], // D
+    [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F], // E
+    [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10], // F
+    [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F], // G
+    [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11], // H
+    [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E], // I
+    [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C], // J
+    [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11], // K
+    [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F], // L
+    [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11], // M
+    [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11], // N
+    [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E], // O
+    [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10], // P
+    [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D], // Q
+    [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11], // R
+    [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E], // S
+    [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04], // T
+    [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E], // U
+    [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04], // V
+    [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A], // W
+    [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11], // X
+    [0x11, 0x11, 0x11, 0x0A, 0x04, 0x04, 0x04], // Y
+    [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F], // Z
+    [0x0E, 0x08, 0x08, 0x08, 0x08, 0x08, 0x0E], // [
+    [0x00, 0x10, 0x08, 0x04, 0x02, 0x01, 0x00], // \
+    [0x0E, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0E], // ]
+    [0x04, 0x0A, 0x11, 0x00, 0x00, 0x00, 0x00], // ^
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F], // _
+    [0x08, 0x04, 0x02, 0x00, 0x00, 0x00, 0x00], // `
+    [0x00, 0x00, 0x0E, 0x01, 0x0F, 0x11, 0x0F], // a
+    [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x1E], // b
+    [0x00, 0x00, 0x0E, 0x10, 0x10, 0x11, 0x0E], // c
+    [0x01, 0x01, 0x0D, 0x13, 0x11, 0x11, 0x0F], // d
+    [0x00, 0x00, 0x0E, 0x11, 0x1F, 0x10, 0x0E], // e
+    [0x06, 0x09, 0x08, 0x1C, 0x08, 0x08, 0x08], // f
+    [0x00, 0x0F, 0x11, 0x11, 0x0F, 0x01, 0x0E], // g
+    [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x11], // h
+    [0x04, 0x00, 0x0C, 0x04, 0x04, 0x04, 0x0E], // i
+    [0x02, 0x00, 0x06, 0x02, 0x02, 0x12, 0x0C], // j
+    [0x10, 0x10, 0x12, 0x14, 0x18, 0x14, 0x12], // k
+    [0x0C, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E], // l
+    [0x00, 0x00, 0x1A, 0x15, 0x15, 0x11, 0x11], // m
+    [0x00, 0x00, 0x16, 0x19, 0x11, 0x11, 0x11], // n
+    [0x00, 0x00, 0x0E, 0x11, 0x11, 0x11, 0x0E], // o
+    [0x00, 0x00, 0x1E, 0x11, 0x1E, 0x10, 0x10], // p
+    [0x00, 0x00, 0x0D, 0x13, 0x0F, 0x01, 0x01], // q
+    [0x00, 0x00, 0x16, 0x19, 0x10, 0x10, 0x10], // r
+    [0x00, 0x00, 0x0E, 0x10, 0x0E, 0x01, 0x1E], // s
+    [0x08, 0x08, 0x1C, 0x08, 0x08, 0x09, 0x06], // t
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x13, 0x0D], // u
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x0A, 0x04], // v
+    [0x00, 0x00, 0x11, 0x11, 0x15, 0x15, 0x0A], // w
+    [0x00, 0x00, 0x11, 0x0A, 0x04, 0x0A, 0x11], // x
+    [0x00, 0x00, 0x11, 0x11, 0x0F, 0x01, 0x0E], // y
+    [0x00, 0x00, 0x1F, 0x02, 0x04, 0x08, 0x1F], // z
+    [0x02, 0x04, 0x04, 0x08, 0x04, 0x04, 0x02], // {
+    [0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04], // |
+    [0x08, 0x04, 0x04, 0x02, 0x04, 0x04, 0x08], // }
+    [0x00, 0x00, 0x08, 0x15, 0x02, 0x00, 0x00], // ~
+];
+
+/// 5x7 glyphs for Greek letters and math symbols used in scientific labels.
+const EXTRA_5X7: [(char, [u8; 7]); 43] = [
+    ('\u{3B1}', [0x00, 0x00, 0x0D, 0x12, 0x12, 0x12, 0x0D]), // alpha
+    ('\u{3B2}', [0x0C, 0x12, 0x1C, 0x12, 0x12, 0x1C, 0x10]), // beta
+    ('\u{3B3}', [0x00, 0x00, 0x11, 0x0A, 0x04, 0x04, 0x04]), // gamma
+    ('\u{3B4}', [0x06, 0x08, 0x04, 0x0E, 0x11, 0x11, 0x0E]), // delta
+    ('\u{3B5}', [0x00, 0x00, 0x0F, 0x10, 0x1E, 0x10, 0x0F]), // epsilon
+    ('\u{3B7}', [0x00, 0x00, 0x16, 0x19, 0x11, 0x11, 0x01]), // eta
+    ('\u{3B8}', [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x0E]), // theta
+    ('\u{3BA}', [0x00, 0x00, 0x12, 0x14, 0x18, 0x14, 0x12]), // kappa
+    ('\u{3BB}', [0x08, 0x04, 0x04, 0x0A, 0x0A, 0x11, 0x11]), // lambda
+    ('\u{3BC}', [0x00, 0x00, 0x11, 0x11, 0x13, 0x1D, 0x10]), // mu
+    ('\u{3BD}', [0x00, 0x00, 0x11, 0x11, 0x0A, 0x0A, 0x04]), // nu
+    ('\u{3BE}', [0x1F, 0x08, 0x0E, 0x08, 0x10, 0x0E, 0x01]), // xi
+    ('\u{3C0}', [0x00, 0x00, 0x1F, 0x0A, 0x0A, 0x0A, 0x0A]), // pi
+    ('\u{3C1}', [0x00, 0x00, 0x0E, 0x11, 0x11, 0x1E, 0x10]), // rho
+    ('\u{3C3}', [0x00, 0x00, 0x0F, 0x12, 0x11, 0x11, 0x0E]), // sigma
+    ('\u{3C4}', [0x00, 0x00, 0x1F, 0x04, 0x04, 0x04, 0x03]), // tau
+    ('\u{3C6}', [0x04, 0x04, 0x0E, 0x15, 0x15, 0x0E, 0x04]), // phi
+    ('\u{3C7}', [0x00, 0x00, 0x11, 0x0A, 0x04, 0x0A, 0x11]), // chi
+    ('\u{3C8}', [0x04, 0x15, 0x15, 0x15, 0x0E, 0x04, 0x04]), // psi
+    ('\u{3C9}', [0x00, 0x00, 0x0A, 0x11, 0x15, 0x15, 0x0A]), // omega
+    ('\u{393}', [0x1F, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10]), // Gamma
+    ('\u{394}', [0x04, 0x04, 0x0A, 0x0A, 0x11, 0x11, 0x1F]), // Delta
+    ('\u{398}', [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x0E]), // Theta
+    ('\u{39B}', [0x04, 0x0A, 0x0A, 0x11, 0x11, 0x11, 0x11]), // Lambda
+    ('\u{39E}', [0x1F, 0x00, 0x00, 0x0E, 0x00, 0x00, 0x1F]), // Xi
+    ('\u{3A0}', [0x1F, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11]), // Pi
+    ('\u{3A3}', [0x1F, 0x10, 0x08, 0x04, 0x08, 0x10, 0x1F]), // Sigma
+    ('\u{3A6}', [0x04, 0x0E, 0x15, 0x15, 0x15, 0x0E, 0x04]), // Phi
+    ('\u{3A8}', [0x15, 0x15, 0x15, 0x0E, 0x04, 0x04, 0x04]), // Psi
+    ('\u{3A9}', [0x0E, 0x11, 0x11, 0x11, 0x0A, 0x0A, 0x1B]), // Omega
+    ('\u{B0}', [0x0C, 0x12, 0x12, 0x0C, 0x00, 0x00, 0x00]),  // degree
+    ('\u{B1}', [0x04, 0x04, 0x1F, 0x04, 0x04, 0x00, 0x1F]),  // plus-minus
+    ('\u{B7}', [0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00]),  // middle dot
+    ('\u{D7}', [0x00, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x00]),  // times
+    ('\u{2192}', [0x00, 0x04, 0x02, 0x1F, 0x02, 0x04, 0x00]), // right arrow
+    ('\u{2202}', [0x0E, 0x01, 0x01, 0x0F, 0x11, 0x11, 0x0E]), // partial
+    ('\u{2207}', [0x1F, 0x11, 0x11, 0x0A, 0x0A, 0x04, 0x04]), // nabla
+    ('\u{221A}', [0x07, 0x04, 0x04, 0x04, 0x14, 0x0C, 0x04]), // square root
+    ('\u{221E}', [0x00, 0x00, 0x0A, 0x15, 0x15, 0x0A, 0x00]), // infinity
+    ('\u{2248}', [0x00, 0x08, 0x15, 0x02, 0x08, 0x15, 0x02]), // approx
+    ('\u{2260}', [0x00, 0x01, 0x1F, 0x04, 0x1F, 0x10, 0x00]), // not equal
+    ('\u{2264}', [0x02, 0x04, 0x08, 0x04, 0x02, 0x00, 0x0E]), // less or equal
+    ('\u{2265}', [0x08, 0x04, 0x02, 0x04, 0x08, 0x00, 0x0E]), // greater or equal
+];
+
+/// Look up the glyph rows for a character.
+fn glyph(ch: char) -> &'static [u8; 7] {
+    let code = ch as u32;
+    if (32..=126).contains(&code) {
+        return &FONT_5X7[(code - 32) as usize];
+    }
+    EXTRA_5X7
+        .iter()
+        .find(|(c, _)| *c == ch)
+        .map_or(&FONT_5X7[usize::from(b'?' - 32)], |(_, rows)| rows)
+}
+
+/// Ellipsis appended by [`ellipsize`] (ASCII so the bitmap font can draw it).
+const ELLIPSIS: &str = "...";
+
+/// Number of glyph cells a character occupies: 0, 1 or 2.
+#[must_use]
+pub fn char_columns(ch: char) -> u32 {
+    let c = ch as u32;
+    let zero_width = matches!(c,
+        0x0300..=0x036F     // combining diacritical marks
+        | 0x0483..=0x0489
+        | 0x0591..=0x05BD
+        | 0x0610..=0x061A
+        | 0x064B..=0x065F
+        | 0x0E31 | 0x0E34..=0x0E3A | 0x0E47..=0x0E4E
+        | 0x1AB0..=0x1AFF
+        | 0x1DC0..=0x1DFF
+        | 0x200B..=0x200F   // zero-width space, joiners, direction marks
+        | 0x202A..=0x202E
+        | 0x2060..=0x2064
+        | 0x20D0..=0x20FF
+        | 0xFE00..=0xFE0F   // variation selectors
+        | 0xFE20..=0xFE2F
+        | 0xFEFF
+        | 0xE0100..=0xE01EF)
+        || ch.is_control();
+    if zero_width {
+        return 0;
+    }
+    let wide = matches!(c,
+        0x1100..=0x115F     // Hangul Jamo
+        | 0x2E80..=0x303E   // CJK radicals, punctuation
+        | 0x3041..=0x33FF   // kana, CJK compatibility
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF   // CJK unified ideographs
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3   // Hangul syllables
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60   // fullwidth forms
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F // emoji
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x3FFFD);
+    if wide {
+        2
+    } else {
+        1
+    }
+}
+
+/// Number of glyph cells a string occupies.
+#[must_use]
+pub fn text_columns(text: &str) -> u32 {
+    text.chars().map(char_columns).sum()
+}
+
+/// Measure the pixel width of a string rendered at the given scale.
+#[must_use]
+pub fn text_width(text: &str, scale: u32) -> u32 {
+    let columns = text_columns(text);
+    if columns == 0 {
+        return 0;
+    }
+    (columns * GLYPH_ADVANCE - 1) * scale.max(1)
+}
+
+/// Most glyph cells that fit in `max_width` pixels (the last glyph needs no
+/// trailing spacing).
+fn fit_columns(max_width: u32, scale: u32) -> u32 {
+    let scale = scale.max(1);
+    (max_width + scale) / (GLYPH_ADVANCE * scale)
+}
+
+/// Shorten `text` with a trailing `...` so it fits in `max_width` pixels.
+///
+/// Text that already fits is returned unchanged; if not even the ellipsis
+/// fits, the result is empty.
+#[must_use]
+pub fn ellipsize(text: &str, max_width: u32, scale: u32) -> String {
+    if text_width(text, scale) <= max_width {
+        return text.to_string();
+    }
+    let Some(budget) = fit_columns(max_width, scale).checked_sub(text_columns(ELLIPSIS)) else {
+        return String::new();
+    };
+    let mut used = 0;
+    let mut out: String = text
+        .chars()
+        .take_while(|&ch| {
+            used += char_columns(ch);
+            used <= budget
+        })
+        .collect();
+    let trimmed = out.trim_end().len();
+    out.truncate(trimmed);
+    out.push_str(ELLIPSIS);
+    out
+}
+
+/// Greedily wrap `text` into lines no wider than `max_width` pixels.
+///
+/// Lines break at whitespace; words wider than a line, and runs of wide
+/// (CJK) characters, break between characters. With `max_lines` set, the
+/// last kept line is ellipsized if text remains.
+#[must_use]
+pub fn wrap_text(text: &str, max_width: u32, scale: u32, max_lines: Option<usize>) -> Vec<String> {
+    let cells = fit_columns(max_width, scale);
+    if cells == 0 {
+        return Vec::new();
+    }
+
+    let mut lines: Vec<String> = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        let mut line_cols = 0;
+        for word in paragraph.split_whitespace() {
+            let word_cols = text_columns(word);
+            let sep = u32::from(!line.is_empty());
+            if line_cols + sep + word_cols <= cells {
+                if sep == 1 {
+                    line.push(' ');
+                }
+                line.push_str(word);
+                line_cols += sep + word_cols;
+                continue;
+            }
+            if word_cols <= cells && !word.chars().any(|c| char_columns(c) == 2) {
+                lines.push(std::mem::take(&mut line));
+                line.push_str(word);
+                line_cols = word_cols;
+                continue;
+            }
+            // Break inside the word, continuing the current line
+            if !line.is_empty() {
+                if line_cols + 1 < cells {
+                    line.push(' ');
+                    line_cols += 1;
+                } else {
+                    lines.push(std::mem::take(&mut line));
+                    line_cols = 0;
+                }
+            }
+            for ch in word.chars() {
+                let w = char_columns(ch);
+                if line_cols + w > cells && !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                    line_cols = 0;
+                }
+                line.push(ch);
+                line_cols += w;
+            }
+        }
+        lines.push(line);
+    }
+
+    if let Some(max) = max_lines {
+        if lines.len() > max {
+            lines.truncate(max);
+            if let Some(last) = lines.last_mut() {
+                // Mark the cut even when the kept line itself fits
+                *last = ellipsize(&format!("{last}{ELLIPSIS}"), max_width, scale);
+            }
+        }
+    }
+    lines
+}
+
+/// Measure the pixel height of a line of text rendered at the given scale.
+#[must_use]
+pub fn text_height(scale: u32) -> u32 {
+    GLYPH_HEIGHT * scale.max(1)
+}
+
+/// Draw text with its top-left corner at (`x`, `y`).
+///
+/// Each font pixel becomes a `scale`×`scale` block. Pixels are alpha-blended
+/// so translucent colors composite over existing content. Right to left
+/// text is reordered with [`visual_order`](super::visual_order) first.
+pub fn draw_text(fb: &mut Framebuffer, x: i32, y: i32, text: &str, scale: u32, color: Rgba) {
+    let scale = i32_px(scale.max(1));
+    let mut pen_x = x;
+
+    for ch in visual_order(text).chars() {
+        let columns = i32_px(char_columns(ch));
+        if columns == 0 {
+            continue;
+        }
+        let rows = glyph(ch);
+        for (row, bits) in (0_i32..).zip(rows.iter()) {
+            for col in 0..i32_px(GLYPH_WIDTH) {
+                if bits & (0x10 >> col) == 0 {
+                    continue;
+                }
+                let px = pen_x + col * scale;
+                let py = y + row * scale;
+                for dy in 0..scale {
+                    for dx in 0..scale {
+                        let (sx, sy) = (px + dx, py + dy);
+                        if sx >= 0 && sy >= 0 {
+                            fb.blend_pixel(sx as u32, sy as u32, color);
+                        }
+                    }
+                }
+            }
+        }
+        pen_x += i32_px(GLYPH_ADVANCE) * scale * columns;
+    }
+}
+
+/// Scale and color of plot labels.
+///
+/// [`TextStyle::current`] takes both from the crate-wide
+/// [`Style`](crate::style::Style) (`font_scale`, `text_color`). Plots measure
+/// and draw their labels and tick text through it so a styled font scale
+/// also moves the layout around the text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextStyle {
+    /// Bitmap font scale (at least 1).
+    pub scale: u32,
+    /// Text color.
+    pub color: Rgba,
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        Self { scale: 1, color: Rgba::rgb(60, 60, 60) }
+    }
+}
+
+impl TextStyle {
+    /// Label style of the process-wide [`Style`](crate::style::Style).
+    #[must_use]
+    pub fn current() -> Self {
+        let style = crate::style::current();
+        Self { scale: style.font_scale.max(1), color: style.text_color }
+    }
+
+    /// The same scale in another color, e.g. for labels colored by series.
+    #[must_use]
+    pub const fn with_color(self, color: Rgba) -> Self {
+        Self { color, ..self }
+    }
+
+    /// Pixel width of `text` at this scale.
+    #[must_use]
+    pub fn width(&self, text: &str) -> u32 {
+        text_width(text, self.scale)
+    }
+
+    /// Pixel height of a line at this scale.
+    #[must_use]
+    pub fn height(&self) -> u32 {
+        text_height(self.scale)
+    }
+
+    /// Shorten `text` to fit in `max_width` pixels at this scale.
+    #[must_use]
+    pub fn ellipsize(&self, text: &str, max_width: u32) -> String {
+        ellipsize(text, max_width, self.scale)
+    }
+
+    /// SVG font size for text set at `base` at scale 1.
+    #[must_use]
+    pub fn font_size(&self, base: f32) -> f32 {
+        base * self.scale as f32
+    }
+
+    /// Draw `text` with its top-left corner at (`x`, `y`).
+    pub fn draw(&self, fb: &mut Framebuffer, x: i32, y: i32, text: &str) {
+        draw_text(fb, x, y, text, self.scale, self.color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_width() {
+        assert_eq!(text_width("", 1), 0);
+        assert_eq!(text_width("A", 1), 5);
+        assert_eq!(text_width("AB", 1), 11);
+        assert_eq!(text_width("AB", 2), 22);
+    }
+
+    #[test]
+    fn test_text_height() {
+        assert_eq!(text_height(1), 7);
+        assert_eq!(text_height(3), 21);
+        assert_eq!(text_height(0), 7);
+    }
+
+    #[test]
+    fn test_text_style_scales_measurement() {
+        let font = TextStyle { scale: 2, color: Rgba::BLACK };
+        assert_eq!(font.width("AB"), text_width("AB", 2));
+        assert_eq!(font.height(), 14);
+        assert!((font.font_size(11.0) - 22.0).abs() < f32::EPSILON);
+        let red = font.with_color(Rgba::RED);
+        assert_eq!((red.scale, red.color), (2, Rgba::RED));
+        assert_eq!(TextStyle::default().scale, 1);
+    }
+
+    #[test]
+    fn test_draw_text_sets_pixels() {
+        let mut fb = Framebuffer::new(20, 10).expect("framebuffer creation should succeed");
+        fb.clear(Rgba::WHITE);
+        draw_text(&mut fb, 0, 0, "I", 1, Rgba::BLACK);
+
+        // Top bar of 'I' spans columns 1..=3
+        assert_eq!(fb.get_pixel(2, 0), Some(Rgba::BLACK));
+        assert_eq!(fb.get_pixel(0, 0), Some(Rgba::WHITE));
+    }
+
+    #[test]
+    fn test_draw_text_clips_offscreen() {
+        let mut fb = Framebuffer::new(10, 10).expect("framebuffer creation should succeed");
+        fb.clear(Rgba::WHITE);
+        draw_text(&mut fb, -20, -20, "Hello", 2, Rgba::BLACK);
+        draw_text(&mut fb, 8, 8, "Hello", 2, Rgba::BLACK);
+        assert_eq!(fb.get_pixel(0, 0), Some(Rgba::WHITE));
+    }
+
+    #[test]
+    fn test_non_ascii_falls_back() {
+        assert_eq!(glyph('\u{00e9}'), glyph('?'));
+        assert_ne!(glyph('\u{3B1}'), glyph('?'));
+    }
+
+    #[test]
+    fn test_unicode_widths() {
+        assert_eq!(text_columns("abc"), 3);
+        // e + combining acute accent
+        assert_eq!(text_columns("e\u{0301}"), 1);
+        assert_eq!(text_columns("\u{6F22}\u{5B57}"), 4);
+        assert_eq!(text_width("\u{6F22}", 1), 11);
+        assert_eq!(text_columns("a\u{200B}b"), 2);
+    }
+
+    #[test]
+    fn test_ellipsize() {
+        assert_eq!(ellipsize("short", 100, 1), "short");
+        // 10 cells: 7 of text plus "..."
+        assert_eq!(ellipsize("a very long title", 60, 1), "a very...");
+        assert_eq!(ellipsize("\u{6F22}\u{5B57}\u{6F22}\u{5B57}", 36, 1), "\u{6F22}...");
+        assert_eq!(ellipsize("abcdef", 10, 1), "");
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        let lines = wrap_text("the quick brown fox jumps", 59, 1, None);
+        assert_eq!(lines, vec!["the quick", "brown fox", "jumps"]);
+        assert!(lines.iter().all(|l| text_width(l, 1) <= 59));
+
+        let lines = wrap_text("supercalifragilistic", 35, 1, None);
+        assert_eq!(lines, vec!["superc", "alifra", "gilist", "ic"]);
+
+        let cjk = "\u{6F22}\u{5B57}\u{6F22}\u{5B57}\u{6F22}";
+        assert_eq!(wrap_text(cjk, 47, 1, None).len(), 2);
+
+        let lines = wrap_text("one two three four five six", 59, 1, Some(2));
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].ends_with("..."));
+        assert!(text_width(&lines[1], 1) <= 59);
+    }
+
+    #[test]
+    fn test_draw_text_skips_zero_width() {
+        let mut a = Framebuffer::new(20, 10).expect("framebuffer creation should succeed");
+        let mut b = Framebuffer::new(20, 10).expect("framebuffer creation should succeed");
+        a.clear(Rgba::WHITE);
+        b.clear(Rgba::WHITE);
+        draw_text(&mut a, 0, 0, "AB", 1, Rgba::BLACK);
+        draw_text(&mut b, 0, 0, "A\u{200B}B", 1, Rgba::BLACK);
+        assert_eq!(a.pixels(), b.pixels());
+    }
+}
@@ -148,12 +148,244 @@ impl Scale<f32, f32> for LogScale {
     }
 }
 
+/// Piecewise linear scale with omitted domain ranges (axis breaks).
+///
+/// Each break removes a value interval from the axis and leaves a fixed
+/// pixel gap in its place; the remaining range is shared by the kept
+/// segments in proportion to their domain length, so a few extreme outliers
+/// do not flatten the rest of the data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrokenScale {
+    /// Kept `(domain, range)` segments in domain order.
+    segments: Vec<((f32, f32), (f32, f32))>,
+    /// Omitted domain intervals, sorted and merged.
+    breaks: Vec<(f32, f32)>,
+    domain_min: f32,
+    domain_max: f32,
+    range_min: f32,
+    range_max: f32,
+}
+
+impl BrokenScale {
+    /// Create a broken scale. Breaks outside the domain are ignored and
+    /// overlapping breaks are merged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the domain is empty or reversed, a break is not a
+    /// finite increasing interval, or the gaps leave no room for data.
+    pub fn new(
+        domain: (f32, f32),
+        range: (f32, f32),
+        breaks: &[(f32, f32)],
+        gap: f32,
+    ) -> Result<Self> {
+        if !domain.0.is_finite() || !domain.1.is_finite() || domain.1 - domain.0 <= f32::EPSILON {
+            return Err(Error::ScaleDomain("Domain min must be below max".to_string()));
+        }
+        let mut sorted: Vec<(f32, f32)> = Vec::with_capacity(breaks.len());
+        for &(from, to) in breaks {
+            if !from.is_finite() || !to.is_finite() || to <= from {
+                return Err(Error::ScaleDomain(format!("Invalid axis break: {from}..{to}")));
+            }
+            let (from, to) = (from.max(domain.0), to.min(domain.1));
+            if to > from && from > domain.0 && to < domain.1 {
+                sorted.push((from, to));
+            }
+        }
+        sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut merged: Vec<(f32, f32)> = Vec::with_capacity(sorted.len());
+        for (from, to) in sorted {
+            match merged.last_mut() {
+                Some(last) if from <= last.1 => last.1 = last.1.max(to),
+                _ => merged.push((from, to)),
+            }
+        }
+
+        let mut kept = Vec::with_capacity(merged.len() + 1);
+        let mut start = domain.0;
+        for &(from, to) in &merged {
+            kept.push((start, from));
+            start = to;
+        }
+        kept.push((start, domain.1));
+
+        let span = range.1 - range.0;
+        let gap = gap.max(0.0).copysign(span);
+        let usable = span - gap * merged.len() as f32;
+        if usable.abs() < f32::EPSILON || usable.signum() != span.signum() {
+            return Err(Error::ScaleDomain("Axis break gaps exceed the range".to_string()));
+        }
+        let kept_total: f32 = kept.iter().map(|(a, b)| b - a).sum();
+
+        let mut segments = Vec::with_capacity(kept.len());
+        let mut cursor = range.0;
+        for (a, b) in kept {
+            let len = usable * (b - a) / kept_total;
+            segments.push(((a, b), (cursor, cursor + len)));
+            cursor += len + gap;
+        }
+
+        Ok(Self {
+            segments,
+            breaks: merged,
+            domain_min: domain.0,
+            domain_max: domain.1,
+            range_min: range.0,
+            range_max: range.1,
+        })
+    }
+
+    /// Omitted domain intervals after merging.
+    #[must_use]
+    pub fn breaks(&self) -> &[(f32, f32)] {
+        &self.breaks
+    }
+
+    /// Range interval of each gap, in axis order.
+    #[must_use]
+    pub fn gaps(&self) -> Vec<(f32, f32)> {
+        self.segments.windows(2).map(|w| (w[0].1 .1, w[1].1 .0)).collect()
+    }
+
+    /// Whether a value falls inside an omitted interval.
+    #[must_use]
+    pub fn is_omitted(&self, value: f32) -> bool {
+        self.breaks.iter().any(|&(from, to)| value > from && value < to)
+    }
+}
+
+impl Scale<f32, f32> for BrokenScale {
+    /// Values inside a break map to the middle of its gap.
+    fn scale(&self, value: f32) -> f32 {
+        let last = self.segments.len() - 1;
+        for (i, &((d0, d1), (r0, r1))) in self.segments.iter().enumerate() {
+            if value <= d1 || i == last {
+                if value < d0 && i > 0 {
+                    let prev_end = self.segments[i - 1].1 .1;
+                    return (prev_end + r0) / 2.0;
+                }
+                return r0 + (value - d0) / (d1 - d0) * (r1 - r0);
+            }
+        }
+        self.range_max
+    }
+
+    fn domain(&self) -> (f32, f32) {
+        (self.domain_min, self.domain_max)
+    }
+
+    fn range(&self) -> (f32, f32) {
+        (self.range_min, self.range_max)
+    }
+}
+
+/// Band scale for mapping discrete categories to evenly spaced bands.
+///
+/// Splits a continuous range into `n` equal bands with optional inner/outer
+/// padding, as used for bar charts and categorical grid axes.
+#[derive(Debug, Clone)]
+pub struct BandScale {
+    categories: Vec<String>,
+    range_min: f32,
+    range_max: f32,
+    padding: f32,
+}
+
+impl BandScale {
+    /// Create a new band scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no categories.
+    pub fn new(categories: &[impl AsRef<str>], range: (f32, f32)) -> Result<Self> {
+        if categories.is_empty() {
+            return Err(Error::ScaleDomain("Band scale needs at least one category".to_string()));
+        }
+
+        Ok(Self {
+            categories: categories.iter().map(|c| c.as_ref().to_string()).collect(),
+            range_min: range.0,
+            range_max: range.1,
+            padding: 0.0,
+        })
+    }
+
+    /// Set the padding as a fraction of the step (0.0 - 1.0).
+    ///
+    /// The same gap is left between adjacent bands and at each outer edge.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Number of categories.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.categories.len()
+    }
+
+    /// Whether the scale has no categories.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.categories.is_empty()
+    }
+
+    /// Category labels in order.
+    #[must_use]
+    pub fn categories(&self) -> &[String] {
+        &self.categories
+    }
+
+    /// Index of a category by name.
+    #[must_use]
+    pub fn index_of(&self, category: &str) -> Option<usize> {
+        self.categories.iter().position(|c| c == category)
+    }
+
+    /// Distance between the starts of adjacent bands.
+    #[must_use]
+    pub fn step(&self) -> f32 {
+        let n = self.categories.len() as f32;
+        (self.range_max - self.range_min) / (n + self.padding)
+    }
+
+    /// Width of each band.
+    #[must_use]
+    pub fn bandwidth(&self) -> f32 {
+        self.step() * (1.0 - self.padding)
+    }
+
+    /// Center position of the band at `index`.
+    #[must_use]
+    pub fn center(&self, index: usize) -> f32 {
+        self.scale(index) + self.bandwidth() / 2.0
+    }
+}
+
+impl Scale<usize, f32> for BandScale {
+    /// Start position of the band at `index`.
+    fn scale(&self, index: usize) -> f32 {
+        self.range_min + self.step() * (self.padding + index as f32)
+    }
+
+    fn domain(&self) -> (usize, usize) {
+        (0, self.categories.len().saturating_sub(1))
+    }
+
+    fn range(&self) -> (f32, f32) {
+        (self.range_min, self.range_max)
+    }
+}
+
 /// Color scale for mapping values to colors.
 #[derive(Debug, Clone)]
 pub struct ColorScale {
     colors: Vec<Rgba>,
     domain_min: f32,
     domain_max: f32,
+    log: bool,
 }
 
 impl ColorScale {
@@ -171,7 +403,66 @@ impl ColorScale {
             return Err(Error::ScaleDomain("Domain min and max cannot be equal".to_string()));
         }
 
-        Ok(Self { colors, domain_min: domain.0, domain_max: domain.1 })
+        Ok(Self { colors, domain_min: domain.0, domain_max: domain.1, log: false })
+    }
+
+    /// Switch to logarithmic mapping, so each decade gets an equal share of
+    /// the colors. Values at or below zero map to the first color.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the domain is not strictly positive.
+    pub fn logarithmic(mut self) -> Result<Self> {
+        if self.domain_min <= 0.0 || self.domain_max <= 0.0 {
+            return Err(Error::ScaleDomain(format!(
+                "Log color scale requires a positive domain, got {}..{}",
+                self.domain_min, self.domain_max
+            )));
+        }
+        self.log = true;
+        Ok(self)
+    }
+
+    /// Whether values are mapped logarithmically.
+    #[must_use]
+    pub const fn is_log(&self) -> bool {
+        self.log
+    }
+
+    /// Position of a value along the color ramp, 0.0 (first color) to 1.0 (last).
+    #[must_use]
+    pub fn normalize(&self, value: f32) -> f32 {
+        let t = if self.log {
+            if value <= 0.0 {
+                return 0.0;
+            }
+            (value / self.domain_min).ln() / (self.domain_max / self.domain_min).ln()
+        } else {
+            (value - self.domain_min) / (self.domain_max - self.domain_min)
+        };
+        if t.is_nan() {
+            0.0
+        } else {
+            t.clamp(0.0, 1.0)
+        }
+    }
+
+    /// Color at a ramp position `t` in 0.0-1.0.
+    #[must_use]
+    pub fn at(&self, t: f32) -> Rgba {
+        let t = t.clamp(0.0, 1.0);
+
+        if self.colors.len() == 1 {
+            return self.colors[0];
+        }
+
+        let segment_count = self.colors.len() - 1;
+        let segment = (t * segment_count as f32).floor() as usize;
+        let segment = segment.min(segment_count - 1);
+
+        let local_t = t * segment_count as f32 - segment as f32;
+
+        self.colors[segment].lerp(self.colors[segment + 1], local_t)
     }
 
     /// Create a sequential blue scale.
@@ -264,19 +555,7 @@ impl ColorScale {
 
 impl Scale<f32, Rgba> for ColorScale {
     fn scale(&self, value: f32) -> Rgba {
-        let t = ((value - self.domain_min) / (self.domain_max - self.domain_min)).clamp(0.0, 1.0);
-
-        if self.colors.len() == 1 {
-            return self.colors[0];
-        }
-
-        let segment_count = self.colors.len() - 1;
-        let segment = (t * segment_count as f32).floor() as usize;
-        let segment = segment.min(segment_count - 1);
-
-        let local_t = t * segment_count as f32 - segment as f32;
-
-        self.colors[segment].lerp(self.colors[segment + 1], local_t)
+        self.at(self.normalize(value))
     }
 
     fn domain(&self) -> (f32, f32) {
@@ -288,6 +567,50 @@ impl Scale<f32, Rgba> for ColorScale {
     }
 }
 
+/// Round tick values covering `[min, max]`, about `count` of them.
+///
+/// Steps are 1, 2 or 5 times a power of ten (Heckbert's nice numbers).
+#[must_use]
+pub(crate) fn nice_ticks(min: f32, max: f32, count: usize) -> Vec<f32> {
+    if !min.is_finite() || !max.is_finite() || max <= min || count == 0 {
+        return Vec::new();
+    }
+    let raw = (max - min) / count as f32;
+    let magnitude = 10f32.powf(raw.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|m| m * magnitude)
+        .find(|s| *s >= raw)
+        .unwrap_or(10.0 * magnitude);
+    let first = (min / step).ceil() as i64;
+    let last = (max / step).floor() as i64;
+    (first..=last).map(|i| i as f32 * step).collect()
+}
+
+/// Tick values for a logarithmic domain: powers of ten within `[min, max]`,
+/// adding 2x and 5x multiples when the domain spans less than two decades.
+#[must_use]
+pub(crate) fn log_ticks(min: f32, max: f32) -> Vec<f32> {
+    if !min.is_finite() || !max.is_finite() || min <= 0.0 || max <= min {
+        return Vec::new();
+    }
+    let first = min.log10().floor() as i32;
+    let last = max.log10().ceil() as i32;
+    let multiples: &[f32] = if last - first <= 2 { &[1.0, 2.0, 5.0] } else { &[1.0] };
+    (first..=last)
+        .flat_map(|e| multiples.iter().map(move |m| m * 10f32.powi(e)))
+        // Tolerate float error at the domain edges
+        .filter(|v| *v >= min * (1.0 - 1e-5) && *v <= max * (1.0 + 1e-5))
+        .collect()
+}
+
+/// Compact tick label in the current style's locale: trims trailing zeros
+/// from up to three decimals.
+#[must_use]
+pub(crate) fn format_tick(value: f32) -> String {
+    crate::style::locale().format_tick(f64::from(value))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -315,6 +638,43 @@ mod tests {
         assert!((scale.scale(1000.0) - 3.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_broken_scale_segments() {
+        let scale = BrokenScale::new((0.0, 100.0), (0.0, 110.0), &[(20.0, 90.0)], 10.0)
+            .expect("operation should succeed");
+        // 30 kept domain units share 100 pixels
+        assert!((scale.scale(10.0) - 33.333).abs() < 0.01);
+        assert!((scale.scale(20.0) - 66.667).abs() < 0.01);
+        assert!((scale.scale(90.0) - 76.667).abs() < 0.01);
+        assert!((scale.scale(100.0) - 110.0).abs() < 0.01);
+        assert!((scale.scale(50.0) - 71.667).abs() < 0.01);
+        assert!(scale.is_omitted(50.0) && !scale.is_omitted(95.0));
+        assert_eq!(scale.gaps().len(), 1);
+    }
+
+    #[test]
+    fn test_broken_scale_inverted_range_and_merge() {
+        let scale =
+            BrokenScale::new((0.0, 10.0), (100.0, 0.0), &[(6.0, 8.0), (2.0, 4.0), (3.0, 5.0)], 4.0)
+                .expect("operation should succeed");
+        assert_eq!(scale.breaks(), &[(2.0, 5.0), (6.0, 8.0)]);
+        assert!((scale.scale(0.0) - 100.0).abs() < 0.001);
+        assert!((scale.scale(10.0) - 0.0).abs() < 0.001);
+        let gaps = scale.gaps();
+        assert!(gaps.iter().all(|(a, b)| (a - b - 4.0).abs() < 0.001));
+    }
+
+    #[test]
+    fn test_broken_scale_invalid() {
+        assert!(BrokenScale::new((1.0, 1.0), (0.0, 1.0), &[], 0.0).is_err());
+        assert!(BrokenScale::new((0.0, 10.0), (0.0, 100.0), &[(5.0, 4.0)], 2.0).is_err());
+        assert!(BrokenScale::new((0.0, 10.0), (0.0, 10.0), &[(2.0, 4.0)], 20.0).is_err());
+        // Breaks outside the domain are ignored
+        let scale = BrokenScale::new((0.0, 10.0), (0.0, 10.0), &[(20.0, 30.0)], 2.0)
+            .expect("operation should succeed");
+        assert!(scale.breaks().is_empty());
+    }
+
     #[test]
     fn test_log_scale_invalid_domain() {
         assert!(LogScale::new((-1.0, 100.0), (0.0, 1.0)).is_err());
@@ -330,6 +690,32 @@ mod tests {
         assert!(mid.r > 100 && mid.r < 150);
     }
 
+    #[test]
+    fn test_color_scale_logarithmic() {
+        let scale = ColorScale::new(vec![Rgba::BLACK, Rgba::WHITE], (1.0, 1000.0))
+            .and_then(ColorScale::logarithmic)
+            .expect("positive domain should succeed");
+        assert!(scale.is_log());
+        assert!((scale.normalize(10.0) - 1.0 / 3.0).abs() < 1e-4);
+        assert!((scale.normalize(100.0) - 2.0 / 3.0).abs() < 1e-4);
+        assert!(scale.normalize(-5.0).abs() < f32::EPSILON);
+        assert_eq!(scale.scale(1000.0), Rgba::WHITE);
+
+        let zero = ColorScale::new(vec![Rgba::BLACK, Rgba::WHITE], (0.0, 10.0));
+        assert!(zero.and_then(ColorScale::logarithmic).is_err());
+    }
+
+    #[test]
+    fn test_tick_helpers() {
+        assert_eq!(nice_ticks(0.0, 10.0, 5), vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+        assert_eq!(nice_ticks(32.0, 212.0, 5), vec![50.0, 100.0, 150.0, 200.0]);
+        assert!(nice_ticks(1.0, 1.0, 5).is_empty());
+        assert_eq!(log_ticks(1.0, 10_000.0), vec![1.0, 10.0, 100.0, 1000.0, 10_000.0]);
+        assert_eq!(log_ticks(3.0, 60.0), vec![5.0, 10.0, 20.0, 50.0]);
+        assert_eq!(format_tick(2.500), "2.5");
+        assert_eq!(format_tick(-0.0001), "0");
+    }
+
     #[test]
     fn test_linear_scale_from_data() {
         let scale = LinearScale::from_data(&[0.0, 50.0, 100.0], (0.0, 1.0))
@@ -525,4 +911,34 @@ mod tests {
         let _ = scale.scale(0.66);
         let _ = scale.scale(1.0);
     }
+
+    #[test]
+    fn test_band_scale_no_padding() {
+        let scale = BandScale::new(&["a", "b", "c", "d"], (0.0, 100.0))
+            .expect("band scale creation should succeed");
+        assert_eq!(scale.len(), 4);
+        assert!((scale.bandwidth() - 25.0).abs() < 0.001);
+        assert!((scale.scale(2) - 50.0).abs() < 0.001);
+        assert!((scale.center(0) - 12.5).abs() < 0.001);
+        assert_eq!(scale.index_of("c"), Some(2));
+        assert_eq!(scale.index_of("z"), None);
+    }
+
+    #[test]
+    fn test_band_scale_padding() {
+        let scale = BandScale::new(&["a", "b"], (0.0, 100.0))
+            .expect("band scale creation should succeed")
+            .padding(0.5);
+        // step = 100 / 2.5 = 40, band = 20, first band starts at 20
+        assert!((scale.step() - 40.0).abs() < 0.001);
+        assert!((scale.bandwidth() - 20.0).abs() < 0.001);
+        assert!((scale.scale(0) - 20.0).abs() < 0.001);
+        assert!((scale.scale(1) + scale.bandwidth() - 80.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_band_scale_empty() {
+        let empty: [&str; 0] = [];
+        assert!(BandScale::new(&empty, (0.0, 1.0)).is_err());
+    }
 }
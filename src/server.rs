@@ -0,0 +1,943 @@
+//! HTTP render service for notebooks and web clients.
+//!
+//! Thin clients POST a [`PlotSpec`] as JSON, optionally pointing its data
+//! fields at datasets uploaded earlier, and receive PNG, SVG or HTML. Output
+//! is kept in an LRU [`RenderCache`] keyed by the resolved request, so
+//! re-displaying a figure (notebook re-runs, page reloads) skips rendering.
+//!
+//! | Method | Path               | Body                              | Response                 |
+//! |--------|--------------------|-----------------------------------|--------------------------|
+//! | POST   | `/render`          | [`RenderRequest`] JSON            | figure bytes             |
+//! | PUT    | `/datasets/{name}` | JSON object of column arrays      | `204 No Content`         |
+//! | GET    | `/datasets`        | -                                 | dataset name -> columns  |
+//! | GET    | `/health`          | -                                 | status and cache stats   |
+//!
+//! Render responses carry `X-Render-Cache: hit|miss`. Figures larger than
+//! [`RenderServer::max_pixels`] are rejected with `400` before drawing, and
+//! uploads beyond [`RenderServer::max_datasets`] or
+//! [`RenderServer::max_dataset_bytes`] with `413`. Request lines and headers
+//! are capped at 8 KiB each and 100 headers (`400` / `431`). The HTTP layer is std-only HTTP/1.1 with one thread per connection, at most
+//! [`RenderServer::max_connections`] at a time (further clients get `503`),
+//! and `Connection: close`; put a reverse proxy in front of it for TLS or
+//! keep-alive.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use trueno_viz::grammar::DataFrame;
+//! use trueno_viz::server::RenderServer;
+//!
+//! let server = RenderServer::new()
+//!     .cache_capacity(128)
+//!     .dataset("iris", DataFrame::from_xy(&[5.1, 4.9, 6.3], &[3.5, 3.0, 3.3]));
+//! server.serve("127.0.0.1:8787").unwrap();
+//! ```
+//!
+//! ```text
+//! curl -X POST localhost:8787/render -o plot.svg -d '{
+//!   "spec": {"plot_type": "scatter", "width": 400, "height": 300},
+//!   "data": {"x": "iris/x", "y": "iris/y"},
+//!   "format": "svg"
+//! }'
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::grammar::{DataFrame, DataValue};
+use crate::output::{HtmlExporter, PngEncoder, SvgEncoder};
+use crate::prompt::PlotSpec;
+
+/// Default number of rendered figures kept in the cache.
+pub const DEFAULT_CACHE_CAPACITY: usize = 64;
+
+/// Default largest figure rendered, in pixels (4096 x 4096).
+pub const DEFAULT_MAX_PIXELS: u64 = 4096 * 4096;
+
+/// Default number of connections handled concurrently.
+pub const DEFAULT_MAX_CONNECTIONS: usize = 64;
+
+/// Default number of datasets held at once.
+pub const DEFAULT_MAX_DATASETS: usize = 256;
+
+/// Default total size of the held datasets (1 GiB).
+pub const DEFAULT_MAX_DATASET_BYTES: usize = 1024 * 1024 * 1024;
+
+/// Largest request body accepted (bytes).
+const MAX_BODY_BYTES: usize = 64 * 1024 * 1024;
+
+/// Longest request line or header line accepted (bytes, including CRLF).
+const MAX_HEADER_BYTES: usize = 8 * 1024;
+
+/// Most header lines accepted in one request.
+const MAX_HEADERS: usize = 100;
+
+/// Read timeout for a client connection.
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Write timeout for turning away a client while at the connection limit.
+const BUSY_WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Pause after a failed accept, so descriptor exhaustion does not spin.
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// Encoding of a rendered figure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// PNG image.
+    #[default]
+    Png,
+    /// SVG document (raster embedded).
+    Svg,
+    /// Self-contained HTML page.
+    Html,
+}
+
+impl OutputFormat {
+    /// MIME type of the encoded figure.
+    #[must_use]
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Svg => "image/svg+xml",
+            Self::Html => "text/html; charset=utf-8",
+        }
+    }
+}
+
+/// Reference to server-side data as `"dataset/column"`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DataRef {
+    /// A single column (for `x`, `y` and `data`).
+    Column(String),
+    /// Several columns (for `groups` and `matrix` rows).
+    Columns(Vec<String>),
+}
+
+/// Body of `POST /render`.
+///
+/// `data` maps [`PlotSpec`] data fields (`x`, `y`, `data`, `groups`,
+/// `matrix`) to dataset columns; referenced fields replace any inline data
+/// in `spec`. Missing values are dropped (pairwise for `x`/`y`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RenderRequest {
+    /// Plot specification, possibly without data.
+    pub spec: PlotSpec,
+    /// Data field -> dataset column references.
+    pub data: BTreeMap<String, DataRef>,
+    /// Output encoding.
+    pub format: OutputFormat,
+}
+
+/// LRU cache of encoded figures keyed by request hash.
+#[derive(Debug, Clone)]
+pub struct RenderCache {
+    capacity: usize,
+    entries: HashMap<u64, Arc<[u8]>>,
+    /// Keys from least to most recently used.
+    order: VecDeque<u64>,
+    hits: u64,
+    misses: u64,
+}
+
+impl RenderCache {
+    /// Create a cache holding up to `capacity` figures (0 disables caching).
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, entries: HashMap::new(), order: VecDeque::new(), hits: 0, misses: 0 }
+    }
+
+    /// Look up a figure, marking it most recently used.
+    pub fn get(&mut self, key: u64) -> Option<Arc<[u8]>> {
+        let Some(bytes) = self.entries.get(&key).cloned() else {
+            self.misses += 1;
+            return None;
+        };
+        self.hits += 1;
+        self.touch(key);
+        Some(bytes)
+    }
+
+    /// Store a figure, evicting the least recently used beyond capacity.
+    pub fn insert(&mut self, key: u64, bytes: Arc<[u8]>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.insert(key, bytes).is_some() {
+            self.touch(key);
+            return;
+        }
+        self.order.push_back(key);
+        while self.order.len() > self.capacity {
+            if let Some(old) = self.order.pop_front() {
+                self.entries.remove(&old);
+            }
+        }
+    }
+
+    fn touch(&mut self, key: u64) {
+        if let Some(pos) = self.order.iter().position(|&k| k == key) {
+            self.order.remove(pos);
+        }
+        self.order.push_back(key);
+    }
+
+    /// Number of cached figures.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Lookups served from the cache.
+    #[must_use]
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Lookups that required rendering.
+    #[must_use]
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+}
+
+/// Figure cache statistics, as reported by `GET /health`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CacheStats {
+    /// Cached figures.
+    pub entries: usize,
+    /// Renders served from the cache.
+    pub hits: u64,
+    /// Renders that had to be drawn.
+    pub misses: u64,
+}
+
+/// Body of `GET /health`.
+#[derive(Serialize)]
+struct Health {
+    status: &'static str,
+    cache: CacheStats,
+}
+
+/// An HTTP response produced by [`RenderServer::handle`].
+#[derive(Debug, Clone)]
+pub struct Response {
+    /// HTTP status code.
+    pub status: u16,
+    /// `Content-Type` header value.
+    pub content_type: &'static str,
+    /// Whether a render was served from the cache (`None` for other routes).
+    pub cache_hit: Option<bool>,
+    /// Response body.
+    pub body: Arc<[u8]>,
+}
+
+impl Response {
+    fn json(status: u16, value: &impl Serialize) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            cache_hit: None,
+            body: serde_json::to_vec(value).unwrap_or_default().into(),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::json(status, &BTreeMap::from([("error", message)]))
+    }
+
+    fn empty(status: u16) -> Self {
+        Self { status, content_type: "text/plain", cache_hit: None, body: Arc::from([]) }
+    }
+
+    fn write_to(&self, out: &mut impl Write) -> io::Result<()> {
+        let reason = match self.status {
+            200 => "OK",
+            204 => "No Content",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            431 => "Request Header Fields Too Large",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        };
+        write!(out, "HTTP/1.1 {} {reason}\r\n", self.status)?;
+        write!(out, "Content-Type: {}\r\n", self.content_type)?;
+        write!(out, "Content-Length: {}\r\n", self.body.len())?;
+        if let Some(hit) = self.cache_hit {
+            write!(out, "X-Render-Cache: {}\r\n", if hit { "hit" } else { "miss" })?;
+        }
+        out.write_all(
+            b"Access-Control-Allow-Origin: *\r\n\
+              Access-Control-Allow-Methods: GET, POST, PUT, OPTIONS\r\n\
+              Access-Control-Allow-Headers: Content-Type\r\n\
+              Connection: close\r\n\r\n",
+        )?;
+        out.write_all(&self.body)?;
+        out.flush()
+    }
+}
+
+/// Render service with uploaded datasets and a shared figure cache.
+///
+/// Clones share datasets and cache, so one server can be handed to several
+/// threads or embedded next to an application's own routes via
+/// [`RenderServer::handle`].
+#[derive(Debug, Clone)]
+pub struct RenderServer {
+    datasets: Arc<RwLock<HashMap<String, DataFrame>>>,
+    cache: Arc<Mutex<RenderCache>>,
+    max_pixels: u64,
+    max_connections: usize,
+    max_datasets: usize,
+    max_dataset_bytes: usize,
+    /// Connections currently being handled.
+    active: Arc<AtomicUsize>,
+}
+
+impl Default for RenderServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderServer {
+    /// Create a server with no datasets and the default cache size.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            datasets: Arc::default(),
+            cache: Arc::new(Mutex::new(RenderCache::new(DEFAULT_CACHE_CAPACITY))),
+            max_pixels: DEFAULT_MAX_PIXELS,
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            max_datasets: DEFAULT_MAX_DATASETS,
+            max_dataset_bytes: DEFAULT_MAX_DATASET_BYTES,
+            active: Arc::default(),
+        }
+    }
+
+    /// Set the number of cached figures (0 disables caching).
+    #[must_use]
+    pub fn cache_capacity(self, capacity: usize) -> Self {
+        Self { cache: Arc::new(Mutex::new(RenderCache::new(capacity))), ..self }
+    }
+
+    /// Set the largest figure (`width * height`) a request may ask for.
+    #[must_use]
+    pub fn max_pixels(self, max_pixels: u64) -> Self {
+        Self { max_pixels, ..self }
+    }
+
+    /// Set how many connections are handled at once (at least 1).
+    #[must_use]
+    pub fn max_connections(self, max_connections: usize) -> Self {
+        Self { max_connections: max_connections.max(1), ..self }
+    }
+
+    /// Set how many datasets `PUT /datasets/{name}` may leave the server
+    /// holding (replacing a dataset does not count twice).
+    #[must_use]
+    pub fn max_datasets(self, max_datasets: usize) -> Self {
+        Self { max_datasets, ..self }
+    }
+
+    /// Set the total in-memory size uploads may bring the datasets to, in
+    /// bytes (see [`dataset_bytes`]).
+    #[must_use]
+    pub fn max_dataset_bytes(self, max_dataset_bytes: usize) -> Self {
+        Self { max_dataset_bytes, ..self }
+    }
+
+    /// Register a dataset that requests can reference by name.
+    #[must_use]
+    pub fn dataset(self, name: &str, df: DataFrame) -> Self {
+        self.insert_dataset(name, df);
+        self
+    }
+
+    /// Register or replace a dataset on a running server.
+    ///
+    /// Datasets registered here are trusted and not checked against the
+    /// upload limits, though they count towards them.
+    pub fn insert_dataset(&self, name: &str, df: DataFrame) {
+        self.datasets.write().unwrap_or_else(PoisonError::into_inner).insert(name.to_string(), df);
+    }
+
+    /// Store an uploaded dataset if it stays within the dataset limits.
+    fn upload_dataset(&self, name: &str, df: DataFrame) -> Response {
+        let mut datasets = self.datasets.write().unwrap_or_else(PoisonError::into_inner);
+        let replaced = datasets.get(name);
+        if replaced.is_none() && datasets.len() >= self.max_datasets {
+            let message = format!("Server already holds {} datasets", self.max_datasets);
+            return Response::error(413, &message);
+        }
+        let held: usize = datasets.values().map(dataset_bytes).sum();
+        let total = held - replaced.map_or(0, dataset_bytes) + dataset_bytes(&df);
+        if total > self.max_dataset_bytes {
+            let message =
+                format!("Datasets would exceed the {} byte limit", self.max_dataset_bytes);
+            return Response::error(413, &message);
+        }
+        datasets.insert(name.to_string(), df);
+        Response::empty(204)
+    }
+
+    /// Snapshot of the cache statistics.
+    #[must_use]
+    pub fn cache_stats(&self) -> CacheStats {
+        let cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+        CacheStats { entries: cache.len(), hits: cache.hits(), misses: cache.misses() }
+    }
+
+    /// Render a request, returning the encoded figure and whether it was
+    /// served from the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the figure exceeds [`RenderServer::max_pixels`],
+    /// a data reference cannot be resolved or the spec fails to render.
+    pub fn render(&self, request: &RenderRequest) -> Result<(Arc<[u8]>, bool)> {
+        let (width, height) = (request.spec.width, request.spec.height);
+        if u64::from(width) * u64::from(height) > self.max_pixels {
+            return Err(Error::Rendering(format!(
+                "Figure of {width}x{height} exceeds the {} pixel limit",
+                self.max_pixels
+            )));
+        }
+        let spec = self.resolve(request)?;
+        let key = request_key(&spec, request.format);
+
+        if let Some(bytes) = self.cache.lock().unwrap_or_else(PoisonError::into_inner).get(key) {
+            return Ok((bytes, true));
+        }
+
+        let fb = spec.render()?;
+        let bytes: Arc<[u8]> = match request.format {
+            OutputFormat::Png => PngEncoder::to_bytes(&fb)?.into(),
+            OutputFormat::Svg => SvgEncoder::from_framebuffer(&fb)?.render().into_bytes().into(),
+            OutputFormat::Html => {
+                let svg = SvgEncoder::from_framebuffer(&fb)?;
+                let title = spec.title.as_deref().unwrap_or("Chart");
+                HtmlExporter::from_svg(&svg).title(title).to_html().into_bytes().into()
+            }
+        };
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner).insert(key, Arc::clone(&bytes));
+        Ok((bytes, false))
+    }
+
+    /// Copy `request.spec`, filling referenced data fields from datasets.
+    fn resolve(&self, request: &RenderRequest) -> Result<PlotSpec> {
+        let mut spec = request.spec.clone();
+        if request.data.is_empty() {
+            return Ok(spec);
+        }
+        let datasets = self.datasets.read().unwrap_or_else(PoisonError::into_inner);
+        let column = |reference: &str| -> Result<Vec<f32>> {
+            let (dataset, name) = reference.split_once('/').ok_or_else(|| {
+                Error::Rendering(format!("Data reference must be dataset/column: {reference}"))
+            })?;
+            let df = datasets
+                .get(dataset)
+                .ok_or_else(|| Error::Rendering(format!("Unknown dataset: {dataset}")))?;
+            let values = df
+                .get(name)
+                .ok_or_else(|| Error::Rendering(format!("Unknown column: {reference}")))?;
+            Ok(values.iter().map(|v| v.as_f32().unwrap_or(f32::NAN)).collect())
+        };
+        let single = |field: &str, data_ref: &DataRef| match data_ref {
+            DataRef::Column(reference) => column(reference),
+            DataRef::Columns(_) => {
+                Err(Error::Rendering(format!("Field {field} takes a single column reference")))
+            }
+        };
+        let many = |data_ref: &DataRef| match data_ref {
+            DataRef::Column(reference) => Ok(vec![column(reference)?]),
+            DataRef::Columns(references) => references.iter().map(|r| column(r)).collect(),
+        };
+
+        for (field, data_ref) in &request.data {
+            match field.as_str() {
+                "x" => spec.x_data = Some(single(field, data_ref)?),
+                "y" => spec.y_data = Some(single(field, data_ref)?),
+                "data" => spec.data = Some(finite(single(field, data_ref)?)),
+                "groups" => spec.groups = Some(many(data_ref)?.into_iter().map(finite).collect()),
+                "matrix" => {
+                    let rows = many(data_ref)?;
+                    if rows.iter().flatten().any(|v| !v.is_finite()) {
+                        return Err(Error::Rendering("Matrix columns must not have gaps".into()));
+                    }
+                    spec.matrix = Some(rows);
+                }
+                _ => return Err(Error::Rendering(format!("Unknown data field: {field}"))),
+            }
+        }
+        if let (Some(x), Some(y)) = (&spec.x_data, &spec.y_data) {
+            let (x, y) = x.iter().zip(y).filter(|(u, v)| u.is_finite() && v.is_finite()).unzip();
+            (spec.x_data, spec.y_data) = (Some(x), Some(y));
+        }
+        Ok(spec)
+    }
+
+    /// Route one request. Useful for embedding the service in another
+    /// HTTP stack; [`RenderServer::serve`] uses it for every connection.
+    #[must_use]
+    pub fn handle(&self, method: &str, path: &str, body: &[u8]) -> Response {
+        let path = path.split('?').next().unwrap_or_default();
+        match (method, path) {
+            ("OPTIONS", _) => Response::empty(204),
+            ("POST", "/render") => match serde_json::from_slice::<RenderRequest>(body) {
+                Ok(request) => match self.render(&request) {
+                    Ok((body, hit)) => Response {
+                        status: 200,
+                        content_type: request.format.content_type(),
+                        cache_hit: Some(hit),
+                        body,
+                    },
+                    Err(err @ (Error::Io(_) | Error::PngEncoding(_))) => {
+                        Response::error(500, &err.to_string())
+                    }
+                    Err(err) => Response::error(400, &err.to_string()),
+                },
+                Err(err) => Response::error(400, &format!("Invalid render request: {err}")),
+            },
+            ("PUT" | "POST", _) if path.starts_with("/datasets/") => {
+                let name = &path["/datasets/".len()..];
+                if name.is_empty() || name.contains('/') {
+                    return Response::error(400, "Dataset name must be a single path segment");
+                }
+                match parse_dataset(body) {
+                    Ok(df) => self.upload_dataset(name, df),
+                    Err(err) => Response::error(400, &err.to_string()),
+                }
+            }
+            ("GET", "/datasets") => {
+                let datasets = self.datasets.read().unwrap_or_else(PoisonError::into_inner);
+                let listing: BTreeMap<&str, Vec<&str>> = datasets
+                    .iter()
+                    .map(|(name, df)| {
+                        let mut columns = df.columns();
+                        columns.sort_unstable();
+                        (name.as_str(), columns)
+                    })
+                    .collect();
+                Response::json(200, &listing)
+            }
+            ("GET", "/health") => {
+                Response::json(200, &Health { status: "ok", cache: self.cache_stats() })
+            }
+            (_, "/render" | "/datasets" | "/health") => Response::error(405, "Method not allowed"),
+            _ => Response::error(404, "Not found"),
+        }
+    }
+
+    /// Bind `addr` and serve requests until the process exits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the address cannot be bound.
+    pub fn serve(&self, addr: impl ToSocketAddrs) -> Result<()> {
+        self.serve_listener(&TcpListener::bind(addr)?)
+    }
+
+    /// Serve requests from an already bound listener (e.g. port 0 in tests).
+    ///
+    /// Failed accepts (e.g. out of file descriptors) are logged to stderr and
+    /// retried. Clients beyond [`RenderServer::max_connections`] receive
+    /// `503 Service Unavailable` without a thread being spawned.
+    ///
+    /// # Errors
+    ///
+    /// Does not currently fail; the `Result` is kept for API stability.
+    pub fn serve_listener(&self, listener: &TcpListener) -> Result<()> {
+        for stream in listener.incoming() {
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(err) => {
+                    eprintln!("trueno-viz server: accept failed: {err}");
+                    std::thread::sleep(ACCEPT_RETRY_DELAY);
+                    continue;
+                }
+            };
+            let Some(slot) = ConnectionSlot::acquire(&self.active, self.max_connections) else {
+                let _ = stream.set_write_timeout(Some(BUSY_WRITE_TIMEOUT));
+                let _ = Response::error(503, "Too many connections").write_to(&mut &stream);
+                continue;
+            };
+            let server = self.clone();
+            std::thread::spawn(move || {
+                let _slot = slot;
+                // A client that disconnects mid-request only loses its own response
+                let _ = server.handle_connection(&stream);
+            });
+        }
+        Ok(())
+    }
+
+    fn handle_connection(&self, stream: &TcpStream) -> io::Result<()> {
+        stream.set_read_timeout(Some(READ_TIMEOUT))?;
+        let mut reader = BufReader::new(stream);
+
+        let response = match read_head(&mut reader)? {
+            Err(rejection) => rejection,
+            Ok(head) if head.content_length > MAX_BODY_BYTES => {
+                Response::error(413, "Request body too large")
+            }
+            Ok(head) => {
+                let mut body = vec![0; head.content_length];
+                reader.read_exact(&mut body)?;
+                self.handle(&head.method, &head.path, &body)
+            }
+        };
+        response.write_to(&mut &*stream)
+    }
+}
+
+/// Request line and headers of one request.
+struct RequestHead {
+    method: String,
+    path: String,
+    content_length: usize,
+}
+
+/// Read the request line and headers, or the response refusing them when a
+/// line exceeds [`MAX_HEADER_BYTES`] or there are more than [`MAX_HEADERS`].
+fn read_head(reader: &mut impl BufRead) -> io::Result<std::result::Result<RequestHead, Response>> {
+    let Some(request_line) = read_head_line(reader)? else {
+        return Ok(Err(Response::error(400, "Request line too long")));
+    };
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or_default(), parts.next().unwrap_or_default());
+    let mut head =
+        RequestHead { method: method.to_string(), path: path.to_string(), content_length: 0 };
+
+    for _ in 0..=MAX_HEADERS {
+        let Some(header) = read_head_line(reader)? else {
+            return Ok(Err(Response::error(431, "Header line too long")));
+        };
+        if header.trim().is_empty() {
+            return Ok(Ok(head));
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                head.content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+    Ok(Err(Response::error(431, "Too many header lines")))
+}
+
+/// Read one line of at most [`MAX_HEADER_BYTES`], `None` if it is longer.
+/// An empty string means the client closed the connection.
+fn read_head_line(reader: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut line = String::new();
+    reader.take(MAX_HEADER_BYTES as u64).read_line(&mut line)?;
+    Ok((line.len() < MAX_HEADER_BYTES || line.ends_with('\n')).then_some(line))
+}
+
+/// A counted connection, released when dropped.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    /// Take a slot unless `limit` connections are already active.
+    fn acquire(active: &Arc<AtomicUsize>, limit: usize) -> Option<Self> {
+        active
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| (n < limit).then_some(n + 1))
+            .ok()
+            .map(|_| Self(Arc::clone(active)))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Approximate in-memory size of a dataset in bytes: column names, one
+/// [`DataValue`] per cell and the text of string cells.
+#[must_use]
+pub fn dataset_bytes(df: &DataFrame) -> usize {
+    df.columns()
+        .into_iter()
+        .map(|name| {
+            let values = df.get(name).unwrap_or_default();
+            let text: usize = values.iter().filter_map(DataValue::as_str).map(str::len).sum();
+            name.len() + std::mem::size_of_val(values) + text
+        })
+        .sum()
+}
+
+/// Drop missing values.
+fn finite(values: Vec<f32>) -> Vec<f32> {
+    values.into_iter().filter(|v| v.is_finite()).collect()
+}
+
+/// Cache key for a resolved spec and output format.
+fn request_key(spec: &PlotSpec, format: OutputFormat) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    // PlotSpec holds floats, so hash its canonical JSON encoding
+    serde_json::to_string(spec).unwrap_or_default().hash(&mut hasher);
+    format.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Parse a JSON object of column arrays (numbers, strings or nulls).
+fn parse_dataset(body: &[u8]) -> Result<DataFrame> {
+    let value: serde_json::Value = serde_json::from_slice(body)
+        .map_err(|err| Error::Rendering(format!("Invalid dataset JSON: {err}")))?;
+    let columns = value
+        .as_object()
+        .ok_or_else(|| Error::Rendering("Dataset must be an object of column arrays".into()))?;
+
+    let mut df = DataFrame::new();
+    for (name, column) in columns {
+        let cells = column
+            .as_array()
+            .ok_or_else(|| Error::Rendering(format!("Column {name} must be an array")))?;
+        let values = cells
+            .iter()
+            .map(|cell| match cell {
+                serde_json::Value::Number(n) => {
+                    Ok(n.as_f64().map_or(DataValue::Null, |v| DataValue::Number(v as f32)))
+                }
+                serde_json::Value::String(s) => Ok(DataValue::Text(s.clone())),
+                serde_json::Value::Null => Ok(DataValue::Null),
+                _ => Err(Error::Rendering(format!("Column {name} has a non-scalar value"))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        df.add_column(name, values);
+    }
+    Ok(df)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server() -> RenderServer {
+        let mut df = DataFrame::from_xy(&[1.0, 2.0, 3.0, 4.0], &[2.0, 4.0, 3.0, 5.0]);
+        df.add_column_str("label", &["a", "b", "c", "d"]);
+        RenderServer::new().dataset("points", df)
+    }
+
+    fn post_render(server: &RenderServer, body: &str) -> Response {
+        server.handle("POST", "/render", body.as_bytes())
+    }
+
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let mut cache = RenderCache::new(2);
+        cache.insert(1, Arc::from(&b"one"[..]));
+        cache.insert(2, Arc::from(&b"two"[..]));
+        assert!(cache.get(1).is_some());
+        cache.insert(3, Arc::from(&b"three"[..]));
+        assert!(cache.get(2).is_none());
+        assert_eq!(cache.get(1).as_deref(), Some(&b"one"[..]));
+        assert_eq!((cache.len(), cache.hits(), cache.misses()), (2, 2, 1));
+
+        let mut disabled = RenderCache::new(0);
+        disabled.insert(1, Arc::from(&b"one"[..]));
+        assert!(disabled.is_empty());
+    }
+
+    #[test]
+    fn test_render_resolves_references_and_caches() {
+        let server = server();
+        let body = r#"{"spec": {"plot_type": "scatter", "width": 120, "height": 90},
+                       "data": {"x": "points/x", "y": "points/y"}}"#;
+
+        let first = post_render(&server, body);
+        assert_eq!(first.status, 200);
+        assert_eq!(first.content_type, "image/png");
+        assert_eq!(first.cache_hit, Some(false));
+        assert_eq!(&first.body[1..4], b"PNG");
+
+        let second = post_render(&server, body);
+        assert_eq!(second.cache_hit, Some(true));
+        assert_eq!(first.body, second.body);
+
+        // New data under the same name is a different figure
+        server.insert_dataset("points", DataFrame::from_xy(&[1.0, 2.0], &[1.0, 0.0]));
+        assert_eq!(post_render(&server, body).cache_hit, Some(false));
+        assert_eq!(server.cache_stats(), CacheStats { entries: 2, hits: 1, misses: 2 });
+    }
+
+    #[test]
+    fn test_svg_and_html_formats() {
+        let server = server();
+        let svg = post_render(
+            &server,
+            r#"{"spec": {"plot_type": "histogram", "width": 200, "height": 150},
+                "data": {"data": "points/y"}, "format": "svg"}"#,
+        );
+        assert_eq!(svg.content_type, "image/svg+xml");
+        assert!(String::from_utf8_lossy(&svg.body).starts_with("<svg"));
+
+        let html = post_render(
+            &server,
+            r#"{"spec": {"plot_type": "boxplot", "width": 200, "height": 150, "title": "Spread"},
+                "data": {"groups": ["points/x", "points/y"]}, "format": "html"}"#,
+        );
+        assert_eq!(html.status, 200);
+        let page = String::from_utf8_lossy(&html.body);
+        assert!(page.contains("<title>Spread</title>"));
+    }
+
+    #[test]
+    fn test_dataset_upload_and_errors() {
+        let server = RenderServer::new();
+        let upload =
+            server.handle("PUT", "/datasets/m", br#"{"a": [1, 2, null, 4], "b": [4, 3, 2, 1]}"#);
+        assert_eq!(upload.status, 204);
+        let listing = server.handle("GET", "/datasets", b"");
+        assert_eq!(&*listing.body, br#"{"m":["a","b"]}"#);
+
+        let ok = post_render(
+            &server,
+            r#"{"spec": {"plot_type": "line", "width": 200, "height": 150},
+                "data": {"x": "m/b", "y": "m/a"}}"#,
+        );
+        assert_eq!(ok.status, 200);
+
+        for (body, message) in [
+            (r#"{"spec": {"plot_type": "scatter"}, "data": {"x": "nope/a"}}"#, "Unknown dataset"),
+            (r#"{"spec": {"plot_type": "scatter"}, "data": {"x": "m/zz"}}"#, "Unknown column"),
+            (r#"{"spec": {"plot_type": "heatmap"}, "data": {"matrix": ["m/a"]}}"#, "gaps"),
+            (r#"{"data": {"color": "m/a"}}"#, "Unknown data field"),
+            ("not json", "Invalid render request"),
+        ] {
+            let response = post_render(&server, body);
+            assert_eq!(response.status, 400, "{body}");
+            assert!(String::from_utf8_lossy(&response.body).contains(message), "{body}");
+        }
+        assert_eq!(server.handle("GET", "/render", b"").status, 405);
+        assert_eq!(server.handle("GET", "/nope", b"").status, 404);
+        assert_eq!(server.handle("PUT", "/datasets/m", b"[1, 2]").status, 400);
+    }
+
+    #[test]
+    fn test_dataset_upload_limits() {
+        let server = RenderServer::new().max_datasets(2);
+        assert_eq!(server.handle("PUT", "/datasets/a", br#"{"x": [1]}"#).status, 204);
+        assert_eq!(server.handle("PUT", "/datasets/b", br#"{"x": [1]}"#).status, 204);
+        let third = server.handle("PUT", "/datasets/c", br#"{"x": [1]}"#);
+        assert_eq!(third.status, 413);
+        assert!(String::from_utf8_lossy(&third.body).contains("2 datasets"));
+        // Replacing an existing dataset stays within the count
+        assert_eq!(server.handle("PUT", "/datasets/a", br#"{"x": [1, 2]}"#).status, 204);
+
+        let small = parse_dataset(br#"{"x": [1, 2]}"#).expect("dataset");
+        let server = RenderServer::new().max_dataset_bytes(dataset_bytes(&small) * 2);
+        assert_eq!(server.handle("PUT", "/datasets/a", br#"{"x": [1, 2]}"#).status, 204);
+        assert_eq!(server.handle("PUT", "/datasets/b", br#"{"x": [1, 2]}"#).status, 204);
+        let over = server.handle("PUT", "/datasets/c", br#"{"x": [1]}"#);
+        assert_eq!(over.status, 413);
+        assert!(String::from_utf8_lossy(&over.body).contains("byte limit"));
+        // The replaced dataset's size is released first
+        assert_eq!(server.handle("PUT", "/datasets/b", br#"{"x": [3, 4]}"#).status, 204);
+    }
+
+    #[test]
+    fn test_bounds_request_head() {
+        let head = |raw: String| read_head(&mut raw.as_bytes()).expect("read head");
+        let status = |raw: String| head(raw).err().map(|response| response.status);
+
+        let Ok(ok) = head("PUT /datasets/m HTTP/1.1\r\nContent-Length: 12\r\n\r\n".to_string())
+        else {
+            panic!("valid head rejected");
+        };
+        assert_eq!(
+            (ok.method.as_str(), ok.path.as_str(), ok.content_length),
+            ("PUT", "/datasets/m", 12)
+        );
+
+        let long_path = "a".repeat(MAX_HEADER_BYTES);
+        assert_eq!(status(format!("GET /{long_path} HTTP/1.1\r\n\r\n")), Some(400));
+        assert_eq!(status(format!("GET / HTTP/1.1\r\nX-Big: {long_path}\r\n\r\n")), Some(431));
+        let many = "X-Header: 1\r\n".repeat(MAX_HEADERS + 1);
+        assert_eq!(status(format!("GET / HTTP/1.1\r\n{many}\r\n")), Some(431));
+        let enough = "X-Header: 1\r\n".repeat(MAX_HEADERS);
+        assert_eq!(status(format!("GET / HTTP/1.1\r\n{enough}\r\n")), None);
+    }
+
+    #[test]
+    fn test_rejects_oversized_figures() {
+        let server = server().max_pixels(100 * 100);
+        let body = |w: u32, h: u32| {
+            format!(
+                r#"{{"spec": {{"plot_type": "scatter", "width": {w}, "height": {h}}},
+                    "data": {{"x": "points/x", "y": "points/y"}}}}"#
+            )
+        };
+        assert_eq!(post_render(&server, &body(100, 100)).status, 200);
+
+        let response = post_render(&server, &body(100, 101));
+        assert_eq!(response.status, 400);
+        assert!(String::from_utf8_lossy(&response.body).contains("10000 pixel limit"));
+        // Rejected before the cache is consulted
+        assert_eq!(server.cache_stats().misses, 1);
+
+        let huge = post_render(&RenderServer::new(), &body(u32::MAX, u32::MAX));
+        assert_eq!(huge.status, 400);
+    }
+
+    #[test]
+    fn test_turns_away_connections_over_limit() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind loopback");
+        let addr = listener.local_addr().expect("local address");
+        let server = server().max_connections(1);
+        std::thread::spawn(move || server.serve_listener(&listener));
+
+        // Holds the only slot while its handler waits for a request line
+        let _idle = TcpStream::connect(addr).expect("connect");
+        let mut busy = TcpStream::connect(addr).expect("connect");
+        let mut response = String::new();
+        busy.read_to_string(&mut response).expect("read response");
+        assert!(response.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+        assert!(response.contains("Too many connections"));
+    }
+
+    #[test]
+    fn test_serves_over_tcp() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind loopback");
+        let addr = listener.local_addr().expect("local address");
+        let server = server();
+        std::thread::spawn(move || server.serve_listener(&listener));
+
+        let body = r#"{"spec": {"plot_type": "scatter", "width": 160, "height": 120},
+                       "data": {"x": "points/x", "y": "points/y"}, "format": "svg"}"#;
+        let mut stream = TcpStream::connect(addr).expect("connect");
+        write!(
+            stream,
+            "POST /render HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .expect("send request");
+        let mut response = String::new();
+        stream.read_to_string(&mut response).expect("read response");
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Type: image/svg+xml\r\n"));
+        assert!(response.contains("X-Render-Cache: miss\r\n"));
+        assert!(response.contains("\r\n\r\n<svg"));
+    }
+}
@@ -0,0 +1,423 @@
+//! Crate-wide style defaults.
+//!
+//! A [`Style`] holds the defaults that new plots start from: figure size,
+//! text scale, label locale, continuous palette and grammar theme. The process-wide style
+//! is resolved once, in increasing precedence, from:
+//!
+//! 1. built-in defaults,
+//! 2. a style file (`$TRUENO_VIZ_STYLE`, else
+//!    `$XDG_CONFIG_HOME/trueno-viz/style.toml`, else
+//!    `~/.config/trueno-viz/style.toml`),
+//! 3. `TRUENO_VIZ_*` environment variables (`TRUENO_VIZ_WIDTH`, ...).
+//!
+//! Builder setters on individual plots always win over the style. Plots
+//! whose built-in size is not 800×600 scale it to the styled figure size
+//! (see [`Style::scaled_size`]).
+//!
+//! The style file uses a flat subset of TOML:
+//!
+//! ```toml
+//! # Company figure defaults
+//! [style]
+//! width = 1024
+//! height = 640
+//! font_scale = 2
+//! palette = "magma"
+//! theme = "minimal"
+//! text_color = "#333333"
+//! locale = "de-DE"
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::{PoisonError, RwLock};
+
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::grammar::Theme;
+use crate::locale::Locale;
+use crate::plots::HeatmapPalette;
+
+/// Environment variable naming an explicit style file.
+pub const STYLE_PATH_ENV: &str = "TRUENO_VIZ_STYLE";
+
+/// Prefix for per-key environment overrides, e.g. `TRUENO_VIZ_WIDTH`.
+pub const ENV_PREFIX: &str = "TRUENO_VIZ_";
+
+/// Process-wide style; `None` until first use or after [`reset`].
+static GLOBAL: RwLock<Option<Style>> = RwLock::new(None);
+
+/// Default appearance for new plots.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    /// Default figure width in pixels.
+    pub width: u32,
+    /// Default figure height in pixels.
+    pub height: u32,
+    /// Bitmap font scale for labels.
+    pub font_scale: u32,
+    /// Label and tick text color.
+    pub text_color: Rgba,
+    /// Number and date conventions for labels.
+    pub locale: Locale,
+    /// Palette for continuous color encodings.
+    pub palette: HeatmapPalette,
+    /// Theme for grammar-of-graphics plots.
+    pub theme: Theme,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            width: 800,
+            height: 600,
+            font_scale: 1,
+            text_color: Rgba::rgb(60, 60, 60),
+            locale: Locale::default(),
+            palette: HeatmapPalette::Viridis,
+            theme: Theme::grey(),
+        }
+    }
+}
+
+impl Style {
+    /// Set one key from its text value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for unknown keys or unparsable values.
+    pub fn set_key(&mut self, key: &str, value: &str) -> Result<()> {
+        let value = value.trim();
+        let invalid = || Error::Style(format!("invalid value for `{key}`: {value}"));
+        match key {
+            "width" | "height" | "font_scale" => {
+                let n: u32 = value.parse().map_err(|_| invalid())?;
+                if n == 0 {
+                    return Err(invalid());
+                }
+                match key {
+                    "width" => self.width = n,
+                    "height" => self.height = n,
+                    _ => self.font_scale = n,
+                }
+            }
+            "text_color" => self.text_color = parse_hex(value).ok_or_else(invalid)?,
+            "locale" => self.locale = Locale::from_name(value).ok_or_else(invalid)?,
+            "palette" => self.palette = parse_palette(value).ok_or_else(invalid)?,
+            "theme" => self.theme = parse_theme(value).ok_or_else(invalid)?,
+            _ => return Err(Error::Style(format!("unknown style key `{key}`"))),
+        }
+        Ok(())
+    }
+
+    /// Apply a style file's contents on top of this style.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the offending line if the text is malformed.
+    pub fn merge_str(mut self, text: &str) -> Result<Self> {
+        for (index, raw) in text.lines().enumerate() {
+            let line = strip_comment(raw).trim();
+            if line.is_empty() || line == "[style]" {
+                continue;
+            }
+            let at_line = |e: Error| match e {
+                Error::Style(msg) => Error::Style(format!("line {}: {msg}", index + 1)),
+                other => other,
+            };
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(at_line(Error::Style(format!("expected `key = value`, got `{line}`"))));
+            };
+            self.set_key(key.trim(), unquote(value.trim())).map_err(at_line)?;
+        }
+        Ok(self)
+    }
+
+    /// Apply a style file on top of this style.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    pub fn merge_file(self, path: impl AsRef<Path>) -> Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        self.merge_str(&text)
+    }
+
+    /// Apply `TRUENO_VIZ_*` overrides from `(name, value)` pairs; other
+    /// variables, including `TRUENO_VIZ_*` names that are not style keys, are
+    /// ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a recognised variable has an invalid value.
+    pub fn merge_env<I, K, V>(mut self, vars: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        for (key, value) in env_overrides(vars) {
+            self.set_key(&key, &value)?;
+        }
+        Ok(self)
+    }
+
+    /// Scale a plot's built-in size, chosen for the default 800×600 figure,
+    /// to this style's figure size.
+    ///
+    /// Plots with a non-default aspect (e.g. a square scatter matrix) keep
+    /// their proportions relative to the styled figure.
+    #[must_use]
+    pub fn scaled_size(&self, width: u32, height: u32) -> (u32, u32) {
+        let default = Self::default();
+        let scale = |n: u32, to: u32, from: u32| {
+            u32::try_from((u64::from(n) * u64::from(to) + u64::from(from) / 2) / u64::from(from))
+                .unwrap_or(u32::MAX)
+                .max(1)
+        };
+        (scale(width, self.width, default.width), scale(height, self.height, default.height))
+    }
+
+    /// Path of the user style file, if a candidate location is known.
+    #[must_use]
+    pub fn config_path() -> Option<PathBuf> {
+        if let Some(path) = std::env::var_os(STYLE_PATH_ENV) {
+            return Some(PathBuf::from(path));
+        }
+        let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(config_dir.join("trueno-viz").join("style.toml"))
+    }
+
+    /// Resolve the style from defaults, the style file (if present) and the
+    /// environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the style file or an environment override is invalid.
+    pub fn discover() -> Result<Self> {
+        let mut style = Self::default();
+        if let Some(path) = Self::config_path().filter(|p| p.is_file()) {
+            style = style.merge_file(path)?;
+        }
+        style.merge_env(std::env::vars())
+    }
+}
+
+/// The process-wide style, resolved like [`Style::discover`] on first use.
+///
+/// Resolution is lenient: an invalid style file falls back to the built-in
+/// defaults, and an invalid environment override is skipped without
+/// discarding the file or the other overrides. Call [`Style::discover`]
+/// directly to see the error.
+#[must_use]
+pub fn current() -> Style {
+    if let Some(style) = GLOBAL.read().unwrap_or_else(PoisonError::into_inner).as_ref() {
+        return style.clone();
+    }
+    let mut global = GLOBAL.write().unwrap_or_else(PoisonError::into_inner);
+    global
+        .get_or_insert_with(|| {
+            let file = Style::config_path()
+                .filter(|p| p.is_file())
+                .map_or_else(|| Ok(Style::default()), |p| Style::default().merge_file(p));
+            resolve_lenient(file, std::env::vars())
+        })
+        .clone()
+}
+
+/// Apply each environment override on top of the parsed file, skipping
+/// invalid ones; an unreadable file falls back to the defaults.
+fn resolve_lenient<I, K, V>(file: Result<Style>, vars: I) -> Style
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let mut style = file.unwrap_or_default();
+    for (key, value) in env_overrides(vars) {
+        let mut next = style.clone();
+        if next.set_key(&key, &value).is_ok() {
+            style = next;
+        }
+    }
+    style
+}
+
+/// Style keys settable from `TRUENO_VIZ_*` variables.
+const ENV_KEYS: [&str; 7] =
+    ["width", "height", "font_scale", "text_color", "locale", "palette", "theme"];
+
+/// `(key, value)` for every variable naming a style key.
+fn env_overrides<I, K, V>(vars: I) -> impl Iterator<Item = (String, String)>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    vars.into_iter().filter_map(|(name, value)| {
+        let key = name.as_ref().strip_prefix(ENV_PREFIX)?.to_lowercase();
+        ENV_KEYS.contains(&key.as_str()).then(|| (key, value.as_ref().to_string()))
+    })
+}
+
+/// Locale of the process-wide style.
+#[must_use]
+pub fn locale() -> Locale {
+    if let Some(style) = GLOBAL.read().unwrap_or_else(PoisonError::into_inner).as_ref() {
+        return style.locale;
+    }
+    current().locale
+}
+
+/// Replace the process-wide style.
+pub fn set(style: Style) {
+    *GLOBAL.write().unwrap_or_else(PoisonError::into_inner) = Some(style);
+}
+
+/// Forget the process-wide style so the next [`current`] call re-discovers it.
+pub fn reset() {
+    *GLOBAL.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Drop a trailing `#` comment that is not inside a quoted string.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Remove surrounding double quotes.
+fn unquote(value: &str) -> &str {
+    value.strip_prefix('"').and_then(|v| v.strip_suffix('"')).unwrap_or(value)
+}
+
+/// Parse `#rrggbb`.
+fn parse_hex(value: &str) -> Option<Rgba> {
+    let hex = value.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some(Rgba::rgb(channel(0)?, channel(2)?, channel(4)?))
+}
+
+fn parse_palette(value: &str) -> Option<HeatmapPalette> {
+    Some(match value.to_lowercase().replace('-', "_").as_str() {
+        "viridis" => HeatmapPalette::Viridis,
+        "blues" => HeatmapPalette::Blues,
+        "red_blue" | "redblue" => HeatmapPalette::RedBlue,
+        "magma" => HeatmapPalette::Magma,
+        "heat" => HeatmapPalette::Heat,
+        "greyscale" | "grayscale" => HeatmapPalette::Greyscale,
+        _ => return None,
+    })
+}
+
+fn parse_theme(value: &str) -> Option<Theme> {
+    Some(match value.to_lowercase().as_str() {
+        "grey" | "gray" => Theme::grey(),
+        "minimal" => Theme::minimal(),
+        "bw" => Theme::bw(),
+        "classic" => Theme::classic(),
+        "dark" => Theme::dark(),
+        "void" => Theme::void(),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_str() {
+        let style = Style::default()
+            .merge_str(
+                "# figure defaults\n[style]\nwidth = 1024\nheight=640 # inline\n\
+                 palette = \"magma\"\ntheme = \"dark\"\ntext_color = \"#102030\"\n\
+                 locale = \"de_DE\"\n",
+            )
+            .expect("valid style");
+        assert_eq!((style.width, style.height), (1024, 640));
+        assert_eq!(style.palette, HeatmapPalette::Magma);
+        assert_eq!(style.theme, Theme::dark());
+        assert_eq!(style.text_color, Rgba::rgb(16, 32, 48));
+        assert_eq!(style.locale, Locale::de_de());
+    }
+
+    #[test]
+    fn test_merge_str_errors_name_line() {
+        let err = Style::default().merge_str("width = 10\nfont = 3\n").expect_err("unknown key");
+        assert!(err.to_string().contains("line 2"));
+        assert!(Style::default().merge_str("width = 0").is_err());
+        assert!(Style::default().merge_str("palette").is_err());
+        assert!(Style::default().merge_str("text_color = \"red\"").is_err());
+    }
+
+    #[test]
+    fn test_env_overrides_file() {
+        let style = Style::default()
+            .merge_str("width = 1024\ntheme = \"bw\"")
+            .and_then(|s| {
+                s.merge_env([
+                    ("TRUENO_VIZ_WIDTH", "640"),
+                    ("TRUENO_VIZ_STYLE", "/tmp/ignored.toml"),
+                    ("HOME", "/root"),
+                ])
+            })
+            .expect("valid style");
+        assert_eq!(style.width, 640);
+        assert_eq!(style.theme, Theme::bw());
+        assert!(Style::default().merge_env([("TRUENO_VIZ_HEIGHT", "tall")]).is_err());
+    }
+
+    #[test]
+    fn test_unknown_env_keys_ignored() {
+        let style = Style::default()
+            .merge_env([("TRUENO_VIZ_FUTURE_KEY", "1"), ("TRUENO_VIZ_WIDHT", "9")])
+            .expect("unknown keys are skipped");
+        assert_eq!(style, Style::default());
+    }
+
+    #[test]
+    fn test_lenient_resolution_keeps_file() {
+        let file = Style::default().merge_str(
+            "width = 1024
+theme = \"bw\"",
+        );
+        let style = resolve_lenient(
+            file,
+            [("TRUENO_VIZ_HEIGHT", "tall"), ("TRUENO_VIZ_PALETTE", "magma"), ("TRUENO_VIZ_X", "1")],
+        );
+        assert_eq!((style.width, style.height), (1024, 600));
+        assert_eq!(style.theme, Theme::bw());
+        assert_eq!(style.palette, HeatmapPalette::Magma);
+
+        let broken = Style::default().merge_str("width = wide");
+        assert_eq!(resolve_lenient(broken, [("TRUENO_VIZ_WIDTH", "640")]).width, 640);
+    }
+
+    #[test]
+    fn test_scaled_size() {
+        assert_eq!(Style::default().scaled_size(800, 300), (800, 300));
+        let style = Style { width: 1024, height: 640, ..Style::default() };
+        assert_eq!(style.scaled_size(800, 600), (1024, 640));
+        assert_eq!(style.scaled_size(800, 800), (1024, 853));
+    }
+
+    #[test]
+    fn test_global_registry() {
+        // Other tests build plots concurrently, so only install the defaults
+        set(Style::default());
+        assert_eq!(current(), Style::default());
+        reset();
+        let _ = current();
+    }
+}
@@ -0,0 +1,394 @@
+//! Interactive terminal chart viewer (a gnuplot-like quick look for CLI
+//! workflows).
+//!
+//! [`interactive_view`] draws a chart with the half-block
+//! [`TerminalEncoder`] on the alternate screen and re-renders it as the view
+//! is panned or zoomed from the keyboard. The raw-mode session and input
+//! loop are shared with the TUI monitor, so the terminal is restored when the
+//! viewer returns, fails or panics (including `panic = "abort"` builds).
+//!
+//! | Keys                    | Action                |
+//! |-------------------------|-----------------------|
+//! | `←` `→` `↑` `↓` / `hjkl` | pan by 10% of the view |
+//! | `+` `=` / `-` `_`       | zoom in / out         |
+//! | `r` / `0`               | reset to the full view |
+//! | `q` / `Esc` / `Ctrl+C`  | quit                  |
+//!
+//! Any chart implementing [`Viewable`] can be shown; built Grammar of
+//! Graphics plots implement it out of the box.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use trueno_viz::grammar::{GGPlot, Geom};
+//! use trueno_viz::terminal::interactive_view;
+//!
+//! let xs: Vec<f32> = (0..200).map(|i| i as f32 / 10.0).collect();
+//! let ys: Vec<f32> = xs.iter().map(|x| x.sin()).collect();
+//! let mut plot = GGPlot::new().data_xy(&xs, &ys).geom(Geom::line()).build().unwrap();
+//! interactive_view(&mut plot).unwrap();
+//! ```
+
+use std::io::{stdout, Write};
+use std::ops::ControlFlow;
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::terminal::{Clear, ClearType};
+use crossterm::{cursor, queue, style::Print};
+
+use crate::error::Result;
+use crate::framebuffer::Framebuffer;
+use crate::grammar::BuiltGGPlot;
+use crate::output::{TerminalEncoder, TerminalMode};
+use crate::tty::{event_loop, LoopEvent, RawScreen};
+
+/// Fraction of the view moved by one pan key press.
+const PAN_STEP: f32 = 0.1;
+
+/// Span ratio applied by one zoom key press.
+const ZOOM_STEP: f32 = 1.25;
+
+/// Minimum width in pixels charts are rendered at before being shrunk to
+/// the terminal, so margins and text keep their usual proportions.
+const MIN_RENDER_WIDTH: u32 = 480;
+
+/// Visible data window: x and y axis limits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// Left edge.
+    pub x_min: f32,
+    /// Right edge.
+    pub x_max: f32,
+    /// Bottom edge.
+    pub y_min: f32,
+    /// Top edge.
+    pub y_max: f32,
+}
+
+impl Viewport {
+    /// Create a viewport; an empty or inverted axis is widened to span 1.
+    #[must_use]
+    pub fn new(x: (f32, f32), y: (f32, f32)) -> Self {
+        let (x_min, x_max) = widen(x);
+        let (y_min, y_max) = widen(y);
+        Self { x_min, x_max, y_min, y_max }
+    }
+
+    /// Width of the x range.
+    #[must_use]
+    pub fn x_span(&self) -> f32 {
+        self.x_max - self.x_min
+    }
+
+    /// Height of the y range.
+    #[must_use]
+    pub fn y_span(&self) -> f32 {
+        self.y_max - self.y_min
+    }
+
+    /// Shift the view by fractions of its own size (positive is right/up).
+    #[must_use]
+    pub fn pan(self, fx: f32, fy: f32) -> Self {
+        let (dx, dy) = (self.x_span() * fx, self.y_span() * fy);
+        Self {
+            x_min: self.x_min + dx,
+            x_max: self.x_max + dx,
+            y_min: self.y_min + dy,
+            y_max: self.y_max + dy,
+        }
+    }
+
+    /// Zoom about the centre; `factor > 1` zooms in.
+    #[must_use]
+    pub fn zoom(self, factor: f32) -> Self {
+        let (cx, cy) = ((self.x_min + self.x_max) / 2.0, (self.y_min + self.y_max) / 2.0);
+        let (hw, hh) = (self.x_span() / factor / 2.0, self.y_span() / factor / 2.0);
+        Self::new((cx - hw, cx + hw), (cy - hh, cy + hh))
+    }
+
+    /// The view after `action`, with `home` as the reset target.
+    #[must_use]
+    pub fn apply(self, action: ViewAction, home: Self) -> Self {
+        match action {
+            ViewAction::Left => self.pan(-PAN_STEP, 0.0),
+            ViewAction::Right => self.pan(PAN_STEP, 0.0),
+            ViewAction::Up => self.pan(0.0, PAN_STEP),
+            ViewAction::Down => self.pan(0.0, -PAN_STEP),
+            ViewAction::ZoomIn => self.zoom(ZOOM_STEP),
+            ViewAction::ZoomOut => self.zoom(1.0 / ZOOM_STEP),
+            ViewAction::Reset => home,
+            ViewAction::Quit | ViewAction::None => self,
+        }
+    }
+}
+
+/// Keep `min < max`, widening degenerate ranges around their midpoint.
+fn widen((min, max): (f32, f32)) -> (f32, f32) {
+    if min < max {
+        (min, max)
+    } else {
+        let mid = (min + max) / 2.0;
+        (mid - 0.5, mid + 0.5)
+    }
+}
+
+/// Viewer action resulting from a key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewAction {
+    /// Close the viewer.
+    Quit,
+    /// Pan left.
+    Left,
+    /// Pan right.
+    Right,
+    /// Pan up.
+    Up,
+    /// Pan down.
+    Down,
+    /// Zoom in.
+    ZoomIn,
+    /// Zoom out.
+    ZoomOut,
+    /// Return to the full view.
+    Reset,
+    /// No action.
+    None,
+}
+
+impl ViewAction {
+    /// Map a key event to a viewer action.
+    #[must_use]
+    pub fn from_key(event: KeyEvent) -> Self {
+        if event.modifiers.contains(KeyModifiers::CONTROL) {
+            if let KeyCode::Char('c' | 'q') = event.code {
+                return Self::Quit;
+            }
+        }
+
+        match event.code {
+            KeyCode::Char('q') | KeyCode::Esc => Self::Quit,
+            KeyCode::Left | KeyCode::Char('h') => Self::Left,
+            KeyCode::Right | KeyCode::Char('l') => Self::Right,
+            KeyCode::Up | KeyCode::Char('k') => Self::Up,
+            KeyCode::Down | KeyCode::Char('j') => Self::Down,
+            KeyCode::Char('+' | '=') => Self::ZoomIn,
+            KeyCode::Char('-' | '_') => Self::ZoomOut,
+            KeyCode::Char('r' | '0') | KeyCode::Home => Self::Reset,
+            _ => Self::None,
+        }
+    }
+}
+
+/// A chart that can be re-rendered for an arbitrary data window.
+pub trait Viewable {
+    /// The full view shown on start and restored by reset.
+    fn home(&self) -> Viewport;
+
+    /// Render the chart showing `view` at `width` x `height` pixels.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chart cannot be rendered at this size.
+    fn render_view(&mut self, view: &Viewport, width: u32, height: u32) -> Result<Framebuffer>;
+}
+
+impl Viewable for BuiltGGPlot {
+    fn home(&self) -> Viewport {
+        let (x_min, x_max, y_min, y_max) = self.limits();
+        Viewport::new((x_min, x_max), (y_min, y_max))
+    }
+
+    /// Sets the plot's limits and size, which it keeps after the viewer closes.
+    fn render_view(&mut self, view: &Viewport, width: u32, height: u32) -> Result<Framebuffer> {
+        self.set_limits((view.x_min, view.x_max), (view.y_min, view.y_max));
+        self.resize(width, height);
+        self.to_framebuffer()
+    }
+}
+
+/// Show `chart` full-screen until the user quits.
+///
+/// # Errors
+///
+/// Returns an error if terminal setup fails or the chart cannot be rendered.
+pub fn interactive_view<C: Viewable + ?Sized>(chart: &mut C) -> Result<()> {
+    let _screen = RawScreen::enter()?;
+    view_loop(chart)
+}
+
+/// Redraw on every view change or resize; block on input otherwise.
+fn view_loop<C: Viewable + ?Sized>(chart: &mut C) -> Result<()> {
+    let home = chart.home();
+    let mut view = home;
+    let mut dirty = true;
+    let mut out = stdout();
+
+    event_loop(Duration::from_millis(250), |event| {
+        match event {
+            LoopEvent::Draw if dirty => {
+                let (cols, rows) = crossterm::terminal::size()?;
+                let screen = render_frame(chart, &view, &home, u32::from(cols), u32::from(rows))?;
+                queue!(out, cursor::MoveTo(0, 0), Clear(ClearType::All), Print(screen))?;
+                out.flush()?;
+                dirty = false;
+            }
+            LoopEvent::Key(key) => {
+                let action = ViewAction::from_key(key);
+                if action == ViewAction::Quit {
+                    return Ok(ControlFlow::Break(()));
+                }
+                let next = view.apply(action, home);
+                dirty = next != view;
+                view = next;
+            }
+            LoopEvent::Resize => dirty = true,
+            LoopEvent::Draw | LoopEvent::Idle => {}
+        }
+        Ok(ControlFlow::Continue(()))
+    })
+}
+
+/// Render one screen: the chart on `rows - 1` lines and a status line, with
+/// raw-mode `\r\n` line endings.
+fn render_frame<C: Viewable + ?Sized>(
+    chart: &mut C,
+    view: &Viewport,
+    home: &Viewport,
+    cols: u32,
+    rows: u32,
+) -> Result<String> {
+    let (cols, lines) = (cols.max(1), rows.saturating_sub(1).max(1));
+    // Half-blocks give one pixel per column and two per line
+    let (width, height) = (cols, lines * 2);
+    let oversample = MIN_RENDER_WIDTH.div_ceil(width).max(2);
+    let fb = chart.render_view(view, width * oversample, height * oversample)?;
+    let fb = shrink(&fb, width, height)?;
+
+    let encoder =
+        TerminalEncoder::new().mode(TerminalMode::UnicodeHalfBlock).width(width).height(height);
+    let mut screen = encoder.render(&fb).replace('\n', "\r\n");
+    screen.extend(status_line(view, home).chars().take(cols as usize));
+    Ok(screen)
+}
+
+/// Current limits, zoom level and key help.
+fn status_line(view: &Viewport, home: &Viewport) -> String {
+    let zoom = home.x_span() / view.x_span();
+    format!(
+        "x [{:.4}, {:.4}]  y [{:.4}, {:.4}]  {zoom:.2}x  \
+         arrows/hjkl pan  +/- zoom  r reset  q quit",
+        view.x_min, view.x_max, view.y_min, view.y_max
+    )
+}
+
+/// Box-filter `fb` down to `width` x `height`, so thin lines survive as
+/// partial coverage instead of being skipped by point sampling.
+fn shrink(fb: &Framebuffer, width: u32, height: u32) -> Result<Framebuffer> {
+    let mut out = Framebuffer::new(width, height)?;
+    let (fx, fy) = (fb.width() / width, fb.height() / height);
+    if fx == 0 || fy == 0 {
+        return Ok(out);
+    }
+    let count = fx * fy;
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = [0u32; 4];
+            for sy in y * fy..(y + 1) * fy {
+                for sx in x * fx..(x + 1) * fx {
+                    if let Some(p) = fb.get_pixel(sx, sy) {
+                        for (acc, c) in sum.iter_mut().zip([p.r, p.g, p.b, p.a]) {
+                            *acc += u32::from(c);
+                        }
+                    }
+                }
+            }
+            let [r, g, b, a] = sum.map(|c| u8::try_from(c / count).unwrap_or(u8::MAX));
+            out.set_pixel(x, y, crate::color::Rgba::new(r, g, b, a));
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Rgba;
+    use crate::grammar::{GGPlot, Geom};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_key_mapping() {
+        assert_eq!(ViewAction::from_key(key(KeyCode::Char('q'))), ViewAction::Quit);
+        assert_eq!(ViewAction::from_key(key(KeyCode::Esc)), ViewAction::Quit);
+        assert_eq!(
+            ViewAction::from_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            ViewAction::Quit
+        );
+        assert_eq!(ViewAction::from_key(key(KeyCode::Left)), ViewAction::Left);
+        assert_eq!(ViewAction::from_key(key(KeyCode::Char('k'))), ViewAction::Up);
+        assert_eq!(ViewAction::from_key(key(KeyCode::Char('+'))), ViewAction::ZoomIn);
+        assert_eq!(ViewAction::from_key(key(KeyCode::Char('-'))), ViewAction::ZoomOut);
+        assert_eq!(ViewAction::from_key(key(KeyCode::Char('r'))), ViewAction::Reset);
+        assert_eq!(ViewAction::from_key(key(KeyCode::Char('x'))), ViewAction::None);
+    }
+
+    #[test]
+    fn test_viewport_pan_zoom_reset() {
+        let home = Viewport::new((0.0, 10.0), (-1.0, 1.0));
+
+        let view = home.apply(ViewAction::Right, home).apply(ViewAction::Up, home);
+        assert_close(view.x_min, 1.0);
+        assert_close(view.x_max, 11.0);
+        assert_close(view.y_min, -0.8);
+
+        let zoomed = view.apply(ViewAction::ZoomIn, home);
+        assert_close(zoomed.x_span(), 8.0);
+        assert_close((zoomed.x_min + zoomed.x_max) / 2.0, 6.0);
+        let back = zoomed.apply(ViewAction::ZoomOut, home);
+        assert_close(back.x_span(), 10.0);
+
+        assert_eq!(back.apply(ViewAction::Reset, home), home);
+        assert_close(Viewport::new((3.0, 3.0), (2.0, 1.0)).x_span(), 1.0);
+    }
+
+    #[test]
+    fn test_render_frame_fits_terminal() {
+        let xs: Vec<f32> = (0..50).map(|i| i as f32).collect();
+        let ys: Vec<f32> = xs.iter().map(|x| (x / 5.0).sin()).collect();
+        let mut plot =
+            GGPlot::new().data_xy(&xs, &ys).geom(Geom::line()).build().expect("plot should build");
+        let home = plot.home();
+        assert!(home.x_min < 0.0 && home.x_max > 49.0);
+
+        let screen = render_frame(&mut plot, &home, &home, 60, 20).expect("frame should render");
+        let lines: Vec<&str> = screen.split("\r\n").collect();
+        assert_eq!(lines.len(), 20);
+        assert!(lines[19].starts_with("x [") && lines[19].contains("1.00x"));
+        assert!(lines[19].chars().count() <= 60);
+
+        let zoomed = home.zoom(2.0);
+        let closer = render_frame(&mut plot, &zoomed, &home, 60, 20).expect("frame should render");
+        assert_ne!(closer, screen);
+        assert!(closer.contains("2.00x"));
+    }
+
+    #[test]
+    fn test_shrink_averages_blocks() {
+        let mut fb = Framebuffer::new(4, 2).expect("framebuffer");
+        fb.clear(Rgba::WHITE);
+        fb.set_pixel(0, 0, Rgba::BLACK);
+        fb.set_pixel(1, 1, Rgba::BLACK);
+
+        let small = shrink(&fb, 2, 1).expect("shrink");
+        assert_eq!(small.get_pixel(0, 0).map(|p| p.r), Some(127));
+        assert_eq!(small.get_pixel(1, 0), Some(Rgba::WHITE));
+    }
+}
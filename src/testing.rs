@@ -0,0 +1,446 @@
+//! Figure comparison for reviewing rendered output changes.
+//!
+//! [`compare_figures`] diffs two rendered framebuffers. Plots draw each
+//! series in its own color, so the structural view groups pixels by exact
+//! color: a color covering at least [`MIN_SERIES_PIXELS`] pixels is a
+//! series, with a pixel count and bounding box. Series present in only one
+//! figure are added or removed (or recolored when their extents overlap),
+//! and series whose footprint moved or grew are changed. A visual diff image
+//! marks removed pixels red, added pixels green and recolored pixels yellow
+//! over a faded copy of the first figure.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::color::Rgba;
+use crate::error::{Error, Result};
+use crate::framebuffer::Framebuffer;
+
+/// Smallest pixel count for a color to count as a series (smaller clusters
+/// are anti-aliasing blends and glyph edges).
+pub const MIN_SERIES_PIXELS: usize = 16;
+
+/// Per-channel difference tolerated before a pixel counts as changed.
+pub const PIXEL_TOLERANCE: u8 = 8;
+
+/// Bounding-box overlap (intersection over union) above which a removed and
+/// an added series are reported as one recolored series.
+const RECOLOR_OVERLAP: f32 = 0.5;
+
+const REMOVED: Rgba = Rgba::new(220, 40, 40, 255);
+const ADDED: Rgba = Rgba::new(40, 170, 60, 255);
+const RECOLORED: Rgba = Rgba::new(230, 180, 0, 255);
+
+/// Inclusive pixel bounding box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PixelBounds {
+    /// Leftmost column.
+    pub min_x: u32,
+    /// Topmost row.
+    pub min_y: u32,
+    /// Rightmost column.
+    pub max_x: u32,
+    /// Bottom row.
+    pub max_y: u32,
+}
+
+impl PixelBounds {
+    fn point(x: u32, y: u32) -> Self {
+        Self { min_x: x, min_y: y, max_x: x, max_y: y }
+    }
+
+    fn include(&mut self, x: u32, y: u32) {
+        self.min_x = self.min_x.min(x);
+        self.min_y = self.min_y.min(y);
+        self.max_x = self.max_x.max(x);
+        self.max_y = self.max_y.max(y);
+    }
+
+    fn area(&self) -> u64 {
+        u64::from(self.max_x - self.min_x + 1) * u64::from(self.max_y - self.min_y + 1)
+    }
+
+    /// Intersection over union with another box (0 when disjoint).
+    #[must_use]
+    pub fn overlap(&self, other: &Self) -> f32 {
+        let w = (self.max_x.min(other.max_x) + 1).saturating_sub(self.min_x.max(other.min_x));
+        let h = (self.max_y.min(other.max_y) + 1).saturating_sub(self.min_y.max(other.min_y));
+        let inter = u64::from(w) * u64::from(h);
+        inter as f32 / (self.area() + other.area() - inter) as f32
+    }
+}
+
+impl fmt::Display for PixelBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})-({},{})", self.min_x, self.min_y, self.max_x, self.max_y)
+    }
+}
+
+/// Footprint of one color in a figure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeriesExtent {
+    /// Series color.
+    pub color: Rgba,
+    /// Pixels drawn in this color.
+    pub pixels: usize,
+    /// Bounding box of those pixels.
+    pub bounds: PixelBounds,
+}
+
+/// How one series differs between the two figures.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SeriesChange {
+    /// Only in the second figure.
+    Added(SeriesExtent),
+    /// Only in the first figure.
+    Removed(SeriesExtent),
+    /// Same place, different color.
+    Recolored {
+        /// Extent in the first figure.
+        before: SeriesExtent,
+        /// Extent in the second figure.
+        after: SeriesExtent,
+    },
+    /// Same color, different pixel count or bounding box.
+    Changed {
+        /// Extent in the first figure.
+        before: SeriesExtent,
+        /// Extent in the second figure.
+        after: SeriesExtent,
+    },
+}
+
+/// Structural and visual difference between two rendered figures.
+#[derive(Debug, Clone)]
+pub struct FigureDiff {
+    /// Background (most common) colors of the two figures.
+    pub background: (Rgba, Rgba),
+    /// Bounding boxes of all non-background pixels (None for a blank figure).
+    pub content_bounds: (Option<PixelBounds>, Option<PixelBounds>),
+    /// Per-series changes, largest series first; unchanged series are omitted.
+    pub series: Vec<SeriesChange>,
+    /// Pixels differing by more than [`PIXEL_TOLERANCE`] in any channel.
+    pub changed_pixels: usize,
+    /// Total pixels compared.
+    pub total_pixels: usize,
+    /// Faded first figure with changed pixels highlighted.
+    pub image: Framebuffer,
+}
+
+impl FigureDiff {
+    /// True when no pixel changed beyond the tolerance.
+    #[must_use]
+    pub fn is_identical(&self) -> bool {
+        self.changed_pixels == 0
+    }
+
+    /// Fraction of pixels that changed (0.0 to 1.0).
+    #[must_use]
+    pub fn changed_fraction(&self) -> f64 {
+        self.changed_pixels as f64 / self.total_pixels.max(1) as f64
+    }
+
+    /// True when the overall content bounding box moved or resized.
+    #[must_use]
+    pub fn bounds_shifted(&self) -> bool {
+        self.content_bounds.0 != self.content_bounds.1
+    }
+}
+
+impl fmt::Display for FigureDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_identical() {
+            return writeln!(f, "figures are identical");
+        }
+        writeln!(
+            f,
+            "{} of {} pixels changed ({:.2}%)",
+            self.changed_pixels,
+            self.total_pixels,
+            self.changed_fraction() * 100.0
+        )?;
+        if self.background.0 != self.background.1 {
+            writeln!(f, "background {} -> {}", hex(self.background.0), hex(self.background.1))?;
+        }
+        if self.bounds_shifted() {
+            let show = |b: Option<PixelBounds>| b.map_or("empty".to_string(), |b| b.to_string());
+            writeln!(
+                f,
+                "content bounds {} -> {}",
+                show(self.content_bounds.0),
+                show(self.content_bounds.1)
+            )?;
+        }
+        for change in &self.series {
+            match change {
+                SeriesChange::Added(s) => {
+                    writeln!(f, "+ series {} ({} px at {})", hex(s.color), s.pixels, s.bounds)?;
+                }
+                SeriesChange::Removed(s) => {
+                    writeln!(f, "- series {} ({} px at {})", hex(s.color), s.pixels, s.bounds)?;
+                }
+                SeriesChange::Recolored { before, after } => {
+                    writeln!(
+                        f,
+                        "~ series recolored {} -> {}",
+                        hex(before.color),
+                        hex(after.color)
+                    )?;
+                }
+                SeriesChange::Changed { before, after } => writeln!(
+                    f,
+                    "~ series {} {} px at {} -> {} px at {}",
+                    hex(before.color),
+                    before.pixels,
+                    before.bounds,
+                    after.pixels,
+                    after.bounds
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn hex(color: Rgba) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+}
+
+/// Color footprints and background of one figure.
+struct Census {
+    background: Rgba,
+    content: Option<PixelBounds>,
+    series: Vec<SeriesExtent>,
+}
+
+fn census(fb: &Framebuffer) -> Census {
+    let mut colors: HashMap<[u8; 4], (usize, PixelBounds)> = HashMap::new();
+    for y in 0..fb.height() {
+        let Some(row) = fb.row(y) else { continue };
+        for (x, px) in (0u32..).zip(row.chunks_exact(4)) {
+            let key = [px[0], px[1], px[2], px[3]];
+            colors
+                .entry(key)
+                .and_modify(|(n, b)| {
+                    *n += 1;
+                    b.include(x, y);
+                })
+                .or_insert((1, PixelBounds::point(x, y)));
+        }
+    }
+
+    let background =
+        colors.iter().max_by_key(|(key, (n, _))| (*n, **key)).map_or([0; 4], |(key, _)| *key);
+    let content =
+        colors.iter().filter(|(key, _)| **key != background).map(|(_, (_, b))| *b).reduce(
+            |mut acc, b| {
+                acc.include(b.min_x, b.min_y);
+                acc.include(b.max_x, b.max_y);
+                acc
+            },
+        );
+    let mut series: Vec<SeriesExtent> = colors
+        .into_iter()
+        .filter(|(key, (n, _))| *key != background && *n >= MIN_SERIES_PIXELS)
+        .map(|(key, (pixels, bounds))| SeriesExtent {
+            color: Rgba::from_array(key),
+            pixels,
+            bounds,
+        })
+        .collect();
+    series.sort_by_key(|s| (std::cmp::Reverse(s.pixels), s.color.to_array()));
+
+    Census { background: Rgba::from_array(background), content, series }
+}
+
+fn pixels_differ(a: &[u8], b: &[u8]) -> bool {
+    a.iter().zip(b).any(|(x, y)| x.abs_diff(*y) > PIXEL_TOLERANCE)
+}
+
+/// Compare two rendered figures of the same size.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidDimensions`] (with the second figure's size) when
+/// the figures differ in size.
+///
+/// # Example
+///
+/// ```
+/// use trueno_viz::color::Rgba;
+/// use trueno_viz::framebuffer::Framebuffer;
+/// use trueno_viz::testing::{compare_figures, SeriesChange};
+///
+/// let mut a = Framebuffer::new(40, 40).unwrap();
+/// a.clear(Rgba::WHITE);
+/// let mut b = a.clone();
+/// a.fill_rect(5, 5, 10, 10, Rgba::BLUE);
+/// b.fill_rect(8, 5, 10, 10, Rgba::BLUE);
+///
+/// let diff = compare_figures(&a, &b).unwrap();
+/// assert!(matches!(diff.series[0], SeriesChange::Changed { .. }));
+/// assert!(diff.bounds_shifted());
+/// ```
+pub fn compare_figures(a: &Framebuffer, b: &Framebuffer) -> Result<FigureDiff> {
+    if a.width() != b.width() || a.height() != b.height() {
+        return Err(Error::InvalidDimensions { width: b.width(), height: b.height() });
+    }
+
+    let before = census(a);
+    let after = census(b);
+
+    let mut series = Vec::new();
+    let mut added: Vec<SeriesExtent> = Vec::new();
+    let mut removed: Vec<SeriesExtent> = Vec::new();
+    for s in &before.series {
+        match after.series.iter().find(|t| t.color == s.color) {
+            Some(t) if t == s => {}
+            Some(t) => series.push(SeriesChange::Changed { before: *s, after: *t }),
+            None => removed.push(*s),
+        }
+    }
+    for t in &after.series {
+        if !before.series.iter().any(|s| s.color == t.color) {
+            added.push(*t);
+        }
+    }
+    for s in removed {
+        let best = added
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (i, s.bounds.overlap(&t.bounds)))
+            .filter(|&(_, o)| o > RECOLOR_OVERLAP)
+            .max_by(|x, y| x.1.total_cmp(&y.1));
+        match best {
+            Some((i, _)) => {
+                series.push(SeriesChange::Recolored { before: s, after: added.remove(i) });
+            }
+            None => series.push(SeriesChange::Removed(s)),
+        }
+    }
+    series.extend(added.into_iter().map(SeriesChange::Added));
+    series.sort_by_key(|c| {
+        let pixels = match c {
+            SeriesChange::Added(s) | SeriesChange::Removed(s) => s.pixels,
+            SeriesChange::Recolored { before, after } | SeriesChange::Changed { before, after } => {
+                before.pixels.max(after.pixels)
+            }
+        };
+        std::cmp::Reverse(pixels)
+    });
+
+    let mut image = Framebuffer::new(a.width(), a.height())?;
+    let faded = |px: &[u8]| {
+        let luma = (u32::from(px[0]) * 3 + u32::from(px[1]) * 6 + u32::from(px[2])) / 10;
+        let v = (255 - (255 - luma) / 4) as u8;
+        Rgba::rgb(v, v, v)
+    };
+    let mut changed_pixels = 0;
+    for y in 0..a.height() {
+        let (Some(row_a), Some(row_b)) = (a.row(y), b.row(y)) else { continue };
+        for (x, (pa, pb)) in (0u32..).zip(row_a.chunks_exact(4).zip(row_b.chunks_exact(4))) {
+            let color = if pixels_differ(pa, pb) {
+                changed_pixels += 1;
+                let was_bg = !pixels_differ(pa, &before.background.to_array());
+                let is_bg = !pixels_differ(pb, &after.background.to_array());
+                match (was_bg, is_bg) {
+                    (true, false) => ADDED,
+                    (false, true) => REMOVED,
+                    _ => RECOLORED,
+                }
+            } else {
+                faded(pa)
+            };
+            image.set_pixel(x, y, color);
+        }
+    }
+
+    Ok(FigureDiff {
+        background: (before.background, after.background),
+        content_bounds: (before.content, after.content),
+        series,
+        changed_pixels,
+        total_pixels: a.pixel_count(),
+        image,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canvas() -> Framebuffer {
+        let mut fb = Framebuffer::new(60, 40).expect("framebuffer");
+        fb.clear(Rgba::WHITE);
+        fb
+    }
+
+    #[test]
+    fn test_identical_figures() {
+        let mut a = canvas();
+        a.fill_rect(10, 10, 8, 8, Rgba::BLUE);
+        let diff = compare_figures(&a, &a.clone()).expect("same size");
+        assert!(diff.is_identical());
+        assert!(diff.series.is_empty());
+        assert!(!diff.bounds_shifted());
+        assert_eq!(diff.to_string(), "figures are identical\n");
+        assert!(compare_figures(&a, &Framebuffer::new(10, 10).expect("framebuffer")).is_err());
+    }
+
+    #[test]
+    fn test_series_added_removed_and_moved() {
+        let mut a = canvas();
+        let mut b = canvas();
+        a.fill_rect(2, 2, 10, 10, Rgba::BLUE);
+        b.fill_rect(6, 2, 10, 10, Rgba::BLUE);
+        a.fill_rect(40, 20, 5, 5, Rgba::RED);
+        b.fill_rect(20, 30, 6, 6, Rgba::GREEN);
+
+        let diff = compare_figures(&a, &b).expect("same size");
+        assert_eq!(diff.series.len(), 3);
+        assert!(matches!(
+            diff.series[0],
+            SeriesChange::Changed { before, after } if after.bounds.min_x == before.bounds.min_x + 4
+        ));
+        assert!(matches!(diff.series[1], SeriesChange::Added(s) if s.color == Rgba::GREEN));
+        assert!(matches!(diff.series[2], SeriesChange::Removed(s) if s.color == Rgba::RED));
+        assert!(diff.bounds_shifted());
+
+        let text = diff.to_string();
+        assert!(text.contains("+ series #00ff00 (36 px"));
+        assert!(text.contains("- series #ff0000"));
+    }
+
+    #[test]
+    fn test_recolor_and_diff_image() {
+        let mut a = canvas();
+        let mut b = canvas();
+        a.fill_rect(10, 10, 10, 10, Rgba::BLUE);
+        b.fill_rect(10, 10, 10, 10, Rgba::RED);
+        b.fill_rect(40, 5, 4, 4, Rgba::GREEN);
+        a.fill_rect(0, 39, 1, 1, Rgba::BLACK);
+
+        let diff = compare_figures(&a, &b).expect("same size");
+        assert!(matches!(
+            diff.series[0],
+            SeriesChange::Recolored { before, after } if before.color == Rgba::BLUE && after.color == Rgba::RED
+        ));
+        assert_eq!(diff.changed_pixels, 100 + 16 + 1);
+        assert_eq!(diff.image.get_pixel(15, 15), Some(RECOLORED));
+        assert_eq!(diff.image.get_pixel(41, 6), Some(ADDED));
+        assert_eq!(diff.image.get_pixel(0, 39), Some(REMOVED));
+        assert_eq!(diff.image.get_pixel(30, 30), Some(Rgba::WHITE));
+        // One-pixel marks are below the series threshold but still counted
+        assert!(diff.to_string().contains("(4.88%)"));
+    }
+
+    #[test]
+    fn test_bounds_overlap() {
+        let a = PixelBounds { min_x: 0, min_y: 0, max_x: 9, max_y: 9 };
+        let b = PixelBounds { min_x: 5, min_y: 0, max_x: 14, max_y: 9 };
+        let c = PixelBounds { min_x: 20, min_y: 20, max_x: 21, max_y: 21 };
+        assert!((a.overlap(&a) - 1.0).abs() < f32::EPSILON);
+        assert!((a.overlap(&b) - 50.0 / 150.0).abs() < 1e-6);
+        assert!(a.overlap(&c).abs() < f32::EPSILON);
+    }
+}
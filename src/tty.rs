@@ -0,0 +1,110 @@
+//! Raw-mode terminal session and input loop shared by the interactive
+//! viewer (`terminal`) and the TUI monitor (`monitor`).
+//!
+//! [`RawScreen`] switches to raw mode on the alternate screen and restores
+//! the terminal when dropped. A panic hook installed on first use restores it
+//! too, since `panic = "abort"` builds never run the drop. [`event_loop`]
+//! draws, polls for input and dispatches key presses and resizes until the
+//! handler breaks out.
+
+use std::io::{self, stdout};
+use std::ops::ControlFlow;
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyEvent, KeyEventKind};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::{cursor, ExecutableCommand};
+
+/// Whether a [`RawScreen`] currently owns the terminal.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Guards the one-time panic hook installation.
+static PANIC_HOOK: Once = Once::new();
+
+/// Raw mode on the alternate screen, restored on drop or panic so an error
+/// while drawing does not leave the terminal unusable.
+pub(crate) struct RawScreen;
+
+impl RawScreen {
+    /// Enter raw mode and the alternate screen with the cursor hidden.
+    pub(crate) fn enter() -> io::Result<Self> {
+        PANIC_HOOK.call_once(install_panic_hook);
+        enable_raw_mode()?;
+        ACTIVE.store(true, Ordering::SeqCst);
+        // From here on a failure still restores whatever was set up
+        let screen = Self;
+        stdout().execute(EnterAlternateScreen)?.execute(cursor::Hide)?;
+        Ok(screen)
+    }
+}
+
+impl Drop for RawScreen {
+    fn drop(&mut self) {
+        restore();
+    }
+}
+
+/// Restore the terminal before the previous hook prints the panic message.
+fn install_panic_hook() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        restore();
+        previous(info);
+    }));
+}
+
+/// Leave the alternate screen and raw mode if a [`RawScreen`] is active.
+fn restore() {
+    if !ACTIVE.swap(false, Ordering::SeqCst) {
+        return;
+    }
+    // Best effort: each step is independent and there is no caller to report to
+    let mut out = stdout();
+    let _ = out.execute(cursor::Show);
+    let _ = out.execute(LeaveAlternateScreen);
+    let _ = disable_raw_mode();
+}
+
+/// Step of [`event_loop`] passed to its handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LoopEvent {
+    /// Draw the current state; sent at the start of every iteration.
+    Draw,
+    /// A key was pressed.
+    Key(KeyEvent),
+    /// The terminal was resized.
+    Resize,
+    /// The poll timed out or returned an event without a handler.
+    Idle,
+}
+
+/// Run `handler` until it returns [`ControlFlow::Break`]: each iteration
+/// sends [`LoopEvent::Draw`], waits up to `poll_timeout` for input and then
+/// sends the key press, resize or [`LoopEvent::Idle`].
+pub(crate) fn event_loop<E: From<io::Error>>(
+    poll_timeout: Duration,
+    mut handler: impl FnMut(LoopEvent) -> Result<ControlFlow<()>, E>,
+) -> Result<(), E> {
+    loop {
+        if handler(LoopEvent::Draw)?.is_break() {
+            return Ok(());
+        }
+        let next = if event::poll(poll_timeout)? {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => LoopEvent::Key(key),
+                Event::Resize(..) => LoopEvent::Resize,
+                _ => LoopEvent::Idle,
+            }
+        } else {
+            LoopEvent::Idle
+        };
+        if handler(next)?.is_break() {
+            return Ok(());
+        }
+    }
+}
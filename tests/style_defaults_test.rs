@@ -0,0 +1,69 @@
+//! Style defaults picked up by plot builders.
+//!
+//! Installs a process-wide style, so it lives in its own test binary rather
+//! than alongside the unit tests that build plots at the default size.
+//!
+//! Run: cargo test --test `style_defaults_test`
+
+use trueno_viz::color::Rgba;
+use trueno_viz::framebuffer::Framebuffer;
+use trueno_viz::plots::{Colormap, Histogram, ParetoChart, WaffleChart};
+use trueno_viz::style::{self, Style};
+
+/// Pixels exactly matching `color`.
+fn count_pixels(fb: &Framebuffer, color: Rgba) -> usize {
+    let want = [color.r, color.g, color.b, color.a];
+    fb.pixels().chunks_exact(4).filter(|px| *px == want).count()
+}
+
+// Both checks install a global style, so they run in one test
+#[test]
+fn test_builders_use_styled_defaults() {
+    style::set(Style { width: 1000, height: 750, ..Style::default() });
+
+    // Built for 800×600: takes the styled size as is
+    let fb = Histogram::new()
+        .data(&[1.0, 2.0, 2.0, 3.0])
+        .build()
+        .expect("histogram")
+        .to_framebuffer()
+        .expect("render");
+    assert_eq!((fb.width(), fb.height()), (1000, 750));
+
+    // Built for other sizes: scaled by the same factor
+    let fb = WaffleChart::new()
+        .category("a", 3.0)
+        .build()
+        .expect("waffle")
+        .to_framebuffer()
+        .expect("render");
+    assert_eq!((fb.width(), fb.height()), (500, 500));
+
+    let fb = Colormap::from_colors("gray", &[Rgba::BLACK, Rgba::WHITE])
+        .preview()
+        .build()
+        .expect("preview")
+        .to_framebuffer()
+        .expect("render");
+    assert_eq!((fb.width(), fb.height()), (900, 550));
+
+    // Label text takes the styled color and scale
+    let red = Rgba::rgb(255, 0, 0);
+    let labels = |font_scale| {
+        style::set(Style { font_scale, text_color: red, ..Style::default() });
+        let fb = ParetoChart::new()
+            .category("parsing", 40.0)
+            .category("io", 25.0)
+            .category("locks", 10.0)
+            .build()
+            .expect("pareto")
+            .to_framebuffer()
+            .expect("render");
+        count_pixels(&fb, red)
+    };
+    let (small, large) = (labels(1), labels(2));
+    assert!(small > 0, "labels should use the styled text color");
+    assert_eq!(large, 4 * small, "scale 2 draws every font pixel as a 2x2 block");
+
+    style::reset();
+}
@@ -192,6 +192,108 @@ test.describe('trueno-viz WASM Demo', () => {
     await page.locator('#physics-btn').click();
   });
 
+  test('heatmap renders and reports backend', async ({ page }) => {
+    await expect(page.locator('#heatmap-canvas')).toBeVisible();
+
+    const perf = page.locator('#heatmap-perf');
+    await expect(perf).toContainText('M cells');
+    const text = await perf.textContent();
+    expect(text).toMatch(/WebGPU|CPU/);
+  });
+
+  test('heatmap palette and size selectors re-render', async ({ page }) => {
+    await page.locator('#heatmap-size').selectOption('512');
+    await expect(page.locator('#heatmap-perf')).toContainText('0.3M cells');
+
+    await page.locator('#heatmap-palette').selectOption('Magma');
+    await page.locator('button:has-text("Reset View")').click();
+    await expect(page.locator('#heatmap-perf')).toContainText('0.3M cells');
+  });
+
+  test('settings changes are written to the URL fragment', async ({ page }) => {
+    await page.locator('#scatter-count').selectOption('50000');
+    await expect(page).toHaveURL(/#[A-Za-z0-9_-]+$/);
+
+    const saved = await page.evaluate(() => localStorage.getItem('trueno-viz-state'));
+    expect(saved).toContain('"scatter_points":50000');
+  });
+
+  test('shared link restores settings', async ({ page }) => {
+    await page.locator('#physics-gravity').selectOption('0.8');
+    await page.locator('#heatmap-palette').selectOption('Coolwarm');
+    await expect(page).toHaveURL(/#[A-Za-z0-9_-]+$/);
+
+    // Only the fragment carries the state now
+    await page.evaluate(() => localStorage.clear());
+    await page.reload();
+    await page.waitForFunction(() => {
+      return document.getElementById('compute-badge')?.textContent !== 'Detecting...';
+    }, { timeout: 10000 });
+
+    await expect(page.locator('#physics-gravity')).toHaveValue('0.8');
+    await expect(page.locator('#heatmap-palette')).toHaveValue('Coolwarm');
+  });
+
+  test('performance HUD toggles onto the canvases', async ({ page }) => {
+    // The idle histogram is blank below its label, so any green pixel in
+    // the bottom-left corner comes from the HUD text
+    const hudPixels = () => page.evaluate(() => {
+      const canvas = document.getElementById('hist-canvas') as HTMLCanvasElement;
+      const ctx = canvas.getContext('2d');
+      if (!ctx) return 0;
+      const data = ctx.getImageData(10, canvas.height - 80, 200, 70).data;
+      let count = 0;
+      for (let i = 0; i < data.length; i += 4) {
+        if (data[i + 1] > 100) count++;
+      }
+      return count;
+    });
+
+    expect(await hudPixels()).toBe(0);
+
+    const btn = page.locator('#hud-btn');
+    await btn.click();
+    await expect(btn).toHaveText('Hide HUD');
+    expect(await hudPixels()).toBeGreaterThan(0);
+
+    await btn.click();
+    await expect(btn).toHaveText('Show HUD');
+    expect(await hudPixels()).toBe(0);
+  });
+
+  test('dropped CSV and JSON files populate the plots', async ({ page }) => {
+    const drop = (canvasId: string, name: string, content: string) =>
+      page.evaluate(([canvasId, name, content]) => {
+        const dt = new DataTransfer();
+        dt.items.add(new File([content], name));
+        document.getElementById(canvasId)!.dispatchEvent(
+          new DragEvent('drop', { dataTransfer: dt, bubbles: true, cancelable: true }));
+      }, [canvasId, name, content]);
+
+    await drop('scatter-canvas', 'points.csv', 'label,x,y\na,1,2\nb,2,4\nc,3,1\n');
+    await expect(page.locator('#scatter-perf')).toContainText('3 pts from file');
+
+    await drop('hist-canvas', 'values.json', '[{"v": 1}, {"v": 2}, {"v": 2}, {"v": 5}]');
+    await expect(page.locator('#hist-perf')).toContainText('4 samples');
+
+    await drop('hist-canvas', 'bad.csv', 'name\nfoo\n');
+    await expect(page.locator('#hist-perf')).toContainText('no numeric columns');
+  });
+
+  test('canvases carry accessible summaries and data tables', async ({ page }) => {
+    const scatter = page.locator('#scatter-canvas');
+    await expect(scatter).toHaveAttribute('role', 'img');
+    await expect(scatter).toHaveAttribute('aria-description', /Scatter plot of \d+ points/);
+    await expect(page.locator('#scatter-desc table caption')).toContainText('Scatter plot data');
+
+    await expect(page.locator('#hist-canvas')).toHaveAttribute('aria-description', 'Histogram with no data.');
+    await page.locator('#hist-btn').click();
+    await expect(page.locator('#hist-canvas')).toHaveAttribute(
+      'aria-description', /Histogram of \d+ values in 50 bins/, { timeout: 3000 });
+    await expect(page.locator('#hist-desc tbody tr')).toHaveCount(50);
+    await page.locator('#hist-btn').click();
+  });
+
   test('screenshot: initial state', async ({ page }) => {
     // Wait for initial render to complete
     await page.waitForTimeout(500);
@@ -7,9 +7,11 @@
 
 #![allow(clippy::unwrap_used)] // WASM demo code
 
+use std::collections::VecDeque;
+
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
-use web_sys::{console, window};
+use web_sys::{console, window, CanvasRenderingContext2d};
 
 #[cfg(feature = "webgpu")]
 use wasm_bindgen_futures::JsFuture;
@@ -546,6 +548,929 @@ impl Histogram {
     }
 }
 
+// ============================================================================
+// Data Loading (CSV / JSON file drop)
+// ============================================================================
+
+/// Numeric columns parsed from a dropped CSV or JSON file
+///
+/// Non-numeric columns are dropped; empty or unparseable cells inside a
+/// numeric column become NaN and are skipped when populating plots.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataTable {
+    names: Vec<String>,
+    columns: Vec<Vec<f32>>,
+}
+
+#[wasm_bindgen]
+impl DataTable {
+    #[wasm_bindgen(getter)]
+    pub fn column_names(&self) -> Vec<String> {
+        self.names.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn column_count(&self) -> usize {
+        self.columns.len()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn row_count(&self) -> usize {
+        self.columns.first().map_or(0, Vec::len)
+    }
+
+    /// Values of a column by name
+    #[wasm_bindgen]
+    pub fn column(&self, name: &str) -> Option<Vec<f32>> {
+        let index = self.names.iter().position(|n| n == name)?;
+        Some(self.columns[index].clone())
+    }
+
+    /// Fill a scatter plot from two columns (default: the first two)
+    #[wasm_bindgen]
+    pub fn populate_scatter(
+        &self,
+        plot: &mut ScatterPlot,
+        x_column: Option<String>,
+        y_column: Option<String>,
+    ) -> Result<usize, JsValue> {
+        let x = self.index_or(x_column.as_deref(), 0)?;
+        let y = self.index_or(y_column.as_deref(), 1)?;
+        let (xs, ys): (Vec<f32>, Vec<f32>) = self.columns[x]
+            .iter()
+            .zip(&self.columns[y])
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .unzip();
+        let count = xs.len();
+        plot.set_data(xs, ys);
+        Ok(count)
+    }
+
+    /// Fill a histogram from one column (default: the first)
+    #[wasm_bindgen]
+    pub fn populate_histogram(
+        &self,
+        histogram: &mut Histogram,
+        column: Option<String>,
+    ) -> Result<usize, JsValue> {
+        let index = self.index_or(column.as_deref(), 0)?;
+        let values: Vec<f32> = self.columns[index]
+            .iter()
+            .copied()
+            .filter(|v| v.is_finite())
+            .collect();
+        let count = values.len();
+        histogram.set_data(values);
+        Ok(count)
+    }
+}
+
+impl DataTable {
+    fn index_or(&self, name: Option<&str>, default: usize) -> Result<usize, JsValue> {
+        match name {
+            Some(name) => self
+                .names
+                .iter()
+                .position(|n| n == name)
+                .ok_or_else(|| JsValue::from_str(&format!("no numeric column '{}'", name))),
+            None if default < self.columns.len() => Ok(default),
+            None => Err(JsValue::from_str(&format!(
+                "need {} numeric columns, file has {}",
+                default + 1,
+                self.columns.len()
+            ))),
+        }
+    }
+
+    /// Keep the columns where every non-empty cell is a number
+    fn from_cells(names: Vec<String>, rows: &[Vec<String>]) -> Result<Self, String> {
+        let mut table = DataTable {
+            names: Vec::new(),
+            columns: Vec::new(),
+        };
+        for (index, name) in names.into_iter().enumerate() {
+            let cells = rows
+                .iter()
+                .map(|row| row.get(index).map_or("", |c| c.trim()));
+            let parsed: Option<Vec<f32>> = cells
+                .map(|cell| {
+                    if cell.is_empty() {
+                        Some(f32::NAN)
+                    } else {
+                        cell.parse().ok()
+                    }
+                })
+                .collect();
+            if let Some(values) = parsed.filter(|v| v.iter().any(|x| x.is_finite())) {
+                table.names.push(name);
+                table.columns.push(values);
+            }
+        }
+        if table.columns.is_empty() {
+            return Err("no numeric columns found".to_string());
+        }
+        Ok(table)
+    }
+
+    fn parse_csv(text: &str) -> Result<Self, String> {
+        let text = text.trim_start_matches('\u{feff}');
+        let first = text
+            .lines()
+            .find(|l| !l.trim().is_empty())
+            .ok_or("empty file")?;
+        let delimiter = [',', ';', '\t']
+            .into_iter()
+            .max_by_key(|&d| first.matches(d).count())
+            .unwrap_or(',');
+        let mut rows: Vec<Vec<String>> = text
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| split_csv_line(l, delimiter))
+            .collect();
+        // Semicolon-separated files use decimal commas (`1,5`)
+        if delimiter == ';' {
+            for field in rows.iter_mut().flatten() {
+                *field = field.replace(',', ".");
+            }
+        }
+
+        // A header row is one with any non-numeric field
+        let has_header = rows[0].iter().any(|f| f.trim().parse::<f32>().is_err());
+        let names = if has_header {
+            rows.remove(0)
+                .into_iter()
+                .map(|f| f.trim().to_string())
+                .collect()
+        } else {
+            (1..=rows[0].len()).map(|i| format!("col{}", i)).collect()
+        };
+        Self::from_cells(names, &rows)
+    }
+
+    fn parse_json(text: &str) -> Result<Self, String> {
+        use serde_json::Value;
+
+        let value: Value = serde_json::from_str(text).map_err(|e| e.to_string())?;
+        let cell = |v: &Value| match v {
+            Value::Number(n) => n.to_string(),
+            Value::String(s) => s.clone(),
+            _ => String::new(),
+        };
+        match value {
+            // { "x": [..], "y": [..] }
+            Value::Object(map) => {
+                let len = map
+                    .values()
+                    .filter_map(Value::as_array)
+                    .map(Vec::len)
+                    .max()
+                    .unwrap_or(0);
+                let names: Vec<String> = map.keys().cloned().collect();
+                let rows: Vec<Vec<String>> = (0..len)
+                    .map(|i| {
+                        map.values()
+                            .map(|col| {
+                                col.as_array()
+                                    .and_then(|a| a.get(i))
+                                    .map_or_else(String::new, cell)
+                            })
+                            .collect()
+                    })
+                    .collect();
+                Self::from_cells(names, &rows)
+            }
+            Value::Array(items) => match items.first() {
+                // [ { "x": 1, "y": 2 }, .. ] - columns from the first record
+                Some(Value::Object(first)) => {
+                    let names: Vec<String> = first.keys().cloned().collect();
+                    let rows: Vec<Vec<String>> = items
+                        .iter()
+                        .map(|item| {
+                            names
+                                .iter()
+                                .map(|n| item.get(n).map_or_else(String::new, cell))
+                                .collect()
+                        })
+                        .collect();
+                    Self::from_cells(names, &rows)
+                }
+                // [ [1, 2], [3, 4] ]
+                Some(Value::Array(first)) => {
+                    let names = (1..=first.len()).map(|i| format!("col{}", i)).collect();
+                    let rows: Vec<Vec<String>> = items
+                        .iter()
+                        .map(|item| {
+                            item.as_array()
+                                .map_or_else(Vec::new, |a| a.iter().map(cell).collect())
+                        })
+                        .collect();
+                    Self::from_cells(names, &rows)
+                }
+                // [1, 2, 3]
+                Some(_) => {
+                    let rows: Vec<Vec<String>> = items.iter().map(|v| vec![cell(v)]).collect();
+                    Self::from_cells(vec!["value".to_string()], &rows)
+                }
+                None => Err("empty array".to_string()),
+            },
+            _ => Err("expected a JSON array or object".to_string()),
+        }
+    }
+}
+
+/// Split one CSV line, honouring double-quoted fields (`""` escapes a quote)
+fn split_csv_line(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// Parse CSV bytes (comma, semicolon or tab separated; header row optional)
+#[wasm_bindgen]
+pub fn load_csv(bytes: &[u8]) -> Result<DataTable, JsValue> {
+    let text = std::str::from_utf8(bytes).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    DataTable::parse_csv(text).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Parse JSON bytes: an array of records, an object of column arrays, an
+/// array of rows, or a flat array of numbers
+#[wasm_bindgen]
+pub fn load_json(bytes: &[u8]) -> Result<DataTable, JsValue> {
+    let text = std::str::from_utf8(bytes).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    DataTable::parse_json(text).map_err(|e| JsValue::from_str(&e))
+}
+
+// ============================================================================
+// Accessibility (canvas descriptions)
+// ============================================================================
+
+/// Rows shown in a generated data table before it is truncated
+const A11Y_TABLE_ROWS: usize = 50;
+
+/// Count, range and mean of a series, ignoring non-finite values
+fn series_stats(values: &[f32]) -> Option<(usize, f32, f32, f64)> {
+    let finite = values.iter().copied().filter(|v| v.is_finite());
+    let (count, min, max, sum) = finite.fold((0, f32::MAX, f32::MIN, 0.0f64), |acc, v| {
+        (acc.0 + 1, acc.1.min(v), acc.2.max(v), acc.3 + f64::from(v))
+    });
+    (count > 0).then(|| (count, min, max, sum / count as f64))
+}
+
+/// Pearson correlation of two series (None when either is constant)
+fn correlation(x: &[f32], y: &[f32]) -> Option<f64> {
+    let pairs: Vec<(f64, f64)> = x
+        .iter()
+        .zip(y)
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .map(|(&x, &y)| (f64::from(x), f64::from(y)))
+        .collect();
+    let n = pairs.len() as f64;
+    let (sx, sy) = pairs
+        .iter()
+        .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+    let (mx, my) = (sx / n, sy / n);
+    let (sxy, sxx, syy) = pairs
+        .iter()
+        .fold((0.0, 0.0, 0.0), |(sxy, sxx, syy), (x, y)| {
+            (
+                sxy + (x - mx) * (y - my),
+                sxx + (x - mx).powi(2),
+                syy + (y - my).powi(2),
+            )
+        });
+    (sxx > 0.0 && syy > 0.0).then(|| sxy / (sxx * syy).sqrt())
+}
+
+/// Plain-language trend for a correlation coefficient
+fn describe_trend(r: Option<f64>) -> String {
+    let Some(r) = r else {
+        return "No trend (constant values)".to_string();
+    };
+    let strength = match r.abs() {
+        a if a < 0.1 => return format!("No clear trend (r = {:.2})", r),
+        a if a < 0.4 => "Weak",
+        a if a < 0.7 => "Moderate",
+        _ => "Strong",
+    };
+    let direction = if r > 0.0 { "increases" } else { "decreases" };
+    format!("{} trend, y {} with x (r = {:.2})", strength, direction, r)
+}
+
+/// Hidden `<table>` markup: caption, header and rows (truncated rows noted
+/// in the caption)
+fn html_table(caption: &str, headers: &[&str], rows: &[Vec<String>], total: usize) -> String {
+    let mut html = format!("<table><caption>{}", caption);
+    if total > rows.len() {
+        html.push_str(&format!(" (first {} of {} rows)", rows.len(), total));
+    }
+    html.push_str("</caption><thead><tr>");
+    for header in headers {
+        html.push_str(&format!(r#"<th scope="col">{}</th>"#, header));
+    }
+    html.push_str("</tr></thead><tbody>");
+    for row in rows {
+        html.push_str("<tr>");
+        for cell in row {
+            html.push_str(&format!("<td>{}</td>", cell));
+        }
+        html.push_str("</tr>");
+    }
+    html.push_str("</tbody></table>");
+    html
+}
+
+#[wasm_bindgen]
+impl ScatterPlot {
+    /// Text summary for `aria-description`: point count, per-axis
+    /// min/max/mean and the x-y trend
+    #[wasm_bindgen]
+    pub fn aria_description(&self) -> String {
+        let (Some(x), Some(y)) = (series_stats(&self.x_data), series_stats(&self.y_data)) else {
+            return "Scatter plot with no data.".to_string();
+        };
+        format!(
+            "Scatter plot of {} points. X ranges from {} to {} (mean {:.3}); \
+             Y ranges from {} to {} (mean {:.3}). {}.",
+            x.0,
+            x.1,
+            x.2,
+            x.3,
+            y.1,
+            y.2,
+            y.3,
+            describe_trend(correlation(&self.x_data, &self.y_data))
+        )
+    }
+
+    /// Data table markup to place (visually hidden) next to the canvas
+    #[wasm_bindgen]
+    pub fn data_table_html(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .x_data
+            .iter()
+            .zip(&self.y_data)
+            .take(A11Y_TABLE_ROWS)
+            .map(|(x, y)| vec![x.to_string(), y.to_string()])
+            .collect();
+        html_table("Scatter plot data", &["x", "y"], &rows, self.x_data.len())
+    }
+}
+
+#[wasm_bindgen]
+impl Histogram {
+    /// Text summary for `aria-description`: sample count, range, mean and
+    /// the most populated bin
+    #[wasm_bindgen]
+    pub fn aria_description(&self) -> String {
+        match series_stats(&self.data) {
+            Some((_, min, max, _)) => histogram_aria_description(self.bin_counts.clone(), min, max),
+            None => "Histogram with no data.".to_string(),
+        }
+    }
+
+    /// Data table markup (one row per bin) to place next to the canvas
+    #[wasm_bindgen]
+    pub fn data_table_html(&self) -> String {
+        let (min, max) = series_stats(&self.data).map_or((0.0, 1.0), |s| (s.1, s.2));
+        histogram_data_table(self.bin_counts.clone(), min, max)
+    }
+}
+
+/// Bin edges for `bins` equal-width bins over `[min, max]`
+fn bin_edges(bins: usize, min: f32, max: f32) -> impl Iterator<Item = (f32, f32)> {
+    let width = (max - min) / bins.max(1) as f32;
+    (0..bins).map(move |i| (min + i as f32 * width, min + (i + 1) as f32 * width))
+}
+
+/// `aria-description` for pre-binned counts over `[min, max]` (e.g. a
+/// streaming histogram binned in JavaScript)
+#[wasm_bindgen]
+pub fn histogram_aria_description(counts: Vec<u32>, min: f32, max: f32) -> String {
+    let total: u64 = counts.iter().map(|&c| u64::from(c)).sum();
+    if total == 0 {
+        return "Histogram with no data.".to_string();
+    }
+    let weighted: f64 = bin_edges(counts.len(), min, max)
+        .zip(&counts)
+        .map(|((lo, hi), &c)| f64::from(lo + hi) / 2.0 * f64::from(c))
+        .sum();
+    let (peak, (lo, hi)) = counts
+        .iter()
+        .copied()
+        .zip(bin_edges(counts.len(), min, max))
+        .max_by_key(|&(c, _)| c)
+        .unwrap_or((0, (min, max)));
+    format!(
+        "Histogram of {} values in {} bins from {} to {} (mean about {:.3}). \
+         Most values ({}) fall between {:.3} and {:.3}.",
+        total,
+        counts.len(),
+        min,
+        max,
+        weighted / total as f64,
+        peak,
+        lo,
+        hi
+    )
+}
+
+/// Data table markup for pre-binned counts over `[min, max]`
+#[wasm_bindgen]
+pub fn histogram_data_table(counts: Vec<u32>, min: f32, max: f32) -> String {
+    let rows: Vec<Vec<String>> = bin_edges(counts.len(), min, max)
+        .zip(&counts)
+        .map(|((lo, hi), c)| vec![format!("{:.3} to {:.3}", lo, hi), c.to_string()])
+        .collect();
+    html_table("Histogram bins", &["Range", "Count"], &rows, counts.len())
+}
+
+// ============================================================================
+// Heatmap Visualization (WebGPU render path)
+// ============================================================================
+
+/// Color palette for heatmaps
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Palette {
+    Viridis,
+    Magma,
+    Coolwarm,
+    Grayscale,
+}
+
+impl Palette {
+    /// Evenly spaced color stops, low to high
+    fn stops(self) -> &'static [[u8; 3]] {
+        match self {
+            Palette::Viridis => &[[68, 1, 84], [59, 82, 139], [33, 145, 140], [94, 201, 98], [253, 231, 37]],
+            Palette::Magma => &[[0, 0, 4], [81, 18, 124], [183, 55, 121], [252, 137, 97], [252, 253, 191]],
+            Palette::Coolwarm => &[[59, 76, 192], [170, 199, 253], [221, 221, 221], [246, 167, 137], [180, 4, 38]],
+            Palette::Grayscale => &[[0, 0, 0], [255, 255, 255]],
+        }
+    }
+}
+
+/// Entries in the color lookup table
+pub const LUT_SIZE: usize = 256;
+
+/// Maximum palette stops the LUT shader accepts
+pub const MAX_PALETTE_STOPS: usize = 16;
+
+/// WGSL compute shader: interpolates palette stops into a 256-entry LUT.
+///
+/// Uniform layout matches [`Heatmap::lut_uniform`].
+pub const HEATMAP_LUT_WGSL: &str = r#"
+struct Stops {
+    count: f32,
+    _pad0: f32,
+    _pad1: f32,
+    _pad2: f32,
+    colors: array<vec4<f32>, 16>,
+}
+
+@group(0) @binding(0) var<uniform> stops: Stops;
+@group(0) @binding(1) var<storage, read_write> lut: array<vec4<f32>, 256>;
+
+@compute @workgroup_size(64)
+fn build_lut(@builtin(global_invocation_id) id: vec3<u32>) {
+    let i = id.x;
+    if (i >= 256u) {
+        return;
+    }
+    let count = u32(stops.count);
+    let pos = f32(i) / 255.0 * f32(count - 1u);
+    let k = min(u32(floor(pos)), count - 2u);
+    lut[i] = mix(stops.colors[k], stops.colors[k + 1u], pos - f32(k));
+}
+"#;
+
+/// WGSL render shader: full-screen triangle whose fragment stage maps each
+/// visible matrix cell through the LUT.
+///
+/// Uniform layout matches [`Heatmap::render_params`]. The matrix is read
+/// from a storage buffer (row-major f32), so a 4096x4096 matrix is 64 MiB
+/// and fits the default 128 MiB binding limit. Cells are sampled nearest
+/// neighbor: zooming in (the `view` rectangle) reveals the full resolution.
+pub const HEATMAP_RENDER_WGSL: &str = r#"
+struct Params {
+    rows: f32,
+    cols: f32,
+    vmin: f32,
+    vmax: f32,
+    view: vec4<f32>, // x0, y0, x1, y1 as fractions of the matrix
+}
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read> values: array<f32>;
+@group(0) @binding(2) var<storage, read> lut: array<vec4<f32>, 256>;
+
+struct VsOut {
+    @builtin(position) pos: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) i: u32) -> VsOut {
+    let xy = vec2<f32>(f32((i << 1u) & 2u), f32(i & 2u)) * 2.0 - 1.0;
+    var out: VsOut;
+    out.pos = vec4<f32>(xy, 0.0, 1.0);
+    out.uv = vec2<f32>((xy.x + 1.0) * 0.5, (1.0 - xy.y) * 0.5);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VsOut) -> @location(0) vec4<f32> {
+    let uv = mix(params.view.xy, params.view.zw, in.uv);
+    let rows = u32(params.rows);
+    let cols = u32(params.cols);
+    let col = min(u32(uv.x * params.cols), cols - 1u);
+    let row = min(u32(uv.y * params.rows), rows - 1u);
+    let v = values[row * cols + col];
+    let t = clamp((v - params.vmin) / max(params.vmax - params.vmin, 1e-12), 0.0, 1.0);
+    return lut[u32(round(t * 255.0))];
+}
+"#;
+
+/// Heatmap configuration
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct HeatmapConfig {
+    palette: Palette,
+}
+
+#[wasm_bindgen]
+impl HeatmapConfig {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self { palette: Palette::Viridis }
+    }
+
+    #[wasm_bindgen]
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+}
+
+impl Default for HeatmapConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Heatmap renderer: WebGPU shaders with a scalar RGBA fallback
+#[wasm_bindgen]
+pub struct Heatmap {
+    config: HeatmapConfig,
+    rows: usize,
+    cols: usize,
+    data: Vec<f32>,
+    min: f32,
+    max: f32,
+}
+
+#[wasm_bindgen]
+impl Heatmap {
+    #[wasm_bindgen(constructor)]
+    pub fn new(config: HeatmapConfig) -> Self {
+        Self {
+            config,
+            rows: 0,
+            cols: 0,
+            data: Vec::new(),
+            min: 0.0,
+            max: 1.0,
+        }
+    }
+
+    /// Set a row-major matrix (`values.len()` must be `rows * cols`)
+    #[wasm_bindgen]
+    pub fn set_data(&mut self, rows: usize, cols: usize, values: Vec<f32>) -> Result<(), JsValue> {
+        if values.len() != rows * cols {
+            return Err(JsValue::from_str(&format!(
+                "expected {} values for {}x{}, got {}",
+                rows * cols,
+                rows,
+                cols,
+                values.len()
+            )));
+        }
+        let (min, max) = values
+            .iter()
+            .filter(|v| v.is_finite())
+            .fold((f32::MAX, f32::MIN), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+        (self.min, self.max) = if min <= max { (min, max) } else { (0.0, 1.0) };
+        self.rows = rows;
+        self.cols = cols;
+        self.data = values;
+        Ok(())
+    }
+
+    /// Change the palette (the LUT must be rebuilt)
+    #[wasm_bindgen]
+    pub fn set_palette(&mut self, palette: Palette) {
+        self.config.palette = palette;
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Uniform data for `HEATMAP_LUT_WGSL`: stop count, padding, then up
+    /// to 16 RGBA stops in 0..1
+    #[wasm_bindgen]
+    pub fn lut_uniform(&self) -> Vec<f32> {
+        let stops = self.config.palette.stops();
+        let mut uniform = vec![0.0; 4 + MAX_PALETTE_STOPS * 4];
+        uniform[0] = stops.len() as f32;
+        for (i, rgb) in stops.iter().take(MAX_PALETTE_STOPS).enumerate() {
+            let base = 4 + i * 4;
+            for (dst, &c) in uniform[base..base + 3].iter_mut().zip(rgb) {
+                *dst = c as f32 / 255.0;
+            }
+            uniform[base + 3] = 1.0;
+        }
+        uniform
+    }
+
+    /// Uniform data for `HEATMAP_RENDER_WGSL`: matrix size, value range and
+    /// the visible rectangle (fractions of the matrix, for zoom and pan)
+    #[wasm_bindgen]
+    pub fn render_params(&self, x0: f32, y0: f32, x1: f32, y1: f32) -> Vec<f32> {
+        vec![
+            self.rows as f32,
+            self.cols as f32,
+            self.min,
+            self.max,
+            x0,
+            y0,
+            x1,
+            y1,
+        ]
+    }
+
+    /// CPU copy of the LUT the compute shader builds (RGBA8, 256 entries)
+    #[wasm_bindgen]
+    pub fn color_lut(&self) -> Vec<u8> {
+        let stops = self.config.palette.stops();
+        let segments = (stops.len() - 1) as f32;
+        let mut lut = Vec::with_capacity(LUT_SIZE * 4);
+        for i in 0..LUT_SIZE {
+            let pos = i as f32 / (LUT_SIZE - 1) as f32 * segments;
+            let k = (pos.floor() as usize).min(stops.len() - 2);
+            let f = pos - k as f32;
+            for (&a, &b) in stops[k].iter().zip(&stops[k + 1]) {
+                let (a, b) = (a as f32, b as f32);
+                lut.push((a + (b - a) * f).round() as u8);
+            }
+            lut.push(255);
+        }
+        lut
+    }
+
+    /// Scalar fallback: one RGBA8 pixel per cell, row-major (for `ImageData`)
+    #[wasm_bindgen]
+    pub fn to_rgba(&self) -> Vec<u8> {
+        let lut = self.color_lut();
+        let span = (self.max - self.min).max(1e-12);
+        let mut rgba = Vec::with_capacity(self.data.len() * 4);
+        for &v in &self.data {
+            let t = ((v - self.min) / span).clamp(0.0, 1.0);
+            let idx = (t * (LUT_SIZE - 1) as f32).round() as usize * 4;
+            rgba.extend_from_slice(&lut[idx..idx + 4]);
+        }
+        rgba
+    }
+
+    /// WGSL source of the LUT compute shader
+    #[wasm_bindgen]
+    pub fn lut_shader() -> String {
+        HEATMAP_LUT_WGSL.to_string()
+    }
+
+    /// WGSL source of the render shader
+    #[wasm_bindgen]
+    pub fn render_shader() -> String {
+        HEATMAP_RENDER_WGSL.to_string()
+    }
+}
+
+// ============================================================================
+// Shareable Plot State
+// ============================================================================
+
+/// Format version of [`PlotState::to_fragment`]
+const PLOT_STATE_VERSION: u8 = 1;
+
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Plot settings and viewport of the demo, shareable as a URL fragment
+/// and persisted as JSON in localStorage
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PlotState {
+    pub scatter_points: u32,
+    pub hist_rate: u32,
+    pub physics_balls: u32,
+    pub physics_gravity: f32,
+    pub heatmap_size: u32,
+    pub palette: Palette,
+    /// Heatmap viewport as fractions of the matrix
+    pub view_x0: f32,
+    pub view_y0: f32,
+    pub view_x1: f32,
+    pub view_y1: f32,
+}
+
+#[wasm_bindgen]
+impl PlotState {
+    /// Demo page defaults
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {
+            scatter_points: 10_000,
+            hist_rate: 10_000,
+            physics_balls: 500,
+            physics_gravity: 0.0,
+            heatmap_size: 1024,
+            palette: Palette::Viridis,
+            view_x0: 0.0,
+            view_y0: 0.0,
+            view_x1: 1.0,
+            view_y1: 1.0,
+        }
+    }
+
+    /// Compact URL-safe encoding: version byte, LEB128 integers, f32
+    /// floats, then base64url without padding (~40 characters, far
+    /// shorter than the JSON even after deflate)
+    #[wasm_bindgen]
+    pub fn to_fragment(&self) -> String {
+        let mut bytes = vec![PLOT_STATE_VERSION];
+        for value in [
+            self.scatter_points,
+            self.hist_rate,
+            self.physics_balls,
+            self.heatmap_size,
+        ] {
+            write_varint(&mut bytes, value);
+        }
+        bytes.push(self.palette as u8);
+        for value in [
+            self.physics_gravity,
+            self.view_x0,
+            self.view_y0,
+            self.view_x1,
+            self.view_y1,
+        ] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        base64url_encode(&bytes)
+    }
+
+    /// Decode a fragment produced by [`PlotState::to_fragment`] (a leading
+    /// `#` is ignored)
+    #[wasm_bindgen]
+    pub fn from_fragment(fragment: &str) -> Result<PlotState, JsValue> {
+        Self::decode(fragment.trim_start_matches('#')).map_err(|e| JsValue::from_str(&e))
+    }
+
+    #[wasm_bindgen]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    #[wasm_bindgen]
+    pub fn from_json(json: &str) -> Result<PlotState, JsValue> {
+        serde_json::from_str(json).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+impl PlotState {
+    fn decode(fragment: &str) -> Result<Self, String> {
+        let bytes = base64url_decode(fragment).ok_or("invalid base64url")?;
+        let mut cursor = bytes.as_slice();
+        if take(&mut cursor, 1)?[0] != PLOT_STATE_VERSION {
+            return Err("unsupported plot state version".to_string());
+        }
+        let scatter_points = read_varint(&mut cursor)?;
+        let hist_rate = read_varint(&mut cursor)?;
+        let physics_balls = read_varint(&mut cursor)?;
+        let heatmap_size = read_varint(&mut cursor)?;
+        let palette = match take(&mut cursor, 1)?[0] {
+            0 => Palette::Viridis,
+            1 => Palette::Magma,
+            2 => Palette::Coolwarm,
+            3 => Palette::Grayscale,
+            p => return Err(format!("unknown palette {}", p)),
+        };
+        let mut float = || -> Result<f32, String> {
+            let raw = take(&mut cursor, 4)?;
+            Ok(f32::from_le_bytes([raw[0], raw[1], raw[2], raw[3]]))
+        };
+        Ok(Self {
+            scatter_points,
+            hist_rate,
+            physics_balls,
+            physics_gravity: float()?,
+            heatmap_size,
+            palette,
+            view_x0: float()?,
+            view_y0: float()?,
+            view_x1: float()?,
+            view_y1: float()?,
+        })
+    }
+}
+
+impl Default for PlotState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn take<'a>(cursor: &mut &'a [u8], n: usize) -> Result<&'a [u8], String> {
+    if cursor.len() < n {
+        return Err("truncated plot state".to_string());
+    }
+    let (head, rest) = cursor.split_at(n);
+    *cursor = rest;
+    Ok(head)
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u32) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn read_varint(cursor: &mut &[u8]) -> Result<u32, String> {
+    let mut value = 0u32;
+    for shift in (0..35).step_by(7) {
+        let byte = take(cursor, 1)?[0];
+        value |= u32::from(byte & 0x7f).checked_shl(shift).unwrap_or(0);
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err("varint too long".to_string())
+}
+
+fn base64url_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | u32::from(b) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            out.push(BASE64URL[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+fn base64url_decode(text: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(text.len() * 3 / 4);
+    for chunk in text.as_bytes().chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut n = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            let v = BASE64URL.iter().position(|&b| b == c)? as u32;
+            n |= v << (18 - 6 * i);
+        }
+        for i in 0..chunk.len() - 1 {
+            out.push((n >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(out)
+}
+
 // ============================================================================
 // Performance Benchmarking
 // ============================================================================
@@ -563,6 +1488,14 @@ pub struct BenchmarkResult {
 
 #[wasm_bindgen]
 impl BenchmarkResult {
+    /// Record a timing measured outside the built-in benchmarks (e.g. a
+    /// live render frame); throughput is derived from size and time
+    #[wasm_bindgen(constructor)]
+    pub fn new(operation: String, data_size: usize, time_ms: f64, tier: String) -> Self {
+        let throughput = if time_ms > 0.0 { data_size as f64 / (time_ms / 1000.0) } else { 0.0 };
+        Self { operation, data_size, time_ms, throughput, tier }
+    }
+
     #[wasm_bindgen(getter)]
     pub fn operation(&self) -> String {
         self.operation.clone()
@@ -583,6 +1516,11 @@ impl BenchmarkResult {
         self.throughput
     }
 
+    #[wasm_bindgen(getter)]
+    pub fn tier(&self) -> String {
+        self.tier.clone()
+    }
+
     #[wasm_bindgen]
     pub fn to_json(&self) -> String {
         serde_json::to_string_pretty(self).unwrap_or_default()
@@ -653,6 +1591,132 @@ pub fn benchmark_histogram(data_size: usize, bins: usize) -> BenchmarkResult {
     }
 }
 
+// ============================================================================
+// Performance HUD
+// ============================================================================
+
+/// Frames averaged for the HUD's FPS and frame time
+const HUD_FRAME_WINDOW: usize = 60;
+
+/// On-canvas performance overlay: FPS, compute tier, data size and the
+/// last transform time (fed from [`BenchmarkResult`]s)
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct PerfHud {
+    tier: String,
+    frame_times: VecDeque<f64>,
+    last_frame: Option<f64>,
+    last: Option<BenchmarkResult>,
+}
+
+#[wasm_bindgen]
+impl PerfHud {
+    #[wasm_bindgen(constructor)]
+    pub fn new(tier: String) -> Self {
+        Self {
+            tier,
+            frame_times: VecDeque::with_capacity(HUD_FRAME_WINDOW),
+            last_frame: None,
+            last: None,
+        }
+    }
+
+    /// Mark a presented frame (`now_ms` from `performance.now()`)
+    #[wasm_bindgen]
+    pub fn frame(&mut self, now_ms: f64) {
+        if let Some(prev) = self.last_frame {
+            if self.frame_times.len() == HUD_FRAME_WINDOW {
+                self.frame_times.pop_front();
+            }
+            self.frame_times.push_back((now_ms - prev).max(0.0));
+        }
+        self.last_frame = Some(now_ms);
+    }
+
+    /// Show the data size and timing of a transform
+    #[wasm_bindgen]
+    pub fn record(&mut self, result: &BenchmarkResult) {
+        self.last = Some(result.clone());
+    }
+
+    /// Forget frame history, e.g. after an animation pauses
+    #[wasm_bindgen]
+    pub fn reset(&mut self) {
+        self.frame_times.clear();
+        self.last_frame = None;
+    }
+
+    /// Mean frame interval over the window (0 until two frames are seen)
+    #[wasm_bindgen(getter)]
+    pub fn frame_time_ms(&self) -> f64 {
+        if self.frame_times.is_empty() {
+            return 0.0;
+        }
+        self.frame_times.iter().sum::<f64>() / self.frame_times.len() as f64
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn fps(&self) -> f64 {
+        let frame_time = self.frame_time_ms();
+        if frame_time > 0.0 {
+            1000.0 / frame_time
+        } else {
+            0.0
+        }
+    }
+
+    /// HUD lines, newline separated
+    #[wasm_bindgen]
+    pub fn text(&self) -> String {
+        let mut lines = vec![
+            format!("{:.0} FPS ({:.1} ms)", self.fps(), self.frame_time_ms()),
+            format!("Tier: {}", self.tier),
+        ];
+        if let Some(last) = &self.last {
+            lines.push(format!("{}: {}", last.operation, format_count(last.data_size)));
+            lines.push(format!("Transform: {:.2} ms", last.time_ms));
+        }
+        lines.join("\n")
+    }
+
+    /// Draw the HUD in the bottom-left corner of a 2D canvas
+    #[wasm_bindgen]
+    pub fn draw(&self, ctx: &CanvasRenderingContext2d) -> Result<(), JsValue> {
+        const LINE_HEIGHT: f64 = 14.0;
+        const CHAR_WIDTH: f64 = 7.0;
+        const PAD: f64 = 6.0;
+
+        let text = self.text();
+        let lines: Vec<&str> = text.lines().collect();
+        let widest = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let width = widest as f64 * CHAR_WIDTH + 2.0 * PAD;
+        let height = lines.len() as f64 * LINE_HEIGHT + 2.0 * PAD;
+        let canvas_height = ctx.canvas().map_or(0.0, |c| f64::from(c.height()));
+        let top = canvas_height - height - 10.0;
+
+        ctx.save();
+        ctx.set_fill_style_str("rgba(0, 0, 0, 0.7)");
+        ctx.fill_rect(10.0, top, width, height);
+        ctx.set_fill_style_str("#50fa7b");
+        ctx.set_font("12px monospace");
+        ctx.set_text_baseline("top");
+        for (i, line) in lines.iter().enumerate() {
+            ctx.fill_text(line, 10.0 + PAD, top + PAD + i as f64 * LINE_HEIGHT)?;
+        }
+        ctx.restore();
+        Ok(())
+    }
+}
+
+/// Compact count for the HUD (1.5K, 2.0M)
+fn format_count(n: usize) -> String {
+    match n {
+        0..=999 => n.to_string(),
+        1_000..=999_999 => format!("{:.1}K", n as f64 / 1e3),
+        _ => format!("{:.1}M", n as f64 / 1e6),
+    }
+}
+
 // ============================================================================
 // ASCII Visualization (Terminal-style)
 // ============================================================================